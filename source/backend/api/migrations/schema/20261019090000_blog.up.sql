@@ -0,0 +1,6 @@
+CREATE TABLE IF NOT EXISTS math_blocks (
+    id UUID PRIMARY KEY REFERENCES post_contents(id),
+    source TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS embed_blocks (
+    id UUID PRIMARY KEY REFERENCES post_contents(id),
+    provider VARCHAR(30) NOT NULL,
+    resource_id VARCHAR(200) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS link_card_blocks (
+    id UUID PRIMARY KEY REFERENCES post_contents(id),
+    url VARCHAR(2048) NOT NULL,
+    title TEXT,
+    description TEXT,
+    site_name TEXT,
+    image_url VARCHAR(2048),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,5 @@
+ALTER TABLE image_blocks
+    DROP COLUMN IF EXISTS link_url,
+    DROP COLUMN IF EXISTS width,
+    DROP COLUMN IF EXISTS caption,
+    DROP COLUMN IF EXISTS alt;
@@ -0,0 +1,5 @@
+ALTER TABLE image_blocks
+    ADD COLUMN IF NOT EXISTS alt VARCHAR(300) NOT NULL DEFAULT '',
+    ADD COLUMN IF NOT EXISTS caption VARCHAR(500),
+    ADD COLUMN IF NOT EXISTS width INTEGER CHECK (width BETWEEN 16 AND 4096),
+    ADD COLUMN IF NOT EXISTS link_url VARCHAR(2048);
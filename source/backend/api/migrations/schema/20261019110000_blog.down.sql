@@ -0,0 +1,5 @@
+ALTER TABLE code_blocks
+    DROP COLUMN IF EXISTS show_line_numbers,
+    DROP COLUMN IF EXISTS diff,
+    DROP COLUMN IF EXISTS start_line,
+    DROP COLUMN IF EXISTS highlight_lines;
@@ -0,0 +1,5 @@
+ALTER TABLE code_blocks
+    ADD COLUMN IF NOT EXISTS highlight_lines VARCHAR(500) NOT NULL DEFAULT '',
+    ADD COLUMN IF NOT EXISTS start_line INTEGER NOT NULL DEFAULT 1 CHECK (start_line >= 1),
+    ADD COLUMN IF NOT EXISTS diff BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN IF NOT EXISTS show_line_numbers BOOLEAN NOT NULL DEFAULT FALSE;
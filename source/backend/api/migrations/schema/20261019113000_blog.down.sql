@@ -0,0 +1 @@
+DROP TABLE IF EXISTS rich_text_rubies;
@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS rich_text_rubies (
+    id UUID PRIMARY KEY,
+    rich_text_id UUID NOT NULL UNIQUE REFERENCES rich_texts(id),
+    ruby VARCHAR(100) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
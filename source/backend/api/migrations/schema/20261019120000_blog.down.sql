@@ -0,0 +1,3 @@
+ALTER TABLE rich_text_links
+    DROP COLUMN IF EXISTS target_heading_id,
+    DROP COLUMN IF EXISTS target_post_id;
@@ -0,0 +1,5 @@
+-- 内部リンクはリンク先の記事と見出しの ID を保持し、URL は読み出し時に解決する
+-- 記事が削除されてもリンク切れとして検出できるよう、外部キー制約は付けない
+ALTER TABLE rich_text_links
+    ADD COLUMN IF NOT EXISTS target_post_id UUID,
+    ADD COLUMN IF NOT EXISTS target_heading_id UUID;
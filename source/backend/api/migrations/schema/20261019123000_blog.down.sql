@@ -0,0 +1,13 @@
+DROP TABLE IF EXISTS rich_text_footnote_refs;
+
+-- 脚注の本文として保存されたリッチテキストを関連ごと削除してから NOT NULL 制約を戻す
+DELETE FROM rich_text_links WHERE rich_text_id IN (SELECT id FROM rich_texts WHERE footnote_id IS NOT NULL);
+DELETE FROM rich_text_rubies WHERE rich_text_id IN (SELECT id FROM rich_texts WHERE footnote_id IS NOT NULL);
+DELETE FROM rich_text_styles WHERE rich_text_id IN (SELECT id FROM rich_texts WHERE footnote_id IS NOT NULL);
+DELETE FROM rich_texts WHERE footnote_id IS NOT NULL;
+
+ALTER TABLE rich_texts DROP CONSTRAINT IF EXISTS rich_texts_owner_check;
+ALTER TABLE rich_texts DROP COLUMN IF EXISTS footnote_id;
+ALTER TABLE rich_texts ALTER COLUMN paragraph_block_id SET NOT NULL;
+
+DROP TABLE IF EXISTS footnotes;
@@ -0,0 +1,23 @@
+-- 記事ごとの脚注。number は本文中で最初に参照された順に振った番号
+CREATE TABLE IF NOT EXISTS footnotes (
+    id UUID PRIMARY KEY,
+    post_id UUID NOT NULL REFERENCES blog_posts(id),
+    number INT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (post_id, number)
+);
+
+-- 脚注の本文も段落と同じ rich_texts に保存し、スタイル・リンク・ルビの仕組みを共有する
+ALTER TABLE rich_texts ALTER COLUMN paragraph_block_id DROP NOT NULL;
+ALTER TABLE rich_texts ADD COLUMN IF NOT EXISTS footnote_id UUID REFERENCES footnotes(id);
+ALTER TABLE rich_texts ADD CONSTRAINT rich_texts_owner_check CHECK (num_nonnulls(paragraph_block_id, footnote_id) = 1);
+
+-- 本文中の脚注参照
+CREATE TABLE IF NOT EXISTS rich_text_footnote_refs (
+    id UUID PRIMARY KEY,
+    rich_text_id UUID NOT NULL UNIQUE REFERENCES rich_texts(id),
+    footnote_id UUID NOT NULL REFERENCES footnotes(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS details_blocks;
+DROP INDEX IF EXISTS post_contents_parent_id_idx;
+ALTER TABLE post_contents DROP COLUMN IF EXISTS parent_id;
@@ -0,0 +1,10 @@
+-- 折りたたみブロックの子コンテンツは親ブロックを参照する。sort_order は同じ親の中での並び順
+ALTER TABLE post_contents ADD COLUMN IF NOT EXISTS parent_id UUID REFERENCES post_contents(id);
+CREATE INDEX IF NOT EXISTS post_contents_parent_id_idx ON post_contents(parent_id);
+
+CREATE TABLE IF NOT EXISTS details_blocks (
+    id UUID PRIMARY KEY REFERENCES post_contents(id),
+    summary VARCHAR(200) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
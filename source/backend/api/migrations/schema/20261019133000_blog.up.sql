@@ -0,0 +1,8 @@
+-- 図はソースのまま保持し、描画はフロントエンドで行う。kind は図を記述する DSL の種類（mermaid など）
+CREATE TABLE IF NOT EXISTS diagram_blocks (
+    id UUID PRIMARY KEY REFERENCES post_contents(id),
+    kind VARCHAR(30) NOT NULL,
+    source TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,10 @@
+-- 記事の SEO メタデータ。未設定の記事には行を作らない。og_image_id はサムネイルの代わりに OGP 画像として使う画像
+CREATE TABLE IF NOT EXISTS blog_post_seo_metadata (
+    post_id UUID PRIMARY KEY REFERENCES blog_posts(id),
+    description VARCHAR(160),
+    og_image_id UUID REFERENCES images(id),
+    canonical_url VARCHAR(2048),
+    noindex BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
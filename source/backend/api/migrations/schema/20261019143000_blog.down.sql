@@ -0,0 +1 @@
+ALTER TABLE blog_posts DROP COLUMN IF EXISTS excerpt;
@@ -0,0 +1,2 @@
+-- 記事一覧に表示する抜粋を手動で設定する場合に使う。NULL の場合は本文から自動で作る
+ALTER TABLE blog_posts ADD COLUMN IF NOT EXISTS excerpt VARCHAR(200);
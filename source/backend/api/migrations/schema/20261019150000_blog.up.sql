@@ -0,0 +1,7 @@
+-- 関連記事の類似度計算に使う、記事ごとの語の出現回数（{"語": 回数}）。記事の保存・更新時に求めて置き換える
+CREATE TABLE IF NOT EXISTS blog_post_term_vectors (
+    post_id UUID PRIMARY KEY REFERENCES blog_posts(id),
+    terms JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
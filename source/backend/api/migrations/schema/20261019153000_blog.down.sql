@@ -0,0 +1,4 @@
+DROP TABLE IF EXISTS popular_post_settings;
+DROP INDEX IF EXISTS post_daily_views_viewed_on_idx;
+DROP TABLE IF EXISTS post_daily_views;
+DROP TABLE IF EXISTS page_view_visitors;
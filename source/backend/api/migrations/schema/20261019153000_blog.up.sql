@@ -0,0 +1,30 @@
+-- 同じ日の同じ閲覧者による閲覧を 1 回にまとめるための閲覧者のハッシュ。集計が済んだ前日以前の行は定期的に削除する
+CREATE TABLE IF NOT EXISTS page_view_visitors (
+    post_id UUID NOT NULL REFERENCES blog_posts(id),
+    viewed_on DATE NOT NULL,
+    visitor_hash CHAR(64) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (post_id, viewed_on, visitor_hash)
+);
+
+-- 記事ごと・日ごと（JST）の閲覧数
+CREATE TABLE IF NOT EXISTS post_daily_views (
+    post_id UUID NOT NULL REFERENCES blog_posts(id),
+    viewed_on DATE NOT NULL,
+    views INTEGER NOT NULL DEFAULT 0 CHECK (views >= 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (post_id, viewed_on)
+);
+
+CREATE INDEX IF NOT EXISTS post_daily_views_viewed_on_idx ON post_daily_views (viewed_on);
+
+-- 人気記事の設定（1 行だけ）。mode は manual（管理者が選ぶ）または automatic（閲覧数から選ぶ）
+CREATE TABLE IF NOT EXISTS popular_post_settings (
+    id BOOLEAN PRIMARY KEY DEFAULT TRUE CHECK (id),
+    mode VARCHAR(16) NOT NULL DEFAULT 'manual' CHECK (mode IN ('manual', 'automatic')),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+INSERT INTO popular_post_settings (id, mode) VALUES (TRUE, 'manual') ON CONFLICT (id) DO NOTHING;
@@ -1,17 +1,36 @@
 pub mod usecase {
   pub mod create_blog_post;
+  pub mod export_blog_archive;
+  pub mod export_blog_post_markdown;
+  pub mod find_dangling_internal_links;
+  pub mod import_blog_archive;
+  pub mod import_markdown_post;
+  pub mod import_wordpress_posts;
+  pub mod rebuild_related_post_index;
+  pub mod recompute_popular_posts;
+  pub mod record_page_view;
+  pub mod refresh_link_card;
   pub mod register_image;
+  pub mod render_blog_post_html;
   pub mod select_pick_up_posts;
   pub mod select_popular_posts;
   pub mod select_top_tech_pick_post;
   pub mod update_blog_post;
+  pub mod update_popular_post_mode;
   pub mod view_admin_blog_post;
   pub mod view_all_blog_posts;
+  pub mod view_blog_feed;
+  pub mod view_blog_json_feed;
   pub mod view_blog_post;
+  pub mod view_blog_post_structured_data;
+  pub mod view_blog_post_toc;
   pub mod view_images;
   pub mod view_latest_blog_posts;
   pub mod view_pick_up_posts;
   pub mod view_popular_blog_posts;
+  pub mod view_popular_post_mode;
+  pub mod view_related_posts;
+  pub mod view_sitemap;
   pub mod view_top_tech_pick;
 }
 pub mod dto;
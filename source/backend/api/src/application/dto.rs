@@ -1,4 +1,4 @@
-use chrono::NaiveDate;
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
 use uuid::Uuid;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -9,8 +9,30 @@ pub struct BlogPostDTO {
   pub post_date: NaiveDate,
   pub last_update_date: NaiveDate,
   pub contents: Vec<BlogPostContentDTO>,
+  pub footnotes: Vec<BlogPostFootnoteDTO>,
   pub published_date: NaiveDate,
   pub is_public: bool,
+  pub seo_metadata: SeoMetadataDTO,
+  pub digest: PostDigestDTO,
+}
+
+/// 記事一覧のカードに表示する抜粋と読了時間
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PostDigestDTO {
+  /// 表示する抜粋（手動で設定した抜粋、なければ本文から作った抜粋）
+  pub excerpt: String,
+  /// 手動で設定した抜粋。未設定の場合は `None`
+  pub manual_excerpt: Option<String>,
+  pub reading_time_minutes: u32,
+}
+
+/// 記事の SEO メタデータ。未設定の項目は `None`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SeoMetadataDTO {
+  pub description: Option<String>,
+  pub og_image: Option<ImageDTO>,
+  pub canonical_url: Option<String>,
+  pub noindex: bool,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -20,6 +42,11 @@ pub enum BlogPostContentDTO {
   Paragraph(BlogPostParagraphBlockDTO),
   Image(BlogPostImageBlockDTO),
   Code(BlogPostCodeBlockDTO),
+  Math(BlogPostMathBlockDTO),
+  Embed(BlogPostEmbedBlockDTO),
+  LinkCard(BlogPostLinkCardBlockDTO),
+  Details(BlogPostDetailsBlockDTO),
+  Diagram(BlogPostDiagramBlockDTO),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -40,28 +67,186 @@ pub struct BlogPostParagraphBlockDTO {
   pub text: Vec<BlogPostRichTextDTO>,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlogPostDetailsBlockDTO {
+  pub id: Uuid,
+  pub summary: String,
+  pub children: Vec<BlogPostContentDTO>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct BlogPostRichTextDTO {
   pub text: String,
   pub styles: BlogPostStyleDTO,
   pub link: Option<BlogPostLinkDTO>,
+  pub ruby: Option<String>,
+  pub footnote_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlogPostFootnoteDTO {
+  pub id: Uuid,
+  pub number: u32,
+  pub text: Vec<BlogPostRichTextDTO>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct BlogPostStyleDTO {
   pub bold: bool,
   pub inline_code: bool,
+  pub inline_math: bool,
+  pub italic: bool,
+  pub strikethrough: bool,
+  pub underline: bool,
+  pub highlight: Option<HighlightColorDTO>,
+}
+
+/// 記事の保存結果。リンクカードの OGP メタデータを取得できなくても保存は完了する
+#[derive(Debug, Clone, PartialEq)]
+pub struct SavedBlogPostDTO {
+  pub blog_post: BlogPostDTO,
+  pub link_card_fetch_failures: Vec<LinkCardFetchFailureDTO>,
+}
+
+/// OGP メタデータを取得できず、空のメタデータで保存したリンクカード
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkCardFetchFailureDTO {
+  pub content_id: Uuid,
+  pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportMarkdownPostResultDTO {
+  pub blog_post: BlogPostDTO,
+  pub warnings: Vec<MarkdownImportWarningDTO>,
+  pub link_card_fetch_failures: Vec<LinkCardFetchFailureDTO>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarkdownImportWarningDTO {
+  pub line: usize,
+  pub message: String,
+}
+
+/// トップテックピック・ピックアップ記事・人気記事に設定されている記事 ID
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FeaturedPostIdsDTO {
+  pub top_tech_pick: Option<Uuid>,
+  pub pick_up: Vec<Uuid>,
+  pub popular: Vec<Uuid>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlogArchiveDTO {
+  pub posts: Vec<BlogPostDTO>,
+  pub images: Vec<ImageDTO>,
+  pub featured: FeaturedPostIdsDTO,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportBlogArchiveResultDTO {
+  pub dry_run: bool,
+  pub post_count: usize,
+  pub image_count: usize,
+  pub conflicts: Vec<BlogArchiveConflictDTO>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlogArchiveConflictDTO {
+  pub kind: BlogArchiveConflictKindDTO,
+  pub id: Uuid,
+  pub detail: String,
+  /// 取り込みを中止しなければならない衝突かどうか
+  pub blocking: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BlogArchiveConflictKindDTO {
+  ExistingPost,
+  ExistingImage,
+  ImagePathInUse,
+}
+
+/// WordPress の記事 1 件ごとの取り込み結果
+#[derive(Debug, Clone, PartialEq)]
+pub struct WordPressImportItemReportDTO {
+  /// WXR の `wp:post_id`
+  pub wordpress_post_id: u64,
+  pub title: String,
+  pub status: WordPressImportStatusDTO,
+  /// 作成した記事の ID（取り込んだ場合のみ）
+  pub post_id: Option<Uuid>,
+  /// 取り込まなかった・失敗した理由
+  pub reason: Option<String>,
+  /// 取り込めなかった、または形を変えて取り込んだ内容
+  pub lossy_notes: Vec<String>,
+  /// 新たに登録した画像のパス
+  pub registered_image_paths: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WordPressImportStatusDTO {
+  Imported,
+  Skipped,
+  Failed,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DanglingInternalLinkDTO {
+  pub source_post_id: Uuid,
+  pub source_post_title: String,
+  pub target_post_id: Uuid,
+  pub target_heading_id: Option<Uuid>,
+  pub reason: DanglingLinkReasonDTO,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DanglingLinkReasonDTO {
+  PostNotFound,
+  Unpublished,
+  HeadingNotFound,
+}
+
+/// 閲覧を記録するときに使う、閲覧者の情報
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageViewClientDTO {
+  pub ip: Option<String>,
+  pub user_agent: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PopularPostModeDTO {
+  Manual,
+  Automatic,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HighlightColorDTO {
+  Yellow,
+  Green,
+  Blue,
+  Pink,
+  Red,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct BlogPostLinkDTO {
+  /// 内部リンクの場合は解決済みの URL
   pub url: String,
+  /// 内部リンクのリンク先の記事 ID
+  pub post_id: Option<Uuid>,
+  /// 内部リンクのリンク先の見出し ID
+  pub heading_id: Option<Uuid>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct BlogPostImageBlockDTO {
   pub id: Uuid,
   pub path: String,
+  pub alt: String,
+  pub caption: Option<String>,
+  pub width: Option<u32>,
+  pub link_url: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -70,10 +255,149 @@ pub struct BlogPostCodeBlockDTO {
   pub title: String,
   pub code: String,
   pub language: String,
+  pub highlight_lines: Option<String>,
+  pub start_line: u32,
+  pub diff: bool,
+  pub show_line_numbers: bool,
+  /// サーバー側でハイライトした結果。要求された場合のみ設定される
+  pub highlighted: Option<HighlightedCodeDTO>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BlogFeedFormatDTO {
+  Rss,
+  Atom,
+}
+
+/// 出力したフィードと、キャッシュの検証に使う最終更新日時
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlogFeedDTO {
+  pub xml: String,
+  /// 記事がない場合は `None`
+  pub last_modified: Option<DateTime<Utc>>,
+}
+
+/// 記事の構造化データ（schema.org の BlogPosting）に載せる情報
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlogPostingDTO {
+  pub headline: String,
+  /// メタディスクリプション。未設定の場合は本文から作った要約
+  pub description: String,
+  /// OGP 画像（未設定の場合はサムネイル）の URL
+  pub image: Option<String>,
+  /// 正規 URL。未設定の場合は記事ページの URL
+  pub url: String,
+  pub date_published: DateTime<FixedOffset>,
+  pub date_modified: DateTime<FixedOffset>,
+  /// 著者・発行者として載せるサイト名
+  pub site_name: String,
+  pub site_url: String,
+}
+
+/// 目次の項目。H2 の項目は続く H3 を `children` に持つ
+#[derive(Debug, Clone, PartialEq)]
+pub struct TocItemDTO {
+  pub id: Uuid,
+  pub text: String,
+  /// 見出しへのリンクに使うアンカー（記事内で一意）
+  pub anchor: String,
+  pub level: u8,
+  pub children: Vec<TocItemDTO>,
+}
+
+/// JSON Feed として出力するフィード
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonFeedDTO {
+  pub title: String,
+  pub description: String,
+  pub home_page_url: String,
+  pub feed_url: String,
+  pub items: Vec<JsonFeedItemDTO>,
+  /// 記事がない場合は `None`
+  pub last_modified: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonFeedItemDTO {
+  pub id: Uuid,
+  pub url: String,
+  pub title: String,
+  pub content_html: String,
+  pub summary: String,
+  /// サムネイル画像の URL
+  pub image: Option<String>,
+  pub date_published: DateTime<FixedOffset>,
+  pub date_modified: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CodeHighlightFormatDTO {
+  Tokens,
+  Html,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum HighlightedCodeDTO {
+  /// 行ごとのトークン列
+  Tokens(Vec<Vec<HighlightTokenDTO>>),
+  Html(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HighlightTokenDTO {
+  pub text: String,
+  pub kind: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlogPostMathBlockDTO {
+  pub id: Uuid,
+  pub source: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlogPostEmbedBlockDTO {
+  pub id: Uuid,
+  pub provider: EmbedProviderDTO,
+  pub resource_id: String,
+  pub url: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlogPostDiagramBlockDTO {
+  pub id: Uuid,
+  pub kind: DiagramKindDTO,
+  pub source: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlogPostLinkCardBlockDTO {
+  pub id: Uuid,
+  pub url: String,
+  pub title: Option<String>,
+  pub description: Option<String>,
+  pub site_name: Option<String>,
+  pub image_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EmbedProviderDTO {
+  YouTube,
+  Twitter,
+  GitHubGist,
+  SpeakerDeck,
+  CodePen,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DiagramKindDTO {
+  Mermaid,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ImageDTO {
   pub id: Uuid,
   pub path: String,
+  pub file_name: Option<String>,
+  pub caption: Option<String>,
 }
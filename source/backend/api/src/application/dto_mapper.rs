@@ -1,4 +1,7 @@
 mod blog_post_dto_mapper;
 pub mod image_dto_mapper;
 
-pub use blog_post_dto_mapper::convert_to_blog_post_dto;
+pub use blog_post_dto_mapper::{
+  convert_diagram_kind, convert_embed_provider, convert_highlight_color, convert_link_card_fetch_failure, convert_popular_post_mode, convert_post_digest,
+  convert_seo_metadata, convert_to_blog_post_dto,
+};
@@ -1,9 +1,17 @@
 use crate::application::dto::{
-  BlogPostCodeBlockDTO, BlogPostContentDTO, BlogPostDTO, BlogPostH2BlockDTO, BlogPostH3BlockDTO, BlogPostImageBlockDTO, BlogPostLinkDTO,
-  BlogPostParagraphBlockDTO, BlogPostRichTextDTO, BlogPostStyleDTO, ImageDTO,
+  BlogPostCodeBlockDTO, BlogPostContentDTO, BlogPostDTO, BlogPostDetailsBlockDTO, BlogPostDiagramBlockDTO, BlogPostEmbedBlockDTO, BlogPostFootnoteDTO,
+  BlogPostH2BlockDTO, BlogPostH3BlockDTO, BlogPostImageBlockDTO, BlogPostLinkCardBlockDTO, BlogPostLinkDTO, BlogPostMathBlockDTO, BlogPostParagraphBlockDTO,
+  BlogPostRichTextDTO, BlogPostStyleDTO, DiagramKindDTO, EmbedProviderDTO, HighlightColorDTO, ImageDTO, LinkCardFetchFailureDTO, PopularPostModeDTO,
+  PostDigestDTO, SeoMetadataDTO,
 };
 use crate::domain::blog_domain::blog_post_entity::content_entity::ContentEntity;
+use crate::domain::blog_domain::blog_post_entity::diagram_source_vo::DiagramKind;
+use crate::domain::blog_domain::blog_post_entity::embed_block_entity::EmbedProvider;
+use crate::domain::blog_domain::blog_post_entity::rich_text_vo::{HighlightColor, RichTextVO};
 use crate::domain::blog_domain::blog_post_entity::BlogPostEntity;
+use crate::domain::blog_domain::link_card_content_factory::LinkCardFetchFailure;
+use crate::domain::blog_domain::popular_post_setting_repository::PopularPostMode;
+use crate::domain::blog_domain::services::post_digest_service::PostDigestService;
 
 pub fn convert_to_blog_post_dto(blog_post: BlogPostEntity) -> BlogPostDTO {
   // JstDateをNaiveDateに変換（DTOはUTCとして扱う）
@@ -11,6 +19,9 @@ pub fn convert_to_blog_post_dto(blog_post: BlogPostEntity) -> BlogPostDTO {
 
   let thumbnail = convert_thumbnail(&blog_post);
   let contents = convert_contents(&blog_post);
+  let footnotes = convert_footnotes(&blog_post);
+  let seo_metadata = convert_seo_metadata(&blog_post);
+  let digest = convert_post_digest(&blog_post);
 
   BlogPostDTO {
     id: blog_post.get_id().to_string(),
@@ -19,8 +30,35 @@ pub fn convert_to_blog_post_dto(blog_post: BlogPostEntity) -> BlogPostDTO {
     post_date: blog_post.get_post_date().to_naive_date(),
     last_update_date: blog_post.get_last_update_date().to_naive_date(),
     contents,
+    footnotes,
     published_date,
     is_public: true, // TODO: 実際の公開状態を使用
+    seo_metadata,
+    digest,
+  }
+}
+
+pub fn convert_post_digest(blog_post: &BlogPostEntity) -> PostDigestDTO {
+  let post_digest = PostDigestService::new();
+  PostDigestDTO {
+    excerpt: post_digest.excerpt(blog_post),
+    manual_excerpt: blog_post.get_manual_excerpt().map(str::to_string),
+    reading_time_minutes: post_digest.reading_minutes(blog_post.get_contents()),
+  }
+}
+
+pub fn convert_seo_metadata(blog_post: &BlogPostEntity) -> SeoMetadataDTO {
+  let seo_metadata = blog_post.get_seo_metadata();
+  SeoMetadataDTO {
+    description: seo_metadata.get_description().map(str::to_string),
+    og_image: seo_metadata.get_og_image().map(|og_image| ImageDTO {
+      id: og_image.get_id(),
+      path: og_image.get_path().to_string(),
+      file_name: og_image.get_file_name().map(str::to_string),
+      caption: og_image.get_caption().map(str::to_string),
+    }),
+    canonical_url: seo_metadata.get_canonical_url().map(str::to_string),
+    noindex: seo_metadata.is_noindex(),
   }
 }
 
@@ -29,66 +67,181 @@ fn convert_thumbnail(blog_post: &BlogPostEntity) -> ImageDTO {
     ImageDTO {
       id: thumb.get_id(),
       path: thumb.get_path().to_string(),
+      file_name: thumb.get_file_name().map(str::to_string),
+      caption: thumb.get_caption().map(str::to_string),
     }
   } else {
     // デフォルトのサムネイル
     ImageDTO {
       id: uuid::Uuid::nil(),
       path: String::new(),
+      file_name: None,
+      caption: None,
     }
   }
 }
 
 fn convert_contents(blog_post: &BlogPostEntity) -> Vec<BlogPostContentDTO> {
+  blog_post.get_contents().iter().map(convert_content).collect()
+}
+
+fn convert_content(content: &ContentEntity) -> BlogPostContentDTO {
+  match content {
+    ContentEntity::H2(h2) => BlogPostContentDTO::H2(BlogPostH2BlockDTO {
+      id: h2.get_id(),
+      text: h2.get_value().to_string(),
+    }),
+    ContentEntity::H3(h3) => BlogPostContentDTO::H3(BlogPostH3BlockDTO {
+      id: h3.get_id(),
+      text: h3.get_value().to_string(),
+    }),
+    ContentEntity::Paragraph(para) => {
+      let rich_texts = convert_rich_text(para.get_value());
+
+      BlogPostContentDTO::Paragraph(BlogPostParagraphBlockDTO {
+        id: para.get_id(),
+        text: rich_texts,
+      })
+    }
+    ContentEntity::Image(img) => BlogPostContentDTO::Image(BlogPostImageBlockDTO {
+      id: img.get_id(),
+      path: img.get_path().to_string(),
+      alt: img.get_presentation().get_alt().to_string(),
+      caption: img.get_presentation().get_caption().map(str::to_string),
+      width: img.get_presentation().get_width(),
+      link_url: img.get_presentation().get_link_url().map(str::to_string),
+    }),
+    ContentEntity::CodeBlock(code) => BlogPostContentDTO::Code(BlogPostCodeBlockDTO {
+      id: code.get_id(),
+      title: code.get_title().to_string(),
+      code: code.get_code().to_string(),
+      language: code.get_language().to_string(),
+      highlight_lines: Some(code.get_options().highlight_lines_to_string()).filter(|lines| !lines.is_empty()),
+      start_line: code.get_options().get_start_line(),
+      diff: code.get_options().is_diff(),
+      show_line_numbers: code.get_options().shows_line_numbers(),
+      highlighted: None,
+    }),
+    ContentEntity::Math(math) => BlogPostContentDTO::Math(BlogPostMathBlockDTO {
+      id: math.get_id(),
+      source: math.get_source().to_string(),
+    }),
+    ContentEntity::Embed(embed) => BlogPostContentDTO::Embed(BlogPostEmbedBlockDTO {
+      id: embed.get_id(),
+      provider: convert_embed_provider(embed.get_provider()),
+      resource_id: embed.get_resource_id().to_string(),
+      url: embed.get_url(),
+    }),
+    ContentEntity::LinkCard(link_card) => {
+      let metadata = link_card.get_metadata();
+      BlogPostContentDTO::LinkCard(BlogPostLinkCardBlockDTO {
+        id: link_card.get_id(),
+        url: link_card.get_url().to_string(),
+        title: metadata.title.clone(),
+        description: metadata.description.clone(),
+        site_name: metadata.site_name.clone(),
+        image_url: metadata.image_url.clone(),
+      })
+    }
+    ContentEntity::Details(details) => BlogPostContentDTO::Details(BlogPostDetailsBlockDTO {
+      id: details.get_id(),
+      summary: details.get_summary().to_string(),
+      children: details.get_children().iter().map(convert_content).collect(),
+    }),
+    ContentEntity::Diagram(diagram) => BlogPostContentDTO::Diagram(BlogPostDiagramBlockDTO {
+      id: diagram.get_id(),
+      kind: convert_diagram_kind(diagram.get_kind()),
+      source: diagram.get_source().to_string(),
+    }),
+  }
+}
+
+fn convert_footnotes(blog_post: &BlogPostEntity) -> Vec<BlogPostFootnoteDTO> {
   blog_post
-    .get_contents()
+    .get_footnotes()
     .iter()
-    .map(|content| match content {
-      ContentEntity::H2(h2) => BlogPostContentDTO::H2(BlogPostH2BlockDTO {
-        id: h2.get_id(),
-        text: h2.get_value().to_string(),
-      }),
-      ContentEntity::H3(h3) => BlogPostContentDTO::H3(BlogPostH3BlockDTO {
-        id: h3.get_id(),
-        text: h3.get_value().to_string(),
-      }),
-      ContentEntity::Paragraph(para) => {
-        let rich_texts: Vec<BlogPostRichTextDTO> = para
-          .get_value()
-          .get_text()
-          .iter()
-          .map(|part| BlogPostRichTextDTO {
-            text: part.get_text().to_string(),
-            styles: BlogPostStyleDTO {
-              bold: part.get_styles().bold,
-              inline_code: part.get_styles().inline_code,
-            },
-            link: part.get_link().map(|link| BlogPostLinkDTO { url: link.url.clone() }),
-          })
-          .collect();
-
-        BlogPostContentDTO::Paragraph(BlogPostParagraphBlockDTO {
-          id: para.get_id(),
-          text: rich_texts,
-        })
-      }
-      ContentEntity::Image(img) => BlogPostContentDTO::Image(BlogPostImageBlockDTO {
-        id: img.get_id(),
-        path: img.get_path().to_string(),
-      }),
-      ContentEntity::CodeBlock(code) => BlogPostContentDTO::Code(BlogPostCodeBlockDTO {
-        id: code.get_id(),
-        title: code.get_title().to_string(),
-        code: code.get_code().to_string(),
-        language: code.get_language().to_string(),
+    .map(|footnote| BlogPostFootnoteDTO {
+      id: footnote.get_id(),
+      number: footnote.get_number(),
+      text: convert_rich_text(footnote.get_body()),
+    })
+    .collect()
+}
+
+fn convert_rich_text(rich_text: &RichTextVO) -> Vec<BlogPostRichTextDTO> {
+  rich_text
+    .get_text()
+    .iter()
+    .map(|part| BlogPostRichTextDTO {
+      text: part.get_text().to_string(),
+      styles: BlogPostStyleDTO {
+        bold: part.get_styles().bold,
+        inline_code: part.get_styles().inline_code,
+        inline_math: part.is_inline_math(),
+        italic: part.get_styles().italic,
+        strikethrough: part.get_styles().strikethrough,
+        underline: part.get_styles().underline,
+        highlight: part.get_styles().highlight.map(convert_highlight_color),
+      },
+      link: part.get_link().map(|link| BlogPostLinkDTO {
+        url: link.resolve_url(),
+        post_id: link.get_internal_target().map(|target| target.get_post_id()),
+        heading_id: link.get_internal_target().and_then(|target| target.get_heading_id()),
       }),
+      ruby: part.get_ruby().map(|ruby| ruby.get_value().to_string()),
+      footnote_id: part.get_footnote_ref(),
     })
     .collect()
 }
 
+/// EmbedProviderをEmbedProviderDTOに変換する
+pub fn convert_embed_provider(provider: EmbedProvider) -> EmbedProviderDTO {
+  match provider {
+    EmbedProvider::YouTube => EmbedProviderDTO::YouTube,
+    EmbedProvider::Twitter => EmbedProviderDTO::Twitter,
+    EmbedProvider::GitHubGist => EmbedProviderDTO::GitHubGist,
+    EmbedProvider::SpeakerDeck => EmbedProviderDTO::SpeakerDeck,
+    EmbedProvider::CodePen => EmbedProviderDTO::CodePen,
+  }
+}
+
+/// DiagramKindをDiagramKindDTOに変換する
+pub fn convert_diagram_kind(kind: DiagramKind) -> DiagramKindDTO {
+  match kind {
+    DiagramKind::Mermaid => DiagramKindDTO::Mermaid,
+  }
+}
+
+/// HighlightColorをHighlightColorDTOに変換する
+pub fn convert_highlight_color(color: HighlightColor) -> HighlightColorDTO {
+  match color {
+    HighlightColor::Yellow => HighlightColorDTO::Yellow,
+    HighlightColor::Green => HighlightColorDTO::Green,
+    HighlightColor::Blue => HighlightColorDTO::Blue,
+    HighlightColor::Pink => HighlightColorDTO::Pink,
+    HighlightColor::Red => HighlightColorDTO::Red,
+  }
+}
+
+/// PopularPostModeをPopularPostModeDTOに変換する
+pub fn convert_link_card_fetch_failure(failure: LinkCardFetchFailure) -> LinkCardFetchFailureDTO {
+  LinkCardFetchFailureDTO {
+    content_id: failure.content_id,
+    message: format!("{:#}", failure.error),
+  }
+}
+
+pub fn convert_popular_post_mode(mode: PopularPostMode) -> PopularPostModeDTO {
+  match mode {
+    PopularPostMode::Manual => PopularPostModeDTO::Manual,
+    PopularPostMode::Automatic => PopularPostModeDTO::Automatic,
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::domain::blog_domain::blog_post_entity::code_block_options_vo::CodeBlockOptionsVO;
   use uuid::Uuid;
 
   #[test]
@@ -119,6 +272,10 @@ mod tests {
         Some(RichTextStylesVO {
           bold: true,
           inline_code: false,
+          italic: false,
+          strikethrough: false,
+          underline: false,
+          highlight: None,
         }),
         None,
       ),
@@ -126,7 +283,7 @@ mod tests {
       RichTextPartVO::new(
         "リンク付きテキスト".to_string(),
         None,
-        Some(LinkVO {
+        Some(LinkVO::External {
           url: "https://example.com".to_string(),
         }),
       ),
@@ -136,6 +293,10 @@ mod tests {
         Some(RichTextStylesVO {
           bold: false,
           inline_code: true,
+          italic: false,
+          strikethrough: false,
+          underline: false,
+          highlight: None,
         }),
         None,
       ),
@@ -171,7 +332,9 @@ mod tests {
         "サンプルコード".to_string(),
         "fn main() { println!(\"Hello\"); }".to_string(),
         "rust".to_string(),
-      ),
+        CodeBlockOptionsVO::default(),
+      )
+      .unwrap(),
     ];
 
     let mut blog_post = BlogPostEntity::new(blog_post_id, "テストブログ記事".to_string());
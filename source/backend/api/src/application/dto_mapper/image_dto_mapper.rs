@@ -5,6 +5,8 @@ pub fn convert_to_image_dto(image: ImageEntity) -> ImageDTO {
   ImageDTO {
     id: image.get_id(),
     path: image.get_path().to_string(),
+    file_name: image.get_file_name().map(str::to_string),
+    caption: image.get_caption().map(str::to_string),
   }
 }
 
@@ -24,6 +26,16 @@ mod tests {
     assert_eq!(dto.path, "images/test.jpg");
   }
 
+  #[test]
+  fn test_convert_image_with_details_to_dto() {
+    let image = ImageEntity::new(Uuid::new_v4(), "images/test.jpg".to_string()).with_details(Some("test.jpg".to_string()), Some("テスト画像".to_string()));
+
+    let dto = convert_to_image_dto(image);
+
+    assert_eq!(dto.file_name.as_deref(), Some("test.jpg"));
+    assert_eq!(dto.caption.as_deref(), Some("テスト画像"));
+  }
+
   #[test]
   fn test_convert_different_image_paths() {
     let test_cases = vec![
@@ -1,3 +1,4 @@
+pub mod code_highlight_service;
 pub mod latest_blog_posts_service;
 pub mod pick_up_post_selector_service;
 pub mod popular_post_selector_service;
@@ -0,0 +1,170 @@
+use crate::application::dto::{BlogPostContentDTO, BlogPostDTO, CodeHighlightFormatDTO, HighlightTokenDTO, HighlightedCodeDTO};
+use crate::domain::blog_domain::code_highlighter::{CodeHighlighter, HighlightedCodeVO};
+use anyhow::Result;
+use std::sync::Arc;
+
+/// コードブロックのハイライトサービス
+///
+/// 記事のコードブロックをサーバー側でハイライトし、要求された形式で DTO に付与する
+pub struct CodeHighlightService {
+  highlighter: Arc<dyn CodeHighlighter>,
+}
+
+impl CodeHighlightService {
+  /// 新しいサービスインスタンスを作成する
+  ///
+  /// # Arguments
+  /// * `highlighter` - コードのハイライタ
+  pub fn new(highlighter: Arc<dyn CodeHighlighter>) -> Self {
+    Self { highlighter }
+  }
+
+  /// 記事内のすべてのコードブロックにハイライト結果を付与する
+  ///
+  /// # Arguments
+  /// * `blog_post` - ハイライト結果を付与する記事
+  /// * `format` - トークン列と HTML のどちらで返すか
+  ///
+  /// # Returns
+  /// * `Err` - コードの解析に失敗した場合
+  pub fn highlight_blog_post(&self, blog_post: &mut BlogPostDTO, format: CodeHighlightFormatDTO) -> Result<()> {
+    self.highlight_contents(&mut blog_post.contents, format)
+  }
+
+  fn highlight_contents(&self, contents: &mut [BlogPostContentDTO], format: CodeHighlightFormatDTO) -> Result<()> {
+    for content in contents {
+      match content {
+        BlogPostContentDTO::Code(code) => {
+          let highlighted = self.highlighter.highlight(&code.code, &code.language)?;
+          code.highlighted = Some(convert_highlighted_code(&highlighted, format));
+        }
+        // 折りたたみブロックの中のコードブロックもハイライトする
+        BlogPostContentDTO::Details(details) => self.highlight_contents(&mut details.children, format)?,
+        _ => {}
+      }
+    }
+    Ok(())
+  }
+}
+
+fn convert_highlighted_code(highlighted: &HighlightedCodeVO, format: CodeHighlightFormatDTO) -> HighlightedCodeDTO {
+  match format {
+    CodeHighlightFormatDTO::Tokens => HighlightedCodeDTO::Tokens(
+      highlighted
+        .get_lines()
+        .iter()
+        .map(|line| {
+          line
+            .iter()
+            .map(|token| HighlightTokenDTO {
+              text: token.text.clone(),
+              kind: token.kind.map(|kind| kind.as_str().to_string()),
+            })
+            .collect()
+        })
+        .collect(),
+    ),
+    CodeHighlightFormatDTO::Html => HighlightedCodeDTO::Html(highlighted.to_html()),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::application::dto::{BlogPostCodeBlockDTO, BlogPostDetailsBlockDTO, ImageDTO};
+  use crate::domain::blog_domain::code_highlighter::{HighlightTokenKind, HighlightTokenVO};
+  use chrono::NaiveDate;
+  use uuid::Uuid;
+
+  /// コード全体を 1 つのキーワードトークンとして返すテスト用のハイライタ
+  struct KeywordHighlighter;
+
+  impl CodeHighlighter for KeywordHighlighter {
+    fn highlight(&self, code: &str, _language: &str) -> Result<Arc<HighlightedCodeVO>> {
+      Ok(Arc::new(HighlightedCodeVO::new(vec![vec![HighlightTokenVO {
+        text: code.to_string(),
+        kind: Some(HighlightTokenKind::Keyword),
+      }]])))
+    }
+  }
+
+  fn code_block(code: &str) -> BlogPostContentDTO {
+    BlogPostContentDTO::Code(BlogPostCodeBlockDTO {
+      id: Uuid::new_v4(),
+      title: "main.rs".to_string(),
+      code: code.to_string(),
+      language: "rust".to_string(),
+      highlight_lines: None,
+      start_line: 1,
+      diff: false,
+      show_line_numbers: false,
+      highlighted: None,
+    })
+  }
+
+  fn blog_post(contents: Vec<BlogPostContentDTO>) -> BlogPostDTO {
+    let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    BlogPostDTO {
+      id: Uuid::new_v4().to_string(),
+      title: "ハイライト記事".to_string(),
+      thumbnail: ImageDTO {
+        id: Uuid::new_v4(),
+        path: "test/thumbnail".to_string(),
+        file_name: None,
+        caption: None,
+      },
+      post_date: date,
+      last_update_date: date,
+      contents,
+      footnotes: vec![],
+      published_date: date,
+      is_public: true,
+      seo_metadata: Default::default(),
+      digest: Default::default(),
+    }
+  }
+
+  #[test]
+  fn code_blocks_get_highlighted_tokens() {
+    let service = CodeHighlightService::new(Arc::new(KeywordHighlighter));
+    let mut dto = blog_post(vec![code_block("fn")]);
+
+    service.highlight_blog_post(&mut dto, CodeHighlightFormatDTO::Tokens).unwrap();
+
+    match &dto.contents[0] {
+      BlogPostContentDTO::Code(code) => assert_eq!(
+        code.highlighted,
+        Some(HighlightedCodeDTO::Tokens(vec![vec![HighlightTokenDTO {
+          text: "fn".to_string(),
+          kind: Some("keyword".to_string()),
+        }]]))
+      ),
+      _ => panic!("コンテンツはCodeである必要があります"),
+    }
+  }
+
+  #[test]
+  fn code_blocks_inside_details_get_highlighted_html() {
+    let service = CodeHighlightService::new(Arc::new(KeywordHighlighter));
+    let mut dto = blog_post(vec![BlogPostContentDTO::Details(BlogPostDetailsBlockDTO {
+      id: Uuid::new_v4(),
+      summary: "実装".to_string(),
+      children: vec![code_block("fn")],
+    })]);
+
+    service.highlight_blog_post(&mut dto, CodeHighlightFormatDTO::Html).unwrap();
+
+    match &dto.contents[0] {
+      BlogPostContentDTO::Details(details) => match &details.children[0] {
+        BlogPostContentDTO::Code(code) => {
+          assert_eq!(
+            code.highlighted,
+            Some(HighlightedCodeDTO::Html("<span class=\"hl-keyword\">fn</span>".to_string()))
+          );
+        }
+        _ => panic!("子コンテンツはCodeである必要があります"),
+      },
+      _ => panic!("コンテンツはDetailsである必要があります"),
+    }
+  }
+}
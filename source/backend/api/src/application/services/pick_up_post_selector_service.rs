@@ -84,11 +84,19 @@ mod tests {
       }
     }
 
-    async fn save(&self, _blog_post: &BlogPostEntity) -> Result<BlogPostEntity> {
+    async fn save(
+      &self,
+      _blog_post: &BlogPostEntity,
+      _term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO,
+    ) -> Result<BlogPostEntity> {
       unimplemented!()
     }
 
-    async fn update(&self, _blog_post: &BlogPostEntity) -> Result<BlogPostEntity> {
+    async fn update(
+      &self,
+      _blog_post: &BlogPostEntity,
+      _term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO,
+    ) -> Result<BlogPostEntity> {
       unimplemented!()
     }
 
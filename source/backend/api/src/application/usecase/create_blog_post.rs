@@ -1,9 +1,10 @@
 use std::sync::Arc;
 
-use crate::application::dto::BlogPostDTO;
+use crate::application::dto::SavedBlogPostDTO;
 use crate::application::dto_mapper;
 use crate::domain::blog_domain::blog_post_factory::BlogPostFactory;
 use crate::domain::blog_domain::blog_post_repository::BlogPostRepository;
+use crate::domain::blog_domain::services::related_posts_service::RelatedPostsService;
 use domain_data_mapper::convert_dto_to_domain_input;
 use dto::CreateBlogPostDTO;
 
@@ -20,29 +21,34 @@ impl CreateBlogPostUseCase {
     Self { repository, blog_post_factory }
   }
 
-  pub async fn execute(&self, dto: CreateBlogPostDTO) -> anyhow::Result<BlogPostDTO> {
+  pub async fn execute(&self, dto: CreateBlogPostDTO) -> anyhow::Result<SavedBlogPostDTO> {
     // DTOをドメイン入力に変換
     let domain_input = convert_dto_to_domain_input(dto);
 
     // ファクトリでBlogPostEntityを作成
-    let blog_post = self.blog_post_factory.create(domain_input).await?;
+    let creation = self.blog_post_factory.create(domain_input).await?;
 
-    // リポジトリで保存
-    let saved_blog_post = self.repository.save(&blog_post).await?;
+    // 関連記事用の語の出現回数を求め、記事と一緒に保存する
+    let term_vector = RelatedPostsService::new().term_vector(&creation.blog_post);
+    let saved_blog_post = self.repository.save(&creation.blog_post, &term_vector).await?;
 
-    // BlogPostEntityをBlogPostDTOに変換
-    let result_dto = dto_mapper::convert_to_blog_post_dto(saved_blog_post);
-
-    Ok(result_dto)
+    // BlogPostEntityをBlogPostDTOに変換し、OGP メタデータを取得できなかったリンクカードを添える
+    Ok(SavedBlogPostDTO {
+      blog_post: dto_mapper::convert_to_blog_post_dto(saved_blog_post),
+      link_card_fetch_failures: creation.link_card_fetch_failures.into_iter().map(dto_mapper::convert_link_card_fetch_failure).collect(),
+    })
   }
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::domain::blog_domain::blog_post_entity::link_card_block_entity::OgpMetadataVO;
   use crate::domain::blog_domain::blog_post_entity::BlogPostEntity;
   use crate::domain::blog_domain::blog_post_repository::BlogPostRepository;
   use crate::domain::blog_domain::image_content_factory::ImageContentFactory;
+  use crate::domain::blog_domain::link_card_content_factory::LinkCardContentFactory;
+  use crate::domain::blog_domain::ogp_fetcher::OgpFetcher;
   use crate::domain::image_domain::{image_entity::ImageEntity, image_repository::ImageRepository, image_repository::ImageRepositoryError};
   use async_trait::async_trait;
   use dto::{CreateBlogPostDTO, CreateImageDTO};
@@ -51,14 +57,23 @@ mod tests {
   use std::sync::Arc;
   use uuid::Uuid;
 
+  mock! {
+    OgpFetcherStub {}
+
+    #[async_trait::async_trait]
+    impl OgpFetcher for OgpFetcherStub {
+      async fn fetch(&self, url: &str) -> anyhow::Result<OgpMetadataVO>;
+    }
+  }
+
   mock! {
     BlogPostRepo {}
 
     #[async_trait::async_trait]
     impl BlogPostRepository for BlogPostRepo {
       async fn find(&self, id: &str) -> anyhow::Result<BlogPostEntity>;
-      async fn save(&self, blog_post: &BlogPostEntity) -> anyhow::Result<BlogPostEntity>;
-      async fn update(&self, blog_post: &BlogPostEntity) -> anyhow::Result<BlogPostEntity>;
+      async fn save(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn update(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
       async fn find_latests(&self, quantity: Option<u32>) -> anyhow::Result<Vec<BlogPostEntity>>;
       async fn find_top_tech_pick(&self) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
       async fn update_top_tech_pick_post(&self, top_tech_pick: &crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
@@ -109,6 +124,10 @@ mod tests {
 
   // テスト用のファクトリ作成ヘルパー
   fn create_test_factory() -> Arc<BlogPostFactory> {
+    create_test_factory_with_fetcher(MockOgpFetcherStub::new())
+  }
+
+  fn create_test_factory_with_fetcher(ogp_fetcher: MockOgpFetcherStub) -> Arc<BlogPostFactory> {
     let mut mock_image_repo = MockImageRepository::new();
 
     // テスト用の画像を追加
@@ -118,7 +137,10 @@ mod tests {
     mock_image_repo.add_image(image_path, image);
 
     let image_factory = Arc::new(ImageContentFactory::new(Arc::new(mock_image_repo)));
-    Arc::new(BlogPostFactory::new(image_factory))
+    Arc::new(BlogPostFactory::new(
+      image_factory,
+      Arc::new(LinkCardContentFactory::new(Arc::new(ogp_fetcher))),
+    ))
   }
 
   // テスト用データ作成ヘルパー
@@ -133,6 +155,9 @@ mod tests {
       last_update_date: None,
       published_date: None,
       contents: vec![],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
     }
   }
 
@@ -143,7 +168,7 @@ mod tests {
     let dto = create_test_dto("テスト記事", thumbnail_id);
 
     let mut mock_repository = MockBlogPostRepo::new();
-    mock_repository.expect_save().times(1).returning(move |blog_post| {
+    mock_repository.expect_save().times(1).returning(move |blog_post, _| {
       let mut new_post = BlogPostEntity::new(blog_post.get_id(), blog_post.get_title_text().to_string());
       if let Some(thumbnail) = blog_post.get_thumbnail() {
         new_post.set_thumbnail(thumbnail.get_id(), thumbnail.get_path().to_string());
@@ -160,7 +185,7 @@ mod tests {
 
     // Assert
     assert!(result.is_ok());
-    let blog_post_dto = result.unwrap();
+    let blog_post_dto = result.unwrap().blog_post;
     assert_eq!(blog_post_dto.title, "テスト記事");
 
     // サムネイルが正しく設定されていることを確認
@@ -168,6 +193,24 @@ mod tests {
     assert_eq!(blog_post_dto.thumbnail.path, "path/to/thumbnail.jpg");
   }
 
+  #[tokio::test]
+  async fn test_saves_term_vector_of_created_blog_post() {
+    // Arrange
+    let dto = create_test_dto("Rust の所有権", Uuid::parse_str("00000000-0000-0000-0000-000000000001").unwrap());
+
+    let mut mock_repository = MockBlogPostRepo::new();
+    mock_repository
+      .expect_save()
+      .withf(|blog_post, term_vector| *term_vector == RelatedPostsService::new().term_vector(blog_post) && term_vector.id == blog_post.get_id())
+      .times(1)
+      .returning(|blog_post, _| Ok(BlogPostEntity::new(blog_post.get_id(), blog_post.get_title_text().to_string())));
+
+    let usecase = CreateBlogPostUseCase::new(Arc::new(mock_repository), create_test_factory());
+
+    // Act & Assert
+    assert!(usecase.execute(dto).await.is_ok());
+  }
+
   #[tokio::test]
   async fn test_factory_generated_id_is_preserved() {
     // Arrange
@@ -175,7 +218,7 @@ mod tests {
     let dto = create_test_dto("ID確認記事", thumbnail_id);
 
     let mut mock_repository = MockBlogPostRepo::new();
-    mock_repository.expect_save().times(1).returning(move |blog_post| {
+    mock_repository.expect_save().times(1).returning(move |blog_post, _| {
       let mut new_post = BlogPostEntity::new(blog_post.get_id(), blog_post.get_title_text().to_string());
       if let Some(thumbnail) = blog_post.get_thumbnail() {
         new_post.set_thumbnail(thumbnail.get_id(), thumbnail.get_path().to_string());
@@ -192,7 +235,7 @@ mod tests {
 
     // Assert
     assert!(result.is_ok());
-    let blog_post_dto = result.unwrap();
+    let blog_post_dto = result.unwrap().blog_post;
 
     // ファクトリで自動生成されたIDが正しく設定されていることを確認
     let id_uuid = Uuid::parse_str(&blog_post_dto.id).unwrap();
@@ -207,7 +250,7 @@ mod tests {
     let dto = create_test_dto("保存確認記事", thumbnail_id);
 
     let mut mock_repository = MockBlogPostRepo::new();
-    mock_repository.expect_save().times(1).returning(move |blog_post| {
+    mock_repository.expect_save().times(1).returning(move |blog_post, _| {
       let mut new_post = BlogPostEntity::new(blog_post.get_id(), blog_post.get_title_text().to_string());
       if let Some(thumbnail) = blog_post.get_thumbnail() {
         new_post.set_thumbnail(thumbnail.get_id(), thumbnail.get_path().to_string());
@@ -225,4 +268,31 @@ mod tests {
     // Assert
     assert!(result.is_ok());
   }
+
+  #[tokio::test]
+  async fn link_card_fetch_failure_is_returned_with_saved_post() {
+    // Arrange
+    let link_card_id = Uuid::parse_str("00000000-0000-0000-0000-000000000004").unwrap();
+    let mut dto = create_test_dto("リンクカード記事", Uuid::new_v4());
+    dto.contents = vec![dto::CreateContentDTO::LinkCard {
+      id: link_card_id,
+      url: "https://example.com/article".to_string(),
+    }];
+
+    let mut mock_repository = MockBlogPostRepo::new();
+    mock_repository.expect_save().times(1).returning(|blog_post, _| Ok(BlogPostEntity::new(blog_post.get_id(), blog_post.get_title_text().to_string())));
+
+    let mut mock_fetcher = MockOgpFetcherStub::new();
+    mock_fetcher.expect_fetch().times(1).returning(|_| Err(anyhow::anyhow!("timeout")));
+
+    let usecase = CreateBlogPostUseCase::new(Arc::new(mock_repository), create_test_factory_with_fetcher(mock_fetcher));
+
+    // Act
+    let result = usecase.execute(dto).await.unwrap();
+
+    // Assert
+    assert_eq!(result.link_card_fetch_failures.len(), 1);
+    assert_eq!(result.link_card_fetch_failures[0].content_id, link_card_id);
+    assert!(result.link_card_fetch_failures[0].message.contains("https://example.com/article"));
+  }
 }
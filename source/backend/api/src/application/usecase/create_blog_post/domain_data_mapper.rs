@@ -1,9 +1,16 @@
+use crate::application::dto::{DiagramKindDTO, HighlightColorDTO};
 use crate::domain::blog_domain::{
-  blog_post_factory::{CreateBlogPostInput, CreateContentInput, CreateImageInput, CreateLinkInput, CreateRichTextInput, CreateStyleInput},
+  blog_post_entity::{diagram_source_vo::DiagramKind, rich_text_vo::HighlightColor},
+  blog_post_factory::{
+    CreateBlogPostInput, CreateCodeBlockOptionsInput, CreateContentInput, CreateFootnoteInput, CreateImageInput, CreateImagePresentationInput, CreateLinkInput,
+    CreateRichTextInput, CreateSeoMetadataInput, CreateStyleInput,
+  },
   jst_date_vo::JstDate,
 };
 
-use super::dto::{CreateBlogPostDTO, CreateContentDTO, CreateImageDTO, CreateLinkDTO, CreateRichTextDTO, CreateStyleDTO};
+use super::dto::{
+  CreateBlogPostDTO, CreateContentDTO, CreateFootnoteDTO, CreateImageDTO, CreateLinkDTO, CreateRichTextDTO, CreateSeoMetadataDTO, CreateStyleDTO,
+};
 
 // DTO -> ドメイン変換関数
 
@@ -16,6 +23,27 @@ pub fn convert_dto_to_domain_input(dto: CreateBlogPostDTO) -> CreateBlogPostInpu
     last_update_date: dto.last_update_date.map(JstDate::from_jst_naive_date),
     published_date: dto.published_date.map(JstDate::from_jst_naive_date),
     contents: dto.contents.into_iter().map(convert_content_dto_to_domain).collect(),
+    footnotes: dto.footnotes.into_iter().map(convert_footnote_dto_to_domain).collect(),
+    seo_metadata: convert_seo_metadata_dto_to_domain(dto.seo_metadata),
+    manual_excerpt: dto.manual_excerpt,
+  }
+}
+
+/// CreateSeoMetadataDTOをCreateSeoMetadataInputに変換する
+fn convert_seo_metadata_dto_to_domain(dto: CreateSeoMetadataDTO) -> CreateSeoMetadataInput {
+  CreateSeoMetadataInput {
+    description: dto.description,
+    og_image: dto.og_image.map(convert_image_dto_to_domain),
+    canonical_url: dto.canonical_url,
+    noindex: dto.noindex,
+  }
+}
+
+/// CreateFootnoteDTOをCreateFootnoteInputに変換する
+fn convert_footnote_dto_to_domain(dto: CreateFootnoteDTO) -> CreateFootnoteInput {
+  CreateFootnoteInput {
+    id: dto.id,
+    text: dto.text.into_iter().map(convert_rich_text_dto_to_domain).collect(),
   }
 }
 
@@ -33,8 +61,47 @@ pub fn convert_content_dto_to_domain(dto: CreateContentDTO) -> CreateContentInpu
       id,
       text: text.into_iter().map(convert_rich_text_dto_to_domain).collect(),
     },
-    CreateContentDTO::Image { id, path } => CreateContentInput::Image { id, path },
-    CreateContentDTO::CodeBlock { id, title, code, language } => CreateContentInput::CodeBlock { id, title, code, language },
+    CreateContentDTO::Image { id, path, presentation } => CreateContentInput::Image {
+      id,
+      path,
+      presentation: CreateImagePresentationInput {
+        alt: presentation.alt,
+        caption: presentation.caption,
+        width: presentation.width,
+        link_url: presentation.link_url,
+      },
+    },
+    CreateContentDTO::CodeBlock {
+      id,
+      title,
+      code,
+      language,
+      options,
+    } => CreateContentInput::CodeBlock {
+      id,
+      title,
+      code,
+      language,
+      options: CreateCodeBlockOptionsInput {
+        highlight_lines: options.highlight_lines,
+        start_line: options.start_line,
+        diff: options.diff,
+        show_line_numbers: options.show_line_numbers,
+      },
+    },
+    CreateContentDTO::Math { id, source } => CreateContentInput::Math { id, source },
+    CreateContentDTO::Embed { id, url } => CreateContentInput::Embed { id, url },
+    CreateContentDTO::LinkCard { id, url } => CreateContentInput::LinkCard { id, url },
+    CreateContentDTO::Details { id, summary, children } => CreateContentInput::Details {
+      id,
+      summary,
+      children: children.into_iter().map(convert_content_dto_to_domain).collect(),
+    },
+    CreateContentDTO::Diagram { id, kind, source } => CreateContentInput::Diagram {
+      id,
+      kind: convert_diagram_kind_dto_to_domain(kind),
+      source,
+    },
   }
 }
 
@@ -44,6 +111,8 @@ fn convert_rich_text_dto_to_domain(dto: CreateRichTextDTO) -> CreateRichTextInpu
     text: dto.text,
     styles: convert_style_dto_to_domain(dto.styles),
     link: dto.link.map(convert_link_dto_to_domain),
+    ruby: dto.ruby,
+    footnote_id: dto.footnote_id,
   }
 }
 
@@ -52,12 +121,39 @@ fn convert_style_dto_to_domain(dto: CreateStyleDTO) -> CreateStyleInput {
   CreateStyleInput {
     bold: dto.bold,
     inline_code: dto.inline_code,
+    inline_math: dto.inline_math,
+    italic: dto.italic,
+    strikethrough: dto.strikethrough,
+    underline: dto.underline,
+    highlight: dto.highlight.map(convert_highlight_color_dto_to_domain),
+  }
+}
+
+/// DiagramKindDTOをドメインのDiagramKindに変換する
+pub fn convert_diagram_kind_dto_to_domain(dto: DiagramKindDTO) -> DiagramKind {
+  match dto {
+    DiagramKindDTO::Mermaid => DiagramKind::Mermaid,
+  }
+}
+
+/// HighlightColorDTOをドメインのHighlightColorに変換する
+pub fn convert_highlight_color_dto_to_domain(dto: HighlightColorDTO) -> HighlightColor {
+  match dto {
+    HighlightColorDTO::Yellow => HighlightColor::Yellow,
+    HighlightColorDTO::Green => HighlightColor::Green,
+    HighlightColorDTO::Blue => HighlightColor::Blue,
+    HighlightColorDTO::Pink => HighlightColor::Pink,
+    HighlightColorDTO::Red => HighlightColor::Red,
   }
 }
 
 /// CreateLinkDTOをCreateLinkInputに変換する
 fn convert_link_dto_to_domain(dto: CreateLinkDTO) -> CreateLinkInput {
-  CreateLinkInput { url: dto.url }
+  CreateLinkInput {
+    url: dto.url,
+    post_id: dto.post_id,
+    heading_id: dto.heading_id,
+  }
 }
 
 #[cfg(test)]
@@ -81,6 +177,9 @@ mod tests {
       last_update_date: None,
       published_date: None,
       contents: vec![],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
     };
 
     let domain_input = convert_dto_to_domain_input(dto);
@@ -106,6 +205,9 @@ mod tests {
       last_update_date: Some(NaiveDate::from_ymd_opt(2024, 6, 16).unwrap()),
       published_date: Some(NaiveDate::from_ymd_opt(2024, 6, 17).unwrap()),
       contents: vec![],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
     };
 
     let domain_input = convert_dto_to_domain_input(dto);
@@ -156,21 +258,42 @@ mod tests {
             styles: CreateStyleDTO {
               bold: false,
               inline_code: false,
+              inline_math: false,
+              italic: false,
+              strikethrough: false,
+              underline: false,
+              highlight: None,
             },
             link: None,
+            ruby: None,
+            footnote_id: None,
           }],
         },
         CreateContentDTO::Image {
           id: img_id,
           path: "path/to/image.jpg".to_string(),
+          presentation: CreateImagePresentationDTO {
+            alt: "サンプル画像".to_string(),
+            width: Some(320),
+            ..Default::default()
+          },
         },
         CreateContentDTO::CodeBlock {
           id: code_id,
           title: "サンプルコード".to_string(),
           code: "println!(\"Hello, world!\");".to_string(),
           language: "rust".to_string(),
+          options: CreateCodeBlockOptionsDTO {
+            highlight_lines: Some("1".to_string()),
+            start_line: Some(5),
+            diff: false,
+            show_line_numbers: true,
+          },
         },
       ],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
     };
 
     let domain_input = convert_dto_to_domain_input(dto);
@@ -204,16 +327,27 @@ mod tests {
     }
 
     match &domain_input.contents[3] {
-      CreateContentInput::Image { id, path } => {
+      CreateContentInput::Image { id, path, presentation } => {
         assert_eq!(*id, img_id);
         assert_eq!(path, "path/to/image.jpg");
+        assert_eq!(presentation.alt, "サンプル画像");
+        assert_eq!(presentation.width, Some(320));
       }
       _ => panic!("期待されるコンテンツタイプはImageです"),
     }
 
     match &domain_input.contents[4] {
-      CreateContentInput::CodeBlock { id, title, code, language } => {
+      CreateContentInput::CodeBlock {
+        id,
+        title,
+        code,
+        language,
+        options,
+      } => {
         assert_eq!(*id, code_id);
+        assert_eq!(options.highlight_lines.as_deref(), Some("1"));
+        assert_eq!(options.start_line, Some(5));
+        assert!(options.show_line_numbers);
         assert_eq!(title, "サンプルコード");
         assert_eq!(code, "println!(\"Hello, world!\");");
         assert_eq!(language, "rust");
@@ -244,37 +378,70 @@ mod tests {
             styles: CreateStyleDTO {
               bold: false,
               inline_code: false,
+              inline_math: false,
+              italic: false,
+              strikethrough: false,
+              underline: false,
+              highlight: None,
             },
             link: None,
+            ruby: None,
+            footnote_id: None,
           },
           CreateRichTextDTO {
             text: "太字テキスト".to_string(),
             styles: CreateStyleDTO {
               bold: true,
               inline_code: false,
+              inline_math: false,
+              italic: false,
+              strikethrough: false,
+              underline: false,
+              highlight: None,
             },
             link: None,
+            ruby: None,
+            footnote_id: None,
           },
           CreateRichTextDTO {
             text: "リンクテキスト".to_string(),
             styles: CreateStyleDTO {
               bold: false,
               inline_code: false,
+              inline_math: false,
+              italic: false,
+              strikethrough: false,
+              underline: false,
+              highlight: None,
             },
             link: Some(CreateLinkDTO {
               url: "https://example.com".to_string(),
+              post_id: None,
+              heading_id: None,
             }),
+            ruby: None,
+            footnote_id: None,
           },
           CreateRichTextDTO {
             text: "インラインコード".to_string(),
             styles: CreateStyleDTO {
               bold: false,
               inline_code: true,
+              inline_math: false,
+              italic: false,
+              strikethrough: false,
+              underline: false,
+              highlight: None,
             },
             link: None,
+            ruby: None,
+            footnote_id: None,
           },
         ],
       }],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
     };
 
     let domain_input = convert_dto_to_domain_input(dto);
@@ -320,6 +487,9 @@ mod tests {
       last_update_date: None,
       published_date: None,
       contents: vec![],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
     };
 
     let domain_input = convert_dto_to_domain_input(dto);
@@ -1,6 +1,8 @@
 use chrono::NaiveDate;
 use uuid::Uuid;
 
+use crate::application::dto::{DiagramKindDTO, HighlightColorDTO};
+
 // DTOの定義（APIリクエストから受け取るデータ構造）
 
 #[derive(Debug, Clone)]
@@ -11,6 +13,9 @@ pub struct CreateBlogPostDTO {
   pub last_update_date: Option<NaiveDate>,
   pub published_date: Option<NaiveDate>,
   pub contents: Vec<CreateContentDTO>,
+  pub footnotes: Vec<CreateFootnoteDTO>,
+  pub seo_metadata: CreateSeoMetadataDTO,
+  pub manual_excerpt: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -19,13 +24,65 @@ pub struct CreateImageDTO {
   pub path: String,
 }
 
+/// 記事の SEO メタデータ。未指定の項目は `None`
+#[derive(Debug, Clone, Default)]
+pub struct CreateSeoMetadataDTO {
+  pub description: Option<String>,
+  pub og_image: Option<CreateImageDTO>,
+  pub canonical_url: Option<String>,
+  pub noindex: bool,
+}
+
 #[derive(Debug, Clone)]
 pub enum CreateContentDTO {
-  H2 { id: Uuid, text: String },
-  H3 { id: Uuid, text: String },
-  Paragraph { id: Uuid, text: Vec<CreateRichTextDTO> },
-  Image { id: Uuid, path: String },
-  CodeBlock { id: Uuid, title: String, code: String, language: String },
+  H2 {
+    id: Uuid,
+    text: String,
+  },
+  H3 {
+    id: Uuid,
+    text: String,
+  },
+  Paragraph {
+    id: Uuid,
+    text: Vec<CreateRichTextDTO>,
+  },
+  Image {
+    id: Uuid,
+    path: String,
+    presentation: CreateImagePresentationDTO,
+  },
+  CodeBlock {
+    id: Uuid,
+    title: String,
+    code: String,
+    language: String,
+    options: CreateCodeBlockOptionsDTO,
+  },
+  Math {
+    id: Uuid,
+    source: String,
+  },
+  Embed {
+    id: Uuid,
+    url: String,
+  },
+  /// OGP メタデータはサーバー側で取得するため、クライアントからは URL のみを受け付ける
+  LinkCard {
+    id: Uuid,
+    url: String,
+  },
+  /// 子コンテンツに折りたたみブロックを含めることはできない
+  Details {
+    id: Uuid,
+    summary: String,
+    children: Vec<CreateContentDTO>,
+  },
+  Diagram {
+    id: Uuid,
+    kind: DiagramKindDTO,
+    source: String,
+  },
 }
 
 #[derive(Debug, Clone)]
@@ -33,15 +90,49 @@ pub struct CreateRichTextDTO {
   pub text: String,
   pub styles: CreateStyleDTO,
   pub link: Option<CreateLinkDTO>,
+  pub ruby: Option<String>,
+  pub footnote_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateFootnoteDTO {
+  pub id: Uuid,
+  pub text: Vec<CreateRichTextDTO>,
 }
 
 #[derive(Debug, Clone)]
 pub struct CreateStyleDTO {
   pub bold: bool,
   pub inline_code: bool,
+  pub inline_math: bool,
+  pub italic: bool,
+  pub strikethrough: bool,
+  pub underline: bool,
+  pub highlight: Option<HighlightColorDTO>,
 }
 
 #[derive(Debug, Clone)]
 pub struct CreateLinkDTO {
+  /// 内部リンクの場合は無視される
   pub url: String,
+  /// 内部リンクのリンク先の記事 ID
+  pub post_id: Option<Uuid>,
+  /// 内部リンクのリンク先の見出し ID
+  pub heading_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CreateCodeBlockOptionsDTO {
+  pub highlight_lines: Option<String>,
+  pub start_line: Option<u32>,
+  pub diff: bool,
+  pub show_line_numbers: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CreateImagePresentationDTO {
+  pub alt: String,
+  pub caption: Option<String>,
+  pub width: Option<u32>,
+  pub link_url: Option<String>,
 }
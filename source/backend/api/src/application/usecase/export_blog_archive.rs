@@ -0,0 +1,151 @@
+use std::sync::Arc;
+
+use crate::application::dto::{BlogArchiveDTO, FeaturedPostIdsDTO};
+use crate::application::dto_mapper::{self, image_dto_mapper::convert_to_image_dto};
+use crate::domain::blog_domain::{blog_archive_repository::BlogArchiveRepository, blog_post_repository::BlogPostRepository};
+use crate::domain::image_domain::image_repository::ImageRepository;
+
+/// 全記事・画像・注目記事の設定をアーカイブとして書き出すユースケース
+///
+/// 障害時の復旧や本番環境からステージング環境への複製に使う管理者向けの機能なので、未公開記事も書き出す。
+pub struct ExportBlogArchiveUseCase {
+  blog_post_repository: Arc<dyn BlogPostRepository>,
+  image_repository: Arc<dyn ImageRepository>,
+  archive_repository: Arc<dyn BlogArchiveRepository>,
+}
+
+impl ExportBlogArchiveUseCase {
+  pub fn new(
+    blog_post_repository: Arc<dyn BlogPostRepository>,
+    image_repository: Arc<dyn ImageRepository>,
+    archive_repository: Arc<dyn BlogArchiveRepository>,
+  ) -> Self {
+    Self {
+      blog_post_repository,
+      image_repository,
+      archive_repository,
+    }
+  }
+
+  pub async fn execute(&self) -> anyhow::Result<BlogArchiveDTO> {
+    let blog_posts = self.blog_post_repository.find_all().await?;
+    let images = self.image_repository.find_all().await.map_err(|e| anyhow::anyhow!("画像の取得に失敗しました: {:?}", e))?;
+    let featured = self.archive_repository.find_featured_post_ids().await?;
+
+    Ok(BlogArchiveDTO {
+      posts: blog_posts.into_iter().map(dto_mapper::convert_to_blog_post_dto).collect(),
+      images: images.into_iter().map(convert_to_image_dto).collect(),
+      featured: FeaturedPostIdsDTO {
+        top_tech_pick: featured.top_tech_pick,
+        pick_up: featured.pick_up,
+        popular: featured.popular,
+      },
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::blog_domain::blog_archive_entity::{BlogArchiveEntity, FeaturedPostIdsVO};
+  use crate::domain::blog_domain::blog_post_entity::BlogPostEntity;
+  use crate::domain::image_domain::{image_entity::ImageEntity, image_repository::ImageRepositoryError};
+  use mockall::mock;
+  use uuid::Uuid;
+
+  mock! {
+    BlogPostRepo {}
+
+    #[async_trait::async_trait]
+    impl BlogPostRepository for BlogPostRepo {
+      async fn find(&self, id: &str) -> anyhow::Result<BlogPostEntity>;
+      async fn save(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn update(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn find_latests(&self, quantity: Option<u32>) -> anyhow::Result<Vec<BlogPostEntity>>;
+      async fn find_top_tech_pick(&self) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
+      async fn update_top_tech_pick_post(&self, top_tech_pick: &crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
+      async fn find_pick_up_posts(&self) -> anyhow::Result<crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity>;
+      async fn update_pick_up_posts(&self, pickup_posts: &crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity) -> anyhow::Result<crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity>;
+      async fn find_popular_posts(&self) -> anyhow::Result<crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity>;
+      async fn update_popular_posts(&self, popular_post_set: &crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity) -> anyhow::Result<crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity>;
+      async fn find_all(&self) -> anyhow::Result<Vec<BlogPostEntity>>;
+    }
+  }
+
+  mock! {
+    ImageRepo {}
+
+    #[async_trait::async_trait]
+    impl ImageRepository for ImageRepo {
+      async fn find(&self, id: &str) -> Result<ImageEntity, ImageRepositoryError>;
+      async fn find_by_path(&self, path: &str) -> Result<ImageEntity, ImageRepositoryError>;
+      async fn save(&self, image: ImageEntity) -> Result<ImageEntity, ImageRepositoryError>;
+      async fn find_all(&self) -> Result<Vec<ImageEntity>, ImageRepositoryError>;
+    }
+  }
+
+  mock! {
+    ArchiveRepo {}
+
+    #[async_trait::async_trait]
+    impl BlogArchiveRepository for ArchiveRepo {
+      async fn find_featured_post_ids(&self) -> anyhow::Result<FeaturedPostIdsVO>;
+      async fn find_existing_post_ids(&self, ids: &[Uuid]) -> anyhow::Result<Vec<Uuid>>;
+      async fn find_images_by_ids_or_paths(&self, ids: &[Uuid], paths: &[String]) -> anyhow::Result<Vec<ImageEntity>>;
+      async fn restore(&self, archive: &BlogArchiveEntity, term_vectors: &[crate::domain::blog_domain::related_post_repository::PostTermVectorVO]) -> anyhow::Result<()>;
+    }
+  }
+
+  #[tokio::test]
+  async fn exports_all_posts_images_and_featured_post_ids() {
+    let post_id = Uuid::from_u128(1);
+    let image_id = Uuid::from_u128(2);
+
+    let mut mock_blog_post_repository = MockBlogPostRepo::new();
+    mock_blog_post_repository.expect_find_all().times(1).returning(move || {
+      let mut post = BlogPostEntity::new(post_id, "アーカイブ記事".to_string());
+      post.set_thumbnail(image_id, "thumbnails/a.png".to_string());
+      Ok(vec![post])
+    });
+    let mut mock_image_repository = MockImageRepo::new();
+    mock_image_repository.expect_find_all().times(1).returning(move || Ok(vec![ImageEntity::new(image_id, "thumbnails/a.png".to_string())]));
+    let mut mock_archive_repository = MockArchiveRepo::new();
+    mock_archive_repository.expect_find_featured_post_ids().times(1).returning(move || {
+      Ok(FeaturedPostIdsVO {
+        top_tech_pick: Some(post_id),
+        ..Default::default()
+      })
+    });
+
+    let usecase = ExportBlogArchiveUseCase::new(
+      Arc::new(mock_blog_post_repository),
+      Arc::new(mock_image_repository),
+      Arc::new(mock_archive_repository),
+    );
+    let archive = usecase.execute().await.unwrap();
+
+    assert_eq!(archive.posts.len(), 1);
+    assert_eq!(archive.posts[0].id, post_id.to_string());
+    assert_eq!(archive.images[0].id, image_id);
+    assert_eq!(archive.featured.top_tech_pick, Some(post_id));
+    assert!(archive.featured.pick_up.is_empty());
+  }
+
+  #[tokio::test]
+  async fn returns_error_when_images_cannot_be_read() {
+    let mut mock_blog_post_repository = MockBlogPostRepo::new();
+    mock_blog_post_repository.expect_find_all().returning(|| Ok(vec![]));
+    let mut mock_image_repository = MockImageRepo::new();
+    mock_image_repository.expect_find_all().returning(|| Err(ImageRepositoryError::FindAllFailed("接続エラー".to_string())));
+    let mut mock_archive_repository = MockArchiveRepo::new();
+    mock_archive_repository.expect_find_featured_post_ids().never();
+
+    let usecase = ExportBlogArchiveUseCase::new(
+      Arc::new(mock_blog_post_repository),
+      Arc::new(mock_image_repository),
+      Arc::new(mock_archive_repository),
+    );
+
+    assert!(usecase.execute().await.is_err());
+  }
+}
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use crate::domain::blog_domain::blog_post_repository::BlogPostRepository;
+use crate::domain::blog_domain::services::markdown_serializer_service::MarkdownSerializerService;
+
+/// 記事を Markdown として書き出すユースケース
+///
+/// バックアップや他サービスへの転載に使う管理者向けの機能なので、未公開記事も書き出せる。
+pub struct ExportBlogPostMarkdownUseCase {
+  repository: Arc<dyn BlogPostRepository>,
+  serializer: MarkdownSerializerService,
+}
+
+impl ExportBlogPostMarkdownUseCase {
+  pub fn new(repository: Arc<dyn BlogPostRepository>, serializer: MarkdownSerializerService) -> Self {
+    Self { repository, serializer }
+  }
+
+  pub async fn execute(&self, id: &str) -> anyhow::Result<String> {
+    // リポジトリから記事を取得
+    let blog_post = self.repository.find(id).await?;
+
+    Ok(self.serializer.serialize(&blog_post))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::blog_domain::blog_post_entity::content_entity::ContentEntity;
+  use crate::domain::blog_domain::blog_post_entity::BlogPostEntity;
+  use crate::domain::blog_domain::jst_date_vo::JstDate;
+  use mockall::mock;
+  use uuid::Uuid;
+
+  mock! {
+    BlogPostRepo {}
+
+    #[async_trait::async_trait]
+    impl BlogPostRepository for BlogPostRepo {
+      async fn find(&self, id: &str) -> anyhow::Result<BlogPostEntity>;
+      async fn save(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn update(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn find_latests(&self, quantity: Option<u32>) -> anyhow::Result<Vec<BlogPostEntity>>;
+      async fn find_top_tech_pick(&self) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
+      async fn update_top_tech_pick_post(&self, top_tech_pick: &crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
+      async fn find_pick_up_posts(&self) -> anyhow::Result<crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity>;
+      async fn update_pick_up_posts(&self, pickup_posts: &crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity) -> anyhow::Result<crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity>;
+      async fn find_popular_posts(&self) -> anyhow::Result<crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity>;
+      async fn update_popular_posts(&self, popular_post_set: &crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity) -> anyhow::Result<crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity>;
+      async fn find_all(&self) -> anyhow::Result<Vec<BlogPostEntity>>;
+    }
+  }
+
+  #[tokio::test]
+  async fn exports_unpublished_post_as_markdown() {
+    let mut mock_repository = MockBlogPostRepo::new();
+    mock_repository.expect_find().times(1).returning(|_| {
+      let mut post = BlogPostEntity::new(Uuid::from_u128(1), "下書き".to_string());
+      post.set_published_date(JstDate::new(3000, 12, 31).unwrap());
+      post.add_content(ContentEntity::h2(Uuid::from_u128(2), "見出し".to_string()));
+      Ok(post)
+    });
+
+    let usecase = ExportBlogPostMarkdownUseCase::new(Arc::new(mock_repository), MarkdownSerializerService::new(String::new()));
+
+    let markdown = usecase.execute("draft-post-id").await.unwrap();
+
+    assert!(markdown.starts_with("---\ntitle: \"下書き\"\n"));
+    assert!(markdown.contains("published: false\n---\n"));
+    assert!(markdown.ends_with("## 見出し\n"));
+  }
+
+  #[tokio::test]
+  async fn returns_error_when_post_not_found() {
+    let mut mock_repository = MockBlogPostRepo::new();
+    mock_repository.expect_find().times(1).returning(|id| Err(anyhow::anyhow!("BlogPostNotFound: {}", id)));
+
+    let usecase = ExportBlogPostMarkdownUseCase::new(Arc::new(mock_repository), MarkdownSerializerService::new(String::new()));
+
+    let result = usecase.execute("missing-post-id").await;
+
+    assert!(result.unwrap_err().to_string().starts_with("BlogPostNotFound:"));
+  }
+}
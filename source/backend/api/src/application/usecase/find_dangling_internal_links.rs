@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use crate::application::dto::{DanglingInternalLinkDTO, DanglingLinkReasonDTO};
+use crate::domain::blog_domain::blog_post_repository::BlogPostRepository;
+use crate::domain::blog_domain::services::internal_link_checker_service::{DanglingInternalLink, DanglingLinkReason, InternalLinkCheckerService};
+
+/// 記事間の内部リンクのうち、リンク切れになっているものを一覧するユースケース
+pub struct FindDanglingInternalLinksUseCase {
+  repository: Arc<dyn BlogPostRepository>,
+}
+
+impl FindDanglingInternalLinksUseCase {
+  pub fn new(repository: Arc<dyn BlogPostRepository>) -> Self {
+    Self { repository }
+  }
+
+  pub async fn execute(&self) -> anyhow::Result<Vec<DanglingInternalLinkDTO>> {
+    // 未公開記事へのリンクも検出するため、未公開記事を含めて取得する
+    let blog_posts = self.repository.find_all().await?;
+
+    let checker = InternalLinkCheckerService::new();
+    let dangling_links = checker.find_dangling_links(&blog_posts);
+
+    Ok(dangling_links.into_iter().map(convert_to_dto).collect())
+  }
+}
+
+fn convert_to_dto(link: DanglingInternalLink) -> DanglingInternalLinkDTO {
+  DanglingInternalLinkDTO {
+    source_post_id: link.source_post_id,
+    source_post_title: link.source_post_title,
+    target_post_id: link.target.get_post_id(),
+    target_heading_id: link.target.get_heading_id(),
+    reason: match link.reason {
+      DanglingLinkReason::PostNotFound => DanglingLinkReasonDTO::PostNotFound,
+      DanglingLinkReason::Unpublished => DanglingLinkReasonDTO::Unpublished,
+      DanglingLinkReason::HeadingNotFound => DanglingLinkReasonDTO::HeadingNotFound,
+    },
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::blog_domain::blog_post_entity::content_entity::ContentEntity;
+  use crate::domain::blog_domain::blog_post_entity::rich_text_vo::{InternalLinkTargetVO, LinkVO, RichTextPartVO, RichTextVO};
+  use crate::domain::blog_domain::blog_post_entity::BlogPostEntity;
+  use crate::domain::blog_domain::jst_date_vo::JstDate;
+  use mockall::mock;
+  use uuid::Uuid;
+
+  mock! {
+    BlogPostRepo {}
+
+    #[async_trait::async_trait]
+    impl BlogPostRepository for BlogPostRepo {
+      async fn find(&self, id: &str) -> anyhow::Result<BlogPostEntity>;
+      async fn save(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn update(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn find_latests(&self, quantity: Option<u32>) -> anyhow::Result<Vec<BlogPostEntity>>;
+      async fn find_top_tech_pick(&self) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
+      async fn update_top_tech_pick_post(&self, top_tech_pick: &crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
+      async fn find_pick_up_posts(&self) -> anyhow::Result<crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity>;
+      async fn update_pick_up_posts(&self, pickup_posts: &crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity) -> anyhow::Result<crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity>;
+      async fn find_popular_posts(&self) -> anyhow::Result<crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity>;
+      async fn update_popular_posts(&self, popular_post_set: &crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity) -> anyhow::Result<crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity>;
+      async fn find_all(&self) -> anyhow::Result<Vec<BlogPostEntity>>;
+    }
+  }
+
+  #[tokio::test]
+  async fn reports_links_to_unpublished_posts() {
+    let mut unpublished = BlogPostEntity::new(Uuid::new_v4(), "公開前の記事".to_string());
+    unpublished.set_published_date(JstDate::new(3000, 1, 1).unwrap());
+    let unpublished_id = unpublished.get_id();
+
+    let mut source = BlogPostEntity::new(Uuid::new_v4(), "リンク元の記事".to_string());
+    source.set_published_date(JstDate::new(2024, 1, 1).unwrap());
+    let link = LinkVO::Internal(InternalLinkTargetVO::new(unpublished_id, None));
+    source.add_content(ContentEntity::paragraph(
+      Uuid::new_v4(),
+      RichTextVO::new(vec![RichTextPartVO::new("次回の記事".to_string(), None, Some(link))]),
+    ));
+    let source_id = source.get_id();
+
+    let mut mock_repo = MockBlogPostRepo::new();
+    mock_repo.expect_find_all().times(1).return_once(move || Ok(vec![source, unpublished]));
+
+    let usecase = FindDanglingInternalLinksUseCase::new(Arc::new(mock_repo));
+    let result = usecase.execute().await.unwrap();
+
+    assert_eq!(
+      result,
+      vec![DanglingInternalLinkDTO {
+        source_post_id: source_id,
+        source_post_title: "リンク元の記事".to_string(),
+        target_post_id: unpublished_id,
+        target_heading_id: None,
+        reason: DanglingLinkReasonDTO::Unpublished,
+      }]
+    );
+  }
+}
@@ -0,0 +1,221 @@
+use std::sync::Arc;
+
+use crate::application::dto::{BlogArchiveConflictDTO, BlogArchiveConflictKindDTO, ImportBlogArchiveResultDTO};
+use crate::domain::blog_domain::blog_archive_entity::{BlogArchiveConflictKind, BlogArchiveConflictVO};
+use crate::domain::blog_domain::blog_archive_repository::BlogArchiveRepository;
+use crate::domain::blog_domain::services::related_posts_service::RelatedPostsService;
+use domain_data_mapper::convert_dto_to_archive_entity;
+use dto::ImportBlogArchiveDTO;
+
+pub mod domain_data_mapper;
+pub mod dto;
+
+/// 書き出したアーカイブを復元するユースケース
+///
+/// ドライランの場合は検証と既存データとの衝突の洗い出しだけを行い、何も書き込まない。
+pub struct ImportBlogArchiveUseCase {
+  archive_repository: Arc<dyn BlogArchiveRepository>,
+}
+
+/// 取り込みを中止しなければならない衝突がある場合のエラー
+#[derive(Debug, PartialEq)]
+pub enum ImportBlogArchiveError {
+  BlockingConflicts(Vec<BlogArchiveConflictDTO>),
+}
+
+impl std::fmt::Display for ImportBlogArchiveError {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    match self {
+      ImportBlogArchiveError::BlockingConflicts(conflicts) => {
+        let details: Vec<String> = conflicts.iter().map(|conflict| format!("{}: {}", conflict.id, conflict.detail)).collect();
+        write!(f, "既存のデータと衝突するため取り込めません: {}", details.join(", "))
+      }
+    }
+  }
+}
+
+impl std::error::Error for ImportBlogArchiveError {}
+
+impl ImportBlogArchiveUseCase {
+  pub fn new(archive_repository: Arc<dyn BlogArchiveRepository>) -> Self {
+    Self { archive_repository }
+  }
+
+  pub async fn execute(&self, dto: ImportBlogArchiveDTO, dry_run: bool) -> anyhow::Result<ImportBlogArchiveResultDTO> {
+    // アーカイブ内の参照関係を検証
+    let archive = convert_dto_to_archive_entity(dto)?;
+
+    // 既存データとの衝突を洗い出す
+    let existing_post_ids = self.archive_repository.find_existing_post_ids(&archive.post_ids()).await?;
+    let existing_images = self.archive_repository.find_images_by_ids_or_paths(&archive.image_ids(), &archive.image_paths()).await?;
+    let conflicts: Vec<BlogArchiveConflictDTO> =
+      archive.detect_conflicts(&existing_post_ids, &existing_images).into_iter().map(convert_conflict_to_dto).collect();
+
+    if !dry_run {
+      let blocking_conflicts: Vec<BlogArchiveConflictDTO> = conflicts.iter().filter(|conflict| conflict.blocking).cloned().collect();
+      if !blocking_conflicts.is_empty() {
+        return Err(ImportBlogArchiveError::BlockingConflicts(blocking_conflicts).into());
+      }
+
+      // 関連記事用の語の出現回数を求め、アーカイブと一緒に復元する
+      let service = RelatedPostsService::new();
+      let term_vectors: Vec<_> = archive.get_posts().iter().map(|blog_post| service.term_vector(blog_post)).collect();
+      self.archive_repository.restore(&archive, &term_vectors).await?;
+    }
+
+    Ok(ImportBlogArchiveResultDTO {
+      dry_run,
+      post_count: archive.get_posts().len(),
+      image_count: archive.get_images().len(),
+      conflicts,
+    })
+  }
+}
+
+fn convert_conflict_to_dto(conflict: BlogArchiveConflictVO) -> BlogArchiveConflictDTO {
+  BlogArchiveConflictDTO {
+    blocking: conflict.is_blocking(),
+    kind: match conflict.kind {
+      BlogArchiveConflictKind::ExistingPost => BlogArchiveConflictKindDTO::ExistingPost,
+      BlogArchiveConflictKind::ExistingImage => BlogArchiveConflictKindDTO::ExistingImage,
+      BlogArchiveConflictKind::ImagePathInUse => BlogArchiveConflictKindDTO::ImagePathInUse,
+    },
+    id: conflict.id,
+    detail: conflict.detail,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::application::dto::{FeaturedPostIdsDTO, ImageDTO};
+  use crate::application::usecase::create_blog_post::dto::{CreateContentDTO, CreateImageDTO};
+  use crate::domain::blog_domain::blog_archive_entity::{BlogArchiveEntity, FeaturedPostIdsVO};
+  use crate::domain::blog_domain::errors::blog_domain_error::BlogDomainError;
+  use crate::domain::image_domain::image_entity::ImageEntity;
+  use chrono::NaiveDate;
+  use dto::ArchivedBlogPostDTO;
+  use mockall::mock;
+  use uuid::Uuid;
+
+  mock! {
+    ArchiveRepo {}
+
+    #[async_trait::async_trait]
+    impl BlogArchiveRepository for ArchiveRepo {
+      async fn find_featured_post_ids(&self) -> anyhow::Result<FeaturedPostIdsVO>;
+      async fn find_existing_post_ids(&self, ids: &[Uuid]) -> anyhow::Result<Vec<Uuid>>;
+      async fn find_images_by_ids_or_paths(&self, ids: &[Uuid], paths: &[String]) -> anyhow::Result<Vec<ImageEntity>>;
+      async fn restore(&self, archive: &BlogArchiveEntity, term_vectors: &[crate::domain::blog_domain::related_post_repository::PostTermVectorVO]) -> anyhow::Result<()>;
+    }
+  }
+
+  const POST_ID: Uuid = Uuid::from_u128(1);
+  const IMAGE_ID: Uuid = Uuid::from_u128(2);
+
+  fn archive_dto() -> ImportBlogArchiveDTO {
+    ImportBlogArchiveDTO {
+      posts: vec![ArchivedBlogPostDTO {
+        id: POST_ID,
+        title: "復元する記事".to_string(),
+        thumbnail: CreateImageDTO {
+          id: IMAGE_ID,
+          path: "thumbnails/a.png".to_string(),
+        },
+        post_date: NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
+        last_update_date: NaiveDate::from_ymd_opt(2024, 2, 3).unwrap(),
+        published_date: NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
+        contents: vec![CreateContentDTO::H2 {
+          id: Uuid::from_u128(3),
+          text: "はじめに".to_string(),
+        }],
+        footnotes: vec![],
+        seo_metadata: Default::default(),
+        manual_excerpt: Default::default(),
+        link_card_metadata: Default::default(),
+      }],
+      images: vec![ImageDTO {
+        id: IMAGE_ID,
+        path: "thumbnails/a.png".to_string(),
+        file_name: None,
+        caption: None,
+      }],
+      featured: FeaturedPostIdsDTO {
+        top_tech_pick: Some(POST_ID),
+        ..Default::default()
+      },
+    }
+  }
+
+  #[tokio::test]
+  async fn dry_run_reports_conflicts_without_restoring() {
+    let mut mock_repository = MockArchiveRepo::new();
+    mock_repository.expect_find_existing_post_ids().times(1).returning(|_| Ok(vec![POST_ID]));
+    mock_repository.expect_find_images_by_ids_or_paths().times(1).returning(|_, _| Ok(vec![]));
+    mock_repository.expect_restore().never();
+
+    let usecase = ImportBlogArchiveUseCase::new(Arc::new(mock_repository));
+    let result = usecase.execute(archive_dto(), true).await.unwrap();
+
+    assert!(result.dry_run);
+    assert_eq!(result.post_count, 1);
+    assert_eq!(result.conflicts.len(), 1);
+    assert_eq!(result.conflicts[0].kind, BlogArchiveConflictKindDTO::ExistingPost);
+    assert_eq!(result.conflicts[0].id, POST_ID);
+    assert!(!result.conflicts[0].blocking);
+  }
+
+  #[tokio::test]
+  async fn restores_archive_with_original_dates() {
+    let mut mock_repository = MockArchiveRepo::new();
+    mock_repository.expect_find_existing_post_ids().returning(|_| Ok(vec![]));
+    mock_repository.expect_find_images_by_ids_or_paths().returning(|_, _| Ok(vec![]));
+    mock_repository
+      .expect_restore()
+      .withf(|archive, term_vectors| {
+        let post = &archive.get_posts()[0];
+        post.get_id() == POST_ID
+          && post.get_last_update_date().to_naive_date() == NaiveDate::from_ymd_opt(2024, 2, 3).unwrap()
+          && archive.get_featured().top_tech_pick == Some(POST_ID)
+          && term_vectors.len() == 1
+          && term_vectors[0].id == POST_ID
+      })
+      .times(1)
+      .returning(|_, _| Ok(()));
+
+    let usecase = ImportBlogArchiveUseCase::new(Arc::new(mock_repository));
+    let result = usecase.execute(archive_dto(), false).await.unwrap();
+
+    assert!(!result.dry_run);
+    assert!(result.conflicts.is_empty());
+  }
+
+  #[tokio::test]
+  async fn refuses_to_restore_when_image_path_is_taken() {
+    let mut mock_repository = MockArchiveRepo::new();
+    mock_repository.expect_find_existing_post_ids().returning(|_| Ok(vec![]));
+    mock_repository.expect_find_images_by_ids_or_paths().returning(|_, _| Ok(vec![ImageEntity::new(Uuid::from_u128(9), "thumbnails/a.png".to_string())]));
+    mock_repository.expect_restore().never();
+
+    let usecase = ImportBlogArchiveUseCase::new(Arc::new(mock_repository));
+    let err = usecase.execute(archive_dto(), false).await.unwrap_err();
+
+    match err.downcast_ref::<ImportBlogArchiveError>() {
+      Some(ImportBlogArchiveError::BlockingConflicts(conflicts)) => assert_eq!(conflicts[0].id, IMAGE_ID),
+      other => panic!("衝突エラーになるべき: {:?}", other),
+    }
+  }
+
+  #[tokio::test]
+  async fn rejects_archive_with_dangling_featured_post() {
+    let mut dto = archive_dto();
+    dto.featured.top_tech_pick = Some(Uuid::from_u128(99));
+    let mut mock_repository = MockArchiveRepo::new();
+    mock_repository.expect_find_existing_post_ids().never();
+
+    let usecase = ImportBlogArchiveUseCase::new(Arc::new(mock_repository));
+    let err = usecase.execute(dto, true).await.unwrap_err();
+
+    assert!(matches!(err.downcast_ref::<BlogDomainError>(), Some(BlogDomainError::InvalidArchive { .. })));
+  }
+}
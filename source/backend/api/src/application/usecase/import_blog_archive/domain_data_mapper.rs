@@ -0,0 +1,67 @@
+use anyhow::Result;
+use std::collections::HashMap;
+
+use super::dto::{ArchivedBlogPostDTO, ArchivedOgpMetadataDTO, ImportBlogArchiveDTO};
+use crate::application::dto::ImageDTO;
+use crate::application::usecase::update_blog_post::{domain_data_mapper::convert_dto_to_entity, dto::UpdateBlogPostDTO};
+use crate::domain::blog_domain::blog_archive_entity::{BlogArchiveEntity, FeaturedPostIdsVO};
+use crate::domain::blog_domain::blog_post_entity::{
+  link_card_block_entity::{LinkCardBlockEntity, OgpMetadataVO},
+  BlogPostEntity,
+};
+use crate::domain::blog_domain::jst_date_vo::JstDate;
+use crate::domain::image_domain::image_entity::ImageEntity;
+
+pub fn convert_dto_to_archive_entity(dto: ImportBlogArchiveDTO) -> Result<BlogArchiveEntity> {
+  let posts = dto.posts.into_iter().map(convert_archived_post_dto_to_entity).collect::<Result<Vec<_>>>()?;
+  let images = dto.images.into_iter().map(convert_image_dto_to_entity).collect();
+  let featured = FeaturedPostIdsVO {
+    top_tech_pick: dto.featured.top_tech_pick,
+    pick_up: dto.featured.pick_up,
+    popular: dto.featured.popular,
+  };
+
+  Ok(BlogArchiveEntity::new(posts, images, featured)?)
+}
+
+fn convert_archived_post_dto_to_entity(dto: ArchivedBlogPostDTO) -> Result<BlogPostEntity> {
+  let mut entity = BlogPostEntity::new(dto.id, dto.title.clone());
+  let link_card_metadata =
+    dto.link_card_metadata.into_iter().map(|(id, metadata)| Ok((id, convert_ogp_metadata_dto_to_vo(metadata)?))).collect::<Result<HashMap<_, _>>>()?;
+
+  // コンテンツと脚注の検証・変換は記事の更新と共通
+  convert_dto_to_entity(
+    UpdateBlogPostDTO {
+      title: dto.title,
+      thumbnail: dto.thumbnail,
+      published_date: dto.published_date,
+      contents: dto.contents,
+      footnotes: dto.footnotes,
+      seo_metadata: dto.seo_metadata,
+      manual_excerpt: dto.manual_excerpt,
+    },
+    &mut entity,
+    &link_card_metadata,
+  )?;
+
+  // 更新では最終更新日が当日になるため、書き出した時点の日付で上書きする
+  entity.set_post_date(JstDate::from_jst_naive_date(dto.post_date));
+  entity.set_last_update_date(JstDate::from_jst_naive_date(dto.last_update_date));
+
+  Ok(entity)
+}
+
+/// 書き出した OGP メタデータを復元する。画像 URL はリンク先と同じく http(s) のみを受け付ける
+fn convert_ogp_metadata_dto_to_vo(dto: ArchivedOgpMetadataDTO) -> Result<OgpMetadataVO> {
+  let image_url = dto.image_url.as_deref().map(LinkCardBlockEntity::validate_url).transpose()?;
+  Ok(OgpMetadataVO {
+    title: dto.title,
+    description: dto.description,
+    site_name: dto.site_name,
+    image_url,
+  })
+}
+
+fn convert_image_dto_to_entity(dto: ImageDTO) -> ImageEntity {
+  ImageEntity::new(dto.id, dto.path).with_details(dto.file_name, dto.caption)
+}
@@ -0,0 +1,38 @@
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::application::dto::{FeaturedPostIdsDTO, ImageDTO};
+use crate::application::usecase::create_blog_post::dto::{CreateContentDTO, CreateFootnoteDTO, CreateImageDTO, CreateSeoMetadataDTO};
+
+#[derive(Debug, Clone)]
+pub struct ImportBlogArchiveDTO {
+  pub posts: Vec<ArchivedBlogPostDTO>,
+  pub images: Vec<ImageDTO>,
+  pub featured: FeaturedPostIdsDTO,
+}
+
+/// アーカイブ内の記事。ID と日付は書き出した時点のものをそのまま復元する
+#[derive(Debug, Clone)]
+pub struct ArchivedBlogPostDTO {
+  pub id: Uuid,
+  pub title: String,
+  pub thumbnail: CreateImageDTO,
+  pub post_date: NaiveDate,
+  pub last_update_date: NaiveDate,
+  pub published_date: NaiveDate,
+  pub contents: Vec<CreateContentDTO>,
+  pub footnotes: Vec<CreateFootnoteDTO>,
+  pub seo_metadata: CreateSeoMetadataDTO,
+  pub manual_excerpt: Option<String>,
+  /// 書き出した時点のリンクカードの OGP メタデータ。復元時に外部へアクセスしないよう、コンテンツ ID ごとに持つ
+  pub link_card_metadata: HashMap<Uuid, ArchivedOgpMetadataDTO>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ArchivedOgpMetadataDTO {
+  pub title: Option<String>,
+  pub description: Option<String>,
+  pub site_name: Option<String>,
+  pub image_url: Option<String>,
+}
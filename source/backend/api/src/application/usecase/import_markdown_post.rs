@@ -0,0 +1,169 @@
+use std::sync::Arc;
+
+use crate::application::dto::{ImportMarkdownPostResultDTO, MarkdownImportWarningDTO};
+use crate::application::dto_mapper;
+use crate::domain::blog_domain::blog_post_factory::BlogPostFactory;
+use crate::domain::blog_domain::blog_post_repository::BlogPostRepository;
+use crate::domain::blog_domain::services::related_posts_service::RelatedPostsService;
+use markdown_post_parser::MarkdownPostParser;
+
+pub mod markdown_post_parser;
+
+/// Markdown ファイルから記事を作成するユースケース
+pub struct ImportMarkdownPostUseCase {
+  repository: Arc<dyn BlogPostRepository>,
+  blog_post_factory: Arc<BlogPostFactory>,
+  markdown_parser: MarkdownPostParser,
+}
+
+impl ImportMarkdownPostUseCase {
+  pub fn new(repository: Arc<dyn BlogPostRepository>, blog_post_factory: Arc<BlogPostFactory>, markdown_parser: MarkdownPostParser) -> Self {
+    Self {
+      repository,
+      blog_post_factory,
+      markdown_parser,
+    }
+  }
+
+  pub async fn execute(&self, markdown: &str) -> anyhow::Result<ImportMarkdownPostResultDTO> {
+    // Markdown をファクトリの入力に変換
+    let parsed = self.markdown_parser.parse(markdown).await?;
+
+    // ファクトリでBlogPostEntityを作成
+    let creation = self.blog_post_factory.create(parsed.input).await?;
+
+    // 関連記事用の語の出現回数を求め、記事と一緒に保存する
+    let term_vector = RelatedPostsService::new().term_vector(&creation.blog_post);
+    let saved_blog_post = self.repository.save(&creation.blog_post, &term_vector).await?;
+
+    Ok(ImportMarkdownPostResultDTO {
+      blog_post: dto_mapper::convert_to_blog_post_dto(saved_blog_post),
+      warnings: parsed
+        .warnings
+        .into_iter()
+        .map(|warning| MarkdownImportWarningDTO {
+          line: warning.line,
+          message: warning.message,
+        })
+        .collect(),
+      link_card_fetch_failures: creation.link_card_fetch_failures.into_iter().map(dto_mapper::convert_link_card_fetch_failure).collect(),
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::markdown_post_parser::MarkdownPostParserError;
+  use super::*;
+  use crate::domain::blog_domain::blog_post_entity::link_card_block_entity::OgpMetadataVO;
+  use crate::domain::blog_domain::blog_post_entity::BlogPostEntity;
+  use crate::domain::blog_domain::image_content_factory::ImageContentFactory;
+  use crate::domain::blog_domain::link_card_content_factory::LinkCardContentFactory;
+  use crate::domain::blog_domain::ogp_fetcher::OgpFetcher;
+  use crate::domain::image_domain::{image_entity::ImageEntity, image_repository::ImageRepository, image_repository::ImageRepositoryError};
+  use async_trait::async_trait;
+  use mockall::mock;
+  use uuid::Uuid;
+
+  mock! {
+    OgpFetcherStub {}
+
+    #[async_trait::async_trait]
+    impl OgpFetcher for OgpFetcherStub {
+      async fn fetch(&self, url: &str) -> anyhow::Result<OgpMetadataVO>;
+    }
+  }
+
+  mock! {
+    BlogPostRepo {}
+
+    #[async_trait::async_trait]
+    impl BlogPostRepository for BlogPostRepo {
+      async fn find(&self, id: &str) -> anyhow::Result<BlogPostEntity>;
+      async fn save(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn update(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn find_latests(&self, quantity: Option<u32>) -> anyhow::Result<Vec<BlogPostEntity>>;
+      async fn find_top_tech_pick(&self) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
+      async fn update_top_tech_pick_post(&self, top_tech_pick: &crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
+      async fn find_pick_up_posts(&self) -> anyhow::Result<crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity>;
+      async fn update_pick_up_posts(&self, pickup_posts: &crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity) -> anyhow::Result<crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity>;
+      async fn find_popular_posts(&self) -> anyhow::Result<crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity>;
+      async fn update_popular_posts(&self, popular_post_set: &crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity) -> anyhow::Result<crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity>;
+      async fn find_all(&self) -> anyhow::Result<Vec<BlogPostEntity>>;
+    }
+  }
+
+  // 1 枚だけ画像が登録されているリポジトリ
+  struct SingleImageRepository;
+
+  #[async_trait]
+  impl ImageRepository for SingleImageRepository {
+    async fn find(&self, _id: &str) -> Result<ImageEntity, ImageRepositoryError> {
+      Err(ImageRepositoryError::FindFailed("not implemented".to_string()))
+    }
+
+    async fn find_by_path(&self, path: &str) -> Result<ImageEntity, ImageRepositoryError> {
+      if path == "posts/sample.png" {
+        Ok(ImageEntity::new(Uuid::from_u128(1), path.to_string()))
+      } else {
+        Err(ImageRepositoryError::FindByPathFailed(format!("ImageNotFoundByPath: {}", path)))
+      }
+    }
+
+    async fn save(&self, _image: ImageEntity) -> Result<ImageEntity, ImageRepositoryError> {
+      Err(ImageRepositoryError::SaveFailed("not implemented".to_string()))
+    }
+
+    async fn find_all(&self) -> Result<Vec<ImageEntity>, ImageRepositoryError> {
+      Err(ImageRepositoryError::FindAllFailed("not implemented".to_string()))
+    }
+  }
+
+  fn create_usecase(mock_repository: MockBlogPostRepo) -> ImportMarkdownPostUseCase {
+    let image_repository: Arc<dyn ImageRepository> = Arc::new(SingleImageRepository);
+    let blog_post_factory = Arc::new(BlogPostFactory::new(
+      Arc::new(ImageContentFactory::new(image_repository.clone())),
+      Arc::new(LinkCardContentFactory::new(Arc::new(MockOgpFetcherStub::new()))),
+    ));
+    ImportMarkdownPostUseCase::new(
+      Arc::new(mock_repository),
+      blog_post_factory,
+      MarkdownPostParser::new(image_repository, String::new()),
+    )
+  }
+
+  #[tokio::test]
+  async fn imports_markdown_and_reports_warnings() {
+    let mut mock_repository = MockBlogPostRepo::new();
+    mock_repository.expect_save().times(1).returning(|blog_post, _| {
+      let mut saved = BlogPostEntity::new(blog_post.get_id(), blog_post.get_title_text().to_string());
+      saved.set_post_date(blog_post.get_post_date().clone());
+      Ok(saved)
+    });
+
+    let usecase = create_usecase(mock_repository);
+    let markdown = "---\ntitle: 取り込み記事\npost_date: 2024-03-04\n---\n\n## 見出し\n\n![図](posts/sample.png)\n\n- リスト\n";
+
+    let result = usecase.execute(markdown).await.unwrap();
+
+    assert_eq!(result.blog_post.title, "取り込み記事");
+    assert_eq!(result.blog_post.post_date.to_string(), "2024-03-04");
+    assert_eq!(result.warnings.len(), 1);
+    assert_eq!(result.warnings[0].line, 10);
+  }
+
+  #[tokio::test]
+  async fn does_not_save_invalid_markdown() {
+    let mut mock_repository = MockBlogPostRepo::new();
+    mock_repository.expect_save().times(0);
+
+    let usecase = create_usecase(mock_repository);
+
+    let result = usecase.execute("タイトルのない本文\n").await;
+
+    assert!(matches!(
+      result.unwrap_err().downcast_ref::<MarkdownPostParserError>(),
+      Some(MarkdownPostParserError::InvalidMarkdown(_))
+    ));
+  }
+}
@@ -0,0 +1,1234 @@
+use std::{
+  collections::{BTreeMap, HashMap},
+  iter::Peekable,
+  sync::Arc,
+};
+
+use chrono::NaiveDate;
+use pulldown_cmark::{CodeBlockKind, Event, HeadingLevel, OffsetIter, Options, Parser, Tag, TagEnd};
+use serde::Deserialize;
+use url::Url;
+use uuid::Uuid;
+
+use crate::domain::blog_domain::{
+  blog_post_entity::diagram_source_vo::DiagramKind,
+  blog_post_factory::{
+    CreateBlogPostInput, CreateCodeBlockOptionsInput, CreateContentInput, CreateFootnoteInput, CreateImageInput, CreateImagePresentationInput, CreateLinkInput,
+    CreateRichTextInput, CreateStyleInput,
+  },
+  code_language_registry::CodeLanguageRegistry,
+  embed_provider_registry::EmbedProviderRegistry,
+  errors::blog_domain_error::BlogDomainError,
+  jst_date_vo::JstDate,
+};
+use crate::domain::image_domain::{image_entity::ImageEntity, image_repository::ImageRepository, image_repository::ImageRepositoryError};
+
+/// 取り込めなかった、または形を変えて取り込んだ記法の報告
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarkdownImportWarning {
+  /// Markdown ファイル上の行番号（1 始まり）
+  pub line: usize,
+  pub message: String,
+}
+
+/// Markdown から組み立てた記事の入力と、取り込み時の警告
+#[derive(Debug)]
+pub struct ParsedMarkdownPost {
+  pub input: CreateBlogPostInput,
+  pub warnings: Vec<MarkdownImportWarning>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum MarkdownPostParserError {
+  InvalidMarkdown(BlogDomainError),
+  RepositoryError(String),
+}
+
+impl std::fmt::Display for MarkdownPostParserError {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    match self {
+      MarkdownPostParserError::InvalidMarkdown(error) => write!(f, "{}", error),
+      MarkdownPostParserError::RepositoryError(msg) => write!(f, "Image repository error: {}", msg),
+    }
+  }
+}
+
+impl std::error::Error for MarkdownPostParserError {}
+
+impl From<BlogDomainError> for MarkdownPostParserError {
+  fn from(error: BlogDomainError) -> Self {
+    MarkdownPostParserError::InvalidMarkdown(error)
+  }
+}
+
+/// Markdown ファイルを `BlogPostFactory` に渡す入力へ変換する
+///
+/// Markdown 書き出しの逆変換として、フロントマター・Zenn 形式のコードブロック（`lang:title`）・
+/// 折りたたみブロック（`:::details`）を解釈する。リストや表のように記事で表現できない記法は、
+/// 黙って捨てずに段落へ変換するか取り込みを見送り、その旨を警告として返す。
+pub struct MarkdownPostParser {
+  image_repository: Arc<dyn ImageRepository>,
+  image_base_url: String,
+}
+
+impl MarkdownPostParser {
+  /// 新しいパーサーを作成する
+  ///
+  /// # Arguments
+  /// * `image_repository` - 画像の参照を登録済みの画像と突き合わせるためのリポジトリ
+  /// * `image_base_url` - 書き出し時に画像のパスの前に付けた配信元の URL。画像の参照からこの部分を取り除いてパスとして検索する
+  pub fn new(image_repository: Arc<dyn ImageRepository>, image_base_url: String) -> Self {
+    Self {
+      image_repository,
+      image_base_url,
+    }
+  }
+
+  pub async fn parse(&self, markdown: &str) -> Result<ParsedMarkdownPost, MarkdownPostParserError> {
+    let document = DocumentConverter::convert(markdown)?;
+    let mut warnings = document.warnings;
+    let front_matter = parse_front_matter(document.front_matter.as_deref())?;
+    for key in front_matter.others.keys() {
+      // published は公開日から決まるため、書き出したファイルを取り込み直しても警告しない
+      if key != "published" {
+        warnings.push(warning(
+          document.front_matter_line,
+          format!("フロントマターの「{}」には対応していないため無視しました", key),
+        ));
+      }
+    }
+
+    let title = front_matter
+      .title
+      .clone()
+      .or(document.heading_title)
+      .filter(|title| !title.trim().is_empty())
+      .ok_or_else(|| invalid_markdown("タイトルがありません。フロントマターの title か # 見出しで指定してください"))?;
+
+    // 本文中の画像は登録済みの画像のみ取り込む
+    let mut images: HashMap<String, Option<ImageEntity>> = HashMap::new();
+    let mut sources = image_sources(&document.contents);
+    sources.sort();
+    sources.dedup();
+    for src in sources {
+      let image = self.find_image(&src).await?;
+      images.insert(src, image);
+    }
+    let mut contents = document.contents;
+    retain_registered_images(&mut contents, &images, &document.image_lines, &mut warnings);
+
+    let thumbnail = match &front_matter.thumbnail {
+      Some(src) => match self.find_image(src).await? {
+        Some(image) => Some(CreateImageInput {
+          id: image.get_id(),
+          path: image.get_path().to_string(),
+        }),
+        None => {
+          warnings.push(warning(
+            document.front_matter_line,
+            format!("サムネイル画像「{}」が登録されていないため設定しませんでした", src),
+          ));
+          None
+        }
+      },
+      None => None,
+    };
+
+    warnings.sort_by_key(|warning| warning.line);
+    Ok(ParsedMarkdownPost {
+      input: CreateBlogPostInput {
+        title,
+        thumbnail,
+        post_date: parse_date("post_date", front_matter.post_date.as_deref())?,
+        last_update_date: parse_date("last_update_date", front_matter.last_update_date.as_deref())?,
+        published_date: parse_date("published_date", front_matter.published_date.as_deref())?,
+        contents,
+        footnotes: document.footnotes,
+        seo_metadata: Default::default(),
+        manual_excerpt: Default::default(),
+      },
+      warnings,
+    })
+  }
+
+  /// 画像の参照から登録済みの画像を探す。登録されていない場合は `None` を返す
+  async fn find_image(&self, src: &str) -> Result<Option<ImageEntity>, MarkdownPostParserError> {
+    let base_url = format!("{}/", self.image_base_url.trim_end_matches('/'));
+    let path = if self.image_base_url.is_empty() {
+      src
+    } else {
+      src.strip_prefix(&base_url).unwrap_or(src)
+    };
+
+    match self.image_repository.find_by_path(path).await {
+      Ok(image) => Ok(Some(image)),
+      Err(ImageRepositoryError::FindByPathFailed(msg)) if msg.starts_with("ImageNotFoundByPath:") => Ok(None),
+      Err(error) => Err(MarkdownPostParserError::RepositoryError(format!("{:?}", error))),
+    }
+  }
+}
+
+/// フロントマターで指定できる項目
+#[derive(Debug, Default, Deserialize)]
+struct FrontMatter {
+  title: Option<String>,
+  post_date: Option<String>,
+  last_update_date: Option<String>,
+  published_date: Option<String>,
+  thumbnail: Option<String>,
+  #[serde(flatten)]
+  others: BTreeMap<String, serde_yaml::Value>,
+}
+
+fn parse_front_matter(source: Option<&str>) -> Result<FrontMatter, BlogDomainError> {
+  match source {
+    Some(source) if !source.trim().is_empty() => serde_yaml::from_str(source).map_err(|e| invalid_markdown(&format!("フロントマターを解釈できません: {}", e))),
+    _ => Ok(FrontMatter::default()),
+  }
+}
+
+fn parse_date(key: &str, value: Option<&str>) -> Result<Option<JstDate>, BlogDomainError> {
+  value
+    .map(|value| {
+      NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d")
+        .map(JstDate::from_jst_naive_date)
+        .map_err(|_| invalid_markdown(&format!("{} は YYYY-MM-DD 形式で指定してください: {}", key, value)))
+    })
+    .transpose()
+}
+
+fn invalid_markdown(detail: &str) -> BlogDomainError {
+  BlogDomainError::InvalidMarkdown { detail: detail.to_string() }
+}
+
+fn warning(line: usize, message: String) -> MarkdownImportWarning {
+  MarkdownImportWarning { line, message }
+}
+
+fn image_sources(contents: &[CreateContentInput]) -> Vec<String> {
+  contents
+    .iter()
+    .flat_map(|content| match content {
+      CreateContentInput::Image { path, .. } => vec![path.clone()],
+      CreateContentInput::Details { children, .. } => image_sources(children),
+      _ => vec![],
+    })
+    .collect()
+}
+
+/// 登録済みの画像は保存されているパスに置き換え、見つからない画像は警告を出して取り除く
+fn retain_registered_images(
+  contents: &mut Vec<CreateContentInput>,
+  images: &HashMap<String, Option<ImageEntity>>,
+  image_lines: &HashMap<Uuid, usize>,
+  warnings: &mut Vec<MarkdownImportWarning>,
+) {
+  contents.retain_mut(|content| match content {
+    CreateContentInput::Image { id, path, .. } => match images.get(path.as_str()) {
+      Some(Some(image)) => {
+        *path = image.get_path().to_string();
+        true
+      }
+      _ => {
+        let line = image_lines.get(id).copied().unwrap_or_default();
+        warnings.push(warning(line, format!("画像「{}」が登録されていないため取り込みませんでした", path)));
+        false
+      }
+    },
+    CreateContentInput::Details { id, children, .. } => {
+      retain_registered_images(children, images, image_lines, warnings);
+      if children.is_empty() {
+        let line = image_lines.get(id).copied().unwrap_or_default();
+        warnings.push(warning(line, "中身が空になった折りたたみブロックを取り込みませんでした".to_string()));
+      }
+      !children.is_empty()
+    }
+    _ => true,
+  });
+}
+
+/// Markdown の解析結果（画像の照合前）
+struct ConvertedDocument {
+  front_matter: Option<String>,
+  front_matter_line: usize,
+  heading_title: Option<String>,
+  contents: Vec<CreateContentInput>,
+  footnotes: Vec<CreateFootnoteInput>,
+  /// 画像ブロックと折りたたみブロックの ID ごとの行番号（画像の照合時の警告に使う）
+  image_lines: HashMap<Uuid, usize>,
+  warnings: Vec<MarkdownImportWarning>,
+}
+
+/// `convert_blocks` がブロックの並びを読み終えた理由
+#[derive(Debug, PartialEq)]
+enum BlockEnd {
+  /// リストなど、外側の要素の終わりに達した
+  Container,
+  /// 折りたたみブロックを閉じる `:::` に達した
+  DetailsClose,
+  /// 文書の終わりに達した
+  Eof,
+}
+
+/// 段落として読んだ内容の解釈
+enum ParagraphBlock {
+  Content(CreateContentInput),
+  DetailsOpen(String),
+  OtherContainerOpen(String),
+  ContainerClose,
+  Empty,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct InlineStyle {
+  bold: bool,
+  italic: bool,
+  strikethrough: bool,
+  inline_code: bool,
+  inline_math: bool,
+}
+
+#[derive(Debug)]
+enum InlineNode {
+  Text {
+    text: String,
+    style: InlineStyle,
+    link: Option<String>,
+    footnote_id: Option<Uuid>,
+  },
+  Image {
+    src: String,
+    alt: String,
+    link: Option<String>,
+  },
+  DisplayMath(String),
+}
+
+/// pulldown-cmark のイベント列をブロック単位で読み進め、記事のコンテンツに変換する
+struct DocumentConverter<'a> {
+  events: Peekable<OffsetIter<'a>>,
+  line_index: LineIndex,
+  warnings: Vec<MarkdownImportWarning>,
+  front_matter: Option<String>,
+  front_matter_line: usize,
+  heading_title: Option<String>,
+  footnote_ids: HashMap<String, Uuid>,
+  footnotes: Vec<CreateFootnoteInput>,
+  /// 脚注ごとの定義された行
+  footnote_lines: HashMap<Uuid, usize>,
+  /// 本文から参照された脚注と、最初に参照された行
+  referenced_footnotes: HashMap<Uuid, usize>,
+  image_lines: HashMap<Uuid, usize>,
+  /// リストや引用の中を読んでいる深さ（折りたたみブロックの記法は最上位でのみ解釈する）
+  container_depth: usize,
+  /// 読み飛ばしている未対応の `:::` ブロックの深さ
+  ignored_containers: usize,
+}
+
+impl<'a> DocumentConverter<'a> {
+  fn convert(markdown: &str) -> Result<ConvertedDocument, BlogDomainError> {
+    let (source, line_index) = isolate_container_markers(markdown);
+    let options = Options::ENABLE_STRIKETHROUGH
+      | Options::ENABLE_FOOTNOTES
+      | Options::ENABLE_MATH
+      | Options::ENABLE_TABLES
+      | Options::ENABLE_TASKLISTS
+      | Options::ENABLE_YAML_STYLE_METADATA_BLOCKS;
+    let mut converter = DocumentConverter {
+      events: Parser::new_ext(&source, options).into_offset_iter().peekable(),
+      line_index,
+      warnings: Vec::new(),
+      front_matter: None,
+      front_matter_line: 1,
+      heading_title: None,
+      footnote_ids: HashMap::new(),
+      footnotes: Vec::new(),
+      footnote_lines: HashMap::new(),
+      referenced_footnotes: HashMap::new(),
+      image_lines: HashMap::new(),
+      container_depth: 0,
+      ignored_containers: 0,
+    };
+
+    let (mut contents, _) = converter.convert_blocks(false);
+    converter.drop_unmatched_footnotes(&mut contents);
+
+    Ok(ConvertedDocument {
+      front_matter: converter.front_matter,
+      front_matter_line: converter.front_matter_line,
+      heading_title: converter.heading_title,
+      contents,
+      footnotes: converter.footnotes,
+      image_lines: converter.image_lines,
+      warnings: converter.warnings,
+    })
+  }
+
+  fn warn(&mut self, line: usize, message: impl Into<String>) {
+    self.warnings.push(warning(line, message.into()));
+  }
+
+  fn peek_line(&mut self) -> usize {
+    let offset = self.events.peek().map(|(_, range)| range.start).unwrap_or_default();
+    self.line_index.line_of(offset)
+  }
+
+  /// 外側の要素の終わりか文書の終わりまで、ブロックを読み進める
+  fn convert_blocks(&mut self, in_details: bool) -> (Vec<CreateContentInput>, BlockEnd) {
+    let mut contents = Vec::new();
+    loop {
+      let line = self.peek_line();
+      let Some((event, _)) = self.events.peek().cloned() else {
+        return (contents, BlockEnd::Eof);
+      };
+
+      match event {
+        Event::End(_) => {
+          self.events.next();
+          return (contents, BlockEnd::Container);
+        }
+        Event::Start(Tag::Paragraph) => {
+          self.events.next();
+          let nodes = self.convert_inlines(line);
+          self.events.next();
+          match self.convert_paragraph(nodes, line) {
+            ParagraphBlock::Content(content) => contents.push(content),
+            ParagraphBlock::DetailsOpen(summary) if !in_details && self.ignored_containers == 0 => {
+              let (children, end) = self.convert_blocks(true);
+              if end != BlockEnd::DetailsClose {
+                self.warn(line, "折りたたみブロックが閉じられていないため、文書の終わりまでを中身として取り込みました");
+              }
+              if let Some(details) = self.build_details(summary, children, line) {
+                contents.push(details);
+              }
+              if end == BlockEnd::Eof {
+                return (contents, end);
+              }
+            }
+            ParagraphBlock::DetailsOpen(_) => {
+              self.warn(line, "折りたたみブロックは入れ子にできないため、内側のブロックは中身だけを取り込みました");
+              self.ignored_containers += 1;
+            }
+            ParagraphBlock::OtherContainerOpen(name) => {
+              self.warn(line, format!(":::{} ブロックには対応していないため、中身だけを取り込みました", name));
+              self.ignored_containers += 1;
+            }
+            ParagraphBlock::ContainerClose if self.ignored_containers > 0 => self.ignored_containers -= 1,
+            ParagraphBlock::ContainerClose if in_details => return (contents, BlockEnd::DetailsClose),
+            ParagraphBlock::ContainerClose => self.warn(line, "対応する開始がない ::: を無視しました"),
+            ParagraphBlock::Empty => {}
+          }
+        }
+        Event::Start(Tag::Heading { level, .. }) => {
+          self.events.next();
+          let nodes = self.convert_inlines(line);
+          self.events.next();
+          let text = plain_text(&nodes).trim().to_string();
+          match level {
+            HeadingLevel::H1 if self.heading_title.is_none() => self.heading_title = Some(text),
+            HeadingLevel::H1 => {
+              self.warn(line, "2 つ目以降の # 見出しは ## 見出しとして取り込みました");
+              contents.push(CreateContentInput::H2 { id: Uuid::new_v4(), text });
+            }
+            HeadingLevel::H2 => contents.push(CreateContentInput::H2 { id: Uuid::new_v4(), text }),
+            HeadingLevel::H3 => contents.push(CreateContentInput::H3 { id: Uuid::new_v4(), text }),
+            _ => {
+              self.warn(line, "#### 以下の見出しは ### 見出しとして取り込みました");
+              contents.push(CreateContentInput::H3 { id: Uuid::new_v4(), text });
+            }
+          }
+        }
+        Event::Start(Tag::CodeBlock(kind)) => {
+          let info = match kind {
+            CodeBlockKind::Fenced(info) => info.to_string(),
+            CodeBlockKind::Indented => String::new(),
+          };
+          self.events.next();
+          let code = self.collect_text_until_end();
+          contents.push(self.convert_code_block(&info, code, line));
+        }
+        Event::Start(Tag::MetadataBlock(_)) => {
+          self.events.next();
+          self.front_matter = Some(self.collect_text_until_end());
+          self.front_matter_line = line;
+        }
+        Event::Start(Tag::FootnoteDefinition(label)) => {
+          let label = label.to_string();
+          self.events.next();
+          self.container_depth += 1;
+          let (blocks, _) = self.convert_blocks(false);
+          self.container_depth -= 1;
+          self.add_footnote(label, blocks, line);
+        }
+        Event::Start(Tag::Table(_)) => {
+          self.events.next();
+          self.warn(line, "表には対応していないため、行ごとの段落として取り込みました");
+          contents.extend(self.convert_table_rows(line));
+        }
+        Event::Start(Tag::HtmlBlock) => {
+          self.events.next();
+          self.collect_text_until_end();
+          self.warn(line, "HTML は取り込めないため無視しました");
+        }
+        Event::Rule => {
+          self.events.next();
+          self.warn(line, "水平線には対応していないため無視しました");
+        }
+        Event::Start(tag) if !is_inline_tag(&tag) => {
+          match tag {
+            Tag::List(_) => self.warn(line, "リストには対応していないため、項目ごとの段落として取り込みました"),
+            Tag::BlockQuote(_) => self.warn(line, "引用には対応していないため、中身を段落として取り込みました"),
+            Tag::DefinitionList => self.warn(line, "定義リストには対応していないため、中身を段落として取り込みました"),
+            _ => {}
+          }
+          self.events.next();
+          self.container_depth += 1;
+          let (children, end) = self.convert_blocks(in_details);
+          self.container_depth -= 1;
+          contents.extend(children);
+          if end == BlockEnd::Eof {
+            return (contents, end);
+          }
+        }
+        // 項目の間に空行がないリストでは、段落に包まれずにインライン要素が並ぶ
+        _ => {
+          let nodes = self.convert_inlines(line);
+          if nodes.is_empty() {
+            self.events.next();
+          } else if let ParagraphBlock::Content(content) = self.convert_paragraph(nodes, line) {
+            contents.push(content);
+          }
+        }
+      }
+    }
+  }
+
+  /// インライン要素を読み進める。インライン以外のイベントに達したら、それを読まずに返す
+  fn convert_inlines(&mut self, line: usize) -> Vec<InlineNode> {
+    let mut nodes = Vec::new();
+    let mut style = InlineStyle::default();
+    let mut links: Vec<String> = Vec::new();
+
+    while let Some((event, _)) = self.events.peek() {
+      if !is_inline_event(event) {
+        break;
+      }
+      let (event, _) = self.events.next().unwrap();
+      match event {
+        Event::Text(text) => push_text(&mut nodes, &text, &style, links.last()),
+        Event::Code(code) => push_text(
+          &mut nodes,
+          &code,
+          &InlineStyle {
+            inline_code: true,
+            ..style.clone()
+          },
+          links.last(),
+        ),
+        Event::InlineMath(math) => push_text(
+          &mut nodes,
+          &math,
+          &InlineStyle {
+            inline_math: true,
+            ..style.clone()
+          },
+          links.last(),
+        ),
+        Event::DisplayMath(math) => nodes.push(InlineNode::DisplayMath(math.to_string())),
+        Event::SoftBreak | Event::HardBreak => push_text(&mut nodes, "\n", &style, links.last()),
+        Event::TaskListMarker(checked) => push_text(&mut nodes, if checked { "[x] " } else { "[ ] " }, &style, links.last()),
+        Event::FootnoteReference(label) => {
+          let footnote_id = self.footnote_id(&label);
+          self.referenced_footnotes.entry(footnote_id).or_insert(line);
+          match nodes.last_mut() {
+            Some(InlineNode::Text { footnote_id: slot @ None, .. }) => *slot = Some(footnote_id),
+            _ => nodes.push(InlineNode::Text {
+              text: String::new(),
+              style: InlineStyle::default(),
+              link: None,
+              footnote_id: Some(footnote_id),
+            }),
+          }
+        }
+        Event::InlineHtml(_) => self.warn(line, "インラインの HTML は取り込めないため無視しました"),
+        Event::Start(Tag::Strong) => style.bold = true,
+        Event::End(TagEnd::Strong) => style.bold = false,
+        Event::Start(Tag::Emphasis) => style.italic = true,
+        Event::End(TagEnd::Emphasis) => style.italic = false,
+        Event::Start(Tag::Strikethrough) => style.strikethrough = true,
+        Event::End(TagEnd::Strikethrough) => style.strikethrough = false,
+        Event::Start(Tag::Link { dest_url, .. }) => links.push(dest_url.to_string()),
+        Event::End(TagEnd::Link) => {
+          links.pop();
+        }
+        Event::Start(Tag::Image { dest_url, .. }) => {
+          let alt = self.collect_text_until_end();
+          nodes.push(InlineNode::Image {
+            src: dest_url.to_string(),
+            alt,
+            link: links.last().cloned(),
+          });
+        }
+        Event::Start(Tag::Superscript | Tag::Subscript) => self.warn(line, "上付き・下付き文字には対応していないため、通常の文字として取り込みました"),
+        _ => {}
+      }
+    }
+    nodes
+  }
+
+  /// 終了タグまでのテキストを連結して返す（終了タグも読み進める）
+  fn collect_text_until_end(&mut self) -> String {
+    let mut text = String::new();
+    let mut depth = 0;
+    for (event, _) in self.events.by_ref() {
+      match event {
+        Event::Start(_) => depth += 1,
+        Event::End(_) if depth == 0 => break,
+        Event::End(_) => depth -= 1,
+        Event::Text(value) | Event::Code(value) | Event::Html(value) | Event::InlineMath(value) | Event::DisplayMath(value) => text.push_str(&value),
+        Event::SoftBreak | Event::HardBreak => text.push('\n'),
+        _ => {}
+      }
+    }
+    text
+  }
+
+  fn convert_paragraph(&mut self, nodes: Vec<InlineNode>, line: usize) -> ParagraphBlock {
+    let text = plain_text(&nodes);
+    let trimmed = text.trim();
+
+    // `:::details 見出し` と `:::`
+    if nodes.iter().all(|node| matches!(node, InlineNode::Text { .. })) && trimmed.starts_with(":::") && !trimmed.contains('\n') && self.container_depth == 0 {
+      let marker = trimmed.trim_start_matches(':').trim();
+      if marker.is_empty() {
+        return ParagraphBlock::ContainerClose;
+      }
+      let (name, argument) = marker.split_once(char::is_whitespace).unwrap_or((marker, ""));
+      if name == "details" {
+        return ParagraphBlock::DetailsOpen(argument.trim().to_string());
+      }
+      return ParagraphBlock::OtherContainerOpen(name.to_string());
+    }
+
+    let meaningful: Vec<&InlineNode> =
+      nodes.iter().filter(|node| !matches!(node, InlineNode::Text { text, footnote_id: None, .. } if text.trim().is_empty())).collect();
+
+    match meaningful.as_slice() {
+      [] => return ParagraphBlock::Empty,
+      [InlineNode::DisplayMath(source)] => {
+        return ParagraphBlock::Content(CreateContentInput::Math {
+          id: Uuid::new_v4(),
+          source: source.trim().to_string(),
+        })
+      }
+      // URL だけの段落は埋め込みかリンクカードにする
+      [InlineNode::Text {
+        text,
+        style,
+        link,
+        footnote_id: None,
+      }] if *style == InlineStyle::default() && link.as_deref().is_none_or(|link| link == text.trim()) && is_bare_url(text.trim()) => {
+        let url = text.trim().to_string();
+        let id = Uuid::new_v4();
+        return ParagraphBlock::Content(if EmbedProviderRegistry::default().resolve(&url).is_ok() {
+          CreateContentInput::Embed { id, url }
+        } else {
+          CreateContentInput::LinkCard { id, url }
+        });
+      }
+      // 画像と、その直後の行に強調で書いたキャプション
+      [InlineNode::Image { src, alt, link }, caption @ ..]
+        if caption.iter().all(|node| matches!(node, InlineNode::Text { style, footnote_id: None, .. } if style.italic)) =>
+      {
+        let caption = caption.iter().map(|node| node_text(node)).collect::<String>().trim().to_string();
+        let id = Uuid::new_v4();
+        self.image_lines.insert(id, line);
+        return ParagraphBlock::Content(CreateContentInput::Image {
+          id,
+          path: src.clone(),
+          presentation: CreateImagePresentationInput {
+            alt: alt.clone(),
+            caption: Some(caption).filter(|caption| !caption.is_empty()),
+            width: None,
+            link_url: link.clone(),
+          },
+        });
+      }
+      _ => {}
+    }
+
+    let text = self.convert_rich_text(nodes, line);
+    ParagraphBlock::Content(CreateContentInput::Paragraph { id: Uuid::new_v4(), text })
+  }
+
+  fn convert_rich_text(&mut self, nodes: Vec<InlineNode>, line: usize) -> Vec<CreateRichTextInput> {
+    let mut parts = Vec::new();
+    for node in nodes {
+      match node {
+        InlineNode::Text {
+          text,
+          style,
+          link,
+          footnote_id,
+        } => parts.push(CreateRichTextInput {
+          text,
+          styles: CreateStyleInput {
+            bold: style.bold,
+            inline_code: style.inline_code,
+            inline_math: style.inline_math,
+            italic: style.italic,
+            strikethrough: style.strikethrough,
+            underline: false,
+            highlight: None,
+          },
+          link: link.map(|url| convert_link(&url)),
+          ruby: None,
+          footnote_id,
+        }),
+        InlineNode::Image { alt, .. } => {
+          self.warn(line, "文中の画像は取り込めないため、代替テキストに置き換えました");
+          parts.push(plain_part(alt));
+        }
+        InlineNode::DisplayMath(source) => parts.push(CreateRichTextInput {
+          styles: CreateStyleInput {
+            inline_math: true,
+            ..plain_part(String::new()).styles
+          },
+          ..plain_part(source)
+        }),
+      }
+    }
+    parts
+  }
+
+  fn convert_code_block(&mut self, info: &str, code: String, line: usize) -> CreateContentInput {
+    // フェンスの直後に付く改行を取り除く
+    let code = code.strip_suffix('\n').unwrap_or(&code).to_string();
+    let info = info.split_whitespace().next().unwrap_or_default();
+    let (language, title) = info.split_once(':').unwrap_or((info, ""));
+    let language = language.to_ascii_lowercase();
+
+    if language == DiagramKind::Mermaid.as_str() {
+      return CreateContentInput::Diagram {
+        id: Uuid::new_v4(),
+        kind: DiagramKind::Mermaid,
+        source: code,
+      };
+    }
+
+    let language = if language.is_empty() {
+      "text".to_string()
+    } else if CodeLanguageRegistry::default().validate(&language).is_err() {
+      self.warn(
+        line,
+        format!("コードブロックの言語「{}」には対応していないため、text として取り込みました", language),
+      );
+      "text".to_string()
+    } else {
+      language
+    };
+    CreateContentInput::CodeBlock {
+      id: Uuid::new_v4(),
+      title: title.to_string(),
+      code,
+      language,
+      options: CreateCodeBlockOptionsInput::default(),
+    }
+  }
+
+  fn convert_table_rows(&mut self, line: usize) -> Vec<CreateContentInput> {
+    let mut rows = Vec::new();
+    let mut row: Vec<InlineNode> = Vec::new();
+    while let Some((event, _)) = self.events.next() {
+      match event {
+        Event::Start(Tag::TableCell) => {
+          if !row.is_empty() {
+            push_text(&mut row, " | ", &InlineStyle::default(), None);
+          }
+          row.extend(self.convert_inlines(line));
+        }
+        Event::End(TagEnd::TableHead | TagEnd::TableRow) => {
+          let text = self.convert_rich_text(std::mem::take(&mut row), line);
+          rows.push(CreateContentInput::Paragraph { id: Uuid::new_v4(), text });
+        }
+        Event::End(TagEnd::Table) => break,
+        _ => {}
+      }
+    }
+    rows
+  }
+
+  /// 折りたたみブロックを組み立てる。記事で折りたたみブロックに置けないものは形を変えるか取り除く
+  fn build_details(&mut self, summary: String, children: Vec<CreateContentInput>, line: usize) -> Option<CreateContentInput> {
+    let children: Vec<CreateContentInput> = children
+      .into_iter()
+      .map(|child| match child {
+        CreateContentInput::H2 { id, text } | CreateContentInput::H3 { id, text } => {
+          self.warn(line, "折りたたみブロックの中の見出しは段落として取り込みました");
+          CreateContentInput::Paragraph {
+            id,
+            text: vec![plain_part(text)],
+          }
+        }
+        other => other,
+      })
+      .collect();
+    if children.is_empty() {
+      self.warn(line, "中身が空の折りたたみブロックを取り込みませんでした");
+      return None;
+    }
+    let summary = if summary.is_empty() {
+      self.warn(line, "折りたたみブロックの見出しがないため「詳細」としました");
+      "詳細".to_string()
+    } else {
+      summary
+    };
+
+    let id = Uuid::new_v4();
+    self.image_lines.insert(id, line);
+    Some(CreateContentInput::Details { id, summary, children })
+  }
+
+  fn footnote_id(&mut self, label: &str) -> Uuid {
+    *self.footnote_ids.entry(label.to_string()).or_insert_with(Uuid::new_v4)
+  }
+
+  fn add_footnote(&mut self, label: String, blocks: Vec<CreateContentInput>, line: usize) {
+    let id = self.footnote_id(&label);
+    if self.footnotes.iter().any(|footnote| footnote.id == id) {
+      self.warn(line, format!("脚注「{}」が重複して定義されているため、2 つ目以降を無視しました", label));
+      return;
+    }
+
+    let mut text = Vec::new();
+    for block in blocks {
+      match block {
+        CreateContentInput::Paragraph { text: paragraph, .. } => {
+          if !text.is_empty() {
+            text.push(plain_part("\n".to_string()));
+          }
+          text.extend(paragraph);
+        }
+        _ => self.warn(line, format!("脚注「{}」には段落以外を含められないため、段落以外を無視しました", label)),
+      }
+    }
+    self.footnote_lines.insert(id, line);
+    self.footnotes.push(CreateFootnoteInput { id, text });
+  }
+
+  /// 参照されていない脚注の定義と、定義のない脚注の参照を取り除く
+  fn drop_unmatched_footnotes(&mut self, contents: &mut [CreateContentInput]) {
+    let defined: Vec<Uuid> = self.footnotes.iter().map(|footnote| footnote.id).collect();
+    let labels: HashMap<Uuid, String> = self.footnote_ids.iter().map(|(label, id)| (*id, label.clone())).collect();
+
+    let referenced = self.referenced_footnotes.clone();
+    let mut unreferenced = Vec::new();
+    self.footnotes.retain(|footnote| {
+      let keep = referenced.contains_key(&footnote.id);
+      if !keep {
+        unreferenced.push(footnote.id);
+      }
+      keep
+    });
+    for id in unreferenced {
+      let line = self.footnote_lines.get(&id).copied().unwrap_or(1);
+      self.warn(line, format!("脚注「{}」は本文から参照されていないため取り込みませんでした", labels[&id]));
+    }
+
+    let mut undefined: Vec<(usize, Uuid)> = referenced.iter().filter(|(id, _)| !defined.contains(id)).map(|(id, line)| (*line, *id)).collect();
+    undefined.sort();
+    for (line, id) in undefined {
+      self.warn(line, format!("脚注「{}」が定義されていないため、参照を取り除きました", labels[&id]));
+      remove_footnote_ref(contents, id);
+    }
+  }
+}
+
+fn remove_footnote_ref(contents: &mut [CreateContentInput], footnote_id: Uuid) {
+  for content in contents {
+    match content {
+      CreateContentInput::Paragraph { text, .. } => {
+        for part in text.iter_mut().filter(|part| part.footnote_id == Some(footnote_id)) {
+          part.footnote_id = None;
+        }
+      }
+      CreateContentInput::Details { children, .. } => remove_footnote_ref(children, footnote_id),
+      _ => {}
+    }
+  }
+}
+
+fn is_inline_tag(tag: &Tag) -> bool {
+  matches!(
+    tag,
+    Tag::Emphasis | Tag::Strong | Tag::Strikethrough | Tag::Superscript | Tag::Subscript | Tag::Link { .. } | Tag::Image { .. }
+  )
+}
+
+fn is_inline_event(event: &Event) -> bool {
+  match event {
+    Event::Start(tag) => is_inline_tag(tag),
+    Event::End(tag) => matches!(
+      tag,
+      TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough | TagEnd::Superscript | TagEnd::Subscript | TagEnd::Link | TagEnd::Image
+    ),
+    Event::Text(_)
+    | Event::Code(_)
+    | Event::InlineMath(_)
+    | Event::DisplayMath(_)
+    | Event::InlineHtml(_)
+    | Event::FootnoteReference(_)
+    | Event::SoftBreak
+    | Event::HardBreak
+    | Event::TaskListMarker(_) => true,
+    _ => false,
+  }
+}
+
+/// 直前のテキストと装飾・リンクが同じであれば連結する
+fn push_text(nodes: &mut Vec<InlineNode>, text: &str, style: &InlineStyle, link: Option<&String>) {
+  if let Some(InlineNode::Text {
+    text: previous,
+    style: previous_style,
+    link: previous_link,
+    footnote_id: None,
+  }) = nodes.last_mut()
+  {
+    if previous_style == style && previous_link.as_ref() == link {
+      previous.push_str(text);
+      return;
+    }
+  }
+  nodes.push(InlineNode::Text {
+    text: text.to_string(),
+    style: style.clone(),
+    link: link.cloned(),
+    footnote_id: None,
+  });
+}
+
+fn node_text(node: &InlineNode) -> &str {
+  match node {
+    InlineNode::Text { text, .. } => text,
+    InlineNode::Image { alt, .. } => alt,
+    InlineNode::DisplayMath(source) => source,
+  }
+}
+
+fn plain_text(nodes: &[InlineNode]) -> String {
+  nodes.iter().map(node_text).collect()
+}
+
+fn plain_part(text: String) -> CreateRichTextInput {
+  CreateRichTextInput {
+    text,
+    styles: CreateStyleInput {
+      bold: false,
+      inline_code: false,
+      inline_math: false,
+      italic: false,
+      strikethrough: false,
+      underline: false,
+      highlight: None,
+    },
+    link: None,
+    ruby: None,
+    footnote_id: None,
+  }
+}
+
+fn is_bare_url(text: &str) -> bool {
+  !text.contains(char::is_whitespace) && Url::parse(text).is_ok_and(|url| url.scheme() == "http" || url.scheme() == "https")
+}
+
+/// `/posts/{記事ID}` と `/posts/{記事ID}#{見出しID}` 形式のパスは内部リンクとして取り込む
+///
+/// 見出しのアンカー（`/posts/{記事ID}#{スラッグ}`）からは見出し ID を復元できないため、記事へのリンクとして取り込む
+fn convert_link(url: &str) -> CreateLinkInput {
+  let internal = url.strip_prefix("/posts/").and_then(|rest| {
+    let (post_id, fragment) = rest.split_once('#').map_or((rest, None), |(post_id, fragment)| (post_id, Some(fragment)));
+    let post_id = Uuid::parse_str(post_id).ok()?;
+    Some((post_id, fragment.and_then(|fragment| Uuid::parse_str(fragment).ok())))
+  });
+
+  CreateLinkInput {
+    url: url.to_string(),
+    post_id: internal.map(|(post_id, _)| post_id),
+    heading_id: internal.and_then(|(_, heading_id)| heading_id),
+  }
+}
+
+/// 前処理した Markdown のバイト位置から、元のファイルの行番号を求める
+struct LineIndex {
+  line_starts: Vec<usize>,
+  original_lines: Vec<usize>,
+}
+
+impl LineIndex {
+  fn line_of(&self, offset: usize) -> usize {
+    let index = self.line_starts.partition_point(|&start| start <= offset).saturating_sub(1);
+    self.original_lines.get(index).copied().unwrap_or(1)
+  }
+}
+
+/// `:::` で始まる行（コードブロックの外側）の前後に空行を入れ、独立した段落として読めるようにする
+///
+/// Zenn の書き方では `:::details` の直後に本文の行が続くため、そのままでは本文と 1 つの段落にまとまってしまう。
+fn isolate_container_markers(markdown: &str) -> (String, LineIndex) {
+  let mut source = String::with_capacity(markdown.len());
+  let mut line_starts = Vec::new();
+  let mut original_lines = Vec::new();
+  let mut push_line = |source: &mut String, line: &str, original_line: usize| {
+    line_starts.push(source.len());
+    original_lines.push(original_line);
+    source.push_str(line);
+    source.push('\n');
+  };
+
+  let mut fence: Option<(char, usize)> = None;
+  for (index, line) in markdown.lines().enumerate() {
+    let original_line = index + 1;
+    let trimmed = line.trim_start();
+    let fence_char = trimmed.chars().next().filter(|c| *c == '`' || *c == '~');
+    let fence_length = fence_char.map(|c| trimmed.chars().take_while(|x| *x == c).count()).unwrap_or_default();
+
+    match fence {
+      Some((c, length)) if fence_char == Some(c) && fence_length >= length && trimmed[fence_length..].trim().is_empty() => fence = None,
+      Some(_) => {}
+      None if fence_length >= 3 => fence = fence_char.map(|c| (c, fence_length)),
+      None if trimmed.starts_with(":::") => {
+        push_line(&mut source, "", original_line);
+        push_line(&mut source, line, original_line);
+        push_line(&mut source, "", original_line);
+        continue;
+      }
+      None => {}
+    }
+    push_line(&mut source, line, original_line);
+  }
+
+  (source, LineIndex { line_starts, original_lines })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use async_trait::async_trait;
+
+  struct MockImageRepository {
+    images: Vec<ImageEntity>,
+  }
+
+  #[async_trait]
+  impl ImageRepository for MockImageRepository {
+    async fn find(&self, id: &str) -> Result<ImageEntity, ImageRepositoryError> {
+      Err(ImageRepositoryError::FindFailed(id.to_string()))
+    }
+
+    async fn find_by_path(&self, path: &str) -> Result<ImageEntity, ImageRepositoryError> {
+      self
+        .images
+        .iter()
+        .find(|image| image.get_path() == path)
+        .map(|image| ImageEntity::new(image.get_id(), image.get_path().to_string()))
+        .ok_or_else(|| ImageRepositoryError::FindByPathFailed(format!("ImageNotFoundByPath: {}", path)))
+    }
+
+    async fn save(&self, image: ImageEntity) -> Result<ImageEntity, ImageRepositoryError> {
+      Ok(image)
+    }
+
+    async fn find_all(&self) -> Result<Vec<ImageEntity>, ImageRepositoryError> {
+      Ok(vec![])
+    }
+  }
+
+  fn parser() -> MarkdownPostParser {
+    let repository = MockImageRepository {
+      images: vec![
+        ImageEntity::new(Uuid::from_u128(1), "posts/sample.png".to_string()),
+        ImageEntity::new(Uuid::from_u128(2), "thumbnails/rust.png".to_string()),
+      ],
+    };
+    MarkdownPostParser::new(Arc::new(repository), "https://images.example.com/blog".to_string())
+  }
+
+  fn texts(parts: &[CreateRichTextInput]) -> Vec<&str> {
+    parts.iter().map(|part| part.text.as_str()).collect()
+  }
+
+  #[tokio::test]
+  async fn parses_exported_markdown() {
+    let markdown = include_str!("../../../tests/golden/markdown_serializer/article.md");
+
+    let parsed = parser().parse(markdown).await.unwrap();
+    let input = parsed.input;
+
+    assert_eq!(parsed.warnings, vec![]);
+    assert_eq!(input.title, "Rust で \"Markdown\" を書き出す");
+    assert_eq!(input.post_date, Some(JstDate::new(2024, 1, 2).unwrap()));
+    assert_eq!(input.last_update_date, Some(JstDate::new(2024, 2, 3).unwrap()));
+    assert_eq!(input.thumbnail.map(|thumbnail| thumbnail.id), Some(Uuid::from_u128(2)));
+    assert_eq!(input.contents.len(), 8);
+
+    assert!(matches!(&input.contents[0], CreateContentInput::H2 { text, .. } if text == "はじめに"));
+    let CreateContentInput::Paragraph { text, .. } = &input.contents[1] else {
+      panic!("段落として取り込まれていません: {:?}", input.contents[1]);
+    };
+    assert_eq!(
+      texts(text),
+      vec!["本文は ", "太字", " と ", "cargo build", " を含む。", "公式サイト", "も参照。"]
+    );
+    assert!(text[1].styles.bold);
+    assert!(text[3].styles.inline_code);
+    assert_eq!(text[5].link.as_ref().map(|link| link.url.as_str()), Some("https://www.rust-lang.org/"));
+    assert_eq!(text[6].footnote_id, Some(input.footnotes[0].id));
+    assert_eq!(texts(&input.footnotes[0].text), vec!["2024 年 1 月時点の情報です。"]);
+
+    assert!(matches!(&input.contents[2], CreateContentInput::H3 { text, .. } if text == "サンプル"));
+    assert!(matches!(
+      &input.contents[3],
+      CreateContentInput::CodeBlock { title, code, language, .. } if title == "main.rs" && language == "rust" && code == "fn main() {\n  println!(\"hello\");\n}"
+    ));
+    assert!(matches!(
+      &input.contents[4],
+      CreateContentInput::Image { path, presentation, .. } if path == "posts/sample.png" && presentation.alt == "構成図" && presentation.caption.as_deref() == Some("システム構成")
+    ));
+    let CreateContentInput::Details { summary, children, .. } = &input.contents[5] else {
+      panic!("折りたたみブロックとして取り込まれていません: {:?}", input.contents[5]);
+    };
+    assert_eq!(summary, "実行ログ");
+    assert!(matches!(&children[..], [CreateContentInput::Paragraph { text, .. }] if texts(text) == vec!["折りたたまれた本文"]));
+    assert!(matches!(&input.contents[6], CreateContentInput::Math { source, .. } if source == "E = mc^2"));
+    assert!(matches!(&input.contents[7], CreateContentInput::Diagram { source, .. } if source == "graph TD\n  A-->B"));
+  }
+
+  #[tokio::test]
+  async fn uses_first_heading_as_title_without_front_matter() {
+    let parsed = parser().parse("# 見出しのタイトル\n\n本文\n").await.unwrap();
+
+    assert_eq!(parsed.input.title, "見出しのタイトル");
+    assert_eq!(parsed.input.post_date, None);
+    assert_eq!(parsed.input.contents.len(), 1);
+  }
+
+  #[tokio::test]
+  async fn rejects_markdown_without_title_or_with_invalid_date() {
+    let without_title = parser().parse("本文だけ\n").await;
+    assert!(matches!(
+      without_title,
+      Err(MarkdownPostParserError::InvalidMarkdown(BlogDomainError::InvalidMarkdown { .. }))
+    ));
+
+    let invalid_date = parser().parse("---\ntitle: 記事\npost_date: 2024/01/02\n---\n\n本文\n").await;
+    assert!(matches!(
+      invalid_date,
+      Err(MarkdownPostParserError::InvalidMarkdown(BlogDomainError::InvalidMarkdown { .. }))
+    ));
+  }
+
+  #[tokio::test]
+  async fn converts_bare_urls_to_embeds_and_link_cards() {
+    let markdown = "# URL\n\nhttps://www.youtube.com/watch?v=dQw4w9WgXcQ\n\nhttps://example.com/article\n\n<https://example.com/autolink>\n";
+
+    let contents = parser().parse(markdown).await.unwrap().input.contents;
+
+    assert!(matches!(&contents[0], CreateContentInput::Embed { url, .. } if url == "https://www.youtube.com/watch?v=dQw4w9WgXcQ"));
+    assert!(matches!(&contents[1], CreateContentInput::LinkCard { url, .. } if url == "https://example.com/article"));
+    assert!(matches!(&contents[2], CreateContentInput::LinkCard { url, .. } if url == "https://example.com/autolink"));
+  }
+
+  #[tokio::test]
+  async fn reads_internal_links() {
+    let post_id = Uuid::from_u128(10);
+    let heading_id = Uuid::from_u128(11);
+    let markdown = format!("# 内部リンク\n\n[別の記事](/posts/{}#{})を参照\n", post_id, heading_id);
+
+    let contents = parser().parse(&markdown).await.unwrap().input.contents;
+
+    let CreateContentInput::Paragraph { text, .. } = &contents[0] else {
+      panic!("段落として取り込まれていません");
+    };
+    let link = text[0].link.as_ref().unwrap();
+    assert_eq!(link.post_id, Some(post_id));
+    assert_eq!(link.heading_id, Some(heading_id));
+  }
+
+  #[tokio::test]
+  async fn reads_links_to_heading_anchors_as_links_to_post() {
+    let post_id = Uuid::from_u128(10);
+    let markdown = format!("# 内部リンク\n\n[別の記事](/posts/{}#概要-0000000b)を参照\n", post_id);
+
+    let contents = parser().parse(&markdown).await.unwrap().input.contents;
+
+    let CreateContentInput::Paragraph { text, .. } = &contents[0] else {
+      panic!("段落として取り込まれていません");
+    };
+    let link = text[0].link.as_ref().unwrap();
+    assert_eq!(link.post_id, Some(post_id));
+    assert_eq!(link.heading_id, None);
+  }
+
+  #[tokio::test]
+  async fn reports_unsupported_constructs_as_warnings() {
+    let markdown = "---
+title: 警告
+emoji: 🦀
+---
+
+- 項目1
+- 項目2
+
+> 引用
+
+<div>HTML</div>
+
+---
+
+#### 小見出し
+
+```brainfuck
++++
+```
+
+![未登録](https://images.example.com/blog/posts/missing.png)
+
+:::message
+メッセージ
+:::
+";
+
+    let parsed = parser().parse(markdown).await.unwrap();
+
+    let lines: Vec<usize> = parsed.warnings.iter().map(|warning| warning.line).collect();
+    assert_eq!(lines, vec![1, 6, 9, 11, 13, 15, 17, 21, 23]);
+    assert!(parsed.warnings[0].message.contains("emoji"));
+    assert!(parsed.warnings[7].message.contains("posts/missing.png"));
+
+    // 未対応の記法も、中身は段落として残す
+    let paragraphs: Vec<String> = parsed
+      .input
+      .contents
+      .iter()
+      .filter_map(|content| match content {
+        CreateContentInput::Paragraph { text, .. } => Some(texts(text).concat()),
+        _ => None,
+      })
+      .collect();
+    assert_eq!(paragraphs, vec!["項目1", "項目2", "引用", "メッセージ"]);
+    assert!(parsed.input.contents.iter().any(|content| matches!(content, CreateContentInput::CodeBlock { language, .. } if language == "text")));
+  }
+
+  #[tokio::test]
+  async fn keeps_container_markers_inside_code_blocks() {
+    let markdown = "# コード\n\n```markdown\n:::details 例\n本文\n:::\n```\n";
+
+    let parsed = parser().parse(markdown).await.unwrap();
+
+    assert_eq!(parsed.warnings, vec![]);
+    assert!(matches!(
+      &parsed.input.contents[..],
+      [CreateContentInput::CodeBlock { code, .. }] if code == ":::details 例\n本文\n:::"
+    ));
+  }
+}
@@ -0,0 +1,445 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::dto::{WordPressImportItemReportDTO, WordPressImportStatusDTO};
+use crate::application::usecase::create_blog_post::{
+  dto::{CreateBlogPostDTO, CreateContentDTO, CreateImageDTO},
+  CreateBlogPostUseCase,
+};
+use crate::application::usecase::register_image::{dto::RegisterImageDTO, RegisterImageUseCase};
+use crate::domain::image_domain::image_repository::{ImageRepository, ImageRepositoryError};
+use html_content_converter::HtmlContentConverter;
+use wxr_parser::{parse_wxr, WxrItem};
+
+pub mod html_content_converter;
+pub mod wxr_parser;
+
+/// 取り込み対象の WordPress の投稿ステータス（公開済み・予約投稿）
+const IMPORTED_STATUSES: [&str; 2] = ["publish", "future"];
+
+#[derive(Debug, PartialEq)]
+pub enum WordPressImportError {
+  InvalidWxr(String),
+}
+
+impl std::fmt::Display for WordPressImportError {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    match self {
+      WordPressImportError::InvalidWxr(msg) => write!(f, "WordPress のエクスポートファイルとして読み込めません: {}", msg),
+    }
+  }
+}
+
+impl std::error::Error for WordPressImportError {}
+
+/// WordPress のエクスポートファイル（WXR）から記事を取り込むユースケース
+///
+/// 公開済み・予約投稿の記事を 1 件ずつ `CreateBlogPostUseCase` で作成し、記事ごとに何が失われたかを報告する。
+/// 本文とアイキャッチ画像が参照する画像は、未登録であれば `RegisterImageUseCase` で登録する（画像ファイル自体は別途アップロードする）。
+/// 1 件の失敗で全体を止めないよう、記事の作成に失敗した場合はその記事を失敗として報告して次の記事に進む。
+pub struct ImportWordPressPostsUseCase {
+  create_blog_post_usecase: CreateBlogPostUseCase,
+  register_image_usecase: RegisterImageUseCase,
+  image_repository: Arc<dyn ImageRepository>,
+  html_converter: HtmlContentConverter,
+}
+
+impl ImportWordPressPostsUseCase {
+  pub fn new(
+    create_blog_post_usecase: CreateBlogPostUseCase,
+    register_image_usecase: RegisterImageUseCase,
+    image_repository: Arc<dyn ImageRepository>,
+    html_converter: HtmlContentConverter,
+  ) -> Self {
+    Self {
+      create_blog_post_usecase,
+      register_image_usecase,
+      image_repository,
+      html_converter,
+    }
+  }
+
+  pub async fn execute(&self, wxr: &str) -> anyhow::Result<Vec<WordPressImportItemReportDTO>> {
+    let items = parse_wxr(wxr)?;
+
+    // アイキャッチ画像は添付ファイルの ID で参照される
+    let attachment_urls: HashMap<u64, String> =
+      items.iter().filter(|item| item.post_type == "attachment").filter_map(|item| item.attachment_url.clone().map(|url| (item.post_id, url))).collect();
+
+    let mut reports = Vec::new();
+    for item in items {
+      match item.post_type.as_str() {
+        "post" if IMPORTED_STATUSES.contains(&item.status.as_str()) => reports.push(self.import_item(item, &attachment_urls).await),
+        "post" => {
+          let reason = format!("公開されていない記事（{}）は取り込み対象外です", item.status);
+          reports.push(skipped_report(item, reason));
+        }
+        "page" => reports.push(skipped_report(item, "固定ページは取り込み対象外です".to_string())),
+        // 添付ファイルやメニューなどは記事ではないため報告しない
+        _ => {}
+      }
+    }
+
+    Ok(reports)
+  }
+
+  async fn import_item(&self, item: WxrItem, attachment_urls: &HashMap<u64, String>) -> WordPressImportItemReportDTO {
+    let converted = self.html_converter.convert(&item.content);
+    let mut lossy_notes = converted.lossy_notes;
+    let contents = converted.contents;
+
+    let title = if item.title.is_empty() {
+      lossy_notes.push("タイトルがないため仮のタイトルを付けました".to_string());
+      format!("無題（WordPress ID {}）", item.post_id)
+    } else {
+      item.title.clone()
+    };
+    if item.post_date.is_none() {
+      lossy_notes.push("投稿日を読み取れないため、取り込んだ日を投稿日にしました".to_string());
+    }
+
+    // サムネイルはアイキャッチ画像、なければ本文の最初の画像にする
+    let image_paths = image_paths_of(&contents);
+    let thumbnail_path = match item.thumbnail_id.and_then(|id| attachment_urls.get(&id)) {
+      Some(url) => Some(self.html_converter.image_path(url)),
+      None => {
+        let first_image = image_paths.first().cloned();
+        if first_image.is_some() {
+          lossy_notes.push("アイキャッチ画像がないため、本文の最初の画像をサムネイルにしました".to_string());
+        }
+        first_image
+      }
+    };
+    let Some(thumbnail_path) = thumbnail_path else {
+      let mut report = skipped_report(item, "サムネイルにできる画像（アイキャッチ画像・本文の画像）がありません".to_string());
+      report.lossy_notes = lossy_notes;
+      return report;
+    };
+
+    // 参照される画像を登録してから記事を作成する
+    let mut registered_image_paths = Vec::new();
+    let mut thumbnail_id = None;
+    let mut paths = vec![thumbnail_path.clone()];
+    paths.extend(image_paths.into_iter().filter(|path| path != &thumbnail_path));
+    for path in paths {
+      match self.find_or_register_image(&path, &mut registered_image_paths).await {
+        Ok(id) if path == thumbnail_path => thumbnail_id = Some(id),
+        Ok(_) => {}
+        Err(reason) => return failed_report(item, title, reason, lossy_notes, registered_image_paths),
+      }
+    }
+
+    let dto = CreateBlogPostDTO {
+      title: title.clone(),
+      thumbnail: CreateImageDTO {
+        id: thumbnail_id.expect("サムネイルは最初に登録される"),
+        path: thumbnail_path,
+      },
+      post_date: item.post_date,
+      last_update_date: item.modified_date.filter(|modified| item.post_date.is_some_and(|post_date| modified >= &post_date)),
+      published_date: item.post_date,
+      contents,
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
+    };
+    match self.create_blog_post_usecase.execute(dto).await {
+      Ok(saved) => {
+        // リンクカードは空のメタデータで作成されるため、取り込めなかった内容として報告する
+        lossy_notes.extend(saved.link_card_fetch_failures.into_iter().map(|failure| failure.message));
+        WordPressImportItemReportDTO {
+          wordpress_post_id: item.post_id,
+          title,
+          status: WordPressImportStatusDTO::Imported,
+          post_id: Uuid::parse_str(&saved.blog_post.id).ok(),
+          reason: None,
+          lossy_notes,
+          registered_image_paths,
+        }
+      }
+      Err(e) => failed_report(item, title, e.to_string(), lossy_notes, registered_image_paths),
+    }
+  }
+
+  /// 画像のパスから登録済みの画像を探し、なければ登録する
+  async fn find_or_register_image(&self, path: &str, registered_image_paths: &mut Vec<String>) -> Result<Uuid, String> {
+    match self.image_repository.find_by_path(path).await {
+      Ok(image) => Ok(image.get_id()),
+      Err(ImageRepositoryError::FindByPathFailed(msg)) if msg.starts_with("ImageNotFoundByPath:") => {
+        let image = self
+          .register_image_usecase
+          .execute(RegisterImageDTO { path: path.to_string() })
+          .await
+          .map_err(|e| format!("画像 {} を登録できません: {:?}", path, e))?;
+        registered_image_paths.push(path.to_string());
+        Ok(image.id)
+      }
+      Err(e) => Err(format!("画像 {} を確認できません: {:?}", path, e)),
+    }
+  }
+}
+
+fn image_paths_of(contents: &[CreateContentDTO]) -> Vec<String> {
+  let mut paths: Vec<String> = Vec::new();
+  for content in contents {
+    if let CreateContentDTO::Image { path, .. } = content {
+      if !paths.contains(path) {
+        paths.push(path.clone());
+      }
+    }
+  }
+  paths
+}
+
+fn skipped_report(item: WxrItem, reason: String) -> WordPressImportItemReportDTO {
+  WordPressImportItemReportDTO {
+    wordpress_post_id: item.post_id,
+    title: item.title,
+    status: WordPressImportStatusDTO::Skipped,
+    post_id: None,
+    reason: Some(reason),
+    lossy_notes: vec![],
+    registered_image_paths: vec![],
+  }
+}
+
+fn failed_report(item: WxrItem, title: String, reason: String, lossy_notes: Vec<String>, registered_image_paths: Vec<String>) -> WordPressImportItemReportDTO {
+  WordPressImportItemReportDTO {
+    wordpress_post_id: item.post_id,
+    title,
+    status: WordPressImportStatusDTO::Failed,
+    post_id: None,
+    reason: Some(reason),
+    lossy_notes,
+    registered_image_paths,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::blog_domain::blog_post_entity::link_card_block_entity::OgpMetadataVO;
+  use crate::domain::blog_domain::blog_post_entity::BlogPostEntity;
+  use crate::domain::blog_domain::blog_post_factory::BlogPostFactory;
+  use crate::domain::blog_domain::blog_post_repository::BlogPostRepository;
+  use crate::domain::blog_domain::image_content_factory::ImageContentFactory;
+  use crate::domain::blog_domain::link_card_content_factory::LinkCardContentFactory;
+  use crate::domain::blog_domain::ogp_fetcher::OgpFetcher;
+  use crate::domain::image_domain::image_entity::ImageEntity;
+  use async_trait::async_trait;
+  use mockall::mock;
+  use std::sync::Mutex;
+
+  mock! {
+    OgpFetcherStub {}
+
+    #[async_trait::async_trait]
+    impl OgpFetcher for OgpFetcherStub {
+      async fn fetch(&self, url: &str) -> anyhow::Result<OgpMetadataVO>;
+    }
+  }
+
+  mock! {
+    BlogPostRepo {}
+
+    #[async_trait::async_trait]
+    impl BlogPostRepository for BlogPostRepo {
+      async fn find(&self, id: &str) -> anyhow::Result<BlogPostEntity>;
+      async fn save(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn update(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn find_latests(&self, quantity: Option<u32>) -> anyhow::Result<Vec<BlogPostEntity>>;
+      async fn find_top_tech_pick(&self) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
+      async fn update_top_tech_pick_post(&self, top_tech_pick: &crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
+      async fn find_pick_up_posts(&self) -> anyhow::Result<crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity>;
+      async fn update_pick_up_posts(&self, pickup_posts: &crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity) -> anyhow::Result<crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity>;
+      async fn find_popular_posts(&self) -> anyhow::Result<crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity>;
+      async fn update_popular_posts(&self, popular_post_set: &crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity) -> anyhow::Result<crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity>;
+      async fn find_all(&self) -> anyhow::Result<Vec<BlogPostEntity>>;
+    }
+  }
+
+  // 保存した画像をパスで探せるリポジトリ
+  #[derive(Default)]
+  struct InMemoryImageRepository {
+    images: Mutex<Vec<ImageEntity>>,
+  }
+
+  #[async_trait]
+  impl ImageRepository for InMemoryImageRepository {
+    async fn find(&self, _id: &str) -> Result<ImageEntity, ImageRepositoryError> {
+      Err(ImageRepositoryError::FindFailed("not implemented".to_string()))
+    }
+
+    async fn find_by_path(&self, path: &str) -> Result<ImageEntity, ImageRepositoryError> {
+      let images = self.images.lock().unwrap();
+      images
+        .iter()
+        .find(|image| image.get_path() == path)
+        .map(|image| ImageEntity::new(image.get_id(), image.get_path().to_string()))
+        .ok_or_else(|| ImageRepositoryError::FindByPathFailed(format!("ImageNotFoundByPath: {}", path)))
+    }
+
+    async fn save(&self, image: ImageEntity) -> Result<ImageEntity, ImageRepositoryError> {
+      self.images.lock().unwrap().push(ImageEntity::new(image.get_id(), image.get_path().to_string()));
+      Ok(image)
+    }
+
+    async fn find_all(&self) -> Result<Vec<ImageEntity>, ImageRepositoryError> {
+      Err(ImageRepositoryError::FindAllFailed("not implemented".to_string()))
+    }
+  }
+
+  fn create_usecase(mock_repository: MockBlogPostRepo, image_repository: Arc<InMemoryImageRepository>) -> ImportWordPressPostsUseCase {
+    let blog_post_factory = Arc::new(BlogPostFactory::new(
+      Arc::new(ImageContentFactory::new(image_repository.clone())),
+      Arc::new(LinkCardContentFactory::new(Arc::new(MockOgpFetcherStub::new()))),
+    ));
+    ImportWordPressPostsUseCase::new(
+      CreateBlogPostUseCase::new(Arc::new(mock_repository), blog_post_factory),
+      RegisterImageUseCase::new(image_repository.clone()),
+      image_repository,
+      HtmlContentConverter::new(String::new()),
+    )
+  }
+
+  fn wxr(items: &str) -> String {
+    format!(
+      r#"<?xml version="1.0" encoding="UTF-8" ?>
+<rss version="2.0" xmlns:content="http://purl.org/rss/1.0/modules/content/" xmlns:wp="http://wordpress.org/export/1.2/">
+<channel>{}</channel>
+</rss>"#,
+      items
+    )
+  }
+
+  fn item(post_id: u64, post_type: &str, status: &str, content: &str, extra: &str) -> String {
+    format!(
+      r#"<item>
+  <title>記事{post_id}</title>
+  <content:encoded><![CDATA[{content}]]></content:encoded>
+  <wp:post_id>{post_id}</wp:post_id>
+  <wp:post_date>2019-05-01 10:00:00</wp:post_date>
+  <wp:status>{status}</wp:status>
+  <wp:post_type>{post_type}</wp:post_type>
+  {extra}
+</item>"#
+    )
+  }
+
+  fn saving_repository() -> MockBlogPostRepo {
+    let mut mock_repository = MockBlogPostRepo::new();
+    mock_repository.expect_save().returning(|blog_post, _| {
+      let mut saved = BlogPostEntity::new(blog_post.get_id(), blog_post.get_title_text().to_string());
+      saved.set_post_date(blog_post.get_post_date().clone());
+      Ok(saved)
+    });
+    mock_repository
+  }
+
+  #[tokio::test]
+  async fn imports_published_posts_and_registers_referenced_images() {
+    let image_repository = Arc::new(InMemoryImageRepository::default());
+    image_repository.save(ImageEntity::new(Uuid::from_u128(1), "wordpress/2019/05/body.png".to_string())).await.unwrap();
+    let usecase = create_usecase(saving_repository(), image_repository.clone());
+
+    let wxr = wxr(
+      &[
+        item(
+          10,
+          "post",
+          "publish",
+          r#"<h2>見出し</h2><p>本文<img src="https://old.example.com/wp-content/uploads/2019/05/body.png"></p><ul><li>項目</li></ul>"#,
+          "<wp:postmeta><wp:meta_key>_thumbnail_id</wp:meta_key><wp:meta_value>30</wp:meta_value></wp:postmeta>",
+        ),
+        item(11, "post", "draft", "<p>下書き</p>", ""),
+        item(12, "page", "publish", "<p>プロフィール</p>", ""),
+        item(
+          30,
+          "attachment",
+          "inherit",
+          "",
+          "<wp:attachment_url>https://old.example.com/wp-content/uploads/2019/05/eyecatch.png</wp:attachment_url>",
+        ),
+      ]
+      .concat(),
+    );
+
+    let reports = usecase.execute(&wxr).await.unwrap();
+
+    assert_eq!(reports.len(), 3);
+    let imported = &reports[0];
+    assert_eq!(imported.wordpress_post_id, 10);
+    assert_eq!(imported.status, WordPressImportStatusDTO::Imported);
+    assert!(imported.post_id.is_some());
+    assert_eq!(imported.lossy_notes, vec!["リストを段落に変換しました"]);
+    // 本文の画像は登録済みのため、アイキャッチ画像だけを登録する
+    assert_eq!(imported.registered_image_paths, vec!["wordpress/2019/05/eyecatch.png"]);
+    assert!(image_repository.find_by_path("wordpress/2019/05/eyecatch.png").await.is_ok());
+
+    assert_eq!(reports[1].status, WordPressImportStatusDTO::Skipped);
+    assert_eq!(reports[1].wordpress_post_id, 11);
+    assert_eq!(reports[2].status, WordPressImportStatusDTO::Skipped);
+    assert_eq!(reports[2].reason.as_deref(), Some("固定ページは取り込み対象外です"));
+  }
+
+  #[tokio::test]
+  async fn uses_first_body_image_as_thumbnail_and_skips_posts_without_images() {
+    let image_repository = Arc::new(InMemoryImageRepository::default());
+    let usecase = create_usecase(saving_repository(), image_repository);
+
+    let wxr = wxr(
+      &[
+        item(1, "post", "publish", r#"<p>画像つき</p><img src="/wp-content/uploads/a.png">"#, ""),
+        item(2, "post", "publish", "<p>画像なし</p>", ""),
+      ]
+      .concat(),
+    );
+
+    let reports = usecase.execute(&wxr).await.unwrap();
+
+    assert_eq!(reports[0].status, WordPressImportStatusDTO::Imported);
+    assert_eq!(reports[0].registered_image_paths, vec!["wordpress/a.png"]);
+    assert_eq!(
+      reports[0].lossy_notes,
+      vec!["アイキャッチ画像がないため、本文の最初の画像をサムネイルにしました"]
+    );
+    assert_eq!(reports[1].status, WordPressImportStatusDTO::Skipped);
+    assert!(reports[1].reason.as_deref().unwrap().contains("サムネイル"));
+  }
+
+  #[tokio::test]
+  async fn reports_failure_and_continues_with_next_post() {
+    let image_repository = Arc::new(InMemoryImageRepository::default());
+    let mut mock_repository = MockBlogPostRepo::new();
+    let mut calls = 0;
+    mock_repository.expect_save().times(2).returning(move |blog_post, _| {
+      calls += 1;
+      if calls == 1 {
+        Err(anyhow::anyhow!("保存に失敗しました"))
+      } else {
+        Ok(BlogPostEntity::new(blog_post.get_id(), blog_post.get_title_text().to_string()))
+      }
+    });
+    let usecase = create_usecase(mock_repository, image_repository);
+
+    let content = r#"<img src="/wp-content/uploads/a.png">"#;
+    let wxr = wxr(&[item(1, "post", "publish", content, ""), item(2, "post", "future", content, "")].concat());
+
+    let reports = usecase.execute(&wxr).await.unwrap();
+
+    assert_eq!(reports[0].status, WordPressImportStatusDTO::Failed);
+    assert_eq!(reports[0].reason.as_deref(), Some("保存に失敗しました"));
+    assert_eq!(reports[1].status, WordPressImportStatusDTO::Imported);
+  }
+
+  #[tokio::test]
+  async fn rejects_invalid_wxr() {
+    let usecase = create_usecase(MockBlogPostRepo::new(), Arc::new(InMemoryImageRepository::default()));
+
+    let err = usecase.execute("<html></html>").await.unwrap_err();
+
+    assert!(matches!(err.downcast_ref::<WordPressImportError>(), Some(WordPressImportError::InvalidWxr(_))));
+  }
+}
@@ -0,0 +1,580 @@
+use ego_tree::NodeRef;
+use scraper::{ElementRef, Html, Node};
+use url::Url;
+use uuid::Uuid;
+
+use crate::application::usecase::create_blog_post::dto::{
+  CreateCodeBlockOptionsDTO, CreateContentDTO, CreateImagePresentationDTO, CreateLinkDTO, CreateRichTextDTO, CreateStyleDTO,
+};
+use crate::domain::blog_domain::code_language_registry::CodeLanguageRegistry;
+
+/// 本文から取り除く WordPress のショートコード
+const SHORTCODES: [&str; 6] = ["caption", "gallery", "embed", "audio", "video", "playlist"];
+
+/// 中身ごと取り除く要素
+const DROPPED_ELEMENTS: [&str; 10] = ["script", "style", "iframe", "video", "audio", "object", "embed", "form", "noscript", "svg"];
+
+/// WordPress 由来の画像を登録するときのパスの接頭辞
+const WORDPRESS_IMAGE_PATH_PREFIX: &str = "wordpress/";
+
+const IMAGE_MIN_WIDTH: u32 = 16;
+const IMAGE_MAX_WIDTH: u32 = 4096;
+
+/// HTML から変換したコンテンツと、変換時に失われた内容の報告
+#[derive(Debug)]
+pub struct ConvertedHtml {
+  pub contents: Vec<CreateContentDTO>,
+  pub lossy_notes: Vec<String>,
+}
+
+/// WordPress の本文 HTML を記事のコンテンツに変換する
+///
+/// h2/h3/p/img/pre/code/a/strong（と em・del などのインライン装飾）を対応するブロック・スタイルに変換する。
+/// リスト・引用・表は段落に、h1 は h2・h4〜h6 は h3 に寄せ、埋め込みやスクリプトは取り除き、その旨を報告する。
+/// クラシックエディタの本文のように `<p>` を含まない HTML は、空行を段落の区切りとして扱う。
+pub struct HtmlContentConverter {
+  image_base_url: String,
+}
+
+impl HtmlContentConverter {
+  /// 新しいコンバーターを作成する
+  ///
+  /// # Arguments
+  /// * `image_base_url` - 画像の配信元の URL。本文の画像がこの配信元を指している場合は、残りの部分をそのままパスとして使う
+  pub fn new(image_base_url: String) -> Self {
+    Self { image_base_url }
+  }
+
+  pub fn convert(&self, html: &str) -> ConvertedHtml {
+    let mut lossy_notes = Vec::new();
+    let html = strip_shortcodes(html, &mut lossy_notes);
+
+    let fragment = Html::parse_fragment(&html);
+    let mut state = ConverterState {
+      converter: self,
+      contents: Vec::new(),
+      paragraph: Vec::new(),
+      lossy_notes,
+    };
+    for child in fragment.root_element().children() {
+      state.convert_block(child);
+    }
+    state.flush_paragraph();
+
+    ConvertedHtml {
+      contents: state.contents,
+      lossy_notes: state.lossy_notes,
+    }
+  }
+
+  /// 画像の URL を画像のパスに変換する
+  ///
+  /// 配信元の画像はそのままのパス、WordPress のアップロード画像は `wordpress/2019/05/a.png` のように
+  /// `wp-content/uploads/` 以降、それ以外は `wordpress/{ホスト名}/{パス}` とする
+  pub fn image_path(&self, src: &str) -> String {
+    let base_url = format!("{}/", self.image_base_url.trim_end_matches('/'));
+    if !self.image_base_url.is_empty() {
+      if let Some(path) = src.strip_prefix(&base_url) {
+        return path.to_string();
+      }
+    }
+
+    let (host, path) = match Url::parse(src) {
+      Ok(url) => (url.host_str().unwrap_or_default().to_string(), url.path().to_string()),
+      Err(_) => (String::new(), src.split(['?', '#']).next().unwrap_or_default().to_string()),
+    };
+    match path.split_once("/wp-content/uploads/") {
+      Some((_, uploaded)) => format!("{}{}", WORDPRESS_IMAGE_PATH_PREFIX, uploaded),
+      None if host.is_empty() => format!("{}{}", WORDPRESS_IMAGE_PATH_PREFIX, path.trim_start_matches('/')),
+      None => format!("{}{}/{}", WORDPRESS_IMAGE_PATH_PREFIX, host, path.trim_start_matches('/')),
+    }
+  }
+}
+
+/// インライン要素の変換中に引き継ぐ装飾
+#[derive(Clone, Default)]
+struct InlineContext {
+  bold: bool,
+  italic: bool,
+  inline_code: bool,
+  strikethrough: bool,
+  underline: bool,
+  link: Option<String>,
+}
+
+struct ConverterState<'a> {
+  converter: &'a HtmlContentConverter,
+  contents: Vec<CreateContentDTO>,
+  /// 組み立て中の段落
+  paragraph: Vec<CreateRichTextDTO>,
+  lossy_notes: Vec<String>,
+}
+
+impl ConverterState<'_> {
+  fn note(&mut self, message: String) {
+    if !self.lossy_notes.contains(&message) {
+      self.lossy_notes.push(message);
+    }
+  }
+
+  fn convert_block(&mut self, node: NodeRef<Node>) {
+    let element = match node.value() {
+      Node::Text(text) => {
+        self.push_top_level_text(text);
+        return;
+      }
+      Node::Element(element) => element,
+      _ => return,
+    };
+    let element_ref = ElementRef::wrap(node).expect("要素のノード");
+
+    match element.name() {
+      "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => self.push_heading(element.name(), element_ref),
+      "p" => {
+        self.flush_paragraph();
+        self.convert_inline_children(node, &InlineContext::default());
+        self.flush_paragraph();
+      }
+      "pre" => self.push_code_block(element_ref),
+      "img" => self.push_image(element_ref, None, None),
+      "figure" => self.push_figure(element_ref),
+      "ul" | "ol" => self.push_list(element_ref),
+      "blockquote" => {
+        self.note("引用を通常の段落として取り込みました".to_string());
+        self.convert_block_children(node);
+      }
+      "table" => self.push_table(element_ref),
+      "hr" => {
+        self.flush_paragraph();
+        self.note("区切り線を取り除きました".to_string());
+      }
+      "div" | "section" | "article" | "main" | "header" | "footer" | "center" => self.convert_block_children(node),
+      name if DROPPED_ELEMENTS.contains(&name) => {
+        self.flush_paragraph();
+        self.note(format!("<{}> には対応していないため取り除きました", name));
+      }
+      _ => self.convert_inline(node, &InlineContext::default()),
+    }
+  }
+
+  fn convert_block_children(&mut self, node: NodeRef<Node>) {
+    self.flush_paragraph();
+    for child in node.children() {
+      self.convert_block(child);
+    }
+    self.flush_paragraph();
+  }
+
+  /// ブロックの外にあるテキストは、クラシックエディタと同じく空行で段落を区切り、改行を保つ
+  fn push_top_level_text(&mut self, text: &str) {
+    let normalized = text.replace("\r\n", "\n");
+    let mut segments = normalized.split("\n\n").peekable();
+    while let Some(segment) = segments.next() {
+      let lines: Vec<String> = segment.split('\n').map(collapse_whitespace).collect();
+      let text = lines.join("\n");
+      if !text.trim().is_empty() {
+        self.paragraph.push(rich_text(text, &InlineContext::default()));
+      }
+      if segments.peek().is_some() {
+        self.flush_paragraph();
+      }
+    }
+  }
+
+  fn convert_inline_children(&mut self, node: NodeRef<Node>, context: &InlineContext) {
+    for child in node.children() {
+      self.convert_inline(child, context);
+    }
+  }
+
+  fn convert_inline(&mut self, node: NodeRef<Node>, context: &InlineContext) {
+    let element = match node.value() {
+      Node::Text(text) => {
+        let text = collapse_whitespace(text);
+        if !text.is_empty() {
+          self.paragraph.push(rich_text(text, context));
+        }
+        return;
+      }
+      Node::Element(element) => element,
+      _ => return,
+    };
+    let element_ref = ElementRef::wrap(node).expect("要素のノード");
+
+    let mut context = context.clone();
+    match element.name() {
+      "strong" | "b" => context.bold = true,
+      "em" | "i" => context.italic = true,
+      "code" | "kbd" => context.inline_code = true,
+      "del" | "s" | "strike" => context.strikethrough = true,
+      "u" | "ins" => context.underline = true,
+      "a" => match element.attr("href").map(str::trim) {
+        Some(href) if !href.is_empty() && !href.starts_with("javascript:") => context.link = Some(href.to_string()),
+        _ => self.note("リンク先のないリンクは文字だけを取り込みました".to_string()),
+      },
+      "br" => {
+        self.paragraph.push(rich_text("\n".to_string(), &context));
+        return;
+      }
+      "img" => {
+        // 段落内の画像は段落を分けて画像ブロックにする
+        let link_url = context.link.clone();
+        self.push_image(element_ref, None, link_url);
+        return;
+      }
+      "span" | "abbr" | "small" | "time" | "label" => {}
+      name if DROPPED_ELEMENTS.contains(&name) => {
+        self.note(format!("<{}> には対応していないため取り除きました", name));
+        return;
+      }
+      name => self.note(format!("<{}> のタグは取り除き、中の文字だけを取り込みました", name)),
+    }
+    self.convert_inline_children(node, &context);
+  }
+
+  fn flush_paragraph(&mut self) {
+    let mut parts = std::mem::take(&mut self.paragraph);
+    if let Some(first) = parts.first_mut() {
+      first.text = first.text.trim_start().to_string();
+    }
+    if let Some(last) = parts.last_mut() {
+      last.text = last.text.trim_end().to_string();
+    }
+    parts.retain(|part| !part.text.is_empty());
+    if parts.is_empty() {
+      return;
+    }
+    self.contents.push(CreateContentDTO::Paragraph {
+      id: Uuid::new_v4(),
+      text: parts,
+    });
+  }
+
+  fn push_heading(&mut self, name: &str, element: ElementRef) {
+    self.flush_paragraph();
+    let text = collapse_whitespace(&element.text().collect::<String>()).trim().to_string();
+    if text.is_empty() {
+      return;
+    }
+
+    let id = Uuid::new_v4();
+    let content = match name {
+      "h1" => {
+        self.note("h1 の見出しを h2 として取り込みました".to_string());
+        CreateContentDTO::H2 { id, text }
+      }
+      "h2" => CreateContentDTO::H2 { id, text },
+      "h3" => CreateContentDTO::H3 { id, text },
+      _ => {
+        self.note(format!("{} の見出しを h3 として取り込みました", name));
+        CreateContentDTO::H3 { id, text }
+      }
+    };
+    self.contents.push(content);
+  }
+
+  fn push_code_block(&mut self, element: ElementRef) {
+    self.flush_paragraph();
+    let code = element.text().collect::<String>();
+    let code = code.strip_suffix('\n').unwrap_or(&code).to_string();
+
+    // 言語は <pre> か中の <code> の class（language-rust・lang-rust）から読み取る
+    let language = std::iter::once(element)
+      .chain(element.children().filter_map(ElementRef::wrap).filter(|child| child.value().name() == "code"))
+      .flat_map(|el| el.value().classes().collect::<Vec<_>>())
+      .find_map(|class| class.strip_prefix("language-").or_else(|| class.strip_prefix("lang-")))
+      .map(str::to_ascii_lowercase);
+    let language = match language {
+      Some(language) if CodeLanguageRegistry::default().validate(&language).is_ok() => language,
+      Some(language) => {
+        self.note(format!("コードブロックの言語「{}」には対応していないため、text として取り込みました", language));
+        "text".to_string()
+      }
+      None => "text".to_string(),
+    };
+
+    self.contents.push(CreateContentDTO::CodeBlock {
+      id: Uuid::new_v4(),
+      title: String::new(),
+      code,
+      language,
+      options: CreateCodeBlockOptionsDTO::default(),
+    });
+  }
+
+  fn push_image(&mut self, element: ElementRef, caption: Option<String>, link_url: Option<String>) {
+    self.flush_paragraph();
+    let Some(src) = element.value().attr("src").map(str::trim).filter(|src| !src.is_empty()) else {
+      self.note("src のない画像を取り除きました".to_string());
+      return;
+    };
+
+    let width =
+      element.value().attr("width").and_then(|width| width.trim().parse::<u32>().ok()).filter(|width| (IMAGE_MIN_WIDTH..=IMAGE_MAX_WIDTH).contains(width));
+    // 画像のリンク先は http(s) のみ。元画像へのリンクは意味がないため取り込まない
+    let link_url = link_url.filter(|url| (url.starts_with("http://") || url.starts_with("https://")) && url != src);
+
+    self.contents.push(CreateContentDTO::Image {
+      id: Uuid::new_v4(),
+      path: self.converter.image_path(src),
+      presentation: CreateImagePresentationDTO {
+        alt: element.value().attr("alt").unwrap_or_default().trim().to_string(),
+        caption,
+        width,
+        link_url,
+      },
+    });
+  }
+
+  fn push_figure(&mut self, element: ElementRef) {
+    let image = element.descendants().filter_map(ElementRef::wrap).find(|el| el.value().name() == "img");
+    let Some(image) = image else {
+      // 表などを包む <figure> は中身だけを変換する
+      self.convert_block_children(*element);
+      return;
+    };
+
+    let caption = element
+      .descendants()
+      .filter_map(ElementRef::wrap)
+      .find(|el| el.value().name() == "figcaption")
+      .map(|el| collapse_whitespace(&el.text().collect::<String>()).trim().to_string())
+      .filter(|caption| !caption.is_empty());
+    let link_url =
+      image.ancestors().filter_map(ElementRef::wrap).find(|el| el.value().name() == "a").and_then(|el| el.value().attr("href")).map(str::to_string);
+    self.push_image(image, caption, link_url);
+  }
+
+  fn push_list(&mut self, element: ElementRef) {
+    self.flush_paragraph();
+    self.note("リストを段落に変換しました".to_string());
+
+    let ordered = element.value().name() == "ol";
+    let items = element.children().filter_map(ElementRef::wrap).filter(|child| child.value().name() == "li");
+    for (index, item) in items.enumerate() {
+      let marker = if ordered { format!("{}. ", index + 1) } else { "・".to_string() };
+      self.paragraph.push(rich_text(marker, &InlineContext::default()));
+      for child in item.children() {
+        match child.value() {
+          // 入れ子のリストは親の項目の後に続ける
+          Node::Element(el) if el.name() == "ul" || el.name() == "ol" => {
+            self.flush_paragraph();
+            self.push_list(ElementRef::wrap(child).expect("要素のノード"));
+          }
+          _ => self.convert_inline(child, &InlineContext::default()),
+        }
+      }
+      self.flush_paragraph();
+    }
+  }
+
+  fn push_table(&mut self, element: ElementRef) {
+    self.flush_paragraph();
+    self.note("表を 1 行ずつ段落に変換しました".to_string());
+
+    let rows = element.descendants().filter_map(ElementRef::wrap).filter(|el| el.value().name() == "tr");
+    for row in rows {
+      let cells: Vec<String> = row
+        .children()
+        .filter_map(ElementRef::wrap)
+        .filter(|cell| matches!(cell.value().name(), "td" | "th"))
+        .map(|cell| collapse_whitespace(&cell.text().collect::<String>()).trim().to_string())
+        .collect();
+      if !cells.is_empty() {
+        self.paragraph.push(rich_text(cells.join(" | "), &InlineContext::default()));
+        self.flush_paragraph();
+      }
+    }
+  }
+}
+
+fn rich_text(text: String, context: &InlineContext) -> CreateRichTextDTO {
+  CreateRichTextDTO {
+    text,
+    styles: CreateStyleDTO {
+      bold: context.bold,
+      inline_code: context.inline_code,
+      inline_math: false,
+      italic: context.italic,
+      strikethrough: context.strikethrough,
+      underline: context.underline,
+      highlight: None,
+    },
+    link: context.link.as_ref().map(|url| CreateLinkDTO {
+      url: url.clone(),
+      post_id: None,
+      heading_id: None,
+    }),
+    ruby: None,
+    footnote_id: None,
+  }
+}
+
+/// HTML と同じく連続する空白・改行を 1 つの空白にまとめる
+fn collapse_whitespace(text: &str) -> String {
+  let mut collapsed = String::with_capacity(text.len());
+  let mut in_whitespace = false;
+  for c in text.chars() {
+    if c.is_whitespace() {
+      if !in_whitespace {
+        collapsed.push(' ');
+      }
+      in_whitespace = true;
+    } else {
+      collapsed.push(c);
+      in_whitespace = false;
+    }
+  }
+  collapsed
+}
+
+/// `[caption id="..."]...[/caption]` のようなショートコードのタグを取り除く（囲まれた中身は残す）
+fn strip_shortcodes(html: &str, lossy_notes: &mut Vec<String>) -> String {
+  let mut stripped = String::with_capacity(html.len());
+  let mut rest = html;
+  while let Some(start) = rest.find('[') {
+    stripped.push_str(&rest[..start]);
+    let candidate = &rest[start..];
+    let tag = candidate.find(']').map(|end| &candidate[..=end]);
+    let name = tag.map(|tag| tag[1..tag.len() - 1].trim_start_matches('/').split_whitespace().next().unwrap_or_default());
+
+    match (tag, name) {
+      (Some(tag), Some(name)) if SHORTCODES.contains(&name) => {
+        let message = format!("ショートコード [{}] を取り除きました", name);
+        if !lossy_notes.contains(&message) {
+          lossy_notes.push(message);
+        }
+        rest = &candidate[tag.len()..];
+      }
+      _ => {
+        stripped.push('[');
+        rest = &candidate[1..];
+      }
+    }
+  }
+  stripped.push_str(rest);
+  stripped
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn convert(html: &str) -> ConvertedHtml {
+    HtmlContentConverter::new("https://res.example.com/blog".to_string()).convert(html)
+  }
+
+  fn paragraph_text(content: &CreateContentDTO) -> String {
+    match content {
+      CreateContentDTO::Paragraph { text, .. } => text.iter().map(|part| part.text.as_str()).collect(),
+      other => panic!("段落になるべき: {:?}", other),
+    }
+  }
+
+  #[test]
+  fn converts_gutenberg_blocks() {
+    let html = r#"<!-- wp:heading -->
+<h2>はじめに</h2>
+<!-- /wp:heading -->
+
+<!-- wp:paragraph -->
+<p>Rust は<strong>安全</strong>で<a href="https://www.rust-lang.org/">速い</a>言語です。</p>
+<!-- /wp:paragraph -->
+
+<!-- wp:code -->
+<pre class="wp-block-code"><code class="language-rust">fn main() {}
+</code></pre>
+<!-- /wp:code -->"#;
+
+    let converted = convert(html);
+
+    assert!(converted.lossy_notes.is_empty(), "{:?}", converted.lossy_notes);
+    assert_eq!(converted.contents.len(), 3);
+    assert!(matches!(&converted.contents[0], CreateContentDTO::H2 { text, .. } if text == "はじめに"));
+    match &converted.contents[1] {
+      CreateContentDTO::Paragraph { text, .. } => {
+        assert_eq!(text.len(), 5);
+        assert!(text[1].styles.bold);
+        assert_eq!(text[3].link.as_ref().unwrap().url, "https://www.rust-lang.org/");
+      }
+      other => panic!("段落になるべき: {:?}", other),
+    }
+    assert!(matches!(&converted.contents[2], CreateContentDTO::CodeBlock { code, language, .. } if code == "fn main() {}" && language == "rust"));
+  }
+
+  #[test]
+  fn splits_classic_editor_text_on_blank_lines() {
+    let converted = convert("1 行目\n2 行目\n\n次の<em>段落</em>");
+
+    assert_eq!(converted.contents.len(), 2);
+    assert_eq!(paragraph_text(&converted.contents[0]), "1 行目\n2 行目");
+    assert_eq!(paragraph_text(&converted.contents[1]), "次の段落");
+  }
+
+  #[test]
+  fn splits_paragraph_around_images_and_maps_image_paths() {
+    let html = r#"<p>前<img src="https://old.example.com/wp-content/uploads/2019/05/a.png" alt="図" width="300">後</p>
+<figure><a href="https://example.com/"><img src="https://res.example.com/blog/posts/b.png"></a><figcaption>説明</figcaption></figure>"#;
+
+    let converted = convert(html);
+
+    assert_eq!(converted.contents.len(), 4);
+    assert_eq!(paragraph_text(&converted.contents[0]), "前");
+    match &converted.contents[1] {
+      CreateContentDTO::Image { path, presentation, .. } => {
+        assert_eq!(path, "wordpress/2019/05/a.png");
+        assert_eq!(presentation.alt, "図");
+        assert_eq!(presentation.width, Some(300));
+      }
+      other => panic!("画像になるべき: {:?}", other),
+    }
+    assert_eq!(paragraph_text(&converted.contents[2]), "後");
+    match &converted.contents[3] {
+      CreateContentDTO::Image { path, presentation, .. } => {
+        assert_eq!(path, "posts/b.png");
+        assert_eq!(presentation.caption.as_deref(), Some("説明"));
+        assert_eq!(presentation.link_url.as_deref(), Some("https://example.com/"));
+      }
+      other => panic!("画像になるべき: {:?}", other),
+    }
+  }
+
+  #[test]
+  fn reports_lossy_conversions() {
+    let html = r#"<h1>大見出し</h1>
+<h4>小見出し</h4>
+<ul><li>項目1</li><li>項目2</li></ul>
+<iframe src="https://www.youtube.com/embed/xxx"></iframe>
+<pre><code class="language-cobol">DISPLAY 'HI'.</code></pre>
+[caption id="attachment_1"]<img src="/wp-content/uploads/c.png">キャプション[/caption]"#;
+
+    let converted = convert(html);
+
+    assert!(matches!(&converted.contents[0], CreateContentDTO::H2 { .. }));
+    assert!(matches!(&converted.contents[1], CreateContentDTO::H3 { .. }));
+    assert_eq!(paragraph_text(&converted.contents[2]), "・項目1");
+    assert_eq!(paragraph_text(&converted.contents[3]), "・項目2");
+    assert!(matches!(&converted.contents[4], CreateContentDTO::CodeBlock { language, .. } if language == "text"));
+    assert!(matches!(&converted.contents[5], CreateContentDTO::Image { path, .. } if path == "wordpress/c.png"));
+    assert_eq!(paragraph_text(&converted.contents[6]), "キャプション");
+    assert_eq!(
+      converted.lossy_notes,
+      vec![
+        "ショートコード [caption] を取り除きました",
+        "h1 の見出しを h2 として取り込みました",
+        "h4 の見出しを h3 として取り込みました",
+        "リストを段落に変換しました",
+        "<iframe> には対応していないため取り除きました",
+        "コードブロックの言語「cobol」には対応していないため、text として取り込みました",
+      ]
+    );
+  }
+
+  #[test]
+  fn keeps_square_brackets_that_are_not_shortcodes() {
+    let converted = convert("<p>[注] 配列 a[0] を使う</p>");
+
+    assert_eq!(paragraph_text(&converted.contents[0]), "[注] 配列 a[0] を使う");
+    assert!(converted.lossy_notes.is_empty());
+  }
+}
@@ -0,0 +1,174 @@
+use chrono::NaiveDate;
+use roxmltree::{Document, Node};
+
+use super::WordPressImportError;
+
+const WORDPRESS_NAMESPACE_PREFIX: &str = "http://wordpress.org/export/";
+const CONTENT_NAMESPACE: &str = "http://purl.org/rss/1.0/modules/content/";
+
+/// WXR の `<item>` 1 件分
+///
+/// 記事だけでなく固定ページや添付ファイル（画像）も `<item>` として書き出される
+#[derive(Debug, Clone, PartialEq)]
+pub struct WxrItem {
+  /// `wp:post_id`
+  pub post_id: u64,
+  pub title: String,
+  /// `wp:post_type`（`post`・`page`・`attachment` など）
+  pub post_type: String,
+  /// `wp:status`（`publish`・`future`・`draft` など）
+  pub status: String,
+  /// `wp:post_date`。下書きなどで `0000-00-00 00:00:00` の場合は `None`
+  pub post_date: Option<NaiveDate>,
+  /// `wp:post_modified`
+  pub modified_date: Option<NaiveDate>,
+  /// `content:encoded` の HTML
+  pub content: String,
+  /// 添付ファイルの場合の `wp:attachment_url`
+  pub attachment_url: Option<String>,
+  /// アイキャッチ画像の添付ファイルの ID（`wp:postmeta` の `_thumbnail_id`）
+  pub thumbnail_id: Option<u64>,
+}
+
+/// WordPress のエクスポートファイル（WXR）を読み込み、`<item>` を書き出された順に返す
+///
+/// WXR のバージョン（1.0〜1.2）で `wp` 名前空間の URI が異なるため、名前空間は前方一致で判定する
+pub fn parse_wxr(xml: &str) -> Result<Vec<WxrItem>, WordPressImportError> {
+  let document = Document::parse(xml).map_err(|e| WordPressImportError::InvalidWxr(format!("XML として読み込めません: {}", e)))?;
+
+  let root = document.root_element();
+  if root.tag_name().name() != "rss" {
+    return Err(WordPressImportError::InvalidWxr("ルート要素が <rss> ではありません".to_string()));
+  }
+  let channel = child_element(root, "channel").ok_or_else(|| WordPressImportError::InvalidWxr("<channel> がありません".to_string()))?;
+
+  channel.children().filter(|node| node.has_tag_name("item")).map(parse_item).collect()
+}
+
+fn parse_item(item: Node) -> Result<WxrItem, WordPressImportError> {
+  let post_id = wp_text(item, "post_id")
+    .and_then(|id| id.trim().parse().ok())
+    .ok_or_else(|| WordPressImportError::InvalidWxr("wp:post_id のない <item> があります".to_string()))?;
+
+  let content =
+    item.children().find(|node| node.tag_name().name() == "encoded" && node.tag_name().namespace() == Some(CONTENT_NAMESPACE)).map(text_of).unwrap_or_default();
+
+  let thumbnail_id = item
+    .children()
+    .filter(|node| is_wp_element(node, "postmeta"))
+    .find(|meta| wp_text(*meta, "meta_key").as_deref() == Some("_thumbnail_id"))
+    .and_then(|meta| wp_text(meta, "meta_value"))
+    .and_then(|value| value.trim().parse().ok());
+
+  Ok(WxrItem {
+    post_id,
+    title: child_element(item, "title").map(text_of).unwrap_or_default().trim().to_string(),
+    post_type: wp_text(item, "post_type").unwrap_or_default(),
+    status: wp_text(item, "status").unwrap_or_default(),
+    post_date: wp_text(item, "post_date").as_deref().and_then(parse_wordpress_date),
+    modified_date: wp_text(item, "post_modified").as_deref().and_then(parse_wordpress_date),
+    content,
+    attachment_url: wp_text(item, "attachment_url").filter(|url| !url.trim().is_empty()),
+    thumbnail_id,
+  })
+}
+
+/// `2019-05-01 10:00:00` 形式の日時から日付を取り出す
+fn parse_wordpress_date(value: &str) -> Option<NaiveDate> {
+  value.trim().get(..10).and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+}
+
+fn child_element<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+  node.children().find(|child| child.is_element() && child.tag_name().name() == name && child.tag_name().namespace().is_none())
+}
+
+fn is_wp_element(node: &Node, name: &str) -> bool {
+  node.is_element() && node.tag_name().name() == name && node.tag_name().namespace().is_some_and(|ns| ns.starts_with(WORDPRESS_NAMESPACE_PREFIX))
+}
+
+fn wp_text(node: Node, name: &str) -> Option<String> {
+  node.children().find(|child| is_wp_element(child, name)).map(text_of)
+}
+
+/// 要素内のテキスト（CDATA を含む）を連結して返す
+fn text_of(node: Node) -> String {
+  node.descendants().filter(|descendant| descendant.is_text()).filter_map(|text| text.text()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const WXR: &str = r#"<?xml version="1.0" encoding="UTF-8" ?>
+<rss version="2.0"
+  xmlns:content="http://purl.org/rss/1.0/modules/content/"
+  xmlns:wp="http://wordpress.org/export/1.2/">
+<channel>
+  <title>旧ブログ</title>
+  <item>
+    <title>Rust を始めました</title>
+    <content:encoded><![CDATA[<p>こんにちは</p>]]></content:encoded>
+    <wp:post_id>12</wp:post_id>
+    <wp:post_date><![CDATA[2019-05-01 10:00:00]]></wp:post_date>
+    <wp:post_modified><![CDATA[2020-01-02 03:04:05]]></wp:post_modified>
+    <wp:status><![CDATA[publish]]></wp:status>
+    <wp:post_type><![CDATA[post]]></wp:post_type>
+    <wp:postmeta>
+      <wp:meta_key><![CDATA[_edit_last]]></wp:meta_key>
+      <wp:meta_value><![CDATA[1]]></wp:meta_value>
+    </wp:postmeta>
+    <wp:postmeta>
+      <wp:meta_key><![CDATA[_thumbnail_id]]></wp:meta_key>
+      <wp:meta_value><![CDATA[34]]></wp:meta_value>
+    </wp:postmeta>
+  </item>
+  <item>
+    <title>eyecatch</title>
+    <wp:post_id>34</wp:post_id>
+    <wp:post_date><![CDATA[0000-00-00 00:00:00]]></wp:post_date>
+    <wp:status><![CDATA[inherit]]></wp:status>
+    <wp:post_type><![CDATA[attachment]]></wp:post_type>
+    <wp:attachment_url><![CDATA[https://old.example.com/wp-content/uploads/2019/05/eyecatch.png]]></wp:attachment_url>
+  </item>
+</channel>
+</rss>"#;
+
+  #[test]
+  fn parses_posts_and_attachments() {
+    let items = parse_wxr(WXR).unwrap();
+
+    assert_eq!(items.len(), 2);
+    let post = &items[0];
+    assert_eq!(post.post_id, 12);
+    assert_eq!(post.title, "Rust を始めました");
+    assert_eq!(post.post_type, "post");
+    assert_eq!(post.status, "publish");
+    assert_eq!(post.post_date, NaiveDate::from_ymd_opt(2019, 5, 1));
+    assert_eq!(post.modified_date, NaiveDate::from_ymd_opt(2020, 1, 2));
+    assert_eq!(post.content, "<p>こんにちは</p>");
+    assert_eq!(post.thumbnail_id, Some(34));
+
+    let attachment = &items[1];
+    assert_eq!(attachment.post_date, None);
+    assert_eq!(
+      attachment.attachment_url.as_deref(),
+      Some("https://old.example.com/wp-content/uploads/2019/05/eyecatch.png")
+    );
+  }
+
+  #[test]
+  fn accepts_older_wxr_namespace() {
+    let xml = WXR.replace("http://wordpress.org/export/1.2/", "http://wordpress.org/export/1.0/");
+
+    let items = parse_wxr(&xml).unwrap();
+
+    assert_eq!(items[0].post_id, 12);
+    assert_eq!(items[0].thumbnail_id, Some(34));
+  }
+
+  #[test]
+  fn rejects_documents_that_are_not_wxr() {
+    assert!(matches!(parse_wxr("<html></html>"), Err(WordPressImportError::InvalidWxr(_))));
+    assert!(matches!(parse_wxr("not xml"), Err(WordPressImportError::InvalidWxr(_))));
+  }
+}
@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use crate::domain::blog_domain::blog_post_repository::BlogPostRepository;
+use crate::domain::blog_domain::related_post_repository::RelatedPostRepository;
+use crate::domain::blog_domain::services::related_posts_service::RelatedPostsService;
+
+/// すべての記事について、関連記事の類似度計算に使う語の出現回数を求め直して保存するユースケース
+///
+/// 語の出現回数は記事の保存・更新時に保存されるため、通常は不要。
+/// 関連記事の機能を追加する前から存在する記事や、語の区切り方を変えた場合に使う
+pub struct RebuildRelatedPostIndexUseCase {
+  repository: Arc<dyn BlogPostRepository>,
+  related_post_repository: Arc<dyn RelatedPostRepository>,
+}
+
+impl RebuildRelatedPostIndexUseCase {
+  pub fn new(repository: Arc<dyn BlogPostRepository>, related_post_repository: Arc<dyn RelatedPostRepository>) -> Self {
+    Self {
+      repository,
+      related_post_repository,
+    }
+  }
+
+  /// 語の出現回数を保存した記事の件数を返す
+  pub async fn execute(&self) -> anyhow::Result<usize> {
+    // 未公開の記事も公開日を過ぎれば関連記事の候補になるため、未公開記事を含めて取得する
+    let blog_posts = self.repository.find_all().await?;
+
+    let service = RelatedPostsService::new();
+    for blog_post in &blog_posts {
+      self.related_post_repository.save_term_vector(&service.term_vector(blog_post)).await?;
+    }
+    Ok(blog_posts.len())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::blog_domain::blog_post_entity::BlogPostEntity;
+  use crate::domain::blog_domain::jst_date_vo::JstDate;
+  use crate::domain::blog_domain::related_post_repository::PostTermVectorVO;
+  use mockall::mock;
+  use uuid::Uuid;
+
+  mock! {
+    BlogPostRepo {}
+
+    #[async_trait::async_trait]
+    impl BlogPostRepository for BlogPostRepo {
+      async fn find(&self, id: &str) -> anyhow::Result<BlogPostEntity>;
+      async fn save(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn update(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn find_latests(&self, quantity: Option<u32>) -> anyhow::Result<Vec<BlogPostEntity>>;
+      async fn find_top_tech_pick(&self) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
+      async fn update_top_tech_pick_post(&self, top_tech_pick: &crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
+      async fn find_pick_up_posts(&self) -> anyhow::Result<crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity>;
+      async fn update_pick_up_posts(&self, pickup_posts: &crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity) -> anyhow::Result<crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity>;
+      async fn find_popular_posts(&self) -> anyhow::Result<crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity>;
+      async fn update_popular_posts(&self, popular_post_set: &crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity) -> anyhow::Result<crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity>;
+      async fn find_all(&self) -> anyhow::Result<Vec<BlogPostEntity>>;
+    }
+  }
+
+  mock! {
+    RelatedPostRepo {}
+
+    #[async_trait::async_trait]
+    impl RelatedPostRepository for RelatedPostRepo {
+      async fn find_published_term_vectors(&self, today: &JstDate) -> anyhow::Result<Vec<PostTermVectorVO>>;
+      async fn save_term_vector(&self, term_vector: &PostTermVectorVO) -> anyhow::Result<()>;
+    }
+  }
+
+  #[tokio::test]
+  async fn saves_term_vectors_of_all_posts() {
+    let mut mock_repository = MockBlogPostRepo::new();
+    mock_repository.expect_find_all().times(1).returning(|| {
+      Ok(vec![
+        BlogPostEntity::new(Uuid::from_u128(1), "Rust の所有権".to_string()),
+        BlogPostEntity::new(Uuid::from_u128(2), "Go の並行処理".to_string()),
+      ])
+    });
+    let mut mock_related_post_repository = MockRelatedPostRepo::new();
+    mock_related_post_repository
+      .expect_save_term_vector()
+      .withf(|term_vector| term_vector.id == Uuid::from_u128(1) && term_vector.terms.contains_key("rust"))
+      .times(1)
+      .returning(|_| Ok(()));
+    mock_related_post_repository
+      .expect_save_term_vector()
+      .withf(|term_vector| term_vector.id == Uuid::from_u128(2) && term_vector.terms.contains_key("go"))
+      .times(1)
+      .returning(|_| Ok(()));
+
+    let usecase = RebuildRelatedPostIndexUseCase::new(Arc::new(mock_repository), Arc::new(mock_related_post_repository));
+
+    assert_eq!(usecase.execute().await.unwrap(), 2);
+  }
+}
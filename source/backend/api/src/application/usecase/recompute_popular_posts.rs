@@ -0,0 +1,256 @@
+use std::sync::Arc;
+
+use chrono::Days;
+
+use crate::application::dto::BlogPostDTO;
+use crate::application::dto_mapper;
+use crate::application::services::popular_post_selector_service::PopularPostSelectorService;
+use crate::domain::blog_domain::blog_post_repository::BlogPostRepository;
+use crate::domain::blog_domain::errors::blog_domain_error::BlogDomainError;
+use crate::domain::blog_domain::jst_date_vo::JstDate;
+use crate::domain::blog_domain::page_view_repository::PageViewRepository;
+use crate::domain::blog_domain::popular_post_setting_repository::{PopularPostMode, PopularPostSettingRepository};
+use crate::domain::blog_domain::services::popular_post_ranking_service::{PopularPostRankingService, POPULAR_POST_COUNT};
+
+#[derive(Debug, thiserror::Error)]
+pub enum RecomputePopularPostsError {
+  #[error("人気記事は管理者が選ぶ設定になっているため、閲覧数からは選び直しません")]
+  ManualMode,
+  #[error("閲覧された記事が人気記事の件数（{} 件）に足りないため、人気記事を選び直せません", POPULAR_POST_COUNT)]
+  NotEnoughPosts,
+}
+
+/// 直近の閲覧数から人気記事を選び直すユースケース
+///
+/// 人気記事を自動で選ぶ設定の場合だけ選び直す。定期実行のたびに、集計が済んだ前日以前の閲覧者のハッシュも削除する
+pub struct RecomputePopularPostsUseCase {
+  repository: Arc<dyn BlogPostRepository>,
+  page_view_repository: Arc<dyn PageViewRepository>,
+  setting_repository: Arc<dyn PopularPostSettingRepository>,
+  popular_post_selector_service: PopularPostSelectorService,
+  window_days: u64,
+}
+
+impl RecomputePopularPostsUseCase {
+  /// 新しいユースケースインスタンスを作成する
+  ///
+  /// # Arguments
+  /// * `window_days` - 閲覧数を数える期間（今日を含む直近の日数。0 の場合は 1 日とみなす）
+  pub fn new(
+    repository: Arc<dyn BlogPostRepository>,
+    page_view_repository: Arc<dyn PageViewRepository>,
+    setting_repository: Arc<dyn PopularPostSettingRepository>,
+    window_days: u64,
+  ) -> Self {
+    Self {
+      popular_post_selector_service: PopularPostSelectorService::new(repository.clone()),
+      repository,
+      page_view_repository,
+      setting_repository,
+      window_days: window_days.max(1),
+    }
+  }
+
+  /// 人気記事を選び直す
+  ///
+  /// # Returns
+  /// * `Ok(Vec<BlogPostDTO>)` - 選び直した人気記事3件のDTOリスト
+  /// * `Err` - 管理者が選ぶ設定の場合（`RecomputePopularPostsError::ManualMode`）、
+  ///   閲覧された記事が足りない場合（`RecomputePopularPostsError::NotEnoughPosts`）、データベースエラーの場合
+  pub async fn execute(&self) -> anyhow::Result<Vec<BlogPostDTO>> {
+    let today = JstDate::today();
+
+    // 重複を除くための閲覧者のハッシュは当日分しか使わない
+    self.page_view_repository.delete_visitor_hashes_before(&today).await?;
+
+    if self.setting_repository.find_mode().await? == PopularPostMode::Manual {
+      return Err(RecomputePopularPostsError::ManualMode.into());
+    }
+
+    let since = JstDate::from_jst_naive_date(today.to_naive_date() - Days::new(self.window_days - 1));
+    let view_counts = self.page_view_repository.find_published_view_counts(&since, &today).await?;
+
+    // 閲覧された記事が足りない場合は現在の人気記事で補う（人気記事が未設定の場合は補わない）
+    let current_popular_post_ids = match self.repository.find_popular_posts().await {
+      Ok(popular_post_set) => popular_post_set.get_all_posts().iter().map(|post| post.get_id()).collect(),
+      Err(e) if matches!(e.downcast_ref::<BlogDomainError>(), Some(BlogDomainError::PopularPostsNotSet)) => Vec::new(),
+      Err(e) => return Err(e),
+    };
+
+    let ranking_service = PopularPostRankingService::new();
+    let post_ids = ranking_service.rank(&view_counts, &current_popular_post_ids).ok_or(RecomputePopularPostsError::NotEnoughPosts)?;
+
+    let popular_post_set = self.popular_post_selector_service.select_popular_posts(post_ids.iter().map(|id| id.to_string()).collect()).await?;
+
+    Ok(popular_post_set.into_all_posts().into_iter().map(dto_mapper::convert_to_blog_post_dto).collect())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::blog_domain::blog_post_entity::BlogPostEntity;
+  use crate::domain::blog_domain::page_view_repository::{PageViewTargetVO, PageViewVO, PostViewCountVO};
+  use crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity;
+  use mockall::{mock, predicate::eq};
+  use uuid::Uuid;
+
+  mock! {
+    BlogPostRepo {}
+
+    #[async_trait::async_trait]
+    impl BlogPostRepository for BlogPostRepo {
+      async fn find(&self, id: &str) -> anyhow::Result<BlogPostEntity>;
+      async fn save(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn update(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn find_latests(&self, quantity: Option<u32>) -> anyhow::Result<Vec<BlogPostEntity>>;
+      async fn find_top_tech_pick(&self) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
+      async fn update_top_tech_pick_post(&self, top_tech_pick: &crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
+      async fn find_pick_up_posts(&self) -> anyhow::Result<crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity>;
+      async fn update_pick_up_posts(&self, pickup_posts: &crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity) -> anyhow::Result<crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity>;
+      async fn find_popular_posts(&self) -> anyhow::Result<crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity>;
+      async fn update_popular_posts(&self, popular_post_set: &crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity) -> anyhow::Result<crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity>;
+      async fn find_all(&self) -> anyhow::Result<Vec<BlogPostEntity>>;
+    }
+  }
+
+  mock! {
+    PageViewRepo {}
+
+    #[async_trait::async_trait]
+    impl PageViewRepository for PageViewRepo {
+      async fn find_page_view_target(&self, id: &str) -> anyhow::Result<PageViewTargetVO>;
+      async fn record_page_view(&self, page_view: &PageViewVO) -> anyhow::Result<bool>;
+      async fn find_published_view_counts(&self, since: &JstDate, until: &JstDate) -> anyhow::Result<Vec<PostViewCountVO>>;
+      async fn delete_visitor_hashes_before(&self, date: &JstDate) -> anyhow::Result<u64>;
+    }
+  }
+
+  mock! {
+    SettingRepo {}
+
+    #[async_trait::async_trait]
+    impl PopularPostSettingRepository for SettingRepo {
+      async fn find_mode(&self) -> anyhow::Result<PopularPostMode>;
+      async fn save_mode(&self, mode: PopularPostMode) -> anyhow::Result<()>;
+    }
+  }
+
+  fn blog_post(id: u128) -> BlogPostEntity {
+    BlogPostEntity::new(Uuid::from_u128(id), format!("記事{}", id))
+  }
+
+  fn view_count(id: u128, views: u64) -> PostViewCountVO {
+    PostViewCountVO {
+      post_id: Uuid::from_u128(id),
+      views,
+    }
+  }
+
+  fn page_view_repository(view_counts: Vec<PostViewCountVO>) -> MockPageViewRepo {
+    let mut mock_page_view_repository = MockPageViewRepo::new();
+    mock_page_view_repository.expect_delete_visitor_hashes_before().with(eq(JstDate::today())).times(1).returning(|_| Ok(0));
+    mock_page_view_repository.expect_find_published_view_counts().returning(move |_, _| Ok(view_counts.clone()));
+    mock_page_view_repository
+  }
+
+  fn setting_repository(mode: PopularPostMode) -> MockSettingRepo {
+    let mut mock_setting_repository = MockSettingRepo::new();
+    mock_setting_repository.expect_find_mode().times(1).returning(move || Ok(mode));
+    mock_setting_repository
+  }
+
+  #[tokio::test]
+  async fn selects_most_viewed_posts_in_automatic_mode() {
+    let mut mock_repository = MockBlogPostRepo::new();
+    mock_repository.expect_find_popular_posts().times(1).returning(|| Ok(PopularPostSetEntity::new([blog_post(7), blog_post(8), blog_post(9)])));
+    mock_repository.expect_find().returning(|id| Ok(blog_post(Uuid::parse_str(id).unwrap().as_u128())));
+    mock_repository
+      .expect_update_popular_posts()
+      .withf(|popular_post_set| popular_post_set.get_all_posts().iter().map(|post| post.get_id().as_u128()).collect::<Vec<_>>() == vec![2, 1, 7])
+      .times(1)
+      .returning(|_| Ok(PopularPostSetEntity::new([blog_post(2), blog_post(1), blog_post(7)])));
+
+    let usecase = RecomputePopularPostsUseCase::new(
+      Arc::new(mock_repository),
+      Arc::new(page_view_repository(vec![view_count(1, 3), view_count(2, 10)])),
+      Arc::new(setting_repository(PopularPostMode::Automatic)),
+      30,
+    );
+    let dtos = usecase.execute().await.unwrap();
+
+    let ids: Vec<String> = dtos.into_iter().map(|dto| dto.id).collect();
+    assert_eq!(
+      ids,
+      vec![Uuid::from_u128(2).to_string(), Uuid::from_u128(1).to_string(), Uuid::from_u128(7).to_string()]
+    );
+  }
+
+  #[tokio::test]
+  async fn counts_views_within_window() {
+    let mut mock_repository = MockBlogPostRepo::new();
+    mock_repository.expect_find_popular_posts().returning(|| Err(BlogDomainError::PopularPostsNotSet.into()));
+    let mut mock_page_view_repository = MockPageViewRepo::new();
+    mock_page_view_repository.expect_delete_visitor_hashes_before().returning(|_| Ok(0));
+    mock_page_view_repository
+      .expect_find_published_view_counts()
+      .withf(|since, until| *until == JstDate::today() && (until.to_naive_date() - since.to_naive_date()).num_days() == 6)
+      .times(1)
+      .returning(|_, _| Ok(vec![]));
+
+    let usecase = RecomputePopularPostsUseCase::new(
+      Arc::new(mock_repository),
+      Arc::new(mock_page_view_repository),
+      Arc::new(setting_repository(PopularPostMode::Automatic)),
+      7,
+    );
+    let error = usecase.execute().await.unwrap_err();
+
+    assert!(matches!(
+      error.downcast_ref::<RecomputePopularPostsError>(),
+      Some(RecomputePopularPostsError::NotEnoughPosts)
+    ));
+  }
+
+  #[tokio::test]
+  async fn returns_error_when_current_popular_posts_cannot_be_loaded() {
+    let mut mock_repository = MockBlogPostRepo::new();
+    mock_repository.expect_find_popular_posts().returning(|| Err(anyhow::anyhow!("人気記事レコードの取得に失敗しました")));
+    mock_repository.expect_update_popular_posts().never();
+    let mut mock_page_view_repository = MockPageViewRepo::new();
+    mock_page_view_repository.expect_delete_visitor_hashes_before().returning(|_| Ok(0));
+    mock_page_view_repository.expect_find_published_view_counts().returning(|_, _| Ok(vec![view_count(1, 10), view_count(2, 20), view_count(3, 30)]));
+
+    let usecase = RecomputePopularPostsUseCase::new(
+      Arc::new(mock_repository),
+      Arc::new(mock_page_view_repository),
+      Arc::new(setting_repository(PopularPostMode::Automatic)),
+      7,
+    );
+    let error = usecase.execute().await.unwrap_err();
+
+    assert_eq!(error.to_string(), "人気記事レコードの取得に失敗しました");
+  }
+
+  #[tokio::test]
+  async fn does_nothing_but_cleanup_in_manual_mode() {
+    let mut mock_repository = MockBlogPostRepo::new();
+    mock_repository.expect_update_popular_posts().never();
+    let mut mock_page_view_repository = MockPageViewRepo::new();
+    mock_page_view_repository.expect_delete_visitor_hashes_before().times(1).returning(|_| Ok(5));
+    mock_page_view_repository.expect_find_published_view_counts().never();
+
+    let usecase = RecomputePopularPostsUseCase::new(
+      Arc::new(mock_repository),
+      Arc::new(mock_page_view_repository),
+      Arc::new(setting_repository(PopularPostMode::Manual)),
+      30,
+    );
+    let error = usecase.execute().await.unwrap_err();
+
+    assert!(matches!(
+      error.downcast_ref::<RecomputePopularPostsError>(),
+      Some(RecomputePopularPostsError::ManualMode)
+    ));
+  }
+}
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use crate::application::dto::PageViewClientDTO;
+use crate::domain::blog_domain::errors::blog_domain_error::BlogDomainError;
+use crate::domain::blog_domain::jst_date_vo::JstDate;
+use crate::domain::blog_domain::page_view_repository::PageViewRepository;
+use crate::domain::blog_domain::services::page_view_service::PageViewService;
+
+/// 公開記事の閲覧を記録するユースケース
+pub struct RecordPageViewUseCase {
+  page_view_repository: Arc<dyn PageViewRepository>,
+  page_view_service: PageViewService,
+}
+
+impl RecordPageViewUseCase {
+  pub fn new(page_view_repository: Arc<dyn PageViewRepository>, page_view_service: PageViewService) -> Self {
+    Self {
+      page_view_repository,
+      page_view_service,
+    }
+  }
+
+  /// 閲覧を記録する
+  ///
+  /// クローラーなどのプログラムによる閲覧と、同じ日の同じ閲覧者による 2 回目以降の閲覧は数えずに `false` を返す
+  pub async fn execute(&self, id: &str, client: PageViewClientDTO) -> anyhow::Result<bool> {
+    // クローラーによる閲覧は記事を取得する前に除き、データベースへの問い合わせを減らす
+    if self.page_view_service.is_bot(client.user_agent.as_deref()) {
+      return Ok(false);
+    }
+
+    // 閲覧のたびに呼ばれるため、記事全体ではなく公開状態の確認に要る情報だけを取得する
+    let target = self.page_view_repository.find_page_view_target(id).await?;
+
+    let today = JstDate::today();
+    if !target.is_published_on(&today) {
+      return Err(anyhow::anyhow!(BlogDomainError::UnpublishedPostAccess { post_title: target.title }.to_string()));
+    }
+
+    let page_view = self.page_view_service.page_view(target.post_id, client.ip.as_deref().unwrap_or_default(), client.user_agent.as_deref(), today);
+    match page_view {
+      Some(page_view) => self.page_view_repository.record_page_view(&page_view).await,
+      None => Ok(false),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::blog_domain::page_view_repository::{PageViewTargetVO, PageViewVO, PostViewCountVO};
+  use mockall::mock;
+  use uuid::Uuid;
+
+  mock! {
+    PageViewRepo {}
+
+    #[async_trait::async_trait]
+    impl PageViewRepository for PageViewRepo {
+      async fn find_page_view_target(&self, id: &str) -> anyhow::Result<PageViewTargetVO>;
+      async fn record_page_view(&self, page_view: &PageViewVO) -> anyhow::Result<bool>;
+      async fn find_published_view_counts(&self, since: &JstDate, until: &JstDate) -> anyhow::Result<Vec<PostViewCountVO>>;
+      async fn delete_visitor_hashes_before(&self, date: &JstDate) -> anyhow::Result<u64>;
+    }
+  }
+
+  const BROWSER: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/130.0.0.0 Safari/537.36";
+
+  fn client(user_agent: &str) -> PageViewClientDTO {
+    PageViewClientDTO {
+      ip: Some("203.0.113.1".to_string()),
+      user_agent: Some(user_agent.to_string()),
+    }
+  }
+
+  fn target(published_date: JstDate) -> PageViewTargetVO {
+    PageViewTargetVO {
+      post_id: Uuid::from_u128(1),
+      title: "閲覧される記事".to_string(),
+      published_date,
+    }
+  }
+
+  fn usecase(mock_page_view_repository: MockPageViewRepo) -> RecordPageViewUseCase {
+    RecordPageViewUseCase::new(Arc::new(mock_page_view_repository), PageViewService::new("salt".to_string()))
+  }
+
+  #[tokio::test]
+  async fn records_view_of_published_post() {
+    let mut mock_page_view_repository = MockPageViewRepo::new();
+    mock_page_view_repository.expect_find_page_view_target().times(1).returning(|_| Ok(target(JstDate::new(2024, 3, 2).unwrap())));
+    mock_page_view_repository
+      .expect_record_page_view()
+      .withf(|page_view| page_view.post_id == Uuid::from_u128(1) && page_view.visitor_hash.len() == 64 && page_view.viewed_on == JstDate::today())
+      .times(1)
+      .returning(|_| Ok(true));
+
+    let counted = usecase(mock_page_view_repository).execute("post-id", client(BROWSER)).await.unwrap();
+
+    assert!(counted);
+  }
+
+  #[tokio::test]
+  async fn ignores_bots_without_looking_up_post() {
+    let mut mock_page_view_repository = MockPageViewRepo::new();
+    mock_page_view_repository.expect_find_page_view_target().never();
+    mock_page_view_repository.expect_record_page_view().never();
+
+    let counted = usecase(mock_page_view_repository).execute("post-id", client("Googlebot/2.1")).await.unwrap();
+
+    assert!(!counted);
+  }
+
+  #[tokio::test]
+  async fn returns_error_for_unpublished_post() {
+    let mut mock_page_view_repository = MockPageViewRepo::new();
+    mock_page_view_repository.expect_find_page_view_target().times(1).returning(|_| Ok(target(JstDate::new(3000, 12, 31).unwrap())));
+    mock_page_view_repository.expect_record_page_view().never();
+
+    let result = usecase(mock_page_view_repository).execute("post-id", client(BROWSER)).await;
+
+    assert!(result.unwrap_err().to_string().contains("未公開記事「閲覧される記事」にアクセスすることはできません"));
+  }
+}
@@ -0,0 +1,154 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::dto::BlogPostDTO;
+use crate::application::dto_mapper;
+use crate::domain::blog_domain::blog_post_repository::BlogPostRepository;
+use crate::domain::blog_domain::errors::blog_domain_error::BlogDomainError;
+use crate::domain::blog_domain::link_card_content_factory::LinkCardContentFactory;
+use crate::domain::blog_domain::services::related_posts_service::RelatedPostsService;
+
+/// リンクカードの OGP メタデータを取得し直して保存するユースケース
+pub struct RefreshLinkCardUseCase {
+  repository: Arc<dyn BlogPostRepository>,
+  link_card_content_factory: Arc<LinkCardContentFactory>,
+}
+
+impl RefreshLinkCardUseCase {
+  pub fn new(repository: Arc<dyn BlogPostRepository>, link_card_content_factory: Arc<LinkCardContentFactory>) -> Self {
+    Self {
+      repository,
+      link_card_content_factory,
+    }
+  }
+
+  pub async fn execute(&self, post_id: &str, content_id: &str) -> anyhow::Result<BlogPostDTO> {
+    let mut blog_post = self.repository.find(post_id).await?;
+
+    // 対象のリンクカードを探し、OGP メタデータを取得し直す
+    let content_id = Uuid::parse_str(content_id).map_err(|_| BlogDomainError::LinkCardNotFound {
+      content_id: content_id.to_string(),
+    })?;
+    let link_card = blog_post.find_link_card_mut(content_id)?;
+    self.link_card_content_factory.refresh(link_card).await?;
+
+    let term_vector = RelatedPostsService::new().term_vector(&blog_post);
+    let updated_blog_post = self.repository.update(&blog_post, &term_vector).await?;
+
+    Ok(dto_mapper::convert_to_blog_post_dto(updated_blog_post))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::application::dto::BlogPostContentDTO;
+  use crate::domain::blog_domain::blog_post_entity::content_entity::ContentEntity;
+  use crate::domain::blog_domain::blog_post_entity::link_card_block_entity::OgpMetadataVO;
+  use crate::domain::blog_domain::blog_post_entity::BlogPostEntity;
+  use crate::domain::blog_domain::ogp_fetcher::OgpFetcher;
+  use mockall::mock;
+
+  mock! {
+    OgpFetcherStub {}
+
+    #[async_trait::async_trait]
+    impl OgpFetcher for OgpFetcherStub {
+      async fn fetch(&self, url: &str) -> anyhow::Result<OgpMetadataVO>;
+    }
+  }
+
+  mock! {
+    BlogPostRepo {}
+
+    #[async_trait::async_trait]
+    impl BlogPostRepository for BlogPostRepo {
+      async fn find(&self, id: &str) -> anyhow::Result<BlogPostEntity>;
+      async fn save(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn update(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn find_latests(&self, quantity: Option<u32>) -> anyhow::Result<Vec<BlogPostEntity>>;
+      async fn find_top_tech_pick(&self) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
+      async fn update_top_tech_pick_post(&self, top_tech_pick: &crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
+      async fn find_pick_up_posts(&self) -> anyhow::Result<crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity>;
+      async fn update_pick_up_posts(&self, pickup_posts: &crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity) -> anyhow::Result<crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity>;
+      async fn find_popular_posts(&self) -> anyhow::Result<crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity>;
+      async fn update_popular_posts(&self, popular_post_set: &crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity) -> anyhow::Result<crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity>;
+      async fn find_all(&self) -> anyhow::Result<Vec<BlogPostEntity>>;
+    }
+  }
+
+  const POST_ID: &str = "00000000-0000-0000-0000-000000000001";
+  const LINK_CARD_ID: &str = "00000000-0000-0000-0000-000000000002";
+
+  fn blog_post_with_link_card(metadata: OgpMetadataVO) -> BlogPostEntity {
+    let mut blog_post = BlogPostEntity::new(Uuid::parse_str(POST_ID).unwrap(), "リンクカード記事".to_string());
+    blog_post.set_thumbnail(Uuid::new_v4(), "path/to/thumbnail.jpg".to_string());
+    blog_post.add_content(ContentEntity::link_card(Uuid::parse_str(LINK_CARD_ID).unwrap(), "https://example.com".to_string(), metadata).unwrap());
+    blog_post
+  }
+
+  fn refreshed_metadata() -> OgpMetadataVO {
+    OgpMetadataVO {
+      title: Some("新しいタイトル".to_string()),
+      ..Default::default()
+    }
+  }
+
+  #[tokio::test]
+  async fn refresh_updates_link_card_metadata() {
+    let mut mock_repository = MockBlogPostRepo::new();
+    mock_repository.expect_find().times(1).returning(|_| Ok(blog_post_with_link_card(OgpMetadataVO::default())));
+    mock_repository
+      .expect_update()
+      .times(1)
+      .withf(|blog_post, _| match &blog_post.get_contents()[0] {
+        ContentEntity::LinkCard(link_card) => link_card.get_metadata() == &refreshed_metadata(),
+        _ => false,
+      })
+      .returning(|_, _| Ok(blog_post_with_link_card(refreshed_metadata())));
+
+    let mut mock_fetcher = MockOgpFetcherStub::new();
+    mock_fetcher.expect_fetch().times(1).with(mockall::predicate::eq("https://example.com")).returning(|_| Ok(refreshed_metadata()));
+
+    let usecase = RefreshLinkCardUseCase::new(Arc::new(mock_repository), Arc::new(LinkCardContentFactory::new(Arc::new(mock_fetcher))));
+    let result = usecase.execute(POST_ID, LINK_CARD_ID).await.unwrap();
+
+    match &result.contents[0] {
+      BlogPostContentDTO::LinkCard(link_card) => assert_eq!(link_card.title.as_deref(), Some("新しいタイトル")),
+      _ => panic!("コンテンツはLinkCardである必要があります"),
+    }
+  }
+
+  #[tokio::test]
+  async fn refresh_fails_when_link_card_does_not_exist() {
+    let mut mock_repository = MockBlogPostRepo::new();
+    mock_repository.expect_find().times(1).returning(|_| Ok(blog_post_with_link_card(OgpMetadataVO::default())));
+    mock_repository.expect_update().times(0);
+
+    let mut mock_fetcher = MockOgpFetcherStub::new();
+    mock_fetcher.expect_fetch().times(0);
+
+    let usecase = RefreshLinkCardUseCase::new(Arc::new(mock_repository), Arc::new(LinkCardContentFactory::new(Arc::new(mock_fetcher))));
+    let error = usecase.execute(POST_ID, "00000000-0000-0000-0000-000000000099").await.unwrap_err();
+
+    assert!(matches!(
+      error.downcast_ref::<BlogDomainError>(),
+      Some(BlogDomainError::LinkCardNotFound { .. })
+    ));
+  }
+
+  #[tokio::test]
+  async fn refresh_does_not_save_when_fetch_fails() {
+    let mut mock_repository = MockBlogPostRepo::new();
+    mock_repository.expect_find().times(1).returning(|_| Ok(blog_post_with_link_card(OgpMetadataVO::default())));
+    mock_repository.expect_update().times(0);
+
+    let mut mock_fetcher = MockOgpFetcherStub::new();
+    mock_fetcher.expect_fetch().times(1).returning(|_| Err(anyhow::anyhow!("timeout")));
+
+    let usecase = RefreshLinkCardUseCase::new(Arc::new(mock_repository), Arc::new(LinkCardContentFactory::new(Arc::new(mock_fetcher))));
+
+    assert!(usecase.execute(POST_ID, LINK_CARD_ID).await.is_err());
+  }
+}
@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use crate::domain::blog_domain::blog_post_repository::BlogPostRepository;
+use crate::domain::blog_domain::services::html_renderer_service::HtmlRendererService;
+use crate::domain::blog_domain::services::published_post_viewer_service::PublishedPostViewerService;
+
+/// 公開記事を HTML として取得するユースケース
+pub struct RenderBlogPostHtmlUseCase {
+  repository: Arc<dyn BlogPostRepository>,
+  renderer: HtmlRendererService,
+}
+
+impl RenderBlogPostHtmlUseCase {
+  pub fn new(repository: Arc<dyn BlogPostRepository>, renderer: HtmlRendererService) -> Self {
+    Self { repository, renderer }
+  }
+
+  pub async fn execute(&self, id: &str) -> anyhow::Result<String> {
+    // リポジトリから記事を取得
+    let blog_post = self.repository.find(id).await?;
+
+    // 公開記事閲覧サービスで公開状態をチェック
+    let published_post_viewer = PublishedPostViewerService::new();
+    let published_post = published_post_viewer.view_published_post(blog_post).map_err(|e| anyhow::anyhow!(e.to_string()))?;
+
+    Ok(self.renderer.render(&published_post))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::blog_domain::blog_post_entity::content_entity::ContentEntity;
+  use crate::domain::blog_domain::blog_post_entity::BlogPostEntity;
+  use crate::domain::blog_domain::jst_date_vo::JstDate;
+  use chrono::NaiveDate;
+  use mockall::mock;
+  use uuid::Uuid;
+
+  mock! {
+    BlogPostRepo {}
+
+    #[async_trait::async_trait]
+    impl BlogPostRepository for BlogPostRepo {
+      async fn find(&self, id: &str) -> anyhow::Result<BlogPostEntity>;
+      async fn save(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn update(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn find_latests(&self, quantity: Option<u32>) -> anyhow::Result<Vec<BlogPostEntity>>;
+      async fn find_top_tech_pick(&self) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
+      async fn update_top_tech_pick_post(&self, top_tech_pick: &crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
+      async fn find_pick_up_posts(&self) -> anyhow::Result<crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity>;
+      async fn update_pick_up_posts(&self, pickup_posts: &crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity) -> anyhow::Result<crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity>;
+      async fn find_popular_posts(&self) -> anyhow::Result<crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity>;
+      async fn update_popular_posts(&self, popular_post_set: &crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity) -> anyhow::Result<crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity>;
+      async fn find_all(&self) -> anyhow::Result<Vec<BlogPostEntity>>;
+    }
+  }
+
+  fn post_published_on(date: NaiveDate) -> BlogPostEntity {
+    let mut post = BlogPostEntity::new(Uuid::from_u128(1), "HTML記事".to_string());
+    post.set_published_date(JstDate::from_jst_naive_date(date));
+    post.add_content(ContentEntity::h2(Uuid::from_u128(2), "見出し".to_string()));
+    post
+  }
+
+  #[tokio::test]
+  async fn renders_published_post_as_html() {
+    let mut mock_repository = MockBlogPostRepo::new();
+    mock_repository.expect_find().times(1).returning(|_| Ok(post_published_on(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())));
+
+    let usecase = RenderBlogPostHtmlUseCase::new(Arc::new(mock_repository), HtmlRendererService::new(String::new()));
+
+    let html = usecase.execute("published-post-id").await.unwrap();
+
+    assert!(html.starts_with("<article>\n<h1>HTML記事</h1>\n"));
+    assert!(html.contains("<h2 id=\"見出し-00000000\">見出し</h2>"));
+  }
+
+  #[tokio::test]
+  async fn returns_error_for_unpublished_post() {
+    let mut mock_repository = MockBlogPostRepo::new();
+    mock_repository.expect_find().times(1).returning(|_| Ok(post_published_on(NaiveDate::from_ymd_opt(3000, 12, 31).unwrap())));
+
+    let usecase = RenderBlogPostHtmlUseCase::new(Arc::new(mock_repository), HtmlRendererService::new(String::new()));
+
+    let result = usecase.execute("unpublished-post-id").await;
+
+    assert!(result.unwrap_err().to_string().contains("未公開記事「HTML記事」にアクセスすることはできません"));
+  }
+}
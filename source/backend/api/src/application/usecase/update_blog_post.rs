@@ -1,8 +1,15 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::application::dto::BlogPostDTO;
+use uuid::Uuid;
+
+use crate::application::dto::SavedBlogPostDTO;
 use crate::application::dto_mapper;
+use crate::application::usecase::create_blog_post::dto::CreateContentDTO;
+use crate::domain::blog_domain::blog_post_entity::{content_entity::ContentEntity, link_card_block_entity::OgpMetadataVO, BlogPostEntity};
 use crate::domain::blog_domain::blog_post_repository::BlogPostRepository;
+use crate::domain::blog_domain::link_card_content_factory::{LinkCardContentFactory, LinkCardFetchFailure};
+use crate::domain::blog_domain::services::related_posts_service::RelatedPostsService;
 use domain_data_mapper::convert_dto_to_entity;
 use dto::UpdateBlogPostDTO;
 
@@ -11,14 +18,18 @@ pub mod dto;
 
 pub struct UpdateBlogPostUseCase {
   repository: Arc<dyn BlogPostRepository>,
+  link_card_content_factory: Arc<LinkCardContentFactory>,
 }
 
 impl UpdateBlogPostUseCase {
-  pub fn new(repository: Arc<dyn BlogPostRepository>) -> Self {
-    Self { repository }
+  pub fn new(repository: Arc<dyn BlogPostRepository>, link_card_content_factory: Arc<LinkCardContentFactory>) -> Self {
+    Self {
+      repository,
+      link_card_content_factory,
+    }
   }
 
-  pub async fn execute(&self, id: &str, dto: UpdateBlogPostDTO) -> anyhow::Result<BlogPostDTO> {
+  pub async fn execute(&self, id: &str, dto: UpdateBlogPostDTO) -> anyhow::Result<SavedBlogPostDTO> {
     // 既存記事の存在確認
     let mut existing_blog_post = self.repository.find(id).await?;
 
@@ -28,16 +39,56 @@ impl UpdateBlogPostUseCase {
       self.validate_unpublish_restrictions(id).await?;
     }
 
+    // リンクカードの OGP メタデータは保存済みの値を引き継ぐか、サーバー側で取得する
+    let mut link_card_fetch_failures = Vec::new();
+    let link_card_metadata = self.resolve_link_card_metadata(&existing_blog_post, &dto.contents, &mut link_card_fetch_failures).await?;
+
     // DTOから更新内容をエンティティに反映
-    convert_dto_to_entity(dto, &mut existing_blog_post)?;
+    convert_dto_to_entity(dto, &mut existing_blog_post, &link_card_metadata)?;
 
-    // リポジトリで更新
-    let updated_blog_post = self.repository.update(&existing_blog_post).await?;
+    // 関連記事用の語の出現回数を求め、記事と一緒に更新する
+    let term_vector = RelatedPostsService::new().term_vector(&existing_blog_post);
+    let updated_blog_post = self.repository.update(&existing_blog_post, &term_vector).await?;
+
+    // BlogPostEntityをBlogPostDTOに変換し、OGP メタデータを取得できなかったリンクカードを添える
+    Ok(SavedBlogPostDTO {
+      blog_post: dto_mapper::convert_to_blog_post_dto(updated_blog_post),
+      link_card_fetch_failures: link_card_fetch_failures.into_iter().map(dto_mapper::convert_link_card_fetch_failure).collect(),
+    })
+  }
 
-    // BlogPostEntityをBlogPostDTOに変換
-    let result_dto = dto_mapper::convert_to_blog_post_dto(updated_blog_post);
+  /// 更新後のリンクカードごとに OGP メタデータを決める
+  ///
+  /// ID と URL が保存済みのものと一致する場合は保存済みのメタデータを引き継ぎ、
+  /// 新しく追加されたものや URL が変わったものは取得し直し、取得できなかったものは `link_card_fetch_failures` に加える
+  async fn resolve_link_card_metadata(
+    &self,
+    existing_blog_post: &BlogPostEntity,
+    contents: &[CreateContentDTO],
+    link_card_fetch_failures: &mut Vec<LinkCardFetchFailure>,
+  ) -> anyhow::Result<HashMap<Uuid, OgpMetadataVO>> {
+    let stored_link_cards: HashMap<Uuid, _> = existing_blog_post
+      .flattened_contents()
+      .filter_map(|content| match content {
+        ContentEntity::LinkCard(link_card) => Some((link_card.get_id(), link_card)),
+        _ => None,
+      })
+      .collect();
 
-    Ok(result_dto)
+    let mut link_card_metadata = HashMap::new();
+    for (id, url) in flattened_link_cards(contents) {
+      let metadata = match stored_link_cards.get(&id) {
+        Some(stored) if stored.get_url() == url.trim() => stored.get_metadata().clone(),
+        _ => {
+          let creation = self.link_card_content_factory.create(id, url.to_string()).await?;
+          link_card_fetch_failures.extend(creation.fetch_failure);
+          creation.link_card.get_metadata().clone()
+        }
+      };
+      link_card_metadata.insert(id, metadata);
+    }
+
+    Ok(link_card_metadata)
   }
 
   async fn validate_unpublish_restrictions(&self, post_id: &str) -> anyhow::Result<()> {
@@ -70,23 +121,49 @@ impl UpdateBlogPostUseCase {
   }
 }
 
+/// 折りたたみブロックの子コンテンツも含めて、リンクカードの ID と URL を列挙する
+fn flattened_link_cards(contents: &[CreateContentDTO]) -> Vec<(Uuid, &str)> {
+  contents
+    .iter()
+    .flat_map(|content| match content {
+      CreateContentDTO::Details { children, .. } => children.iter().collect::<Vec<_>>(),
+      other => vec![other],
+    })
+    .filter_map(|content| match content {
+      CreateContentDTO::LinkCard { id, url } => Some((*id, url.as_str())),
+      _ => None,
+    })
+    .collect()
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::application::dto::BlogPostContentDTO;
   use crate::domain::blog_domain::blog_post_entity::BlogPostEntity;
   use crate::domain::blog_domain::blog_post_repository::BlogPostRepository;
+  use crate::domain::blog_domain::ogp_fetcher::OgpFetcher;
   use mockall::mock;
   use std::sync::Arc;
   use uuid::Uuid;
 
+  mock! {
+    OgpFetcherStub {}
+
+    #[async_trait::async_trait]
+    impl OgpFetcher for OgpFetcherStub {
+      async fn fetch(&self, url: &str) -> anyhow::Result<OgpMetadataVO>;
+    }
+  }
+
   mock! {
     BlogPostRepo {}
 
     #[async_trait::async_trait]
     impl BlogPostRepository for BlogPostRepo {
       async fn find(&self, id: &str) -> anyhow::Result<BlogPostEntity>;
-      async fn save(&self, blog_post: &BlogPostEntity) -> anyhow::Result<BlogPostEntity>;
-      async fn update(&self, blog_post: &BlogPostEntity) -> anyhow::Result<BlogPostEntity>;
+      async fn save(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn update(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
       async fn find_latests(&self, quantity: Option<u32>) -> anyhow::Result<Vec<BlogPostEntity>>;
       async fn find_top_tech_pick(&self) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
       async fn update_top_tech_pick_post(&self, top_tech_pick: &crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
@@ -115,14 +192,14 @@ mod tests {
     });
 
     // updateメソッドが呼ばれることを期待
-    mock_repository.expect_update().times(1).returning(move |_| {
+    mock_repository.expect_update().times(1).returning(move |_, _| {
       Ok(BlogPostEntity::new(
         Uuid::parse_str("00000000-0000-0000-0000-000000000001").unwrap(),
         "更新されたタイトル".to_string(),
       ))
     });
 
-    let usecase = UpdateBlogPostUseCase::new(Arc::new(mock_repository));
+    let usecase = UpdateBlogPostUseCase::new(Arc::new(mock_repository), link_card_factory(MockOgpFetcherStub::new()));
     let dto = dto::UpdateBlogPostDTO {
       title: updated_title.to_string(),
       thumbnail: crate::application::usecase::create_blog_post::dto::CreateImageDTO {
@@ -131,6 +208,9 @@ mod tests {
       },
       published_date: chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
       contents: vec![],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
     };
 
     // Act
@@ -138,7 +218,136 @@ mod tests {
 
     // Assert
     assert!(result.is_ok());
-    let blog_post_dto = result.unwrap();
+    let blog_post_dto = result.unwrap().blog_post;
     assert_eq!(blog_post_dto.title, updated_title);
   }
+
+  const POST_ID: &str = "00000000-0000-0000-0000-000000000001";
+  const LINK_CARD_ID: &str = "00000000-0000-0000-0000-000000000002";
+
+  fn link_card_factory(fetcher: MockOgpFetcherStub) -> Arc<LinkCardContentFactory> {
+    Arc::new(LinkCardContentFactory::new(Arc::new(fetcher)))
+  }
+
+  fn stored_metadata() -> OgpMetadataVO {
+    OgpMetadataVO {
+      title: Some("保存済みのタイトル".to_string()),
+      image_url: Some("https://example.com/stored.png".to_string()),
+      ..Default::default()
+    }
+  }
+
+  fn fetched_metadata() -> OgpMetadataVO {
+    OgpMetadataVO {
+      title: Some("取得したタイトル".to_string()),
+      ..Default::default()
+    }
+  }
+
+  fn stored_blog_post() -> BlogPostEntity {
+    let mut blog_post = BlogPostEntity::new(Uuid::parse_str(POST_ID).unwrap(), "リンクカード記事".to_string());
+    blog_post.add_content(
+      ContentEntity::link_card(
+        Uuid::parse_str(LINK_CARD_ID).unwrap(),
+        "https://example.com/article".to_string(),
+        stored_metadata(),
+      )
+      .unwrap(),
+    );
+    blog_post
+  }
+
+  fn update_dto_with_link_card(id: Uuid, url: &str) -> dto::UpdateBlogPostDTO {
+    dto::UpdateBlogPostDTO {
+      title: "リンクカード記事".to_string(),
+      thumbnail: crate::application::usecase::create_blog_post::dto::CreateImageDTO {
+        id: Uuid::new_v4(),
+        path: "path/to/image.jpg".to_string(),
+      },
+      published_date: chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+      contents: vec![CreateContentDTO::LinkCard { id, url: url.to_string() }],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
+    }
+  }
+
+  // 更新後のエンティティをそのまま返すリポジトリ
+  fn repository_returning_updated_post() -> MockBlogPostRepo {
+    let mut mock_repository = MockBlogPostRepo::new();
+    mock_repository.expect_find().times(1).returning(|_| Ok(stored_blog_post()));
+    mock_repository.expect_update().times(1).returning(|blog_post, _| {
+      let mut updated = BlogPostEntity::new(blog_post.get_id(), blog_post.get_title_text().to_string());
+      for content in blog_post.get_contents() {
+        if let ContentEntity::LinkCard(link_card) = content {
+          updated.add_content(ContentEntity::link_card(link_card.get_id(), link_card.get_url().to_string(), link_card.get_metadata().clone()).unwrap());
+        }
+      }
+      Ok(updated)
+    });
+    mock_repository
+  }
+
+  fn link_card_title(result: &SavedBlogPostDTO) -> Option<String> {
+    match &result.blog_post.contents[0] {
+      BlogPostContentDTO::LinkCard(link_card) => link_card.title.clone(),
+      _ => panic!("コンテンツはLinkCardである必要があります"),
+    }
+  }
+
+  #[tokio::test]
+  async fn unchanged_link_card_keeps_stored_metadata_without_fetching() {
+    let mut mock_fetcher = MockOgpFetcherStub::new();
+    mock_fetcher.expect_fetch().times(0);
+
+    let usecase = UpdateBlogPostUseCase::new(Arc::new(repository_returning_updated_post()), link_card_factory(mock_fetcher));
+    let dto = update_dto_with_link_card(Uuid::parse_str(LINK_CARD_ID).unwrap(), "https://example.com/article");
+
+    let result = usecase.execute(POST_ID, dto).await.unwrap();
+
+    assert_eq!(link_card_title(&result).as_deref(), Some("保存済みのタイトル"));
+  }
+
+  #[tokio::test]
+  async fn link_card_with_changed_url_is_fetched_again() {
+    let mut mock_fetcher = MockOgpFetcherStub::new();
+    mock_fetcher.expect_fetch().times(1).with(mockall::predicate::eq("https://example.com/another")).returning(|_| Ok(fetched_metadata()));
+
+    let usecase = UpdateBlogPostUseCase::new(Arc::new(repository_returning_updated_post()), link_card_factory(mock_fetcher));
+    let dto = update_dto_with_link_card(Uuid::parse_str(LINK_CARD_ID).unwrap(), "https://example.com/another");
+
+    let result = usecase.execute(POST_ID, dto).await.unwrap();
+
+    assert_eq!(link_card_title(&result).as_deref(), Some("取得したタイトル"));
+  }
+
+  #[tokio::test]
+  async fn new_link_card_is_fetched() {
+    let mut mock_fetcher = MockOgpFetcherStub::new();
+    mock_fetcher.expect_fetch().times(1).with(mockall::predicate::eq("https://example.com/article")).returning(|_| Ok(fetched_metadata()));
+
+    let usecase = UpdateBlogPostUseCase::new(Arc::new(repository_returning_updated_post()), link_card_factory(mock_fetcher));
+    let dto = update_dto_with_link_card(Uuid::new_v4(), "https://example.com/article");
+
+    let result = usecase.execute(POST_ID, dto).await.unwrap();
+
+    assert_eq!(link_card_title(&result).as_deref(), Some("取得したタイトル"));
+    assert!(result.link_card_fetch_failures.is_empty());
+  }
+
+  #[tokio::test]
+  async fn link_card_fetch_failure_is_returned_with_updated_post() {
+    let mut mock_fetcher = MockOgpFetcherStub::new();
+    mock_fetcher.expect_fetch().times(1).returning(|_| Err(anyhow::anyhow!("timeout")));
+
+    let usecase = UpdateBlogPostUseCase::new(Arc::new(repository_returning_updated_post()), link_card_factory(mock_fetcher));
+    let link_card_id = Uuid::new_v4();
+    let dto = update_dto_with_link_card(link_card_id, "https://example.com/article");
+
+    let result = usecase.execute(POST_ID, dto).await.unwrap();
+
+    assert_eq!(link_card_title(&result), None);
+    assert_eq!(result.link_card_fetch_failures.len(), 1);
+    assert_eq!(result.link_card_fetch_failures[0].content_id, link_card_id);
+  }
 }
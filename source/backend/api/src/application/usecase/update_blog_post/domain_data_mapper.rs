@@ -1,17 +1,31 @@
 use anyhow::Result;
+use std::collections::HashMap;
+use uuid::Uuid;
 
 use super::dto::UpdateBlogPostDTO;
-use crate::application::usecase::create_blog_post::dto::CreateContentDTO;
+use crate::application::usecase::create_blog_post::{
+  domain_data_mapper::{convert_diagram_kind_dto_to_domain, convert_highlight_color_dto_to_domain},
+  dto::{CreateContentDTO, CreateFootnoteDTO, CreateRichTextDTO, CreateSeoMetadataDTO},
+};
 use crate::domain::blog_domain::blog_post_entity::{
+  code_block_options_vo::CodeBlockOptionsVO,
   content_entity::ContentEntity,
+  footnote_entity::FootnoteEntity,
   image_content_entity::ImageContentEntity,
-  rich_text_vo::{RichTextPartVO, RichTextVO},
+  image_presentation_vo::ImagePresentationVO,
+  link_card_block_entity::OgpMetadataVO,
+  rich_text_vo::{LinkVO, RichTextPartVO, RichTextStylesVO, RichTextVO, RubyVO},
+  seo_metadata_vo::SeoMetadataVO,
   BlogPostEntity,
 };
+use crate::domain::blog_domain::embed_provider_registry::EmbedProviderRegistry;
 use crate::domain::blog_domain::jst_date_vo::JstDate;
 use crate::domain::image_domain::ImageEntity;
 
-pub fn convert_dto_to_entity(dto: UpdateBlogPostDTO, entity: &mut BlogPostEntity) -> Result<()> {
+/// 更新内容をエンティティに反映する
+///
+/// リンクカードの OGP メタデータはリクエストに含めず、呼び出し側で決めたものを `link_card_metadata` で受け取る
+pub fn convert_dto_to_entity(dto: UpdateBlogPostDTO, entity: &mut BlogPostEntity, link_card_metadata: &HashMap<Uuid, OgpMetadataVO>) -> Result<()> {
   // タイトルの更新
   entity.update_title(dto.title);
 
@@ -28,43 +42,106 @@ pub fn convert_dto_to_entity(dto: UpdateBlogPostDTO, entity: &mut BlogPostEntity
   // コンテンツの更新
   entity.clear_contents();
   for content_dto in dto.contents {
-    let content = convert_content_dto_to_entity(content_dto)?;
+    let content = convert_content_dto_to_entity(content_dto, link_card_metadata)?;
     entity.add_content(content);
   }
 
+  // 脚注の更新（本文中の参照と突き合わせて番号を振り直す）
+  let footnotes = dto.footnotes.into_iter().map(convert_footnote_dto_to_entity).collect::<Result<Vec<_>>>()?;
+  entity.set_footnotes(footnotes)?;
+
+  // SEO メタデータの更新
+  entity.set_seo_metadata(convert_seo_metadata_dto_to_vo(dto.seo_metadata)?);
+
+  // 手動で設定する抜粋の更新（未指定の場合は本文から自動で作る）
+  entity.set_manual_excerpt(dto.manual_excerpt)?;
+
   Ok(())
 }
 
-fn convert_content_dto_to_entity(dto: CreateContentDTO) -> Result<ContentEntity> {
+fn convert_seo_metadata_dto_to_vo(dto: CreateSeoMetadataDTO) -> Result<SeoMetadataVO> {
+  Ok(SeoMetadataVO::new(
+    dto.description,
+    dto.og_image.map(|og_image| ImageEntity::new(og_image.id, og_image.path)),
+    dto.canonical_url,
+    dto.noindex,
+  )?)
+}
+
+fn convert_footnote_dto_to_entity(dto: CreateFootnoteDTO) -> Result<FootnoteEntity> {
+  let rich_text_parts: Vec<RichTextPartVO> = dto.text.into_iter().map(convert_rich_text_dto_to_part).collect::<Result<_>>()?;
+  Ok(FootnoteEntity::new(dto.id, RichTextVO::new(rich_text_parts))?)
+}
+
+fn convert_rich_text_dto_to_part(rich_text_dto: CreateRichTextDTO) -> Result<RichTextPartVO> {
+  let styles = RichTextStylesVO {
+    bold: rich_text_dto.styles.bold,
+    inline_code: rich_text_dto.styles.inline_code,
+    italic: rich_text_dto.styles.italic,
+    strikethrough: rich_text_dto.styles.strikethrough,
+    underline: rich_text_dto.styles.underline,
+    highlight: rich_text_dto.styles.highlight.map(convert_highlight_color_dto_to_domain),
+  };
+  let mut part = RichTextPartVO::new(
+    rich_text_dto.text,
+    if styles.has_any() { Some(styles) } else { None },
+    rich_text_dto.link.map(|link_dto| LinkVO::new(link_dto.url, link_dto.post_id, link_dto.heading_id)),
+  );
+  if rich_text_dto.styles.inline_math {
+    part = part.with_inline_math()?;
+  }
+  if let Some(ruby) = rich_text_dto.ruby {
+    part = part.with_ruby(RubyVO::new(ruby)?)?;
+  }
+  if let Some(footnote_id) = rich_text_dto.footnote_id {
+    part = part.with_footnote_ref(footnote_id);
+  }
+  Ok(part)
+}
+
+fn convert_content_dto_to_entity(dto: CreateContentDTO, link_card_metadata: &HashMap<Uuid, OgpMetadataVO>) -> Result<ContentEntity> {
   let content = match dto {
     CreateContentDTO::H2 { id, text } => ContentEntity::h2(id, text),
     CreateContentDTO::H3 { id, text } => ContentEntity::h3(id, text),
     CreateContentDTO::Paragraph { id, text } => {
-      let rich_text_parts: Vec<RichTextPartVO> = text
-        .into_iter()
-        .map(|rich_text_dto| {
-          RichTextPartVO::new(
-            rich_text_dto.text,
-            if rich_text_dto.styles.bold || rich_text_dto.styles.inline_code {
-              Some(crate::domain::blog_domain::blog_post_entity::rich_text_vo::RichTextStylesVO {
-                bold: rich_text_dto.styles.bold,
-                inline_code: rich_text_dto.styles.inline_code,
-              })
-            } else {
-              None
-            },
-            rich_text_dto.link.map(|link_dto| crate::domain::blog_domain::blog_post_entity::rich_text_vo::LinkVO { url: link_dto.url }),
-          )
-        })
-        .collect();
+      let rich_text_parts: Vec<RichTextPartVO> = text.into_iter().map(convert_rich_text_dto_to_part).collect::<Result<_>>()?;
       ContentEntity::paragraph(id, RichTextVO::new(rich_text_parts))
     }
-    CreateContentDTO::Image { id, path } => {
+    CreateContentDTO::Image { id, path, presentation } => {
       let image_entity = ImageEntity::new(id, path.clone());
-      let image_content = ImageContentEntity::new(id, image_entity);
+      let presentation = ImagePresentationVO::new(presentation.alt, presentation.caption, presentation.width, presentation.link_url)?;
+      let image_content = ImageContentEntity::new(id, image_entity).with_presentation(presentation);
       ContentEntity::image_from_entity(image_content)
     }
-    CreateContentDTO::CodeBlock { id, title, code, language } => ContentEntity::code_block(id, title, code, language),
+    CreateContentDTO::CodeBlock {
+      id,
+      title,
+      code,
+      language,
+      options,
+    } => {
+      let options = CodeBlockOptionsVO::new(
+        options.highlight_lines.as_deref().unwrap_or_default(),
+        options.start_line.unwrap_or(1),
+        options.diff,
+        options.show_line_numbers,
+      )?;
+      ContentEntity::code_block(id, title, code, language, options)?
+    }
+    CreateContentDTO::Math { id, source } => ContentEntity::math_block(id, source)?,
+    CreateContentDTO::Embed { id, url } => {
+      let resolved = EmbedProviderRegistry::default().resolve(&url)?;
+      ContentEntity::embed(id, resolved.provider, resolved.resource_id)?
+    }
+    CreateContentDTO::LinkCard { id, url } => {
+      let metadata = link_card_metadata.get(&id).cloned().unwrap_or_default();
+      ContentEntity::link_card(id, url, metadata)?
+    }
+    CreateContentDTO::Details { id, summary, children } => {
+      let children = children.into_iter().map(|child| convert_content_dto_to_entity(child, link_card_metadata)).collect::<Result<Vec<_>>>()?;
+      ContentEntity::details(id, summary, children)?
+    }
+    CreateContentDTO::Diagram { id, kind, source } => ContentEntity::diagram(id, convert_diagram_kind_dto_to_domain(kind), source)?,
   };
   Ok(content)
 }
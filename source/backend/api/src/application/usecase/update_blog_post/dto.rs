@@ -1,6 +1,6 @@
 use chrono::NaiveDate;
 
-use crate::application::usecase::create_blog_post::dto::{CreateContentDTO, CreateImageDTO};
+use crate::application::usecase::create_blog_post::dto::{CreateContentDTO, CreateFootnoteDTO, CreateImageDTO, CreateSeoMetadataDTO};
 
 #[derive(Debug, Clone)]
 pub struct UpdateBlogPostDTO {
@@ -8,4 +8,7 @@ pub struct UpdateBlogPostDTO {
   pub thumbnail: CreateImageDTO,
   pub published_date: NaiveDate,
   pub contents: Vec<CreateContentDTO>,
+  pub footnotes: Vec<CreateFootnoteDTO>,
+  pub seo_metadata: CreateSeoMetadataDTO,
+  pub manual_excerpt: Option<String>,
 }
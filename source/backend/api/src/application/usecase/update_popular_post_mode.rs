@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use crate::application::dto::PopularPostModeDTO;
+use crate::application::dto_mapper;
+use crate::domain::blog_domain::popular_post_setting_repository::{PopularPostMode, PopularPostSettingRepository};
+
+/// 人気記事の選び方を切り替えるユースケース
+///
+/// 自動で選ぶ設定に切り替えても、人気記事は次に閲覧数から選び直すまで変わらない。
+/// 自動で選ぶ設定のまま人気記事を手動で選んだ場合も、次に選び直すまではその記事を表示する
+pub struct UpdatePopularPostModeUseCase {
+  setting_repository: Arc<dyn PopularPostSettingRepository>,
+}
+
+impl UpdatePopularPostModeUseCase {
+  pub fn new(setting_repository: Arc<dyn PopularPostSettingRepository>) -> Self {
+    Self { setting_repository }
+  }
+
+  pub async fn execute(&self, mode: PopularPostModeDTO) -> anyhow::Result<PopularPostModeDTO> {
+    let mode = match mode {
+      PopularPostModeDTO::Manual => PopularPostMode::Manual,
+      PopularPostModeDTO::Automatic => PopularPostMode::Automatic,
+    };
+    self.setting_repository.save_mode(mode).await?;
+
+    let saved_mode = self.setting_repository.find_mode().await?;
+    Ok(dto_mapper::convert_popular_post_mode(saved_mode))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use mockall::{mock, predicate::eq};
+
+  mock! {
+    SettingRepo {}
+
+    #[async_trait::async_trait]
+    impl PopularPostSettingRepository for SettingRepo {
+      async fn find_mode(&self) -> anyhow::Result<PopularPostMode>;
+      async fn save_mode(&self, mode: PopularPostMode) -> anyhow::Result<()>;
+    }
+  }
+
+  #[tokio::test]
+  async fn saves_mode() {
+    let mut mock_setting_repository = MockSettingRepo::new();
+    mock_setting_repository.expect_save_mode().with(eq(PopularPostMode::Automatic)).times(1).returning(|_| Ok(()));
+    mock_setting_repository.expect_find_mode().times(1).returning(|| Ok(PopularPostMode::Automatic));
+
+    let mode = UpdatePopularPostModeUseCase::new(Arc::new(mock_setting_repository)).execute(PopularPostModeDTO::Automatic).await.unwrap();
+
+    assert_eq!(mode, PopularPostModeDTO::Automatic);
+  }
+}
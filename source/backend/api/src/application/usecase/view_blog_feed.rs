@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use crate::application::dto::{BlogFeedDTO, BlogFeedFormatDTO};
+use crate::application::services::latest_blog_posts_service::LatestBlogPostsService;
+use crate::domain::blog_domain::blog_post_repository::BlogPostRepository;
+use crate::domain::blog_domain::services::feed_renderer_service::{last_updated, FeedRendererService};
+
+/// フィードに載せる記事数
+const FEED_POST_COUNT: u32 = 20;
+
+pub struct ViewBlogFeedUseCase {
+  latest_blog_posts_service: LatestBlogPostsService,
+  feed_renderer: FeedRendererService,
+}
+
+impl ViewBlogFeedUseCase {
+  pub fn new(repository: Arc<dyn BlogPostRepository>, feed_renderer: FeedRendererService) -> Self {
+    Self {
+      latest_blog_posts_service: LatestBlogPostsService::new(repository),
+      feed_renderer,
+    }
+  }
+
+  pub async fn execute(&self, format: BlogFeedFormatDTO) -> anyhow::Result<BlogFeedDTO> {
+    let blog_posts = self.latest_blog_posts_service.get_published_latest_posts(Some(FEED_POST_COUNT)).await?;
+
+    let xml = match format {
+      BlogFeedFormatDTO::Rss => self.feed_renderer.render_rss(&blog_posts),
+      BlogFeedFormatDTO::Atom => self.feed_renderer.render_atom(&blog_posts),
+    };
+
+    Ok(BlogFeedDTO {
+      xml,
+      last_modified: last_updated(&blog_posts),
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::blog_domain::blog_post_entity::BlogPostEntity;
+  use crate::domain::blog_domain::jst_date_vo::JstDate;
+  use crate::domain::blog_domain::services::feed_renderer_service::FeedChannelVO;
+  use mockall::mock;
+  use uuid::Uuid;
+
+  mock! {
+    BlogPostRepo {}
+
+    #[async_trait::async_trait]
+    impl BlogPostRepository for BlogPostRepo {
+      async fn find(&self, id: &str) -> anyhow::Result<BlogPostEntity>;
+      async fn save(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn update(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn find_latests(&self, quantity: Option<u32>) -> anyhow::Result<Vec<BlogPostEntity>>;
+      async fn find_top_tech_pick(&self) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
+      async fn update_top_tech_pick_post(&self, top_tech_pick: &crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
+      async fn find_pick_up_posts(&self) -> anyhow::Result<crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity>;
+      async fn update_pick_up_posts(&self, pickup_posts: &crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity) -> anyhow::Result<crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity>;
+      async fn find_popular_posts(&self) -> anyhow::Result<crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity>;
+      async fn update_popular_posts(&self, popular_post_set: &crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity) -> anyhow::Result<crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity>;
+      async fn find_all(&self) -> anyhow::Result<Vec<BlogPostEntity>>;
+    }
+  }
+
+  fn feed_renderer() -> FeedRendererService {
+    FeedRendererService::new(
+      FeedChannelVO {
+        title: "鉄火ブログ".to_string(),
+        description: String::new(),
+        site_url: "https://blog.example.com".to_string(),
+      },
+      String::new(),
+    )
+  }
+
+  fn blog_post(title: &str, published_date: JstDate, last_update_date: JstDate) -> BlogPostEntity {
+    let mut blog_post = BlogPostEntity::new(Uuid::new_v4(), title.to_string());
+    blog_post.set_published_date(published_date);
+    blog_post.set_last_update_date(last_update_date);
+    blog_post
+  }
+
+  #[tokio::test]
+  async fn renders_only_published_posts_with_latest_update_as_last_modified() {
+    let mut mock_repository = MockBlogPostRepo::new();
+    mock_repository.expect_find_latests().with(mockall::predicate::eq(Some(FEED_POST_COUNT))).times(1).returning(|_| {
+      Ok(vec![
+        blog_post("公開済み記事", JstDate::new(2024, 1, 1).unwrap(), JstDate::new(2024, 2, 1).unwrap()),
+        blog_post("未公開記事", JstDate::new(3000, 1, 1).unwrap(), JstDate::new(3000, 1, 1).unwrap()),
+      ])
+    });
+    let usecase = ViewBlogFeedUseCase::new(Arc::new(mock_repository), feed_renderer());
+
+    let feed = usecase.execute(BlogFeedFormatDTO::Rss).await.unwrap();
+
+    assert!(feed.xml.contains("<title>公開済み記事</title>"));
+    assert!(!feed.xml.contains("未公開記事"));
+    assert_eq!(feed.last_modified, Some(JstDate::new(2024, 2, 1).unwrap().to_utc_datetime()));
+  }
+
+  #[tokio::test]
+  async fn renders_atom_feed_without_posts() {
+    let mut mock_repository = MockBlogPostRepo::new();
+    mock_repository.expect_find_latests().times(1).returning(|_| Ok(vec![]));
+    let usecase = ViewBlogFeedUseCase::new(Arc::new(mock_repository), feed_renderer());
+
+    let feed = usecase.execute(BlogFeedFormatDTO::Atom).await.unwrap();
+
+    assert!(feed.xml.contains("<feed xmlns=\"http://www.w3.org/2005/Atom\""));
+    assert_eq!(feed.last_modified, None);
+  }
+}
@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use crate::application::dto::{JsonFeedDTO, JsonFeedItemDTO};
+use crate::application::services::latest_blog_posts_service::LatestBlogPostsService;
+use crate::domain::blog_domain::blog_post_repository::BlogPostRepository;
+use crate::domain::blog_domain::services::feed_renderer_service::{last_updated, summarize, to_jst, FeedRendererService, JSON_FEED_PATH};
+use crate::domain::blog_domain::services::html_renderer_service::HtmlRendererService;
+
+/// フィードに載せる記事数（RSS・Atom と揃える）
+const FEED_POST_COUNT: u32 = 20;
+
+/// 新着の公開記事を JSON Feed として取得するユースケース
+pub struct ViewBlogJsonFeedUseCase {
+  latest_blog_posts_service: LatestBlogPostsService,
+  feed_renderer: FeedRendererService,
+  html_renderer: HtmlRendererService,
+}
+
+impl ViewBlogJsonFeedUseCase {
+  pub fn new(repository: Arc<dyn BlogPostRepository>, feed_renderer: FeedRendererService, html_renderer: HtmlRendererService) -> Self {
+    Self {
+      latest_blog_posts_service: LatestBlogPostsService::new(repository),
+      feed_renderer,
+      html_renderer,
+    }
+  }
+
+  pub async fn execute(&self) -> anyhow::Result<JsonFeedDTO> {
+    let blog_posts = self.latest_blog_posts_service.get_published_latest_posts(Some(FEED_POST_COUNT)).await?;
+
+    let items = blog_posts
+      .iter()
+      .map(|blog_post| JsonFeedItemDTO {
+        id: blog_post.get_id(),
+        url: self.feed_renderer.post_url(blog_post),
+        title: blog_post.get_title_text().to_string(),
+        content_html: self.html_renderer.render_contents(blog_post),
+        summary: summarize(blog_post),
+        image: blog_post.get_thumbnail().map(|thumbnail| self.feed_renderer.image_url(thumbnail.get_path())),
+        date_published: to_jst(blog_post.get_published_date().to_utc_datetime()),
+        date_modified: to_jst(blog_post.get_last_update_date().to_utc_datetime()),
+      })
+      .collect();
+
+    let channel = self.feed_renderer.channel();
+    Ok(JsonFeedDTO {
+      title: channel.title.clone(),
+      description: channel.description.clone(),
+      home_page_url: self.feed_renderer.site_url(),
+      feed_url: self.feed_renderer.feed_url(JSON_FEED_PATH),
+      items,
+      last_modified: last_updated(&blog_posts),
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::blog_domain::blog_post_entity::content_entity::ContentEntity;
+  use crate::domain::blog_domain::blog_post_entity::BlogPostEntity;
+  use crate::domain::blog_domain::jst_date_vo::JstDate;
+  use crate::domain::blog_domain::services::feed_renderer_service::FeedChannelVO;
+  use mockall::mock;
+  use uuid::Uuid;
+
+  mock! {
+    BlogPostRepo {}
+
+    #[async_trait::async_trait]
+    impl BlogPostRepository for BlogPostRepo {
+      async fn find(&self, id: &str) -> anyhow::Result<BlogPostEntity>;
+      async fn save(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn update(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn find_latests(&self, quantity: Option<u32>) -> anyhow::Result<Vec<BlogPostEntity>>;
+      async fn find_top_tech_pick(&self) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
+      async fn update_top_tech_pick_post(&self, top_tech_pick: &crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
+      async fn find_pick_up_posts(&self) -> anyhow::Result<crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity>;
+      async fn update_pick_up_posts(&self, pickup_posts: &crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity) -> anyhow::Result<crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity>;
+      async fn find_popular_posts(&self) -> anyhow::Result<crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity>;
+      async fn update_popular_posts(&self, popular_post_set: &crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity) -> anyhow::Result<crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity>;
+      async fn find_all(&self) -> anyhow::Result<Vec<BlogPostEntity>>;
+    }
+  }
+
+  fn usecase(mock_repository: MockBlogPostRepo) -> ViewBlogJsonFeedUseCase {
+    ViewBlogJsonFeedUseCase::new(
+      Arc::new(mock_repository),
+      FeedRendererService::new(
+        FeedChannelVO {
+          title: "鉄火ブログ".to_string(),
+          description: "技術ブログ".to_string(),
+          site_url: "https://blog.example.com".to_string(),
+        },
+        "https://res.example.com".to_string(),
+      ),
+      HtmlRendererService::new("https://res.example.com".to_string()),
+    )
+  }
+
+  fn blog_post(id: Uuid, title: &str, published_date: JstDate) -> BlogPostEntity {
+    let mut blog_post = BlogPostEntity::new(id, title.to_string());
+    blog_post.set_thumbnail(Uuid::new_v4(), "thumbnails/a.png".to_string());
+    blog_post.set_published_date(published_date);
+    blog_post.set_last_update_date(JstDate::new(2024, 4, 5).unwrap());
+    blog_post.add_content(ContentEntity::h2(Uuid::new_v4(), "はじめに".to_string()));
+    blog_post
+  }
+
+  #[tokio::test]
+  async fn builds_items_from_published_posts() {
+    let post_id = Uuid::new_v4();
+    let mut mock_repository = MockBlogPostRepo::new();
+    mock_repository.expect_find_latests().with(mockall::predicate::eq(Some(FEED_POST_COUNT))).times(1).returning(move |_| {
+      Ok(vec![
+        blog_post(post_id, "公開済み記事", JstDate::new(2024, 3, 2).unwrap()),
+        blog_post(Uuid::new_v4(), "未公開記事", JstDate::new(3000, 1, 1).unwrap()),
+      ])
+    });
+
+    let feed = usecase(mock_repository).execute().await.unwrap();
+
+    assert_eq!(feed.home_page_url, "https://blog.example.com/");
+    assert_eq!(feed.feed_url, "https://blog.example.com/api/blog/feed.json");
+    assert_eq!(feed.items.len(), 1);
+    let item = &feed.items[0];
+    assert_eq!(item.id, post_id);
+    assert_eq!(item.url, format!("https://blog.example.com/posts/{}", post_id));
+    assert!(item.content_html.contains("はじめに</h2>"));
+    assert_eq!(item.image.as_deref(), Some("https://res.example.com/thumbnails/a.png"));
+    assert_eq!(item.date_published.to_rfc3339(), "2024-03-02T00:00:00+09:00");
+    assert_eq!(item.date_modified.to_rfc3339(), "2024-04-05T00:00:00+09:00");
+    assert_eq!(feed.last_modified, Some(JstDate::new(2024, 4, 5).unwrap().to_utc_datetime()));
+  }
+}
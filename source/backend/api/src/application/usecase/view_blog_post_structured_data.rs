@@ -0,0 +1,157 @@
+use std::sync::Arc;
+
+use crate::application::dto::BlogPostingDTO;
+use crate::domain::blog_domain::blog_post_repository::BlogPostRepository;
+use crate::domain::blog_domain::services::feed_renderer_service::{summarize, to_jst, FeedRendererService};
+use crate::domain::blog_domain::services::published_post_viewer_service::PublishedPostViewerService;
+
+/// 公開記事の構造化データ（schema.org の BlogPosting）を取得するユースケース
+///
+/// SEO メタデータが設定されていればそれを優先し、未設定の項目は本文の要約・サムネイル・記事ページの URL で補う
+pub struct ViewBlogPostStructuredDataUseCase {
+  repository: Arc<dyn BlogPostRepository>,
+  feed_renderer: FeedRendererService,
+}
+
+impl ViewBlogPostStructuredDataUseCase {
+  pub fn new(repository: Arc<dyn BlogPostRepository>, feed_renderer: FeedRendererService) -> Self {
+    Self { repository, feed_renderer }
+  }
+
+  pub async fn execute(&self, id: &str) -> anyhow::Result<BlogPostingDTO> {
+    // リポジトリから記事を取得
+    let blog_post = self.repository.find(id).await?;
+
+    // 公開記事閲覧サービスで公開状態をチェック
+    let published_post_viewer = PublishedPostViewerService::new();
+    let blog_post = published_post_viewer.view_published_post(blog_post).map_err(|e| anyhow::anyhow!(e.to_string()))?;
+
+    let seo_metadata = blog_post.get_seo_metadata();
+    let image = seo_metadata.get_og_image().or(blog_post.get_thumbnail()).map(|image| self.feed_renderer.image_url(image.get_path()));
+    let channel = self.feed_renderer.channel();
+
+    Ok(BlogPostingDTO {
+      headline: blog_post.get_title_text().to_string(),
+      description: seo_metadata.get_description().map(str::to_string).unwrap_or_else(|| summarize(&blog_post)),
+      image,
+      url: seo_metadata.get_canonical_url().map(str::to_string).unwrap_or_else(|| self.feed_renderer.post_url(&blog_post)),
+      date_published: to_jst(blog_post.get_published_date().to_utc_datetime()),
+      date_modified: to_jst(blog_post.get_last_update_date().to_utc_datetime()),
+      site_name: channel.title.clone(),
+      site_url: self.feed_renderer.site_url(),
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::blog_domain::blog_post_entity::{
+    content_entity::ContentEntity,
+    paragraph_entity::ParagraphEntity,
+    rich_text_vo::{RichTextPartVO, RichTextVO},
+    seo_metadata_vo::SeoMetadataVO,
+    BlogPostEntity,
+  };
+  use crate::domain::blog_domain::jst_date_vo::JstDate;
+  use crate::domain::blog_domain::services::feed_renderer_service::FeedChannelVO;
+  use crate::domain::image_domain::ImageEntity;
+  use mockall::mock;
+  use uuid::Uuid;
+
+  mock! {
+    BlogPostRepo {}
+
+    #[async_trait::async_trait]
+    impl BlogPostRepository for BlogPostRepo {
+      async fn find(&self, id: &str) -> anyhow::Result<BlogPostEntity>;
+      async fn save(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn update(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn find_latests(&self, quantity: Option<u32>) -> anyhow::Result<Vec<BlogPostEntity>>;
+      async fn find_top_tech_pick(&self) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
+      async fn update_top_tech_pick_post(&self, top_tech_pick: &crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
+      async fn find_pick_up_posts(&self) -> anyhow::Result<crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity>;
+      async fn update_pick_up_posts(&self, pickup_posts: &crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity) -> anyhow::Result<crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity>;
+      async fn find_popular_posts(&self) -> anyhow::Result<crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity>;
+      async fn update_popular_posts(&self, popular_post_set: &crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity) -> anyhow::Result<crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity>;
+      async fn find_all(&self) -> anyhow::Result<Vec<BlogPostEntity>>;
+    }
+  }
+
+  fn usecase(mock_repository: MockBlogPostRepo) -> ViewBlogPostStructuredDataUseCase {
+    ViewBlogPostStructuredDataUseCase::new(
+      Arc::new(mock_repository),
+      FeedRendererService::new(
+        FeedChannelVO {
+          title: "鉄火ブログ".to_string(),
+          description: "技術ブログ".to_string(),
+          site_url: "https://blog.example.com".to_string(),
+        },
+        "https://res.example.com".to_string(),
+      ),
+    )
+  }
+
+  fn blog_post(published_date: JstDate) -> BlogPostEntity {
+    let mut blog_post = BlogPostEntity::new(Uuid::from_u128(1), "構造化データの記事".to_string());
+    blog_post.set_thumbnail(Uuid::new_v4(), "thumbnails/a.png".to_string());
+    blog_post.set_published_date(published_date);
+    blog_post.set_last_update_date(JstDate::new(2024, 4, 5).unwrap());
+    blog_post.add_content(ContentEntity::Paragraph(ParagraphEntity::new(
+      Uuid::new_v4(),
+      RichTextVO::new(vec![RichTextPartVO::new("本文の書き出し".to_string(), None, None)]),
+    )));
+    blog_post
+  }
+
+  #[tokio::test]
+  async fn falls_back_to_summary_thumbnail_and_post_url() {
+    let mut mock_repository = MockBlogPostRepo::new();
+    mock_repository.expect_find().times(1).returning(|_| Ok(blog_post(JstDate::new(2024, 3, 2).unwrap())));
+
+    let posting = usecase(mock_repository).execute("post-id").await.unwrap();
+
+    assert_eq!(posting.headline, "構造化データの記事");
+    assert_eq!(posting.description, "本文の書き出し");
+    assert_eq!(posting.image.as_deref(), Some("https://res.example.com/thumbnails/a.png"));
+    assert_eq!(posting.url, format!("https://blog.example.com/posts/{}", Uuid::from_u128(1)));
+    assert_eq!(posting.date_published.to_rfc3339(), "2024-03-02T00:00:00+09:00");
+    assert_eq!(posting.date_modified.to_rfc3339(), "2024-04-05T00:00:00+09:00");
+    assert_eq!(posting.site_name, "鉄火ブログ");
+    assert_eq!(posting.site_url, "https://blog.example.com/");
+  }
+
+  #[tokio::test]
+  async fn prefers_seo_metadata() {
+    let mut mock_repository = MockBlogPostRepo::new();
+    mock_repository.expect_find().times(1).returning(|_| {
+      let mut blog_post = blog_post(JstDate::new(2024, 3, 2).unwrap());
+      blog_post.set_seo_metadata(
+        SeoMetadataVO::new(
+          Some("検索結果向けの説明".to_string()),
+          Some(ImageEntity::new(Uuid::new_v4(), "ogp/a.png".to_string())),
+          Some("https://example.com/original".to_string()),
+          false,
+        )
+        .unwrap(),
+      );
+      Ok(blog_post)
+    });
+
+    let posting = usecase(mock_repository).execute("post-id").await.unwrap();
+
+    assert_eq!(posting.description, "検索結果向けの説明");
+    assert_eq!(posting.image.as_deref(), Some("https://res.example.com/ogp/a.png"));
+    assert_eq!(posting.url, "https://example.com/original");
+  }
+
+  #[tokio::test]
+  async fn returns_error_for_unpublished_post() {
+    let mut mock_repository = MockBlogPostRepo::new();
+    mock_repository.expect_find().times(1).returning(|_| Ok(blog_post(JstDate::new(3000, 12, 31).unwrap())));
+
+    let result = usecase(mock_repository).execute("post-id").await;
+
+    assert!(result.unwrap_err().to_string().contains("未公開記事「構造化データの記事」にアクセスすることはできません"));
+  }
+}
@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use crate::application::dto::TocItemDTO;
+use crate::domain::blog_domain::blog_post_repository::BlogPostRepository;
+use crate::domain::blog_domain::services::published_post_viewer_service::PublishedPostViewerService;
+use crate::domain::blog_domain::services::table_of_contents_service::{TableOfContentsService, TocItemVO};
+
+/// 公開記事の目次を取得するユースケース
+pub struct ViewBlogPostTocUseCase {
+  repository: Arc<dyn BlogPostRepository>,
+}
+
+impl ViewBlogPostTocUseCase {
+  pub fn new(repository: Arc<dyn BlogPostRepository>) -> Self {
+    Self { repository }
+  }
+
+  pub async fn execute(&self, id: &str) -> anyhow::Result<Vec<TocItemDTO>> {
+    // リポジトリから記事を取得
+    let blog_post = self.repository.find(id).await?;
+
+    // 公開記事閲覧サービスで公開状態をチェック
+    let published_post_viewer = PublishedPostViewerService::new();
+    let blog_post = published_post_viewer.view_published_post(blog_post).map_err(|e| anyhow::anyhow!(e.to_string()))?;
+
+    let toc = TableOfContentsService::new().build(blog_post.get_contents());
+    Ok(toc.into_iter().map(convert_toc_item_to_dto).collect())
+  }
+}
+
+fn convert_toc_item_to_dto(item: TocItemVO) -> TocItemDTO {
+  TocItemDTO {
+    id: item.id,
+    text: item.text,
+    anchor: item.anchor,
+    level: item.level,
+    children: item.children.into_iter().map(convert_toc_item_to_dto).collect(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::blog_domain::blog_post_entity::{content_entity::ContentEntity, BlogPostEntity};
+  use crate::domain::blog_domain::jst_date_vo::JstDate;
+  use mockall::mock;
+  use uuid::Uuid;
+
+  mock! {
+    BlogPostRepo {}
+
+    #[async_trait::async_trait]
+    impl BlogPostRepository for BlogPostRepo {
+      async fn find(&self, id: &str) -> anyhow::Result<BlogPostEntity>;
+      async fn save(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn update(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn find_latests(&self, quantity: Option<u32>) -> anyhow::Result<Vec<BlogPostEntity>>;
+      async fn find_top_tech_pick(&self) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
+      async fn update_top_tech_pick_post(&self, top_tech_pick: &crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
+      async fn find_pick_up_posts(&self) -> anyhow::Result<crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity>;
+      async fn update_pick_up_posts(&self, pickup_posts: &crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity) -> anyhow::Result<crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity>;
+      async fn find_popular_posts(&self) -> anyhow::Result<crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity>;
+      async fn update_popular_posts(&self, popular_post_set: &crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity) -> anyhow::Result<crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity>;
+      async fn find_all(&self) -> anyhow::Result<Vec<BlogPostEntity>>;
+    }
+  }
+
+  fn blog_post(published_date: JstDate) -> BlogPostEntity {
+    let mut blog_post = BlogPostEntity::new(Uuid::new_v4(), "目次のある記事".to_string());
+    blog_post.set_published_date(published_date);
+    blog_post.add_content(ContentEntity::h2(Uuid::from_u128(1), "概要".to_string()));
+    blog_post.add_content(ContentEntity::h3(Uuid::from_u128(2), "背景".to_string()));
+    blog_post
+  }
+
+  #[tokio::test]
+  async fn returns_toc_of_published_post() {
+    let mut mock_repository = MockBlogPostRepo::new();
+    mock_repository.expect_find().times(1).returning(|_| Ok(blog_post(JstDate::new(2024, 3, 2).unwrap())));
+
+    let toc = ViewBlogPostTocUseCase::new(Arc::new(mock_repository)).execute("post-id").await.unwrap();
+
+    assert_eq!(toc.len(), 1);
+    assert_eq!(toc[0].id, Uuid::from_u128(1));
+    assert_eq!(toc[0].anchor, "概要-00000000");
+    assert_eq!(toc[0].children[0].text, "背景");
+    assert_eq!(toc[0].children[0].level, 3);
+  }
+
+  #[tokio::test]
+  async fn returns_error_for_unpublished_post() {
+    let mut mock_repository = MockBlogPostRepo::new();
+    mock_repository.expect_find().times(1).returning(|_| Ok(blog_post(JstDate::new(3000, 12, 31).unwrap())));
+
+    let result = ViewBlogPostTocUseCase::new(Arc::new(mock_repository)).execute("post-id").await;
+
+    assert!(result.unwrap_err().to_string().contains("未公開記事「目次のある記事」にアクセスすることはできません"));
+  }
+}
@@ -1,6 +1,8 @@
 use chrono::NaiveDate;
 use uuid::Uuid;
 
+use crate::application::dto::{DiagramKindDTO, EmbedProviderDTO, HighlightColorDTO, PostDigestDTO, SeoMetadataDTO};
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ViewLatestBlogPostsDTO {
   pub blog_posts: Vec<ViewLatestBlogPostItemDTO>,
@@ -14,14 +16,19 @@ pub struct ViewLatestBlogPostItemDTO {
   pub post_date: NaiveDate,
   pub last_update_date: NaiveDate,
   pub contents: Vec<ViewLatestBlogPostContentDTO>,
+  pub footnotes: Vec<ViewLatestBlogPostFootnoteDTO>,
   pub published_date: NaiveDate,
   pub is_public: bool,
+  pub seo_metadata: SeoMetadataDTO,
+  pub digest: PostDigestDTO,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ViewLatestBlogPostImageDTO {
   pub id: Uuid,
   pub path: String,
+  pub file_name: Option<String>,
+  pub caption: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -31,6 +38,11 @@ pub enum ViewLatestBlogPostContentDTO {
   Paragraph(ViewLatestBlogPostParagraphBlockDTO),
   Image(ViewLatestBlogPostImageBlockDTO),
   Code(ViewLatestBlogPostCodeBlockDTO),
+  Math(ViewLatestBlogPostMathBlockDTO),
+  Embed(ViewLatestBlogPostEmbedBlockDTO),
+  LinkCard(ViewLatestBlogPostLinkCardBlockDTO),
+  Details(ViewLatestBlogPostDetailsBlockDTO),
+  Diagram(ViewLatestBlogPostDiagramBlockDTO),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -51,28 +63,58 @@ pub struct ViewLatestBlogPostParagraphBlockDTO {
   pub text: Vec<ViewLatestBlogPostRichTextDTO>,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct ViewLatestBlogPostDetailsBlockDTO {
+  pub id: Uuid,
+  pub summary: String,
+  pub children: Vec<ViewLatestBlogPostContentDTO>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ViewLatestBlogPostRichTextDTO {
   pub text: String,
   pub styles: ViewLatestBlogPostStyleDTO,
   pub link: Option<ViewLatestBlogPostLinkDTO>,
+  pub ruby: Option<String>,
+  pub footnote_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ViewLatestBlogPostFootnoteDTO {
+  pub id: Uuid,
+  pub number: u32,
+  pub text: Vec<ViewLatestBlogPostRichTextDTO>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ViewLatestBlogPostStyleDTO {
   pub bold: bool,
   pub inline_code: bool,
+  pub inline_math: bool,
+  pub italic: bool,
+  pub strikethrough: bool,
+  pub underline: bool,
+  pub highlight: Option<HighlightColorDTO>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ViewLatestBlogPostLinkDTO {
+  /// 内部リンクの場合は解決済みの URL
   pub url: String,
+  /// 内部リンクのリンク先の記事 ID
+  pub post_id: Option<Uuid>,
+  /// 内部リンクのリンク先の見出し ID
+  pub heading_id: Option<Uuid>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ViewLatestBlogPostImageBlockDTO {
   pub id: Uuid,
   pub path: String,
+  pub alt: String,
+  pub caption: Option<String>,
+  pub width: Option<u32>,
+  pub link_url: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -81,4 +123,39 @@ pub struct ViewLatestBlogPostCodeBlockDTO {
   pub title: String,
   pub code: String,
   pub language: String,
+  pub highlight_lines: Option<String>,
+  pub start_line: u32,
+  pub diff: bool,
+  pub show_line_numbers: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ViewLatestBlogPostMathBlockDTO {
+  pub id: Uuid,
+  pub source: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ViewLatestBlogPostEmbedBlockDTO {
+  pub id: Uuid,
+  pub provider: EmbedProviderDTO,
+  pub resource_id: String,
+  pub url: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ViewLatestBlogPostDiagramBlockDTO {
+  pub id: Uuid,
+  pub kind: DiagramKindDTO,
+  pub source: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ViewLatestBlogPostLinkCardBlockDTO {
+  pub id: Uuid,
+  pub url: String,
+  pub title: Option<String>,
+  pub description: Option<String>,
+  pub site_name: Option<String>,
+  pub image_url: Option<String>,
 }
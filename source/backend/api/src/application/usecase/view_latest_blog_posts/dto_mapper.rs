@@ -1,11 +1,15 @@
 use crate::domain::blog_domain::blog_post_entity::content_entity::ContentEntity;
+use crate::domain::blog_domain::blog_post_entity::rich_text_vo::RichTextVO;
 use crate::domain::blog_domain::blog_post_entity::BlogPostEntity;
 use anyhow::Result;
 
+use crate::application::dto_mapper::{convert_diagram_kind, convert_embed_provider, convert_highlight_color, convert_post_digest, convert_seo_metadata};
+
 use super::dto::{
-  ViewLatestBlogPostCodeBlockDTO, ViewLatestBlogPostContentDTO, ViewLatestBlogPostH2BlockDTO, ViewLatestBlogPostH3BlockDTO, ViewLatestBlogPostImageBlockDTO,
-  ViewLatestBlogPostImageDTO, ViewLatestBlogPostItemDTO, ViewLatestBlogPostLinkDTO, ViewLatestBlogPostParagraphBlockDTO, ViewLatestBlogPostRichTextDTO,
-  ViewLatestBlogPostStyleDTO, ViewLatestBlogPostsDTO,
+  ViewLatestBlogPostCodeBlockDTO, ViewLatestBlogPostContentDTO, ViewLatestBlogPostDetailsBlockDTO, ViewLatestBlogPostDiagramBlockDTO,
+  ViewLatestBlogPostEmbedBlockDTO, ViewLatestBlogPostFootnoteDTO, ViewLatestBlogPostH2BlockDTO, ViewLatestBlogPostH3BlockDTO, ViewLatestBlogPostImageBlockDTO,
+  ViewLatestBlogPostImageDTO, ViewLatestBlogPostItemDTO, ViewLatestBlogPostLinkCardBlockDTO, ViewLatestBlogPostLinkDTO, ViewLatestBlogPostMathBlockDTO,
+  ViewLatestBlogPostParagraphBlockDTO, ViewLatestBlogPostRichTextDTO, ViewLatestBlogPostStyleDTO, ViewLatestBlogPostsDTO,
 };
 
 /// BlogPostEntityのVecからViewLatestBlogPostsDTOに変換する
@@ -27,6 +31,8 @@ fn blog_post_entity_to_view_latest_single_dto(entity: BlogPostEntity) -> Result<
     Some(thumbnail_entity) => ViewLatestBlogPostImageDTO {
       id: thumbnail_entity.get_id(),
       path: thumbnail_entity.get_path().to_string(),
+      file_name: thumbnail_entity.get_file_name().map(str::to_string),
+      caption: thumbnail_entity.get_caption().map(str::to_string),
     },
     None => {
       return Err(anyhow::anyhow!("記事にサムネイル画像が設定されていません"));
@@ -40,6 +46,17 @@ fn blog_post_entity_to_view_latest_single_dto(entity: BlogPostEntity) -> Result<
     contents.push(content);
   }
 
+  // 脚注の変換
+  let footnotes = entity
+    .get_footnotes()
+    .iter()
+    .map(|footnote| ViewLatestBlogPostFootnoteDTO {
+      id: footnote.get_id(),
+      number: footnote.get_number(),
+      text: convert_rich_text_to_view_latest(footnote.get_body()),
+    })
+    .collect();
+
   Ok(ViewLatestBlogPostItemDTO {
     id: entity.get_id().to_string(),
     title: entity.get_title_text().to_string(),
@@ -47,8 +64,11 @@ fn blog_post_entity_to_view_latest_single_dto(entity: BlogPostEntity) -> Result<
     post_date: entity.get_post_date().to_naive_date(),
     last_update_date: entity.get_last_update_date().to_naive_date(),
     contents,
+    footnotes,
     published_date: entity.get_published_date().to_naive_date(),
     is_public: true, // 公開済みの記事のみ取得するため常にtrue
+    seo_metadata: convert_seo_metadata(&entity),
+    digest: convert_post_digest(&entity),
   })
 }
 
@@ -64,25 +84,7 @@ fn convert_content_entity_to_view_latest_content(content_entity: &ContentEntity)
       text: h3_entity.get_value().to_string(),
     })),
     ContentEntity::Paragraph(paragraph_entity) => {
-      let rich_text_vo = paragraph_entity.get_value();
-      let mut text_elements = Vec::new();
-
-      for rich_text_part in rich_text_vo.get_text() {
-        let link = match rich_text_part.get_link() {
-          Some(link_vo) => Some(ViewLatestBlogPostLinkDTO { url: link_vo.url.clone() }),
-          None => None,
-        };
-
-        let rich_text_element = ViewLatestBlogPostRichTextDTO {
-          text: rich_text_part.get_text().to_string(),
-          styles: ViewLatestBlogPostStyleDTO {
-            bold: rich_text_part.get_styles().bold,
-            inline_code: rich_text_part.get_styles().inline_code,
-          },
-          link,
-        };
-        text_elements.push(rich_text_element);
-      }
+      let text_elements = convert_rich_text_to_view_latest(paragraph_entity.get_value());
 
       Ok(ViewLatestBlogPostContentDTO::Paragraph(ViewLatestBlogPostParagraphBlockDTO {
         id: paragraph_entity.get_id(),
@@ -92,19 +94,97 @@ fn convert_content_entity_to_view_latest_content(content_entity: &ContentEntity)
     ContentEntity::Image(image_entity) => Ok(ViewLatestBlogPostContentDTO::Image(ViewLatestBlogPostImageBlockDTO {
       id: image_entity.get_id(),
       path: image_entity.get_path().to_string(),
+      alt: image_entity.get_presentation().get_alt().to_string(),
+      caption: image_entity.get_presentation().get_caption().map(str::to_string),
+      width: image_entity.get_presentation().get_width(),
+      link_url: image_entity.get_presentation().get_link_url().map(str::to_string),
     })),
     ContentEntity::CodeBlock(code_entity) => Ok(ViewLatestBlogPostContentDTO::Code(ViewLatestBlogPostCodeBlockDTO {
       id: code_entity.get_id(),
       title: code_entity.get_title().to_string(),
       code: code_entity.get_code().to_string(),
       language: code_entity.get_language().to_string(),
+      highlight_lines: Some(code_entity.get_options().highlight_lines_to_string()).filter(|lines| !lines.is_empty()),
+      start_line: code_entity.get_options().get_start_line(),
+      diff: code_entity.get_options().is_diff(),
+      show_line_numbers: code_entity.get_options().shows_line_numbers(),
+    })),
+    ContentEntity::Math(math_entity) => Ok(ViewLatestBlogPostContentDTO::Math(ViewLatestBlogPostMathBlockDTO {
+      id: math_entity.get_id(),
+      source: math_entity.get_source().to_string(),
+    })),
+    ContentEntity::Embed(embed_entity) => Ok(ViewLatestBlogPostContentDTO::Embed(ViewLatestBlogPostEmbedBlockDTO {
+      id: embed_entity.get_id(),
+      provider: convert_embed_provider(embed_entity.get_provider()),
+      resource_id: embed_entity.get_resource_id().to_string(),
+      url: embed_entity.get_url(),
+    })),
+    ContentEntity::LinkCard(link_card_entity) => {
+      let metadata = link_card_entity.get_metadata();
+      Ok(ViewLatestBlogPostContentDTO::LinkCard(ViewLatestBlogPostLinkCardBlockDTO {
+        id: link_card_entity.get_id(),
+        url: link_card_entity.get_url().to_string(),
+        title: metadata.title.clone(),
+        description: metadata.description.clone(),
+        site_name: metadata.site_name.clone(),
+        image_url: metadata.image_url.clone(),
+      }))
+    }
+    ContentEntity::Details(details_entity) => {
+      let children = details_entity.get_children().iter().map(convert_content_entity_to_view_latest_content).collect::<Result<Vec<_>>>()?;
+      Ok(ViewLatestBlogPostContentDTO::Details(ViewLatestBlogPostDetailsBlockDTO {
+        id: details_entity.get_id(),
+        summary: details_entity.get_summary().to_string(),
+        children,
+      }))
+    }
+    ContentEntity::Diagram(diagram_entity) => Ok(ViewLatestBlogPostContentDTO::Diagram(ViewLatestBlogPostDiagramBlockDTO {
+      id: diagram_entity.get_id(),
+      kind: convert_diagram_kind(diagram_entity.get_kind()),
+      source: diagram_entity.get_source().to_string(),
     })),
   }
 }
 
+/// RichTextVOをViewLatestBlogPostRichTextDTOのベクターに変換する
+fn convert_rich_text_to_view_latest(rich_text_vo: &RichTextVO) -> Vec<ViewLatestBlogPostRichTextDTO> {
+  let mut text_elements = Vec::new();
+
+  for rich_text_part in rich_text_vo.get_text() {
+    let link = match rich_text_part.get_link() {
+      Some(link_vo) => Some(ViewLatestBlogPostLinkDTO {
+        url: link_vo.resolve_url(),
+        post_id: link_vo.get_internal_target().map(|target| target.get_post_id()),
+        heading_id: link_vo.get_internal_target().and_then(|target| target.get_heading_id()),
+      }),
+      None => None,
+    };
+
+    let rich_text_element = ViewLatestBlogPostRichTextDTO {
+      text: rich_text_part.get_text().to_string(),
+      styles: ViewLatestBlogPostStyleDTO {
+        bold: rich_text_part.get_styles().bold,
+        inline_code: rich_text_part.get_styles().inline_code,
+        inline_math: rich_text_part.is_inline_math(),
+        italic: rich_text_part.get_styles().italic,
+        strikethrough: rich_text_part.get_styles().strikethrough,
+        underline: rich_text_part.get_styles().underline,
+        highlight: rich_text_part.get_styles().highlight.map(convert_highlight_color),
+      },
+      link,
+      ruby: rich_text_part.get_ruby().map(|ruby| ruby.get_value().to_string()),
+      footnote_id: rich_text_part.get_footnote_ref(),
+    };
+    text_elements.push(rich_text_element);
+  }
+
+  text_elements
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::domain::blog_domain::blog_post_entity::code_block_options_vo::CodeBlockOptionsVO;
   use crate::domain::blog_domain::blog_post_entity::content_entity::ContentEntity;
   use crate::domain::blog_domain::blog_post_entity::BlogPostEntity;
   use crate::domain::blog_domain::jst_date_vo::JstDate;
@@ -230,6 +310,10 @@ mod tests {
         Some(RichTextStylesVO {
           bold: false,
           inline_code: false,
+          italic: false,
+          strikethrough: false,
+          underline: false,
+          highlight: None,
         }),
         None,
       ),
@@ -238,6 +322,10 @@ mod tests {
         Some(RichTextStylesVO {
           bold: true,
           inline_code: false,
+          italic: false,
+          strikethrough: false,
+          underline: false,
+          highlight: None,
         }),
         None,
       ),
@@ -246,8 +334,12 @@ mod tests {
         Some(RichTextStylesVO {
           bold: false,
           inline_code: false,
+          italic: false,
+          strikethrough: false,
+          underline: false,
+          highlight: None,
         }),
-        Some(LinkVO {
+        Some(LinkVO::External {
           url: "https://example.com".to_string(),
         }),
       ),
@@ -270,7 +362,9 @@ mod tests {
       "サンプルコード".to_string(),
       "console.log('Hello, World!');".to_string(),
       "javascript".to_string(),
-    );
+      CodeBlockOptionsVO::default(),
+    )
+    .unwrap();
     entity.add_content(code_content);
 
     let entities = vec![entity];
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use crate::application::dto::PopularPostModeDTO;
+use crate::application::dto_mapper;
+use crate::domain::blog_domain::popular_post_setting_repository::PopularPostSettingRepository;
+
+/// 人気記事の選び方（管理者が選ぶか、閲覧数から自動で選ぶか）を取得するユースケース
+pub struct ViewPopularPostModeUseCase {
+  setting_repository: Arc<dyn PopularPostSettingRepository>,
+}
+
+impl ViewPopularPostModeUseCase {
+  pub fn new(setting_repository: Arc<dyn PopularPostSettingRepository>) -> Self {
+    Self { setting_repository }
+  }
+
+  pub async fn execute(&self) -> anyhow::Result<PopularPostModeDTO> {
+    let mode = self.setting_repository.find_mode().await?;
+    Ok(dto_mapper::convert_popular_post_mode(mode))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::blog_domain::popular_post_setting_repository::PopularPostMode;
+  use mockall::mock;
+
+  mock! {
+    SettingRepo {}
+
+    #[async_trait::async_trait]
+    impl PopularPostSettingRepository for SettingRepo {
+      async fn find_mode(&self) -> anyhow::Result<PopularPostMode>;
+      async fn save_mode(&self, mode: PopularPostMode) -> anyhow::Result<()>;
+    }
+  }
+
+  #[tokio::test]
+  async fn returns_saved_mode() {
+    let mut mock_setting_repository = MockSettingRepo::new();
+    mock_setting_repository.expect_find_mode().times(1).returning(|| Ok(PopularPostMode::Automatic));
+
+    let mode = ViewPopularPostModeUseCase::new(Arc::new(mock_setting_repository)).execute().await.unwrap();
+
+    assert_eq!(mode, PopularPostModeDTO::Automatic);
+  }
+}
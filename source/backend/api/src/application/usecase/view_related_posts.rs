@@ -0,0 +1,146 @@
+use std::sync::Arc;
+
+use crate::application::dto::BlogPostDTO;
+use crate::application::dto_mapper;
+use crate::domain::blog_domain::blog_post_repository::BlogPostRepository;
+use crate::domain::blog_domain::jst_date_vo::JstDate;
+use crate::domain::blog_domain::related_post_repository::RelatedPostRepository;
+use crate::domain::blog_domain::services::published_post_viewer_service::PublishedPostViewerService;
+use crate::domain::blog_domain::services::related_posts_service::RelatedPostsService;
+
+/// 公開記事に関連する公開記事を、近い順に取得するユースケース
+pub struct ViewRelatedPostsUseCase {
+  repository: Arc<dyn BlogPostRepository>,
+  related_post_repository: Arc<dyn RelatedPostRepository>,
+}
+
+impl ViewRelatedPostsUseCase {
+  pub fn new(repository: Arc<dyn BlogPostRepository>, related_post_repository: Arc<dyn RelatedPostRepository>) -> Self {
+    Self {
+      repository,
+      related_post_repository,
+    }
+  }
+
+  /// `id` の記事に関連する記事を最大 `limit` 件取得する（記事自身と未公開記事は含めない）
+  pub async fn execute(&self, id: &str, limit: usize) -> anyhow::Result<Vec<BlogPostDTO>> {
+    // リポジトリから記事を取得
+    let blog_post = self.repository.find(id).await?;
+
+    // 公開記事閲覧サービスで公開状態をチェック
+    let published_post_viewer = PublishedPostViewerService::new();
+    let blog_post = published_post_viewer.view_published_post(blog_post).map_err(|e| anyhow::anyhow!(e.to_string()))?;
+
+    // 対象の記事は保存済みの語の出現回数ではなく、取得した記事から求め直す
+    let service = RelatedPostsService::new();
+    let target = service.term_vector(&blog_post);
+    let candidates = self.related_post_repository.find_published_term_vectors(&JstDate::today()).await?;
+
+    let mut dtos = Vec::new();
+    for (post_id, _) in service.rank(&target, &candidates, limit) {
+      let related_post = self.repository.find(&post_id.to_string()).await?;
+      dtos.push(dto_mapper::convert_to_blog_post_dto(related_post));
+    }
+    Ok(dtos)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::blog_domain::blog_post_entity::{
+    content_entity::ContentEntity,
+    paragraph_entity::ParagraphEntity,
+    rich_text_vo::{RichTextPartVO, RichTextVO},
+    BlogPostEntity,
+  };
+  use crate::domain::blog_domain::related_post_repository::PostTermVectorVO;
+  use mockall::mock;
+  use uuid::Uuid;
+
+  mock! {
+    BlogPostRepo {}
+
+    #[async_trait::async_trait]
+    impl BlogPostRepository for BlogPostRepo {
+      async fn find(&self, id: &str) -> anyhow::Result<BlogPostEntity>;
+      async fn save(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn update(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> anyhow::Result<BlogPostEntity>;
+      async fn find_latests(&self, quantity: Option<u32>) -> anyhow::Result<Vec<BlogPostEntity>>;
+      async fn find_top_tech_pick(&self) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
+      async fn update_top_tech_pick_post(&self, top_tech_pick: &crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity) -> anyhow::Result<crate::domain::blog_domain::top_tech_pick_entity::TopTechPickEntity>;
+      async fn find_pick_up_posts(&self) -> anyhow::Result<crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity>;
+      async fn update_pick_up_posts(&self, pickup_posts: &crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity) -> anyhow::Result<crate::domain::blog_domain::pick_up_post_set_entity::PickUpPostSetEntity>;
+      async fn find_popular_posts(&self) -> anyhow::Result<crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity>;
+      async fn update_popular_posts(&self, popular_post_set: &crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity) -> anyhow::Result<crate::domain::blog_domain::popular_post_set_entity::PopularPostSetEntity>;
+      async fn find_all(&self) -> anyhow::Result<Vec<BlogPostEntity>>;
+    }
+  }
+
+  mock! {
+    RelatedPostRepo {}
+
+    #[async_trait::async_trait]
+    impl RelatedPostRepository for RelatedPostRepo {
+      async fn find_published_term_vectors(&self, today: &JstDate) -> anyhow::Result<Vec<PostTermVectorVO>>;
+      async fn save_term_vector(&self, term_vector: &PostTermVectorVO) -> anyhow::Result<()>;
+    }
+  }
+
+  fn blog_post(id: u128, title: &str, published_date: JstDate) -> BlogPostEntity {
+    let mut blog_post = BlogPostEntity::new(Uuid::from_u128(id), title.to_string());
+    blog_post.set_published_date(published_date);
+    blog_post.add_content(ContentEntity::Paragraph(ParagraphEntity::new(
+      Uuid::new_v4(),
+      RichTextVO::new(vec![RichTextPartVO::new(format!("{}について解説します", title), None, None)]),
+    )));
+    blog_post
+  }
+
+  fn published_post(id: u128, title: &str) -> BlogPostEntity {
+    blog_post(id, title, JstDate::new(2024, 3, 2).unwrap())
+  }
+
+  #[tokio::test]
+  async fn returns_related_posts_in_order_of_similarity() {
+    let service = RelatedPostsService::new();
+    let candidates = vec![
+      service.term_vector(&published_post(1, "Rust の所有権")),
+      service.term_vector(&published_post(2, "Go の並行処理")),
+      service.term_vector(&published_post(3, "Rust の所有権と借用")),
+      service.term_vector(&published_post(4, "Rust のマクロ")),
+    ];
+
+    let mut mock_repository = MockBlogPostRepo::new();
+    mock_repository.expect_find().returning(|id| {
+      let post = match Uuid::parse_str(id).unwrap().as_u128() {
+        1 => published_post(1, "Rust の所有権"),
+        3 => published_post(3, "Rust の所有権と借用"),
+        4 => published_post(4, "Rust のマクロ"),
+        _ => return Err(anyhow::anyhow!("BlogPostNotFound: {}", id)),
+      };
+      Ok(post)
+    });
+    let mut mock_related_post_repository = MockRelatedPostRepo::new();
+    mock_related_post_repository.expect_find_published_term_vectors().times(1).returning(move |_| Ok(candidates.clone()));
+
+    let usecase = ViewRelatedPostsUseCase::new(Arc::new(mock_repository), Arc::new(mock_related_post_repository));
+    let dtos = usecase.execute(&Uuid::from_u128(1).to_string(), 2).await.unwrap();
+
+    let ids: Vec<String> = dtos.into_iter().map(|dto| dto.id).collect();
+    assert_eq!(ids, vec![Uuid::from_u128(3).to_string(), Uuid::from_u128(4).to_string()]);
+  }
+
+  #[tokio::test]
+  async fn returns_error_for_unpublished_post() {
+    let mut mock_repository = MockBlogPostRepo::new();
+    mock_repository.expect_find().times(1).returning(|_| Ok(blog_post(1, "下書き", JstDate::new(3000, 12, 31).unwrap())));
+    let mut mock_related_post_repository = MockRelatedPostRepo::new();
+    mock_related_post_repository.expect_find_published_term_vectors().never();
+
+    let usecase = ViewRelatedPostsUseCase::new(Arc::new(mock_repository), Arc::new(mock_related_post_repository));
+    let result = usecase.execute("post-id", 3).await;
+
+    assert!(result.unwrap_err().to_string().contains("未公開記事「下書き」にアクセスすることはできません"));
+  }
+}
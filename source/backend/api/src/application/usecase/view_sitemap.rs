@@ -0,0 +1,138 @@
+use std::sync::Arc;
+
+use crate::domain::blog_domain::jst_date_vo::JstDate;
+use crate::domain::blog_domain::services::sitemap_renderer_service::{SitemapRendererService, MAX_URLS_PER_SITEMAP};
+use crate::domain::blog_domain::sitemap_repository::SitemapRepository;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ViewSitemapError {
+  #[error("サイトマップ {0} は存在しません")]
+  PageNotFound(u64),
+}
+
+/// 公開済みの記事を列挙するサイトマップを取得するユースケース
+pub struct ViewSitemapUseCase {
+  repository: Arc<dyn SitemapRepository>,
+  renderer: SitemapRendererService,
+}
+
+impl ViewSitemapUseCase {
+  pub fn new(repository: Arc<dyn SitemapRepository>, renderer: SitemapRendererService) -> Self {
+    Self { repository, renderer }
+  }
+
+  /// サイトマップを取得する
+  ///
+  /// 記事数が 1 つのサイトマップに収まる場合は記事を列挙し、収まらない場合は分割したサイトマップのインデックスを返す
+  pub async fn execute(&self) -> anyhow::Result<String> {
+    let today = JstDate::today();
+    let post_count = self.repository.count_published_posts(&today).await?;
+
+    let page_count = SitemapRendererService::page_count(post_count);
+    if page_count > 1 {
+      return Ok(self.renderer.render_index(page_count));
+    }
+
+    let entries = self.repository.find_published_post_entries(&today, 0, MAX_URLS_PER_SITEMAP).await?;
+    Ok(self.renderer.render_urlset(&entries))
+  }
+
+  /// 分割したサイトマップのうち `page` 番目（1 始まり）を取得する
+  pub async fn execute_page(&self, page: u64) -> anyhow::Result<String> {
+    let today = JstDate::today();
+    let post_count = self.repository.count_published_posts(&today).await?;
+
+    if page == 0 || page > SitemapRendererService::page_count(post_count) {
+      return Err(ViewSitemapError::PageNotFound(page).into());
+    }
+
+    let offset = (page - 1) * MAX_URLS_PER_SITEMAP;
+    let entries = self.repository.find_published_post_entries(&today, offset, MAX_URLS_PER_SITEMAP).await?;
+    Ok(self.renderer.render_urlset(&entries))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::blog_domain::sitemap_repository::SitemapPostEntryVO;
+  use mockall::{mock, predicate::eq};
+  use uuid::Uuid;
+
+  mock! {
+    SitemapRepo {}
+
+    #[async_trait::async_trait]
+    impl SitemapRepository for SitemapRepo {
+      async fn count_published_posts(&self, today: &JstDate) -> anyhow::Result<u64>;
+      async fn find_published_post_entries(&self, today: &JstDate, offset: u64, limit: u64) -> anyhow::Result<Vec<SitemapPostEntryVO>>;
+    }
+  }
+
+  fn usecase(mock_repository: MockSitemapRepo) -> ViewSitemapUseCase {
+    ViewSitemapUseCase::new(Arc::new(mock_repository), SitemapRendererService::new("https://blog.example.com".to_string()))
+  }
+
+  fn entry() -> SitemapPostEntryVO {
+    SitemapPostEntryVO {
+      id: Uuid::new_v4(),
+      last_update_date: JstDate::new(2024, 4, 5).unwrap(),
+    }
+  }
+
+  #[tokio::test]
+  async fn lists_posts_when_they_fit_in_one_sitemap() {
+    let mut mock_repository = MockSitemapRepo::new();
+    mock_repository.expect_count_published_posts().with(eq(JstDate::today())).times(1).returning(|_| Ok(2));
+    mock_repository
+      .expect_find_published_post_entries()
+      .with(eq(JstDate::today()), eq(0), eq(MAX_URLS_PER_SITEMAP))
+      .times(1)
+      .returning(|_, _, _| Ok(vec![entry(), entry()]));
+
+    let xml = usecase(mock_repository).execute().await.unwrap();
+
+    assert!(xml.contains("<urlset"));
+    assert_eq!(xml.matches("<url>").count(), 2);
+  }
+
+  #[tokio::test]
+  async fn returns_index_when_posts_exceed_one_sitemap() {
+    let mut mock_repository = MockSitemapRepo::new();
+    mock_repository.expect_count_published_posts().times(1).returning(|_| Ok(MAX_URLS_PER_SITEMAP * 2 + 1));
+    mock_repository.expect_find_published_post_entries().never();
+
+    let xml = usecase(mock_repository).execute().await.unwrap();
+
+    assert!(xml.contains("<sitemapindex"));
+    assert!(xml.contains("https://blog.example.com/api/blog/sitemap-3.xml"));
+    assert!(!xml.contains("sitemap-4.xml"));
+  }
+
+  #[tokio::test]
+  async fn fetches_posts_of_requested_page() {
+    let mut mock_repository = MockSitemapRepo::new();
+    mock_repository.expect_count_published_posts().times(1).returning(|_| Ok(MAX_URLS_PER_SITEMAP + 1));
+    mock_repository
+      .expect_find_published_post_entries()
+      .with(eq(JstDate::today()), eq(MAX_URLS_PER_SITEMAP), eq(MAX_URLS_PER_SITEMAP))
+      .times(1)
+      .returning(|_, _, _| Ok(vec![entry()]));
+
+    let xml = usecase(mock_repository).execute_page(2).await.unwrap();
+
+    assert_eq!(xml.matches("<url>").count(), 1);
+  }
+
+  #[tokio::test]
+  async fn rejects_page_out_of_range() {
+    let mut mock_repository = MockSitemapRepo::new();
+    mock_repository.expect_count_published_posts().returning(|_| Ok(1));
+    let usecase = usecase(mock_repository);
+
+    for page in [0, 2] {
+      let err = usecase.execute_page(page).await.unwrap_err();
+      assert!(matches!(err.downcast_ref::<ViewSitemapError>(), Some(ViewSitemapError::PageNotFound(p)) if *p == page));
+    }
+  }
+}
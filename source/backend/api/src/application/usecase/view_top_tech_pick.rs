@@ -39,6 +39,9 @@ impl ViewTopTechPickUseCase {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::domain::blog_domain::blog_post_entity::link_card_block_entity::OgpMetadataVO;
+  use crate::domain::blog_domain::link_card_content_factory::LinkCardContentFactory;
+  use crate::domain::blog_domain::ogp_fetcher::OgpFetcher;
   use crate::domain::blog_domain::{
     blog_post_entity::BlogPostEntity,
     blog_post_factory::{BlogPostFactory, CreateBlogPostInput, CreateImageInput},
@@ -52,14 +55,23 @@ mod tests {
   use std::sync::Arc;
 
   // モックリポジトリの定義
+  mock! {
+    OgpFetcherStub {}
+
+    #[async_trait::async_trait]
+    impl OgpFetcher for OgpFetcherStub {
+      async fn fetch(&self, url: &str) -> anyhow::Result<OgpMetadataVO>;
+    }
+  }
+
   mock! {
       BlogPostRepositoryImpl {}
 
       #[async_trait::async_trait]
       impl BlogPostRepository for BlogPostRepositoryImpl {
           async fn find(&self, id: &str) -> Result<BlogPostEntity>;
-          async fn save(&self, blog_post: &BlogPostEntity) -> Result<BlogPostEntity>;
-          async fn update(&self, blog_post: &BlogPostEntity) -> Result<BlogPostEntity>;
+          async fn save(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> Result<BlogPostEntity>;
+          async fn update(&self, blog_post: &BlogPostEntity, term_vector: &crate::domain::blog_domain::related_post_repository::PostTermVectorVO) -> Result<BlogPostEntity>;
           async fn find_latests(&self, quantity: Option<u32>) -> Result<Vec<BlogPostEntity>>;
           async fn find_top_tech_pick(&self) -> Result<TopTechPickEntity>;
           async fn update_top_tech_pick_post(&self, top_tech_pick: &TopTechPickEntity) -> Result<TopTechPickEntity>;
@@ -120,7 +132,10 @@ mod tests {
     }
 
     let image_factory = Arc::new(ImageContentFactory::new(Arc::new(mock_image_repo)));
-    let factory = Arc::new(BlogPostFactory::new(image_factory));
+    let factory = Arc::new(BlogPostFactory::new(
+      image_factory,
+      Arc::new(LinkCardContentFactory::new(Arc::new(MockOgpFetcherStub::new()))),
+    ));
 
     let input = CreateBlogPostInput {
       title: title.to_string(),
@@ -132,9 +147,12 @@ mod tests {
       last_update_date: None,
       published_date: None,
       contents: vec![],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
     };
 
-    factory.create(input).await.unwrap()
+    factory.create(input).await.unwrap().blog_post
   }
 
   #[tokio::test]
@@ -1,10 +1,21 @@
+pub mod blog_archive_entity;
+pub mod blog_archive_repository;
 pub mod blog_post_entity;
 pub mod blog_post_factory;
 pub mod blog_post_repository;
+pub mod code_highlighter;
+pub mod code_language_registry;
+pub mod embed_provider_registry;
 pub mod image_content_factory;
 pub mod jst_date_vo;
+pub mod link_card_content_factory;
+pub mod ogp_fetcher;
+pub mod page_view_repository;
 pub mod pick_up_post_set_entity;
 pub mod popular_post_set_entity;
+pub mod popular_post_setting_repository;
+pub mod related_post_repository;
+pub mod sitemap_repository;
 pub mod top_tech_pick_entity;
 
 // Errors
@@ -14,7 +25,17 @@ pub mod errors {
 
 // Services
 pub mod services {
+  pub mod feed_renderer_service;
+  pub mod html_renderer_service;
+  pub mod internal_link_checker_service;
+  pub mod markdown_serializer_service;
+  pub mod page_view_service;
+  pub mod popular_post_ranking_service;
+  pub mod post_digest_service;
   pub mod published_post_viewer_service;
+  pub mod related_posts_service;
+  pub mod sitemap_renderer_service;
+  pub mod table_of_contents_service;
 }
 
 // 公開型（必要に応じて後で追加）
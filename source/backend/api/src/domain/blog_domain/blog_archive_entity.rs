@@ -0,0 +1,324 @@
+use std::collections::{HashMap, HashSet};
+
+use uuid::Uuid;
+
+use crate::domain::blog_domain::{
+  blog_post_entity::content_entity::ContentEntity, blog_post_entity::BlogPostEntity, errors::blog_domain_error::BlogDomainError,
+};
+use crate::domain::image_domain::image_entity::ImageEntity;
+
+/// ピックアップ記事・人気記事は 3 件固定で登録される
+const FEATURED_POST_SET_SIZE: usize = 3;
+
+/// トップテックピック・ピックアップ記事・人気記事に設定されている記事 ID
+///
+/// 未設定の場合はトップテックピックが `None`、ピックアップ記事・人気記事が空になる
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FeaturedPostIdsVO {
+  pub top_tech_pick: Option<Uuid>,
+  pub pick_up: Vec<Uuid>,
+  pub popular: Vec<Uuid>,
+}
+
+impl FeaturedPostIdsVO {
+  fn all_ids(&self) -> impl Iterator<Item = &Uuid> {
+    self.top_tech_pick.iter().chain(self.pick_up.iter()).chain(self.popular.iter())
+  }
+}
+
+/// アーカイブの取り込み時に既存データと衝突するレコードの種類
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BlogArchiveConflictKind {
+  /// 同じ ID の記事が既に存在する（取り込むと上書きされる）
+  ExistingPost,
+  /// 同じ ID の画像が既に存在する（取り込むと上書きされる）
+  ExistingImage,
+  /// 同じパスの画像が別の ID で登録されている（取り込めない）
+  ImagePathInUse,
+}
+
+/// アーカイブの取り込み時に既存データと衝突するレコード
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlogArchiveConflictVO {
+  pub kind: BlogArchiveConflictKind,
+  /// アーカイブ内のレコードの ID
+  pub id: Uuid,
+  pub detail: String,
+}
+
+impl BlogArchiveConflictVO {
+  /// 取り込みを中止しなければならない衝突かどうか
+  pub fn is_blocking(&self) -> bool {
+    self.kind == BlogArchiveConflictKind::ImagePathInUse
+  }
+}
+
+/// 全記事・画像・注目記事の設定をまとめたアーカイブ
+///
+/// ビジネスルール:
+/// - 記事・画像の ID と画像のパスはアーカイブ内で重複しない
+/// - 記事のサムネイル・OGP 画像と画像ブロックが参照する画像はアーカイブに含まれる
+/// - 注目記事に設定されている記事はアーカイブに含まれ、ピックアップ記事・人気記事は 0 件または 3 件
+#[derive(Debug)]
+pub struct BlogArchiveEntity {
+  posts: Vec<BlogPostEntity>,
+  images: Vec<ImageEntity>,
+  featured: FeaturedPostIdsVO,
+}
+
+impl BlogArchiveEntity {
+  pub fn new(posts: Vec<BlogPostEntity>, images: Vec<ImageEntity>, featured: FeaturedPostIdsVO) -> Result<Self, BlogDomainError> {
+    let mut image_ids = HashSet::new();
+    let mut image_paths = HashSet::new();
+    for image in &images {
+      if !image_ids.insert(image.get_id()) {
+        return Err(invalid_archive(format!("画像 ID {} が重複しています", image.get_id())));
+      }
+      if !image_paths.insert(image.get_path()) {
+        return Err(invalid_archive(format!("画像のパス {} が重複しています", image.get_path())));
+      }
+    }
+
+    let mut post_ids = HashSet::new();
+    for post in &posts {
+      if !post_ids.insert(post.get_id()) {
+        return Err(invalid_archive(format!("記事 ID {} が重複しています", post.get_id())));
+      }
+
+      let thumbnail = post.get_thumbnail().ok_or_else(|| invalid_archive(format!("記事 {} にサムネイルが設定されていません", post.get_id())))?;
+      if !image_ids.contains(&thumbnail.get_id()) {
+        return Err(invalid_archive(format!(
+          "記事 {} のサムネイル {} がアーカイブに含まれていません",
+          post.get_id(),
+          thumbnail.get_id()
+        )));
+      }
+
+      if let Some(og_image) = post.get_seo_metadata().get_og_image() {
+        if !image_ids.contains(&og_image.get_id()) {
+          return Err(invalid_archive(format!(
+            "記事 {} の OGP 画像 {} がアーカイブに含まれていません",
+            post.get_id(),
+            og_image.get_id()
+          )));
+        }
+      }
+
+      for content in post.flattened_contents() {
+        if let ContentEntity::Image(image_content) = content {
+          if !image_paths.contains(image_content.get_path()) {
+            return Err(invalid_archive(format!(
+              "記事 {} の画像 {} がアーカイブに含まれていません",
+              post.get_id(),
+              image_content.get_path()
+            )));
+          }
+        }
+      }
+    }
+
+    for (name, ids) in [("ピックアップ記事", &featured.pick_up), ("人気記事", &featured.popular)] {
+      if !ids.is_empty() && ids.len() != FEATURED_POST_SET_SIZE {
+        return Err(invalid_archive(format!(
+          "{}は {} 件である必要があります: {} 件",
+          name,
+          FEATURED_POST_SET_SIZE,
+          ids.len()
+        )));
+      }
+    }
+    if let Some(id) = featured.all_ids().find(|id| !post_ids.contains(id)) {
+      return Err(invalid_archive(format!("注目記事に設定された記事 {} がアーカイブに含まれていません", id)));
+    }
+
+    Ok(Self { posts, images, featured })
+  }
+
+  pub fn get_posts(&self) -> &[BlogPostEntity] {
+    &self.posts
+  }
+
+  pub fn get_images(&self) -> &[ImageEntity] {
+    &self.images
+  }
+
+  pub fn get_featured(&self) -> &FeaturedPostIdsVO {
+    &self.featured
+  }
+
+  pub fn post_ids(&self) -> Vec<Uuid> {
+    self.posts.iter().map(BlogPostEntity::get_id).collect()
+  }
+
+  pub fn image_ids(&self) -> Vec<Uuid> {
+    self.images.iter().map(ImageEntity::get_id).collect()
+  }
+
+  pub fn image_paths(&self) -> Vec<String> {
+    self.images.iter().map(|image| image.get_path().to_string()).collect()
+  }
+
+  /// 既存データと突き合わせて衝突するレコードを洗い出す
+  ///
+  /// # Arguments
+  /// * `existing_post_ids` - アーカイブ内の記事のうち既に存在する記事の ID
+  /// * `existing_images` - アーカイブ内の画像と ID またはパスが一致する既存の画像
+  pub fn detect_conflicts(&self, existing_post_ids: &[Uuid], existing_images: &[ImageEntity]) -> Vec<BlogArchiveConflictVO> {
+    let mut conflicts = Vec::new();
+
+    let existing_images_by_id: HashMap<Uuid, &ImageEntity> = existing_images.iter().map(|image| (image.get_id(), image)).collect();
+    let existing_images_by_path: HashMap<&str, &ImageEntity> = existing_images.iter().map(|image| (image.get_path(), image)).collect();
+    for image in &self.images {
+      if let Some(existing) = existing_images_by_path.get(image.get_path()).filter(|existing| existing.get_id() != image.get_id()) {
+        conflicts.push(BlogArchiveConflictVO {
+          kind: BlogArchiveConflictKind::ImagePathInUse,
+          id: image.get_id(),
+          detail: format!("パス {} は画像 {} で使用されています", image.get_path(), existing.get_id()),
+        });
+      } else if existing_images_by_id.contains_key(&image.get_id()) {
+        conflicts.push(BlogArchiveConflictVO {
+          kind: BlogArchiveConflictKind::ExistingImage,
+          id: image.get_id(),
+          detail: format!("画像 {} を上書きします", image.get_path()),
+        });
+      }
+    }
+
+    for post in &self.posts {
+      if existing_post_ids.contains(&post.get_id()) {
+        conflicts.push(BlogArchiveConflictVO {
+          kind: BlogArchiveConflictKind::ExistingPost,
+          id: post.get_id(),
+          detail: format!("記事「{}」を上書きします", post.get_title_text()),
+        });
+      }
+    }
+
+    conflicts
+  }
+}
+
+fn invalid_archive(detail: String) -> BlogDomainError {
+  BlogDomainError::InvalidArchive { detail }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::blog_domain::blog_post_entity::{image_content_entity::ImageContentEntity, seo_metadata_vo::SeoMetadataVO};
+
+  fn image(path: &str) -> ImageEntity {
+    ImageEntity::new(Uuid::new_v4(), path.to_string())
+  }
+
+  fn post_with_thumbnail(thumbnail: &ImageEntity) -> BlogPostEntity {
+    let mut post = BlogPostEntity::new(Uuid::new_v4(), "アーカイブ記事".to_string());
+    post.set_thumbnail(thumbnail.get_id(), thumbnail.get_path().to_string());
+    post
+  }
+
+  fn copy_of(image: &ImageEntity) -> ImageEntity {
+    ImageEntity::new(image.get_id(), image.get_path().to_string())
+  }
+
+  #[test]
+  fn accepts_archive_whose_references_are_all_included() {
+    let thumbnail = image("thumbnails/a.png");
+    let body_image = image("posts/a.png");
+    let mut post = post_with_thumbnail(&thumbnail);
+    post.add_content(ContentEntity::Image(ImageContentEntity::new(Uuid::new_v4(), copy_of(&body_image))));
+    let post_id = post.get_id();
+    let featured = FeaturedPostIdsVO {
+      top_tech_pick: Some(post_id),
+      ..Default::default()
+    };
+
+    let archive = BlogArchiveEntity::new(vec![post], vec![thumbnail, body_image], featured).unwrap();
+
+    assert_eq!(archive.post_ids(), vec![post_id]);
+    assert_eq!(archive.get_featured().top_tech_pick, Some(post_id));
+  }
+
+  #[test]
+  fn rejects_post_whose_thumbnail_is_missing() {
+    let thumbnail = image("thumbnails/a.png");
+    let post = post_with_thumbnail(&thumbnail);
+
+    let result = BlogArchiveEntity::new(vec![post], vec![], FeaturedPostIdsVO::default());
+
+    assert!(matches!(result, Err(BlogDomainError::InvalidArchive { .. })));
+  }
+
+  #[test]
+  fn rejects_post_whose_body_image_is_missing() {
+    let thumbnail = image("thumbnails/a.png");
+    let mut post = post_with_thumbnail(&thumbnail);
+    post.add_content(ContentEntity::Image(ImageContentEntity::new(Uuid::new_v4(), image("posts/missing.png"))));
+
+    let result = BlogArchiveEntity::new(vec![post], vec![thumbnail], FeaturedPostIdsVO::default());
+
+    assert!(matches!(result, Err(BlogDomainError::InvalidArchive { .. })));
+  }
+
+  #[test]
+  fn rejects_post_whose_og_image_is_missing() {
+    let thumbnail = image("thumbnails/a.png");
+    let mut post = post_with_thumbnail(&thumbnail);
+    post.set_seo_metadata(SeoMetadataVO::new(None, Some(image("ogp/missing.png")), None, false).unwrap());
+
+    let result = BlogArchiveEntity::new(vec![post], vec![thumbnail], FeaturedPostIdsVO::default());
+
+    assert!(matches!(result, Err(BlogDomainError::InvalidArchive { .. })));
+  }
+
+  #[test]
+  fn rejects_duplicated_image_paths() {
+    let result = BlogArchiveEntity::new(vec![], vec![image("same.png"), image("same.png")], FeaturedPostIdsVO::default());
+
+    assert!(matches!(result, Err(BlogDomainError::InvalidArchive { .. })));
+  }
+
+  #[test]
+  fn rejects_featured_posts_outside_archive_or_with_wrong_count() {
+    let thumbnail = image("thumbnails/a.png");
+    let post = post_with_thumbnail(&thumbnail);
+    let post_id = post.get_id();
+
+    let unknown = FeaturedPostIdsVO {
+      top_tech_pick: Some(Uuid::new_v4()),
+      ..Default::default()
+    };
+    assert!(BlogArchiveEntity::new(vec![post_with_thumbnail(&thumbnail)], vec![copy_of(&thumbnail)], unknown).is_err());
+
+    let too_few = FeaturedPostIdsVO {
+      pick_up: vec![post_id],
+      ..Default::default()
+    };
+    let result = BlogArchiveEntity::new(vec![post], vec![thumbnail], too_few);
+    assert!(matches!(result, Err(BlogDomainError::InvalidArchive { .. })));
+  }
+
+  #[test]
+  fn detects_overwritten_records_and_image_path_collisions() {
+    let thumbnail = image("thumbnails/a.png");
+    let moved = image("posts/b.png");
+    let post = post_with_thumbnail(&thumbnail);
+    let post_id = post.get_id();
+    let archive = BlogArchiveEntity::new(vec![post], vec![copy_of(&thumbnail), copy_of(&moved)], FeaturedPostIdsVO::default()).unwrap();
+
+    // サムネイルは同じ ID で存在し、posts/b.png は別の ID で登録されている
+    let existing_images = vec![copy_of(&thumbnail), image("posts/b.png")];
+    let conflicts = archive.detect_conflicts(&[post_id], &existing_images);
+
+    let kinds: Vec<(BlogArchiveConflictKind, Uuid)> = conflicts.iter().map(|conflict| (conflict.kind, conflict.id)).collect();
+    assert_eq!(
+      kinds,
+      vec![
+        (BlogArchiveConflictKind::ExistingImage, thumbnail.get_id()),
+        (BlogArchiveConflictKind::ImagePathInUse, moved.get_id()),
+        (BlogArchiveConflictKind::ExistingPost, post_id),
+      ]
+    );
+    assert_eq!(conflicts.iter().filter(|conflict| conflict.is_blocking()).count(), 1);
+  }
+}
@@ -0,0 +1,37 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::blog_domain::blog_archive_entity::{BlogArchiveEntity, FeaturedPostIdsVO};
+use crate::domain::blog_domain::related_post_repository::PostTermVectorVO;
+use crate::domain::image_domain::image_entity::ImageEntity;
+
+/// ブログ全体のアーカイブを書き出し・復元するためのリポジトリのトレイト
+#[async_trait::async_trait]
+pub trait BlogArchiveRepository: Send + Sync {
+  /// トップテックピック・ピックアップ記事・人気記事に設定されている記事 ID を取得する
+  ///
+  /// # Returns
+  /// * `Ok(FeaturedPostIdsVO)` - 未設定のものは空として返す
+  /// * `Err` - データベースエラーの場合
+  async fn find_featured_post_ids(&self) -> Result<FeaturedPostIdsVO>;
+
+  /// 指定した ID のうち既に存在する記事の ID を取得する
+  async fn find_existing_post_ids(&self, ids: &[Uuid]) -> Result<Vec<Uuid>>;
+
+  /// 指定した ID またはパスのいずれかに一致する既存の画像を取得する
+  async fn find_images_by_ids_or_paths(&self, ids: &[Uuid], paths: &[String]) -> Result<Vec<ImageEntity>>;
+
+  /// アーカイブの内容を 1 つのトランザクションで復元する
+  ///
+  /// 同じ ID の画像・記事は上書きし、注目記事はアーカイブに含まれるもので置き換える。
+  /// 同じアーカイブを何度復元しても結果は変わらない
+  ///
+  /// # Arguments
+  /// * `archive` - 復元するアーカイブ
+  /// * `term_vectors` - アーカイブ内の各記事の関連記事用の語の出現回数
+  ///
+  /// # Returns
+  /// * `Ok(())` - 全て復元できた場合
+  /// * `Err` - いずれかの復元に失敗した場合（何も変更されない）
+  async fn restore(&self, archive: &BlogArchiveEntity, term_vectors: &[PostTermVectorVO]) -> Result<()>;
+}
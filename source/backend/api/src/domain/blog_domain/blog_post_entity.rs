@@ -1,27 +1,56 @@
 pub mod code_block_entity;
+pub mod code_block_options_vo;
 pub mod content_entity;
+pub mod details_block_entity;
+pub mod diagram_block_entity;
+pub mod diagram_source_vo;
+pub mod embed_block_entity;
+pub mod footnote_entity;
 pub mod h2_entity;
 pub mod h3_entity;
+pub mod heading_anchor_vo;
 pub mod image_content_entity;
+pub mod image_presentation_vo;
+pub mod latex_vo;
+pub mod link_card_block_entity;
+pub mod math_block_entity;
 pub mod paragraph_entity;
 pub mod rich_text_vo;
+pub mod seo_metadata_vo;
 
 use crate::domain::{
-  blog_domain::{blog_post_entity::content_entity::ContentEntity, jst_date_vo::JstDate},
+  blog_domain::{
+    blog_post_entity::{
+      content_entity::ContentEntity,
+      footnote_entity::FootnoteEntity,
+      link_card_block_entity::LinkCardBlockEntity,
+      rich_text_vo::{InternalLinkTargetVO, LinkVO},
+      seo_metadata_vo::SeoMetadataVO,
+    },
+    errors::blog_domain_error::BlogDomainError,
+    jst_date_vo::JstDate,
+  },
   image_domain::ImageEntity,
 };
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
+/// 手動で設定する抜粋の最大文字数
+pub const MAX_MANUAL_EXCERPT_LENGTH: usize = 200;
+
 // BlogPost aggregate root
 #[derive(Debug)]
 pub struct BlogPostEntity {
   id: Uuid,
   title: String,
   contents: Vec<ContentEntity>,
+  footnotes: Vec<FootnoteEntity>,
   thumbnail: Option<ImageEntity>,
   post_date: JstDate,
   last_update_date: JstDate,
   published_date: JstDate,
+  seo_metadata: SeoMetadataVO,
+  manual_excerpt: Option<String>,
 }
 
 impl BlogPostEntity {
@@ -31,10 +60,13 @@ impl BlogPostEntity {
       id,
       title,
       contents: Vec::new(),
+      footnotes: Vec::new(),
       thumbnail: None,
       post_date: today.clone(),
       last_update_date: today.clone(),
       published_date: today,
+      seo_metadata: SeoMetadataVO::default(),
+      manual_excerpt: None,
     }
   }
 
@@ -51,6 +83,12 @@ impl BlogPostEntity {
     self
   }
 
+  /// 保存済みのファイル名やキャプションを含む画像エンティティをそのままサムネイルに設定する
+  pub fn set_thumbnail_image(&mut self, image: ImageEntity) -> &mut Self {
+    self.thumbnail = Some(image);
+    self
+  }
+
   pub fn get_thumbnail(&self) -> Option<&ImageEntity> {
     self.thumbnail.as_ref()
   }
@@ -64,6 +102,79 @@ impl BlogPostEntity {
     &self.contents
   }
 
+  /// 折りたたみブロックを子コンテンツに展開し、末端のコンテンツを本文の出現順に列挙する
+  pub fn flattened_contents(&self) -> impl Iterator<Item = &ContentEntity> {
+    self.contents.iter().flat_map(|content| match content {
+      ContentEntity::Details(details) => details.get_children().iter(),
+      other => std::slice::from_ref(other).iter(),
+    })
+  }
+
+  fn flattened_contents_mut(&mut self) -> impl Iterator<Item = &mut ContentEntity> {
+    self.contents.iter_mut().flat_map(|content| match content {
+      ContentEntity::Details(details) => details.get_children_mut().iter_mut(),
+      other => std::slice::from_mut(other).iter_mut(),
+    })
+  }
+
+  /// 脚注を設定する
+  ///
+  /// 本文中の脚注参照と脚注の定義が過不足なく対応しているかを検証し、
+  /// 本文中で最初に参照された順に 1 から番号を振り直して並べ替える。
+  /// 本文（コンテンツ）を設定した後に呼び出すこと。
+  pub fn set_footnotes(&mut self, footnotes: Vec<FootnoteEntity>) -> Result<&mut Self, BlogDomainError> {
+    let mut defined_ids = HashSet::new();
+    for footnote in &footnotes {
+      if !defined_ids.insert(footnote.get_id()) {
+        return Err(BlogDomainError::InvalidFootnote {
+          detail: format!("脚注「{}」が重複して定義されています", footnote.get_id()),
+        });
+      }
+    }
+
+    // 本文中で最初に参照された位置をそのまま脚注番号にする
+    let mut numbers: HashMap<Uuid, u32> = HashMap::new();
+    for footnote_id in self.footnote_refs() {
+      if !defined_ids.contains(&footnote_id) {
+        return Err(BlogDomainError::InvalidFootnote {
+          detail: format!("定義されていない脚注「{}」が参照されています", footnote_id),
+        });
+      }
+      let next_number = numbers.len() as u32 + 1;
+      numbers.entry(footnote_id).or_insert(next_number);
+    }
+
+    let mut footnotes = footnotes;
+    for footnote in footnotes.iter_mut() {
+      let number = numbers.get(&footnote.get_id()).copied().ok_or_else(|| BlogDomainError::InvalidFootnote {
+        detail: format!("脚注「{}」が本文から参照されていません", footnote.get_id()),
+      })?;
+      footnote.set_number(number);
+    }
+    footnotes.sort_by_key(FootnoteEntity::get_number);
+
+    self.footnotes = footnotes;
+    Ok(self)
+  }
+
+  /// 脚注番号順に並んだ脚注
+  pub fn get_footnotes(&self) -> &[FootnoteEntity] {
+    &self.footnotes
+  }
+
+  /// 本文中の脚注参照を出現順に列挙する（同じ脚注への参照が複数あればその数だけ含む）
+  fn footnote_refs(&self) -> Vec<Uuid> {
+    self
+      .flattened_contents()
+      .filter_map(|content| match content {
+        ContentEntity::Paragraph(paragraph) => Some(paragraph.get_value().get_text()),
+        _ => None,
+      })
+      .flatten()
+      .filter_map(|part| part.get_footnote_ref())
+      .collect()
+  }
+
   pub fn set_post_date(&mut self, date: JstDate) -> &mut Self {
     self.post_date = date;
     self
@@ -96,6 +207,35 @@ impl BlogPostEntity {
     &self.published_date <= &today
   }
 
+  pub fn set_seo_metadata(&mut self, seo_metadata: SeoMetadataVO) -> &mut Self {
+    self.seo_metadata = seo_metadata;
+    self
+  }
+
+  pub fn get_seo_metadata(&self) -> &SeoMetadataVO {
+    &self.seo_metadata
+  }
+
+  /// 記事一覧に表示する抜粋を手動で設定する
+  ///
+  /// 前後の空白を取り除き、空の場合は未設定（本文から自動で作る）として扱う
+  pub fn set_manual_excerpt(&mut self, manual_excerpt: Option<String>) -> Result<&mut Self, BlogDomainError> {
+    let manual_excerpt = manual_excerpt.map(|excerpt| excerpt.trim().to_string()).filter(|excerpt| !excerpt.is_empty());
+    if let Some(excerpt) = &manual_excerpt {
+      if excerpt.chars().count() > MAX_MANUAL_EXCERPT_LENGTH {
+        return Err(BlogDomainError::InvalidExcerpt {
+          detail: format!("抜粋は {} 文字以内で入力してください", MAX_MANUAL_EXCERPT_LENGTH),
+        });
+      }
+    }
+    self.manual_excerpt = manual_excerpt;
+    Ok(self)
+  }
+
+  pub fn get_manual_excerpt(&self) -> Option<&str> {
+    self.manual_excerpt.as_deref()
+  }
+
   pub fn update_title(&mut self, title: String) -> &mut Self {
     self.title = title;
     self
@@ -105,6 +245,48 @@ impl BlogPostEntity {
     self.contents.clear();
     self
   }
+
+  /// 記事内の内部リンクのリンク先を本文の出現順に列挙する（脚注内のリンクは本文の後に脚注番号順に続ける）
+  pub fn internal_link_targets(&self) -> Vec<InternalLinkTargetVO> {
+    self
+      .flattened_contents()
+      .filter_map(|content| match content {
+        ContentEntity::Paragraph(paragraph) => Some(paragraph.get_value().get_text()),
+        _ => None,
+      })
+      .chain(self.footnotes.iter().map(|footnote| footnote.get_body().get_text()))
+      .flatten()
+      .filter_map(|part| part.get_link().and_then(LinkVO::get_internal_target).cloned())
+      .collect()
+  }
+
+  /// 指定した ID の見出し（H2 / H3）が記事内に存在するかどうか
+  pub fn has_heading(&self, heading_id: Uuid) -> bool {
+    self.contents.iter().any(|content| match content {
+      ContentEntity::H2(h2) => h2.get_id() == heading_id,
+      ContentEntity::H3(h3) => h3.get_id() == heading_id,
+      _ => false,
+    })
+  }
+
+  /// 記事内のリンクカードをコンテンツ ID で検索する
+  ///
+  /// # Arguments
+  /// * `content_id` - リンクカードのコンテンツ ID
+  ///
+  /// # Returns
+  /// * `Result<&mut LinkCardBlockEntity, BlogDomainError>` - 該当するリンクカードがない場合はエラー
+  pub fn find_link_card_mut(&mut self, content_id: Uuid) -> Result<&mut LinkCardBlockEntity, BlogDomainError> {
+    self
+      .flattened_contents_mut()
+      .find_map(|content| match content {
+        ContentEntity::LinkCard(link_card) if link_card.get_id() == content_id => Some(link_card),
+        _ => None,
+      })
+      .ok_or_else(|| BlogDomainError::LinkCardNotFound {
+        content_id: content_id.to_string(),
+      })
+  }
 }
 
 #[cfg(test)]
@@ -212,6 +394,129 @@ mod tests {
     assert_eq!(blog_post.get_contents().len(), 5);
   }
 
+  #[test]
+  fn can_find_link_card_by_content_id() {
+    use crate::domain::blog_domain::blog_post_entity::link_card_block_entity::OgpMetadataVO;
+
+    let mut blog_post = BlogPostEntity::new(Uuid::new_v4(), "記事タイトル".to_string());
+    let link_card_id = Uuid::new_v4();
+    blog_post.add_content(ContentEntity::h2(Uuid::new_v4(), "見出し".to_string()));
+    blog_post.add_content(ContentEntity::link_card(link_card_id, "https://example.com".to_string(), OgpMetadataVO::default()).unwrap());
+
+    let link_card = blog_post.find_link_card_mut(link_card_id).unwrap();
+    assert_eq!(link_card.get_url(), "https://example.com");
+
+    let result = blog_post.find_link_card_mut(Uuid::new_v4());
+    assert!(matches!(result, Err(BlogDomainError::LinkCardNotFound { .. })));
+  }
+
+  #[test]
+  fn footnotes_are_numbered_in_order_of_first_reference() {
+    let first_defined = Uuid::new_v4();
+    let second_defined = Uuid::new_v4();
+    let mut blog_post = BlogPostEntity::new(Uuid::new_v4(), "脚注のある記事".to_string());
+    blog_post.add_content(ContentEntity::paragraph(
+      Uuid::new_v4(),
+      RichTextVO::new(vec![
+        RichTextPartVO::new("後で定義した脚注".to_string(), None, None).with_footnote_ref(second_defined),
+        RichTextPartVO::new("先に定義した脚注".to_string(), None, None).with_footnote_ref(first_defined),
+        RichTextPartVO::new("再度参照".to_string(), None, None).with_footnote_ref(second_defined),
+      ]),
+    ));
+
+    let footnote = |id: Uuid, text: &str| FootnoteEntity::new(id, RichTextVO::new(vec![RichTextPartVO::new(text.to_string(), None, None)])).unwrap();
+    blog_post.set_footnotes(vec![footnote(first_defined, "脚注A"), footnote(second_defined, "脚注B")]).unwrap();
+
+    let footnotes = blog_post.get_footnotes();
+    assert_eq!(footnotes.len(), 2);
+    assert_eq!((footnotes[0].get_id(), footnotes[0].get_number()), (second_defined, 1));
+    assert_eq!((footnotes[1].get_id(), footnotes[1].get_number()), (first_defined, 2));
+  }
+
+  #[test]
+  fn footnote_references_and_definitions_must_match() {
+    let referenced = Uuid::new_v4();
+    let mut blog_post = BlogPostEntity::new(Uuid::new_v4(), "脚注のある記事".to_string());
+    blog_post.add_content(ContentEntity::paragraph(
+      Uuid::new_v4(),
+      RichTextVO::new(vec![RichTextPartVO::new("本文".to_string(), None, None).with_footnote_ref(referenced)]),
+    ));
+    let footnote = |id: Uuid| FootnoteEntity::new(id, RichTextVO::new(vec![RichTextPartVO::new("脚注".to_string(), None, None)])).unwrap();
+
+    // 参照されている脚注が定義されていない
+    let result = blog_post.set_footnotes(vec![]);
+    assert!(matches!(result, Err(BlogDomainError::InvalidFootnote { .. })));
+
+    // 定義された脚注が参照されていない
+    let result = blog_post.set_footnotes(vec![footnote(referenced), footnote(Uuid::new_v4())]);
+    assert!(matches!(result, Err(BlogDomainError::InvalidFootnote { .. })));
+
+    // 同じ脚注が重複して定義されている
+    let result = blog_post.set_footnotes(vec![footnote(referenced), footnote(referenced)]);
+    assert!(matches!(result, Err(BlogDomainError::InvalidFootnote { .. })));
+  }
+
+  #[test]
+  fn contents_inside_details_block_are_flattened_in_document_order() {
+    let referenced = Uuid::new_v4();
+    let mut blog_post = BlogPostEntity::new(Uuid::new_v4(), "折りたたみのある記事".to_string());
+    let paragraph = |text: &str| ContentEntity::paragraph(Uuid::new_v4(), RichTextVO::new(vec![RichTextPartVO::new(text.to_string(), None, None)]));
+    blog_post.add_content(paragraph("前"));
+    blog_post.add_content(
+      ContentEntity::details(
+        Uuid::new_v4(),
+        "補足".to_string(),
+        vec![ContentEntity::paragraph(
+          Uuid::new_v4(),
+          RichTextVO::new(vec![RichTextPartVO::new("中".to_string(), None, None).with_footnote_ref(referenced)]),
+        )],
+      )
+      .unwrap(),
+    );
+    blog_post.add_content(paragraph("後"));
+
+    let texts: Vec<&str> = blog_post
+      .flattened_contents()
+      .map(|content| match content {
+        ContentEntity::Paragraph(paragraph) => paragraph.get_value().get_text()[0].get_text(),
+        _ => panic!("期待されるコンテンツタイプはParagraphです"),
+      })
+      .collect();
+    assert_eq!(texts, vec!["前", "中", "後"]);
+
+    // 折りたたみブロック内の脚注参照も脚注の定義と突き合わせられる
+    let footnote = FootnoteEntity::new(referenced, RichTextVO::new(vec![RichTextPartVO::new("脚注".to_string(), None, None)])).unwrap();
+    assert!(blog_post.set_footnotes(vec![footnote]).is_ok());
+  }
+
+  #[test]
+  fn internal_link_targets_include_links_in_footnotes() {
+    use crate::domain::blog_domain::blog_post_entity::rich_text_vo::{InternalLinkTargetVO, LinkVO};
+
+    let footnote_id = Uuid::new_v4();
+    let in_body = InternalLinkTargetVO::new(Uuid::new_v4(), None);
+    let in_footnote = InternalLinkTargetVO::new(Uuid::new_v4(), Some(Uuid::new_v4()));
+    let mut blog_post = BlogPostEntity::new(Uuid::new_v4(), "脚注にリンクのある記事".to_string());
+    blog_post.add_content(ContentEntity::paragraph(
+      Uuid::new_v4(),
+      RichTextVO::new(vec![
+        RichTextPartVO::new("本文".to_string(), None, Some(LinkVO::Internal(in_body.clone()))).with_footnote_ref(footnote_id)
+      ]),
+    ));
+    let footnote = FootnoteEntity::new(
+      footnote_id,
+      RichTextVO::new(vec![RichTextPartVO::new(
+        "関連記事".to_string(),
+        None,
+        Some(LinkVO::Internal(in_footnote.clone())),
+      )]),
+    )
+    .unwrap();
+    blog_post.set_footnotes(vec![footnote]).unwrap();
+
+    assert_eq!(blog_post.internal_link_targets(), vec![in_body, in_footnote]);
+  }
+
   #[test]
   fn can_create_empty_blog_post() {
     let id = Uuid::new_v4();
@@ -292,4 +597,18 @@ mod tests {
 
     assert_eq!(blog_post.get_published_date(), &published_date);
   }
+
+  #[test]
+  fn manual_excerpt_is_trimmed_and_limited() {
+    let mut blog_post = BlogPostEntity::new(Uuid::new_v4(), "抜粋設定記事".to_string());
+
+    blog_post.set_manual_excerpt(Some("  手動の抜粋  ".to_string())).unwrap();
+    assert_eq!(blog_post.get_manual_excerpt(), Some("手動の抜粋"));
+
+    blog_post.set_manual_excerpt(Some("   ".to_string())).unwrap();
+    assert_eq!(blog_post.get_manual_excerpt(), None);
+
+    let result = blog_post.set_manual_excerpt(Some("あ".repeat(MAX_MANUAL_EXCERPT_LENGTH + 1)));
+    assert!(matches!(result, Err(BlogDomainError::InvalidExcerpt { .. })));
+  }
 }
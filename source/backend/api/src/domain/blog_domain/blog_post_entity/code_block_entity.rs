@@ -1,16 +1,49 @@
 use uuid::Uuid;
 
+use super::code_block_options_vo::CodeBlockOptionsVO;
+use crate::domain::blog_domain::code_language_registry::CodeLanguageRegistry;
+use crate::domain::blog_domain::errors::blog_domain_error::BlogDomainError;
+
 #[derive(Debug)]
 pub struct CodeBlockEntity {
   id: Uuid,
   title: String,
   code: String,
   language: String,
+  options: CodeBlockOptionsVO,
 }
 
 impl CodeBlockEntity {
-  pub fn new(id: Uuid, title: String, code: String, language: String) -> Self {
-    Self { id, title, code, language }
+  /// 新しいコードブロックを作成する
+  ///
+  /// # Returns
+  /// * `Result<Self, BlogDomainError>` - 対応していない言語の場合はエラー
+  pub fn new(id: Uuid, title: String, code: String, language: String) -> Result<Self, BlogDomainError> {
+    CodeLanguageRegistry::default().validate(&language)?;
+    Ok(Self::restore(id, title, code, language))
+  }
+
+  /// 保存済みのコードブロックを復元する
+  ///
+  /// 対応する言語を後から減らしても既存の記事を読み込めるよう、言語は検証しない
+  pub fn restore(id: Uuid, title: String, code: String, language: String) -> Self {
+    Self {
+      id,
+      title,
+      code,
+      language,
+      options: CodeBlockOptionsVO::default(),
+    }
+  }
+
+  /// 表示オプションを設定する
+  ///
+  /// # Returns
+  /// * `Result<Self, BlogDomainError>` - 強調表示する行がコードの行数を超えている場合はエラー
+  pub fn with_options(mut self, options: CodeBlockOptionsVO) -> Result<Self, BlogDomainError> {
+    options.validate_against_line_count(self.code.lines().count())?;
+    self.options = options;
+    Ok(self)
   }
 
   pub fn get_id(&self) -> Uuid {
@@ -28,4 +61,52 @@ impl CodeBlockEntity {
   pub fn get_language(&self) -> &str {
     &self.language
   }
+
+  pub fn get_options(&self) -> &CodeBlockOptionsVO {
+    &self.options
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn code_block_has_default_options() {
+    let code_block = CodeBlockEntity::new(Uuid::new_v4(), "main.rs".to_string(), "fn main() {}".to_string(), "rust".to_string()).unwrap();
+
+    assert_eq!(code_block.get_options(), &CodeBlockOptionsVO::default());
+  }
+
+  #[test]
+  fn can_set_options_within_line_count() {
+    let options = CodeBlockOptionsVO::new("2", 1, true, true).unwrap();
+    let code_block =
+      CodeBlockEntity::new(Uuid::new_v4(), "diff".to_string(), "-old\n+new".to_string(), "rust".to_string()).unwrap().with_options(options).unwrap();
+
+    assert!(code_block.get_options().is_diff());
+    assert_eq!(code_block.get_options().highlight_lines_to_string(), "2");
+  }
+
+  #[test]
+  fn unsupported_language_is_rejected() {
+    let result = CodeBlockEntity::new(Uuid::new_v4(), "main.zig".to_string(), "const x = 1;".to_string(), "zig".to_string());
+
+    assert!(matches!(result, Err(BlogDomainError::InvalidCodeBlock { .. })));
+  }
+
+  #[test]
+  fn stored_code_block_with_unsupported_language_can_be_restored() {
+    let code_block = CodeBlockEntity::restore(Uuid::new_v4(), "main.zig".to_string(), "const x = 1;".to_string(), "zig".to_string());
+
+    assert_eq!(code_block.get_language(), "zig");
+  }
+
+  #[test]
+  fn highlight_lines_beyond_code_are_rejected() {
+    let options = CodeBlockOptionsVO::new("3", 1, false, false).unwrap();
+    let result = CodeBlockEntity::new(Uuid::new_v4(), "main.rs".to_string(), "line1\nline2".to_string(), "rust".to_string()).unwrap().with_options(options);
+
+    assert!(matches!(result, Err(BlogDomainError::InvalidCodeBlock { .. })));
+  }
 }
@@ -0,0 +1,215 @@
+use crate::domain::blog_domain::errors::blog_domain_error::BlogDomainError;
+
+/// 強調表示として指定できる範囲の最大数
+const MAX_HIGHLIGHT_RANGES: usize = 100;
+/// 開始行番号の上限
+const MAX_START_LINE: u32 = 1_000_000;
+
+/// 強調表示する行の範囲（両端を含む）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineRange {
+  pub start: u32,
+  pub end: u32,
+}
+
+/// コードブロックの表示オプション
+///
+/// 強調表示する行は画面に表示される行番号（開始行番号を起点とした番号）で指定する。
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodeBlockOptionsVO {
+  highlight_lines: Vec<LineRange>,
+  start_line: u32,
+  diff: bool,
+  show_line_numbers: bool,
+}
+
+impl Default for CodeBlockOptionsVO {
+  fn default() -> Self {
+    Self {
+      highlight_lines: Vec::new(),
+      start_line: 1,
+      diff: false,
+      show_line_numbers: false,
+    }
+  }
+}
+
+impl CodeBlockOptionsVO {
+  /// 表示オプションを検証して値オブジェクトを生成する
+  ///
+  /// # Arguments
+  /// * `highlight_lines` - 強調表示する行の指定（例: "3-5,9"）。空文字列の場合は強調表示なし
+  /// * `start_line` - 1行目に表示する行番号
+  /// * `diff` - `+` / `-` で始まる行を差分として表示するかどうか
+  /// * `show_line_numbers` - 行番号を表示するかどうか
+  ///
+  /// # Returns
+  /// * `Result<Self, BlogDomainError>` - 行の指定が解釈できない場合や開始行番号が範囲外の場合はエラー
+  pub fn new(highlight_lines: &str, start_line: u32, diff: bool, show_line_numbers: bool) -> Result<Self, BlogDomainError> {
+    if !(1..=MAX_START_LINE).contains(&start_line) {
+      return Err(invalid_code_block(&format!("開始行番号は1から{}の範囲で指定してください", MAX_START_LINE)));
+    }
+    let highlight_lines = parse_line_ranges(highlight_lines)?;
+    Ok(Self {
+      highlight_lines,
+      start_line,
+      diff,
+      show_line_numbers,
+    })
+  }
+
+  /// 強調表示する行を "3-5,9" 形式の文字列に変換する
+  pub fn highlight_lines_to_string(&self) -> String {
+    self
+      .highlight_lines
+      .iter()
+      .map(|range| {
+        if range.start == range.end {
+          range.start.to_string()
+        } else {
+          format!("{}-{}", range.start, range.end)
+        }
+      })
+      .collect::<Vec<_>>()
+      .join(",")
+  }
+
+  pub fn get_start_line(&self) -> u32 {
+    self.start_line
+  }
+
+  pub fn is_diff(&self) -> bool {
+    self.diff
+  }
+
+  pub fn shows_line_numbers(&self) -> bool {
+    self.show_line_numbers
+  }
+
+  /// 強調表示する行がコードの行数に収まっているかを検証する
+  pub fn validate_against_line_count(&self, line_count: usize) -> Result<(), BlogDomainError> {
+    let Some(last_range) = self.highlight_lines.last() else {
+      return Ok(());
+    };
+    let last_line = u64::from(self.start_line) + line_count as u64;
+    let first_range = self.highlight_lines[0];
+    if first_range.start < self.start_line || u64::from(last_range.end) >= last_line {
+      return Err(invalid_code_block(&format!(
+        "強調表示する行は{}行目から{}行目の範囲で指定してください",
+        self.start_line,
+        last_line.saturating_sub(1)
+      )));
+    }
+    Ok(())
+  }
+}
+
+/// "3-5,9" 形式の行指定を解析し、昇順に並べて重なる範囲をまとめる
+fn parse_line_ranges(raw: &str) -> Result<Vec<LineRange>, BlogDomainError> {
+  let mut ranges = Vec::new();
+  for part in raw.split(',').map(str::trim).filter(|part| !part.is_empty()) {
+    let range = match part.split_once('-') {
+      Some((start, end)) => LineRange {
+        start: parse_line_number(start, raw)?,
+        end: parse_line_number(end, raw)?,
+      },
+      None => {
+        let line = parse_line_number(part, raw)?;
+        LineRange { start: line, end: line }
+      }
+    };
+    if range.start > range.end {
+      return Err(invalid_code_block(&format!("行の範囲は小さい番号から指定してください: {}", part)));
+    }
+    ranges.push(range);
+  }
+  if ranges.len() > MAX_HIGHLIGHT_RANGES {
+    return Err(invalid_code_block(&format!(
+      "強調表示する範囲は{}個以内で指定してください",
+      MAX_HIGHLIGHT_RANGES
+    )));
+  }
+
+  ranges.sort_by_key(|range| range.start);
+  let mut merged: Vec<LineRange> = Vec::with_capacity(ranges.len());
+  for range in ranges {
+    match merged.last_mut() {
+      Some(last) if range.start <= last.end.saturating_add(1) => last.end = last.end.max(range.end),
+      _ => merged.push(range),
+    }
+  }
+  Ok(merged)
+}
+
+fn parse_line_number(raw_number: &str, raw: &str) -> Result<u32, BlogDomainError> {
+  match raw_number.trim().parse::<u32>() {
+    Ok(number) if number >= 1 => Ok(number),
+    _ => Err(invalid_code_block(&format!("強調表示する行の指定を解釈できません: {}", raw))),
+  }
+}
+
+fn invalid_code_block(detail: &str) -> BlogDomainError {
+  BlogDomainError::InvalidCodeBlock { detail: detail.to_string() }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn can_parse_highlight_lines() {
+    let options = CodeBlockOptionsVO::new("3-5, 9", 1, false, true).unwrap();
+
+    assert_eq!(options.highlight_lines, vec![LineRange { start: 3, end: 5 }, LineRange { start: 9, end: 9 }]);
+    assert_eq!(options.highlight_lines_to_string(), "3-5,9");
+    assert!(options.shows_line_numbers());
+  }
+
+  #[test]
+  fn overlapping_and_adjacent_ranges_are_merged() {
+    let options = CodeBlockOptionsVO::new("9,2-4,3-6,7", 1, false, false).unwrap();
+
+    assert_eq!(options.highlight_lines_to_string(), "2-7,9");
+  }
+
+  #[test]
+  fn empty_highlight_lines_means_no_highlight() {
+    let options = CodeBlockOptionsVO::new("", 1, false, false).unwrap();
+
+    assert!(options.highlight_lines.is_empty());
+    assert_eq!(options, CodeBlockOptionsVO::default());
+  }
+
+  #[test]
+  fn invalid_highlight_lines_are_rejected() {
+    let cases = ["a", "0", "5-3", "1-", "-2", "1--2"];
+
+    for case in cases {
+      let result = CodeBlockOptionsVO::new(case, 1, false, false);
+      assert!(matches!(result, Err(BlogDomainError::InvalidCodeBlock { .. })), "{} は拒否されるべき", case);
+    }
+  }
+
+  #[test]
+  fn start_line_must_be_positive() {
+    assert!(CodeBlockOptionsVO::new("", 0, false, false).is_err());
+    assert!(CodeBlockOptionsVO::new("", MAX_START_LINE + 1, false, false).is_err());
+  }
+
+  #[test]
+  fn highlight_lines_are_validated_against_line_count() {
+    let options = CodeBlockOptionsVO::new("2-3", 1, false, false).unwrap();
+    assert!(options.validate_against_line_count(3).is_ok());
+    assert!(options.validate_against_line_count(2).is_err());
+  }
+
+  #[test]
+  fn highlight_lines_are_relative_to_start_line() {
+    let options = CodeBlockOptionsVO::new("10-12", 10, false, false).unwrap();
+    assert!(options.validate_against_line_count(3).is_ok());
+    assert!(options.validate_against_line_count(2).is_err());
+
+    let before_start = CodeBlockOptionsVO::new("9", 10, false, false).unwrap();
+    assert!(before_start.validate_against_line_count(3).is_err());
+  }
+}
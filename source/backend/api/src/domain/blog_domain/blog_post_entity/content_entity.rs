@@ -1,7 +1,20 @@
 use super::{
-  code_block_entity::CodeBlockEntity, h2_entity::H2Entity, h3_entity::H3Entity, image_content_entity::ImageContentEntity, paragraph_entity::ParagraphEntity,
+  code_block_entity::CodeBlockEntity,
+  code_block_options_vo::CodeBlockOptionsVO,
+  details_block_entity::DetailsBlockEntity,
+  diagram_block_entity::DiagramBlockEntity,
+  diagram_source_vo::{DiagramKind, DiagramSourceVO},
+  embed_block_entity::{EmbedBlockEntity, EmbedProvider},
+  h2_entity::H2Entity,
+  h3_entity::H3Entity,
+  image_content_entity::ImageContentEntity,
+  latex_vo::LatexVO,
+  link_card_block_entity::{LinkCardBlockEntity, OgpMetadataVO},
+  math_block_entity::MathBlockEntity,
+  paragraph_entity::ParagraphEntity,
   rich_text_vo::RichTextVO,
 };
+use crate::domain::blog_domain::errors::blog_domain_error::BlogDomainError;
 use uuid::Uuid;
 
 #[derive(Debug)]
@@ -11,6 +24,11 @@ pub enum ContentEntity {
   Paragraph(ParagraphEntity),
   Image(ImageContentEntity),
   CodeBlock(CodeBlockEntity),
+  Math(MathBlockEntity),
+  Embed(EmbedBlockEntity),
+  LinkCard(LinkCardBlockEntity),
+  Details(DetailsBlockEntity),
+  Diagram(DiagramBlockEntity),
 }
 
 impl ContentEntity {
@@ -30,7 +48,40 @@ impl ContentEntity {
     ContentEntity::Image(image_content)
   }
 
-  pub fn code_block(id: Uuid, title: String, code: String, language: String) -> Self {
-    ContentEntity::CodeBlock(CodeBlockEntity::new(id, title, code, language))
+  pub fn code_block(id: Uuid, title: String, code: String, language: String, options: CodeBlockOptionsVO) -> Result<Self, BlogDomainError> {
+    Ok(ContentEntity::CodeBlock(
+      CodeBlockEntity::new(id, title, code, language)?.with_options(options)?,
+    ))
+  }
+
+  /// 保存済みのコードブロックを復元する（言語は検証しない）
+  pub fn restore_code_block(id: Uuid, title: String, code: String, language: String, options: CodeBlockOptionsVO) -> Result<Self, BlogDomainError> {
+    Ok(ContentEntity::CodeBlock(
+      CodeBlockEntity::restore(id, title, code, language).with_options(options)?,
+    ))
+  }
+
+  pub fn math_block(id: Uuid, source: String) -> Result<Self, BlogDomainError> {
+    Ok(ContentEntity::Math(MathBlockEntity::new(id, LatexVO::new(source)?)))
+  }
+
+  pub fn embed(id: Uuid, provider: EmbedProvider, resource_id: String) -> Result<Self, BlogDomainError> {
+    Ok(ContentEntity::Embed(EmbedBlockEntity::new(id, provider, resource_id)?))
+  }
+
+  pub fn link_card(id: Uuid, url: String, metadata: OgpMetadataVO) -> Result<Self, BlogDomainError> {
+    Ok(ContentEntity::LinkCard(LinkCardBlockEntity::new(id, url, metadata)?))
+  }
+
+  pub fn link_card_from_entity(link_card: LinkCardBlockEntity) -> Self {
+    ContentEntity::LinkCard(link_card)
+  }
+
+  pub fn details(id: Uuid, summary: String, children: Vec<ContentEntity>) -> Result<Self, BlogDomainError> {
+    Ok(ContentEntity::Details(DetailsBlockEntity::new(id, summary, children)?))
+  }
+
+  pub fn diagram(id: Uuid, kind: DiagramKind, source: String) -> Result<Self, BlogDomainError> {
+    Ok(ContentEntity::Diagram(DiagramBlockEntity::new(id, DiagramSourceVO::new(kind, source)?)))
   }
 }
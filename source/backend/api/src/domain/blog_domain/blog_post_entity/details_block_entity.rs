@@ -0,0 +1,110 @@
+use super::content_entity::ContentEntity;
+use crate::domain::blog_domain::errors::blog_domain_error::BlogDomainError;
+use uuid::Uuid;
+
+/// 見出し行の最大文字数
+const MAX_SUMMARY_LENGTH: usize = 200;
+
+/// 見出し行をクリックすると子コンテンツが開閉する折りたたみブロック
+///
+/// 入れ子は 1 階層までとし、子コンテンツに折りたたみブロックや見出しは含められない。
+#[derive(Debug)]
+pub struct DetailsBlockEntity {
+  id: Uuid,
+  summary: String,
+  children: Vec<ContentEntity>,
+}
+
+impl DetailsBlockEntity {
+  pub fn new(id: Uuid, summary: String, children: Vec<ContentEntity>) -> Result<Self, BlogDomainError> {
+    if summary.trim().is_empty() {
+      return Err(BlogDomainError::InvalidDetailsBlock {
+        detail: "見出し行が空です".to_string(),
+      });
+    }
+    if summary.chars().count() > MAX_SUMMARY_LENGTH {
+      return Err(BlogDomainError::InvalidDetailsBlock {
+        detail: format!("見出し行は {} 文字以内で指定してください", MAX_SUMMARY_LENGTH),
+      });
+    }
+    if children.is_empty() {
+      return Err(BlogDomainError::InvalidDetailsBlock {
+        detail: "子コンテンツがありません".to_string(),
+      });
+    }
+    for child in &children {
+      match child {
+        ContentEntity::Details(_) => {
+          return Err(BlogDomainError::InvalidDetailsBlock {
+            detail: "折りたたみブロックは入れ子にできません".to_string(),
+          })
+        }
+        // 見出しは目次やアンカーリンクの対象になるため、折りたたまれる位置には置かせない
+        ContentEntity::H2(_) | ContentEntity::H3(_) => {
+          return Err(BlogDomainError::InvalidDetailsBlock {
+            detail: "折りたたみブロックの中に見出しは置けません".to_string(),
+          })
+        }
+        _ => {}
+      }
+    }
+
+    Ok(Self { id, summary, children })
+  }
+
+  pub fn get_id(&self) -> Uuid {
+    self.id
+  }
+
+  pub fn get_summary(&self) -> &str {
+    &self.summary
+  }
+
+  pub fn get_children(&self) -> &[ContentEntity] {
+    &self.children
+  }
+
+  pub fn get_children_mut(&mut self) -> &mut [ContentEntity] {
+    &mut self.children
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::blog_domain::blog_post_entity::rich_text_vo::{RichTextPartVO, RichTextVO};
+
+  fn paragraph(text: &str) -> ContentEntity {
+    ContentEntity::paragraph(Uuid::new_v4(), RichTextVO::new(vec![RichTextPartVO::new(text.to_string(), None, None)]))
+  }
+
+  #[test]
+  fn can_create_details_block_with_children() {
+    let id = Uuid::new_v4();
+    let details = DetailsBlockEntity::new(id, "実行ログ".to_string(), vec![paragraph("1行目"), paragraph("2行目")]).unwrap();
+
+    assert_eq!(details.get_id(), id);
+    assert_eq!(details.get_summary(), "実行ログ");
+    assert_eq!(details.get_children().len(), 2);
+  }
+
+  #[test]
+  fn rejects_blank_summary_and_empty_children() {
+    assert!(DetailsBlockEntity::new(Uuid::new_v4(), "  ".to_string(), vec![paragraph("本文")]).is_err());
+    assert!(DetailsBlockEntity::new(Uuid::new_v4(), "補足".to_string(), vec![]).is_err());
+  }
+
+  #[test]
+  fn rejects_nested_details_and_headings() {
+    let inner = ContentEntity::details(Uuid::new_v4(), "内側".to_string(), vec![paragraph("本文")]).unwrap();
+    let nested = DetailsBlockEntity::new(Uuid::new_v4(), "外側".to_string(), vec![inner]);
+    assert!(matches!(nested, Err(BlogDomainError::InvalidDetailsBlock { .. })));
+
+    let heading = DetailsBlockEntity::new(
+      Uuid::new_v4(),
+      "補足".to_string(),
+      vec![ContentEntity::h2(Uuid::new_v4(), "見出し".to_string())],
+    );
+    assert!(matches!(heading, Err(BlogDomainError::InvalidDetailsBlock { .. })));
+  }
+}
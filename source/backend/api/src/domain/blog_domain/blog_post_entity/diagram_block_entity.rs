@@ -0,0 +1,27 @@
+use uuid::Uuid;
+
+use super::diagram_source_vo::{DiagramKind, DiagramSourceVO};
+
+#[derive(Debug)]
+pub struct DiagramBlockEntity {
+  id: Uuid,
+  source: DiagramSourceVO,
+}
+
+impl DiagramBlockEntity {
+  pub fn new(id: Uuid, source: DiagramSourceVO) -> Self {
+    Self { id, source }
+  }
+
+  pub fn get_id(&self) -> Uuid {
+    self.id
+  }
+
+  pub fn get_kind(&self) -> DiagramKind {
+    self.source.get_kind()
+  }
+
+  pub fn get_source(&self) -> &str {
+    self.source.get_value()
+  }
+}
@@ -0,0 +1,234 @@
+use crate::domain::blog_domain::errors::blog_domain_error::BlogDomainError;
+
+/// 図として保存可能なソースの最大文字数
+const MAX_DIAGRAM_SOURCE_LENGTH: usize = 20000;
+
+/// 図として保存可能なソースの最大行数
+const MAX_DIAGRAM_SOURCE_LINES: usize = 1000;
+
+/// Mermaid の図の種類を表す宣言
+const MERMAID_DIAGRAM_TYPES: [&str; 20] = [
+  "graph",
+  "flowchart",
+  "sequenceDiagram",
+  "classDiagram",
+  "classDiagram-v2",
+  "stateDiagram",
+  "stateDiagram-v2",
+  "erDiagram",
+  "journey",
+  "gantt",
+  "pie",
+  "quadrantChart",
+  "requirementDiagram",
+  "gitGraph",
+  "C4Context",
+  "mindmap",
+  "timeline",
+  "sankey-beta",
+  "xychart-beta",
+  "block-beta",
+];
+
+/// 図を記述する DSL の種類
+///
+/// 現在は Mermaid のみに対応している。PlantUML や Graphviz を追加する場合はここに種類を増やし、
+/// `DiagramSourceVO::validate` に構文チェックを追加する。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagramKind {
+  Mermaid,
+}
+
+impl DiagramKind {
+  pub fn all() -> [DiagramKind; 1] {
+    [DiagramKind::Mermaid]
+  }
+
+  /// 永続化などに用いる識別子
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      DiagramKind::Mermaid => "mermaid",
+    }
+  }
+
+  pub fn parse(value: &str) -> Option<Self> {
+    Self::all().into_iter().find(|kind| kind.as_str() == value)
+  }
+}
+
+/// 検証済みの図のソース
+///
+/// 画像には変換せずソースのまま保持し、描画はフロントエンドで行う。
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiagramSourceVO {
+  kind: DiagramKind,
+  source: String,
+}
+
+impl DiagramSourceVO {
+  /// 図のソースを検証して値オブジェクトを生成する
+  ///
+  /// # Arguments
+  /// * `kind` - 図を記述する DSL の種類
+  /// * `source` - 図のソース
+  ///
+  /// # Returns
+  /// * `Result<Self, BlogDomainError>` - 大きすぎる場合や図の種類の宣言がない場合などはエラー
+  pub fn new(kind: DiagramKind, source: String) -> Result<Self, BlogDomainError> {
+    Self::validate(kind, &source)?;
+    Ok(Self { kind, source })
+  }
+
+  pub fn get_kind(&self) -> DiagramKind {
+    self.kind
+  }
+
+  pub fn get_value(&self) -> &str {
+    &self.source
+  }
+
+  fn validate(kind: DiagramKind, source: &str) -> Result<(), BlogDomainError> {
+    if source.trim().is_empty() {
+      return Err(invalid_diagram("図のソースが空です"));
+    }
+    if source.chars().count() > MAX_DIAGRAM_SOURCE_LENGTH {
+      return Err(invalid_diagram(&format!("図のソースは{}文字以内で入力してください", MAX_DIAGRAM_SOURCE_LENGTH)));
+    }
+    if source.lines().count() > MAX_DIAGRAM_SOURCE_LINES {
+      return Err(invalid_diagram(&format!("図のソースは{}行以内で入力してください", MAX_DIAGRAM_SOURCE_LINES)));
+    }
+    // クリック時のリンクからスクリプトを実行させない
+    if source.to_ascii_lowercase().contains("javascript:") {
+      return Err(invalid_diagram("javascript: スキームは使用できません"));
+    }
+
+    match kind {
+      DiagramKind::Mermaid => validate_mermaid(source),
+    }
+  }
+}
+
+/// Mermaid のソースとして最低限の体裁が整っているかを検証する
+///
+/// 完全な構文解析はフロントエンドの描画時に任せ、ここでは図の種類の宣言とブロックの対応のみを確認する。
+fn validate_mermaid(source: &str) -> Result<(), BlogDomainError> {
+  let mut lines = source.lines().map(str::trim).filter(|line| !line.is_empty()).peekable();
+
+  // 先頭のフロントマター（--- で囲まれた設定）は読み飛ばす
+  if lines.peek() == Some(&"---") {
+    lines.next();
+    if !lines.by_ref().any(|line| line == "---") {
+      return Err(invalid_diagram("フロントマターが閉じられていません"));
+    }
+  }
+
+  let mut diagram_type = None;
+  let mut block_depth: usize = 0;
+  for line in lines {
+    // 描画設定を上書きするディレクティブでセキュリティ設定を緩めさせない
+    if line.starts_with("%%{") && line.contains("securityLevel") {
+      return Err(invalid_diagram("securityLevel は変更できません"));
+    }
+    // コメント行
+    if line.starts_with("%%") {
+      continue;
+    }
+
+    let first_word = line.split_whitespace().next().unwrap_or_default();
+    let Some(declared_type) = diagram_type else {
+      if !MERMAID_DIAGRAM_TYPES.contains(&first_word) {
+        return Err(invalid_diagram(&format!("図の種類の宣言がありません: {}", first_word)));
+      }
+      diagram_type = Some(first_word);
+      continue;
+    };
+
+    if mermaid_block_openers(declared_type).contains(&first_word) {
+      block_depth += 1;
+    } else if first_word == "end" {
+      if block_depth == 0 {
+        return Err(invalid_diagram("対応するブロックのない end があります"));
+      }
+      block_depth -= 1;
+    }
+  }
+
+  if diagram_type.is_none() {
+    return Err(invalid_diagram("図の種類の宣言がありません"));
+  }
+  if block_depth > 0 {
+    return Err(invalid_diagram("end で閉じられていないブロックがあります"));
+  }
+
+  Ok(())
+}
+
+/// `end` で閉じる必要のあるブロックの開始キーワード
+fn mermaid_block_openers(diagram_type: &str) -> &'static [&'static str] {
+  match diagram_type {
+    "graph" | "flowchart" => &["subgraph"],
+    "sequenceDiagram" => &["loop", "alt", "opt", "par", "critical", "break", "rect", "box"],
+    _ => &[],
+  }
+}
+
+fn invalid_diagram(detail: &str) -> BlogDomainError {
+  BlogDomainError::InvalidDiagram { detail: detail.to_string() }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn can_create_diagram_source_vo_from_valid_mermaid() {
+    let source = "%% 構成図\nflowchart LR\n  subgraph api\n    A[handler] --> B[usecase]\n  end\n  B --> C[(db)]";
+    let diagram = DiagramSourceVO::new(DiagramKind::Mermaid, source.to_string()).unwrap();
+
+    assert_eq!(diagram.get_kind(), DiagramKind::Mermaid);
+    assert_eq!(diagram.get_value(), source);
+  }
+
+  #[test]
+  fn front_matter_and_sequence_blocks_are_accepted() {
+    let source = "---\ntitle: ログイン\n---\nsequenceDiagram\n  loop 3回まで\n    A->>B: retry\n  end";
+
+    assert!(DiagramSourceVO::new(DiagramKind::Mermaid, source.to_string()).is_ok());
+  }
+
+  #[test]
+  fn source_without_diagram_type_is_rejected() {
+    assert!(DiagramSourceVO::new(DiagramKind::Mermaid, "A --> B".to_string()).is_err());
+    assert!(DiagramSourceVO::new(DiagramKind::Mermaid, "%% コメントのみ".to_string()).is_err());
+    assert!(DiagramSourceVO::new(DiagramKind::Mermaid, "  ".to_string()).is_err());
+  }
+
+  #[test]
+  fn unbalanced_blocks_are_rejected() {
+    assert!(DiagramSourceVO::new(DiagramKind::Mermaid, "flowchart TD\n  subgraph a\n  A --> B".to_string()).is_err());
+    assert!(DiagramSourceVO::new(DiagramKind::Mermaid, "sequenceDiagram\n  A->>B: hi\n  end".to_string()).is_err());
+  }
+
+  #[test]
+  fn unsafe_sources_are_rejected() {
+    let script_link = "flowchart TD\n  A --> B\n  click A \"javascript:alert(1)\"";
+    let loosened = "%%{init: {'securityLevel': 'loose'}}%%\nflowchart TD\n  A --> B";
+
+    let result = DiagramSourceVO::new(DiagramKind::Mermaid, script_link.to_string());
+    assert!(matches!(result, Err(BlogDomainError::InvalidDiagram { .. })));
+    assert!(DiagramSourceVO::new(DiagramKind::Mermaid, loosened.to_string()).is_err());
+  }
+
+  #[test]
+  fn oversized_source_is_rejected() {
+    let too_many_lines = format!("flowchart TD\n{}", "  A --> B\n".repeat(MAX_DIAGRAM_SOURCE_LINES));
+
+    assert!(DiagramSourceVO::new(DiagramKind::Mermaid, too_many_lines).is_err());
+  }
+
+  #[test]
+  fn diagram_kind_round_trips_through_identifier() {
+    assert_eq!(DiagramKind::parse(DiagramKind::Mermaid.as_str()), Some(DiagramKind::Mermaid));
+    assert_eq!(DiagramKind::parse("plantuml"), None);
+  }
+}
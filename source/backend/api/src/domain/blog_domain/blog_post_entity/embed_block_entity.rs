@@ -0,0 +1,154 @@
+use uuid::Uuid;
+
+use crate::domain::blog_domain::errors::blog_domain_error::BlogDomainError;
+
+/// 埋め込みに対応している外部サービス
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmbedProvider {
+  YouTube,
+  Twitter,
+  GitHubGist,
+  SpeakerDeck,
+  CodePen,
+}
+
+impl EmbedProvider {
+  pub fn all() -> [EmbedProvider; 5] {
+    [
+      EmbedProvider::YouTube,
+      EmbedProvider::Twitter,
+      EmbedProvider::GitHubGist,
+      EmbedProvider::SpeakerDeck,
+      EmbedProvider::CodePen,
+    ]
+  }
+
+  /// 永続化などに用いる識別子
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      EmbedProvider::YouTube => "youtube",
+      EmbedProvider::Twitter => "twitter",
+      EmbedProvider::GitHubGist => "gist",
+      EmbedProvider::SpeakerDeck => "speakerdeck",
+      EmbedProvider::CodePen => "codepen",
+    }
+  }
+
+  pub fn parse(value: &str) -> Option<Self> {
+    Self::all().into_iter().find(|provider| provider.as_str() == value)
+  }
+
+  /// プロバイダが配信に利用しているホスト名
+  pub fn hosts(&self) -> &'static [&'static str] {
+    match self {
+      EmbedProvider::YouTube => &["youtube.com", "www.youtube.com", "m.youtube.com", "youtu.be", "www.youtube-nocookie.com"],
+      EmbedProvider::Twitter => &["twitter.com", "www.twitter.com", "mobile.twitter.com", "x.com", "www.x.com"],
+      EmbedProvider::GitHubGist => &["gist.github.com"],
+      EmbedProvider::SpeakerDeck => &["speakerdeck.com", "www.speakerdeck.com"],
+      EmbedProvider::CodePen => &["codepen.io"],
+    }
+  }
+
+  /// リソース ID がプロバイダの形式に沿っているかを判定する
+  pub fn is_valid_resource_id(&self, resource_id: &str) -> bool {
+    match self {
+      EmbedProvider::YouTube => resource_id.len() == 11 && is_slug(resource_id),
+      EmbedProvider::Twitter => (1..=20).contains(&resource_id.len()) && resource_id.chars().all(|c| c.is_ascii_digit()),
+      EmbedProvider::GitHubGist => match resource_id.split_once('/') {
+        Some((user, gist_id)) => is_account_name(user) && (1..=40).contains(&gist_id.len()) && gist_id.chars().all(|c| c.is_ascii_hexdigit()),
+        None => false,
+      },
+      EmbedProvider::SpeakerDeck | EmbedProvider::CodePen => match resource_id.split_once('/') {
+        Some((user, slug)) => is_account_name(user) && slug.len() <= 100 && is_slug(slug),
+        None => false,
+      },
+    }
+  }
+
+  /// リソース ID から正規化された URL を組み立てる
+  pub fn canonical_url(&self, resource_id: &str) -> String {
+    match self {
+      EmbedProvider::YouTube => format!("https://www.youtube.com/watch?v={}", resource_id),
+      EmbedProvider::Twitter => format!("https://x.com/i/status/{}", resource_id),
+      EmbedProvider::GitHubGist => format!("https://gist.github.com/{}", resource_id),
+      EmbedProvider::SpeakerDeck => format!("https://speakerdeck.com/{}", resource_id),
+      EmbedProvider::CodePen => {
+        let (user, slug) = resource_id.split_once('/').unwrap_or((resource_id, ""));
+        format!("https://codepen.io/{}/pen/{}", user, slug)
+      }
+    }
+  }
+}
+
+fn is_slug(value: &str) -> bool {
+  !value.is_empty() && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+fn is_account_name(value: &str) -> bool {
+  value.len() <= 50 && is_slug(value)
+}
+
+#[derive(Debug)]
+pub struct EmbedBlockEntity {
+  id: Uuid,
+  provider: EmbedProvider,
+  resource_id: String,
+}
+
+impl EmbedBlockEntity {
+  pub fn new(id: Uuid, provider: EmbedProvider, resource_id: String) -> Result<Self, BlogDomainError> {
+    if !provider.is_valid_resource_id(&resource_id) {
+      return Err(BlogDomainError::InvalidEmbed {
+        detail: format!("{} のリソース ID として不正です: {}", provider.as_str(), resource_id),
+      });
+    }
+    Ok(Self { id, provider, resource_id })
+  }
+
+  pub fn get_id(&self) -> Uuid {
+    self.id
+  }
+
+  pub fn get_provider(&self) -> EmbedProvider {
+    self.provider
+  }
+
+  pub fn get_resource_id(&self) -> &str {
+    &self.resource_id
+  }
+
+  pub fn get_url(&self) -> String {
+    self.provider.canonical_url(&self.resource_id)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn can_create_embed_block_with_valid_resource_id() {
+    let id = Uuid::new_v4();
+    let embed = EmbedBlockEntity::new(id, EmbedProvider::CodePen, "someone/abcDEF".to_string()).unwrap();
+
+    assert_eq!(embed.get_id(), id);
+    assert_eq!(embed.get_provider(), EmbedProvider::CodePen);
+    assert_eq!(embed.get_resource_id(), "someone/abcDEF");
+    assert_eq!(embed.get_url(), "https://codepen.io/someone/pen/abcDEF");
+  }
+
+  #[test]
+  fn invalid_resource_id_is_rejected() {
+    let result = EmbedBlockEntity::new(Uuid::new_v4(), EmbedProvider::YouTube, "<script>".to_string());
+
+    assert!(matches!(result, Err(BlogDomainError::InvalidEmbed { .. })));
+  }
+
+  #[test]
+  fn provider_round_trips_through_str() {
+    for provider in EmbedProvider::all() {
+      assert_eq!(EmbedProvider::parse(provider.as_str()), Some(provider));
+    }
+    assert_eq!(EmbedProvider::parse("vimeo"), None);
+  }
+}
@@ -0,0 +1,80 @@
+use uuid::Uuid;
+
+use super::rich_text_vo::RichTextVO;
+use crate::domain::blog_domain::errors::blog_domain_error::BlogDomainError;
+
+/// 記事末尾に並べる脚注
+///
+/// 番号は本文中で最初に参照された順に記事（BlogPostEntity）側で採番する。
+#[derive(Debug)]
+pub struct FootnoteEntity {
+  id: Uuid,
+  number: u32,
+  body: RichTextVO,
+}
+
+impl FootnoteEntity {
+  /// 脚注を作成する
+  ///
+  /// 本文が空の場合や、本文の中でさらに脚注を参照している場合はエラー
+  pub fn new(id: Uuid, body: RichTextVO) -> Result<Self, BlogDomainError> {
+    if body.get_text().iter().all(|part| part.get_text().trim().is_empty()) {
+      return Err(BlogDomainError::InvalidFootnote {
+        detail: format!("脚注「{}」の本文が空です", id),
+      });
+    }
+    if body.get_text().iter().any(|part| part.get_footnote_ref().is_some()) {
+      return Err(BlogDomainError::InvalidFootnote {
+        detail: format!("脚注「{}」の本文から脚注を参照することはできません", id),
+      });
+    }
+    Ok(Self { id, number: 0, body })
+  }
+
+  pub fn get_id(&self) -> Uuid {
+    self.id
+  }
+
+  /// 1 から始まる脚注番号
+  pub fn get_number(&self) -> u32 {
+    self.number
+  }
+
+  pub fn get_body(&self) -> &RichTextVO {
+    &self.body
+  }
+
+  pub(super) fn set_number(&mut self, number: u32) {
+    self.number = number;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::blog_domain::blog_post_entity::rich_text_vo::RichTextPartVO;
+
+  #[test]
+  fn can_create_footnote_with_rich_text_body() {
+    let id = Uuid::new_v4();
+    let footnote = FootnoteEntity::new(id, RichTextVO::new(vec![RichTextPartVO::new("補足説明".to_string(), None, None)])).unwrap();
+
+    assert_eq!(footnote.get_id(), id);
+    assert_eq!(footnote.get_body().get_text()[0].get_text(), "補足説明");
+  }
+
+  #[test]
+  fn footnote_with_empty_body_is_rejected() {
+    let result = FootnoteEntity::new(Uuid::new_v4(), RichTextVO::new(vec![RichTextPartVO::new(" ".to_string(), None, None)]));
+
+    assert!(matches!(result, Err(BlogDomainError::InvalidFootnote { .. })));
+  }
+
+  #[test]
+  fn footnote_referencing_another_footnote_is_rejected() {
+    let part = RichTextPartVO::new("入れ子".to_string(), None, None).with_footnote_ref(Uuid::new_v4());
+    let result = FootnoteEntity::new(Uuid::new_v4(), RichTextVO::new(vec![part]));
+
+    assert!(matches!(result, Err(BlogDomainError::InvalidFootnote { .. })));
+  }
+}
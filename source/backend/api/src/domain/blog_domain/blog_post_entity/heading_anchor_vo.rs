@@ -0,0 +1,78 @@
+use std::fmt;
+
+use uuid::Uuid;
+
+/// アンカーに付ける見出し ID の桁数
+const ANCHOR_ID_DIGITS: usize = 8;
+
+/// 見出しへのアンカー（URL のフラグメント）
+///
+/// 見出しのテキストから作ったスラッグに見出し ID の先頭を付ける。見出し ID はブロックが作られたときから変わらないため、
+/// 他の見出しを追加・削除・並べ替えてもアンカーは変わらず、同じテキストの見出しがあっても記事内で重複しない。
+/// 目次・HTML の見出しの id 属性・内部リンクのフラグメントはすべてこの値を使う
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeadingAnchorVO(String);
+
+impl HeadingAnchorVO {
+  /// 見出し ID とテキストからアンカーを作る
+  ///
+  /// テキストがスラッグにならない（記号だけなど）場合は `section-{見出し ID の先頭}` にする
+  pub fn new(heading_id: Uuid, text: &str) -> Self {
+    let slug = slugify(text);
+    let id = heading_id.simple().to_string();
+    let id = &id[..ANCHOR_ID_DIGITS];
+
+    match slug.is_empty() {
+      true => Self(format!("section-{}", id)),
+      false => Self(format!("{}-{}", slug, id)),
+    }
+  }
+}
+
+impl fmt::Display for HeadingAnchorVO {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(&self.0)
+  }
+}
+
+/// 見出しのテキストを URL のフラグメントに使えるスラッグにする
+///
+/// 英数字は小文字にし、日本語などの文字はそのまま残す。記号と空白はハイフン 1 つにまとめる
+fn slugify(text: &str) -> String {
+  let mut slug = String::new();
+  let mut pending_hyphen = false;
+
+  for c in text.chars() {
+    if c.is_alphanumeric() {
+      if pending_hyphen && !slug.is_empty() {
+        slug.push('-');
+      }
+      pending_hyphen = false;
+      slug.extend(c.to_lowercase());
+    } else {
+      pending_hyphen = true;
+    }
+  }
+  slug
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn anchor_is_slug_with_heading_id_prefix() {
+    let heading_id = Uuid::parse_str("1a2b3c4d-0000-0000-0000-000000000000").unwrap();
+
+    assert_eq!(HeadingAnchorVO::new(heading_id, "Rust の 所有権!?").to_string(), "rust-の-所有権-1a2b3c4d");
+    assert_eq!(HeadingAnchorVO::new(heading_id, "？？").to_string(), "section-1a2b3c4d");
+  }
+
+  #[test]
+  fn headings_with_same_text_get_different_anchors() {
+    let first = HeadingAnchorVO::new(Uuid::parse_str("1a2b3c4d-0000-0000-0000-000000000000").unwrap(), "概要");
+    let second = HeadingAnchorVO::new(Uuid::parse_str("5e6f7a8b-0000-0000-0000-000000000000").unwrap(), "概要");
+
+    assert_ne!(first, second);
+  }
+}
@@ -1,3 +1,4 @@
+use crate::domain::blog_domain::blog_post_entity::image_presentation_vo::ImagePresentationVO;
 use crate::domain::image_domain::image_entity::ImageEntity;
 use uuid::Uuid;
 
@@ -5,11 +6,22 @@ use uuid::Uuid;
 pub struct ImageContentEntity {
   id: Uuid,
   image: ImageEntity,
+  presentation: ImagePresentationVO,
 }
 
 impl ImageContentEntity {
   pub fn new(id: Uuid, image: ImageEntity) -> Self {
-    Self { id, image }
+    Self {
+      id,
+      image,
+      presentation: ImagePresentationVO::default(),
+    }
+  }
+
+  /// 代替テキストやキャプションなど、記事内での見せ方を設定する
+  pub fn with_presentation(mut self, presentation: ImagePresentationVO) -> Self {
+    self.presentation = presentation;
+    self
   }
 
   pub fn get_id(&self) -> Uuid {
@@ -23,4 +35,8 @@ impl ImageContentEntity {
   pub fn get_path(&self) -> &str {
     self.image.get_path()
   }
+
+  pub fn get_presentation(&self) -> &ImagePresentationVO {
+    &self.presentation
+  }
 }
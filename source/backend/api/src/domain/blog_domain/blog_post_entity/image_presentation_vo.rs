@@ -0,0 +1,183 @@
+use url::Url;
+
+use crate::domain::blog_domain::errors::blog_domain_error::BlogDomainError;
+
+/// 代替テキストの最大文字数
+const MAX_ALT_LENGTH: usize = 300;
+/// キャプションの最大文字数
+const MAX_CAPTION_LENGTH: usize = 500;
+/// 表示幅 (px) の下限
+const MIN_WIDTH: u32 = 16;
+/// 表示幅 (px) の上限
+const MAX_WIDTH: u32 = 4096;
+/// リンク先 URL の最大文字数
+const MAX_LINK_URL_LENGTH: usize = 2048;
+
+/// 記事内で画像を表示する際の見せ方
+///
+/// 同じ画像でも記事ごとに代替テキストやキャプションを変えられるよう、画像そのものではなく画像ブロックが保持する。
+/// 代替テキストが空の場合は装飾目的の画像として扱う。
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ImagePresentationVO {
+  alt: String,
+  caption: Option<String>,
+  width: Option<u32>,
+  link_url: Option<String>,
+}
+
+impl ImagePresentationVO {
+  /// 画像の見せ方を検証して値オブジェクトを生成する
+  ///
+  /// # Arguments
+  /// * `alt` - 代替テキスト
+  /// * `caption` - 画像の下に表示するキャプション
+  /// * `width` - 表示幅 (px)。`None` の場合は本文幅に合わせる
+  /// * `link_url` - 画像クリック時の遷移先。http(s) の URL かサイト内の絶対パス
+  ///
+  /// # Returns
+  /// * `Result<Self, BlogDomainError>` - 文字数や表示幅が範囲外の場合、リンク先が不正な場合はエラー
+  pub fn new(alt: String, caption: Option<String>, width: Option<u32>, link_url: Option<String>) -> Result<Self, BlogDomainError> {
+    let alt = alt.trim().to_string();
+    if alt.chars().count() > MAX_ALT_LENGTH {
+      return Err(invalid_image_block(&format!("代替テキストは{}文字以内で入力してください", MAX_ALT_LENGTH)));
+    }
+
+    let caption = normalize_optional(caption);
+    if caption.as_ref().is_some_and(|caption| caption.chars().count() > MAX_CAPTION_LENGTH) {
+      return Err(invalid_image_block(&format!("キャプションは{}文字以内で入力してください", MAX_CAPTION_LENGTH)));
+    }
+
+    if let Some(width) = width {
+      if !(MIN_WIDTH..=MAX_WIDTH).contains(&width) {
+        return Err(invalid_image_block(&format!(
+          "表示幅は{}px から{}px の範囲で指定してください",
+          MIN_WIDTH, MAX_WIDTH
+        )));
+      }
+    }
+
+    let link_url = normalize_optional(link_url);
+    if let Some(link_url) = &link_url {
+      Self::validate_link_url(link_url)?;
+    }
+
+    Ok(Self { alt, caption, width, link_url })
+  }
+
+  pub fn get_alt(&self) -> &str {
+    &self.alt
+  }
+
+  pub fn get_caption(&self) -> Option<&str> {
+    self.caption.as_deref()
+  }
+
+  pub fn get_width(&self) -> Option<u32> {
+    self.width
+  }
+
+  pub fn get_link_url(&self) -> Option<&str> {
+    self.link_url.as_deref()
+  }
+
+  fn validate_link_url(link_url: &str) -> Result<(), BlogDomainError> {
+    if link_url.chars().count() > MAX_LINK_URL_LENGTH {
+      return Err(invalid_image_block(&format!(
+        "リンク先 URL は{}文字以内で入力してください",
+        MAX_LINK_URL_LENGTH
+      )));
+    }
+
+    // サイト内リンクはスキーム相対 URL (//example.com) と区別する
+    if link_url.starts_with('/') && !link_url.starts_with("//") {
+      return Ok(());
+    }
+
+    let url = Url::parse(link_url).map_err(|_| invalid_image_block(&format!("リンク先を URL として解釈できません: {}", link_url)))?;
+    if url.scheme() != "https" && url.scheme() != "http" {
+      return Err(invalid_image_block(&format!("http(s) 以外の URL はリンク先にできません: {}", link_url)));
+    }
+    Ok(())
+  }
+}
+
+/// 前後の空白を取り除き、空文字列は未指定として扱う
+fn normalize_optional(value: Option<String>) -> Option<String> {
+  value.map(|value| value.trim().to_string()).filter(|value| !value.is_empty())
+}
+
+fn invalid_image_block(detail: &str) -> BlogDomainError {
+  BlogDomainError::InvalidImageBlock { detail: detail.to_string() }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn can_create_presentation_with_all_fields() {
+    let presentation = ImagePresentationVO::new(
+      " 構成図 ".to_string(),
+      Some("システム全体の構成".to_string()),
+      Some(640),
+      Some("https://example.com/large.png".to_string()),
+    )
+    .unwrap();
+
+    assert_eq!(presentation.get_alt(), "構成図");
+    assert_eq!(presentation.get_caption(), Some("システム全体の構成"));
+    assert_eq!(presentation.get_width(), Some(640));
+    assert_eq!(presentation.get_link_url(), Some("https://example.com/large.png"));
+  }
+
+  #[test]
+  fn empty_alt_is_treated_as_decorative() {
+    let presentation = ImagePresentationVO::new("".to_string(), None, None, None).unwrap();
+
+    assert_eq!(presentation.get_alt(), "");
+    assert_eq!(presentation, ImagePresentationVO::default());
+  }
+
+  #[test]
+  fn blank_caption_and_link_are_treated_as_unset() {
+    let presentation = ImagePresentationVO::new("alt".to_string(), Some("  ".to_string()), None, Some("".to_string())).unwrap();
+
+    assert_eq!(presentation.get_caption(), None);
+    assert_eq!(presentation.get_link_url(), None);
+  }
+
+  #[test]
+  fn too_long_texts_are_rejected() {
+    let long_alt = "a".repeat(MAX_ALT_LENGTH + 1);
+    let result = ImagePresentationVO::new(long_alt, None, None, None);
+    assert!(matches!(result, Err(BlogDomainError::InvalidImageBlock { .. })));
+
+    let long_caption = "あ".repeat(MAX_CAPTION_LENGTH + 1);
+    assert!(ImagePresentationVO::new("alt".to_string(), Some(long_caption), None, None).is_err());
+  }
+
+  #[test]
+  fn width_out_of_range_is_rejected() {
+    assert!(ImagePresentationVO::new("alt".to_string(), None, Some(MIN_WIDTH - 1), None).is_err());
+    assert!(ImagePresentationVO::new("alt".to_string(), None, Some(MAX_WIDTH + 1), None).is_err());
+    assert!(ImagePresentationVO::new("alt".to_string(), None, Some(MIN_WIDTH), None).is_ok());
+    assert!(ImagePresentationVO::new("alt".to_string(), None, Some(MAX_WIDTH), None).is_ok());
+  }
+
+  #[test]
+  fn site_relative_paths_are_allowed_as_link() {
+    let presentation = ImagePresentationVO::new("alt".to_string(), None, None, Some("/posts/abc".to_string())).unwrap();
+
+    assert_eq!(presentation.get_link_url(), Some("/posts/abc"));
+  }
+
+  #[test]
+  fn unsafe_links_are_rejected() {
+    let cases = ["javascript:alert(1)", "//evil.example.com", "data:text/html,hello", "not a url"];
+
+    for link in cases {
+      let result = ImagePresentationVO::new("alt".to_string(), None, None, Some(link.to_string()));
+      assert!(matches!(result, Err(BlogDomainError::InvalidImageBlock { .. })), "{} は拒否されるべき", link);
+    }
+  }
+}
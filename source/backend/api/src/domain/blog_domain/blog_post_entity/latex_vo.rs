@@ -0,0 +1,223 @@
+use crate::domain::blog_domain::errors::blog_domain_error::BlogDomainError;
+
+/// 数式として保存可能な LaTeX ソースの最大文字数
+const MAX_LATEX_LENGTH: usize = 5000;
+
+/// 外部リソースの読み込みやマクロ定義など、数式の描画に不要かつ危険なマクロ
+const DISALLOWED_MACROS: [&str; 20] = [
+  "input",
+  "include",
+  "includeonly",
+  "write",
+  "immediate",
+  "openin",
+  "openout",
+  "read",
+  "def",
+  "gdef",
+  "edef",
+  "xdef",
+  "let",
+  "newcommand",
+  "renewcommand",
+  "providecommand",
+  "catcode",
+  "href",
+  "url",
+  "htmlData",
+];
+
+/// 検証済みの LaTeX ソース
+#[derive(Debug, Clone, PartialEq)]
+pub struct LatexVO {
+  source: String,
+}
+
+impl LatexVO {
+  /// LaTeX ソースを検証して値オブジェクトを生成する
+  ///
+  /// # Arguments
+  /// * `source` - LaTeX ソース
+  ///
+  /// # Returns
+  /// * `Result<Self, BlogDomainError>` - 括弧の対応が崩れている場合や禁止マクロを含む場合はエラー
+  pub fn new(source: String) -> Result<Self, BlogDomainError> {
+    Self::validate(&source)?;
+    Ok(Self { source })
+  }
+
+  pub fn get_value(&self) -> &str {
+    &self.source
+  }
+
+  fn validate(source: &str) -> Result<(), BlogDomainError> {
+    if source.trim().is_empty() {
+      return Err(invalid_latex("数式が空です"));
+    }
+    if source.chars().count() > MAX_LATEX_LENGTH {
+      return Err(invalid_latex(&format!("数式は{}文字以内で入力してください", MAX_LATEX_LENGTH)));
+    }
+
+    let chars: Vec<char> = source.chars().collect();
+    let mut brace_depth: usize = 0;
+    let mut left_right_depth: usize = 0;
+    let mut environments: Vec<String> = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+      match chars[i] {
+        '\\' => {
+          let (macro_name, next) = read_macro_name(&chars, i + 1);
+          i = next;
+          match macro_name.as_str() {
+            // `\{` や `\\` のような記号のエスケープは 1 文字読み飛ばす
+            "" => i += 1,
+            "left" => left_right_depth += 1,
+            "right" => {
+              if left_right_depth == 0 {
+                return Err(invalid_latex("対応する \\left のない \\right があります"));
+              }
+              left_right_depth -= 1;
+            }
+            "begin" | "end" => {
+              let (env_name, next) = read_group(&chars, i).ok_or_else(|| invalid_latex(&format!("\\{} の後に環境名がありません", macro_name)))?;
+              i = next;
+              if macro_name == "begin" {
+                environments.push(env_name);
+              } else if environments.pop().as_deref() != Some(env_name.as_str()) {
+                return Err(invalid_latex(&format!("\\end{{{}}} に対応する \\begin がありません", env_name)));
+              }
+            }
+            name if DISALLOWED_MACROS.contains(&name) => {
+              return Err(invalid_latex(&format!("\\{} は使用できません", name)));
+            }
+            _ => {}
+          }
+        }
+        '{' => {
+          brace_depth += 1;
+          i += 1;
+        }
+        '}' => {
+          if brace_depth == 0 {
+            return Err(invalid_latex("対応する { のない } があります"));
+          }
+          brace_depth -= 1;
+          i += 1;
+        }
+        _ => i += 1,
+      }
+    }
+
+    if brace_depth > 0 {
+      return Err(invalid_latex("閉じられていない { があります"));
+    }
+    if left_right_depth > 0 {
+      return Err(invalid_latex("対応する \\right のない \\left があります"));
+    }
+    if let Some(env_name) = environments.pop() {
+      return Err(invalid_latex(&format!("\\begin{{{}}} が閉じられていません", env_name)));
+    }
+
+    Ok(())
+  }
+}
+
+fn invalid_latex(detail: &str) -> BlogDomainError {
+  BlogDomainError::InvalidLatex { detail: detail.to_string() }
+}
+
+/// `start` 位置から英字のマクロ名を読み取り、マクロ名と読み終えた位置を返す
+fn read_macro_name(chars: &[char], start: usize) -> (String, usize) {
+  let mut end = start;
+  while end < chars.len() && chars[end].is_ascii_alphabetic() {
+    end += 1;
+  }
+  (chars[start..end].iter().collect(), end)
+}
+
+/// `start` 位置以降の `{...}` を読み取り、中身と読み終えた位置を返す
+fn read_group(chars: &[char], start: usize) -> Option<(String, usize)> {
+  let mut i = start;
+  while i < chars.len() && chars[i].is_whitespace() {
+    i += 1;
+  }
+  if chars.get(i) != Some(&'{') {
+    return None;
+  }
+  let close = chars[i + 1..].iter().position(|c| *c == '}')? + i + 1;
+  Some((chars[i + 1..close].iter().collect(), close + 1))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn can_create_latex_vo_from_valid_source() {
+    let source = r"\frac{1}{2} + \left( \sum_{i=0}^{n} a_i \right)";
+    let latex = LatexVO::new(source.to_string()).unwrap();
+
+    assert_eq!(latex.get_value(), source);
+  }
+
+  #[test]
+  fn environments_and_escaped_braces_are_accepted() {
+    let source = r"\begin{pmatrix} a & b \\ c & d \end{pmatrix} \{ x \}";
+
+    assert!(LatexVO::new(source.to_string()).is_ok());
+  }
+
+  #[test]
+  fn empty_source_is_rejected() {
+    let result = LatexVO::new("  ".to_string());
+
+    assert!(matches!(result, Err(BlogDomainError::InvalidLatex { .. })));
+  }
+
+  #[test]
+  fn unbalanced_braces_are_rejected() {
+    assert!(LatexVO::new(r"\frac{1}{2".to_string()).is_err());
+    assert!(LatexVO::new(r"x}".to_string()).is_err());
+  }
+
+  #[test]
+  fn unbalanced_left_right_is_rejected() {
+    assert!(LatexVO::new(r"\left( x".to_string()).is_err());
+    assert!(LatexVO::new(r"x \right)".to_string()).is_err());
+  }
+
+  #[test]
+  fn mismatched_environments_are_rejected() {
+    assert!(LatexVO::new(r"\begin{matrix} a \end{pmatrix}".to_string()).is_err());
+    assert!(LatexVO::new(r"\begin{matrix} a".to_string()).is_err());
+    assert!(LatexVO::new(r"\begin x".to_string()).is_err());
+  }
+
+  #[test]
+  fn disallowed_macros_are_rejected() {
+    let result = LatexVO::new(r"\input{/etc/passwd}".to_string());
+
+    assert_eq!(
+      result,
+      Err(BlogDomainError::InvalidLatex {
+        detail: "\\input は使用できません".to_string()
+      })
+    );
+    assert!(LatexVO::new(r"\def\x{1}".to_string()).is_err());
+    assert!(LatexVO::new(r"\href{https://example.com}{x}".to_string()).is_err());
+  }
+
+  #[test]
+  fn macros_with_disallowed_prefix_are_accepted() {
+    // `\in` や `\left` などは禁止マクロの接頭辞と一致しても許可される
+    assert!(LatexVO::new(r"x \in \mathbb{R}, \int_0^1 f".to_string()).is_ok());
+  }
+
+  #[test]
+  fn too_long_source_is_rejected() {
+    let source = "x".repeat(MAX_LATEX_LENGTH + 1);
+
+    assert!(LatexVO::new(source).is_err());
+  }
+}
@@ -0,0 +1,121 @@
+use url::Url;
+use uuid::Uuid;
+
+use crate::domain::blog_domain::errors::blog_domain_error::BlogDomainError;
+
+/// リンクカードとして保存できる URL の最大文字数
+const MAX_LINK_CARD_URL_LENGTH: usize = 2048;
+
+/// リンク先ページから取得した OGP メタデータ
+///
+/// 取得できなかった項目は `None` として保持し、表示側でフォールバックする。
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OgpMetadataVO {
+  pub title: Option<String>,
+  pub description: Option<String>,
+  pub site_name: Option<String>,
+  pub image_url: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct LinkCardBlockEntity {
+  id: Uuid,
+  url: String,
+  metadata: OgpMetadataVO,
+}
+
+impl LinkCardBlockEntity {
+  /// リンクカードを生成する
+  ///
+  /// # Arguments
+  /// * `id` - コンテンツ ID
+  /// * `url` - リンク先の URL
+  /// * `metadata` - キャッシュ済みの OGP メタデータ
+  ///
+  /// # Returns
+  /// * `Result<Self, BlogDomainError>` - http(s) の URL でない場合はエラー
+  pub fn new(id: Uuid, url: String, metadata: OgpMetadataVO) -> Result<Self, BlogDomainError> {
+    let url = Self::validate_url(&url)?;
+    Ok(Self { id, url, metadata })
+  }
+
+  pub fn get_id(&self) -> Uuid {
+    self.id
+  }
+
+  pub fn get_url(&self) -> &str {
+    &self.url
+  }
+
+  pub fn get_metadata(&self) -> &OgpMetadataVO {
+    &self.metadata
+  }
+
+  /// 再取得した OGP メタデータでキャッシュを置き換える
+  pub fn refresh_metadata(&mut self, metadata: OgpMetadataVO) -> &mut Self {
+    self.metadata = metadata;
+    self
+  }
+
+  /// URL を検証し、前後の空白を取り除いた値を返す
+  pub fn validate_url(raw_url: &str) -> Result<String, BlogDomainError> {
+    let trimmed = raw_url.trim();
+    if trimmed.chars().count() > MAX_LINK_CARD_URL_LENGTH {
+      return Err(invalid_link_card(&format!("URL は{}文字以内で入力してください", MAX_LINK_CARD_URL_LENGTH)));
+    }
+    let url = Url::parse(trimmed).map_err(|_| invalid_link_card(&format!("URL として解釈できません: {}", raw_url)))?;
+    if url.scheme() != "https" && url.scheme() != "http" {
+      return Err(invalid_link_card(&format!("http(s) 以外の URL はリンクカードにできません: {}", raw_url)));
+    }
+    if url.host_str().is_none() {
+      return Err(invalid_link_card(&format!("ホスト名のない URL です: {}", raw_url)));
+    }
+    Ok(trimmed.to_string())
+  }
+}
+
+fn invalid_link_card(detail: &str) -> BlogDomainError {
+  BlogDomainError::InvalidLinkCard { detail: detail.to_string() }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn can_create_link_card_with_metadata() {
+    let id = Uuid::new_v4();
+    let metadata = OgpMetadataVO {
+      title: Some("タイトル".to_string()),
+      description: Some("説明".to_string()),
+      site_name: Some("サイト".to_string()),
+      image_url: Some("https://example.com/ogp.png".to_string()),
+    };
+    let link_card = LinkCardBlockEntity::new(id, " https://example.com/article ".to_string(), metadata.clone()).unwrap();
+
+    assert_eq!(link_card.get_id(), id);
+    assert_eq!(link_card.get_url(), "https://example.com/article");
+    assert_eq!(link_card.get_metadata(), &metadata);
+  }
+
+  #[test]
+  fn non_http_urls_are_rejected() {
+    let result = LinkCardBlockEntity::new(Uuid::new_v4(), "javascript:alert(1)".to_string(), OgpMetadataVO::default());
+    assert!(matches!(result, Err(BlogDomainError::InvalidLinkCard { .. })));
+
+    assert!(LinkCardBlockEntity::new(Uuid::new_v4(), "not a url".to_string(), OgpMetadataVO::default()).is_err());
+  }
+
+  #[test]
+  fn metadata_can_be_refreshed() {
+    let mut link_card = LinkCardBlockEntity::new(Uuid::new_v4(), "https://example.com".to_string(), OgpMetadataVO::default()).unwrap();
+    let refreshed = OgpMetadataVO {
+      title: Some("新しいタイトル".to_string()),
+      ..Default::default()
+    };
+
+    link_card.refresh_metadata(refreshed.clone());
+
+    assert_eq!(link_card.get_metadata(), &refreshed);
+  }
+}
@@ -0,0 +1,23 @@
+use uuid::Uuid;
+
+use super::latex_vo::LatexVO;
+
+#[derive(Debug)]
+pub struct MathBlockEntity {
+  id: Uuid,
+  source: LatexVO,
+}
+
+impl MathBlockEntity {
+  pub fn new(id: Uuid, source: LatexVO) -> Self {
+    Self { id, source }
+  }
+
+  pub fn get_id(&self) -> Uuid {
+    self.id
+  }
+
+  pub fn get_source(&self) -> &str {
+    self.source.get_value()
+  }
+}
@@ -1,3 +1,12 @@
+use uuid::Uuid;
+
+use super::heading_anchor_vo::HeadingAnchorVO;
+use super::latex_vo::LatexVO;
+use crate::domain::blog_domain::errors::blog_domain_error::BlogDomainError;
+
+/// ルビの最大文字数
+const MAX_RUBY_LENGTH: usize = 100;
+
 #[derive(Debug, PartialEq)]
 pub struct RichTextVO {
   text: Vec<RichTextPartVO>,
@@ -18,6 +27,9 @@ pub struct RichTextPartVO {
   text: String,
   styles: RichTextStylesVO,
   link: Option<LinkVO>,
+  ruby: Option<RubyVO>,
+  footnote_ref: Option<Uuid>,
+  inline_math: bool,
 }
 
 impl RichTextPartVO {
@@ -26,9 +38,42 @@ impl RichTextPartVO {
       text,
       styles: styles.unwrap_or_default(),
       link,
+      ruby: None,
+      footnote_ref: None,
+      inline_math: false,
     }
   }
 
+  /// 文字列をインライン数式（LaTeX）として扱う
+  ///
+  /// 文字列が LaTeX として妥当でない場合はエラー
+  pub fn with_inline_math(mut self) -> Result<Self, BlogDomainError> {
+    LatexVO::new(self.text.clone())?;
+    self.inline_math = true;
+    Ok(self)
+  }
+
+  /// ルビを振る
+  ///
+  /// ルビを振る対象の文字列が空（空白のみを含む）の場合はエラー
+  pub fn with_ruby(mut self, ruby: RubyVO) -> Result<Self, BlogDomainError> {
+    if self.text.trim().is_empty() {
+      return Err(BlogDomainError::InvalidRuby {
+        detail: "ルビを振る文字列が空です".to_string(),
+      });
+    }
+    self.ruby = Some(ruby);
+    Ok(self)
+  }
+
+  /// 脚注を参照する
+  ///
+  /// 参照先の脚注が定義されているかどうかは記事全体で検証する
+  pub fn with_footnote_ref(mut self, footnote_id: Uuid) -> Self {
+    self.footnote_ref = Some(footnote_id);
+    self
+  }
+
   pub fn get_text(&self) -> &str {
     &self.text
   }
@@ -40,17 +85,195 @@ impl RichTextPartVO {
   pub fn get_link(&self) -> Option<&LinkVO> {
     self.link.as_ref()
   }
+
+  pub fn get_ruby(&self) -> Option<&RubyVO> {
+    self.ruby.as_ref()
+  }
+
+  pub fn get_footnote_ref(&self) -> Option<Uuid> {
+    self.footnote_ref
+  }
+
+  pub fn is_inline_math(&self) -> bool {
+    self.inline_math
+  }
 }
 
 #[derive(Debug, PartialEq, Default)]
 pub struct RichTextStylesVO {
   pub bold: bool,
   pub inline_code: bool,
+  pub italic: bool,
+  pub strikethrough: bool,
+  pub underline: bool,
+  pub highlight: Option<HighlightColor>,
 }
 
+impl RichTextStylesVO {
+  /// いずれかのスタイルが指定されているかどうか
+  pub fn has_any(&self) -> bool {
+    self.bold || self.inline_code || self.italic || self.strikethrough || self.underline || self.highlight.is_some()
+  }
+}
+
+/// マーカー（背景色）として指定できる色
+///
+/// 配色はフロントエンド側のテーマで決めるため、任意の色ではなく決められたパレットから選ぶ。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HighlightColor {
+  Yellow,
+  Green,
+  Blue,
+  Pink,
+  Red,
+}
+
+impl HighlightColor {
+  pub fn all() -> [HighlightColor; 5] {
+    [
+      HighlightColor::Yellow,
+      HighlightColor::Green,
+      HighlightColor::Blue,
+      HighlightColor::Pink,
+      HighlightColor::Red,
+    ]
+  }
+
+  /// 永続化などに用いる識別子
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      HighlightColor::Yellow => "yellow",
+      HighlightColor::Green => "green",
+      HighlightColor::Blue => "blue",
+      HighlightColor::Pink => "pink",
+      HighlightColor::Red => "red",
+    }
+  }
+
+  pub fn parse(value: &str) -> Option<Self> {
+    Self::all().into_iter().find(|color| color.as_str() == value)
+  }
+}
+
+/// リッチテキストのリンク先
 #[derive(Debug, PartialEq)]
-pub struct LinkVO {
-  pub url: String,
+pub enum LinkVO {
+  /// 入力された URL をそのまま保持するリンク
+  External { url: String },
+  /// 自サイトの記事へのリンク。URL は読み出し時に記事の現在の URL へ解決する
+  Internal(InternalLinkTargetVO),
+}
+
+impl LinkVO {
+  /// リンク先の記事 ID が指定されている場合は内部リンク、そうでない場合は外部リンクとして生成する
+  pub fn new(url: String, post_id: Option<Uuid>, heading_id: Option<Uuid>) -> Self {
+    match post_id {
+      Some(post_id) => LinkVO::Internal(InternalLinkTargetVO::new(post_id, heading_id)),
+      None => LinkVO::External { url },
+    }
+  }
+
+  /// 内部リンクの場合はリンク先の見出しの現在のテキストを設定する
+  pub fn with_heading_text(self, heading_text: Option<String>) -> Self {
+    match self {
+      LinkVO::External { url } => LinkVO::External { url },
+      LinkVO::Internal(target) => LinkVO::Internal(target.with_heading_text(heading_text)),
+    }
+  }
+
+  /// リンク先の URL を解決する
+  pub fn resolve_url(&self) -> String {
+    match self {
+      LinkVO::External { url } => url.clone(),
+      LinkVO::Internal(target) => target.to_path(),
+    }
+  }
+
+  pub fn get_internal_target(&self) -> Option<&InternalLinkTargetVO> {
+    match self {
+      LinkVO::External { .. } => None,
+      LinkVO::Internal(target) => Some(target),
+    }
+  }
+}
+
+/// 内部リンクのリンク先（記事と、必要であれば記事内の見出し）
+///
+/// 見出しのアンカーは見出しのテキストから作るため、記事を読み出すときにリンク先の見出しの現在のテキストを持たせる
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InternalLinkTargetVO {
+  post_id: Uuid,
+  heading_id: Option<Uuid>,
+  heading_text: Option<String>,
+}
+
+impl InternalLinkTargetVO {
+  pub fn new(post_id: Uuid, heading_id: Option<Uuid>) -> Self {
+    Self {
+      post_id,
+      heading_id,
+      heading_text: None,
+    }
+  }
+
+  /// リンク先の見出しの現在のテキストを設定する
+  pub fn with_heading_text(mut self, heading_text: Option<String>) -> Self {
+    self.heading_text = heading_text;
+    self
+  }
+
+  pub fn get_post_id(&self) -> Uuid {
+    self.post_id
+  }
+
+  pub fn get_heading_id(&self) -> Option<Uuid> {
+    self.heading_id
+  }
+
+  /// 記事ページのパスに変換する。見出しが指定されている場合は見出しのアンカーを付与する
+  ///
+  /// 見出しのテキストがない（リンク先の見出しが削除された）場合は、テキストのない見出しと同じアンカーにする
+  pub fn to_path(&self) -> String {
+    match self.heading_id {
+      Some(heading_id) => format!(
+        "/posts/{}#{}",
+        self.post_id,
+        HeadingAnchorVO::new(heading_id, self.heading_text.as_deref().unwrap_or_default())
+      ),
+      None => format!("/posts/{}", self.post_id),
+    }
+  }
+}
+
+/// ルビ（読み仮名）
+#[derive(Debug, Clone, PartialEq)]
+pub struct RubyVO {
+  value: String,
+}
+
+impl RubyVO {
+  /// ルビを検証して値オブジェクトを生成する
+  ///
+  /// # Returns
+  /// * `Result<Self, BlogDomainError>` - ルビが空の場合や長すぎる場合はエラー
+  pub fn new(value: String) -> Result<Self, BlogDomainError> {
+    let value = value.trim().to_string();
+    if value.is_empty() {
+      return Err(BlogDomainError::InvalidRuby {
+        detail: "ルビが空です".to_string(),
+      });
+    }
+    if value.chars().count() > MAX_RUBY_LENGTH {
+      return Err(BlogDomainError::InvalidRuby {
+        detail: format!("ルビは{}文字以内で入力してください", MAX_RUBY_LENGTH),
+      });
+    }
+    Ok(Self { value })
+  }
+
+  pub fn get_value(&self) -> &str {
+    &self.value
+  }
 }
 
 #[cfg(test)]
@@ -66,6 +289,7 @@ mod tests {
         Some(RichTextStylesVO {
           bold: true,
           inline_code: false,
+          ..Default::default()
         }),
         None,
       ),
@@ -80,13 +304,30 @@ mod tests {
 
   #[test]
   fn rich_text_part_vo_can_have_link() {
-    let link = LinkVO {
+    let link = LinkVO::External {
       url: "https://example.com".to_string(),
     };
     let part = RichTextPartVO::new("リンクテキスト".to_string(), None, Some(link));
 
     assert_eq!(part.get_text(), "リンクテキスト");
-    assert_eq!(part.get_link().unwrap().url, "https://example.com");
+    assert_eq!(part.get_link().unwrap().resolve_url(), "https://example.com");
+    assert!(part.get_link().unwrap().get_internal_target().is_none());
+  }
+
+  #[test]
+  fn internal_link_is_resolved_to_post_path() {
+    let post_id = Uuid::parse_str("2f9a4a4f-5b3c-4d2e-9f1a-0c8b7e6d5a41").unwrap();
+    let heading_id = Uuid::parse_str("7c1d2e3f-4a5b-4c6d-8e9f-0a1b2c3d4e5f").unwrap();
+
+    let to_post = LinkVO::Internal(InternalLinkTargetVO::new(post_id, None));
+    assert_eq!(to_post.resolve_url(), "/posts/2f9a4a4f-5b3c-4d2e-9f1a-0c8b7e6d5a41");
+
+    let to_heading = LinkVO::new(String::new(), Some(post_id), Some(heading_id)).with_heading_text(Some("Rust の所有権".to_string()));
+    assert_eq!(to_heading.resolve_url(), "/posts/2f9a4a4f-5b3c-4d2e-9f1a-0c8b7e6d5a41#rust-の所有権-7c1d2e3f");
+
+    // リンク先の見出しが削除されていてテキストがない場合
+    let to_deleted_heading = LinkVO::Internal(InternalLinkTargetVO::new(post_id, Some(heading_id)));
+    assert_eq!(to_deleted_heading.resolve_url(), "/posts/2f9a4a4f-5b3c-4d2e-9f1a-0c8b7e6d5a41#section-7c1d2e3f");
   }
 
   #[test]
@@ -96,6 +337,7 @@ mod tests {
       Some(RichTextStylesVO {
         bold: false,
         inline_code: true,
+        ..Default::default()
       }),
       None,
     );
@@ -103,4 +345,66 @@ mod tests {
     assert!(part.get_styles().inline_code);
     assert!(!part.get_styles().bold);
   }
+
+  #[test]
+  fn rich_text_part_vo_can_be_inline_math() {
+    let part = RichTextPartVO::new(r"e^{i\pi} + 1 = 0".to_string(), None, None).with_inline_math().unwrap();
+
+    assert!(part.is_inline_math());
+    assert!(!part.get_styles().inline_code);
+    assert!(!RichTextPartVO::new("x".to_string(), None, None).is_inline_math());
+  }
+
+  #[test]
+  fn inline_math_must_be_valid_latex() {
+    let result = RichTextPartVO::new(r"\frac{1}{2".to_string(), None, None).with_inline_math();
+
+    assert!(matches!(result, Err(BlogDomainError::InvalidLatex { .. })));
+  }
+
+  #[test]
+  fn rich_text_styles_vo_detects_any_style() {
+    assert!(!RichTextStylesVO::default().has_any());
+    assert!(RichTextStylesVO {
+      strikethrough: true,
+      ..Default::default()
+    }
+    .has_any());
+    assert!(RichTextStylesVO {
+      highlight: Some(HighlightColor::Yellow),
+      ..Default::default()
+    }
+    .has_any());
+  }
+
+  #[test]
+  fn rich_text_part_vo_can_have_ruby() {
+    let ruby = RubyVO::new(" しょうじん ".to_string()).unwrap();
+    let part = RichTextPartVO::new("精進".to_string(), None, None).with_ruby(ruby).unwrap();
+
+    assert_eq!(part.get_ruby().unwrap().get_value(), "しょうじん");
+  }
+
+  #[test]
+  fn ruby_on_empty_text_is_rejected() {
+    let ruby = RubyVO::new("よみ".to_string()).unwrap();
+    let result = RichTextPartVO::new("  ".to_string(), None, None).with_ruby(ruby);
+
+    assert!(matches!(result, Err(BlogDomainError::InvalidRuby { .. })));
+  }
+
+  #[test]
+  fn empty_or_too_long_ruby_is_rejected() {
+    assert!(RubyVO::new(" ".to_string()).is_err());
+    assert!(RubyVO::new("あ".repeat(MAX_RUBY_LENGTH + 1)).is_err());
+    assert!(RubyVO::new("あ".repeat(MAX_RUBY_LENGTH)).is_ok());
+  }
+
+  #[test]
+  fn highlight_color_round_trips_through_string() {
+    for color in HighlightColor::all() {
+      assert_eq!(HighlightColor::parse(color.as_str()), Some(color));
+    }
+    assert_eq!(HighlightColor::parse("purple"), None);
+  }
 }
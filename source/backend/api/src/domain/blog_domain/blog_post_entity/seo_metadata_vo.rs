@@ -0,0 +1,148 @@
+use url::Url;
+
+use crate::domain::{blog_domain::errors::blog_domain_error::BlogDomainError, image_domain::ImageEntity};
+
+/// メタディスクリプションの最大文字数（検索結果で省略されずに表示されるおおよその長さ）
+pub const MAX_META_DESCRIPTION_LENGTH: usize = 160;
+
+/// 正規 URL の最大文字数
+pub const MAX_CANONICAL_URL_LENGTH: usize = 2048;
+
+/// 記事の SEO メタデータ
+///
+/// 未設定の項目はフロントエンドで本文やサムネイルから補う。空文字列は未設定として扱う。
+#[derive(Debug, Default, PartialEq)]
+pub struct SeoMetadataVO {
+  description: Option<String>,
+  og_image: Option<ImageEntity>,
+  canonical_url: Option<String>,
+  noindex: bool,
+}
+
+impl SeoMetadataVO {
+  /// SEO メタデータを検証して値オブジェクトを生成する
+  ///
+  /// # Arguments
+  /// * `description` - メタディスクリプション（[`MAX_META_DESCRIPTION_LENGTH`] 文字以内）
+  /// * `og_image` - OGP 画像として サムネイルの代わりに使う画像
+  /// * `canonical_url` - 正規 URL（http / https の絶対 URL）
+  /// * `noindex` - 検索エンジンにインデックスさせない場合は true
+  pub fn new(description: Option<String>, og_image: Option<ImageEntity>, canonical_url: Option<String>, noindex: bool) -> Result<Self, BlogDomainError> {
+    let description = normalize(description);
+    if let Some(description) = &description {
+      let length = description.chars().count();
+      if length > MAX_META_DESCRIPTION_LENGTH {
+        return Err(BlogDomainError::InvalidSeoMetadata {
+          detail: format!(
+            "メタディスクリプションは {} 文字以内で指定してください（{} 文字）",
+            MAX_META_DESCRIPTION_LENGTH, length
+          ),
+        });
+      }
+    }
+
+    let canonical_url = normalize(canonical_url);
+    if let Some(canonical_url) = &canonical_url {
+      validate_canonical_url(canonical_url)?;
+    }
+
+    Ok(Self {
+      description,
+      og_image,
+      canonical_url,
+      noindex,
+    })
+  }
+
+  pub fn get_description(&self) -> Option<&str> {
+    self.description.as_deref()
+  }
+
+  pub fn get_og_image(&self) -> Option<&ImageEntity> {
+    self.og_image.as_ref()
+  }
+
+  pub fn get_canonical_url(&self) -> Option<&str> {
+    self.canonical_url.as_deref()
+  }
+
+  pub fn is_noindex(&self) -> bool {
+    self.noindex
+  }
+
+  /// 何も設定されていないかどうか
+  pub fn is_empty(&self) -> bool {
+    *self == Self::default()
+  }
+}
+
+fn normalize(value: Option<String>) -> Option<String> {
+  value.map(|value| value.trim().to_string()).filter(|value| !value.is_empty())
+}
+
+fn validate_canonical_url(canonical_url: &str) -> Result<(), BlogDomainError> {
+  if canonical_url.len() > MAX_CANONICAL_URL_LENGTH {
+    return Err(BlogDomainError::InvalidSeoMetadata {
+      detail: format!("正規 URL は {} 文字以内で指定してください", MAX_CANONICAL_URL_LENGTH),
+    });
+  }
+
+  match Url::parse(canonical_url) {
+    Ok(url) if matches!(url.scheme(), "http" | "https") && url.has_host() => Ok(()),
+    _ => Err(BlogDomainError::InvalidSeoMetadata {
+      detail: format!("正規 URL は http または https の絶対 URL で指定してください: {}", canonical_url),
+    }),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use uuid::Uuid;
+
+  #[test]
+  fn treats_blank_values_as_unset() {
+    let seo_metadata = SeoMetadataVO::new(Some("  ".to_string()), None, Some(String::new()), false).unwrap();
+
+    assert!(seo_metadata.is_empty());
+  }
+
+  #[test]
+  fn keeps_valid_values() {
+    let og_image = ImageEntity::new(Uuid::new_v4(), "ogp/a.png".to_string());
+
+    let seo_metadata = SeoMetadataVO::new(
+      Some(" 記事の説明 ".to_string()),
+      Some(og_image),
+      Some("https://example.com/posts/1".to_string()),
+      true,
+    )
+    .unwrap();
+
+    assert_eq!(seo_metadata.get_description(), Some("記事の説明"));
+    assert_eq!(seo_metadata.get_og_image().unwrap().get_path(), "ogp/a.png");
+    assert_eq!(seo_metadata.get_canonical_url(), Some("https://example.com/posts/1"));
+    assert!(seo_metadata.is_noindex());
+  }
+
+  #[test]
+  fn rejects_too_long_description() {
+    let description = "あ".repeat(MAX_META_DESCRIPTION_LENGTH + 1);
+
+    let result = SeoMetadataVO::new(Some(description), None, None, false);
+
+    assert!(matches!(result, Err(BlogDomainError::InvalidSeoMetadata { .. })));
+    assert!(SeoMetadataVO::new(Some("あ".repeat(MAX_META_DESCRIPTION_LENGTH)), None, None, false).is_ok());
+  }
+
+  #[test]
+  fn rejects_invalid_canonical_url() {
+    for canonical_url in ["/posts/1", "javascript:alert(1)", "ftp://example.com/a"] {
+      let result = SeoMetadataVO::new(None, None, Some(canonical_url.to_string()), false);
+      assert!(matches!(result, Err(BlogDomainError::InvalidSeoMetadata { .. })), "{}", canonical_url);
+    }
+
+    let too_long = format!("https://example.com/{}", "a".repeat(MAX_CANONICAL_URL_LENGTH));
+    assert!(SeoMetadataVO::new(None, None, Some(too_long), false).is_err());
+  }
+}
@@ -1,14 +1,24 @@
 use std::sync::Arc;
 use uuid::Uuid;
 
+use crate::domain::image_domain::ImageEntity;
+
 use super::{
   blog_post_entity::{
+    code_block_options_vo::CodeBlockOptionsVO,
     content_entity::ContentEntity,
-    rich_text_vo::{LinkVO, RichTextPartVO, RichTextStylesVO, RichTextVO},
+    diagram_source_vo::DiagramKind,
+    footnote_entity::FootnoteEntity,
+    image_presentation_vo::ImagePresentationVO,
+    rich_text_vo::{HighlightColor, LinkVO, RichTextPartVO, RichTextStylesVO, RichTextVO, RubyVO},
+    seo_metadata_vo::SeoMetadataVO,
     BlogPostEntity,
   },
+  embed_provider_registry::EmbedProviderRegistry,
+  errors::blog_domain_error::BlogDomainError,
   image_content_factory::{ImageContentFactory, ImageContentFactoryError},
   jst_date_vo::JstDate,
+  link_card_content_factory::{LinkCardContentFactory, LinkCardFetchFailure},
 };
 
 // ファクトリの入力用構造体（APIレスポンス型を参考にドメイン層独自に定義）
@@ -21,6 +31,10 @@ pub struct CreateBlogPostInput {
   pub last_update_date: Option<JstDate>,
   pub published_date: Option<JstDate>,
   pub contents: Vec<CreateContentInput>,
+  pub footnotes: Vec<CreateFootnoteInput>,
+  pub seo_metadata: CreateSeoMetadataInput,
+  // 記事一覧に表示する抜粋。未指定の場合は本文から自動で作る
+  pub manual_excerpt: Option<String>,
 }
 
 #[derive(Debug)]
@@ -31,11 +45,81 @@ pub struct CreateImageInput {
 
 #[derive(Debug)]
 pub enum CreateContentInput {
-  H2 { id: Uuid, text: String },
-  H3 { id: Uuid, text: String },
-  Paragraph { id: Uuid, text: Vec<CreateRichTextInput> },
-  Image { id: Uuid, path: String },
-  CodeBlock { id: Uuid, title: String, code: String, language: String },
+  H2 {
+    id: Uuid,
+    text: String,
+  },
+  H3 {
+    id: Uuid,
+    text: String,
+  },
+  Paragraph {
+    id: Uuid,
+    text: Vec<CreateRichTextInput>,
+  },
+  Image {
+    id: Uuid,
+    path: String,
+    presentation: CreateImagePresentationInput,
+  },
+  CodeBlock {
+    id: Uuid,
+    title: String,
+    code: String,
+    language: String,
+    options: CreateCodeBlockOptionsInput,
+  },
+  Math {
+    id: Uuid,
+    source: String,
+  },
+  Embed {
+    id: Uuid,
+    url: String,
+  },
+  LinkCard {
+    id: Uuid,
+    url: String,
+  },
+  Details {
+    id: Uuid,
+    summary: String,
+    children: Vec<CreateContentInput>,
+  },
+  Diagram {
+    id: Uuid,
+    kind: DiagramKind,
+    source: String,
+  },
+}
+
+/// コードブロックの表示オプション
+#[derive(Debug, Default)]
+pub struct CreateCodeBlockOptionsInput {
+  /// 強調表示する行（例: "3-5,9"）
+  pub highlight_lines: Option<String>,
+  /// 1行目に表示する行番号。未指定の場合は 1
+  pub start_line: Option<u32>,
+  pub diff: bool,
+  pub show_line_numbers: bool,
+}
+
+/// 記事の SEO メタデータ。未指定の項目は `None`
+#[derive(Debug, Default)]
+pub struct CreateSeoMetadataInput {
+  pub description: Option<String>,
+  pub og_image: Option<CreateImageInput>,
+  pub canonical_url: Option<String>,
+  pub noindex: bool,
+}
+
+/// 記事内での画像の見せ方
+#[derive(Debug, Default)]
+pub struct CreateImagePresentationInput {
+  pub alt: String,
+  pub caption: Option<String>,
+  pub width: Option<u32>,
+  pub link_url: Option<String>,
 }
 
 #[derive(Debug)]
@@ -43,17 +127,37 @@ pub struct CreateRichTextInput {
   pub text: String,
   pub styles: CreateStyleInput,
   pub link: Option<CreateLinkInput>,
+  pub ruby: Option<String>,
+  /// 参照する脚注の ID
+  pub footnote_id: Option<Uuid>,
+}
+
+/// 脚注。ID は本文中の脚注参照と対応付けるため呼び出し側で採番する
+#[derive(Debug)]
+pub struct CreateFootnoteInput {
+  pub id: Uuid,
+  pub text: Vec<CreateRichTextInput>,
 }
 
 #[derive(Debug)]
 pub struct CreateStyleInput {
   pub bold: bool,
   pub inline_code: bool,
+  pub inline_math: bool,
+  pub italic: bool,
+  pub strikethrough: bool,
+  pub underline: bool,
+  pub highlight: Option<HighlightColor>,
 }
 
 #[derive(Debug)]
 pub struct CreateLinkInput {
+  /// 内部リンクの場合は無視される
   pub url: String,
+  /// 内部リンクのリンク先の記事 ID
+  pub post_id: Option<Uuid>,
+  /// 内部リンクのリンク先の見出し ID
+  pub heading_id: Option<Uuid>,
 }
 
 // BlogPostFactory 構造体
@@ -61,12 +165,14 @@ pub struct CreateLinkInput {
 #[derive(Debug, PartialEq)]
 pub enum BlogPostFactoryError {
   ImageContentCreationFailed(String),
+  InvalidContent(BlogDomainError),
 }
 
 impl std::fmt::Display for BlogPostFactoryError {
   fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
     match self {
       BlogPostFactoryError::ImageContentCreationFailed(msg) => write!(f, "Image content creation failed: {}", msg),
+      BlogPostFactoryError::InvalidContent(error) => write!(f, "{}", error),
     }
   }
 }
@@ -79,16 +185,34 @@ impl From<ImageContentFactoryError> for BlogPostFactoryError {
   }
 }
 
+impl From<BlogDomainError> for BlogPostFactoryError {
+  fn from(error: BlogDomainError) -> Self {
+    BlogPostFactoryError::InvalidContent(error)
+  }
+}
+
+/// 記事の生成結果
+#[derive(Debug)]
+pub struct BlogPostCreation {
+  pub blog_post: BlogPostEntity,
+  /// OGP メタデータを取得できず、空のメタデータで生成したリンクカード
+  pub link_card_fetch_failures: Vec<LinkCardFetchFailure>,
+}
+
 pub struct BlogPostFactory {
   image_content_factory: Arc<ImageContentFactory>,
+  link_card_content_factory: Arc<LinkCardContentFactory>,
 }
 
 impl BlogPostFactory {
-  pub fn new(image_content_factory: Arc<ImageContentFactory>) -> Self {
-    Self { image_content_factory }
+  pub fn new(image_content_factory: Arc<ImageContentFactory>, link_card_content_factory: Arc<LinkCardContentFactory>) -> Self {
+    Self {
+      image_content_factory,
+      link_card_content_factory,
+    }
   }
 
-  pub async fn create(&self, input: CreateBlogPostInput) -> Result<BlogPostEntity, BlogPostFactoryError> {
+  pub async fn create(&self, input: CreateBlogPostInput) -> Result<BlogPostCreation, BlogPostFactoryError> {
     // 新しいIDを生成
     let post_id = Uuid::new_v4();
 
@@ -118,52 +242,136 @@ impl BlogPostFactory {
     }
 
     // コンテンツを変換して追加
+    let mut link_card_fetch_failures = Vec::new();
     for content_input in input.contents {
-      let content_entity = self.convert_content(content_input).await?;
+      let content_entity = self.convert_content(content_input, &mut link_card_fetch_failures).await?;
       blog_post.add_content(content_entity);
     }
 
-    Ok(blog_post)
+    // 脚注は本文中の参照と突き合わせるため、コンテンツを追加した後に設定する
+    let footnotes = input.footnotes.into_iter().map(Self::convert_footnote).collect::<Result<Vec<_>, _>>()?;
+    blog_post.set_footnotes(footnotes)?;
+
+    blog_post.set_seo_metadata(Self::convert_seo_metadata(input.seo_metadata)?);
+    blog_post.set_manual_excerpt(input.manual_excerpt)?;
+
+    Ok(BlogPostCreation {
+      blog_post,
+      link_card_fetch_failures,
+    })
   }
 
-  async fn convert_content(&self, input: CreateContentInput) -> Result<ContentEntity, BlogPostFactoryError> {
+  fn convert_seo_metadata(input: CreateSeoMetadataInput) -> Result<SeoMetadataVO, BlogDomainError> {
+    SeoMetadataVO::new(
+      input.description,
+      input.og_image.map(|og_image| ImageEntity::new(og_image.id, og_image.path)),
+      input.canonical_url,
+      input.noindex,
+    )
+  }
+
+  fn convert_footnote(input: CreateFootnoteInput) -> Result<FootnoteEntity, BlogDomainError> {
+    let rich_text_parts: Vec<RichTextPartVO> = input.text.into_iter().map(Self::convert_rich_text).collect::<Result<_, _>>()?;
+    FootnoteEntity::new(input.id, RichTextVO::new(rich_text_parts))
+  }
+
+  async fn convert_content(
+    &self,
+    input: CreateContentInput,
+    link_card_fetch_failures: &mut Vec<LinkCardFetchFailure>,
+  ) -> Result<ContentEntity, BlogPostFactoryError> {
     match input {
       CreateContentInput::H2 { id, text } => Ok(ContentEntity::h2(id, text)),
       CreateContentInput::H3 { id, text } => Ok(ContentEntity::h3(id, text)),
       CreateContentInput::Paragraph { id, text } => {
-        let rich_text_parts: Vec<RichTextPartVO> = text.into_iter().map(Self::convert_rich_text).collect();
+        let rich_text_parts: Vec<RichTextPartVO> = text.into_iter().map(Self::convert_rich_text).collect::<Result<_, _>>()?;
         let rich_text = RichTextVO::new(rich_text_parts);
         Ok(ContentEntity::paragraph(id, rich_text))
       }
-      CreateContentInput::Image { id: _unused_id, path } => {
+      CreateContentInput::Image {
+        id: _unused_id,
+        path,
+        presentation,
+      } => {
+        // 画像の検索より先に見せ方を検証し、不正な入力ではリポジトリへ問い合わせない
+        let presentation = ImagePresentationVO::new(presentation.alt, presentation.caption, presentation.width, presentation.link_url)?;
         // pathを使ってImageContentFactoryから適切なエンティティを作成
         let image_content = self.image_content_factory.create(path).await?;
-        Ok(ContentEntity::image_from_entity(image_content))
+        Ok(ContentEntity::image_from_entity(image_content.with_presentation(presentation)))
+      }
+      CreateContentInput::CodeBlock {
+        id,
+        title,
+        code,
+        language,
+        options,
+      } => {
+        let options = CodeBlockOptionsVO::new(
+          options.highlight_lines.as_deref().unwrap_or_default(),
+          options.start_line.unwrap_or(1),
+          options.diff,
+          options.show_line_numbers,
+        )?;
+        Ok(ContentEntity::code_block(id, title, code, language, options)?)
+      }
+      CreateContentInput::Math { id, source } => Ok(ContentEntity::math_block(id, source)?),
+      CreateContentInput::Embed { id, url } => {
+        // 貼り付けられた URL からプロバイダとリソース ID のみを取り出して保持する
+        let resolved = EmbedProviderRegistry::default().resolve(&url)?;
+        Ok(ContentEntity::embed(id, resolved.provider, resolved.resource_id)?)
+      }
+      CreateContentInput::LinkCard { id, url } => {
+        // 作成時に OGP メタデータを取得し、閲覧時には保存済みの値を使う
+        let creation = self.link_card_content_factory.create(id, url).await?;
+        link_card_fetch_failures.extend(creation.fetch_failure);
+        Ok(ContentEntity::link_card_from_entity(creation.link_card))
+      }
+      CreateContentInput::Details { id, summary, children } => {
+        // 入れ子の深さや子に置けるコンテンツの検証は DetailsBlockEntity に任せる
+        let mut child_entities = Vec::with_capacity(children.len());
+        for child in children {
+          child_entities.push(Box::pin(self.convert_content(child, link_card_fetch_failures)).await?);
+        }
+        Ok(ContentEntity::details(id, summary, child_entities)?)
       }
-      CreateContentInput::CodeBlock { id, title, code, language } => Ok(ContentEntity::code_block(id, title, code, language)),
+      CreateContentInput::Diagram { id, kind, source } => Ok(ContentEntity::diagram(id, kind, source)?),
     }
   }
 
-  fn convert_rich_text(input: CreateRichTextInput) -> RichTextPartVO {
-    let link_vo = input.link.map(|link| LinkVO { url: link.url });
+  fn convert_rich_text(input: CreateRichTextInput) -> Result<RichTextPartVO, BlogDomainError> {
+    let link_vo = input.link.map(|link| LinkVO::new(link.url, link.post_id, link.heading_id));
 
     // スタイル情報を持つ場合とそうでない場合を判別
-    let style_vo = if input.styles.bold || input.styles.inline_code {
-      Some(RichTextStylesVO {
-        bold: input.styles.bold,
-        inline_code: input.styles.inline_code,
-      })
-    } else {
-      None
+    let styles = RichTextStylesVO {
+      bold: input.styles.bold,
+      inline_code: input.styles.inline_code,
+      italic: input.styles.italic,
+      strikethrough: input.styles.strikethrough,
+      underline: input.styles.underline,
+      highlight: input.styles.highlight,
     };
+    let style_vo = if styles.has_any() { Some(styles) } else { None };
 
-    RichTextPartVO::new(input.text, style_vo, link_vo)
+    let mut part = RichTextPartVO::new(input.text, style_vo, link_vo);
+    if input.styles.inline_math {
+      part = part.with_inline_math()?;
+    }
+    if let Some(ruby) = input.ruby {
+      part = part.with_ruby(RubyVO::new(ruby)?)?;
+    }
+    if let Some(footnote_id) = input.footnote_id {
+      part = part.with_footnote_ref(footnote_id);
+    }
+    Ok(part)
   }
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::domain::blog_domain::blog_post_entity::embed_block_entity::EmbedProvider;
+  use crate::domain::blog_domain::blog_post_entity::link_card_block_entity::OgpMetadataVO;
+  use crate::domain::blog_domain::ogp_fetcher::OgpFetcher;
   use crate::domain::image_domain::{image_entity::ImageEntity, image_repository::ImageRepository, image_repository::ImageRepositoryError};
   use async_trait::async_trait;
   use std::collections::HashMap;
@@ -206,11 +414,28 @@ mod tests {
     }
   }
 
+  // テスト用の OGP 取得スタブ
+  struct StubOgpFetcher;
+
+  #[async_trait]
+  impl OgpFetcher for StubOgpFetcher {
+    async fn fetch(&self, url: &str) -> anyhow::Result<OgpMetadataVO> {
+      Ok(OgpMetadataVO {
+        title: Some(format!("{} のタイトル", url)),
+        ..Default::default()
+      })
+    }
+  }
+
+  fn link_card_factory() -> Arc<LinkCardContentFactory> {
+    Arc::new(LinkCardContentFactory::new(Arc::new(StubOgpFetcher)))
+  }
+
   #[tokio::test]
   async fn basic_blog_post_creation() {
     let mock_repo = MockImageRepository::new();
     let image_factory = Arc::new(ImageContentFactory::new(Arc::new(mock_repo)));
-    let factory = BlogPostFactory::new(image_factory);
+    let factory = BlogPostFactory::new(image_factory, link_card_factory());
 
     let input = CreateBlogPostInput {
       title: "テスト記事".to_string(),
@@ -219,12 +444,15 @@ mod tests {
       last_update_date: None,
       published_date: None,
       contents: vec![],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
     };
 
     let result = factory.create(input).await;
 
     assert!(result.is_ok());
-    let blog_post = result.unwrap();
+    let blog_post = result.unwrap().blog_post;
 
     assert_eq!(blog_post.get_title_text(), "テスト記事");
     assert!(blog_post.get_thumbnail().is_none());
@@ -236,7 +464,7 @@ mod tests {
   async fn blog_post_creation_with_thumbnail() {
     let mock_repo = MockImageRepository::new();
     let image_factory = Arc::new(ImageContentFactory::new(Arc::new(mock_repo)));
-    let factory = BlogPostFactory::new(image_factory);
+    let factory = BlogPostFactory::new(image_factory, link_card_factory());
 
     let thumbnail_id = Uuid::parse_str("00000000-0000-0000-0000-000000000001").unwrap();
     let input = CreateBlogPostInput {
@@ -249,12 +477,15 @@ mod tests {
       last_update_date: None,
       published_date: None,
       contents: vec![],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
     };
 
     let result = factory.create(input).await;
 
     assert!(result.is_ok());
-    let blog_post = result.unwrap();
+    let blog_post = result.unwrap().blog_post;
 
     let thumbnail = blog_post.get_thumbnail().unwrap();
     assert_eq!(thumbnail.get_id(), thumbnail_id);
@@ -270,7 +501,7 @@ mod tests {
     mock_repo.add_image(image_path.clone(), image_entity);
 
     let image_factory = Arc::new(ImageContentFactory::new(Arc::new(mock_repo)));
-    let factory = BlogPostFactory::new(image_factory);
+    let factory = BlogPostFactory::new(image_factory, link_card_factory());
 
     let h2_id = Uuid::parse_str("00000000-0000-0000-0000-000000000001").unwrap();
     let h3_id = Uuid::parse_str("00000000-0000-0000-0000-000000000002").unwrap();
@@ -300,24 +531,39 @@ mod tests {
             styles: CreateStyleInput {
               bold: false,
               inline_code: false,
+              inline_math: false,
+              italic: false,
+              strikethrough: false,
+              underline: false,
+              highlight: None,
             },
             link: None,
+            ruby: None,
+            footnote_id: None,
           }],
         },
-        CreateContentInput::Image { id: img_id, path: image_path },
+        CreateContentInput::Image {
+          id: img_id,
+          path: image_path,
+          presentation: CreateImagePresentationInput::default(),
+        },
         CreateContentInput::CodeBlock {
           id: code_id,
           title: "サンプルコード".to_string(),
           code: "println!(\"Hello, world!\");".to_string(),
           language: "rust".to_string(),
+          options: CreateCodeBlockOptionsInput::default(),
         },
       ],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
     };
 
     let result = factory.create(input).await;
 
     assert!(result.is_ok());
-    let blog_post = result.unwrap();
+    let blog_post = result.unwrap().blog_post;
 
     assert_eq!(blog_post.get_contents().len(), 5);
 
@@ -356,7 +602,7 @@ mod tests {
 
     let mock_repo = MockImageRepository::new();
     let image_factory = Arc::new(ImageContentFactory::new(Arc::new(mock_repo)));
-    let factory = BlogPostFactory::new(image_factory);
+    let factory = BlogPostFactory::new(image_factory, link_card_factory());
 
     let specified_date = NaiveDate::from_ymd_opt(2024, 6, 15).unwrap();
     let input = CreateBlogPostInput {
@@ -366,12 +612,15 @@ mod tests {
       last_update_date: Some(JstDate::from_jst_naive_date(specified_date)),
       published_date: None,
       contents: vec![],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
     };
 
     let result = factory.create(input).await;
 
     assert!(result.is_ok());
-    let blog_post = result.unwrap();
+    let blog_post = result.unwrap().blog_post;
 
     assert_eq!(blog_post.get_post_date(), &JstDate::from_jst_naive_date(specified_date));
   }
@@ -380,7 +629,7 @@ mod tests {
   async fn rich_text_conversion_accuracy() {
     let mock_repo = MockImageRepository::new();
     let image_factory = Arc::new(ImageContentFactory::new(Arc::new(mock_repo)));
-    let factory = BlogPostFactory::new(image_factory);
+    let factory = BlogPostFactory::new(image_factory, link_card_factory());
 
     let para_id = Uuid::parse_str("00000000-0000-0000-0000-000000000001").unwrap();
 
@@ -398,43 +647,76 @@ mod tests {
             styles: CreateStyleInput {
               bold: false,
               inline_code: false,
+              inline_math: false,
+              italic: false,
+              strikethrough: false,
+              underline: false,
+              highlight: None,
             },
             link: None,
+            ruby: None,
+            footnote_id: None,
           },
           CreateRichTextInput {
             text: "太字テキスト".to_string(),
             styles: CreateStyleInput {
               bold: true,
               inline_code: false,
+              inline_math: false,
+              italic: false,
+              strikethrough: false,
+              underline: false,
+              highlight: None,
             },
             link: None,
+            ruby: None,
+            footnote_id: None,
           },
           CreateRichTextInput {
             text: "リンクテキスト".to_string(),
             styles: CreateStyleInput {
               bold: false,
               inline_code: false,
+              inline_math: false,
+              italic: false,
+              strikethrough: false,
+              underline: false,
+              highlight: None,
             },
             link: Some(CreateLinkInput {
               url: "https://example.com".to_string(),
+              post_id: None,
+              heading_id: None,
             }),
+            ruby: None,
+            footnote_id: None,
           },
           CreateRichTextInput {
             text: "インラインコード".to_string(),
             styles: CreateStyleInput {
               bold: false,
               inline_code: true,
+              inline_math: false,
+              italic: false,
+              strikethrough: false,
+              underline: false,
+              highlight: None,
             },
             link: None,
+            ruby: None,
+            footnote_id: None,
           },
         ],
       }],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
     };
 
     let result = factory.create(input).await;
 
     assert!(result.is_ok());
-    let blog_post = result.unwrap();
+    let blog_post = result.unwrap().blog_post;
 
     let contents = blog_post.get_contents();
     match &contents[0] {
@@ -457,7 +739,7 @@ mod tests {
 
         // リンクテキスト
         assert_eq!(parts[2].get_text(), "リンクテキスト");
-        assert_eq!(parts[2].get_link().unwrap().url, "https://example.com");
+        assert_eq!(parts[2].get_link().unwrap().resolve_url(), "https://example.com");
 
         // インラインコード
         assert_eq!(parts[3].get_text(), "インラインコード");
@@ -472,7 +754,7 @@ mod tests {
   async fn edge_case_empty_content() {
     let mock_repo = MockImageRepository::new();
     let image_factory = Arc::new(ImageContentFactory::new(Arc::new(mock_repo)));
-    let factory = BlogPostFactory::new(image_factory);
+    let factory = BlogPostFactory::new(image_factory, link_card_factory());
 
     let input = CreateBlogPostInput {
       title: "空の記事".to_string(),
@@ -481,12 +763,15 @@ mod tests {
       last_update_date: None,
       published_date: None,
       contents: vec![],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
     };
 
     let result = factory.create(input).await;
 
     assert!(result.is_ok());
-    let blog_post = result.unwrap();
+    let blog_post = result.unwrap().blog_post;
 
     assert_eq!(blog_post.get_contents().len(), 0);
     assert_eq!(blog_post.get_title_text(), "空の記事");
@@ -496,7 +781,7 @@ mod tests {
   async fn factory_generates_unique_ids_automatically() {
     let mock_repo = MockImageRepository::new();
     let image_factory = Arc::new(ImageContentFactory::new(Arc::new(mock_repo)));
-    let factory = BlogPostFactory::new(image_factory);
+    let factory = BlogPostFactory::new(image_factory, link_card_factory());
 
     let input1 = CreateBlogPostInput {
       title: "記事1".to_string(),
@@ -505,6 +790,9 @@ mod tests {
       last_update_date: None,
       published_date: None,
       contents: vec![],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
     };
 
     let input2 = CreateBlogPostInput {
@@ -514,6 +802,9 @@ mod tests {
       last_update_date: None,
       published_date: None,
       contents: vec![],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
     };
 
     let result1 = factory.create(input1).await;
@@ -522,8 +813,8 @@ mod tests {
     assert!(result1.is_ok());
     assert!(result2.is_ok());
 
-    let blog_post1 = result1.unwrap();
-    let blog_post2 = result2.unwrap();
+    let blog_post1 = result1.unwrap().blog_post;
+    let blog_post2 = result2.unwrap().blog_post;
 
     // 各記事が異なるIDを持つことを確認（重要なビジネスロジック）
     assert_ne!(blog_post1.get_id(), blog_post2.get_id());
@@ -541,7 +832,7 @@ mod tests {
   async fn multiple_executions_generate_different_ids_each_time() {
     let mock_repo = MockImageRepository::new();
     let image_factory = Arc::new(ImageContentFactory::new(Arc::new(mock_repo)));
-    let factory = BlogPostFactory::new(image_factory);
+    let factory = BlogPostFactory::new(image_factory, link_card_factory());
 
     // 同じ入力で10回記事を生成
     let mut generated_ids = std::collections::HashSet::new();
@@ -554,11 +845,14 @@ mod tests {
           last_update_date: None,
           published_date: None,
           contents: vec![],
+          footnotes: vec![],
+          seo_metadata: Default::default(),
+          manual_excerpt: Default::default(),
         })
         .await;
 
       assert!(result.is_ok());
-      let blog_post = result.unwrap();
+      let blog_post = result.unwrap().blog_post;
 
       // 重複するIDが生成されないことを確認
       assert!(generated_ids.insert(blog_post.get_id()), "重複したIDが生成されました: {}", blog_post.get_id());
@@ -574,7 +868,7 @@ mod tests {
 
     let mock_repo = MockImageRepository::new();
     let image_factory = Arc::new(ImageContentFactory::new(Arc::new(mock_repo)));
-    let factory = BlogPostFactory::new(image_factory);
+    let factory = BlogPostFactory::new(image_factory, link_card_factory());
 
     let specified_published_date = NaiveDate::from_ymd_opt(2024, 7, 20).unwrap();
     let input = CreateBlogPostInput {
@@ -584,12 +878,15 @@ mod tests {
       last_update_date: None,
       published_date: Some(JstDate::from_jst_naive_date(specified_published_date)),
       contents: vec![],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
     };
 
     let result = factory.create(input).await;
 
     assert!(result.is_ok());
-    let blog_post = result.unwrap();
+    let blog_post = result.unwrap().blog_post;
 
     assert_eq!(blog_post.get_published_date(), &JstDate::from_jst_naive_date(specified_published_date));
     assert_eq!(blog_post.get_title_text(), "公開日指定記事");
@@ -599,7 +896,7 @@ mod tests {
   async fn blog_post_creation_without_published_date_uses_default() {
     let mock_repo = MockImageRepository::new();
     let image_factory = Arc::new(ImageContentFactory::new(Arc::new(mock_repo)));
-    let factory = BlogPostFactory::new(image_factory);
+    let factory = BlogPostFactory::new(image_factory, link_card_factory());
 
     let input = CreateBlogPostInput {
       title: "デフォルト公開日記事".to_string(),
@@ -608,15 +905,625 @@ mod tests {
       last_update_date: None,
       published_date: None,
       contents: vec![],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
     };
 
     let result = factory.create(input).await;
 
     assert!(result.is_ok());
-    let blog_post = result.unwrap();
+    let blog_post = result.unwrap().blog_post;
 
     // デフォルトでは今日の日付が設定される
     assert_eq!(blog_post.get_published_date(), &JstDate::today());
     assert_eq!(blog_post.get_title_text(), "デフォルト公開日記事");
   }
+
+  #[tokio::test]
+  async fn blog_post_creation_with_math_block() {
+    let mock_repo = MockImageRepository::new();
+    let image_factory = Arc::new(ImageContentFactory::new(Arc::new(mock_repo)));
+    let factory = BlogPostFactory::new(image_factory, link_card_factory());
+
+    let math_id = Uuid::new_v4();
+    let input = CreateBlogPostInput {
+      title: "数式記事".to_string(),
+      thumbnail: None,
+      post_date: None,
+      last_update_date: None,
+      published_date: None,
+      contents: vec![
+        CreateContentInput::Math {
+          id: math_id,
+          source: r"\sum_{i=1}^{n} i = \frac{n(n+1)}{2}".to_string(),
+        },
+        CreateContentInput::Paragraph {
+          id: Uuid::new_v4(),
+          text: vec![CreateRichTextInput {
+            text: r"O(n \log n)".to_string(),
+            styles: CreateStyleInput {
+              bold: false,
+              inline_code: false,
+              inline_math: true,
+              italic: false,
+              strikethrough: false,
+              underline: false,
+              highlight: None,
+            },
+            link: None,
+            ruby: None,
+            footnote_id: None,
+          }],
+        },
+      ],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
+    };
+
+    let blog_post = factory.create(input).await.unwrap().blog_post;
+    let contents = blog_post.get_contents();
+
+    match &contents[0] {
+      ContentEntity::Math(math) => {
+        assert_eq!(math.get_id(), math_id);
+        assert_eq!(math.get_source(), r"\sum_{i=1}^{n} i = \frac{n(n+1)}{2}");
+      }
+      _ => panic!("最初のコンテンツはMathである必要があります"),
+    }
+    match &contents[1] {
+      ContentEntity::Paragraph(paragraph) => assert!(paragraph.get_value().get_text()[0].is_inline_math()),
+      _ => panic!("2番目のコンテンツはParagraphである必要があります"),
+    }
+  }
+
+  #[tokio::test]
+  async fn blog_post_creation_fails_with_invalid_latex() {
+    let mock_repo = MockImageRepository::new();
+    let image_factory = Arc::new(ImageContentFactory::new(Arc::new(mock_repo)));
+    let factory = BlogPostFactory::new(image_factory, link_card_factory());
+
+    let input = CreateBlogPostInput {
+      title: "不正な数式記事".to_string(),
+      thumbnail: None,
+      post_date: None,
+      last_update_date: None,
+      published_date: None,
+      contents: vec![CreateContentInput::Math {
+        id: Uuid::new_v4(),
+        source: r"\frac{1}{2".to_string(),
+      }],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
+    };
+
+    let result = factory.create(input).await;
+
+    assert!(matches!(
+      result,
+      Err(BlogPostFactoryError::InvalidContent(BlogDomainError::InvalidLatex { .. }))
+    ));
+  }
+
+  #[tokio::test]
+  async fn blog_post_creation_fails_with_disallowed_macro_in_inline_math() {
+    let mock_repo = MockImageRepository::new();
+    let image_factory = Arc::new(ImageContentFactory::new(Arc::new(mock_repo)));
+    let factory = BlogPostFactory::new(image_factory, link_card_factory());
+
+    let input = CreateBlogPostInput {
+      title: "不正なインライン数式記事".to_string(),
+      thumbnail: None,
+      post_date: None,
+      last_update_date: None,
+      published_date: None,
+      contents: vec![CreateContentInput::Paragraph {
+        id: Uuid::new_v4(),
+        text: vec![CreateRichTextInput {
+          text: r"\input{secret}".to_string(),
+          styles: CreateStyleInput {
+            bold: false,
+            inline_code: false,
+            inline_math: true,
+            italic: false,
+            strikethrough: false,
+            underline: false,
+            highlight: None,
+          },
+          link: None,
+          ruby: None,
+          footnote_id: None,
+        }],
+      }],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
+    };
+
+    let result = factory.create(input).await;
+
+    assert!(matches!(result, Err(BlogPostFactoryError::InvalidContent(_))));
+  }
+
+  #[tokio::test]
+  async fn blog_post_creation_keeps_decoration_styles() {
+    let mock_repo = MockImageRepository::new();
+    let image_factory = Arc::new(ImageContentFactory::new(Arc::new(mock_repo)));
+    let factory = BlogPostFactory::new(image_factory, link_card_factory());
+
+    let input = CreateBlogPostInput {
+      title: "装飾テキスト記事".to_string(),
+      thumbnail: None,
+      post_date: None,
+      last_update_date: None,
+      published_date: None,
+      contents: vec![CreateContentInput::Paragraph {
+        id: Uuid::new_v4(),
+        text: vec![CreateRichTextInput {
+          text: "重要な箇所".to_string(),
+          styles: CreateStyleInput {
+            bold: false,
+            inline_code: false,
+            inline_math: false,
+            italic: true,
+            strikethrough: false,
+            underline: true,
+            highlight: Some(HighlightColor::Yellow),
+          },
+          link: None,
+          ruby: None,
+          footnote_id: None,
+        }],
+      }],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
+    };
+
+    let blog_post = factory.create(input).await.unwrap().blog_post;
+
+    match &blog_post.get_contents()[0] {
+      ContentEntity::Paragraph(paragraph) => {
+        let styles = paragraph.get_value().get_text()[0].get_styles();
+        assert!(styles.italic);
+        assert!(!styles.strikethrough);
+        assert!(styles.underline);
+        assert_eq!(styles.highlight, Some(HighlightColor::Yellow));
+      }
+      _ => panic!("段落ではありません"),
+    }
+  }
+
+  #[tokio::test]
+  async fn blog_post_creation_with_embed_block_from_url() {
+    let mock_repo = MockImageRepository::new();
+    let image_factory = Arc::new(ImageContentFactory::new(Arc::new(mock_repo)));
+    let factory = BlogPostFactory::new(image_factory, link_card_factory());
+
+    let input = CreateBlogPostInput {
+      title: "埋め込み記事".to_string(),
+      thumbnail: None,
+      post_date: None,
+      last_update_date: None,
+      published_date: None,
+      contents: vec![CreateContentInput::Embed {
+        id: Uuid::new_v4(),
+        url: "https://youtu.be/dQw4w9WgXcQ".to_string(),
+      }],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
+    };
+
+    let blog_post = factory.create(input).await.unwrap().blog_post;
+
+    match &blog_post.get_contents()[0] {
+      ContentEntity::Embed(embed) => {
+        assert_eq!(embed.get_provider(), EmbedProvider::YouTube);
+        assert_eq!(embed.get_resource_id(), "dQw4w9WgXcQ");
+      }
+      _ => panic!("コンテンツはEmbedである必要があります"),
+    }
+  }
+
+  #[tokio::test]
+  async fn blog_post_creation_fails_with_raw_iframe_embed() {
+    let mock_repo = MockImageRepository::new();
+    let image_factory = Arc::new(ImageContentFactory::new(Arc::new(mock_repo)));
+    let factory = BlogPostFactory::new(image_factory, link_card_factory());
+
+    let input = CreateBlogPostInput {
+      title: "不正な埋め込み記事".to_string(),
+      thumbnail: None,
+      post_date: None,
+      last_update_date: None,
+      published_date: None,
+      contents: vec![CreateContentInput::Embed {
+        id: Uuid::new_v4(),
+        url: r#"<iframe src="https://evil.example.com"></iframe>"#.to_string(),
+      }],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
+    };
+
+    let result = factory.create(input).await;
+
+    assert!(matches!(
+      result,
+      Err(BlogPostFactoryError::InvalidContent(BlogDomainError::InvalidEmbed { .. }))
+    ));
+  }
+
+  #[tokio::test]
+  async fn blog_post_creation_with_image_presentation() {
+    let mut mock_repo = MockImageRepository::new();
+    let image_path = "path/to/diagram.png".to_string();
+    mock_repo.add_image(image_path.clone(), ImageEntity::new(Uuid::new_v4(), image_path.clone()));
+    let image_factory = Arc::new(ImageContentFactory::new(Arc::new(mock_repo)));
+    let factory = BlogPostFactory::new(image_factory, link_card_factory());
+
+    let input = CreateBlogPostInput {
+      title: "図解記事".to_string(),
+      thumbnail: None,
+      post_date: None,
+      last_update_date: None,
+      published_date: None,
+      contents: vec![CreateContentInput::Image {
+        id: Uuid::new_v4(),
+        path: image_path,
+        presentation: CreateImagePresentationInput {
+          alt: "構成図".to_string(),
+          caption: Some("全体の構成".to_string()),
+          width: Some(480),
+          link_url: Some("/images/diagram-large".to_string()),
+        },
+      }],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
+    };
+
+    let blog_post = factory.create(input).await.unwrap().blog_post;
+
+    match &blog_post.get_contents()[0] {
+      ContentEntity::Image(image) => {
+        let presentation = image.get_presentation();
+        assert_eq!(presentation.get_alt(), "構成図");
+        assert_eq!(presentation.get_caption(), Some("全体の構成"));
+        assert_eq!(presentation.get_width(), Some(480));
+        assert_eq!(presentation.get_link_url(), Some("/images/diagram-large"));
+      }
+      _ => panic!("コンテンツはImageである必要があります"),
+    }
+  }
+
+  #[tokio::test]
+  async fn blog_post_creation_fails_with_unsafe_image_link() {
+    let mut mock_repo = MockImageRepository::new();
+    let image_path = "path/to/diagram.png".to_string();
+    mock_repo.add_image(image_path.clone(), ImageEntity::new(Uuid::new_v4(), image_path.clone()));
+    let image_factory = Arc::new(ImageContentFactory::new(Arc::new(mock_repo)));
+    let factory = BlogPostFactory::new(image_factory, link_card_factory());
+
+    let input = CreateBlogPostInput {
+      title: "不正なリンクの画像".to_string(),
+      thumbnail: None,
+      post_date: None,
+      last_update_date: None,
+      published_date: None,
+      contents: vec![CreateContentInput::Image {
+        id: Uuid::new_v4(),
+        path: image_path,
+        presentation: CreateImagePresentationInput {
+          link_url: Some("javascript:alert(1)".to_string()),
+          ..Default::default()
+        },
+      }],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
+    };
+
+    let result = factory.create(input).await;
+
+    assert!(matches!(
+      result,
+      Err(BlogPostFactoryError::InvalidContent(BlogDomainError::InvalidImageBlock { .. }))
+    ));
+  }
+
+  #[tokio::test]
+  async fn blog_post_creation_with_code_block_options() {
+    let mock_repo = MockImageRepository::new();
+    let image_factory = Arc::new(ImageContentFactory::new(Arc::new(mock_repo)));
+    let factory = BlogPostFactory::new(image_factory, link_card_factory());
+
+    let input = CreateBlogPostInput {
+      title: "差分記事".to_string(),
+      thumbnail: None,
+      post_date: None,
+      last_update_date: None,
+      published_date: None,
+      contents: vec![CreateContentInput::CodeBlock {
+        id: Uuid::new_v4(),
+        title: "main.rs".to_string(),
+        code: " fn main() {\n-    old();\n+    new();\n }".to_string(),
+        language: "rust".to_string(),
+        options: CreateCodeBlockOptionsInput {
+          highlight_lines: Some("11-12".to_string()),
+          start_line: Some(10),
+          diff: true,
+          show_line_numbers: true,
+        },
+      }],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
+    };
+
+    let blog_post = factory.create(input).await.unwrap().blog_post;
+
+    match &blog_post.get_contents()[0] {
+      ContentEntity::CodeBlock(code_block) => {
+        let options = code_block.get_options();
+        assert_eq!(options.highlight_lines_to_string(), "11-12");
+        assert_eq!(options.get_start_line(), 10);
+        assert!(options.is_diff());
+        assert!(options.shows_line_numbers());
+      }
+      _ => panic!("コンテンツはCodeBlockである必要があります"),
+    }
+  }
+
+  #[tokio::test]
+  async fn blog_post_creation_fails_with_highlight_beyond_code() {
+    let mock_repo = MockImageRepository::new();
+    let image_factory = Arc::new(ImageContentFactory::new(Arc::new(mock_repo)));
+    let factory = BlogPostFactory::new(image_factory, link_card_factory());
+
+    let input = CreateBlogPostInput {
+      title: "不正な強調表示".to_string(),
+      thumbnail: None,
+      post_date: None,
+      last_update_date: None,
+      published_date: None,
+      contents: vec![CreateContentInput::CodeBlock {
+        id: Uuid::new_v4(),
+        title: "main.rs".to_string(),
+        code: "fn main() {}".to_string(),
+        language: "rust".to_string(),
+        options: CreateCodeBlockOptionsInput {
+          highlight_lines: Some("2".to_string()),
+          ..Default::default()
+        },
+      }],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
+    };
+
+    let result = factory.create(input).await;
+
+    assert!(matches!(
+      result,
+      Err(BlogPostFactoryError::InvalidContent(BlogDomainError::InvalidCodeBlock { .. }))
+    ));
+  }
+
+  #[tokio::test]
+  async fn blog_post_creation_fails_with_unsupported_code_language() {
+    let mock_repo = MockImageRepository::new();
+    let image_factory = Arc::new(ImageContentFactory::new(Arc::new(mock_repo)));
+    let factory = BlogPostFactory::new(image_factory, link_card_factory());
+
+    let input = CreateBlogPostInput {
+      title: "未対応の言語".to_string(),
+      thumbnail: None,
+      post_date: None,
+      last_update_date: None,
+      published_date: None,
+      contents: vec![CreateContentInput::CodeBlock {
+        id: Uuid::new_v4(),
+        title: "hello.bf".to_string(),
+        code: "++++++++[>++++<-]>.".to_string(),
+        language: "brainfuck".to_string(),
+        options: CreateCodeBlockOptionsInput::default(),
+      }],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
+    };
+
+    let result = factory.create(input).await;
+
+    assert!(matches!(
+      result,
+      Err(BlogPostFactoryError::InvalidContent(BlogDomainError::InvalidCodeBlock { .. }))
+    ));
+  }
+
+  #[tokio::test]
+  async fn blog_post_creation_with_link_card_fetches_ogp_metadata() {
+    let mock_repo = MockImageRepository::new();
+    let image_factory = Arc::new(ImageContentFactory::new(Arc::new(mock_repo)));
+    let factory = BlogPostFactory::new(image_factory, link_card_factory());
+
+    let input = CreateBlogPostInput {
+      title: "リンクカード記事".to_string(),
+      thumbnail: None,
+      post_date: None,
+      last_update_date: None,
+      published_date: None,
+      contents: vec![CreateContentInput::LinkCard {
+        id: Uuid::new_v4(),
+        url: "https://example.com/article".to_string(),
+      }],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
+    };
+
+    let blog_post = factory.create(input).await.unwrap().blog_post;
+
+    match &blog_post.get_contents()[0] {
+      ContentEntity::LinkCard(link_card) => {
+        assert_eq!(link_card.get_url(), "https://example.com/article");
+        assert_eq!(link_card.get_metadata().title.as_deref(), Some("https://example.com/article のタイトル"));
+      }
+      _ => panic!("コンテンツはLinkCardである必要があります"),
+    }
+  }
+
+  #[tokio::test]
+  async fn blog_post_creation_with_details_block() {
+    let mock_repo = MockImageRepository::new();
+    let image_factory = Arc::new(ImageContentFactory::new(Arc::new(mock_repo)));
+    let factory = BlogPostFactory::new(image_factory, link_card_factory());
+
+    let details_id = Uuid::new_v4();
+    let input = CreateBlogPostInput {
+      title: "折りたたみ記事".to_string(),
+      thumbnail: None,
+      post_date: None,
+      last_update_date: None,
+      published_date: None,
+      contents: vec![CreateContentInput::Details {
+        id: details_id,
+        summary: "実行ログ".to_string(),
+        children: vec![
+          CreateContentInput::CodeBlock {
+            id: Uuid::new_v4(),
+            title: "stdout".to_string(),
+            code: "ok".to_string(),
+            language: "text".to_string(),
+            options: CreateCodeBlockOptionsInput::default(),
+          },
+          CreateContentInput::Math {
+            id: Uuid::new_v4(),
+            source: "x^2".to_string(),
+          },
+        ],
+      }],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
+    };
+
+    let blog_post = factory.create(input).await.unwrap().blog_post;
+
+    match &blog_post.get_contents()[0] {
+      ContentEntity::Details(details) => {
+        assert_eq!(details.get_id(), details_id);
+        assert_eq!(details.get_summary(), "実行ログ");
+        assert!(matches!(details.get_children()[0], ContentEntity::CodeBlock(_)));
+        assert!(matches!(details.get_children()[1], ContentEntity::Math(_)));
+      }
+      _ => panic!("コンテンツはDetailsである必要があります"),
+    }
+  }
+
+  #[tokio::test]
+  async fn blog_post_creation_fails_with_nested_details_block() {
+    let mock_repo = MockImageRepository::new();
+    let image_factory = Arc::new(ImageContentFactory::new(Arc::new(mock_repo)));
+    let factory = BlogPostFactory::new(image_factory, link_card_factory());
+
+    let input = CreateBlogPostInput {
+      title: "入れ子の折りたたみ記事".to_string(),
+      thumbnail: None,
+      post_date: None,
+      last_update_date: None,
+      published_date: None,
+      contents: vec![CreateContentInput::Details {
+        id: Uuid::new_v4(),
+        summary: "外側".to_string(),
+        children: vec![CreateContentInput::Details {
+          id: Uuid::new_v4(),
+          summary: "内側".to_string(),
+          children: vec![CreateContentInput::Math {
+            id: Uuid::new_v4(),
+            source: "x".to_string(),
+          }],
+        }],
+      }],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
+    };
+
+    let result = factory.create(input).await;
+
+    assert!(matches!(
+      result,
+      Err(BlogPostFactoryError::InvalidContent(BlogDomainError::InvalidDetailsBlock { .. }))
+    ));
+  }
+
+  #[tokio::test]
+  async fn blog_post_creation_with_diagram_block() {
+    let mock_repo = MockImageRepository::new();
+    let image_factory = Arc::new(ImageContentFactory::new(Arc::new(mock_repo)));
+    let factory = BlogPostFactory::new(image_factory, link_card_factory());
+
+    let diagram_id = Uuid::new_v4();
+    let input = CreateBlogPostInput {
+      title: "図を含む記事".to_string(),
+      thumbnail: None,
+      post_date: None,
+      last_update_date: None,
+      published_date: None,
+      contents: vec![CreateContentInput::Diagram {
+        id: diagram_id,
+        kind: DiagramKind::Mermaid,
+        source: "flowchart LR\n  A --> B".to_string(),
+      }],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
+    };
+
+    let blog_post = factory.create(input).await.unwrap().blog_post;
+
+    match &blog_post.get_contents()[0] {
+      ContentEntity::Diagram(diagram) => {
+        assert_eq!(diagram.get_id(), diagram_id);
+        assert_eq!(diagram.get_kind(), DiagramKind::Mermaid);
+        assert_eq!(diagram.get_source(), "flowchart LR\n  A --> B");
+      }
+      _ => panic!("コンテンツはDiagramである必要があります"),
+    }
+  }
+
+  #[tokio::test]
+  async fn blog_post_creation_fails_with_invalid_diagram_source() {
+    let mock_repo = MockImageRepository::new();
+    let image_factory = Arc::new(ImageContentFactory::new(Arc::new(mock_repo)));
+    let factory = BlogPostFactory::new(image_factory, link_card_factory());
+
+    let input = CreateBlogPostInput {
+      title: "不正な図を含む記事".to_string(),
+      thumbnail: None,
+      post_date: None,
+      last_update_date: None,
+      published_date: None,
+      contents: vec![CreateContentInput::Diagram {
+        id: Uuid::new_v4(),
+        kind: DiagramKind::Mermaid,
+        source: "A --> B".to_string(),
+      }],
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      manual_excerpt: Default::default(),
+    };
+
+    let result = factory.create(input).await;
+
+    assert!(matches!(
+      result,
+      Err(BlogPostFactoryError::InvalidContent(BlogDomainError::InvalidDiagram { .. }))
+    ));
+  }
 }
@@ -1,6 +1,6 @@
 use crate::domain::blog_domain::{
   blog_post_entity::BlogPostEntity, pick_up_post_set_entity::PickUpPostSetEntity, popular_post_set_entity::PopularPostSetEntity,
-  top_tech_pick_entity::TopTechPickEntity,
+  related_post_repository::PostTermVectorVO, top_tech_pick_entity::TopTechPickEntity,
 };
 use anyhow::Result;
 
@@ -23,21 +23,23 @@ pub trait BlogPostRepository: Send + Sync {
   ///
   /// # Arguments
   /// * `blog_post` - 保存する記事エンティティ
+  /// * `term_vector` - 関連記事の類似度計算に使う語の出現回数（記事と同じトランザクションで保存する）
   ///
   /// # Returns
   /// * `Ok(BlogPostEntity)` - 保存に成功した場合、保存された記事を返す
   /// * `Err` - 保存に失敗した場合
-  async fn save(&self, blog_post: &BlogPostEntity) -> Result<BlogPostEntity>;
+  async fn save(&self, blog_post: &BlogPostEntity, term_vector: &PostTermVectorVO) -> Result<BlogPostEntity>;
 
   /// 既存の記事を更新する
   ///
   /// # Arguments
   /// * `blog_post` - 更新する記事エンティティ
+  /// * `term_vector` - 関連記事の類似度計算に使う語の出現回数（記事と同じトランザクションで保存する）
   ///
   /// # Returns
   /// * `Ok(BlogPostEntity)` - 更新に成功した場合、更新された記事を返す
   /// * `Err` - 更新に失敗した場合
-  async fn update(&self, blog_post: &BlogPostEntity) -> Result<BlogPostEntity>;
+  async fn update(&self, blog_post: &BlogPostEntity, term_vector: &PostTermVectorVO) -> Result<BlogPostEntity>;
 
   /// 最新の記事を複数取得する
   ///
@@ -87,6 +89,7 @@ pub trait BlogPostRepository: Send + Sync {
   ///
   /// # Returns
   /// * `Ok(PopularPostSetEntity)` - 人気記事群エンティティ（3件固定）
+  /// * `Err(BlogDomainError::PopularPostsNotSet)` - 人気記事がまだ設定されていない場合
   /// * `Err` - データベースエラーの場合
   async fn find_popular_posts(&self) -> Result<PopularPostSetEntity>;
 
@@ -0,0 +1,131 @@
+use anyhow::Result;
+use std::sync::Arc;
+
+use super::services::html_renderer_service::escape_html;
+
+/// コードをシンタックスハイライトしたトークンに分割するトレイト
+///
+/// 文法定義の読み込みやキャッシュはこのトレイトの実装に閉じ込める。
+pub trait CodeHighlighter: Send + Sync {
+  /// コードをハイライトする
+  ///
+  /// # Arguments
+  /// * `code` - ハイライト対象のコード
+  /// * `language` - コードブロックに指定された言語の識別子
+  ///
+  /// # Returns
+  /// * `Ok(Arc<HighlightedCodeVO>)` - 行ごとのトークン列（同じ内容のコードはキャッシュを共有する）
+  /// * `Err` - 文法の解析に失敗した場合
+  fn highlight(&self, code: &str, language: &str) -> Result<Arc<HighlightedCodeVO>>;
+}
+
+/// ハイライトされたトークンの種類
+///
+/// 文法ごとのスコープ名をそのまま返さず、フロントエンドがスタイルを当てやすい粒度にまとめる。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HighlightTokenKind {
+  Keyword,
+  String,
+  Comment,
+  Number,
+  Constant,
+  Function,
+  Type,
+  Variable,
+  Operator,
+  Punctuation,
+}
+
+impl HighlightTokenKind {
+  /// CSS クラス名などに用いる識別子
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      HighlightTokenKind::Keyword => "keyword",
+      HighlightTokenKind::String => "string",
+      HighlightTokenKind::Comment => "comment",
+      HighlightTokenKind::Number => "number",
+      HighlightTokenKind::Constant => "constant",
+      HighlightTokenKind::Function => "function",
+      HighlightTokenKind::Type => "type",
+      HighlightTokenKind::Variable => "variable",
+      HighlightTokenKind::Operator => "operator",
+      HighlightTokenKind::Punctuation => "punctuation",
+    }
+  }
+}
+
+/// ハイライトされたトークン。種類を持たないトークンは装飾せずに表示する
+#[derive(Debug, Clone, PartialEq)]
+pub struct HighlightTokenVO {
+  pub text: String,
+  pub kind: Option<HighlightTokenKind>,
+}
+
+/// 行ごとのトークン列としてハイライトされたコード
+#[derive(Debug, Clone, PartialEq)]
+pub struct HighlightedCodeVO {
+  lines: Vec<Vec<HighlightTokenVO>>,
+}
+
+impl HighlightedCodeVO {
+  pub fn new(lines: Vec<Vec<HighlightTokenVO>>) -> Self {
+    Self { lines }
+  }
+
+  pub fn get_lines(&self) -> &[Vec<HighlightTokenVO>] {
+    &self.lines
+  }
+
+  /// トークンを `<span class="hl-{種類}">` で囲んだ HTML を生成する
+  ///
+  /// コードのテキストはすべてエスケープし、行は改行で連結する。
+  pub fn to_html(&self) -> String {
+    self
+      .lines
+      .iter()
+      .map(|line| {
+        line
+          .iter()
+          .map(|token| match token.kind {
+            Some(kind) => format!("<span class=\"hl-{}\">{}</span>", kind.as_str(), escape_html(&token.text)),
+            None => escape_html(&token.text),
+          })
+          .collect::<String>()
+      })
+      .collect::<Vec<_>>()
+      .join("\n")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn token(text: &str, kind: Option<HighlightTokenKind>) -> HighlightTokenVO {
+    HighlightTokenVO { text: text.to_string(), kind }
+  }
+
+  #[test]
+  fn to_html_wraps_classified_tokens_and_joins_lines() {
+    let highlighted = HighlightedCodeVO::new(vec![
+      vec![
+        token("let", Some(HighlightTokenKind::Keyword)),
+        token(" x = ", None),
+        token("1", Some(HighlightTokenKind::Number)),
+      ],
+      vec![token("// end", Some(HighlightTokenKind::Comment))],
+    ]);
+
+    assert_eq!(
+      highlighted.to_html(),
+      "<span class=\"hl-keyword\">let</span> x = <span class=\"hl-number\">1</span>\n<span class=\"hl-comment\">// end</span>"
+    );
+  }
+
+  #[test]
+  fn to_html_escapes_code_text() {
+    let highlighted = HighlightedCodeVO::new(vec![vec![token("<script>\"&'", Some(HighlightTokenKind::String))]]);
+
+    assert_eq!(highlighted.to_html(), "<span class=\"hl-string\">&lt;script&gt;&quot;&amp;&#39;</span>");
+  }
+}
@@ -0,0 +1,93 @@
+use super::errors::blog_domain_error::BlogDomainError;
+
+/// コードブロックで指定できる言語の識別子
+///
+/// サーバー側のシンタックスハイライトで扱える言語に限定する。
+/// 追加する場合はハイライタ側で対応する文法が解決できることも確認する。
+pub const SUPPORTED_CODE_LANGUAGES: [&str; 27] = [
+  "text",
+  "rust",
+  "javascript",
+  "jsx",
+  "typescript",
+  "tsx",
+  "json",
+  "yaml",
+  "toml",
+  "html",
+  "css",
+  "scss",
+  "sql",
+  "bash",
+  "shell",
+  "python",
+  "go",
+  "java",
+  "c",
+  "cpp",
+  "csharp",
+  "ruby",
+  "php",
+  "markdown",
+  "dockerfile",
+  "diff",
+  "xml",
+];
+
+/// コードブロックの言語が対応済みのものかを判定する
+///
+/// 既に保存されている記事の読み込みには影響させず、作成・更新時にのみ検証する。
+pub struct CodeLanguageRegistry {
+  languages: Vec<&'static str>,
+}
+
+impl Default for CodeLanguageRegistry {
+  fn default() -> Self {
+    Self::new(SUPPORTED_CODE_LANGUAGES.to_vec())
+  }
+}
+
+impl CodeLanguageRegistry {
+  pub fn new(languages: Vec<&'static str>) -> Self {
+    Self { languages }
+  }
+
+  /// 言語が対応済みかを検証する
+  ///
+  /// # Arguments
+  /// * `language` - コードブロックに指定された言語の識別子
+  ///
+  /// # Returns
+  /// * `Result<(), BlogDomainError>` - 対応していない言語の場合はエラー
+  pub fn validate(&self, language: &str) -> Result<(), BlogDomainError> {
+    if self.languages.contains(&language) {
+      return Ok(());
+    }
+    Err(BlogDomainError::InvalidCodeBlock {
+      detail: format!("対応していない言語です: {}", language),
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn supported_languages_are_accepted() {
+    let registry = CodeLanguageRegistry::default();
+
+    assert!(registry.validate("rust").is_ok());
+    assert!(registry.validate("typescript").is_ok());
+    assert!(registry.validate("text").is_ok());
+  }
+
+  #[test]
+  fn unknown_or_differently_cased_languages_are_rejected() {
+    let registry = CodeLanguageRegistry::default();
+
+    assert!(matches!(registry.validate("brainfuck"), Err(BlogDomainError::InvalidCodeBlock { .. })));
+    assert!(registry.validate("Rust").is_err());
+    assert!(registry.validate("").is_err());
+  }
+}
@@ -0,0 +1,175 @@
+use url::Url;
+
+use super::{blog_post_entity::embed_block_entity::EmbedProvider, errors::blog_domain_error::BlogDomainError};
+
+/// 埋め込み元の URL から解決したプロバイダとリソース ID
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedEmbed {
+  pub provider: EmbedProvider,
+  pub resource_id: String,
+}
+
+/// 埋め込みに対応するプロバイダを管理し、貼り付けられた URL からリソース ID を抽出する
+///
+/// iframe などの HTML をそのまま保存しないよう、URL として解釈できる入力のみを受け付け、
+/// プロバイダごとの正規のリソース ID だけを取り出す。
+pub struct EmbedProviderRegistry {
+  providers: Vec<EmbedProvider>,
+}
+
+impl Default for EmbedProviderRegistry {
+  fn default() -> Self {
+    Self::new(EmbedProvider::all().to_vec())
+  }
+}
+
+impl EmbedProviderRegistry {
+  pub fn new(providers: Vec<EmbedProvider>) -> Self {
+    Self { providers }
+  }
+
+  /// 貼り付けられた URL からプロバイダとリソース ID を解決する
+  ///
+  /// # Arguments
+  /// * `raw_url` - 埋め込み元として貼り付けられた URL
+  ///
+  /// # Returns
+  /// * `Result<ResolvedEmbed, BlogDomainError>` - 対応していない URL の場合はエラー
+  pub fn resolve(&self, raw_url: &str) -> Result<ResolvedEmbed, BlogDomainError> {
+    let url = Url::parse(raw_url.trim()).map_err(|_| invalid_embed(&format!("URL として解釈できません: {}", raw_url)))?;
+    if url.scheme() != "https" && url.scheme() != "http" {
+      return Err(invalid_embed(&format!("http(s) 以外の URL は埋め込めません: {}", raw_url)));
+    }
+    let host = url.host_str().unwrap_or_default().to_ascii_lowercase();
+
+    let provider = self
+      .providers
+      .iter()
+      .find(|provider| provider.hosts().contains(&host.as_str()))
+      .ok_or_else(|| invalid_embed(&format!("埋め込みに対応していないサービスの URL です: {}", raw_url)))?;
+
+    let resource_id = extract_resource_id(*provider, &url).ok_or_else(|| invalid_embed(&format!("埋め込み対象を特定できない URL です: {}", raw_url)))?;
+
+    Ok(ResolvedEmbed {
+      provider: *provider,
+      resource_id,
+    })
+  }
+}
+
+/// プロバイダごとの URL 形式からリソース ID を取り出す
+fn extract_resource_id(provider: EmbedProvider, url: &Url) -> Option<String> {
+  let segments: Vec<&str> = url.path_segments().map(|s| s.filter(|segment| !segment.is_empty()).collect()).unwrap_or_default();
+
+  let resource_id = match provider {
+    EmbedProvider::YouTube => {
+      if url.host_str() == Some("youtu.be") {
+        segments.first().map(|id| id.to_string())
+      } else {
+        match segments.as_slice() {
+          ["watch"] => url.query_pairs().find(|(key, _)| key == "v").map(|(_, value)| value.to_string()),
+          ["embed" | "shorts" | "live", id] => Some(id.to_string()),
+          _ => None,
+        }
+      }
+    }
+    EmbedProvider::Twitter => match segments.as_slice() {
+      [_, "status", id, ..] | ["i", "web", "status", id, ..] => Some(id.to_string()),
+      _ => None,
+    },
+    EmbedProvider::GitHubGist => match segments.as_slice() {
+      [user, id] => Some(format!("{}/{}", user, id)),
+      _ => None,
+    },
+    EmbedProvider::SpeakerDeck => match segments.as_slice() {
+      [user, slug] => Some(format!("{}/{}", user, slug)),
+      _ => None,
+    },
+    EmbedProvider::CodePen => match segments.as_slice() {
+      [user, "pen" | "embed" | "full" | "details", slug] => Some(format!("{}/{}", user, slug)),
+      _ => None,
+    },
+  }?;
+
+  provider.is_valid_resource_id(&resource_id).then_some(resource_id)
+}
+
+fn invalid_embed(detail: &str) -> BlogDomainError {
+  BlogDomainError::InvalidEmbed { detail: detail.to_string() }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn resolve(url: &str) -> Result<ResolvedEmbed, BlogDomainError> {
+    EmbedProviderRegistry::default().resolve(url)
+  }
+
+  #[test]
+  fn resolves_youtube_urls() {
+    let expected = ResolvedEmbed {
+      provider: EmbedProvider::YouTube,
+      resource_id: "dQw4w9WgXcQ".to_string(),
+    };
+
+    assert_eq!(resolve("https://www.youtube.com/watch?v=dQw4w9WgXcQ&t=10s").unwrap(), expected);
+    assert_eq!(resolve("https://youtu.be/dQw4w9WgXcQ").unwrap(), expected);
+    assert_eq!(resolve("https://www.youtube.com/embed/dQw4w9WgXcQ").unwrap(), expected);
+    assert_eq!(resolve("https://youtube.com/shorts/dQw4w9WgXcQ").unwrap(), expected);
+  }
+
+  #[test]
+  fn resolves_twitter_and_x_urls() {
+    let expected = ResolvedEmbed {
+      provider: EmbedProvider::Twitter,
+      resource_id: "1234567890123456789".to_string(),
+    };
+
+    assert_eq!(resolve("https://twitter.com/someone/status/1234567890123456789").unwrap(), expected);
+    assert_eq!(resolve("https://x.com/someone/status/1234567890123456789?s=20").unwrap(), expected);
+  }
+
+  #[test]
+  fn resolves_gist_speakerdeck_and_codepen_urls() {
+    let gist = resolve("https://gist.github.com/octocat/6cad326836d38bd3a7ae").unwrap();
+    assert_eq!(gist.provider, EmbedProvider::GitHubGist);
+    assert_eq!(gist.resource_id, "octocat/6cad326836d38bd3a7ae");
+
+    let speakerdeck = resolve("https://speakerdeck.com/someone/my-great-talk").unwrap();
+    assert_eq!(speakerdeck.provider, EmbedProvider::SpeakerDeck);
+    assert_eq!(speakerdeck.resource_id, "someone/my-great-talk");
+
+    let codepen = resolve("https://codepen.io/someone/pen/abcDEF").unwrap();
+    assert_eq!(codepen.provider, EmbedProvider::CodePen);
+    assert_eq!(codepen.resource_id, "someone/abcDEF");
+  }
+
+  #[test]
+  fn rejects_raw_iframe() {
+    let result = resolve(r#"<iframe src="https://www.youtube.com/embed/dQw4w9WgXcQ"></iframe>"#);
+
+    assert!(matches!(result, Err(BlogDomainError::InvalidEmbed { .. })));
+  }
+
+  #[test]
+  fn rejects_unsupported_hosts_and_schemes() {
+    assert!(resolve("https://example.com/watch?v=dQw4w9WgXcQ").is_err());
+    assert!(resolve("javascript:alert(1)").is_err());
+  }
+
+  #[test]
+  fn rejects_malformed_resource_ids() {
+    assert!(resolve("https://www.youtube.com/watch?v=short").is_err());
+    assert!(resolve("https://x.com/someone/status/not-a-number").is_err());
+    assert!(resolve("https://gist.github.com/octocat/not-hex!").is_err());
+  }
+
+  #[test]
+  fn registry_only_resolves_registered_providers() {
+    let registry = EmbedProviderRegistry::new(vec![EmbedProvider::YouTube]);
+
+    assert!(registry.resolve("https://youtu.be/dQw4w9WgXcQ").is_ok());
+    assert!(registry.resolve("https://codepen.io/someone/pen/abcDEF").is_err());
+  }
+}
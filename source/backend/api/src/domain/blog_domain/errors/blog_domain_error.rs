@@ -13,6 +13,78 @@ pub enum BlogDomainError {
     /// エラーの詳細
     detail: String,
   },
+  /// 数式の LaTeX ソースが不正な場合のエラー
+  InvalidLatex {
+    /// エラーの詳細
+    detail: String,
+  },
+  /// 埋め込み元の URL やリソース ID が不正な場合のエラー
+  InvalidEmbed {
+    /// エラーの詳細
+    detail: String,
+  },
+  /// リンクカードの URL が不正な場合のエラー
+  InvalidLinkCard {
+    /// エラーの詳細
+    detail: String,
+  },
+  /// コードブロックの強調表示行や開始行番号が不正な場合のエラー
+  InvalidCodeBlock {
+    /// エラーの詳細
+    detail: String,
+  },
+  /// 画像ブロックの代替テキストやリンク先などが不正な場合のエラー
+  InvalidImageBlock {
+    /// エラーの詳細
+    detail: String,
+  },
+  /// ルビの指定が不正な場合のエラー
+  InvalidRuby {
+    /// エラーの詳細
+    detail: String,
+  },
+  /// 脚注の参照と定義が対応していない場合のエラー
+  InvalidFootnote {
+    /// エラーの詳細
+    detail: String,
+  },
+  /// 折りたたみブロックの見出しや子コンテンツが不正な場合のエラー
+  InvalidDetailsBlock {
+    /// エラーの詳細
+    detail: String,
+  },
+  /// 図のソースが不正な場合のエラー
+  InvalidDiagram {
+    /// エラーの詳細
+    detail: String,
+  },
+  /// 取り込む Markdown のフロントマターやタイトルが不正な場合のエラー
+  InvalidMarkdown {
+    /// エラーの詳細
+    detail: String,
+  },
+  /// 取り込むアーカイブの内容が不正な場合のエラー
+  InvalidArchive {
+    /// エラーの詳細
+    detail: String,
+  },
+  /// メタディスクリプションや正規 URL などの SEO メタデータが不正な場合のエラー
+  InvalidSeoMetadata {
+    /// エラーの詳細
+    detail: String,
+  },
+  /// 手動で設定する抜粋が不正な場合のエラー
+  InvalidExcerpt {
+    /// エラーの詳細
+    detail: String,
+  },
+  /// 記事内に指定したリンクカードが存在しない場合のエラー
+  LinkCardNotFound {
+    /// コンテンツ ID
+    content_id: String,
+  },
+  /// 人気記事がまだ一度も設定されていない場合のエラー
+  PopularPostsNotSet,
 }
 
 impl fmt::Display for BlogDomainError {
@@ -24,6 +96,51 @@ impl fmt::Display for BlogDomainError {
       BlogDomainError::InvalidDate { detail } => {
         write!(f, "無効な日付: {}", detail)
       }
+      BlogDomainError::InvalidLatex { detail } => {
+        write!(f, "無効な数式: {}", detail)
+      }
+      BlogDomainError::InvalidEmbed { detail } => {
+        write!(f, "無効な埋め込み: {}", detail)
+      }
+      BlogDomainError::InvalidLinkCard { detail } => {
+        write!(f, "無効なリンクカード: {}", detail)
+      }
+      BlogDomainError::InvalidCodeBlock { detail } => {
+        write!(f, "無効なコードブロック: {}", detail)
+      }
+      BlogDomainError::InvalidImageBlock { detail } => {
+        write!(f, "無効な画像ブロック: {}", detail)
+      }
+      BlogDomainError::InvalidRuby { detail } => {
+        write!(f, "無効なルビ: {}", detail)
+      }
+      BlogDomainError::InvalidFootnote { detail } => {
+        write!(f, "無効な脚注: {}", detail)
+      }
+      BlogDomainError::InvalidDetailsBlock { detail } => {
+        write!(f, "無効な折りたたみブロック: {}", detail)
+      }
+      BlogDomainError::InvalidDiagram { detail } => {
+        write!(f, "無効な図: {}", detail)
+      }
+      BlogDomainError::InvalidMarkdown { detail } => {
+        write!(f, "無効な Markdown: {}", detail)
+      }
+      BlogDomainError::InvalidArchive { detail } => {
+        write!(f, "無効なアーカイブ: {}", detail)
+      }
+      BlogDomainError::InvalidSeoMetadata { detail } => {
+        write!(f, "無効な SEO メタデータ: {}", detail)
+      }
+      BlogDomainError::InvalidExcerpt { detail } => {
+        write!(f, "無効な抜粋: {}", detail)
+      }
+      BlogDomainError::LinkCardNotFound { content_id } => {
+        write!(f, "リンクカード「{}」が見つかりません", content_id)
+      }
+      BlogDomainError::PopularPostsNotSet => {
+        write!(f, "人気記事がまだ設定されていません")
+      }
     }
   }
 }
@@ -105,4 +222,111 @@ mod tests {
     assert_eq!(error1, error2);
     assert_ne!(error1, error3);
   }
+
+  #[test]
+  fn invalid_latex_error_displays_correct_message() {
+    let error = BlogDomainError::InvalidLatex {
+      detail: "閉じられていない { があります".to_string(),
+    };
+
+    assert_eq!(error.to_string(), "無効な数式: 閉じられていない { があります");
+  }
+
+  #[test]
+  fn invalid_latex_error_supports_equality() {
+    let error1 = BlogDomainError::InvalidLatex {
+      detail: "同じエラー".to_string(),
+    };
+    let error2 = BlogDomainError::InvalidLatex {
+      detail: "同じエラー".to_string(),
+    };
+    let error3 = BlogDomainError::InvalidLatex {
+      detail: "異なるエラー".to_string(),
+    };
+
+    assert_eq!(error1, error2);
+    assert_ne!(error1, error3);
+  }
+
+  #[test]
+  fn invalid_embed_error_displays_correct_message() {
+    let error = BlogDomainError::InvalidEmbed {
+      detail: "埋め込みに対応していないサービスの URL です".to_string(),
+    };
+
+    assert_eq!(error.to_string(), "無効な埋め込み: 埋め込みに対応していないサービスの URL です");
+  }
+
+  #[test]
+  fn invalid_link_card_error_displays_correct_message() {
+    let error = BlogDomainError::InvalidLinkCard {
+      detail: "URL として解釈できません".to_string(),
+    };
+
+    assert_eq!(error.to_string(), "無効なリンクカード: URL として解釈できません");
+  }
+
+  #[test]
+  fn invalid_code_block_error_displays_correct_message() {
+    let error = BlogDomainError::InvalidCodeBlock {
+      detail: "強調表示する行は1行目から3行目の範囲で指定してください".to_string(),
+    };
+
+    assert_eq!(
+      error.to_string(),
+      "無効なコードブロック: 強調表示する行は1行目から3行目の範囲で指定してください"
+    );
+  }
+
+  #[test]
+  fn invalid_image_block_error_displays_correct_message() {
+    let error = BlogDomainError::InvalidImageBlock {
+      detail: "表示幅は16px から4096px の範囲で指定してください".to_string(),
+    };
+
+    assert_eq!(error.to_string(), "無効な画像ブロック: 表示幅は16px から4096px の範囲で指定してください");
+  }
+
+  #[test]
+  fn invalid_ruby_error_displays_correct_message() {
+    let error = BlogDomainError::InvalidRuby {
+      detail: "ルビを振る文字列が空です".to_string(),
+    };
+
+    assert_eq!(error.to_string(), "無効なルビ: ルビを振る文字列が空です");
+  }
+
+  #[test]
+  fn invalid_footnote_error_displays_correct_message() {
+    let error = BlogDomainError::InvalidFootnote {
+      detail: "定義されていない脚注が参照されています".to_string(),
+    };
+
+    assert_eq!(error.to_string(), "無効な脚注: 定義されていない脚注が参照されています");
+  }
+
+  #[test]
+  fn invalid_details_block_error_displays_correct_message() {
+    let error = BlogDomainError::InvalidDetailsBlock {
+      detail: "折りたたみブロックは入れ子にできません".to_string(),
+    };
+
+    assert_eq!(error.to_string(), "無効な折りたたみブロック: 折りたたみブロックは入れ子にできません");
+  }
+
+  #[test]
+  fn invalid_diagram_error_displays_correct_message() {
+    let error = BlogDomainError::InvalidDiagram {
+      detail: "図の種類の宣言がありません".to_string(),
+    };
+
+    assert_eq!(error.to_string(), "無効な図: 図の種類の宣言がありません");
+  }
+
+  #[test]
+  fn link_card_not_found_error_displays_correct_message() {
+    let error = BlogDomainError::LinkCardNotFound { content_id: "abc".to_string() };
+
+    assert_eq!(error.to_string(), "リンクカード「abc」が見つかりません");
+  }
 }
@@ -0,0 +1,157 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::{
+  blog_post_entity::link_card_block_entity::{LinkCardBlockEntity, OgpMetadataVO},
+  errors::blog_domain_error::BlogDomainError,
+  ogp_fetcher::{OgpFetchError, OgpFetcher},
+};
+
+/// OGP メタデータを取得できず、空のメタデータで生成したリンクカード
+///
+/// 記事の作成は妨げないため、記録するかどうかは呼び出し側に任せる。
+#[derive(Debug)]
+pub struct LinkCardFetchFailure {
+  pub content_id: Uuid,
+  /// `OgpFetchError` を文脈に持つ取得元のエラー
+  pub error: anyhow::Error,
+}
+
+/// リンクカードの生成結果
+#[derive(Debug)]
+pub struct LinkCardCreation {
+  pub link_card: LinkCardBlockEntity,
+  pub fetch_failure: Option<LinkCardFetchFailure>,
+}
+
+pub struct LinkCardContentFactory {
+  ogp_fetcher: Arc<dyn OgpFetcher>,
+}
+
+impl LinkCardContentFactory {
+  pub fn new(ogp_fetcher: Arc<dyn OgpFetcher>) -> Self {
+    Self { ogp_fetcher }
+  }
+
+  /// URL から OGP メタデータを取得してリンクカードを生成する
+  ///
+  /// メタデータの取得に失敗しても記事の作成は妨げず、空のメタデータで生成して失敗の内容を結果に含める。
+  /// 取得し直す場合は管理画面からの再取得を利用する。
+  ///
+  /// # Arguments
+  /// * `id` - コンテンツ ID
+  /// * `url` - リンク先の URL
+  ///
+  /// # Returns
+  /// * `Result<LinkCardCreation, BlogDomainError>` - URL が不正な場合はエラー
+  pub async fn create(&self, id: Uuid, url: String) -> Result<LinkCardCreation, BlogDomainError> {
+    let url = LinkCardBlockEntity::validate_url(&url)?;
+    let (metadata, fetch_failure) = match self.ogp_fetcher.fetch(&url).await {
+      Ok(metadata) => (metadata, None),
+      Err(e) => (
+        OgpMetadataVO::default(),
+        Some(LinkCardFetchFailure {
+          content_id: id,
+          error: e.context(OgpFetchError { url: url.clone() }),
+        }),
+      ),
+    };
+    Ok(LinkCardCreation {
+      link_card: LinkCardBlockEntity::new(id, url, metadata)?,
+      fetch_failure,
+    })
+  }
+
+  /// 既存のリンクカードの OGP メタデータを取得し直す
+  ///
+  /// # Arguments
+  /// * `link_card` - 再取得の対象となるリンクカード
+  ///
+  /// # Returns
+  /// * `anyhow::Result<()>` - 取得に失敗した場合は `OgpFetchError` を文脈に持つエラーを返し、キャッシュは変更しない
+  pub async fn refresh(&self, link_card: &mut LinkCardBlockEntity) -> anyhow::Result<()> {
+    let metadata = self.ogp_fetcher.fetch(link_card.get_url()).await.map_err(|e| {
+      e.context(OgpFetchError {
+        url: link_card.get_url().to_string(),
+      })
+    })?;
+    link_card.refresh_metadata(metadata);
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use async_trait::async_trait;
+
+  // テスト用のスタブ
+  struct StubOgpFetcher {
+    result: Option<OgpMetadataVO>,
+  }
+
+  #[async_trait]
+  impl OgpFetcher for StubOgpFetcher {
+    async fn fetch(&self, _url: &str) -> anyhow::Result<OgpMetadataVO> {
+      self.result.clone().ok_or_else(|| anyhow::anyhow!("timeout"))
+    }
+  }
+
+  fn metadata() -> OgpMetadataVO {
+    OgpMetadataVO {
+      title: Some("記事タイトル".to_string()),
+      description: Some("記事の説明".to_string()),
+      site_name: Some("Example".to_string()),
+      image_url: Some("https://example.com/ogp.png".to_string()),
+    }
+  }
+
+  #[tokio::test]
+  async fn create_stores_fetched_metadata() {
+    let factory = LinkCardContentFactory::new(Arc::new(StubOgpFetcher { result: Some(metadata()) }));
+
+    let creation = factory.create(Uuid::new_v4(), "https://example.com/article".to_string()).await.unwrap();
+
+    assert_eq!(creation.link_card.get_url(), "https://example.com/article");
+    assert_eq!(creation.link_card.get_metadata(), &metadata());
+    assert!(creation.fetch_failure.is_none());
+  }
+
+  #[tokio::test]
+  async fn create_falls_back_to_empty_metadata_when_fetch_fails() {
+    let factory = LinkCardContentFactory::new(Arc::new(StubOgpFetcher { result: None }));
+
+    let id = Uuid::new_v4();
+
+    let creation = factory.create(id, "https://example.com/article".to_string()).await.unwrap();
+
+    assert_eq!(creation.link_card.get_metadata(), &OgpMetadataVO::default());
+    let failure = creation.fetch_failure.unwrap();
+    assert_eq!(failure.content_id, id);
+    assert_eq!(
+      failure.error.downcast_ref::<OgpFetchError>(),
+      Some(&OgpFetchError {
+        url: "https://example.com/article".to_string()
+      })
+    );
+  }
+
+  #[tokio::test]
+  async fn create_rejects_invalid_url_without_fetching() {
+    let factory = LinkCardContentFactory::new(Arc::new(StubOgpFetcher { result: Some(metadata()) }));
+
+    let result = factory.create(Uuid::new_v4(), "ftp://example.com".to_string()).await;
+
+    assert!(matches!(result, Err(BlogDomainError::InvalidLinkCard { .. })));
+  }
+
+  #[tokio::test]
+  async fn refresh_keeps_cache_when_fetch_fails() {
+    let factory = LinkCardContentFactory::new(Arc::new(StubOgpFetcher { result: None }));
+    let mut link_card = LinkCardBlockEntity::new(Uuid::new_v4(), "https://example.com".to_string(), metadata()).unwrap();
+
+    let error = factory.refresh(&mut link_card).await.unwrap_err();
+    assert!(error.downcast_ref::<OgpFetchError>().is_some());
+    assert_eq!(link_card.get_metadata(), &metadata());
+  }
+}
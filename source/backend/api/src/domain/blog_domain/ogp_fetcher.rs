@@ -0,0 +1,34 @@
+use anyhow::Result;
+
+use super::blog_post_entity::link_card_block_entity::OgpMetadataVO;
+
+/// リンク先ページの OGP メタデータを取得するトレイト
+///
+/// 外部ネットワークへのアクセスはこのトレイトの実装に閉じ込め、
+/// 記事の閲覧時には永続化済みのメタデータのみを利用する。
+#[async_trait::async_trait]
+pub trait OgpFetcher: Send + Sync {
+  /// 指定した URL のページから OGP メタデータを取得する
+  ///
+  /// # Arguments
+  /// * `url` - 取得対象ページの URL
+  ///
+  /// # Returns
+  /// * `Ok(OgpMetadataVO)` - 取得できたメタデータ（存在しない項目は `None`）
+  /// * `Err` - 接続失敗やタイムアウトなどで取得できなかった場合
+  async fn fetch(&self, url: &str) -> Result<OgpMetadataVO>;
+}
+
+/// OGP メタデータの取得に失敗したことを表すエラー
+///
+/// 取得元のエラーに文脈として付与し、呼び出し側で取得失敗を判別できるようにする。
+#[derive(Debug, Clone, PartialEq)]
+pub struct OgpFetchError {
+  pub url: String,
+}
+
+impl std::fmt::Display for OgpFetchError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "OGP メタデータの取得に失敗しました: {}", self.url)
+  }
+}
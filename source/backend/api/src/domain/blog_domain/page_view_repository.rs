@@ -0,0 +1,60 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::blog_domain::jst_date_vo::JstDate;
+
+/// 記事の閲覧 1 回分
+///
+/// `visitor_hash` は閲覧者の IP アドレスと User-Agent から日ごとに作るハッシュで、同じ日の同じ閲覧者による閲覧を 1 回にまとめるのに使う
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageViewVO {
+  pub post_id: Uuid,
+  pub visitor_hash: String,
+  pub viewed_on: JstDate,
+}
+
+/// 記事ごとの期間内の閲覧数
+#[derive(Debug, Clone, PartialEq)]
+pub struct PostViewCountVO {
+  pub post_id: Uuid,
+  pub views: u64,
+}
+
+/// 閲覧を記録する記事の公開状態を確かめるための情報
+///
+/// 閲覧のたびに読むため、本文などは読まずに記事 ID・タイトル・公開日だけを扱う
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageViewTargetVO {
+  pub post_id: Uuid,
+  pub title: String,
+  pub published_date: JstDate,
+}
+
+impl PageViewTargetVO {
+  /// `today` の時点で公開済みかどうか
+  pub fn is_published_on(&self, today: &JstDate) -> bool {
+    &self.published_date <= today
+  }
+}
+
+/// 記事の閲覧数を記録・集計するリポジトリのトレイト
+///
+/// 閲覧数は記事と日付ごとに集計して保存し、個々の閲覧は同じ日の重複を除くためにだけ保存する
+#[async_trait::async_trait]
+pub trait PageViewRepository: Send + Sync {
+  /// 閲覧を記録する記事を取得する
+  ///
+  /// 記事が存在しない場合は `BlogPostNotFound:` で始まるエラーを返す
+  async fn find_page_view_target(&self, id: &str) -> Result<PageViewTargetVO>;
+
+  /// 閲覧を記録する
+  ///
+  /// 同じ日に同じ閲覧者がすでに閲覧している場合は何もせず `false` を返す
+  async fn record_page_view(&self, page_view: &PageViewVO) -> Result<bool>;
+
+  /// `since` から `until` まで（両端を含む）の公開済みの記事の閲覧数を取得する（閲覧がない記事は含めない）
+  async fn find_published_view_counts(&self, since: &JstDate, until: &JstDate) -> Result<Vec<PostViewCountVO>>;
+
+  /// `date` より前の日の閲覧者のハッシュを削除する（集計済みの閲覧数は残す）
+  async fn delete_visitor_hashes_before(&self, date: &JstDate) -> Result<u64>;
+}
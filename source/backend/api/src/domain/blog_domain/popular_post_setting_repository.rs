@@ -0,0 +1,37 @@
+use anyhow::Result;
+
+/// 人気記事の選び方
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PopularPostMode {
+  /// 管理者が選んだ記事を表示する
+  Manual,
+  /// 直近の閲覧数から定期的に選び直す
+  Automatic,
+}
+
+impl PopularPostMode {
+  pub fn all() -> [PopularPostMode; 2] {
+    [PopularPostMode::Manual, PopularPostMode::Automatic]
+  }
+
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      PopularPostMode::Manual => "manual",
+      PopularPostMode::Automatic => "automatic",
+    }
+  }
+
+  pub fn parse(value: &str) -> Option<Self> {
+    Self::all().into_iter().find(|mode| mode.as_str() == value)
+  }
+}
+
+/// 人気記事の設定を読み書きするリポジトリのトレイト
+#[async_trait::async_trait]
+pub trait PopularPostSettingRepository: Send + Sync {
+  /// 人気記事の選び方を取得する
+  async fn find_mode(&self) -> Result<PopularPostMode>;
+
+  /// 人気記事の選び方を保存する
+  async fn save_mode(&self, mode: PopularPostMode) -> Result<()>;
+}
@@ -0,0 +1,25 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::blog_domain::jst_date_vo::JstDate;
+
+/// 関連記事の類似度計算に使う、記事 1 件分の語の出現回数
+#[derive(Debug, Clone, PartialEq)]
+pub struct PostTermVectorVO {
+  pub id: Uuid,
+  pub terms: HashMap<String, u32>,
+}
+
+/// 関連記事を探すためのリポジトリのトレイト
+///
+/// 語の出現回数は記事の保存・更新時に求めて保存しておき、記事の本文を読まずに類似度を計算できるようにする
+#[async_trait::async_trait]
+pub trait RelatedPostRepository: Send + Sync {
+  /// 公開済み（公開日が `today` 以前）の記事の語の出現回数を取得する
+  async fn find_published_term_vectors(&self, today: &JstDate) -> Result<Vec<PostTermVectorVO>>;
+
+  /// 記事の語の出現回数を保存する（保存済みの場合は置き換える）
+  async fn save_term_vector(&self, term_vector: &PostTermVectorVO) -> Result<()>;
+}
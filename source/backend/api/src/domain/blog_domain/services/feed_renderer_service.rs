@@ -0,0 +1,350 @@
+use chrono::{DateTime, FixedOffset, SecondsFormat, Utc};
+
+use super::html_renderer_service::escape_html;
+use super::post_digest_service::PostDigestService;
+use crate::domain::blog_domain::blog_post_entity::BlogPostEntity;
+
+/// フィードの公開パス（Nginx で `/api` 配下に公開される）
+pub const RSS_FEED_PATH: &str = "/api/blog/feed.xml";
+pub const ATOM_FEED_PATH: &str = "/api/blog/atom.xml";
+pub const JSON_FEED_PATH: &str = "/api/blog/feed.json";
+
+const JST_OFFSET_SECONDS: i32 = 9 * 3600;
+
+/// フィードのチャンネル（サイト全体）の情報
+#[derive(Debug, Clone)]
+pub struct FeedChannelVO {
+  pub title: String,
+  pub description: String,
+  /// フロントエンドの URL。記事の URL は `{site_url}/posts/{id}` になる
+  pub site_url: String,
+}
+
+/// RSS 2.0・Atom（RFC 4287）のフィードを出力するドメインサービス
+///
+/// JSON Feed のように XML 以外で出力する場合に使えるよう、URL や要約を組み立てる処理も公開する。
+///
+/// 記事ごとにタイトル・URL・公開日・最終更新日・サムネイル（enclosure）と、段落から作ったプレーンテキストの要約を出力する。
+/// 日付は JST の 0 時として扱う。
+pub struct FeedRendererService {
+  channel: FeedChannelVO,
+  image_base_url: String,
+}
+
+impl FeedRendererService {
+  /// 新しいサービスインスタンスを作成する
+  ///
+  /// # Arguments
+  /// * `channel` - フィードのタイトルや URL
+  /// * `image_base_url` - 画像のパスの前に付ける配信元の URL。空の場合はパスをそのまま出力する
+  pub fn new(channel: FeedChannelVO, image_base_url: String) -> Self {
+    Self { channel, image_base_url }
+  }
+
+  /// RSS 2.0 のフィードを出力する
+  pub fn render_rss(&self, blog_posts: &[BlogPostEntity]) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str("<rss version=\"2.0\" xmlns:atom=\"http://www.w3.org/2005/Atom\">\n<channel>\n");
+    xml.push_str(&format!("<title>{}</title>\n", escape_html(&self.channel.title)));
+    xml.push_str(&format!("<link>{}</link>\n", escape_html(&self.site_url())));
+    xml.push_str(&format!("<description>{}</description>\n", escape_html(&self.channel.description)));
+    xml.push_str("<language>ja</language>\n");
+    xml.push_str(&format!(
+      "<atom:link href=\"{}\" rel=\"self\" type=\"application/rss+xml\"/>\n",
+      escape_html(&self.feed_url(RSS_FEED_PATH))
+    ));
+    if let Some(last_updated) = last_updated(blog_posts) {
+      xml.push_str(&format!("<lastBuildDate>{}</lastBuildDate>\n", to_jst(last_updated).to_rfc2822()));
+    }
+
+    for blog_post in blog_posts {
+      let post_url = self.post_url(blog_post);
+      xml.push_str("<item>\n");
+      xml.push_str(&format!("<title>{}</title>\n", escape_html(blog_post.get_title_text())));
+      xml.push_str(&format!("<link>{}</link>\n", escape_html(&post_url)));
+      xml.push_str(&format!("<guid isPermaLink=\"true\">{}</guid>\n", escape_html(&post_url)));
+      xml.push_str(&format!(
+        "<pubDate>{}</pubDate>\n",
+        to_jst(blog_post.get_published_date().to_utc_datetime()).to_rfc2822()
+      ));
+      xml.push_str(&format!("<description>{}</description>\n", escape_html(&summarize(blog_post))));
+      if let Some(thumbnail) = blog_post.get_thumbnail() {
+        // 画像のファイルサイズは保持していないため length は 0 とする
+        xml.push_str(&format!(
+          "<enclosure url=\"{}\" length=\"0\" type=\"{}\"/>\n",
+          escape_html(&self.image_url(thumbnail.get_path())),
+          image_mime_type(thumbnail.get_path())
+        ));
+      }
+      xml.push_str("</item>\n");
+    }
+
+    xml.push_str("</channel>\n</rss>\n");
+    xml
+  }
+
+  /// Atom のフィードを出力する
+  pub fn render_atom(&self, blog_posts: &[BlogPostEntity]) -> String {
+    let updated = last_updated(blog_posts).unwrap_or(DateTime::UNIX_EPOCH);
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\" xml:lang=\"ja\">\n");
+    xml.push_str(&format!("<id>{}</id>\n", escape_html(&self.feed_url(ATOM_FEED_PATH))));
+    xml.push_str(&format!("<title>{}</title>\n", escape_html(&self.channel.title)));
+    if !self.channel.description.is_empty() {
+      xml.push_str(&format!("<subtitle>{}</subtitle>\n", escape_html(&self.channel.description)));
+    }
+    xml.push_str(&format!("<updated>{}</updated>\n", to_rfc3339(updated)));
+    xml.push_str(&format!(
+      "<link rel=\"self\" type=\"application/atom+xml\" href=\"{}\"/>\n",
+      escape_html(&self.feed_url(ATOM_FEED_PATH))
+    ));
+    xml.push_str(&format!(
+      "<link rel=\"alternate\" type=\"text/html\" href=\"{}\"/>\n",
+      escape_html(&self.site_url())
+    ));
+    // エントリーに著者がない場合、フィードに著者が必須
+    xml.push_str(&format!("<author><name>{}</name></author>\n", escape_html(&self.channel.title)));
+
+    for blog_post in blog_posts {
+      xml.push_str("<entry>\n");
+      xml.push_str(&format!("<id>urn:uuid:{}</id>\n", blog_post.get_id()));
+      xml.push_str(&format!("<title>{}</title>\n", escape_html(blog_post.get_title_text())));
+      xml.push_str(&format!(
+        "<link rel=\"alternate\" type=\"text/html\" href=\"{}\"/>\n",
+        escape_html(&self.post_url(blog_post))
+      ));
+      xml.push_str(&format!(
+        "<published>{}</published>\n",
+        to_rfc3339(blog_post.get_published_date().to_utc_datetime())
+      ));
+      xml.push_str(&format!(
+        "<updated>{}</updated>\n",
+        to_rfc3339(blog_post.get_last_update_date().to_utc_datetime())
+      ));
+      xml.push_str(&format!("<summary type=\"text\">{}</summary>\n", escape_html(&summarize(blog_post))));
+      if let Some(thumbnail) = blog_post.get_thumbnail() {
+        xml.push_str(&format!(
+          "<link rel=\"enclosure\" type=\"{}\" href=\"{}\"/>\n",
+          image_mime_type(thumbnail.get_path()),
+          escape_html(&self.image_url(thumbnail.get_path()))
+        ));
+      }
+      xml.push_str("</entry>\n");
+    }
+
+    xml.push_str("</feed>\n");
+    xml
+  }
+
+  /// フィードのタイトルやサイトの URL
+  pub fn channel(&self) -> &FeedChannelVO {
+    &self.channel
+  }
+
+  /// サイトのトップページの URL
+  pub fn site_url(&self) -> String {
+    format!("{}/", self.channel.site_url.trim_end_matches('/'))
+  }
+
+  /// フィードの公開パスから URL を作る
+  pub fn feed_url(&self, path: &str) -> String {
+    format!("{}{}", self.channel.site_url.trim_end_matches('/'), path)
+  }
+
+  /// 記事ページの URL
+  pub fn post_url(&self, blog_post: &BlogPostEntity) -> String {
+    format!("{}/posts/{}", self.channel.site_url.trim_end_matches('/'), blog_post.get_id())
+  }
+
+  /// 画像の配信元の URL
+  pub fn image_url(&self, path: &str) -> String {
+    if self.image_base_url.is_empty() {
+      return path.to_string();
+    }
+    format!("{}/{}", self.image_base_url.trim_end_matches('/'), path.trim_start_matches('/'))
+  }
+}
+
+/// フィードの最終更新日時（記事の最終更新日のうち最も新しいもの）を返す
+pub fn last_updated(blog_posts: &[BlogPostEntity]) -> Option<DateTime<Utc>> {
+  blog_posts.iter().map(|blog_post| blog_post.get_last_update_date().to_utc_datetime()).max()
+}
+
+/// 記事一覧のカードと同じ抜粋（手動で設定した抜粋、なければ段落のテキストから作った抜粋）を要約にする
+pub fn summarize(blog_post: &BlogPostEntity) -> String {
+  PostDigestService::new().excerpt(blog_post)
+}
+
+fn image_mime_type(path: &str) -> &'static str {
+  let extension = path.rsplit_once('.').map(|(_, extension)| extension.to_ascii_lowercase()).unwrap_or_default();
+  match extension.as_str() {
+    "png" => "image/png",
+    "gif" => "image/gif",
+    "webp" => "image/webp",
+    "avif" => "image/avif",
+    "svg" => "image/svg+xml",
+    _ => "image/jpeg",
+  }
+}
+
+/// 日時を JST（+09:00）で表す
+pub fn to_jst(datetime: DateTime<Utc>) -> DateTime<FixedOffset> {
+  datetime.with_timezone(&FixedOffset::east_opt(JST_OFFSET_SECONDS).unwrap())
+}
+
+fn to_rfc3339(datetime: DateTime<Utc>) -> String {
+  to_jst(datetime).to_rfc3339_opts(SecondsFormat::Secs, false)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::blog_domain::blog_post_entity::content_entity::ContentEntity;
+  use crate::domain::blog_domain::blog_post_entity::rich_text_vo::{RichTextPartVO, RichTextVO};
+  use crate::domain::blog_domain::jst_date_vo::JstDate;
+  use crate::domain::blog_domain::services::post_digest_service::EXCERPT_MAX_GRAPHEMES;
+  use roxmltree::{Document, Node};
+  use uuid::Uuid;
+
+  const ATOM_NAMESPACE: &str = "http://www.w3.org/2005/Atom";
+
+  fn service() -> FeedRendererService {
+    FeedRendererService::new(
+      FeedChannelVO {
+        title: "鉄火ブログ".to_string(),
+        description: "技術ブログ".to_string(),
+        site_url: "https://blog.example.com/".to_string(),
+      },
+      "https://res.example.com/blog".to_string(),
+    )
+  }
+
+  fn blog_post(title: &str, paragraph: &str) -> BlogPostEntity {
+    let mut blog_post = BlogPostEntity::new(Uuid::new_v4(), title.to_string());
+    blog_post.set_thumbnail(Uuid::new_v4(), "thumbnails/a.png".to_string());
+    blog_post.set_post_date(JstDate::new(2024, 3, 1).unwrap());
+    blog_post.set_published_date(JstDate::new(2024, 3, 2).unwrap());
+    blog_post.set_last_update_date(JstDate::new(2024, 4, 5).unwrap());
+    blog_post.add_content(ContentEntity::h2(Uuid::new_v4(), "見出しは要約に含めない".to_string()));
+    blog_post.add_content(ContentEntity::Paragraph(
+      crate::domain::blog_domain::blog_post_entity::paragraph_entity::ParagraphEntity::new(
+        Uuid::new_v4(),
+        RichTextVO::new(vec![RichTextPartVO::new(paragraph.to_string(), None, None)]),
+      ),
+    ));
+    blog_post
+  }
+
+  fn child<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    node.children().find(|child| child.is_element() && child.tag_name().name() == name)
+  }
+
+  fn child_text(node: Node, name: &str) -> String {
+    child(node, name).and_then(|child| child.text()).unwrap_or_default().to_string()
+  }
+
+  /// RSS 2.0 仕様の必須要素と日付・enclosure の形式を検証する
+  fn assert_valid_rss(xml: &str) {
+    let document = Document::parse(xml).expect("整形式の XML であること");
+    let rss = document.root_element();
+    assert_eq!(rss.tag_name().name(), "rss");
+    assert_eq!(rss.attribute("version"), Some("2.0"));
+    let channel = child(rss, "channel").expect("channel は必須");
+    for name in ["title", "link", "description"] {
+      assert!(child(channel, name).is_some(), "channel の {} は必須", name);
+    }
+    if let Some(last_build_date) = child(channel, "lastBuildDate") {
+      DateTime::parse_from_rfc2822(last_build_date.text().unwrap()).expect("lastBuildDate は RFC 822 形式");
+    }
+
+    for item in channel.children().filter(|node| node.has_tag_name("item")) {
+      assert!(
+        child(item, "title").is_some() || child(item, "description").is_some(),
+        "item には title か description が必要"
+      );
+      DateTime::parse_from_rfc2822(&child_text(item, "pubDate")).expect("pubDate は RFC 822 形式");
+      if let Some(enclosure) = child(item, "enclosure") {
+        for attribute in ["url", "length", "type"] {
+          assert!(enclosure.attribute(attribute).is_some(), "enclosure の {} は必須", attribute);
+        }
+      }
+    }
+  }
+
+  /// Atom（RFC 4287）の必須要素と日付の形式を検証する
+  fn assert_valid_atom(xml: &str) {
+    let document = Document::parse(xml).expect("整形式の XML であること");
+    let feed = document.root_element();
+    assert_eq!(feed.tag_name().name(), "feed");
+    assert_eq!(feed.tag_name().namespace(), Some(ATOM_NAMESPACE));
+    for name in ["id", "title", "updated", "author"] {
+      assert!(child(feed, name).is_some(), "feed の {} は必須", name);
+    }
+    DateTime::parse_from_rfc3339(&child_text(feed, "updated")).expect("updated は RFC 3339 形式");
+    assert!(feed.children().any(|node| node.has_tag_name("link") && node.attribute("rel") == Some("self")));
+
+    for entry in feed.children().filter(|node| node.has_tag_name("entry")) {
+      for name in ["id", "title", "updated"] {
+        assert!(child(entry, name).is_some(), "entry の {} は必須", name);
+      }
+      DateTime::parse_from_rfc3339(&child_text(entry, "updated")).expect("updated は RFC 3339 形式");
+      DateTime::parse_from_rfc3339(&child_text(entry, "published")).expect("published は RFC 3339 形式");
+      assert!(entry.children().any(|node| node.has_tag_name("link") && node.attribute("rel") == Some("alternate")));
+    }
+  }
+
+  #[test]
+  fn renders_valid_rss() {
+    let blog_post = blog_post("Rust & <WebAssembly>", "本文です。");
+
+    let post_id = blog_post.get_id();
+
+    let xml = service().render_rss(&[blog_post]);
+
+    assert_valid_rss(&xml);
+    let document = Document::parse(&xml).unwrap();
+    let item = document.descendants().find(|node| node.has_tag_name("item")).unwrap();
+    assert_eq!(child_text(item, "title"), "Rust & <WebAssembly>");
+    assert_eq!(child_text(item, "link"), format!("https://blog.example.com/posts/{}", post_id));
+    assert_eq!(child_text(item, "pubDate"), "Sat, 2 Mar 2024 00:00:00 +0900");
+    assert_eq!(child_text(item, "description"), "本文です。");
+    let enclosure = child(item, "enclosure").unwrap();
+    assert_eq!(enclosure.attribute("url"), Some("https://res.example.com/blog/thumbnails/a.png"));
+    assert_eq!(enclosure.attribute("type"), Some("image/png"));
+  }
+
+  #[test]
+  fn renders_valid_atom_with_last_update_date_as_updated() {
+    let blog_post = blog_post("記事", "本文です。");
+
+    let post_id = blog_post.get_id();
+
+    let xml = service().render_atom(&[blog_post]);
+
+    assert_valid_atom(&xml);
+    let document = Document::parse(&xml).unwrap();
+    let entry = document.descendants().find(|node| node.has_tag_name("entry")).unwrap();
+    assert_eq!(child_text(entry, "id"), format!("urn:uuid:{}", post_id));
+    assert_eq!(child_text(entry, "published"), "2024-03-02T00:00:00+09:00");
+    assert_eq!(child_text(entry, "updated"), "2024-04-05T00:00:00+09:00");
+    assert_eq!(child_text(document.root_element(), "updated"), "2024-04-05T00:00:00+09:00");
+    assert!(entry.children().any(|node| node.attribute("rel") == Some("enclosure")));
+  }
+
+  #[test]
+  fn renders_valid_feeds_without_posts() {
+    assert_valid_rss(&service().render_rss(&[]));
+    assert_valid_atom(&service().render_atom(&[]));
+  }
+
+  #[test]
+  fn truncates_long_summary() {
+    let blog_post = blog_post("長い記事", &"あ".repeat(EXCERPT_MAX_GRAPHEMES + 10));
+
+    let summary = summarize(&blog_post);
+
+    assert_eq!(summary.chars().count(), EXCERPT_MAX_GRAPHEMES + 1);
+    assert!(summary.ends_with('…'));
+  }
+}
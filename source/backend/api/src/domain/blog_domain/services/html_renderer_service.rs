@@ -0,0 +1,531 @@
+use std::collections::{HashMap, HashSet};
+
+use url::Url;
+use uuid::Uuid;
+
+use crate::domain::blog_domain::blog_post_entity::{
+  content_entity::ContentEntity,
+  heading_anchor_vo::HeadingAnchorVO,
+  rich_text_vo::{RichTextPartVO, RichTextVO},
+  BlogPostEntity,
+};
+
+/// リンクとして出力してよい URL スキーム
+const ALLOWED_URL_SCHEMES: [&str; 3] = ["http", "https", "mailto"];
+
+/// HTML レンダリングサービス
+///
+/// Next.js のフロントエンド以外（フィード、メールなど）に向けて、記事を意味付けされた HTML に変換するドメインサービス。
+/// 記事内のテキストはすべてエスケープし、スクリプトを実行できる URL や iframe は出力しない。
+pub struct HtmlRendererService {
+  image_base_url: String,
+}
+
+impl HtmlRendererService {
+  /// 新しいサービスインスタンスを作成する
+  ///
+  /// # Arguments
+  /// * `image_base_url` - 画像のパスの前に付ける配信元の URL。空の場合はパスをそのまま出力する
+  pub fn new(image_base_url: String) -> Self {
+    Self { image_base_url }
+  }
+
+  /// 記事全体をタイトル付きの `<article>` 要素として出力する
+  pub fn render(&self, blog_post: &BlogPostEntity) -> String {
+    format!(
+      "<article>\n<h1>{}</h1>\n{}</article>\n",
+      escape_html(blog_post.get_title_text()),
+      self.render_contents(blog_post)
+    )
+  }
+
+  /// 記事の本文と脚注を出力する
+  ///
+  /// 見出しにはコンテンツ ID をアンカー ID として付与するため、内部リンクの `#見出しID` がそのまま使える。
+  pub fn render_contents(&self, blog_post: &BlogPostEntity) -> String {
+    let mut context = RenderContext {
+      footnote_numbers: blog_post.get_footnotes().iter().map(|footnote| (footnote.get_id(), footnote.get_number())).collect(),
+      referenced_footnotes: HashSet::new(),
+    };
+
+    let mut html = String::new();
+    for content in blog_post.get_contents() {
+      self.render_content(content, &mut context, &mut html);
+    }
+
+    if !blog_post.get_footnotes().is_empty() {
+      html.push_str("<section class=\"footnotes\">\n<ol>\n");
+      for footnote in blog_post.get_footnotes() {
+        html.push_str(&format!(
+          "<li id=\"fn-{}\">{} <a href=\"#fnref-{}\" class=\"footnote-back\">↩</a></li>\n",
+          footnote.get_id(),
+          render_rich_text(footnote.get_body(), &mut context),
+          footnote.get_id()
+        ));
+      }
+      html.push_str("</ol>\n</section>\n");
+    }
+    html
+  }
+
+  fn render_content(&self, content: &ContentEntity, context: &mut RenderContext, html: &mut String) {
+    match content {
+      ContentEntity::H2(h2) => html.push_str(&format!(
+        "<h2 id=\"{}\">{}</h2>\n",
+        HeadingAnchorVO::new(h2.get_id(), h2.get_value()),
+        escape_html(h2.get_value())
+      )),
+      ContentEntity::H3(h3) => html.push_str(&format!(
+        "<h3 id=\"{}\">{}</h3>\n",
+        HeadingAnchorVO::new(h3.get_id(), h3.get_value()),
+        escape_html(h3.get_value())
+      )),
+      ContentEntity::Paragraph(paragraph) => html.push_str(&format!("<p>{}</p>\n", render_rich_text(paragraph.get_value(), context))),
+      ContentEntity::Image(image) => {
+        let presentation = image.get_presentation();
+        let width = presentation.get_width().map(|width| format!(" width=\"{}\"", width)).unwrap_or_default();
+        let mut img = format!(
+          "<img src=\"{}\" alt=\"{}\"{} loading=\"lazy\">",
+          escape_html(&self.image_url(image.get_path())),
+          escape_html(presentation.get_alt()),
+          width
+        );
+        if let Some(link_url) = presentation.get_link_url().and_then(sanitize_url) {
+          img = format!("<a href=\"{}\">{}</a>", escape_html(&link_url), img);
+        }
+        let caption = presentation.get_caption().map(|caption| format!("<figcaption>{}</figcaption>", escape_html(caption))).unwrap_or_default();
+        html.push_str(&format!("<figure>{}{}</figure>\n", img, caption));
+      }
+      ContentEntity::CodeBlock(code) => {
+        let title = if code.get_title().trim().is_empty() {
+          String::new()
+        } else {
+          format!("<figcaption>{}</figcaption>", escape_html(code.get_title()))
+        };
+        html.push_str(&format!(
+          "<figure class=\"code-block\">{}<pre><code class=\"language-{}\">{}</code></pre></figure>\n",
+          title,
+          escape_html(code.get_language()),
+          escape_html(code.get_code())
+        ));
+      }
+      ContentEntity::Math(math) => html.push_str(&format!("<div class=\"math math-display\">{}</div>\n", escape_html(math.get_source()))),
+      // iframe は出力せず、埋め込み先へのリンクとして表示する
+      ContentEntity::Embed(embed) => {
+        let url = escape_html(&embed.get_url());
+        html.push_str(&format!(
+          "<p class=\"embed embed-{}\"><a href=\"{}\">{}</a></p>\n",
+          embed.get_provider().as_str(),
+          url,
+          url
+        ));
+      }
+      ContentEntity::LinkCard(link_card) => {
+        let metadata = link_card.get_metadata();
+        let title = metadata.title.as_deref().unwrap_or(link_card.get_url());
+        let description = metadata.description.as_ref().map(|description| format!("<p>{}</p>", escape_html(description))).unwrap_or_default();
+        let site_name = metadata.site_name.as_ref().map(|site_name| format!("<small>{}</small>", escape_html(site_name))).unwrap_or_default();
+        html.push_str(&format!(
+          "<aside class=\"link-card\"><a href=\"{}\">{}</a>{}{}</aside>\n",
+          escape_html(link_card.get_url()),
+          escape_html(title),
+          description,
+          site_name
+        ));
+      }
+      ContentEntity::Details(details) => {
+        html.push_str(&format!("<details>\n<summary>{}</summary>\n", escape_html(details.get_summary())));
+        for child in details.get_children() {
+          self.render_content(child, context, html);
+        }
+        html.push_str("</details>\n");
+      }
+      ContentEntity::Diagram(diagram) => html.push_str(&format!(
+        "<pre class=\"diagram diagram-{}\">{}</pre>\n",
+        diagram.get_kind().as_str(),
+        escape_html(diagram.get_source())
+      )),
+    }
+  }
+
+  fn image_url(&self, path: &str) -> String {
+    if self.image_base_url.is_empty() {
+      return path.to_string();
+    }
+    format!("{}/{}", self.image_base_url.trim_end_matches('/'), path.trim_start_matches('/'))
+  }
+}
+
+/// 1 つの記事を出力する間だけ保持する状態
+struct RenderContext {
+  footnote_numbers: HashMap<Uuid, u32>,
+  /// 既に参照元のアンカーを出力した脚注（同じ脚注を複数回参照しても ID が重複しないようにする）
+  referenced_footnotes: HashSet<Uuid>,
+}
+
+fn render_rich_text(rich_text: &RichTextVO, context: &mut RenderContext) -> String {
+  rich_text.get_text().iter().map(|part| render_rich_text_part(part, context)).collect()
+}
+
+/// リッチテキストの断片を出力する
+///
+/// ルビ → スタイル → リンクの順に内側から囲み、脚注の参照は断片の直後に番号として出力する。
+fn render_rich_text_part(part: &RichTextPartVO, context: &mut RenderContext) -> String {
+  let styles = part.get_styles();
+  let mut html = escape_html(part.get_text());
+
+  if let Some(ruby) = part.get_ruby() {
+    html = format!("<ruby>{}<rt>{}</rt></ruby>", html, escape_html(ruby.get_value()));
+  }
+  if styles.inline_code {
+    html = format!("<code>{}</code>", html);
+  }
+  if part.is_inline_math() {
+    html = format!("<span class=\"math math-inline\">{}</span>", html);
+  }
+  if styles.bold {
+    html = format!("<strong>{}</strong>", html);
+  }
+  if styles.italic {
+    html = format!("<em>{}</em>", html);
+  }
+  if styles.strikethrough {
+    html = format!("<s>{}</s>", html);
+  }
+  if styles.underline {
+    html = format!("<u>{}</u>", html);
+  }
+  if let Some(color) = styles.highlight {
+    html = format!("<mark class=\"highlight-{}\">{}</mark>", color.as_str(), html);
+  }
+  if let Some(url) = part.get_link().and_then(|link| sanitize_url(&link.resolve_url())) {
+    html = format!("<a href=\"{}\">{}</a>", escape_html(&url), html);
+  }
+
+  if let Some(footnote_id) = part.get_footnote_ref() {
+    let number = context.footnote_numbers.get(&footnote_id).copied().unwrap_or_default();
+    let anchor_id = if context.referenced_footnotes.insert(footnote_id) {
+      format!(" id=\"fnref-{}\"", footnote_id)
+    } else {
+      String::new()
+    };
+    html.push_str(&format!(
+      "<sup class=\"footnote-ref\"><a href=\"#fn-{}\"{}>{}</a></sup>",
+      footnote_id, anchor_id, number
+    ));
+  }
+  html
+}
+
+/// リンク先として出力してよい URL だけを返す
+///
+/// サイト内のパス・アンカーと、許可したスキームの絶対 URL 以外（`javascript:` など）は捨てる。
+pub fn sanitize_url(raw_url: &str) -> Option<String> {
+  let url = raw_url.trim();
+  if url.starts_with('#') || (url.starts_with('/') && !url.starts_with("//")) {
+    return Some(url.to_string());
+  }
+  let parsed = Url::parse(url).ok()?;
+  if ALLOWED_URL_SCHEMES.contains(&parsed.scheme()) {
+    Some(url.to_string())
+  } else {
+    None
+  }
+}
+
+/// HTML の本文・属性値として安全に埋め込めるようにエスケープする
+pub fn escape_html(text: &str) -> String {
+  let mut escaped = String::with_capacity(text.len());
+  for c in text.chars() {
+    match c {
+      '&' => escaped.push_str("&amp;"),
+      '<' => escaped.push_str("&lt;"),
+      '>' => escaped.push_str("&gt;"),
+      '"' => escaped.push_str("&quot;"),
+      '\'' => escaped.push_str("&#39;"),
+      _ => escaped.push(c),
+    }
+  }
+  escaped
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::blog_domain::blog_post_entity::{
+    code_block_options_vo::CodeBlockOptionsVO,
+    diagram_source_vo::DiagramKind,
+    embed_block_entity::EmbedProvider,
+    footnote_entity::FootnoteEntity,
+    image_content_entity::ImageContentEntity,
+    image_presentation_vo::ImagePresentationVO,
+    link_card_block_entity::OgpMetadataVO,
+    rich_text_vo::{HighlightColor, LinkVO, RichTextStylesVO, RubyVO},
+  };
+  use crate::domain::image_domain::ImageEntity;
+  use std::path::PathBuf;
+
+  /// ゴールデンファイルと出力を比較する
+  ///
+  /// `UPDATE_GOLDEN=1` を指定して実行すると、ゴールデンファイルを現在の出力で上書きする。
+  fn assert_golden(name: &str, actual: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src/tests/golden/html_renderer").join(format!("{}.html", name));
+    if std::env::var("UPDATE_GOLDEN").is_ok() {
+      std::fs::write(&path, actual).unwrap();
+    }
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|_| panic!("ゴールデンファイルがありません: {}", path.display()));
+    assert_eq!(actual, expected, "{} の出力がゴールデンファイルと一致しません", name);
+  }
+
+  fn id(n: u128) -> Uuid {
+    Uuid::from_u128(n)
+  }
+
+  fn renderer() -> HtmlRendererService {
+    HtmlRendererService::new("https://images.example.com/blog/".to_string())
+  }
+
+  fn post_with(contents: Vec<ContentEntity>) -> BlogPostEntity {
+    let mut post = BlogPostEntity::new(id(1), "テスト記事".to_string());
+    for content in contents {
+      post.add_content(content);
+    }
+    post
+  }
+
+  fn plain(text: &str) -> RichTextPartVO {
+    RichTextPartVO::new(text.to_string(), None, None)
+  }
+
+  #[test]
+  fn renders_headings_with_anchor_ids() {
+    let post = post_with(vec![
+      ContentEntity::h2(id(10), "はじめに <概要>".to_string()),
+      ContentEntity::h3(id(11), "背景 & 目的".to_string()),
+    ]);
+
+    assert_golden("headings", &renderer().render_contents(&post));
+  }
+
+  #[test]
+  fn renders_paragraph_with_styles() {
+    let styled = |text: &str, styles: RichTextStylesVO| RichTextPartVO::new(text.to_string(), Some(styles), None);
+    let post = post_with(vec![ContentEntity::paragraph(
+      id(10),
+      RichTextVO::new(vec![
+        plain("通常 "),
+        styled(
+          "太字",
+          RichTextStylesVO {
+            bold: true,
+            ..Default::default()
+          },
+        ),
+        styled(
+          "let x = 1;",
+          RichTextStylesVO {
+            inline_code: true,
+            ..Default::default()
+          },
+        ),
+        RichTextPartVO::new("E = mc^2".to_string(), None, None).with_inline_math().unwrap(),
+        styled(
+          "斜体",
+          RichTextStylesVO {
+            italic: true,
+            ..Default::default()
+          },
+        ),
+        styled(
+          "取り消し",
+          RichTextStylesVO {
+            strikethrough: true,
+            ..Default::default()
+          },
+        ),
+        styled(
+          "下線",
+          RichTextStylesVO {
+            underline: true,
+            ..Default::default()
+          },
+        ),
+        styled(
+          "重要",
+          RichTextStylesVO {
+            bold: true,
+            highlight: Some(HighlightColor::Yellow),
+            ..Default::default()
+          },
+        ),
+        plain("漢字").with_ruby(RubyVO::new("かんじ".to_string()).unwrap()).unwrap(),
+      ]),
+    )]);
+
+    assert_golden("paragraph_styles", &renderer().render_contents(&post));
+  }
+
+  #[test]
+  fn renders_links_and_drops_unsafe_urls() {
+    let linked = |text: &str, link: LinkVO| RichTextPartVO::new(text.to_string(), None, Some(link));
+    let post = post_with(vec![ContentEntity::paragraph(
+      id(10),
+      RichTextVO::new(vec![
+        linked("外部", LinkVO::new("https://example.com/?a=1&b=2".to_string(), None, None)),
+        plain(" / "),
+        linked(
+          "内部",
+          LinkVO::new(String::new(), Some(id(2)), Some(id(3))).with_heading_text(Some("見出し".to_string())),
+        ),
+        plain(" / "),
+        linked("危険", LinkVO::new("javascript:alert(1)".to_string(), None, None)),
+      ]),
+    )]);
+
+    assert_golden("paragraph_links", &renderer().render_contents(&post));
+  }
+
+  #[test]
+  fn renders_image_with_presentation() {
+    let image = |content_id: u128, presentation: ImagePresentationVO| {
+      ContentEntity::image_from_entity(
+        ImageContentEntity::new(id(content_id), ImageEntity::new(id(20), "posts/sample.png".to_string())).with_presentation(presentation),
+      )
+    };
+    let post = post_with(vec![
+      image(
+        10,
+        ImagePresentationVO::new(
+          "構成図".to_string(),
+          Some("システム構成".to_string()),
+          Some(640),
+          Some("https://example.com/large.png".to_string()),
+        )
+        .unwrap(),
+      ),
+      image(11, ImagePresentationVO::default()),
+    ]);
+
+    assert_golden("image", &renderer().render_contents(&post));
+  }
+
+  #[test]
+  fn renders_code_block_with_escaped_code() {
+    let post = post_with(vec![
+      ContentEntity::code_block(
+        id(10),
+        "main.rs".to_string(),
+        "fn main() {\n  println!(\"<hello>\");\n}".to_string(),
+        "rust".to_string(),
+        CodeBlockOptionsVO::default(),
+      )
+      .unwrap(),
+      ContentEntity::code_block(id(11), String::new(), "echo ok".to_string(), "bash".to_string(), CodeBlockOptionsVO::default()).unwrap(),
+    ]);
+
+    assert_golden("code_block", &renderer().render_contents(&post));
+  }
+
+  #[test]
+  fn renders_math_block() {
+    let post = post_with(vec![ContentEntity::math_block(id(10), "\\frac{a}{b} < c".to_string()).unwrap()]);
+
+    assert_golden("math", &renderer().render_contents(&post));
+  }
+
+  #[test]
+  fn renders_embed_as_link() {
+    let post = post_with(vec![ContentEntity::embed(id(10), EmbedProvider::YouTube, "dQw4w9WgXcQ".to_string()).unwrap()]);
+
+    assert_golden("embed", &renderer().render_contents(&post));
+  }
+
+  #[test]
+  fn renders_link_card_with_fallback_title() {
+    let post = post_with(vec![
+      ContentEntity::link_card(
+        id(10),
+        "https://example.com/article".to_string(),
+        OgpMetadataVO {
+          title: Some("記事タイトル".to_string()),
+          description: Some("記事の説明".to_string()),
+          site_name: Some("Example".to_string()),
+          image_url: Some("https://example.com/ogp.png".to_string()),
+        },
+      )
+      .unwrap(),
+      ContentEntity::link_card(id(11), "https://example.com/no-ogp".to_string(), OgpMetadataVO::default()).unwrap(),
+    ]);
+
+    assert_golden("link_card", &renderer().render_contents(&post));
+  }
+
+  #[test]
+  fn renders_details_with_children() {
+    let post = post_with(vec![ContentEntity::details(
+      id(10),
+      "詳細を見る".to_string(),
+      vec![
+        ContentEntity::paragraph(id(11), RichTextVO::new(vec![plain("折りたたまれた本文")])),
+        ContentEntity::code_block(id(12), "a.txt".to_string(), "a".to_string(), "text".to_string(), CodeBlockOptionsVO::default()).unwrap(),
+      ],
+    )
+    .unwrap()]);
+
+    assert_golden("details", &renderer().render_contents(&post));
+  }
+
+  #[test]
+  fn renders_diagram_source() {
+    let post = post_with(vec![
+      ContentEntity::diagram(id(10), DiagramKind::Mermaid, "graph TD\n  A-->B".to_string()).unwrap()
+    ]);
+
+    assert_golden("diagram", &renderer().render_contents(&post));
+  }
+
+  #[test]
+  fn renders_footnotes_in_number_order() {
+    let mut post = post_with(vec![ContentEntity::paragraph(
+      id(10),
+      RichTextVO::new(vec![
+        plain("一つ目").with_footnote_ref(id(31)),
+        plain("、二つ目").with_footnote_ref(id(30)),
+        plain("、再び一つ目").with_footnote_ref(id(31)),
+      ]),
+    )]);
+    post
+      .set_footnotes(vec![
+        FootnoteEntity::new(id(30), RichTextVO::new(vec![plain("二番目の脚注")])).unwrap(),
+        FootnoteEntity::new(id(31), RichTextVO::new(vec![plain("一番目の脚注")])).unwrap(),
+      ])
+      .unwrap();
+
+    assert_golden("footnotes", &renderer().render_contents(&post));
+  }
+
+  #[test]
+  fn render_wraps_contents_in_article_with_title() {
+    let mut post = post_with(vec![ContentEntity::paragraph(id(10), RichTextVO::new(vec![plain("本文")]))]);
+    post.update_title("<script>タイトル</script>".to_string());
+
+    assert_golden("article", &renderer().render(&post));
+  }
+
+  #[test]
+  fn image_path_is_used_as_is_without_base_url() {
+    let renderer = HtmlRendererService::new(String::new());
+
+    assert_eq!(renderer.image_url("posts/a.png"), "posts/a.png");
+  }
+
+  #[test]
+  fn sanitize_url_allows_only_safe_destinations() {
+    assert_eq!(sanitize_url("/posts/1"), Some("/posts/1".to_string()));
+    assert_eq!(sanitize_url("#section"), Some("#section".to_string()));
+    assert_eq!(sanitize_url("mailto:me@example.com"), Some("mailto:me@example.com".to_string()));
+    assert_eq!(sanitize_url("//evil.example.com"), None);
+    assert_eq!(sanitize_url("JavaScript:alert(1)"), None);
+    assert_eq!(sanitize_url("data:text/html,<script>"), None);
+  }
+}
@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use crate::domain::blog_domain::blog_post_entity::rich_text_vo::InternalLinkTargetVO;
+use crate::domain::blog_domain::blog_post_entity::BlogPostEntity;
+
+/// 内部リンクが切れている理由
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DanglingLinkReason {
+  /// リンク先の記事が存在しない（削除された）
+  PostNotFound,
+  /// リンク先の記事が未公開
+  Unpublished,
+  /// リンク先の記事に指定した見出しが存在しない
+  HeadingNotFound,
+}
+
+/// リンク切れになっている内部リンク
+#[derive(Debug, Clone, PartialEq)]
+pub struct DanglingInternalLink {
+  pub source_post_id: Uuid,
+  pub source_post_title: String,
+  pub target: InternalLinkTargetVO,
+  pub reason: DanglingLinkReason,
+}
+
+/// 内部リンク検査サービス
+///
+/// 記事間の内部リンクのうち、リンク先が削除・未公開になっているものを検出するドメインサービス
+pub struct InternalLinkCheckerService;
+
+impl InternalLinkCheckerService {
+  /// 新しいサービスインスタンスを作成する
+  pub fn new() -> Self {
+    Self
+  }
+
+  /// リンク切れの内部リンクを検出する
+  ///
+  /// # Arguments
+  /// * `blog_posts` - 未公開記事を含むすべての記事
+  ///
+  /// # Returns
+  /// * `Vec<DanglingInternalLink>` - リンク元の記事の並び順、本文の出現順に並んだリンク切れの一覧
+  pub fn find_dangling_links(&self, blog_posts: &[BlogPostEntity]) -> Vec<DanglingInternalLink> {
+    let posts_by_id: HashMap<Uuid, &BlogPostEntity> = blog_posts.iter().map(|post| (post.get_id(), post)).collect();
+
+    let mut dangling_links = Vec::new();
+    for source_post in blog_posts {
+      for target in source_post.internal_link_targets() {
+        if let Some(reason) = Self::check_target(&posts_by_id, &target) {
+          dangling_links.push(DanglingInternalLink {
+            source_post_id: source_post.get_id(),
+            source_post_title: source_post.get_title_text().to_string(),
+            target,
+            reason,
+          });
+        }
+      }
+    }
+    dangling_links
+  }
+
+  fn check_target(posts_by_id: &HashMap<Uuid, &BlogPostEntity>, target: &InternalLinkTargetVO) -> Option<DanglingLinkReason> {
+    let Some(target_post) = posts_by_id.get(&target.get_post_id()) else {
+      return Some(DanglingLinkReason::PostNotFound);
+    };
+    if !target_post.is_published() {
+      return Some(DanglingLinkReason::Unpublished);
+    }
+    match target.get_heading_id() {
+      Some(heading_id) if !target_post.has_heading(heading_id) => Some(DanglingLinkReason::HeadingNotFound),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::blog_domain::blog_post_entity::content_entity::ContentEntity;
+  use crate::domain::blog_domain::blog_post_entity::rich_text_vo::{LinkVO, RichTextPartVO, RichTextVO};
+  use crate::domain::blog_domain::jst_date_vo::JstDate;
+
+  fn create_post(title: &str, published: bool) -> BlogPostEntity {
+    let mut post = BlogPostEntity::new(Uuid::new_v4(), title.to_string());
+    let published_date = if published { JstDate::new(2024, 1, 1) } else { JstDate::new(3000, 1, 1) };
+    post.set_published_date(published_date.unwrap());
+    post
+  }
+
+  fn add_internal_link(post: &mut BlogPostEntity, target: InternalLinkTargetVO) {
+    let link = LinkVO::Internal(target);
+    let rich_text = RichTextVO::new(vec![RichTextPartVO::new("関連記事".to_string(), None, Some(link))]);
+    post.add_content(ContentEntity::paragraph(Uuid::new_v4(), rich_text));
+  }
+
+  #[test]
+  fn links_to_published_posts_are_not_reported() {
+    let service = InternalLinkCheckerService::new();
+    let heading_id = Uuid::new_v4();
+    let mut target = create_post("リンク先", true);
+    target.add_content(ContentEntity::h2(heading_id, "見出し".to_string()));
+    let mut source = create_post("リンク元", true);
+    add_internal_link(&mut source, InternalLinkTargetVO::new(target.get_id(), None));
+    add_internal_link(&mut source, InternalLinkTargetVO::new(target.get_id(), Some(heading_id)));
+
+    let dangling_links = service.find_dangling_links(&[source, target]);
+
+    assert!(dangling_links.is_empty());
+  }
+
+  #[test]
+  fn links_to_missing_or_unpublished_posts_are_reported() {
+    let service = InternalLinkCheckerService::new();
+    let unpublished = create_post("未公開記事", false);
+    let missing_post_id = Uuid::new_v4();
+    let mut source = create_post("リンク元", true);
+    add_internal_link(&mut source, InternalLinkTargetVO::new(missing_post_id, None));
+    add_internal_link(&mut source, InternalLinkTargetVO::new(unpublished.get_id(), None));
+    let source_id = source.get_id();
+
+    let dangling_links = service.find_dangling_links(&[source, unpublished]);
+
+    assert_eq!(dangling_links.len(), 2);
+    assert_eq!(dangling_links[0].source_post_id, source_id);
+    assert_eq!(dangling_links[0].source_post_title, "リンク元");
+    assert_eq!(dangling_links[0].target.get_post_id(), missing_post_id);
+    assert_eq!(dangling_links[0].reason, DanglingLinkReason::PostNotFound);
+    assert_eq!(dangling_links[1].reason, DanglingLinkReason::Unpublished);
+  }
+
+  #[test]
+  fn links_to_removed_heading_are_reported() {
+    let service = InternalLinkCheckerService::new();
+    let target = create_post("リンク先", true);
+    let mut source = create_post("リンク元", true);
+    add_internal_link(&mut source, InternalLinkTargetVO::new(target.get_id(), Some(Uuid::new_v4())));
+
+    let dangling_links = service.find_dangling_links(&[source, target]);
+
+    assert_eq!(dangling_links.len(), 1);
+    assert_eq!(dangling_links[0].reason, DanglingLinkReason::HeadingNotFound);
+  }
+}
@@ -0,0 +1,405 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use super::html_renderer_service::sanitize_url;
+use crate::domain::blog_domain::{
+  blog_post_entity::{
+    content_entity::ContentEntity,
+    rich_text_vo::{RichTextPartVO, RichTextVO},
+    BlogPostEntity,
+  },
+  jst_date_vo::JstDate,
+};
+
+/// Markdown 変換サービス
+///
+/// git でのバックアップや Zenn・Qiita への転載に向けて、記事を YAML フロントマター付きの Markdown に変換するドメインサービス。
+/// コードブロックのタイトルや折りたたみブロックは Zenn の記法（`lang:title`、`:::details`）で出力する。
+/// 下線・ハイライト・ルビのように Markdown に対応する記法がない装飾は外し、テキストだけを残す。
+pub struct MarkdownSerializerService {
+  image_base_url: String,
+}
+
+impl MarkdownSerializerService {
+  /// 新しいサービスインスタンスを作成する
+  ///
+  /// # Arguments
+  /// * `image_base_url` - 画像のパスの前に付ける配信元の URL。空の場合はパスをそのまま出力する
+  pub fn new(image_base_url: String) -> Self {
+    Self { image_base_url }
+  }
+
+  /// 記事全体をフロントマター付きの Markdown として出力する
+  pub fn serialize(&self, blog_post: &BlogPostEntity) -> String {
+    format!("{}\n{}", self.front_matter(blog_post), self.serialize_contents(blog_post))
+  }
+
+  /// 記事の本文と脚注を出力する
+  ///
+  /// ブロック同士は空行で区切り、脚注は本文の後ろに `[^番号]: 本文` の形で並べる。
+  pub fn serialize_contents(&self, blog_post: &BlogPostEntity) -> String {
+    let footnote_numbers: HashMap<Uuid, u32> = blog_post.get_footnotes().iter().map(|footnote| (footnote.get_id(), footnote.get_number())).collect();
+
+    let mut blocks: Vec<String> = blog_post.get_contents().iter().map(|content| self.serialize_content(content, &footnote_numbers)).collect();
+    if !blog_post.get_footnotes().is_empty() {
+      let footnotes: Vec<String> = blog_post
+        .get_footnotes()
+        .iter()
+        .map(|footnote| format!("[^{}]: {}", footnote.get_number(), serialize_rich_text(footnote.get_body(), &footnote_numbers)))
+        .collect();
+      blocks.push(footnotes.join("\n"));
+    }
+
+    blocks.iter().map(|block| format!("{}\n", block)).collect::<Vec<_>>().join("\n")
+  }
+
+  fn front_matter(&self, blog_post: &BlogPostEntity) -> String {
+    let mut lines = vec![
+      "---".to_string(),
+      format!("title: {}", yaml_string(blog_post.get_title_text())),
+      format!("post_date: {}", format_date(blog_post.get_post_date())),
+      format!("last_update_date: {}", format_date(blog_post.get_last_update_date())),
+      format!("published_date: {}", format_date(blog_post.get_published_date())),
+    ];
+    if let Some(thumbnail) = blog_post.get_thumbnail() {
+      lines.push(format!("thumbnail: {}", yaml_string(&self.image_url(thumbnail.get_path()))));
+    }
+    lines.push(format!("published: {}", blog_post.is_published()));
+    lines.push("---".to_string());
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+  }
+
+  fn serialize_content(&self, content: &ContentEntity, footnote_numbers: &HashMap<Uuid, u32>) -> String {
+    match content {
+      ContentEntity::H2(h2) => format!("## {}", escape_markdown(h2.get_value())),
+      ContentEntity::H3(h3) => format!("### {}", escape_markdown(h3.get_value())),
+      ContentEntity::Paragraph(paragraph) => escape_line_start(&serialize_rich_text(paragraph.get_value(), footnote_numbers)),
+      ContentEntity::Image(image) => {
+        let presentation = image.get_presentation();
+        let mut markdown = format!(
+          "![{}]({})",
+          escape_markdown(presentation.get_alt()),
+          escape_link_destination(&self.image_url(image.get_path()))
+        );
+        if let Some(link_url) = presentation.get_link_url().and_then(sanitize_url) {
+          markdown = format!("[{}]({})", markdown, escape_link_destination(&link_url));
+        }
+        // Zenn と同じく、画像の直後の行に強調で書いたテキストをキャプションとして扱う
+        if let Some(caption) = presentation.get_caption() {
+          markdown.push_str(&format!("\n*{}*", escape_markdown(caption)));
+        }
+        markdown
+      }
+      ContentEntity::CodeBlock(code) => {
+        let info = if code.get_title().trim().is_empty() {
+          code.get_language().to_string()
+        } else {
+          format!("{}:{}", code.get_language(), code.get_title())
+        };
+        fenced_block(&info, code.get_code())
+      }
+      ContentEntity::Math(math) => format!("$$\n{}\n$$", math.get_source()),
+      // URL だけの行は Zenn・Qiita のどちらでも埋め込みやリンクカードとして表示される
+      ContentEntity::Embed(embed) => embed.get_url(),
+      ContentEntity::LinkCard(link_card) => link_card.get_url().to_string(),
+      ContentEntity::Details(details) => {
+        let children: Vec<String> = details.get_children().iter().map(|child| self.serialize_content(child, footnote_numbers)).collect();
+        format!(":::details {}\n{}\n:::", details.get_summary(), children.join("\n\n"))
+      }
+      ContentEntity::Diagram(diagram) => fenced_block(diagram.get_kind().as_str(), diagram.get_source()),
+    }
+  }
+
+  fn image_url(&self, path: &str) -> String {
+    if self.image_base_url.is_empty() {
+      return path.to_string();
+    }
+    format!("{}/{}", self.image_base_url.trim_end_matches('/'), path.trim_start_matches('/'))
+  }
+}
+
+fn serialize_rich_text(rich_text: &RichTextVO, footnote_numbers: &HashMap<Uuid, u32>) -> String {
+  rich_text.get_text().iter().map(|part| serialize_rich_text_part(part, footnote_numbers)).collect()
+}
+
+/// リッチテキストの断片を出力する
+///
+/// 強調の記号は前後に空白があると効かないため、断片の前後の空白は記号の外側に出す。
+fn serialize_rich_text_part(part: &RichTextPartVO, footnote_numbers: &HashMap<Uuid, u32>) -> String {
+  let styles = part.get_styles();
+  let text = part.get_text();
+  let trimmed = text.trim();
+  let (leading, trailing) = if trimmed.is_empty() {
+    (text, "")
+  } else {
+    let start = text.len() - text.trim_start().len();
+    (&text[..start], &text[start + trimmed.len()..])
+  };
+
+  let mut markdown = if styles.inline_code {
+    inline_code(trimmed)
+  } else if part.is_inline_math() {
+    format!("${}$", trimmed)
+  } else {
+    escape_markdown(trimmed)
+  };
+  if !trimmed.is_empty() {
+    if styles.bold {
+      markdown = format!("**{}**", markdown);
+    }
+    if styles.italic {
+      markdown = format!("*{}*", markdown);
+    }
+    if styles.strikethrough {
+      markdown = format!("~~{}~~", markdown);
+    }
+    if let Some(url) = part.get_link().and_then(|link| sanitize_url(&link.resolve_url())) {
+      markdown = format!("[{}]({})", markdown, escape_link_destination(&url));
+    }
+  }
+
+  let mut markdown = format!("{}{}{}", leading, markdown, trailing);
+  if let Some(number) = part.get_footnote_ref().and_then(|footnote_id| footnote_numbers.get(&footnote_id)) {
+    markdown.push_str(&format!("[^{}]", number));
+  }
+  markdown
+}
+
+/// コード中のバッククォートより長いフェンスで囲んだコードブロックを出力する
+fn fenced_block(info: &str, code: &str) -> String {
+  let fence = "`".repeat((longest_backtick_run(code) + 1).max(3));
+  format!("{}{}\n{}\n{}", fence, info, code, fence)
+}
+
+/// インラインコードを出力する
+///
+/// コード中にバッククォートが含まれる場合は、それより長いバッククォートで囲む。
+fn inline_code(code: &str) -> String {
+  let fence = "`".repeat(longest_backtick_run(code) + 1);
+  if code.starts_with('`') || code.ends_with('`') {
+    format!("{} {} {}", fence, code, fence)
+  } else {
+    format!("{}{}{}", fence, code, fence)
+  }
+}
+
+fn longest_backtick_run(text: &str) -> usize {
+  text.split(|c| c != '`').map(str::len).max().unwrap_or(0)
+}
+
+/// Markdown の記法として解釈される記号をエスケープする
+fn escape_markdown(text: &str) -> String {
+  let mut escaped = String::with_capacity(text.len());
+  for c in text.chars() {
+    if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '~' | '$') {
+      escaped.push('\\');
+    }
+    escaped.push(c);
+  }
+  escaped
+}
+
+/// 段落の先頭が見出しやリストの記号として解釈されないようにする
+fn escape_line_start(markdown: &str) -> String {
+  if markdown.starts_with(['#', '-', '+']) {
+    return format!("\\{}", markdown);
+  }
+  // 「1. 」のような番号付きリストの書き出し
+  let digits = markdown.chars().take_while(char::is_ascii_digit).count();
+  if digits > 0 && markdown[digits..].starts_with(". ") {
+    return format!("{}\\{}", &markdown[..digits], &markdown[digits..]);
+  }
+  markdown.to_string()
+}
+
+/// リンク先の URL に含まれる括弧と空白をパーセントエンコードする
+fn escape_link_destination(url: &str) -> String {
+  url.replace(' ', "%20").replace('(', "%28").replace(')', "%29")
+}
+
+/// YAML の文字列として出力する
+///
+/// JSON の文字列リテラルは YAML のダブルクォート文字列としてそのまま読めるため、エスケープは serde_json に任せる。
+fn yaml_string(value: &str) -> String {
+  serde_json::to_string(value).unwrap_or_else(|_| "\"\"".to_string())
+}
+
+fn format_date(date: &JstDate) -> String {
+  date.to_naive_date().format("%Y-%m-%d").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::blog_domain::blog_post_entity::{
+    code_block_options_vo::CodeBlockOptionsVO,
+    diagram_source_vo::DiagramKind,
+    footnote_entity::FootnoteEntity,
+    image_content_entity::ImageContentEntity,
+    image_presentation_vo::ImagePresentationVO,
+    rich_text_vo::{LinkVO, RichTextStylesVO},
+  };
+  use crate::domain::image_domain::ImageEntity;
+  use std::path::PathBuf;
+
+  /// ゴールデンファイルと出力を比較する
+  ///
+  /// `UPDATE_GOLDEN=1` を指定して実行すると、ゴールデンファイルを現在の出力で上書きする。
+  fn assert_golden(name: &str, actual: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src/tests/golden/markdown_serializer").join(format!("{}.md", name));
+    if std::env::var("UPDATE_GOLDEN").is_ok() {
+      std::fs::write(&path, actual).unwrap();
+    }
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|_| panic!("ゴールデンファイルがありません: {}", path.display()));
+    assert_eq!(actual, expected, "{} の出力がゴールデンファイルと一致しません", name);
+  }
+
+  fn id(n: u128) -> Uuid {
+    Uuid::from_u128(n)
+  }
+
+  fn serializer() -> MarkdownSerializerService {
+    MarkdownSerializerService::new("https://images.example.com/blog/".to_string())
+  }
+
+  fn plain(text: &str) -> RichTextPartVO {
+    RichTextPartVO::new(text.to_string(), None, None)
+  }
+
+  fn styled(text: &str, styles: RichTextStylesVO) -> RichTextPartVO {
+    RichTextPartVO::new(text.to_string(), Some(styles), None)
+  }
+
+  #[test]
+  fn serializes_article_with_front_matter() {
+    let mut post = BlogPostEntity::new(id(1), "Rust で \"Markdown\" を書き出す".to_string());
+    post
+      .set_post_date(JstDate::new(2024, 1, 2).unwrap())
+      .set_last_update_date(JstDate::new(2024, 2, 3).unwrap())
+      .set_published_date(JstDate::new(2024, 1, 2).unwrap())
+      .set_thumbnail(id(2), "thumbnails/rust.png".to_string())
+      .add_content(ContentEntity::h2(id(10), "はじめに".to_string()))
+      .add_content(ContentEntity::paragraph(
+        id(11),
+        RichTextVO::new(vec![
+          plain("本文は "),
+          styled(
+            "太字",
+            RichTextStylesVO {
+              bold: true,
+              ..Default::default()
+            },
+          ),
+          plain(" と "),
+          styled(
+            "cargo build",
+            RichTextStylesVO {
+              inline_code: true,
+              ..Default::default()
+            },
+          ),
+          plain(" を含む。"),
+          RichTextPartVO::new(
+            "公式サイト".to_string(),
+            None,
+            Some(LinkVO::new("https://www.rust-lang.org/".to_string(), None, None)),
+          ),
+          plain("も参照。").with_footnote_ref(id(30)),
+        ]),
+      ))
+      .add_content(ContentEntity::h3(id(12), "サンプル".to_string()))
+      .add_content(
+        ContentEntity::code_block(
+          id(13),
+          "main.rs".to_string(),
+          "fn main() {\n  println!(\"hello\");\n}".to_string(),
+          "rust".to_string(),
+          CodeBlockOptionsVO::default(),
+        )
+        .unwrap(),
+      )
+      .add_content(ContentEntity::image_from_entity(
+        ImageContentEntity::new(id(14), ImageEntity::new(id(20), "posts/sample.png".to_string()))
+          .with_presentation(ImagePresentationVO::new("構成図".to_string(), Some("システム構成".to_string()), None, None).unwrap()),
+      ))
+      .add_content(
+        ContentEntity::details(
+          id(15),
+          "実行ログ".to_string(),
+          vec![ContentEntity::paragraph(id(16), RichTextVO::new(vec![plain("折りたたまれた本文")]))],
+        )
+        .unwrap(),
+      )
+      .add_content(ContentEntity::math_block(id(17), "E = mc^2".to_string()).unwrap())
+      .add_content(ContentEntity::diagram(id(18), DiagramKind::Mermaid, "graph TD\n  A-->B".to_string()).unwrap());
+    post
+      .set_footnotes(vec![
+        FootnoteEntity::new(id(30), RichTextVO::new(vec![plain("2024 年 1 月時点の情報です。")])).unwrap()
+      ])
+      .unwrap();
+
+    assert_golden("article", &serializer().serialize(&post));
+  }
+
+  #[test]
+  fn serializes_code_block_without_title_and_with_backticks() {
+    let mut post = BlogPostEntity::new(id(1), "コード".to_string());
+    post
+      .add_content(ContentEntity::code_block(id(10), String::new(), "echo ok".to_string(), "bash".to_string(), CodeBlockOptionsVO::default()).unwrap())
+      .add_content(
+        ContentEntity::code_block(
+          id(11),
+          "README.md".to_string(),
+          "```sh\nls\n```".to_string(),
+          "markdown".to_string(),
+          CodeBlockOptionsVO::default(),
+        )
+        .unwrap(),
+      );
+
+    assert_eq!(
+      serializer().serialize_contents(&post),
+      "```bash\necho ok\n```\n\n````markdown:README.md\n```sh\nls\n```\n````\n"
+    );
+  }
+
+  #[test]
+  fn escapes_markdown_syntax_in_plain_text() {
+    let mut post = BlogPostEntity::new(id(1), "エスケープ".to_string());
+    post
+      .add_content(ContentEntity::paragraph(
+        id(10),
+        RichTextVO::new(vec![plain("# 見出しではない *強調でもない* [リンク](でもない)")]),
+      ))
+      .add_content(ContentEntity::paragraph(id(11), RichTextVO::new(vec![plain("1. リストではない")])));
+
+    assert_eq!(
+      serializer().serialize_contents(&post),
+      "\\# 見出しではない \\*強調でもない\\* \\[リンク\\](でもない)\n\n1\\. リストではない\n"
+    );
+  }
+
+  #[test]
+  fn keeps_whitespace_outside_of_emphasis_and_drops_unsafe_links() {
+    let rich_text = RichTextVO::new(vec![
+      styled(
+        " 太字 ",
+        RichTextStylesVO {
+          bold: true,
+          ..Default::default()
+        },
+      ),
+      RichTextPartVO::new("危険".to_string(), None, Some(LinkVO::new("javascript:alert(1)".to_string(), None, None))),
+      styled(
+        "a`b",
+        RichTextStylesVO {
+          inline_code: true,
+          ..Default::default()
+        },
+      ),
+    ]);
+
+    assert_eq!(serialize_rich_text(&rich_text, &HashMap::new()), " **太字** 危険``a`b``");
+  }
+}
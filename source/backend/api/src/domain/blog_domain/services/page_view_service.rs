@@ -0,0 +1,122 @@
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::domain::blog_domain::jst_date_vo::JstDate;
+use crate::domain::blog_domain::page_view_repository::PageViewVO;
+
+/// User-Agent にこれらの語が含まれる場合はクローラーやプレビュー生成などのプログラムとみなす（小文字で比較する）
+const BOT_USER_AGENT_KEYWORDS: [&str; 17] = [
+  "bot",
+  "crawl",
+  "spider",
+  "slurp",
+  "mediapartners",
+  "facebookexternalhit",
+  "embedly",
+  "preview",
+  "headless",
+  "lighthouse",
+  "pingdom",
+  "uptime",
+  "curl",
+  "wget",
+  "python-requests",
+  "go-http-client",
+  "okhttp",
+];
+
+/// 閲覧記録サービス
+///
+/// 記事の閲覧のうち人による閲覧だけを、閲覧者を特定できない形で記録するためのドメインサービス
+pub struct PageViewService {
+  salt: String,
+}
+
+impl PageViewService {
+  /// 新しいサービスインスタンスを作成する
+  ///
+  /// `salt` は閲覧者のハッシュを作るときに混ぜる秘密の値で、ハッシュから IP アドレスを総当たりで求められないようにする
+  pub fn new(salt: String) -> Self {
+    Self { salt }
+  }
+
+  /// User-Agent からクローラーなどのプログラムによる閲覧かどうかを判定する（User-Agent がない場合もプログラムとみなす）
+  pub fn is_bot(&self, user_agent: Option<&str>) -> bool {
+    let Some(user_agent) = user_agent.map(str::trim).filter(|user_agent| !user_agent.is_empty()) else {
+      return true;
+    };
+    let user_agent = user_agent.to_lowercase();
+    BOT_USER_AGENT_KEYWORDS.iter().any(|keyword| user_agent.contains(keyword))
+  }
+
+  /// 記録する閲覧を作る（プログラムによる閲覧の場合は記録しないため `None` を返す）
+  ///
+  /// 閲覧者のハッシュには日付を混ぜ、日をまたいで同じ閲覧者を追跡できないようにする
+  pub fn page_view(&self, post_id: Uuid, client_ip: &str, user_agent: Option<&str>, today: JstDate) -> Option<PageViewVO> {
+    if self.is_bot(user_agent) {
+      return None;
+    }
+
+    let mut hasher = Sha256::new();
+    for part in [
+      self.salt.as_str(),
+      &today.to_naive_date().to_string(),
+      client_ip,
+      user_agent.unwrap_or_default(),
+    ] {
+      hasher.update(part.as_bytes());
+      hasher.update([0]);
+    }
+
+    Some(PageViewVO {
+      post_id,
+      visitor_hash: format!("{:x}", hasher.finalize()),
+      viewed_on: today,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const BROWSER: &str = "Mozilla/5.0 (Macintosh; Intel Mac OS X 14_0) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.0 Safari/605.1.15";
+
+  #[test]
+  fn detects_bots_by_user_agent() {
+    let service = PageViewService::new("salt".to_string());
+
+    assert!(service.is_bot(Some("Mozilla/5.0 (compatible; Googlebot/2.1; +http://www.google.com/bot.html)")));
+    assert!(service.is_bot(Some("Slackbot-LinkExpanding 1.0 (+https://api.slack.com/robots)")));
+    assert!(service.is_bot(Some("curl/8.4.0")));
+    assert!(service.is_bot(Some("  ")));
+    assert!(service.is_bot(None));
+    assert!(!service.is_bot(Some(BROWSER)));
+  }
+
+  #[test]
+  fn same_visitor_has_same_hash_only_on_the_same_day() {
+    let service = PageViewService::new("salt".to_string());
+    let post_id = Uuid::new_v4();
+    let day = JstDate::new(2026, 10, 19).unwrap();
+    let next_day = JstDate::new(2026, 10, 20).unwrap();
+
+    let first = service.page_view(post_id, "203.0.113.1", Some(BROWSER), day.clone()).unwrap();
+    let second = service.page_view(post_id, "203.0.113.1", Some(BROWSER), day.clone()).unwrap();
+    let other_visitor = service.page_view(post_id, "203.0.113.2", Some(BROWSER), day.clone()).unwrap();
+    let next_day = service.page_view(post_id, "203.0.113.1", Some(BROWSER), next_day).unwrap();
+
+    assert_eq!(first.visitor_hash, second.visitor_hash);
+    assert_ne!(first.visitor_hash, other_visitor.visitor_hash);
+    assert_ne!(first.visitor_hash, next_day.visitor_hash);
+    assert!(!first.visitor_hash.contains("203.0.113.1"));
+    assert_eq!(first.viewed_on, day);
+  }
+
+  #[test]
+  fn does_not_record_bot_views() {
+    let service = PageViewService::new("salt".to_string());
+
+    assert_eq!(service.page_view(Uuid::new_v4(), "203.0.113.1", Some("Twitterbot/1.0"), JstDate::today()), None);
+  }
+}
@@ -0,0 +1,81 @@
+use std::collections::HashSet;
+
+use uuid::Uuid;
+
+use crate::domain::blog_domain::page_view_repository::PostViewCountVO;
+
+/// 人気記事の件数（PopularPostSetEntity と同じく 3 件固定）
+pub const POPULAR_POST_COUNT: usize = 3;
+
+/// 人気記事ランキングサービス
+///
+/// 直近の閲覧数から人気記事を選ぶドメインサービス
+pub struct PopularPostRankingService;
+
+impl PopularPostRankingService {
+  /// 新しいサービスインスタンスを作成する
+  pub fn new() -> Self {
+    Self
+  }
+
+  /// 閲覧数の多い順に人気記事を 3 件選ぶ
+  ///
+  /// 閲覧数が同じ場合は記事 ID の順に並べる。閲覧された記事が 3 件に満たない場合は、
+  /// 現在の人気記事のうち選ばれていないものを前から順に補う。それでも足りない場合は `None` を返す
+  pub fn rank(&self, view_counts: &[PostViewCountVO], current_popular_post_ids: &[Uuid]) -> Option<Vec<Uuid>> {
+    let mut view_counts: Vec<&PostViewCountVO> = view_counts.iter().filter(|view_count| view_count.views > 0).collect();
+    view_counts.sort_by(|a, b| b.views.cmp(&a.views).then(a.post_id.cmp(&b.post_id)));
+
+    let mut selected: Vec<Uuid> = Vec::new();
+    let mut seen = HashSet::new();
+    let candidates = view_counts.into_iter().map(|view_count| view_count.post_id).chain(current_popular_post_ids.iter().copied());
+    for post_id in candidates {
+      if selected.len() == POPULAR_POST_COUNT {
+        break;
+      }
+      if seen.insert(post_id) {
+        selected.push(post_id);
+      }
+    }
+
+    (selected.len() == POPULAR_POST_COUNT).then_some(selected)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn view_count(id: u128, views: u64) -> PostViewCountVO {
+    PostViewCountVO {
+      post_id: Uuid::from_u128(id),
+      views,
+    }
+  }
+
+  #[test]
+  fn ranks_posts_by_views_and_breaks_ties_by_id() {
+    let view_counts = vec![view_count(1, 10), view_count(4, 30), view_count(3, 20), view_count(2, 20)];
+
+    let ranked = PopularPostRankingService::new().rank(&view_counts, &[]);
+
+    assert_eq!(ranked, Some(vec![Uuid::from_u128(4), Uuid::from_u128(2), Uuid::from_u128(3)]));
+  }
+
+  #[test]
+  fn fills_with_current_popular_posts_when_views_are_not_enough() {
+    let view_counts = vec![view_count(1, 5), view_count(2, 0)];
+    let current = vec![Uuid::from_u128(1), Uuid::from_u128(8), Uuid::from_u128(9)];
+
+    let ranked = PopularPostRankingService::new().rank(&view_counts, &current);
+
+    assert_eq!(ranked, Some(vec![Uuid::from_u128(1), Uuid::from_u128(8), Uuid::from_u128(9)]));
+  }
+
+  #[test]
+  fn returns_none_when_posts_are_not_enough() {
+    let ranked = PopularPostRankingService::new().rank(&[view_count(1, 5)], &[Uuid::from_u128(1)]);
+
+    assert_eq!(ranked, None);
+  }
+}
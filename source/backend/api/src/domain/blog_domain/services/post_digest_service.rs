@@ -0,0 +1,251 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::domain::blog_domain::blog_post_entity::content_entity::ContentEntity;
+use crate::domain::blog_domain::blog_post_entity::BlogPostEntity;
+
+/// 本文から作る抜粋の最大文字数（書記素単位）
+pub const EXCERPT_MAX_GRAPHEMES: usize = 120;
+/// 日本語（かな・漢字・全角記号）を 1 分間に読める文字数
+const JAPANESE_CHARS_PER_MINUTE: f64 = 500.0;
+/// 英文やコードを 1 分間に読める単語数
+const WORDS_PER_MINUTE: f64 = 200.0;
+
+/// 記事ダイジェストサービス
+///
+/// 記事一覧のカードに表示する抜粋と読了時間を本文のコンテンツから求めるドメインサービス
+pub struct PostDigestService;
+
+impl PostDigestService {
+  /// 新しいサービスインスタンスを作成する
+  pub fn new() -> Self {
+    Self
+  }
+
+  /// 記事の抜粋を取得する
+  ///
+  /// 手動で設定された抜粋があればそれを優先し、なければ本文の段落から作る
+  pub fn excerpt(&self, blog_post: &BlogPostEntity) -> String {
+    match blog_post.get_manual_excerpt() {
+      Some(manual_excerpt) => manual_excerpt.to_string(),
+      None => self.excerpt_from_contents(blog_post.get_contents(), EXCERPT_MAX_GRAPHEMES),
+    }
+  }
+
+  /// 段落のテキストをつなげたプレーンテキストから、先頭 `max_graphemes` 文字の抜粋を作る
+  ///
+  /// 折りたたみブロックの中の段落も含め、インライン数式は LaTeX のソースをそのまま出さないよう除く。
+  /// 濁点の結合文字や絵文字の異体字セレクタなどを途中で切らないよう、書記素単位で数える。
+  /// 切り詰めた場合は末尾に「…」を付ける
+  pub fn excerpt_from_contents(&self, contents: &[ContentEntity], max_graphemes: usize) -> String {
+    let mut paragraphs = Vec::new();
+    collect_paragraph_texts(contents, &mut paragraphs);
+    let text = paragraphs.join(" ");
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    let graphemes = text.graphemes(true).collect::<Vec<_>>();
+    if graphemes.len() <= max_graphemes {
+      return text;
+    }
+    let mut excerpt = graphemes[..max_graphemes].concat();
+    excerpt.push('…');
+    excerpt
+  }
+
+  /// 読了時間（分）を求める
+  ///
+  /// 日本語は文字数、英文とコードは単語数から求めた時間を合算し、1 分未満は 1 分に切り上げる。
+  /// 読む文章がない場合は 0 を返す
+  pub fn reading_minutes(&self, contents: &[ContentEntity]) -> u32 {
+    let mut counter = ReadingCounter::default();
+    counter.count_contents(contents);
+
+    if counter.japanese_chars == 0 && counter.words == 0 {
+      return 0;
+    }
+    let minutes = counter.japanese_chars as f64 / JAPANESE_CHARS_PER_MINUTE + counter.words as f64 / WORDS_PER_MINUTE;
+    (minutes.ceil() as u32).max(1)
+  }
+}
+
+/// 段落のテキストを折りたたみブロックの中まで順に集める（インライン数式は除く）
+fn collect_paragraph_texts(contents: &[ContentEntity], paragraphs: &mut Vec<String>) {
+  for content in contents {
+    match content {
+      ContentEntity::Paragraph(paragraph) => {
+        paragraphs.push(paragraph.get_value().get_text().iter().filter(|part| !part.is_inline_math()).map(|part| part.get_text()).collect());
+      }
+      ContentEntity::Details(details) => collect_paragraph_texts(details.get_children(), paragraphs),
+      _ => {}
+    }
+  }
+}
+
+/// 読了時間の算出に使う日本語の文字数と単語数
+#[derive(Default)]
+struct ReadingCounter {
+  japanese_chars: usize,
+  words: usize,
+}
+
+impl ReadingCounter {
+  fn count_contents(&mut self, contents: &[ContentEntity]) {
+    for content in contents {
+      match content {
+        ContentEntity::H2(h2) => self.count_text(h2.get_value()),
+        ContentEntity::H3(h3) => self.count_text(h3.get_value()),
+        ContentEntity::Paragraph(paragraph) => {
+          for part in paragraph.get_value().get_text() {
+            self.count_text(part.get_text());
+          }
+        }
+        // コードは日本語のコメントが含まれていても単語として数える
+        ContentEntity::CodeBlock(code_block) => self.words += code_block.get_code().split_whitespace().count(),
+        ContentEntity::Details(details) => {
+          self.count_text(details.get_summary());
+          self.count_contents(details.get_children());
+        }
+        ContentEntity::Image(_) | ContentEntity::Math(_) | ContentEntity::Embed(_) | ContentEntity::LinkCard(_) | ContentEntity::Diagram(_) => {}
+      }
+    }
+  }
+
+  /// 日本語の文字は 1 文字ずつ、それ以外は空白や日本語で区切られた単語として数える
+  fn count_text(&mut self, text: &str) {
+    let mut in_word = false;
+    for c in text.chars() {
+      if is_japanese(c) {
+        self.japanese_chars += 1;
+        in_word = false;
+      } else if c.is_alphanumeric() {
+        if !in_word {
+          self.words += 1;
+        }
+        in_word = true;
+      } else if c.is_whitespace() {
+        in_word = false;
+      }
+    }
+  }
+}
+
+/// かな・漢字・和文の記号など、日本語の文字かどうか
+pub fn is_japanese(c: char) -> bool {
+  matches!(
+    c,
+    '\u{3000}'..='\u{303F}' // 和文の句読点・括弧
+      | '\u{3040}'..='\u{309F}' // ひらがな
+      | '\u{30A0}'..='\u{30FF}' // カタカナ
+      | '\u{3400}'..='\u{4DBF}' // CJK 統合漢字拡張 A
+      | '\u{4E00}'..='\u{9FFF}' // CJK 統合漢字
+      | '\u{F900}'..='\u{FAFF}' // CJK 互換漢字
+      | '\u{FF01}'..='\u{FF60}' // 全角英数・記号
+      | '\u{FF61}'..='\u{FF9F}' // 半角カナ
+      | '\u{20000}'..='\u{2FA1F}' // CJK 統合漢字拡張 B 以降
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::blog_domain::blog_post_entity::{
+    code_block_entity::CodeBlockEntity,
+    paragraph_entity::ParagraphEntity,
+    rich_text_vo::{RichTextPartVO, RichTextVO},
+  };
+  use uuid::Uuid;
+
+  fn paragraph(text: &str) -> ContentEntity {
+    ContentEntity::Paragraph(ParagraphEntity::new(
+      Uuid::new_v4(),
+      RichTextVO::new(vec![RichTextPartVO::new(text.to_string(), None, None)]),
+    ))
+  }
+
+  #[test]
+  fn excerpt_joins_paragraphs_and_skips_other_contents() {
+    let service = PostDigestService::new();
+    let contents = vec![
+      ContentEntity::h2(Uuid::new_v4(), "見出し".to_string()),
+      paragraph("最初の段落"),
+      paragraph("次の  段落"),
+    ];
+
+    assert_eq!(service.excerpt_from_contents(&contents, EXCERPT_MAX_GRAPHEMES), "最初の段落 次の 段落");
+  }
+
+  #[test]
+  fn excerpt_includes_paragraphs_in_details() {
+    let service = PostDigestService::new();
+    let details = ContentEntity::details(Uuid::new_v4(), "補足".to_string(), vec![paragraph("折りたたみの中の段落")]).unwrap();
+    let contents = vec![paragraph("最初の段落"), details, paragraph("最後の段落")];
+
+    assert_eq!(
+      service.excerpt_from_contents(&contents, EXCERPT_MAX_GRAPHEMES),
+      "最初の段落 折りたたみの中の段落 最後の段落"
+    );
+  }
+
+  #[test]
+  fn excerpt_skips_inline_math() {
+    let service = PostDigestService::new();
+    let contents = vec![ContentEntity::Paragraph(ParagraphEntity::new(
+      Uuid::new_v4(),
+      RichTextVO::new(vec![
+        RichTextPartVO::new("円の面積は ".to_string(), None, None),
+        RichTextPartVO::new(r"\pi r^2".to_string(), None, None).with_inline_math().unwrap(),
+        RichTextPartVO::new(" で求める".to_string(), None, None),
+      ]),
+    ))];
+
+    assert_eq!(service.excerpt_from_contents(&contents, EXCERPT_MAX_GRAPHEMES), "円の面積は で求める");
+  }
+
+  #[test]
+  fn excerpt_does_not_split_combining_characters_or_emoji() {
+    let service = PostDigestService::new();
+    // 「か」+ 結合用濁点、ZWJ でつながった家族の絵文字、日本の国旗はそれぞれ 1 文字として数える
+    let contents = vec![paragraph("か\u{3099}👨\u{200D}👩\u{200D}👧🇯🇵あいう")];
+
+    assert_eq!(service.excerpt_from_contents(&contents, 3), "か\u{3099}👨\u{200D}👩\u{200D}👧🇯🇵…");
+  }
+
+  #[test]
+  fn excerpt_prefers_manual_excerpt() {
+    let service = PostDigestService::new();
+    let mut blog_post = BlogPostEntity::new(Uuid::new_v4(), "記事".to_string());
+    blog_post.add_content(paragraph("本文"));
+    assert_eq!(service.excerpt(&blog_post), "本文");
+
+    blog_post.set_manual_excerpt(Some("手動の抜粋".to_string())).unwrap();
+    assert_eq!(service.excerpt(&blog_post), "手動の抜粋");
+  }
+
+  #[test]
+  fn reading_minutes_combines_japanese_chars_and_words() {
+    let service = PostDigestService::new();
+    // 日本語 750 文字（1.5 分）と英単語 100 語（0.5 分）で 2 分
+    let contents = vec![paragraph(&"あ".repeat(750)), paragraph(&"word ".repeat(100))];
+
+    assert_eq!(service.reading_minutes(&contents), 2);
+  }
+
+  #[test]
+  fn reading_minutes_counts_code_as_words() {
+    let service = PostDigestService::new();
+    let code = "let x = 1;\n".repeat(100);
+    let contents = vec![ContentEntity::CodeBlock(
+      CodeBlockEntity::new(Uuid::new_v4(), "".to_string(), code, "rust".to_string()).unwrap(),
+    )];
+
+    // 1 行 4 語 × 100 行 = 400 語で 2 分
+    assert_eq!(service.reading_minutes(&contents), 2);
+  }
+
+  #[test]
+  fn reading_minutes_rounds_up_short_posts_and_returns_zero_for_empty_posts() {
+    let service = PostDigestService::new();
+
+    assert_eq!(service.reading_minutes(&[paragraph("短い記事")]), 1);
+    assert_eq!(service.reading_minutes(&[]), 0);
+  }
+}
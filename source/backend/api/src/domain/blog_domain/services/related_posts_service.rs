@@ -0,0 +1,223 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use super::post_digest_service::is_japanese;
+use crate::domain::blog_domain::blog_post_entity::content_entity::ContentEntity;
+use crate::domain::blog_domain::blog_post_entity::BlogPostEntity;
+use crate::domain::blog_domain::related_post_repository::PostTermVectorVO;
+
+/// タイトルに含まれる語の重み（本文の段落を 1 とする）
+const TITLE_WEIGHT: u32 = 3;
+/// 見出しに含まれる語の重み
+const HEADING_WEIGHT: u32 = 2;
+
+/// 関連記事サービス
+///
+/// タイトル・見出し・段落の語の TF-IDF ベクトルのコサイン類似度で、記事どうしの近さを求めるドメインサービス。
+/// 日本語は分かち書きせずに 2 文字ずつ（bigram）区切り、英数字は単語ごとに区切る。
+/// IDF は記事が増えるたびに変わるため、保存しておくのは語の出現回数だけにして、IDF は類似度を求めるときに計算する
+pub struct RelatedPostsService;
+
+impl RelatedPostsService {
+  /// 新しいサービスインスタンスを作成する
+  pub fn new() -> Self {
+    Self
+  }
+
+  /// 記事のタイトル・見出し・段落から語の出現回数（重み付き）を求める
+  ///
+  /// 折りたたみブロックの中の見出しと段落も含める。コードや数式は語として扱わない
+  pub fn term_vector(&self, blog_post: &BlogPostEntity) -> PostTermVectorVO {
+    let mut terms = HashMap::new();
+    add_terms(&mut terms, blog_post.get_title_text(), TITLE_WEIGHT);
+
+    for content in blog_post.flattened_contents() {
+      match content {
+        ContentEntity::H2(h2) => add_terms(&mut terms, h2.get_value(), HEADING_WEIGHT),
+        ContentEntity::H3(h3) => add_terms(&mut terms, h3.get_value(), HEADING_WEIGHT),
+        ContentEntity::Paragraph(paragraph) => {
+          let text: String = paragraph.get_value().get_text().iter().map(|part| part.get_text()).collect();
+          add_terms(&mut terms, &text, 1);
+        }
+        _ => {}
+      }
+    }
+
+    PostTermVectorVO { id: blog_post.get_id(), terms }
+  }
+
+  /// `target` に近い順に記事を並べ、類似度とあわせて最大 `limit` 件返す
+  ///
+  /// `target` 自身と、共通する語がない（類似度が 0 の）記事は含めない。類似度が同じ場合は記事 ID の順に並べる
+  pub fn rank(&self, target: &PostTermVectorVO, candidates: &[PostTermVectorVO], limit: usize) -> Vec<(Uuid, f64)> {
+    let candidates: Vec<&PostTermVectorVO> = candidates.iter().filter(|candidate| candidate.id != target.id).collect();
+
+    // 文書頻度は候補の記事と `target` をあわせた全体で数える
+    let mut document_frequencies: HashMap<&str, u32> = HashMap::new();
+    for terms in candidates.iter().map(|candidate| &candidate.terms).chain(std::iter::once(&target.terms)) {
+      for term in terms.keys() {
+        *document_frequencies.entry(term.as_str()).or_default() += 1;
+      }
+    }
+    let document_count = candidates.len() + 1;
+    let idf = |term: &str| {
+      let document_frequency = document_frequencies.get(term).copied().unwrap_or_default();
+      ((1 + document_count) as f64 / (1 + document_frequency) as f64).ln() + 1.0
+    };
+
+    let target_weights = tf_idf(&target.terms, &idf);
+    let target_norm = norm(&target_weights);
+    if target_norm == 0.0 {
+      return Vec::new();
+    }
+
+    let mut scores: Vec<(Uuid, f64)> = candidates
+      .into_iter()
+      .filter_map(|candidate| {
+        let weights = tf_idf(&candidate.terms, &idf);
+        let dot: f64 = target_weights.iter().filter_map(|(term, weight)| weights.get(term).map(|other| weight * other)).sum();
+        let candidate_norm = norm(&weights);
+        (dot > 0.0 && candidate_norm > 0.0).then(|| (candidate.id, dot / (target_norm * candidate_norm)))
+      })
+      .collect();
+
+    scores.sort_by(|(a_id, a_score), (b_id, b_score)| b_score.total_cmp(a_score).then(a_id.cmp(b_id)));
+    scores.truncate(limit);
+    scores
+  }
+}
+
+/// 出現回数は対数で抑え、長い記事ほど類似度が高くなりすぎないようにする
+fn tf_idf<'a>(terms: &'a HashMap<String, u32>, idf: &impl Fn(&str) -> f64) -> HashMap<&'a str, f64> {
+  terms.iter().map(|(term, count)| (term.as_str(), (1.0 + (*count as f64).ln()) * idf(term))).collect()
+}
+
+fn norm(weights: &HashMap<&str, f64>) -> f64 {
+  weights.values().map(|weight| weight * weight).sum::<f64>().sqrt()
+}
+
+fn add_terms(terms: &mut HashMap<String, u32>, text: &str, weight: u32) {
+  for token in tokenize(text) {
+    *terms.entry(token).or_default() += weight;
+  }
+}
+
+/// テキストを語に区切る
+///
+/// 日本語の連続した文字は 2 文字ずつずらして区切り（1 文字だけの場合はそのまま）、
+/// 英数字の連続は小文字にした単語にする。1 文字の英数字と記号・空白は語にしない
+fn tokenize(text: &str) -> Vec<String> {
+  let mut tokens = Vec::new();
+  let mut japanese_run: Vec<char> = Vec::new();
+  let mut word = String::new();
+
+  let flush_japanese = |run: &mut Vec<char>, tokens: &mut Vec<String>| {
+    match run.len() {
+      0 => {}
+      1 => tokens.push(run[0].to_string()),
+      _ => tokens.extend(run.windows(2).map(|pair| pair.iter().collect::<String>())),
+    }
+    run.clear();
+  };
+  let flush_word = |word: &mut String, tokens: &mut Vec<String>| {
+    if word.chars().count() > 1 {
+      tokens.push(word.clone());
+    }
+    word.clear();
+  };
+
+  for c in text.chars() {
+    if c.is_alphanumeric() && is_japanese(c) {
+      flush_word(&mut word, &mut tokens);
+      japanese_run.push(c);
+    } else if c.is_alphanumeric() {
+      flush_japanese(&mut japanese_run, &mut tokens);
+      word.extend(c.to_lowercase());
+    } else {
+      flush_japanese(&mut japanese_run, &mut tokens);
+      flush_word(&mut word, &mut tokens);
+    }
+  }
+  flush_japanese(&mut japanese_run, &mut tokens);
+  flush_word(&mut word, &mut tokens);
+  tokens
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::blog_domain::blog_post_entity::{
+    paragraph_entity::ParagraphEntity,
+    rich_text_vo::{RichTextPartVO, RichTextVO},
+  };
+
+  fn blog_post(id: u128, title: &str, paragraph: &str) -> BlogPostEntity {
+    let mut blog_post = BlogPostEntity::new(Uuid::from_u128(id), title.to_string());
+    blog_post.add_content(ContentEntity::h2(Uuid::new_v4(), "はじめに".to_string()));
+    blog_post.add_content(ContentEntity::Paragraph(ParagraphEntity::new(
+      Uuid::new_v4(),
+      RichTextVO::new(vec![RichTextPartVO::new(paragraph.to_string(), None, None)]),
+    )));
+    blog_post
+  }
+
+  #[test]
+  fn tokenizes_japanese_into_bigrams_and_latin_into_words() {
+    assert_eq!(tokenize("Rustの所有権、Go言語。a"), vec!["rust", "の所", "所有", "有権", "go", "言語"]);
+    assert_eq!(tokenize("型 API"), vec!["型", "api"]);
+  }
+
+  #[test]
+  fn term_vector_weights_title_and_headings() {
+    let term_vector = RelatedPostsService::new().term_vector(&blog_post(1, "所有権", "所有権"));
+
+    // タイトル 3 + 段落 1
+    assert_eq!(term_vector.terms.get("所有"), Some(&4));
+    // 見出し 2
+    assert_eq!(term_vector.terms.get("はじ"), Some(&2));
+  }
+
+  #[test]
+  fn ranks_similar_posts_first_and_excludes_target() {
+    let service = RelatedPostsService::new();
+    let target = service.term_vector(&blog_post(1, "Rust の所有権入門", "所有権と借用チェッカーを解説します"));
+    let candidates = vec![
+      target.clone(),
+      service.term_vector(&blog_post(2, "Rust のライフタイム", "借用チェッカーとライフタイム注釈")),
+      service.term_vector(&blog_post(3, "Rust の所有権と借用", "所有権と借用チェッカーを詳しく解説します")),
+    ];
+
+    let ranked = service.rank(&target, &candidates, 10);
+
+    let ids: Vec<Uuid> = ranked.iter().map(|(id, _)| *id).collect();
+    assert_eq!(ids, vec![Uuid::from_u128(3), Uuid::from_u128(2)]);
+    assert!(ranked[0].1 > ranked[1].1);
+  }
+
+  #[test]
+  fn rank_skips_posts_without_common_terms_and_respects_limit() {
+    let service = RelatedPostsService::new();
+    let target = PostTermVectorVO {
+      id: Uuid::from_u128(1),
+      terms: HashMap::from([("rust".to_string(), 1)]),
+    };
+    let candidates = vec![
+      PostTermVectorVO {
+        id: Uuid::from_u128(2),
+        terms: HashMap::from([("go".to_string(), 1)]),
+      },
+      PostTermVectorVO {
+        id: Uuid::from_u128(3),
+        terms: HashMap::from([("rust".to_string(), 1)]),
+      },
+      PostTermVectorVO {
+        id: Uuid::from_u128(4),
+        terms: HashMap::from([("rust".to_string(), 2)]),
+      },
+    ];
+
+    assert_eq!(service.rank(&target, &candidates, 10).len(), 2);
+    assert_eq!(service.rank(&target, &candidates, 1).len(), 1);
+  }
+}
@@ -0,0 +1,118 @@
+use super::html_renderer_service::escape_html;
+use crate::domain::blog_domain::sitemap_repository::SitemapPostEntryVO;
+
+/// 1 つのサイトマップに載せられる URL の上限（sitemaps.org のプロトコルによる）
+pub const MAX_URLS_PER_SITEMAP: u64 = 50_000;
+
+const SITEMAP_NAMESPACE: &str = "http://www.sitemaps.org/schemas/sitemap/0.9";
+
+/// サイトマップ（sitemaps.org のプロトコル）を出力するドメインサービス
+///
+/// 記事数が [`MAX_URLS_PER_SITEMAP`] を超える場合は、分割したサイトマップを列挙するサイトマップインデックスを出力する。
+pub struct SitemapRendererService {
+  site_url: String,
+}
+
+impl SitemapRendererService {
+  /// 新しいサービスインスタンスを作成する
+  ///
+  /// # Arguments
+  /// * `site_url` - 公開されているサイトの URL。記事の URL は `{site_url}/posts/{id}` になる
+  pub fn new(site_url: String) -> Self {
+    Self { site_url }
+  }
+
+  /// 記事数から分割後のサイトマップの数を求める（記事がなくても 1 つは出力する）
+  pub fn page_count(post_count: u64) -> u64 {
+    post_count.div_ceil(MAX_URLS_PER_SITEMAP).max(1)
+  }
+
+  /// 記事の URL を列挙するサイトマップを出力する
+  pub fn render_urlset(&self, entries: &[SitemapPostEntryVO]) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!("<urlset xmlns=\"{}\">\n", SITEMAP_NAMESPACE));
+    for entry in entries {
+      xml.push_str("<url>\n");
+      xml.push_str(&format!("<loc>{}</loc>\n", escape_html(&format!("{}/posts/{}", self.base_url(), entry.id))));
+      xml.push_str(&format!("<lastmod>{}</lastmod>\n", entry.last_update_date.to_naive_date().format("%Y-%m-%d")));
+      xml.push_str("</url>\n");
+    }
+    xml.push_str("</urlset>\n");
+    xml
+  }
+
+  /// 分割したサイトマップを列挙するサイトマップインデックスを出力する
+  pub fn render_index(&self, page_count: u64) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!("<sitemapindex xmlns=\"{}\">\n", SITEMAP_NAMESPACE));
+    for page in 1..=page_count {
+      xml.push_str(&format!("<sitemap>\n<loc>{}</loc>\n</sitemap>\n", escape_html(&self.page_url(page))));
+    }
+    xml.push_str("</sitemapindex>\n");
+    xml
+  }
+
+  /// 分割したサイトマップの URL（Nginx で `/api` 配下に公開される）
+  pub fn page_url(&self, page: u64) -> String {
+    format!("{}/api/blog/sitemap-{}.xml", self.base_url(), page)
+  }
+
+  fn base_url(&self) -> &str {
+    self.site_url.trim_end_matches('/')
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::blog_domain::jst_date_vo::JstDate;
+  use roxmltree::Document;
+  use uuid::Uuid;
+
+  fn service() -> SitemapRendererService {
+    SitemapRendererService::new("https://blog.example.com/".to_string())
+  }
+
+  #[test]
+  fn renders_urlset_with_lastmod() {
+    let id = Uuid::new_v4();
+    let entries = vec![SitemapPostEntryVO {
+      id,
+      last_update_date: JstDate::new(2024, 4, 5).unwrap(),
+    }];
+
+    let xml = service().render_urlset(&entries);
+
+    let document = Document::parse(&xml).unwrap();
+    let urlset = document.root_element();
+    assert_eq!(urlset.tag_name().name(), "urlset");
+    assert_eq!(urlset.tag_name().namespace(), Some(SITEMAP_NAMESPACE));
+    let url = urlset.children().find(|node| node.has_tag_name("url")).unwrap();
+    let text = |name: &str| url.children().find(|node| node.has_tag_name(name)).and_then(|node| node.text()).unwrap().to_string();
+    assert_eq!(text("loc"), format!("https://blog.example.com/posts/{}", id));
+    assert_eq!(text("lastmod"), "2024-04-05");
+  }
+
+  #[test]
+  fn renders_index_of_split_sitemaps() {
+    let xml = service().render_index(2);
+
+    let document = Document::parse(&xml).unwrap();
+    assert_eq!(document.root_element().tag_name().name(), "sitemapindex");
+    let locs: Vec<&str> = document.descendants().filter(|node| node.has_tag_name("loc")).filter_map(|node| node.text()).collect();
+    assert_eq!(
+      locs,
+      vec![
+        "https://blog.example.com/api/blog/sitemap-1.xml",
+        "https://blog.example.com/api/blog/sitemap-2.xml"
+      ]
+    );
+  }
+
+  #[test]
+  fn page_count_splits_by_max_urls() {
+    assert_eq!(SitemapRendererService::page_count(0), 1);
+    assert_eq!(SitemapRendererService::page_count(MAX_URLS_PER_SITEMAP), 1);
+    assert_eq!(SitemapRendererService::page_count(MAX_URLS_PER_SITEMAP + 1), 2);
+  }
+}
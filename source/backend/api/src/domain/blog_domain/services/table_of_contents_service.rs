@@ -0,0 +1,137 @@
+use uuid::Uuid;
+
+use crate::domain::blog_domain::blog_post_entity::{content_entity::ContentEntity, heading_anchor_vo::HeadingAnchorVO};
+
+/// 目次の項目
+///
+/// H2 の項目は続く H3 を `children` に持つ。最初の H2 より前にある H3 はトップレベルの項目になる
+#[derive(Debug, Clone, PartialEq)]
+pub struct TocItemVO {
+  pub id: Uuid,
+  pub text: String,
+  pub anchor: String,
+  pub level: u8,
+  pub children: Vec<TocItemVO>,
+}
+
+/// 目次生成サービス
+///
+/// 本文の見出し（H2 / H3）から目次の木構造を作るドメインサービス
+///
+/// アンカーは HTML の見出しの id 属性や内部リンクと同じく [`HeadingAnchorVO`] で作る
+pub struct TableOfContentsService;
+
+impl TableOfContentsService {
+  /// 新しいサービスインスタンスを作成する
+  pub fn new() -> Self {
+    Self
+  }
+
+  /// 本文の見出しから目次を作る
+  ///
+  /// 折りたたみブロックの中の見出しは畳まれていて目次から移動できないため含めない
+  pub fn build(&self, contents: &[ContentEntity]) -> Vec<TocItemVO> {
+    let mut items: Vec<TocItemVO> = Vec::new();
+
+    for content in contents {
+      match content {
+        ContentEntity::H2(h2) => items.push(toc_item(h2.get_id(), h2.get_value(), 2)),
+        ContentEntity::H3(h3) => {
+          let item = toc_item(h3.get_id(), h3.get_value(), 3);
+          match items.last_mut() {
+            Some(parent) if parent.level == 2 => parent.children.push(item),
+            _ => items.push(item),
+          }
+        }
+        _ => {}
+      }
+    }
+    items
+  }
+}
+
+fn toc_item(id: Uuid, text: &str, level: u8) -> TocItemVO {
+  TocItemVO {
+    id,
+    text: text.to_string(),
+    anchor: HeadingAnchorVO::new(id, text).to_string(),
+    level,
+    children: Vec::new(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::blog_domain::blog_post_entity::{
+    paragraph_entity::ParagraphEntity,
+    rich_text_vo::{InternalLinkTargetVO, RichTextPartVO, RichTextVO},
+  };
+
+  fn id(value: u128) -> Uuid {
+    Uuid::from_u128(value)
+  }
+
+  fn paragraph() -> ContentEntity {
+    ContentEntity::Paragraph(ParagraphEntity::new(
+      Uuid::new_v4(),
+      RichTextVO::new(vec![RichTextPartVO::new("本文".to_string(), None, None)]),
+    ))
+  }
+
+  #[test]
+  fn nests_h3_under_preceding_h2() {
+    let contents = vec![
+      ContentEntity::h3(id(1), "はじめに".to_string()),
+      ContentEntity::h2(id(2), "Rust の所有権".to_string()),
+      paragraph(),
+      ContentEntity::h3(id(3), "Borrow Checker".to_string()),
+      ContentEntity::h3(id(4), "ライフタイム".to_string()),
+      ContentEntity::h2(id(5), "まとめ".to_string()),
+    ];
+
+    let toc = TableOfContentsService::new().build(&contents);
+
+    assert_eq!(
+      toc.iter().map(|item| (item.level, item.text.as_str())).collect::<Vec<_>>(),
+      vec![(3, "はじめに"), (2, "Rust の所有権"), (2, "まとめ")]
+    );
+    assert_eq!(
+      toc[1].children.iter().map(|item| item.text.as_str()).collect::<Vec<_>>(),
+      vec!["Borrow Checker", "ライフタイム"]
+    );
+    assert!(toc[0].children.is_empty());
+    assert!(toc[2].children.is_empty());
+  }
+
+  #[test]
+  fn anchors_match_heading_ids_used_in_html_and_internal_links() {
+    let heading_id = Uuid::parse_str("1a2b3c4d-0000-0000-0000-000000000000").unwrap();
+    let contents = vec![ContentEntity::h2(heading_id, "Rust の 所有権!?".to_string())];
+
+    let toc = TableOfContentsService::new().build(&contents);
+
+    assert_eq!(toc[0].anchor, "rust-の-所有権-1a2b3c4d");
+    let link = InternalLinkTargetVO::new(Uuid::from_u128(9), Some(heading_id)).with_heading_text(Some("Rust の 所有権!?".to_string()));
+    assert!(link.to_path().ends_with(&format!("#{}", toc[0].anchor)));
+  }
+
+  #[test]
+  fn anchors_are_stable_when_other_headings_change() {
+    let (first_id, second_id) = (Uuid::new_v4(), Uuid::new_v4());
+    let before = vec![
+      ContentEntity::h2(first_id, "概要".to_string()),
+      ContentEntity::h2(second_id, "概要".to_string()),
+    ];
+    let after = vec![
+      ContentEntity::h2(Uuid::new_v4(), "新しく追加した見出し".to_string()),
+      ContentEntity::h2(second_id, "概要".to_string()),
+    ];
+
+    let before = TableOfContentsService::new().build(&before);
+    let after = TableOfContentsService::new().build(&after);
+
+    assert_eq!(before[1].anchor, after[1].anchor);
+    assert_ne!(before[0].anchor, before[1].anchor);
+  }
+}
@@ -0,0 +1,29 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::blog_domain::jst_date_vo::JstDate;
+
+/// サイトマップに載せる記事 1 件分の情報
+#[derive(Debug, Clone, PartialEq)]
+pub struct SitemapPostEntryVO {
+  pub id: Uuid,
+  pub last_update_date: JstDate,
+}
+
+/// サイトマップを作るためのリポジトリのトレイト
+///
+/// 記事数が多くても軽く済むよう、本文などは読まずに記事 ID と最終更新日だけを扱う
+/// SEO メタデータで noindex に設定された記事はサイトマップに載せないため、件数にも含めない
+#[async_trait::async_trait]
+pub trait SitemapRepository: Send + Sync {
+  /// 公開済み（公開日が `today` 以前）の記事数を取得する
+  async fn count_published_posts(&self, today: &JstDate) -> Result<u64>;
+
+  /// 公開済みの記事を公開日の古い順に取得する
+  ///
+  /// # Arguments
+  /// * `today` - この日以前に公開された記事を公開済みとする
+  /// * `offset` - 読み飛ばす記事数
+  /// * `limit` - 取得する最大の記事数
+  async fn find_published_post_entries(&self, today: &JstDate, offset: u64, limit: u64) -> Result<Vec<SitemapPostEntryVO>>;
+}
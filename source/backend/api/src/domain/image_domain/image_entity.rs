@@ -4,11 +4,25 @@ use uuid::Uuid;
 pub struct ImageEntity {
   id: Uuid,
   path: String,
+  file_name: Option<String>,
+  caption: Option<String>,
 }
 
 impl ImageEntity {
   pub fn new(id: Uuid, path: String) -> Self {
-    Self { id, path }
+    Self {
+      id,
+      path,
+      file_name: None,
+      caption: None,
+    }
+  }
+
+  /// アップロード時のファイル名と画像自体に付けられたキャプションを設定する
+  pub fn with_details(mut self, file_name: Option<String>, caption: Option<String>) -> Self {
+    self.file_name = file_name;
+    self.caption = caption;
+    self
   }
 
   pub fn get_id(&self) -> Uuid {
@@ -18,6 +32,14 @@ impl ImageEntity {
   pub fn get_path(&self) -> &str {
     &self.path
   }
+
+  pub fn get_file_name(&self) -> Option<&str> {
+    self.file_name.as_deref()
+  }
+
+  pub fn get_caption(&self) -> Option<&str> {
+    self.caption.as_deref()
+  }
 }
 
 #[cfg(test)]
@@ -35,6 +57,14 @@ mod tests {
     assert_eq!(image.get_path(), "images/test.jpg");
   }
 
+  #[test]
+  fn can_create_image_with_details() {
+    let image = ImageEntity::new(Uuid::new_v4(), "images/test.jpg".to_string()).with_details(Some("test.jpg".to_string()), Some("テスト画像".to_string()));
+
+    assert_eq!(image.get_file_name(), Some("test.jpg"));
+    assert_eq!(image.get_caption(), Some("テスト画像"));
+  }
+
   #[test]
   fn images_with_same_id_are_equal() {
     let id = Uuid::new_v4();
@@ -4,5 +4,7 @@
 //! この層は永続化、外部API、その他のI/O処理を担当する。
 
 pub mod di_container;
+pub mod external_services;
 pub mod repositories;
+pub mod scheduler;
 pub mod server;
@@ -1,19 +1,73 @@
-use anyhow::Result;
-use std::sync::Arc;
+use anyhow::{Context, Result};
+use std::{env, net::IpAddr, sync::Arc, time::Duration};
 
 use crate::{
+  application::services::code_highlight_service::CodeHighlightService,
   application::usecase::{
-    create_blog_post::CreateBlogPostUseCase, register_image::RegisterImageUseCase, select_pick_up_posts::SelectPickUpPostsUseCase,
-    select_popular_posts::SelectPopularPostsUseCase, select_top_tech_pick_post::SelectTopTechPickPostUseCase, update_blog_post::UpdateBlogPostUseCase,
-    view_admin_blog_post::ViewAdminBlogPostUseCase, view_all_blog_posts::ViewAllBlogPostsUseCase, view_blog_post::ViewBlogPostUseCase,
-    view_images::ViewImagesUseCase, view_latest_blog_posts::ViewLatestBlogPostsUseCase, view_pick_up_posts::ViewPickUpPostsUseCase,
-    view_popular_blog_posts::ViewPopularBlogPostsUseCase, view_top_tech_pick::ViewTopTechPickUseCase,
+    create_blog_post::CreateBlogPostUseCase,
+    export_blog_archive::ExportBlogArchiveUseCase,
+    export_blog_post_markdown::ExportBlogPostMarkdownUseCase,
+    find_dangling_internal_links::FindDanglingInternalLinksUseCase,
+    import_blog_archive::ImportBlogArchiveUseCase,
+    import_markdown_post::{markdown_post_parser::MarkdownPostParser, ImportMarkdownPostUseCase},
+    import_wordpress_posts::{html_content_converter::HtmlContentConverter, ImportWordPressPostsUseCase},
+    rebuild_related_post_index::RebuildRelatedPostIndexUseCase,
+    recompute_popular_posts::RecomputePopularPostsUseCase,
+    record_page_view::RecordPageViewUseCase,
+    refresh_link_card::RefreshLinkCardUseCase,
+    register_image::RegisterImageUseCase,
+    render_blog_post_html::RenderBlogPostHtmlUseCase,
+    select_pick_up_posts::SelectPickUpPostsUseCase,
+    select_popular_posts::SelectPopularPostsUseCase,
+    select_top_tech_pick_post::SelectTopTechPickPostUseCase,
+    update_blog_post::UpdateBlogPostUseCase,
+    update_popular_post_mode::UpdatePopularPostModeUseCase,
+    view_admin_blog_post::ViewAdminBlogPostUseCase,
+    view_all_blog_posts::ViewAllBlogPostsUseCase,
+    view_blog_feed::ViewBlogFeedUseCase,
+    view_blog_json_feed::ViewBlogJsonFeedUseCase,
+    view_blog_post::ViewBlogPostUseCase,
+    view_blog_post_structured_data::ViewBlogPostStructuredDataUseCase,
+    view_blog_post_toc::ViewBlogPostTocUseCase,
+    view_images::ViewImagesUseCase,
+    view_latest_blog_posts::ViewLatestBlogPostsUseCase,
+    view_pick_up_posts::ViewPickUpPostsUseCase,
+    view_popular_blog_posts::ViewPopularBlogPostsUseCase,
+    view_popular_post_mode::ViewPopularPostModeUseCase,
+    view_related_posts::ViewRelatedPostsUseCase,
+    view_sitemap::ViewSitemapUseCase,
+    view_top_tech_pick::ViewTopTechPickUseCase,
   },
   domain::{
-    blog_domain::{blog_post_factory::BlogPostFactory, blog_post_repository::BlogPostRepository, image_content_factory::ImageContentFactory},
+    blog_domain::{
+      blog_archive_repository::BlogArchiveRepository,
+      blog_post_factory::BlogPostFactory,
+      blog_post_repository::BlogPostRepository,
+      code_highlighter::CodeHighlighter,
+      image_content_factory::ImageContentFactory,
+      link_card_content_factory::LinkCardContentFactory,
+      ogp_fetcher::OgpFetcher,
+      page_view_repository::PageViewRepository,
+      popular_post_setting_repository::PopularPostSettingRepository,
+      related_post_repository::RelatedPostRepository,
+      services::{
+        feed_renderer_service::{FeedChannelVO, FeedRendererService},
+        html_renderer_service::HtmlRendererService,
+        markdown_serializer_service::MarkdownSerializerService,
+        page_view_service::PageViewService,
+        sitemap_renderer_service::SitemapRendererService,
+      },
+      sitemap_repository::SitemapRepository,
+    },
     image_domain::image_repository::ImageRepository,
   },
-  infrastructure::repositories::{blog_post_sqlx_repository::BlogPostSqlxRepository, db_pool::create_db_pool, image_sqlx_repository::ImageSqlxRepository},
+  infrastructure::{
+    external_services::{
+      http_ogp_fetcher::{HttpOgpFetcher, HttpOgpFetcherConfig},
+      syntect_code_highlighter::SyntectCodeHighlighter,
+    },
+    repositories::{blog_post_sqlx_repository::BlogPostSqlxRepository, db_pool::create_db_pool, image_sqlx_repository::ImageSqlxRepository},
+  },
 };
 
 /// DIコンテナ
@@ -23,6 +77,34 @@ pub struct DiContainer {
   blog_post_repository: Arc<dyn BlogPostRepository>,
   /// 画像リポジトリ
   image_repository: Arc<dyn ImageRepository>,
+  /// アーカイブの書き出し・復元用リポジトリ
+  blog_archive_repository: Arc<dyn BlogArchiveRepository>,
+  /// サイトマップ用リポジトリ
+  sitemap_repository: Arc<dyn SitemapRepository>,
+  /// 関連記事用リポジトリ
+  related_post_repository: Arc<dyn RelatedPostRepository>,
+  /// 閲覧数用リポジトリ
+  page_view_repository: Arc<dyn PageViewRepository>,
+  /// 人気記事の設定用リポジトリ
+  popular_post_setting_repository: Arc<dyn PopularPostSettingRepository>,
+  /// OGP メタデータ取得サービス
+  ogp_fetcher: Arc<dyn OgpFetcher>,
+  /// コードのハイライタ（ハイライト結果のキャッシュを共有するため 1 つのインスタンスを使い回す）
+  code_highlighter: Arc<dyn CodeHighlighter>,
+  /// HTML として出力する際に画像のパスの前に付ける配信元の URL
+  image_base_url: String,
+  /// 公開されているサイト（フロントエンド）の URL
+  site_url: String,
+  /// RSS・Atom フィードのタイトルやサイトの URL
+  feed_channel: FeedChannelVO,
+  /// 閲覧者のハッシュを作るときに混ぜる秘密の値
+  page_view_hash_salt: String,
+  /// X-Forwarded-For を信頼するプロキシの IP アドレス
+  trusted_proxies: Vec<IpAddr>,
+  /// 人気記事を選ぶときに閲覧数を数える日数
+  popular_posts_window_days: u64,
+  /// 閲覧数から人気記事を選び直す間隔
+  popular_posts_recompute_interval: Duration,
 }
 
 impl DiContainer {
@@ -38,9 +120,80 @@ impl DiContainer {
     let image_sqlx_repository = ImageSqlxRepository::new((*db_pool).clone());
     let blog_post_repository: Arc<dyn BlogPostRepository> = Arc::new(BlogPostSqlxRepository::new((*db_pool).clone(), image_sqlx_repository));
 
+    // アーカイブの書き出し・復元用リポジトリを作成（記事の保存処理を共有するため BlogPostSqlxRepository が実装する）
+    let blog_archive_repository: Arc<dyn BlogArchiveRepository> =
+      Arc::new(BlogPostSqlxRepository::new((*db_pool).clone(), ImageSqlxRepository::new((*db_pool).clone())));
+
+    // サイトマップ用リポジトリを作成（記事の本文は読まない軽いクエリを使う）
+    let sitemap_repository: Arc<dyn SitemapRepository> =
+      Arc::new(BlogPostSqlxRepository::new((*db_pool).clone(), ImageSqlxRepository::new((*db_pool).clone())));
+
+    // 関連記事用リポジトリを作成（記事の本文は読まず、保存済みの語の出現回数だけを読む）
+    let related_post_repository: Arc<dyn RelatedPostRepository> =
+      Arc::new(BlogPostSqlxRepository::new((*db_pool).clone(), ImageSqlxRepository::new((*db_pool).clone())));
+
+    // 閲覧数用・人気記事の設定用リポジトリを作成
+    let page_view_repository: Arc<dyn PageViewRepository> =
+      Arc::new(BlogPostSqlxRepository::new((*db_pool).clone(), ImageSqlxRepository::new((*db_pool).clone())));
+    let popular_post_setting_repository: Arc<dyn PopularPostSettingRepository> =
+      Arc::new(BlogPostSqlxRepository::new((*db_pool).clone(), ImageSqlxRepository::new((*db_pool).clone())));
+
+    // OGP メタデータ取得サービスを作成（タイムアウトなどは環境変数で設定）
+    let ogp_fetcher: Arc<dyn OgpFetcher> = Arc::new(HttpOgpFetcher::new(HttpOgpFetcherConfig::from_env())?);
+
+    // コードのハイライタを作成（文法定義の読み込みは起動時に一度だけ行う）
+    let code_highlighter: Arc<dyn CodeHighlighter> = Arc::new(SyntectCodeHighlighter::default());
+
+    // 画像の配信元（未設定の場合は画像のパスをそのまま出力する）
+    let image_base_url = env::var("IMAGE_BASE_URL").unwrap_or_default();
+
+    // 公開されているサイトの URL（フィードやサイトマップの記事の URL はここから組み立てる）
+    let site_url = env::var("SITE_URL").unwrap_or_else(|_| "http://localhost:3000".to_string());
+
+    // フィードに載せるサイトの情報
+    let feed_channel = FeedChannelVO {
+      title: env::var("FEED_TITLE").unwrap_or_else(|_| "鉄火ブログ".to_string()),
+      description: env::var("FEED_DESCRIPTION").unwrap_or_default(),
+      site_url: site_url.clone(),
+    };
+
+    // 閲覧者のハッシュに混ぜる値（再起動をまたいでも同じ日の同じ閲覧者を 1 回と数えるため、固定の値を必ず設定する）
+    let page_view_hash_salt = env::var("PAGE_VIEW_HASH_SALT")
+      .ok()
+      .filter(|salt| !salt.is_empty())
+      .ok_or_else(|| anyhow::anyhow!("PAGE_VIEW_HASH_SALT environment variable must be set"))?;
+
+    // X-Forwarded-For を信頼するプロキシ（カンマ区切りの IP アドレス。未設定の場合は接続元をそのまま閲覧者とする）
+    let trusted_proxies = env::var("TRUSTED_PROXIES")
+      .unwrap_or_default()
+      .split(',')
+      .map(str::trim)
+      .filter(|value| !value.is_empty())
+      .map(|value| value.parse::<IpAddr>().with_context(|| format!("TRUSTED_PROXIES に IP アドレスでない値があります: {}", value)))
+      .collect::<Result<Vec<_>>>()?;
+
+    // 人気記事を閲覧数から選ぶときの集計期間（日）と選び直す間隔（秒）
+    let popular_posts_window_days = env::var("POPULAR_POSTS_WINDOW_DAYS").ok().and_then(|value| value.parse::<u64>().ok()).unwrap_or(30);
+    let popular_posts_recompute_interval =
+      Duration::from_secs(env::var("POPULAR_POSTS_RECOMPUTE_INTERVAL_SECONDS").ok().and_then(|value| value.parse::<u64>().ok()).unwrap_or(3600).max(60));
+
     Ok(Self {
       blog_post_repository,
       image_repository,
+      blog_archive_repository,
+      sitemap_repository,
+      related_post_repository,
+      page_view_repository,
+      popular_post_setting_repository,
+      ogp_fetcher,
+      code_highlighter,
+      image_base_url,
+      site_url,
+      feed_channel,
+      page_view_hash_salt,
+      trusted_proxies,
+      popular_posts_window_days,
+      popular_posts_recompute_interval,
     })
   }
 
@@ -56,9 +209,29 @@ impl DiContainer {
 
   /// CreateBlogPostUseCaseを作成する
   pub fn create_blog_post_usecase(&self) -> CreateBlogPostUseCase {
+    CreateBlogPostUseCase::new(self.blog_post_repository.clone(), self.blog_post_factory())
+  }
+
+  /// ImportMarkdownPostUseCaseを作成する
+  pub fn import_markdown_post_usecase(&self) -> ImportMarkdownPostUseCase {
+    let markdown_parser = MarkdownPostParser::new(self.image_repository.clone(), self.image_base_url.clone());
+    ImportMarkdownPostUseCase::new(self.blog_post_repository.clone(), self.blog_post_factory(), markdown_parser)
+  }
+
+  /// ImportWordPressPostsUseCaseを作成する
+  pub fn import_wordpress_posts_usecase(&self) -> ImportWordPressPostsUseCase {
+    ImportWordPressPostsUseCase::new(
+      self.create_blog_post_usecase(),
+      self.register_image_usecase(),
+      self.image_repository.clone(),
+      HtmlContentConverter::new(self.image_base_url.clone()),
+    )
+  }
+
+  fn blog_post_factory(&self) -> Arc<BlogPostFactory> {
     let image_content_factory = Arc::new(ImageContentFactory::new(self.image_repository.clone()));
-    let blog_post_factory = Arc::new(BlogPostFactory::new(image_content_factory));
-    CreateBlogPostUseCase::new(self.blog_post_repository.clone(), blog_post_factory)
+    let link_card_content_factory = Arc::new(LinkCardContentFactory::new(self.ogp_fetcher.clone()));
+    Arc::new(BlogPostFactory::new(image_content_factory, link_card_content_factory))
   }
 
   /// RegisterImageUseCaseを作成する
@@ -113,6 +286,127 @@ impl DiContainer {
 
   /// UpdateBlogPostUseCaseを作成する
   pub fn update_blog_post_usecase(&self) -> UpdateBlogPostUseCase {
-    UpdateBlogPostUseCase::new(self.blog_post_repository.clone())
+    let link_card_content_factory = Arc::new(LinkCardContentFactory::new(self.ogp_fetcher.clone()));
+    UpdateBlogPostUseCase::new(self.blog_post_repository.clone(), link_card_content_factory)
+  }
+
+  /// RefreshLinkCardUseCaseを作成する
+  pub fn refresh_link_card_usecase(&self) -> RefreshLinkCardUseCase {
+    let link_card_content_factory = Arc::new(LinkCardContentFactory::new(self.ogp_fetcher.clone()));
+    RefreshLinkCardUseCase::new(self.blog_post_repository.clone(), link_card_content_factory)
+  }
+
+  /// FindDanglingInternalLinksUseCaseを作成する
+  pub fn find_dangling_internal_links_usecase(&self) -> FindDanglingInternalLinksUseCase {
+    FindDanglingInternalLinksUseCase::new(self.blog_post_repository.clone())
+  }
+
+  /// CodeHighlightServiceを作成する
+  pub fn code_highlight_service(&self) -> CodeHighlightService {
+    CodeHighlightService::new(self.code_highlighter.clone())
+  }
+
+  /// RenderBlogPostHtmlUseCaseを作成する
+  pub fn render_blog_post_html_usecase(&self) -> RenderBlogPostHtmlUseCase {
+    RenderBlogPostHtmlUseCase::new(self.blog_post_repository.clone(), HtmlRendererService::new(self.image_base_url.clone()))
+  }
+
+  /// ViewBlogFeedUseCaseを作成する
+  pub fn view_blog_feed_usecase(&self) -> ViewBlogFeedUseCase {
+    ViewBlogFeedUseCase::new(
+      self.blog_post_repository.clone(),
+      FeedRendererService::new(self.feed_channel.clone(), self.image_base_url.clone()),
+    )
+  }
+
+  /// ViewBlogJsonFeedUseCaseを作成する
+  pub fn view_blog_json_feed_usecase(&self) -> ViewBlogJsonFeedUseCase {
+    ViewBlogJsonFeedUseCase::new(
+      self.blog_post_repository.clone(),
+      FeedRendererService::new(self.feed_channel.clone(), self.image_base_url.clone()),
+      HtmlRendererService::new(self.image_base_url.clone()),
+    )
+  }
+
+  /// ViewBlogPostStructuredDataUseCaseを作成する
+  pub fn view_blog_post_structured_data_usecase(&self) -> ViewBlogPostStructuredDataUseCase {
+    ViewBlogPostStructuredDataUseCase::new(
+      self.blog_post_repository.clone(),
+      FeedRendererService::new(self.feed_channel.clone(), self.image_base_url.clone()),
+    )
+  }
+
+  /// ViewBlogPostTocUseCaseを作成する
+  pub fn view_blog_post_toc_usecase(&self) -> ViewBlogPostTocUseCase {
+    ViewBlogPostTocUseCase::new(self.blog_post_repository.clone())
+  }
+
+  /// ViewRelatedPostsUseCaseを作成する
+  pub fn view_related_posts_usecase(&self) -> ViewRelatedPostsUseCase {
+    ViewRelatedPostsUseCase::new(self.blog_post_repository.clone(), self.related_post_repository.clone())
+  }
+
+  /// RebuildRelatedPostIndexUseCaseを作成する
+  pub fn rebuild_related_post_index_usecase(&self) -> RebuildRelatedPostIndexUseCase {
+    RebuildRelatedPostIndexUseCase::new(self.blog_post_repository.clone(), self.related_post_repository.clone())
+  }
+
+  /// RecordPageViewUseCaseを作成する
+  pub fn record_page_view_usecase(&self) -> RecordPageViewUseCase {
+    RecordPageViewUseCase::new(self.page_view_repository.clone(), PageViewService::new(self.page_view_hash_salt.clone()))
+  }
+
+  /// RecomputePopularPostsUseCaseを作成する
+  pub fn recompute_popular_posts_usecase(&self) -> RecomputePopularPostsUseCase {
+    RecomputePopularPostsUseCase::new(
+      self.blog_post_repository.clone(),
+      self.page_view_repository.clone(),
+      self.popular_post_setting_repository.clone(),
+      self.popular_posts_window_days,
+    )
+  }
+
+  /// ViewPopularPostModeUseCaseを作成する
+  pub fn view_popular_post_mode_usecase(&self) -> ViewPopularPostModeUseCase {
+    ViewPopularPostModeUseCase::new(self.popular_post_setting_repository.clone())
+  }
+
+  /// UpdatePopularPostModeUseCaseを作成する
+  pub fn update_popular_post_mode_usecase(&self) -> UpdatePopularPostModeUseCase {
+    UpdatePopularPostModeUseCase::new(self.popular_post_setting_repository.clone())
+  }
+
+  /// 閲覧数から人気記事を選び直す間隔を取得する
+  pub fn popular_posts_recompute_interval(&self) -> Duration {
+    self.popular_posts_recompute_interval
+  }
+
+  /// X-Forwarded-For を信頼するプロキシの IP アドレスを取得する
+  pub fn trusted_proxies(&self) -> &[IpAddr] {
+    &self.trusted_proxies
+  }
+
+  /// ViewSitemapUseCaseを作成する
+  pub fn view_sitemap_usecase(&self) -> ViewSitemapUseCase {
+    ViewSitemapUseCase::new(self.sitemap_repository.clone(), SitemapRendererService::new(self.site_url.clone()))
+  }
+
+  /// ExportBlogPostMarkdownUseCaseを作成する
+  pub fn export_blog_post_markdown_usecase(&self) -> ExportBlogPostMarkdownUseCase {
+    ExportBlogPostMarkdownUseCase::new(self.blog_post_repository.clone(), MarkdownSerializerService::new(self.image_base_url.clone()))
+  }
+
+  /// ExportBlogArchiveUseCaseを作成する
+  pub fn export_blog_archive_usecase(&self) -> ExportBlogArchiveUseCase {
+    ExportBlogArchiveUseCase::new(
+      self.blog_post_repository.clone(),
+      self.image_repository.clone(),
+      self.blog_archive_repository.clone(),
+    )
+  }
+
+  /// ImportBlogArchiveUseCaseを作成する
+  pub fn import_blog_archive_usecase(&self) -> ImportBlogArchiveUseCase {
+    ImportBlogArchiveUseCase::new(self.blog_archive_repository.clone())
   }
 }
@@ -0,0 +1,6 @@
+//! 外部サービスアダプターモジュール
+//!
+//! ドメインモデルで定義された外部サービスのトレイトの具象実装を含む。
+
+pub mod http_ogp_fetcher;
+pub mod syntect_code_highlighter;
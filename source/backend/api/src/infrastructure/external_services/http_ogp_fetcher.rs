@@ -0,0 +1,494 @@
+use std::{
+  collections::HashMap,
+  env,
+  net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+  sync::Arc,
+  time::Duration,
+};
+
+use anyhow::{Context, Result};
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use scraper::{Html, Selector};
+use url::{Host, Url};
+
+use crate::domain::blog_domain::{blog_post_entity::link_card_block_entity::OgpMetadataVO, ogp_fetcher::OgpFetcher};
+
+const DEFAULT_TIMEOUT_MS: u64 = 5000;
+const DEFAULT_USER_AGENT: &str = "blog-api-ogp-fetcher/0.1";
+/// OGP 取得時に読み込む HTML の最大バイト数（meta タグは head 内にあるため先頭のみで十分）
+const DEFAULT_MAX_BODY_BYTES: usize = 512 * 1024;
+const MAX_REDIRECTS: usize = 5;
+
+const MAX_TITLE_LENGTH: usize = 200;
+const MAX_DESCRIPTION_LENGTH: usize = 500;
+const MAX_SITE_NAME_LENGTH: usize = 100;
+const MAX_IMAGE_URL_LENGTH: usize = 2048;
+
+/// OGP 取得用 HTTP クライアントの設定
+#[derive(Debug, Clone)]
+pub struct HttpOgpFetcherConfig {
+  /// 接続から本文の読み込みまでを含めたタイムアウト
+  pub timeout: Duration,
+  pub user_agent: String,
+  pub max_body_bytes: usize,
+  /// ループバックやプライベートアドレスへのアクセスを許可するか（ローカルのスタブサーバーを使うテスト向け）
+  pub allow_private_network: bool,
+}
+
+impl Default for HttpOgpFetcherConfig {
+  fn default() -> Self {
+    Self {
+      timeout: Duration::from_millis(DEFAULT_TIMEOUT_MS),
+      user_agent: DEFAULT_USER_AGENT.to_string(),
+      max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+      allow_private_network: false,
+    }
+  }
+}
+
+impl HttpOgpFetcherConfig {
+  /// 環境変数から設定を読み込む
+  ///
+  /// `OGP_FETCH_TIMEOUT_MS` と `OGP_FETCH_USER_AGENT` が未設定の場合はデフォルト値を使う。
+  pub fn from_env() -> Self {
+    let default = Self::default();
+    let timeout = env::var("OGP_FETCH_TIMEOUT_MS").ok().and_then(|value| value.parse::<u64>().ok()).map(Duration::from_millis).unwrap_or(default.timeout);
+    let user_agent = env::var("OGP_FETCH_USER_AGENT").unwrap_or(default.user_agent);
+    Self {
+      timeout,
+      user_agent,
+      ..default
+    }
+  }
+}
+
+/// HTTP でページを取得し、meta タグから OGP メタデータを読み取る OgpFetcher の実装
+///
+/// 記事に書かれた任意の URL へアクセスするため、既定では内部ネットワークへのアクセスを拒否する。
+/// 最初のリクエストとリダイレクト先のそれぞれで、IP アドレスで書かれたホストは [`reqwest::redirect::Policy`] で、
+/// ホスト名は名前解決の結果を [`PublicAddressResolver`] で検査する。
+pub struct HttpOgpFetcher {
+  client: reqwest::Client,
+  max_body_bytes: usize,
+  allow_private_network: bool,
+}
+
+impl HttpOgpFetcher {
+  /// 設定から HTTP クライアントを組み立てる
+  pub fn new(config: HttpOgpFetcherConfig) -> Result<Self> {
+    let allow_private_network = config.allow_private_network;
+    let mut builder = reqwest::Client::builder().timeout(config.timeout).user_agent(config.user_agent).redirect(redirect_policy(allow_private_network));
+    if !allow_private_network {
+      builder = builder.dns_resolver(Arc::new(PublicAddressResolver));
+    }
+    let client = builder.build().context("OGP 取得用の HTTP クライアントの作成に失敗しました")?;
+    Ok(Self {
+      allow_private_network,
+      ..Self::with_client(client, config.max_body_bytes)
+    })
+  }
+
+  /// 構築済みの HTTP クライアントを利用する
+  ///
+  /// アクセス先の検査はクライアントの設定に委ねるため、内部ネットワークへのアクセスを許可したものとして扱う。
+  pub fn with_client(client: reqwest::Client, max_body_bytes: usize) -> Self {
+    Self {
+      client,
+      max_body_bytes,
+      allow_private_network: true,
+    }
+  }
+}
+
+/// リダイレクトの回数を制限し、リダイレクト先のホストも検査する
+fn redirect_policy(allow_private_network: bool) -> reqwest::redirect::Policy {
+  reqwest::redirect::Policy::custom(move |attempt| {
+    if attempt.previous().len() >= MAX_REDIRECTS {
+      return attempt.error(format!("リダイレクトが {} 回を超えました", MAX_REDIRECTS));
+    }
+    match ensure_public_host(attempt.url(), allow_private_network) {
+      Ok(()) => attempt.follow(),
+      Err(e) => attempt.error(e.to_string()),
+    }
+  })
+}
+
+/// 名前解決の結果に内部ネットワークのアドレスが含まれる場合は接続させない DNS リゾルバ
+///
+/// 検査した結果のアドレスにそのまま接続するため、検査後に名前解決の結果が変わっても内部ネットワークには届かない。
+struct PublicAddressResolver;
+
+impl Resolve for PublicAddressResolver {
+  fn resolve(&self, name: Name) -> Resolving {
+    Box::pin(async move {
+      let host = name.as_str().to_string();
+      let addresses: Vec<SocketAddr> = tokio::net::lookup_host((host.as_str(), 0)).await?.collect();
+      if let Some(address) = addresses.iter().find(|address| !is_public_address(address.ip())) {
+        return Err(format!("内部ネットワークのアドレスにはアクセスできません: {} ({})", host, address.ip()).into());
+      }
+      let addresses: Addrs = Box::new(addresses.into_iter());
+      Ok(addresses)
+    })
+  }
+}
+
+/// URL のホストが IP アドレスの場合に、内部ネットワークのアドレスでないことを確かめる
+///
+/// ホスト名の場合は名前解決の時点で [`PublicAddressResolver`] が検査する。
+fn ensure_public_host(url: &Url, allow_private_network: bool) -> Result<()> {
+  if url.scheme() != "https" && url.scheme() != "http" {
+    anyhow::bail!("http(s) 以外の URL にはアクセスできません: {}", url);
+  }
+  if allow_private_network {
+    return Ok(());
+  }
+  let ip = match url.host() {
+    Some(Host::Ipv4(ip)) => IpAddr::V4(ip),
+    Some(Host::Ipv6(ip)) => IpAddr::V6(ip),
+    Some(Host::Domain(_)) => return Ok(()),
+    None => anyhow::bail!("ホスト名のない URL です: {}", url),
+  };
+  if !is_public_address(ip) {
+    anyhow::bail!("内部ネットワークのアドレスにはアクセスできません: {}", url);
+  }
+  Ok(())
+}
+
+/// ループバック・プライベート・リンクローカル・ユニークローカルなど、インターネット上で到達できないアドレスを除外する
+fn is_public_address(ip: IpAddr) -> bool {
+  match ip {
+    IpAddr::V4(ip) => is_public_ipv4(ip),
+    IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+      Some(mapped) => is_public_ipv4(mapped),
+      None => is_public_ipv6(ip),
+    },
+  }
+}
+
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+  let [first, second, ..] = ip.octets();
+  let is_shared = first == 100 && (64..128).contains(&second);
+  !(ip.is_loopback() || ip.is_private() || ip.is_link_local() || ip.is_unspecified() || ip.is_broadcast() || ip.is_multicast() || is_shared || first == 0)
+}
+
+fn is_public_ipv6(ip: Ipv6Addr) -> bool {
+  let first_segment = ip.segments()[0];
+  let is_unique_local = (first_segment & 0xfe00) == 0xfc00;
+  let is_link_local = (first_segment & 0xffc0) == 0xfe80;
+  !(ip.is_loopback() || ip.is_unspecified() || ip.is_multicast() || is_unique_local || is_link_local)
+}
+
+#[async_trait::async_trait]
+impl OgpFetcher for HttpOgpFetcher {
+  async fn fetch(&self, url: &str) -> Result<OgpMetadataVO> {
+    let parsed_url = Url::parse(url).with_context(|| format!("URL として解釈できません: {}", url))?;
+    ensure_public_host(&parsed_url, self.allow_private_network)?;
+
+    let mut response = self
+      .client
+      .get(url)
+      .header(reqwest::header::ACCEPT, "text/html,application/xhtml+xml")
+      .send()
+      .await
+      .with_context(|| format!("ページの取得に失敗しました: {}", url))?
+      .error_for_status()
+      .with_context(|| format!("ページの取得でエラーが返されました: {}", url))?;
+
+    if let Some(content_type) = response.headers().get(reqwest::header::CONTENT_TYPE).and_then(|value| value.to_str().ok()) {
+      if !content_type.contains("html") {
+        anyhow::bail!("HTML 以外のコンテンツは OGP を取得できません: {}", content_type);
+      }
+    }
+
+    // リダイレクト後の URL を相対パスの解決に使う
+    let page_url = response.url().clone();
+
+    let mut body: Vec<u8> = Vec::new();
+    while let Some(chunk) = response.chunk().await.with_context(|| format!("ページの読み込みに失敗しました: {}", url))? {
+      body.extend_from_slice(&chunk);
+      if body.len() >= self.max_body_bytes {
+        body.truncate(self.max_body_bytes);
+        break;
+      }
+    }
+
+    Ok(parse_ogp_metadata(&String::from_utf8_lossy(&body), &page_url))
+  }
+}
+
+/// OGP・Twitter カードの meta タグ
+const OGP_META_SELECTOR: &str = r#"meta[property^="og:"], meta[name^="og:"], meta[property^="twitter:"], meta[name^="twitter:"], meta[name="description"]"#;
+const TITLE_SELECTOR: &str = "title";
+
+/// HTML から OGP メタデータを読み取る
+///
+/// og:* が無い項目は twitter:* や title タグ、description メタタグで補う。
+pub fn parse_ogp_metadata(html: &str, page_url: &Url) -> OgpMetadataVO {
+  let document = Html::parse_document(html);
+  let meta = collect_meta_contents(&document);
+  let find = |keys: &[&str]| keys.iter().find_map(|key| meta.get(*key).cloned());
+
+  let title = find(&["og:title", "twitter:title"]).or_else(|| extract_title_tag(&document));
+  let description = find(&["og:description", "twitter:description", "description"]);
+  let site_name = find(&["og:site_name"]);
+  let image_url = find(&["og:image:secure_url", "og:image", "og:image:url", "twitter:image"]).and_then(|image| resolve_image_url(&image, page_url));
+
+  OgpMetadataVO {
+    title: title.and_then(|value| normalize_text(&value, MAX_TITLE_LENGTH)),
+    description: description.and_then(|value| normalize_text(&value, MAX_DESCRIPTION_LENGTH)),
+    site_name: site_name.and_then(|value| normalize_text(&value, MAX_SITE_NAME_LENGTH)),
+    image_url,
+  }
+}
+
+/// meta タグの property / name をキーに content を集める（同じキーは最初のものを優先）
+fn collect_meta_contents(document: &Html) -> HashMap<String, String> {
+  let selector = Selector::parse(OGP_META_SELECTOR).expect("OGP の meta タグのセレクタが不正です");
+  let mut contents = HashMap::new();
+
+  for element in document.select(&selector) {
+    let element = element.value();
+    let key = element.attr("property").or_else(|| element.attr("name"));
+    if let (Some(key), Some(content)) = (key, element.attr("content")) {
+      contents.entry(key.to_ascii_lowercase()).or_insert_with(|| content.to_string());
+    }
+  }
+
+  contents
+}
+
+fn extract_title_tag(document: &Html) -> Option<String> {
+  let selector = Selector::parse(TITLE_SELECTOR).expect("title タグのセレクタが不正です");
+  document.select(&selector).next().map(|title| title.text().collect())
+}
+
+/// og:image の値を絶対 URL に解決し、http(s) のみを受け付ける
+fn resolve_image_url(image: &str, page_url: &Url) -> Option<String> {
+  let resolved = page_url.join(image.trim()).ok()?;
+  if resolved.scheme() != "https" && resolved.scheme() != "http" {
+    return None;
+  }
+  let resolved = resolved.to_string();
+  (resolved.len() <= MAX_IMAGE_URL_LENGTH).then_some(resolved)
+}
+
+/// 空白を詰めて最大文字数で切り詰める（空になる場合は None）
+fn normalize_text(value: &str, max_length: usize) -> Option<String> {
+  let collapsed = value.split_whitespace().collect::<Vec<_>>().join(" ");
+  if collapsed.is_empty() {
+    return None;
+  }
+  Some(collapsed.chars().take(max_length).collect())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+  };
+
+  const SAMPLE_HTML: &str = r#"<!DOCTYPE html>
+<html>
+<head>
+  <title>タイトルタグ</title>
+  <meta charset="utf-8">
+  <META property="og:title" content="Rust &amp; 所有権">
+  <meta property='og:description' content='  所有権の
+    解説記事 '>
+  <meta property="og:site_name" content="Example Blog" />
+  <meta property="og:image" content="/images/ogp.png">
+</head>
+<body></body>
+</html>"#;
+
+  /// 固定のレスポンスを返すローカルのスタブサーバーを起動し、その URL を返す
+  async fn spawn_stub_server(response: String, delay: Duration) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+      while let Ok((mut socket, _)) = listener.accept().await {
+        let response = response.clone();
+        tokio::spawn(async move {
+          let mut buffer = [0u8; 4096];
+          let _ = socket.read(&mut buffer).await;
+          tokio::time::sleep(delay).await;
+          let _ = socket.write_all(response.as_bytes()).await;
+          let _ = socket.shutdown().await;
+        });
+      }
+    });
+    format!("http://{}/articles/1", address)
+  }
+
+  fn html_response(body: &str) -> String {
+    format!(
+      "HTTP/1.1 200 OK\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+      body.len(),
+      body
+    )
+  }
+
+  fn fetcher_with_timeout(timeout: Duration) -> HttpOgpFetcher {
+    HttpOgpFetcher::new(HttpOgpFetcherConfig {
+      timeout,
+      allow_private_network: true,
+      ..Default::default()
+    })
+    .unwrap()
+  }
+
+  #[test]
+  fn parses_og_meta_tags() {
+    let page_url = Url::parse("https://example.com/articles/1").unwrap();
+
+    let metadata = parse_ogp_metadata(SAMPLE_HTML, &page_url);
+
+    assert_eq!(metadata.title.as_deref(), Some("Rust & 所有権"));
+    assert_eq!(metadata.description.as_deref(), Some("所有権の 解説記事"));
+    assert_eq!(metadata.site_name.as_deref(), Some("Example Blog"));
+    assert_eq!(metadata.image_url.as_deref(), Some("https://example.com/images/ogp.png"));
+  }
+
+  #[test]
+  fn falls_back_to_title_tag_and_description_meta() {
+    let html = r#"<head><title> ページ &#x30BF;イトル </title><meta name="description" content="説明文"></head>"#;
+    let page_url = Url::parse("https://example.com/").unwrap();
+
+    let metadata = parse_ogp_metadata(html, &page_url);
+
+    assert_eq!(metadata.title.as_deref(), Some("ページ タイトル"));
+    assert_eq!(metadata.description.as_deref(), Some("説明文"));
+    assert_eq!(metadata.site_name, None);
+    assert_eq!(metadata.image_url, None);
+  }
+
+  #[test]
+  fn ignores_meta_tags_in_comments_and_scripts() {
+    let html = r#"<head>
+  <!-- <meta property="og:title" content="コメント内"> -->
+  <script>document.write('<meta property="og:title" content="スクリプト内">');</script>
+  <meta property="og:title" content="a > b の比較">
+</head>"#;
+    let page_url = Url::parse("https://example.com/").unwrap();
+
+    assert_eq!(parse_ogp_metadata(html, &page_url).title.as_deref(), Some("a > b の比較"));
+  }
+
+  #[test]
+  fn ignores_non_http_image_urls() {
+    let html = r#"<meta property="og:image" content="javascript:alert(1)">"#;
+    let page_url = Url::parse("https://example.com/").unwrap();
+
+    assert_eq!(parse_ogp_metadata(html, &page_url).image_url, None);
+  }
+
+  #[test]
+  fn truncates_too_long_title() {
+    let html = format!(r#"<meta property="og:title" content="{}">"#, "あ".repeat(MAX_TITLE_LENGTH + 10));
+    let page_url = Url::parse("https://example.com/").unwrap();
+
+    let title = parse_ogp_metadata(&html, &page_url).title.unwrap();
+
+    assert_eq!(title.chars().count(), MAX_TITLE_LENGTH);
+  }
+
+  #[tokio::test]
+  async fn fetches_metadata_from_stub_server() {
+    let url = spawn_stub_server(html_response(SAMPLE_HTML), Duration::ZERO).await;
+    let fetcher = fetcher_with_timeout(Duration::from_secs(5));
+
+    let metadata = fetcher.fetch(&url).await.unwrap();
+
+    assert_eq!(metadata.title.as_deref(), Some("Rust & 所有権"));
+    let expected_image_url = Url::parse(&url).unwrap().join("/images/ogp.png").unwrap().to_string();
+    assert_eq!(metadata.image_url, Some(expected_image_url));
+  }
+
+  #[tokio::test]
+  async fn times_out_when_server_is_slow() {
+    let url = spawn_stub_server(html_response(SAMPLE_HTML), Duration::from_secs(3)).await;
+    let fetcher = fetcher_with_timeout(Duration::from_millis(200));
+
+    assert!(fetcher.fetch(&url).await.is_err());
+  }
+
+  #[tokio::test]
+  async fn returns_error_for_non_success_status() {
+    let url = spawn_stub_server(
+      "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string(),
+      Duration::ZERO,
+    )
+    .await;
+    let fetcher = fetcher_with_timeout(Duration::from_secs(5));
+
+    assert!(fetcher.fetch(&url).await.is_err());
+  }
+
+  #[tokio::test]
+  async fn rejects_non_html_content() {
+    let response = "HTTP/1.1 200 OK\r\nContent-Type: application/pdf\r\nContent-Length: 4\r\nConnection: close\r\n\r\n%PDF".to_string();
+    let url = spawn_stub_server(response, Duration::ZERO).await;
+    let fetcher = fetcher_with_timeout(Duration::from_secs(5));
+
+    assert!(fetcher.fetch(&url).await.is_err());
+  }
+
+  #[tokio::test]
+  async fn refuses_loopback_target_by_default() {
+    let url = spawn_stub_server(html_response(SAMPLE_HTML), Duration::ZERO).await;
+    let fetcher = HttpOgpFetcher::new(HttpOgpFetcherConfig::default()).unwrap();
+
+    let error = fetcher.fetch(&url).await.unwrap_err();
+
+    assert!(error.to_string().contains("内部ネットワーク"));
+  }
+
+  #[tokio::test]
+  async fn refuses_host_name_resolving_to_loopback() {
+    let url = spawn_stub_server(html_response(SAMPLE_HTML), Duration::ZERO).await;
+    let url = url.replace("127.0.0.1", "localhost");
+    let fetcher = HttpOgpFetcher::new(HttpOgpFetcherConfig::default()).unwrap();
+
+    let error = fetcher.fetch(&url).await.unwrap_err();
+
+    assert!(format!("{:#}", error).contains("内部ネットワーク"));
+  }
+
+  #[tokio::test]
+  async fn refuses_redirect_to_loopback() {
+    let target = spawn_stub_server(html_response(SAMPLE_HTML), Duration::ZERO).await;
+    let redirect = format!("HTTP/1.1 302 Found\r\nLocation: {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n", target);
+    let url = spawn_stub_server(redirect, Duration::ZERO).await;
+    // 最初のリクエストだけを許可し、リダイレクト先の検査を確かめる
+    let client = reqwest::Client::builder().redirect(redirect_policy(false)).build().unwrap();
+    let fetcher = HttpOgpFetcher::with_client(client, DEFAULT_MAX_BODY_BYTES);
+
+    let error = fetcher.fetch(&url).await.unwrap_err();
+
+    assert!(format!("{:#}", error).contains("内部ネットワーク"));
+  }
+
+  #[test]
+  fn classifies_internal_addresses() {
+    for address in [
+      "127.0.0.1",
+      "10.0.0.1",
+      "172.16.0.1",
+      "192.168.1.1",
+      "169.254.169.254",
+      "100.64.0.1",
+      "0.0.0.0",
+      "::1",
+      "fc00::1",
+      "fe80::1",
+      "::ffff:127.0.0.1",
+    ] {
+      assert!(!is_public_address(address.parse().unwrap()), "{} は内部アドレスとして扱う", address);
+    }
+    for address in ["93.184.216.34", "2606:2800:220:1:248:1893:25c8:1946"] {
+      assert!(is_public_address(address.parse().unwrap()), "{} は公開アドレスとして扱う", address);
+    }
+  }
+}
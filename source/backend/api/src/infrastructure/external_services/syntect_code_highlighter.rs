@@ -0,0 +1,282 @@
+use std::{
+  collections::{HashMap, VecDeque},
+  sync::{Arc, Mutex},
+};
+
+use anyhow::Result;
+use sha2::{Digest, Sha256};
+use syntect::{
+  easy::ScopeRangeIterator,
+  parsing::{ParseState, Scope, ScopeStack, SyntaxReference, SyntaxSet},
+  util::LinesWithEndings,
+};
+
+use crate::domain::blog_domain::code_highlighter::{CodeHighlighter, HighlightTokenKind, HighlightTokenVO, HighlightedCodeVO};
+
+/// キャッシュしておくハイライト結果の最大件数
+const DEFAULT_CACHE_CAPACITY: usize = 1024;
+
+/// 同梱の文法定義を使ってハイライトする CodeHighlighter の実装
+///
+/// 文法定義の読み込みはコストが高いため、インスタンスはアプリケーション全体で共有する。
+/// ハイライト結果は言語とコードのハッシュをキーにキャッシュする。
+pub struct SyntectCodeHighlighter {
+  syntax_set: SyntaxSet,
+  cache: Mutex<HighlightCache>,
+}
+
+impl Default for SyntectCodeHighlighter {
+  fn default() -> Self {
+    Self::new(DEFAULT_CACHE_CAPACITY)
+  }
+}
+
+impl SyntectCodeHighlighter {
+  pub fn new(cache_capacity: usize) -> Self {
+    Self {
+      syntax_set: SyntaxSet::load_defaults_newlines(),
+      cache: Mutex::new(HighlightCache::new(cache_capacity)),
+    }
+  }
+
+  /// コードブロックの言語に対応する文法を探す
+  ///
+  /// 同梱の文法定義に含まれない言語は近い文法で代用し、代用できないものはプレーンテキストとして扱う。
+  fn find_syntax(&self, language: &str) -> Option<&SyntaxReference> {
+    let extension = match language {
+      "text" | "toml" | "dockerfile" => return None,
+      "rust" => "rs",
+      "javascript" | "jsx" | "typescript" | "tsx" => "js",
+      "json" => "json",
+      "yaml" => "yaml",
+      "html" => "html",
+      "css" | "scss" => "css",
+      "sql" => "sql",
+      "bash" | "shell" => "sh",
+      "python" => "py",
+      "go" => "go",
+      "java" => "java",
+      "c" => "c",
+      "cpp" => "cpp",
+      "csharp" => "cs",
+      "ruby" => "rb",
+      "php" => "php",
+      "markdown" => "md",
+      "diff" => "diff",
+      "xml" => "xml",
+      other => other,
+    };
+    self.syntax_set.find_syntax_by_extension(extension)
+  }
+
+  fn highlight_uncached(&self, code: &str, language: &str) -> Result<HighlightedCodeVO> {
+    let syntax = self.find_syntax(language).unwrap_or_else(|| self.syntax_set.find_syntax_plain_text());
+    let mut parse_state = ParseState::new(syntax);
+    let mut scope_stack = ScopeStack::new();
+    let mut lines = Vec::new();
+
+    for line in LinesWithEndings::from(code) {
+      let ops = parse_state.parse_line(line, &self.syntax_set)?;
+      let mut tokens: Vec<HighlightTokenVO> = Vec::new();
+      for (range, op) in ScopeRangeIterator::new(&ops, line) {
+        scope_stack.apply(op)?;
+        let text = line[range].trim_end_matches(['\n', '\r']);
+        if text.is_empty() {
+          continue;
+        }
+        let kind = classify_scopes(scope_stack.as_slice());
+        // 同じ種類のトークンが続く場合は 1 つにまとめる
+        match tokens.last_mut() {
+          Some(last) if last.kind == kind => last.text.push_str(text),
+          _ => tokens.push(HighlightTokenVO { text: text.to_string(), kind }),
+        }
+      }
+      lines.push(tokens);
+    }
+
+    Ok(HighlightedCodeVO::new(lines))
+  }
+}
+
+impl CodeHighlighter for SyntectCodeHighlighter {
+  fn highlight(&self, code: &str, language: &str) -> Result<Arc<HighlightedCodeVO>> {
+    let key = content_hash(code, language);
+    if let Some(cached) = self.cache.lock().map_err(|_| anyhow::anyhow!("ハイライト結果のキャッシュにアクセスできません"))?.get(&key) {
+      return Ok(cached);
+    }
+
+    // ロックを保持したまま解析しないよう、キャッシュへの登録は解析後に行う
+    let highlighted = Arc::new(self.highlight_uncached(code, language)?);
+    self.cache.lock().map_err(|_| anyhow::anyhow!("ハイライト結果のキャッシュにアクセスできません"))?.insert(key, highlighted.clone());
+    Ok(highlighted)
+  }
+}
+
+/// 言語とコードの内容からキャッシュのキーを作る
+fn content_hash(code: &str, language: &str) -> [u8; 32] {
+  let mut hasher = Sha256::new();
+  hasher.update(language.as_bytes());
+  hasher.update([0]);
+  hasher.update(code.as_bytes());
+  hasher.finalize().into()
+}
+
+/// スコープの積み重なりからトークンの種類を判定する
+///
+/// コメントと文字列は内側のスコープ（引用符など）より優先し、それ以外は最も内側のスコープで判定する。
+fn classify_scopes(scopes: &[Scope]) -> Option<HighlightTokenKind> {
+  let names: Vec<String> = scopes.iter().map(|scope| scope.build_string()).collect();
+  if names.iter().any(|name| name.starts_with("comment")) {
+    return Some(HighlightTokenKind::Comment);
+  }
+  if names.iter().any(|name| name.starts_with("string")) {
+    return Some(HighlightTokenKind::String);
+  }
+
+  names.iter().rev().find_map(|name| classify_scope(name))
+}
+
+fn classify_scope(name: &str) -> Option<HighlightTokenKind> {
+  const RULES: [(&str, HighlightTokenKind); 14] = [
+    ("constant.numeric", HighlightTokenKind::Number),
+    ("constant", HighlightTokenKind::Constant),
+    ("keyword.operator", HighlightTokenKind::Operator),
+    ("keyword", HighlightTokenKind::Keyword),
+    ("storage", HighlightTokenKind::Keyword),
+    ("entity.name.function", HighlightTokenKind::Function),
+    ("support.function", HighlightTokenKind::Function),
+    ("variable.function", HighlightTokenKind::Function),
+    ("entity.name", HighlightTokenKind::Type),
+    ("entity.other.inherited-class", HighlightTokenKind::Type),
+    ("support.type", HighlightTokenKind::Type),
+    ("support.class", HighlightTokenKind::Type),
+    ("variable", HighlightTokenKind::Variable),
+    ("punctuation", HighlightTokenKind::Punctuation),
+  ];
+  RULES.iter().find(|(prefix, _)| name == *prefix || name.starts_with(&format!("{}.", prefix))).map(|(_, kind)| *kind)
+}
+
+/// 上限件数を超えたら古いものから捨てるハイライト結果のキャッシュ
+struct HighlightCache {
+  capacity: usize,
+  entries: HashMap<[u8; 32], Arc<HighlightedCodeVO>>,
+  order: VecDeque<[u8; 32]>,
+}
+
+impl HighlightCache {
+  fn new(capacity: usize) -> Self {
+    Self {
+      capacity,
+      entries: HashMap::new(),
+      order: VecDeque::new(),
+    }
+  }
+
+  fn get(&self, key: &[u8; 32]) -> Option<Arc<HighlightedCodeVO>> {
+    self.entries.get(key).cloned()
+  }
+
+  fn insert(&mut self, key: [u8; 32], value: Arc<HighlightedCodeVO>) {
+    if self.capacity == 0 || self.entries.contains_key(&key) {
+      return;
+    }
+    while self.entries.len() >= self.capacity {
+      match self.order.pop_front() {
+        Some(oldest) => {
+          self.entries.remove(&oldest);
+        }
+        None => break,
+      }
+    }
+    self.order.push_back(key);
+    self.entries.insert(key, value);
+  }
+
+  #[cfg(test)]
+  fn len(&self) -> usize {
+    self.entries.len()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::blog_domain::code_language_registry::SUPPORTED_CODE_LANGUAGES;
+
+  fn kinds_of(line: &[HighlightTokenVO]) -> Vec<(&str, Option<HighlightTokenKind>)> {
+    line.iter().map(|token| (token.text.as_str(), token.kind)).collect()
+  }
+
+  #[test]
+  fn highlights_rust_tokens_by_kind() {
+    let highlighter = SyntectCodeHighlighter::default();
+
+    let highlighted = highlighter.highlight("// 挨拶\nfn main() { let s = \"hi\"; }", "rust").unwrap();
+
+    let lines = highlighted.get_lines();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(kinds_of(&lines[0]), vec![("// 挨拶", Some(HighlightTokenKind::Comment))]);
+    let second = kinds_of(&lines[1]);
+    assert!(second.contains(&("fn", Some(HighlightTokenKind::Keyword))));
+    assert!(second.contains(&("main", Some(HighlightTokenKind::Function))));
+    assert!(second.contains(&("\"hi\"", Some(HighlightTokenKind::String))));
+  }
+
+  #[test]
+  fn tokens_reproduce_original_code_without_newlines() {
+    let highlighter = SyntectCodeHighlighter::default();
+    let code = "SELECT id, title\nFROM blog_posts\nWHERE id = 1;";
+
+    let highlighted = highlighter.highlight(code, "sql").unwrap();
+
+    let restored = highlighted.get_lines().iter().map(|line| line.iter().map(|token| token.text.as_str()).collect::<String>()).collect::<Vec<_>>().join("\n");
+    assert_eq!(restored, code);
+  }
+
+  #[test]
+  fn plain_text_languages_have_no_token_kinds() {
+    let highlighter = SyntectCodeHighlighter::default();
+
+    let highlighted = highlighter.highlight("[package]\nname = \"blog-api\"", "toml").unwrap();
+
+    assert!(highlighted.get_lines().iter().flatten().all(|token| token.kind.is_none()));
+  }
+
+  #[test]
+  fn all_supported_languages_can_be_highlighted() {
+    let highlighter = SyntectCodeHighlighter::default();
+
+    for language in SUPPORTED_CODE_LANGUAGES {
+      let resolved = highlighter.find_syntax(language).is_some();
+      let plain_text = matches!(language, "text" | "toml" | "dockerfile");
+      assert_eq!(resolved, !plain_text, "{} の文法が解決できません", language);
+      assert!(highlighter.highlight("a\nb", language).is_ok());
+    }
+  }
+
+  #[test]
+  fn same_content_is_served_from_cache() {
+    let highlighter = SyntectCodeHighlighter::default();
+
+    let first = highlighter.highlight("let x = 1;", "javascript").unwrap();
+    let second = highlighter.highlight("let x = 1;", "javascript").unwrap();
+    let other_language = highlighter.highlight("let x = 1;", "rust").unwrap();
+
+    assert!(Arc::ptr_eq(&first, &second));
+    assert!(!Arc::ptr_eq(&first, &other_language));
+  }
+
+  #[test]
+  fn cache_evicts_oldest_entry_beyond_capacity() {
+    let mut cache = HighlightCache::new(2);
+    let value = Arc::new(HighlightedCodeVO::new(vec![]));
+
+    cache.insert(content_hash("a", "text"), value.clone());
+    cache.insert(content_hash("b", "text"), value.clone());
+    cache.insert(content_hash("c", "text"), value);
+
+    assert_eq!(cache.len(), 2);
+    assert!(cache.get(&content_hash("a", "text")).is_none());
+    assert!(cache.get(&content_hash("c", "text")).is_some());
+  }
+}
@@ -1,5 +1,9 @@
+mod blog_archive;
 pub mod domain_data_mapper;
+mod page_view;
 pub mod record_mapper;
+mod related;
+mod sitemap;
 pub mod tables;
 
 // 公開する必要のある型をre-export
@@ -8,14 +12,19 @@ pub use record_mapper::*;
 pub use tables::*;
 
 use anyhow::{Context, Result};
-use sqlx::PgPool;
+use sqlx::{PgPool, Postgres, Transaction};
 use uuid::Uuid;
 
 use crate::{
   domain::{
     blog_domain::{
-      blog_post_entity::BlogPostEntity, blog_post_repository::BlogPostRepository, pick_up_post_set_entity::PickUpPostSetEntity,
-      popular_post_set_entity::PopularPostSetEntity, top_tech_pick_entity::TopTechPickEntity,
+      blog_post_entity::{seo_metadata_vo::SeoMetadataVO, BlogPostEntity},
+      blog_post_repository::BlogPostRepository,
+      errors::blog_domain_error::BlogDomainError,
+      pick_up_post_set_entity::PickUpPostSetEntity,
+      popular_post_set_entity::PopularPostSetEntity,
+      related_post_repository::PostTermVectorVO,
+      top_tech_pick_entity::TopTechPickEntity,
     },
     image_domain::image_repository::ImageRepository,
   },
@@ -28,10 +37,21 @@ use crate::{
 use self::tables::{
   blog_posts_table::{insert_blog_post, update_blog_post_record},
   code_blocks_table::insert_code_block,
+  details_blocks_table::insert_details_block,
+  diagram_blocks_table::insert_diagram_block,
+  embed_blocks_table::insert_embed_block,
+  footnotes_table::{delete_footnotes_by_post_id, fetch_footnotes_with_relations_by_post_id, insert_footnote},
   heading_blocks_table::insert_heading_block,
   image_blocks_table::{insert_image_block, ImageBlockRecord},
-  paragraph_blocks_table::{insert_paragraph_block, insert_rich_text, insert_rich_text_link, insert_rich_text_style, insert_text_style_if_not_exists},
+  link_card_blocks_table::insert_link_card_block,
+  math_blocks_table::insert_math_block,
+  paragraph_blocks_table::{
+    insert_paragraph_block, insert_rich_text, insert_rich_text_footnote_ref, insert_rich_text_link, insert_rich_text_ruby, insert_rich_text_style,
+    insert_text_style_if_not_exists,
+  },
   post_contents_table::{delete_post_contents_by_post_id, fetch_any_content_block, fetch_post_contents_by_post_id, insert_blog_post_content},
+  seo_metadata_table::{delete_seo_metadata_by_post_id, fetch_seo_metadata_with_relations_by_post_id, upsert_seo_metadata},
+  term_vectors_table::upsert_term_vector,
 };
 
 use crate::infrastructure::repositories::image_sqlx_repository::table::images_table::fetch_image_by_path;
@@ -47,6 +67,12 @@ impl<I: ImageRepository> BlogPostSqlxRepository<I> {
   pub fn new(pool: PgPool, image_repository: I) -> Self {
     Self { pool, image_repository }
   }
+
+  /// 記事の SEO メタデータを取得する
+  async fn find_seo_metadata(&self, post_id: Uuid) -> Result<SeoMetadataVO> {
+    let seo_metadata_record = fetch_seo_metadata_with_relations_by_post_id(&self.pool, post_id).await.context("SEO メタデータの取得に失敗しました")?;
+    convert_to_seo_metadata_vo(seo_metadata_record)
+  }
 }
 
 #[async_trait::async_trait]
@@ -88,13 +114,22 @@ impl<I: ImageRepository + Send + Sync> BlogPostRepository for BlogPostSqlxReposi
       content_blocks.push((post_content_record, content_block));
     }
 
+    // 脚注を取得
+    let footnote_records = fetch_footnotes_with_relations_by_post_id(&self.pool, post_id).await.context("脚注の取得に失敗しました")?;
+
     // エンティティに変換
-    convert_to_blog_post_entity(blog_post_record, thumbnail_record, content_blocks).context("BlogPostEntityへの変換に失敗しました")
+    let mut blog_post_entity =
+      convert_to_blog_post_entity(blog_post_record, thumbnail_record, content_blocks, footnote_records).context("BlogPostEntityへの変換に失敗しました")?;
+    blog_post_entity.set_seo_metadata(self.find_seo_metadata(post_id).await?);
+
+    Ok(blog_post_entity)
   }
 
-  async fn save(&self, blog_post: &BlogPostEntity) -> Result<BlogPostEntity> {
+  async fn save(&self, blog_post: &BlogPostEntity, term_vector: &PostTermVectorVO) -> Result<BlogPostEntity> {
     // record_mapperを使用してBlogPostEntityをDBレコードに変換
     let (blog_post_record, content_records) = convert_from_blog_post_entity(blog_post).context("BlogPostEntityからDBレコードへの変換に失敗しました")?;
+    let footnote_records = convert_footnotes_from_blog_post_entity(blog_post).context("脚注からDBレコードへの変換に失敗しました")?;
+    let seo_metadata_record = convert_seo_metadata_from_blog_post_entity(blog_post);
 
     // トランザクションを開始
     let mut tx = self.pool.begin().await.context("トランザクションの開始に失敗しました")?;
@@ -102,63 +137,17 @@ impl<I: ImageRepository + Send + Sync> BlogPostRepository for BlogPostSqlxReposi
     // 1. ブログ記事の挿入
     insert_blog_post(&mut *tx, blog_post_record).await.context("ブログ記事の挿入に失敗しました")?;
 
-    // 2. コンテンツの挿入
-    for (post_content_record, content_block_record) in content_records {
-      // PostContentRecordの挿入
-      insert_blog_post_content(&mut *tx, post_content_record).await.context("コンテンツレコードの挿入に失敗しました")?;
+    // 2. 脚注の挿入（本文中の脚注参照から参照されるため、コンテンツより先に挿入する）
+    insert_footnote_records(&mut tx, footnote_records).await?;
 
-      // 各コンテンツタイプごとの詳細データを挿入
-      match content_block_record {
-        AnyContentBlockRecord::HeadingBlockRecord(heading) => {
-          insert_heading_block(&mut *tx, heading).await.context("見出しブロックの挿入に失敗しました")?;
-        }
-        AnyContentBlockRecord::ParagraphBlockRecord(paragraph) => {
-          // ParagraphBlockの挿入
-          insert_paragraph_block(&mut *tx, paragraph.paragraph_block).await.context("段落ブロックの挿入に失敗しました")?;
-
-          // RichTextRecordの挿入
-          for rich_text_record in paragraph.rich_text_records_with_relations {
-            let rich_text_id = rich_text_record.text_record.id;
-
-            insert_rich_text(&mut *tx, rich_text_record.text_record).await.context("リッチテキストの挿入に失敗しました")?;
-
-            // スタイルの挿入
-            for style_record in rich_text_record.style_records {
-              // text_stylesテーブルにスタイルが存在しない場合は挿入
-              insert_text_style_if_not_exists(&mut *tx, style_record.clone()).await.context("テキストスタイルの挿入に失敗しました")?;
-
-              let rich_text_style = crate::infrastructure::repositories::blog_post_sqlx_repository::tables::paragraph_blocks_table::RichTextStyleRecord {
-                style_id: style_record.id,
-                rich_text_id,
-              };
-              insert_rich_text_style(&mut *tx, rich_text_style).await.context("リッチテキストスタイルの挿入に失敗しました")?;
-            }
-
-            // リンクの挿入（存在する場合）
-            if let Some(link_record) = rich_text_record.link_record {
-              insert_rich_text_link(&mut *tx, link_record).await.context("リッチテキストリンクの挿入に失敗しました")?;
-            }
-          }
-        }
-        AnyContentBlockRecord::ImageBlockRecord(image_block) => {
-          // 画像パスから実際の画像IDを検索
-          let actual_image_record =
-            fetch_image_by_path(&mut *tx, &image_block.image_record.file_path).await.context("画像パスから画像IDの検索に失敗しました")?;
-
-          // 正しい画像IDで画像ブロックレコードを作成
-          let corrected_image_block_record = ImageBlockRecord {
-            id: image_block.image_block_record.id,
-            image_id: actual_image_record.id,
-          };
-
-          // 画像ブロックの挿入
-          insert_image_block(&mut *tx, corrected_image_block_record).await.context("画像ブロックの挿入に失敗しました")?;
-        }
-        AnyContentBlockRecord::CodeBlockRecord(code_block) => {
-          insert_code_block(&mut *tx, code_block).await.context("コードブロックの挿入に失敗しました")?;
-        }
-      }
-    }
+    // 3. コンテンツの挿入
+    insert_content_records(&mut tx, content_records).await?;
+
+    // 4. SEO メタデータの挿入
+    replace_seo_metadata_record(&mut tx, blog_post.get_id(), seo_metadata_record).await?;
+
+    // 5. 関連記事用の語の出現回数の保存
+    save_term_vector_record(&mut tx, term_vector).await?;
 
     // トランザクションをコミット
     tx.commit().await.context("トランザクションのコミットに失敗しました")?;
@@ -167,9 +156,11 @@ impl<I: ImageRepository + Send + Sync> BlogPostRepository for BlogPostSqlxReposi
     self.find(&blog_post.get_id().to_string()).await
   }
 
-  async fn update(&self, blog_post: &BlogPostEntity) -> Result<BlogPostEntity> {
+  async fn update(&self, blog_post: &BlogPostEntity, term_vector: &PostTermVectorVO) -> Result<BlogPostEntity> {
     // record_mapperを使用してBlogPostEntityをDBレコードに変換
     let (blog_post_record, content_records) = convert_from_blog_post_entity(blog_post).context("BlogPostEntityからDBレコードへの変換に失敗しました")?;
+    let footnote_records = convert_footnotes_from_blog_post_entity(blog_post).context("脚注からDBレコードへの変換に失敗しました")?;
+    let seo_metadata_record = convert_seo_metadata_from_blog_post_entity(blog_post);
 
     // トランザクションを開始
     let mut tx = self.pool.begin().await.context("トランザクションの開始に失敗しました")?;
@@ -177,66 +168,20 @@ impl<I: ImageRepository + Send + Sync> BlogPostRepository for BlogPostSqlxReposi
     // 1. ブログ記事の更新
     update_blog_post_record(&mut *tx, blog_post_record).await.context("ブログ記事の更新に失敗しました")?;
 
-    // 2. 既存のコンテンツを削除（DELETE & INSERT戦略）
+    // 2. 既存のコンテンツと脚注を削除（DELETE & INSERT戦略）
+    // 脚注は本文中の脚注参照から参照されているため、コンテンツを削除した後に削除する
     delete_post_contents_by_post_id(&mut *tx, blog_post.get_id()).await.context("既存コンテンツの削除に失敗しました")?;
+    delete_footnotes_by_post_id(&mut *tx, blog_post.get_id()).await.context("既存の脚注の削除に失敗しました")?;
 
-    // 3. 新しいコンテンツの挿入
-    for (post_content_record, content_block_record) in content_records {
-      // PostContentRecordの挿入
-      insert_blog_post_content(&mut *tx, post_content_record).await.context("コンテンツレコードの挿入に失敗しました")?;
+    // 3. 新しい脚注とコンテンツの挿入
+    insert_footnote_records(&mut tx, footnote_records).await?;
+    insert_content_records(&mut tx, content_records).await?;
 
-      // 各コンテンツタイプごとの詳細データを挿入
-      match content_block_record {
-        AnyContentBlockRecord::HeadingBlockRecord(heading) => {
-          insert_heading_block(&mut *tx, heading).await.context("見出しブロックの挿入に失敗しました")?;
-        }
-        AnyContentBlockRecord::ParagraphBlockRecord(paragraph) => {
-          // ParagraphBlockの挿入
-          insert_paragraph_block(&mut *tx, paragraph.paragraph_block).await.context("段落ブロックの挿入に失敗しました")?;
-
-          // RichTextRecordの挿入
-          for rich_text_record in paragraph.rich_text_records_with_relations {
-            let rich_text_id = rich_text_record.text_record.id;
-
-            insert_rich_text(&mut *tx, rich_text_record.text_record).await.context("リッチテキストの挿入に失敗しました")?;
-
-            // スタイルの挿入
-            for style_record in rich_text_record.style_records {
-              // text_stylesテーブルにスタイルが存在しない場合は挿入
-              insert_text_style_if_not_exists(&mut *tx, style_record.clone()).await.context("テキストスタイルの挿入に失敗しました")?;
-
-              let rich_text_style = crate::infrastructure::repositories::blog_post_sqlx_repository::tables::paragraph_blocks_table::RichTextStyleRecord {
-                style_id: style_record.id,
-                rich_text_id,
-              };
-              insert_rich_text_style(&mut *tx, rich_text_style).await.context("リッチテキストスタイルの挿入に失敗しました")?;
-            }
-
-            // リンクの挿入（存在する場合）
-            if let Some(link_record) = rich_text_record.link_record {
-              insert_rich_text_link(&mut *tx, link_record).await.context("リッチテキストリンクの挿入に失敗しました")?;
-            }
-          }
-        }
-        AnyContentBlockRecord::ImageBlockRecord(image_block) => {
-          // 画像パスから実際の画像IDを検索
-          let actual_image_record =
-            fetch_image_by_path(&mut *tx, &image_block.image_record.file_path).await.context("画像パスから画像IDの検索に失敗しました")?;
-
-          // 正しい画像IDで画像ブロックレコードを作成
-          let corrected_image_block_record = ImageBlockRecord {
-            id: image_block.image_block_record.id,
-            image_id: actual_image_record.id,
-          };
-
-          // 画像ブロックの挿入
-          insert_image_block(&mut *tx, corrected_image_block_record).await.context("画像ブロックの挿入に失敗しました")?;
-        }
-        AnyContentBlockRecord::CodeBlockRecord(code_block) => {
-          insert_code_block(&mut *tx, code_block).await.context("コードブロックの挿入に失敗しました")?;
-        }
-      }
-    }
+    // 4. SEO メタデータの置き換え
+    replace_seo_metadata_record(&mut tx, blog_post.get_id(), seo_metadata_record).await?;
+
+    // 5. 関連記事用の語の出現回数の置き換え
+    save_term_vector_record(&mut tx, term_vector).await?;
 
     // トランザクションをコミット
     tx.commit().await.context("トランザクションのコミットに失敗しました")?;
@@ -272,8 +217,13 @@ impl<I: ImageRepository + Send + Sync> BlogPostRepository for BlogPostSqlxReposi
         content_blocks.push((post_content_record, content_block));
       }
 
+      // 脚注を取得
+      let footnote_records = fetch_footnotes_with_relations_by_post_id(&self.pool, blog_post_record.id).await.context("脚注の取得に失敗しました")?;
+
       // エンティティに変換
-      let blog_post_entity = convert_to_blog_post_entity(blog_post_record, thumbnail_record, content_blocks).context("BlogPostEntityへの変換に失敗しました")?;
+      let mut blog_post_entity =
+        convert_to_blog_post_entity(blog_post_record, thumbnail_record, content_blocks, footnote_records).context("BlogPostEntityへの変換に失敗しました")?;
+      blog_post_entity.set_seo_metadata(self.find_seo_metadata(blog_post_entity.get_id()).await?);
       blog_post_entities.push(blog_post_entity);
     }
 
@@ -312,6 +262,9 @@ impl<I: ImageRepository + Send + Sync> BlogPostRepository for BlogPostSqlxReposi
 
     // 人気記事レコードを取得（3件固定）
     let popular_post_records = fetch_all_popular_blog_posts(&self.pool).await.context("人気記事レコードの取得に失敗しました")?;
+    if popular_post_records.is_empty() {
+      return Err(BlogDomainError::PopularPostsNotSet.into());
+    }
 
     // 各記事IDからBlogPostEntityを取得
     let mut blog_posts = Vec::new();
@@ -438,8 +391,13 @@ impl<I: ImageRepository + Send + Sync> BlogPostRepository for BlogPostSqlxReposi
         content_blocks.push((post_content_record, content_block));
       }
 
+      // 脚注を取得
+      let footnote_records = fetch_footnotes_with_relations_by_post_id(&self.pool, blog_post_record.id).await.context("脚注の取得に失敗しました")?;
+
       // エンティティに変換
-      let blog_post_entity = convert_to_blog_post_entity(blog_post_record, thumbnail_record, content_blocks).context("BlogPostEntityへの変換に失敗しました")?;
+      let mut blog_post_entity =
+        convert_to_blog_post_entity(blog_post_record, thumbnail_record, content_blocks, footnote_records).context("BlogPostEntityへの変換に失敗しました")?;
+      blog_post_entity.set_seo_metadata(self.find_seo_metadata(blog_post_entity.get_id()).await?);
       blog_post_entities.push(blog_post_entity);
     }
 
@@ -447,17 +405,137 @@ impl<I: ImageRepository + Send + Sync> BlogPostRepository for BlogPostSqlxReposi
   }
 }
 
+/// 記事コンテンツとコンテンツタイプごとの詳細データをトランザクション内で挿入する
+async fn insert_content_records(tx: &mut Transaction<'_, Postgres>, content_records: Vec<(PostContentRecord, AnyContentBlockRecord)>) -> Result<()> {
+  for (post_content_record, content_block_record) in content_records {
+    // PostContentRecordの挿入
+    insert_blog_post_content(&mut **tx, post_content_record).await.context("コンテンツレコードの挿入に失敗しました")?;
+
+    // 各コンテンツタイプごとの詳細データを挿入
+    match content_block_record {
+      AnyContentBlockRecord::HeadingBlockRecord(heading) => {
+        insert_heading_block(&mut **tx, heading).await.context("見出しブロックの挿入に失敗しました")?;
+      }
+      AnyContentBlockRecord::ParagraphBlockRecord(paragraph) => {
+        // ParagraphBlockの挿入
+        insert_paragraph_block(&mut **tx, paragraph.paragraph_block).await.context("段落ブロックの挿入に失敗しました")?;
+
+        // RichTextRecordの挿入
+        insert_rich_text_records(tx, paragraph.rich_text_records_with_relations).await?;
+      }
+      AnyContentBlockRecord::ImageBlockRecord(image_block) => {
+        // 画像パスから実際の画像IDを検索
+        let actual_image_record =
+          fetch_image_by_path(&mut **tx, &image_block.image_record.file_path).await.context("画像パスから画像IDの検索に失敗しました")?;
+
+        // 正しい画像IDで画像ブロックレコードを作成（代替テキストなどの表示設定はそのまま引き継ぐ）
+        let corrected_image_block_record = ImageBlockRecord {
+          image_id: actual_image_record.id,
+          ..image_block.image_block_record
+        };
+
+        // 画像ブロックの挿入
+        insert_image_block(&mut **tx, corrected_image_block_record).await.context("画像ブロックの挿入に失敗しました")?;
+      }
+      AnyContentBlockRecord::CodeBlockRecord(code_block) => {
+        insert_code_block(&mut **tx, code_block).await.context("コードブロックの挿入に失敗しました")?;
+      }
+      AnyContentBlockRecord::MathBlockRecord(math_block) => {
+        insert_math_block(&mut **tx, math_block).await.context("数式ブロックの挿入に失敗しました")?;
+      }
+      AnyContentBlockRecord::EmbedBlockRecord(embed_block) => {
+        insert_embed_block(&mut **tx, embed_block).await.context("埋め込みブロックの挿入に失敗しました")?;
+      }
+      AnyContentBlockRecord::LinkCardBlockRecord(link_card_block) => {
+        insert_link_card_block(&mut **tx, link_card_block).await.context("リンクカードブロックの挿入に失敗しました")?;
+      }
+      AnyContentBlockRecord::DetailsBlockRecord(details_block) => {
+        // 子コンテンツは親の直後に並んでいるため、このあと続けて挿入される
+        insert_details_block(&mut **tx, details_block).await.context("折りたたみブロックの挿入に失敗しました")?;
+      }
+      AnyContentBlockRecord::DiagramBlockRecord(diagram_block) => {
+        insert_diagram_block(&mut **tx, diagram_block).await.context("図ブロックの挿入に失敗しました")?;
+      }
+    }
+  }
+
+  Ok(())
+}
+
+/// 脚注とその本文のリッチテキストをトランザクション内で挿入する
+async fn insert_footnote_records(tx: &mut Transaction<'_, Postgres>, footnote_records: Vec<FootnoteRecordWithRelations>) -> Result<()> {
+  for footnote_record in footnote_records {
+    insert_footnote(&mut **tx, footnote_record.footnote).await.context("脚注の挿入に失敗しました")?;
+    insert_rich_text_records(tx, footnote_record.rich_text_records_with_relations).await?;
+  }
+
+  Ok(())
+}
+
+/// SEO メタデータをトランザクション内で置き換える（未設定になった場合は行を削除する）
+async fn replace_seo_metadata_record(tx: &mut Transaction<'_, Postgres>, post_id: Uuid, seo_metadata_record: Option<SeoMetadataRecord>) -> Result<()> {
+  match seo_metadata_record {
+    Some(seo_metadata_record) => upsert_seo_metadata(&mut **tx, seo_metadata_record).await.context("SEO メタデータの保存に失敗しました"),
+    None => delete_seo_metadata_by_post_id(&mut **tx, post_id).await.context("SEO メタデータの削除に失敗しました"),
+  }
+}
+
+/// 関連記事の類似度計算に使う語の出現回数をトランザクション内で保存する
+async fn save_term_vector_record(tx: &mut Transaction<'_, Postgres>, term_vector: &PostTermVectorVO) -> Result<()> {
+  upsert_term_vector(&mut **tx, convert_term_vector_to_record(term_vector)).await.context("関連記事用の語の出現回数の保存に失敗しました")
+}
+
+/// リッチテキストとそれに紐づくスタイル・リンク・ルビ・脚注参照をトランザクション内で挿入する
+async fn insert_rich_text_records(tx: &mut Transaction<'_, Postgres>, rich_text_records: Vec<RichTextRecordWithRelations>) -> Result<()> {
+  for rich_text_record in rich_text_records {
+    let rich_text_id = rich_text_record.text_record.id;
+
+    insert_rich_text(&mut **tx, rich_text_record.text_record).await.context("リッチテキストの挿入に失敗しました")?;
+
+    // スタイルの挿入
+    for style_record in rich_text_record.style_records {
+      // text_stylesテーブルにスタイルが存在しない場合は挿入
+      insert_text_style_if_not_exists(&mut **tx, style_record.clone()).await.context("テキストスタイルの挿入に失敗しました")?;
+
+      let rich_text_style = crate::infrastructure::repositories::blog_post_sqlx_repository::tables::paragraph_blocks_table::RichTextStyleRecord {
+        style_id: style_record.id,
+        rich_text_id,
+      };
+      insert_rich_text_style(&mut **tx, rich_text_style).await.context("リッチテキストスタイルの挿入に失敗しました")?;
+    }
+
+    // リンクの挿入（存在する場合）
+    if let Some(link_record) = rich_text_record.link_record {
+      insert_rich_text_link(&mut **tx, link_record).await.context("リッチテキストリンクの挿入に失敗しました")?;
+    }
+
+    // ルビの挿入（存在する場合）
+    if let Some(ruby_record) = rich_text_record.ruby_record {
+      insert_rich_text_ruby(&mut **tx, ruby_record).await.context("リッチテキストのルビの挿入に失敗しました")?;
+    }
+
+    // 脚注参照の挿入（存在する場合）
+    if let Some(footnote_ref_record) = rich_text_record.footnote_ref_record {
+      insert_rich_text_footnote_ref(&mut **tx, footnote_ref_record).await.context("脚注参照の挿入に失敗しました")?;
+    }
+  }
+
+  Ok(())
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
   use crate::{
     domain::blog_domain::{
       blog_post_entity::{
+        code_block_options_vo::CodeBlockOptionsVO,
         content_entity::ContentEntity,
         rich_text_vo::{LinkVO, RichTextPartVO, RichTextStylesVO, RichTextVO},
         BlogPostEntity,
       },
       jst_date_vo::JstDate,
+      services::related_posts_service::RelatedPostsService,
     },
     infrastructure::repositories::db_pool::create_db_pool,
   };
@@ -478,7 +556,7 @@ mod tests {
     }
 
     // コンテンツ内の画像挿入
-    for content in blog_post.get_contents() {
+    for content in blog_post.flattened_contents() {
       if let ContentEntity::Image(image_content) = content {
         sqlx::query("INSERT INTO images (id, file_path) VALUES ($1, $2) ON CONFLICT (file_path) DO NOTHING")
           .bind(uuid::Uuid::new_v4()) // 画像コンテンツ用の新しいID
@@ -521,7 +599,7 @@ mod tests {
     let mut styles = RichTextStylesVO::default();
     styles.bold = true;
 
-    let link = LinkVO {
+    let link = LinkVO::External {
       url: "https://example.com/test".to_string(),
     };
 
@@ -536,7 +614,9 @@ mod tests {
       "テストコード".to_string(),
       "fn test() { println!(\"Hello, World!\"); }".to_string(),
       "rust".to_string(),
-    );
+      CodeBlockOptionsVO::default(),
+    )
+    .unwrap();
     blog_post.add_content(code_block_content);
 
     blog_post
@@ -558,7 +638,7 @@ mod tests {
     insert_test_images(&pool, &blog_post).await.expect("テスト用画像の挿入に失敗しました");
 
     // saveメソッドを実行
-    let result = repository.save(&blog_post).await;
+    let result = repository.save(&blog_post, &RelatedPostsService::new().term_vector(&blog_post)).await;
 
     // 結果を検証
     assert!(result.is_ok(), "save操作が失敗しました: {:?}", result.err());
@@ -594,7 +674,7 @@ mod tests {
         assert_eq!(rich_text_parts[0].get_text(), "テストパラグラフ内容");
         assert!(rich_text_parts[0].get_styles().bold);
         assert!(rich_text_parts[0].get_link().is_some());
-        assert_eq!(rich_text_parts[0].get_link().unwrap().url, "https://example.com/test");
+        assert_eq!(rich_text_parts[0].get_link().unwrap().resolve_url(), "https://example.com/test");
       }
       _ => panic!("2番目のコンテンツはパラグラフである必要があります"),
     }
@@ -626,7 +706,7 @@ mod tests {
     insert_test_images(&pool, &original_blog_post).await.expect("テスト用画像の挿入に失敗しました");
 
     // saveメソッドを実行
-    let saved_result = repository.save(&original_blog_post).await;
+    let saved_result = repository.save(&original_blog_post, &RelatedPostsService::new().term_vector(&original_blog_post)).await;
     assert!(saved_result.is_ok(), "save操作が失敗しました: {:?}", saved_result.err());
 
     // findメソッドで保存したデータを取得
@@ -668,7 +748,7 @@ mod tests {
     // 各記事をデータベースに保存
     for blog_post in &test_posts {
       insert_test_images(&pool, blog_post).await.expect("テスト用画像の挿入に失敗しました");
-      repository.save(blog_post).await.expect("記事の保存に失敗しました");
+      repository.save(blog_post, &RelatedPostsService::new().term_vector(blog_post)).await.expect("記事の保存に失敗しました");
     }
 
     // 人気記事テーブルに挿入
@@ -717,7 +797,7 @@ mod tests {
     // 各記事をデータベースに保存
     for blog_post in &test_posts {
       insert_test_images(&pool, blog_post).await.expect("テスト用画像の挿入に失敗しました");
-      repository.save(blog_post).await.expect("記事の保存に失敗しました");
+      repository.save(blog_post, &RelatedPostsService::new().term_vector(blog_post)).await.expect("記事の保存に失敗しました");
     }
 
     // PopularPostSetEntityを作成
@@ -764,7 +844,7 @@ mod tests {
     // 各記事をデータベースに保存
     for blog_post in &test_posts {
       insert_test_images(&pool, blog_post).await.expect("テスト用画像の挿入に失敗しました");
-      repository.save(blog_post).await.expect("記事の保存に失敗しました");
+      repository.save(blog_post, &RelatedPostsService::new().term_vector(blog_post)).await.expect("記事の保存に失敗しました");
     }
 
     // ピックアップ記事テーブルに挿入
@@ -808,7 +888,7 @@ mod tests {
     // 各記事をデータベースに保存
     for blog_post in &test_posts {
       insert_test_images(&pool, blog_post).await.expect("テスト用画像の挿入に失敗しました");
-      repository.save(blog_post).await.expect("記事の保存に失敗しました");
+      repository.save(blog_post, &RelatedPostsService::new().term_vector(blog_post)).await.expect("記事の保存に失敗しました");
     }
 
     // PickUpPostSetEntityを作成
@@ -0,0 +1,99 @@
+use anyhow::{Context, Result};
+use uuid::Uuid;
+
+use super::tables::{
+  blog_posts_table::{fetch_existing_blog_post_ids, upsert_blog_post_record},
+  footnotes_table::delete_footnotes_by_post_id,
+  pickup_posts_table::{fetch_all_pickup_blog_posts, update_pickup_blog_posts, PickUpPostRecord},
+  popular_posts_table::{fetch_all_popular_blog_posts, update_popular_blog_posts, PopularPostRecord},
+  post_contents_table::delete_post_contents_by_post_id,
+  top_tech_pick_table::{fetch_top_tech_pick_blog_post, replace_top_tech_pick_post},
+};
+use super::{
+  convert_footnotes_from_blog_post_entity, convert_from_blog_post_entity, convert_seo_metadata_from_blog_post_entity, insert_content_records,
+  insert_footnote_records, replace_seo_metadata_record, save_term_vector_record, BlogPostSqlxRepository,
+};
+use crate::domain::{
+  blog_domain::{
+    blog_archive_entity::{BlogArchiveEntity, FeaturedPostIdsVO},
+    blog_archive_repository::BlogArchiveRepository,
+    related_post_repository::PostTermVectorVO,
+  },
+  image_domain::{image_entity::ImageEntity, image_repository::ImageRepository},
+};
+use crate::infrastructure::repositories::image_sqlx_repository::{
+  convert_from_image_entity, convert_to_image_entity,
+  table::images_table::{fetch_images_by_ids_or_paths, upsert_image},
+};
+
+#[async_trait::async_trait]
+impl<I: ImageRepository + Send + Sync> BlogArchiveRepository for BlogPostSqlxRepository<I> {
+  async fn find_featured_post_ids(&self) -> Result<FeaturedPostIdsVO> {
+    // トップテック記事は未設定の場合がある
+    let top_tech_pick = match fetch_top_tech_pick_blog_post(&self.pool).await {
+      Ok(record) => Some(record.post_id),
+      Err(err) if matches!(err.downcast_ref::<sqlx::Error>(), Some(sqlx::Error::RowNotFound)) => None,
+      Err(err) => return Err(err.context("トップテック記事の取得に失敗しました")),
+    };
+    let pick_up = fetch_all_pickup_blog_posts(&self.pool).await.context("ピックアップ記事の取得に失敗しました")?;
+    let popular = fetch_all_popular_blog_posts(&self.pool).await.context("人気記事の取得に失敗しました")?;
+
+    Ok(FeaturedPostIdsVO {
+      top_tech_pick,
+      pick_up: pick_up.into_iter().map(|record| record.post_id).collect(),
+      popular: popular.into_iter().map(|record| record.post_id).collect(),
+    })
+  }
+
+  async fn find_existing_post_ids(&self, ids: &[Uuid]) -> Result<Vec<Uuid>> {
+    fetch_existing_blog_post_ids(&self.pool, ids).await.context("既存記事の確認に失敗しました")
+  }
+
+  async fn find_images_by_ids_or_paths(&self, ids: &[Uuid], paths: &[String]) -> Result<Vec<ImageEntity>> {
+    let image_records = fetch_images_by_ids_or_paths(&self.pool, ids, paths).await.context("既存画像の確認に失敗しました")?;
+    Ok(image_records.into_iter().map(convert_to_image_entity).collect())
+  }
+
+  async fn restore(&self, archive: &BlogArchiveEntity, term_vectors: &[PostTermVectorVO]) -> Result<()> {
+    let mut tx = self.pool.begin().await.context("トランザクションの開始に失敗しました")?;
+
+    // 1. 画像の復元（記事のサムネイル・OGP 画像と画像ブロックから参照されるため先に復元する）
+    for image in archive.get_images() {
+      upsert_image(&mut *tx, convert_from_image_entity(image)).await.context(format!("画像 {} の復元に失敗しました", image.get_id()))?;
+    }
+
+    // 2. 記事の復元（既存のコンテンツと脚注は削除してから挿入し直す）
+    for blog_post in archive.get_posts() {
+      let (blog_post_record, content_records) = convert_from_blog_post_entity(blog_post).context("BlogPostEntityからDBレコードへの変換に失敗しました")?;
+      let footnote_records = convert_footnotes_from_blog_post_entity(blog_post).context("脚注からDBレコードへの変換に失敗しました")?;
+
+      upsert_blog_post_record(&mut *tx, blog_post_record).await.context(format!("記事 {} の復元に失敗しました", blog_post.get_id()))?;
+      delete_post_contents_by_post_id(&mut *tx, blog_post.get_id()).await.context("既存コンテンツの削除に失敗しました")?;
+      delete_footnotes_by_post_id(&mut *tx, blog_post.get_id()).await.context("既存の脚注の削除に失敗しました")?;
+      insert_footnote_records(&mut tx, footnote_records).await?;
+      insert_content_records(&mut tx, content_records).await?;
+      replace_seo_metadata_record(&mut tx, blog_post.get_id(), convert_seo_metadata_from_blog_post_entity(blog_post)).await?;
+    }
+    for term_vector in term_vectors {
+      save_term_vector_record(&mut tx, term_vector).await?;
+    }
+
+    // 3. 注目記事の復元（アーカイブに含まれるものだけ置き換える）
+    let featured = archive.get_featured();
+    if let Some(post_id) = featured.top_tech_pick {
+      replace_top_tech_pick_post(&mut tx, post_id).await.context("トップテック記事の復元に失敗しました")?;
+    }
+    if !featured.pick_up.is_empty() {
+      let records = featured.pick_up.iter().map(|post_id| PickUpPostRecord { post_id: *post_id }).collect();
+      update_pickup_blog_posts(&mut tx, records).await.context("ピックアップ記事の復元に失敗しました")?;
+    }
+    if !featured.popular.is_empty() {
+      let records = featured.popular.iter().map(|post_id| PopularPostRecord { post_id: *post_id }).collect();
+      update_popular_blog_posts(&mut tx, records).await.context("人気記事の復元に失敗しました")?;
+    }
+
+    tx.commit().await.context("トランザクションのコミットに失敗しました")?;
+
+    Ok(())
+  }
+}
@@ -1,26 +1,37 @@
 use anyhow::{Context, Result};
+use std::collections::HashMap;
+use uuid::Uuid;
 
 use crate::{
-  domain::{
-    blog_domain::{
-      blog_post_entity::{
-        content_entity::ContentEntity,
-        image_content_entity::ImageContentEntity,
-        rich_text_vo::{LinkVO, RichTextPartVO, RichTextStylesVO, RichTextVO},
-        BlogPostEntity,
-      },
-      jst_date_vo::JstDate,
-      pick_up_post_set_entity::PickUpPostSetEntity,
-      popular_post_set_entity::PopularPostSetEntity,
+  domain::blog_domain::{
+    blog_post_entity::{
+      code_block_options_vo::CodeBlockOptionsVO,
+      content_entity::ContentEntity,
+      diagram_source_vo::DiagramKind,
+      embed_block_entity::EmbedProvider,
+      footnote_entity::FootnoteEntity,
+      image_content_entity::ImageContentEntity,
+      image_presentation_vo::ImagePresentationVO,
+      link_card_block_entity::OgpMetadataVO,
+      rich_text_vo::{HighlightColor, LinkVO, RichTextPartVO, RichTextStylesVO, RichTextVO, RubyVO},
+      seo_metadata_vo::SeoMetadataVO,
+      BlogPostEntity,
     },
-    image_domain::ImageEntity,
+    jst_date_vo::JstDate,
+    pick_up_post_set_entity::PickUpPostSetEntity,
+    popular_post_set_entity::PopularPostSetEntity,
+    related_post_repository::PostTermVectorVO,
   },
-  infrastructure::repositories::image_sqlx_repository::ImageRecord,
+  infrastructure::repositories::image_sqlx_repository::{convert_to_image_entity, ImageRecord},
 };
 
 use super::tables::{
-  pickup_posts_table::PickUpPostRecord, popular_posts_table::PopularPostRecord, AnyContentBlockRecord, BlogPostRecord, CodeBlockRecord, HeadingBlockRecord,
-  ImageBlockRecordWithRelations, ParagraphBlockRecordWithRelations, PostContentRecord, RichTextRecordWithRelations,
+  paragraph_blocks_table::{HIGHLIGHT_STYLE_PREFIX, INLINE_MATH_STYLE},
+  pickup_posts_table::PickUpPostRecord,
+  popular_posts_table::PopularPostRecord,
+  AnyContentBlockRecord, BlogPostRecord, CodeBlockRecord, DetailsBlockRecord, DiagramBlockRecord, EmbedBlockRecord, FootnoteRecordWithRelations,
+  HeadingBlockRecord, ImageBlockRecordWithRelations, LinkCardBlockRecord, MathBlockRecord, ParagraphBlockRecordWithRelations, PostContentRecord,
+  RichTextRecordWithRelations, SeoMetadataRecordWithRelations, TermVectorRecord,
 };
 
 /// BlogPostRecordとその関連データからBlogPostEntityを作成する
@@ -28,29 +39,97 @@ pub fn convert_to_blog_post_entity(
   blog_post_record: BlogPostRecord,
   thumbnail_record: ImageRecord,
   content_records: Vec<(PostContentRecord, AnyContentBlockRecord)>,
+  footnote_records: Vec<FootnoteRecordWithRelations>,
 ) -> Result<BlogPostEntity> {
   let mut blog_post = BlogPostEntity::new(blog_post_record.id, blog_post_record.title);
 
   // サムネイル画像を設定
-  blog_post.set_thumbnail(thumbnail_record.id, thumbnail_record.file_path);
+  blog_post.set_thumbnail_image(convert_to_image_entity(thumbnail_record));
 
   // 投稿日と最終更新日、公開日を設定
   blog_post.set_post_date(JstDate::from_jst_naive_date(blog_post_record.post_date));
   blog_post.set_last_update_date(JstDate::from_jst_naive_date(blog_post_record.last_update_date));
   blog_post.set_published_date(JstDate::from_jst_naive_date(blog_post_record.published_at));
+  blog_post.set_manual_excerpt(blog_post_record.excerpt).context("抜粋が不正です")?;
+
+  // 折りたたみブロックの子コンテンツを親ごとにまとめ、トップレベルのコンテンツから順番通りに変換・追加
+  let mut top_level_contents = Vec::new();
+  let mut children_by_parent: HashMap<Uuid, Vec<(PostContentRecord, AnyContentBlockRecord)>> = HashMap::new();
+  for (post_content, content_block) in content_records {
+    match post_content.parent_id {
+      Some(parent_id) => children_by_parent.entry(parent_id).or_default().push((post_content, content_block)),
+      None => top_level_contents.push((post_content, content_block)),
+    }
+  }
 
-  // コンテンツを順番通りに変換・追加
-  let mut sorted_contents = content_records;
-  sorted_contents.sort_by_key(|(post_content, _)| post_content.sort_order);
-
-  for (_, content_block) in sorted_contents {
-    let content_entity = convert_content_block_to_entity(content_block).context("コンテンツブロックの変換に失敗しました")?;
+  for content_entity in convert_sibling_records_to_entities(top_level_contents, &mut children_by_parent)? {
     blog_post.add_content(content_entity);
   }
+  if !children_by_parent.is_empty() {
+    anyhow::bail!("親のコンテンツが存在しない子コンテンツがあります");
+  }
+
+  // 脚注は本文中の参照と突き合わせて設定する（番号も本文から振り直される）
+  let footnotes = footnote_records.into_iter().map(convert_footnote_record_to_entity).collect::<Result<Vec<_>>>()?;
+  blog_post.set_footnotes(footnotes).context("脚注の参照と定義が対応していません")?;
 
   Ok(blog_post)
 }
 
+/// SeoMetadataRecordWithRelationsからSeoMetadataVOに変換する（レコードがない場合は未設定）
+pub fn convert_to_seo_metadata_vo(record: Option<SeoMetadataRecordWithRelations>) -> Result<SeoMetadataVO> {
+  let Some(record) = record else {
+    return Ok(SeoMetadataVO::default());
+  };
+
+  SeoMetadataVO::new(
+    record.seo_metadata_record.description,
+    record.og_image_record.map(convert_to_image_entity),
+    record.seo_metadata_record.canonical_url,
+    record.seo_metadata_record.noindex,
+  )
+  .context("SEO メタデータが不正です")
+}
+
+/// TermVectorRecordからPostTermVectorVOに変換する（回数が負の語は壊れたデータとして読み飛ばす）
+pub fn convert_to_post_term_vector_vo(record: TermVectorRecord) -> PostTermVectorVO {
+  PostTermVectorVO {
+    id: record.post_id,
+    terms: record.terms.0.into_iter().filter_map(|(term, count)| u32::try_from(count).ok().map(|count| (term, count))).collect(),
+  }
+}
+
+/// FootnoteRecordWithRelationsからFootnoteEntityに変換する
+fn convert_footnote_record_to_entity(footnote_record: FootnoteRecordWithRelations) -> Result<FootnoteEntity> {
+  let rich_text_parts =
+    convert_rich_text_records_to_parts(footnote_record.rich_text_records_with_relations).context("脚注のリッチテキストの変換に失敗しました")?;
+  FootnoteEntity::new(footnote_record.footnote.id, RichTextVO::new(rich_text_parts)).context("脚注の本文が不正です")
+}
+
+/// 同じ親を持つコンテンツのレコードを並び順に ContentEntity へ変換する
+fn convert_sibling_records_to_entities(
+  mut siblings: Vec<(PostContentRecord, AnyContentBlockRecord)>,
+  children_by_parent: &mut HashMap<Uuid, Vec<(PostContentRecord, AnyContentBlockRecord)>>,
+) -> Result<Vec<ContentEntity>> {
+  siblings.sort_by_key(|(post_content, _)| post_content.sort_order);
+
+  let mut entities = Vec::with_capacity(siblings.len());
+  for (post_content, content_block) in siblings {
+    let content_entity = match content_block {
+      AnyContentBlockRecord::DetailsBlockRecord(details_block) => {
+        let child_records = children_by_parent.remove(&post_content.id).unwrap_or_default();
+        let children = convert_sibling_records_to_entities(child_records, children_by_parent)?;
+        convert_details_block_to_content_entity(details_block, children)
+      }
+      other => convert_content_block_to_entity(other),
+    }
+    .context("コンテンツブロックの変換に失敗しました")?;
+    entities.push(content_entity);
+  }
+
+  Ok(entities)
+}
+
 /// AnyContentBlockRecordからContentEntityに変換する
 fn convert_content_block_to_entity(content_block: AnyContentBlockRecord) -> Result<ContentEntity> {
   match content_block {
@@ -58,6 +137,11 @@ fn convert_content_block_to_entity(content_block: AnyContentBlockRecord) -> Resu
     AnyContentBlockRecord::ParagraphBlockRecord(paragraph) => convert_paragraph_to_content_entity(paragraph),
     AnyContentBlockRecord::ImageBlockRecord(image_block) => convert_image_block_to_content_entity(image_block),
     AnyContentBlockRecord::CodeBlockRecord(code_block) => convert_code_block_to_content_entity(code_block),
+    AnyContentBlockRecord::MathBlockRecord(math_block) => convert_math_block_to_content_entity(math_block),
+    AnyContentBlockRecord::EmbedBlockRecord(embed_block) => convert_embed_block_to_content_entity(embed_block),
+    AnyContentBlockRecord::LinkCardBlockRecord(link_card_block) => convert_link_card_block_to_content_entity(link_card_block),
+    AnyContentBlockRecord::DetailsBlockRecord(_) => anyhow::bail!("折りたたみブロックは子コンテンツと合わせて変換する必要があります"),
+    AnyContentBlockRecord::DiagramBlockRecord(diagram_block) => convert_diagram_block_to_content_entity(diagram_block),
   }
 }
 
@@ -81,10 +165,16 @@ fn convert_paragraph_to_content_entity(paragraph: ParagraphBlockRecordWithRelati
 /// ImageBlockRecordWithRelationsからContentEntityに変換する
 fn convert_image_block_to_content_entity(image_block: ImageBlockRecordWithRelations) -> Result<ContentEntity> {
   // ImageEntityを作成
-  let image_entity = ImageEntity::new(image_block.image_record.id, image_block.image_record.file_path);
+  let image_entity = convert_to_image_entity(image_block.image_record);
+
+  // 記事内での見せ方を復元
+  let block_record = image_block.image_block_record;
+  let width = block_record.width.map(u32::try_from).transpose().context("画像の表示幅が不正です")?;
+  let presentation =
+    ImagePresentationVO::new(block_record.alt, block_record.caption, width, block_record.link_url).context("画像ブロックの表示設定が不正です")?;
 
   // ImageContentEntityを作成
-  let image_content_entity = ImageContentEntity::new(image_block.image_block_record.id, image_entity);
+  let image_content_entity = ImageContentEntity::new(block_record.id, image_entity).with_presentation(presentation);
 
   // ContentEntityとして返す
   Ok(ContentEntity::image_from_entity(image_content_entity))
@@ -92,7 +182,45 @@ fn convert_image_block_to_content_entity(image_block: ImageBlockRecordWithRelati
 
 /// CodeBlockRecordからContentEntityに変換する
 fn convert_code_block_to_content_entity(code_block: CodeBlockRecord) -> Result<ContentEntity> {
-  Ok(ContentEntity::code_block(code_block.id, code_block.title, code_block.code, code_block.language))
+  let start_line = u32::try_from(code_block.start_line).context("コードブロックの開始行番号が不正です")?;
+  let options = CodeBlockOptionsVO::new(&code_block.highlight_lines, start_line, code_block.diff, code_block.show_line_numbers)
+    .context("コードブロックの表示オプションが不正です")?;
+  ContentEntity::restore_code_block(code_block.id, code_block.title, code_block.code, code_block.language, options)
+    .context("コードブロックの強調表示行がコードの行数を超えています")
+}
+
+/// MathBlockRecordからContentEntityに変換する
+fn convert_math_block_to_content_entity(math_block: MathBlockRecord) -> Result<ContentEntity> {
+  ContentEntity::math_block(math_block.id, math_block.source).context("数式ブロックの LaTeX ソースが不正です")
+}
+
+/// EmbedBlockRecordからContentEntityに変換する
+fn convert_embed_block_to_content_entity(embed_block: EmbedBlockRecord) -> Result<ContentEntity> {
+  let provider =
+    EmbedProvider::parse(&embed_block.provider).ok_or_else(|| anyhow::anyhow!("サポートされていない埋め込みプロバイダ: {}", embed_block.provider))?;
+  ContentEntity::embed(embed_block.id, provider, embed_block.resource_id).context("埋め込みブロックのリソース ID が不正です")
+}
+
+/// DiagramBlockRecordからContentEntityに変換する
+fn convert_diagram_block_to_content_entity(diagram_block: DiagramBlockRecord) -> Result<ContentEntity> {
+  let kind = DiagramKind::parse(&diagram_block.kind).ok_or_else(|| anyhow::anyhow!("サポートされていない図の種類: {}", diagram_block.kind))?;
+  ContentEntity::diagram(diagram_block.id, kind, diagram_block.source).context("図ブロックのソースが不正です")
+}
+
+/// LinkCardBlockRecordからContentEntityに変換する
+fn convert_link_card_block_to_content_entity(link_card_block: LinkCardBlockRecord) -> Result<ContentEntity> {
+  let metadata = OgpMetadataVO {
+    title: link_card_block.title,
+    description: link_card_block.description,
+    site_name: link_card_block.site_name,
+    image_url: link_card_block.image_url,
+  };
+  ContentEntity::link_card(link_card_block.id, link_card_block.url, metadata).context("リンクカードブロックの URL が不正です")
+}
+
+/// DetailsBlockRecordと変換済みの子コンテンツからContentEntityに変換する
+fn convert_details_block_to_content_entity(details_block: DetailsBlockRecord, children: Vec<ContentEntity>) -> Result<ContentEntity> {
+  ContentEntity::details(details_block.id, details_block.summary, children).context("折りたたみブロックの見出し行や子コンテンツが不正です")
 }
 
 /// RichTextRecordWithRelationsのベクターからRichTextPartVOのベクターに変換する
@@ -116,9 +244,22 @@ fn convert_rich_text_record_to_part(rich_text_record: RichTextRecordWithRelation
   let styles = convert_styles_to_vo(&rich_text_record.style_records);
 
   // リンクを変換
-  let link = rich_text_record.link_record.map(|link_record| LinkVO { url: link_record.url });
-
-  Ok(RichTextPartVO::new(rich_text_record.text_record.text_content, Some(styles), link))
+  // 内部リンクの見出しのアンカーはリンク先の見出しの現在のテキストから作る
+  let link = rich_text_record.link_record.map(|link_record| {
+    LinkVO::new(link_record.url, link_record.target_post_id, link_record.target_heading_id).with_heading_text(link_record.target_heading_text)
+  });
+
+  let mut part = RichTextPartVO::new(rich_text_record.text_record.text_content, Some(styles), link);
+  if rich_text_record.style_records.iter().any(|style_record| style_record.style_type == INLINE_MATH_STYLE) {
+    part = part.with_inline_math()?;
+  }
+  if let Some(ruby_record) = rich_text_record.ruby_record {
+    part = part.with_ruby(RubyVO::new(ruby_record.ruby)?)?;
+  }
+  if let Some(footnote_ref_record) = rich_text_record.footnote_ref_record {
+    part = part.with_footnote_ref(footnote_ref_record.footnote_id);
+  }
+  Ok(part)
 }
 
 /// TextStyleRecordのベクターからRichTextStylesVOに変換する
@@ -129,6 +270,12 @@ fn convert_styles_to_vo(style_records: &[super::tables::TextStyleRecord]) -> Ric
     match style_record.style_type.as_str() {
       "bold" => styles.bold = true,
       "inline-code" => styles.inline_code = true,
+      "italic" => styles.italic = true,
+      "strikethrough" => styles.strikethrough = true,
+      "underline" => styles.underline = true,
+      style_type if style_type.starts_with(HIGHLIGHT_STYLE_PREFIX) => {
+        styles.highlight = HighlightColor::parse(&style_type[HIGHLIGHT_STYLE_PREFIX.len()..]);
+      }
       _ => {
         // 未知のスタイルは無視（ログ出力など考慮）
       }
@@ -142,6 +289,7 @@ fn convert_styles_to_vo(style_records: &[super::tables::TextStyleRecord]) -> Ric
 mod tests {
   use uuid::Uuid;
 
+  use super::super::tables::ImageBlockRecord;
   use super::*;
 
   #[test]
@@ -163,6 +311,25 @@ mod tests {
     assert!(styles.inline_code);
   }
 
+  #[test]
+  fn test_convert_decoration_styles_to_vo() {
+    let style_records: Vec<_> = ["italic", "strikethrough", "underline", "highlight-green"]
+      .into_iter()
+      .map(|style_type| super::super::tables::TextStyleRecord {
+        id: Uuid::new_v4(),
+        style_type: style_type.to_string(),
+      })
+      .collect();
+
+    let styles = convert_styles_to_vo(&style_records);
+
+    assert!(styles.italic);
+    assert!(styles.strikethrough);
+    assert!(styles.underline);
+    assert_eq!(styles.highlight, Some(HighlightColor::Green));
+    assert!(!styles.bold);
+  }
+
   #[test]
   fn test_convert_heading_to_content_entity() {
     let heading_id = Uuid::new_v4();
@@ -192,6 +359,10 @@ mod tests {
       title: "サンプルコード".to_string(),
       code: "console.log('Hello');".to_string(),
       language: "javascript".to_string(),
+      highlight_lines: "1".to_string(),
+      start_line: 1,
+      diff: false,
+      show_line_numbers: true,
     };
 
     let result = convert_code_block_to_content_entity(code_block);
@@ -203,10 +374,196 @@ mod tests {
         assert_eq!(code_entity.get_title(), "サンプルコード");
         assert_eq!(code_entity.get_code(), "console.log('Hello');");
         assert_eq!(code_entity.get_language(), "javascript");
+        assert_eq!(code_entity.get_options().highlight_lines_to_string(), "1");
+        assert!(code_entity.get_options().shows_line_numbers());
       }
       _ => panic!("期待されるコンテンツタイプはCodeBlockです"),
     }
   }
+
+  #[test]
+  fn test_convert_math_block_to_content_entity() {
+    let math_block_id = Uuid::new_v4();
+    let math_block = MathBlockRecord {
+      id: math_block_id,
+      source: r"\int_0^1 x^2 \, dx".to_string(),
+    };
+
+    let result = convert_math_block_to_content_entity(math_block);
+
+    match result.unwrap() {
+      ContentEntity::Math(math_entity) => {
+        assert_eq!(math_entity.get_id(), math_block_id);
+        assert_eq!(math_entity.get_source(), r"\int_0^1 x^2 \, dx");
+      }
+      _ => panic!("期待されるコンテンツタイプはMathです"),
+    }
+  }
+
+  #[test]
+  fn test_convert_embed_block_to_content_entity() {
+    let embed_block_id = Uuid::new_v4();
+    let embed_block = EmbedBlockRecord {
+      id: embed_block_id,
+      provider: "gist".to_string(),
+      resource_id: "octocat/6cad326836d38bd3a7ae".to_string(),
+    };
+
+    match convert_embed_block_to_content_entity(embed_block).unwrap() {
+      ContentEntity::Embed(embed_entity) => {
+        assert_eq!(embed_entity.get_id(), embed_block_id);
+        assert_eq!(embed_entity.get_provider(), EmbedProvider::GitHubGist);
+        assert_eq!(embed_entity.get_resource_id(), "octocat/6cad326836d38bd3a7ae");
+      }
+      _ => panic!("期待されるコンテンツタイプはEmbedです"),
+    }
+  }
+
+  #[test]
+  fn test_convert_diagram_block_to_content_entity() {
+    let diagram_block_id = Uuid::new_v4();
+    let diagram_block = DiagramBlockRecord {
+      id: diagram_block_id,
+      kind: "mermaid".to_string(),
+      source: "sequenceDiagram\n  A->>B: hello".to_string(),
+    };
+
+    match convert_diagram_block_to_content_entity(diagram_block).unwrap() {
+      ContentEntity::Diagram(diagram_entity) => {
+        assert_eq!(diagram_entity.get_id(), diagram_block_id);
+        assert_eq!(diagram_entity.get_kind(), DiagramKind::Mermaid);
+        assert_eq!(diagram_entity.get_source(), "sequenceDiagram\n  A->>B: hello");
+      }
+      _ => panic!("期待されるコンテンツタイプはDiagramです"),
+    }
+  }
+
+  #[test]
+  fn test_convert_embed_block_with_unknown_provider_fails() {
+    let embed_block = EmbedBlockRecord {
+      id: Uuid::new_v4(),
+      provider: "vimeo".to_string(),
+      resource_id: "12345".to_string(),
+    };
+
+    assert!(convert_embed_block_to_content_entity(embed_block).is_err());
+  }
+
+  #[test]
+  fn test_convert_link_card_block_to_content_entity() {
+    let link_card_block_id = Uuid::new_v4();
+    let link_card_block = LinkCardBlockRecord {
+      id: link_card_block_id,
+      url: "https://example.com/article".to_string(),
+      title: Some("記事タイトル".to_string()),
+      description: None,
+      site_name: Some("Example".to_string()),
+      image_url: Some("https://example.com/ogp.png".to_string()),
+    };
+
+    match convert_link_card_block_to_content_entity(link_card_block).unwrap() {
+      ContentEntity::LinkCard(link_card_entity) => {
+        assert_eq!(link_card_entity.get_id(), link_card_block_id);
+        assert_eq!(link_card_entity.get_url(), "https://example.com/article");
+        assert_eq!(link_card_entity.get_metadata().title.as_deref(), Some("記事タイトル"));
+        assert_eq!(link_card_entity.get_metadata().description, None);
+        assert_eq!(link_card_entity.get_metadata().image_url.as_deref(), Some("https://example.com/ogp.png"));
+      }
+      _ => panic!("期待されるコンテンツタイプはLinkCardです"),
+    }
+  }
+
+  #[test]
+  fn test_convert_details_block_with_children_in_sort_order() {
+    let details_id = Uuid::new_v4();
+    let post_content = |id: Uuid, content_type: &str, parent_id: Option<Uuid>, sort_order: i32| PostContentRecord {
+      id,
+      post_id: Uuid::new_v4(),
+      content_type: content_type.to_string(),
+      parent_id,
+      sort_order,
+    };
+    let math_block = |id: Uuid, source: &str| {
+      AnyContentBlockRecord::MathBlockRecord(MathBlockRecord {
+        id,
+        source: source.to_string(),
+      })
+    };
+
+    let (first_child_id, second_child_id) = (Uuid::new_v4(), Uuid::new_v4());
+    let top_level = vec![(
+      post_content(details_id, "details", None, 0),
+      AnyContentBlockRecord::DetailsBlockRecord(DetailsBlockRecord {
+        id: details_id,
+        summary: "補足".to_string(),
+      }),
+    )];
+    let mut children_by_parent = HashMap::from([(
+      details_id,
+      vec![
+        (
+          post_content(second_child_id, "math_block", Some(details_id), 1),
+          math_block(second_child_id, "y"),
+        ),
+        (post_content(first_child_id, "math_block", Some(details_id), 0), math_block(first_child_id, "x")),
+      ],
+    )]);
+
+    let entities = convert_sibling_records_to_entities(top_level, &mut children_by_parent).unwrap();
+
+    assert!(children_by_parent.is_empty());
+    match &entities[0] {
+      ContentEntity::Details(details) => {
+        assert_eq!(details.get_id(), details_id);
+        assert_eq!(details.get_summary(), "補足");
+        let child_ids: Vec<Uuid> = details
+          .get_children()
+          .iter()
+          .map(|child| match child {
+            ContentEntity::Math(math) => math.get_id(),
+            _ => panic!("期待されるコンテンツタイプはMathです"),
+          })
+          .collect();
+        assert_eq!(child_ids, vec![first_child_id, second_child_id]);
+      }
+      _ => panic!("期待されるコンテンツタイプはDetailsです"),
+    }
+  }
+
+  #[test]
+  fn test_convert_image_block_to_content_entity() {
+    let image_block_id = Uuid::new_v4();
+    let image_id = Uuid::new_v4();
+    let image_block = ImageBlockRecordWithRelations {
+      image_block_record: ImageBlockRecord {
+        id: image_block_id,
+        image_id,
+        alt: "構成図".to_string(),
+        caption: Some("全体の構成".to_string()),
+        width: Some(640),
+        link_url: Some("/posts/abc".to_string()),
+      },
+      image_record: ImageRecord {
+        id: image_id,
+        file_path: "images/diagram.png".to_string(),
+        file_name: Some("diagram.png".to_string()),
+        caption: Some("アップロード時のキャプション".to_string()),
+      },
+    };
+
+    match convert_image_block_to_content_entity(image_block).unwrap() {
+      ContentEntity::Image(image_content) => {
+        assert_eq!(image_content.get_id(), image_block_id);
+        assert_eq!(image_content.get_path(), "images/diagram.png");
+        assert_eq!(image_content.get_image().get_file_name(), Some("diagram.png"));
+        assert_eq!(image_content.get_presentation().get_alt(), "構成図");
+        assert_eq!(image_content.get_presentation().get_caption(), Some("全体の構成"));
+        assert_eq!(image_content.get_presentation().get_width(), Some(640));
+        assert_eq!(image_content.get_presentation().get_link_url(), Some("/posts/abc"));
+      }
+      _ => panic!("期待されるコンテンツタイプはImageです"),
+    }
+  }
 }
 
 /// PopularPostRecordのVecからPopularPostSetEntityに変換する（記事取得には外部リポジトリが必要）
@@ -0,0 +1,82 @@
+use anyhow::{Context, Result};
+
+use uuid::Uuid;
+
+use super::tables::blog_posts_table::fetch_page_view_target_record_by_id;
+use super::tables::page_views_table::{delete_page_view_visitors_before, fetch_published_view_count_records, insert_page_view, PageViewVisitorRecord};
+use super::tables::popular_post_settings_table::{fetch_popular_post_setting, upsert_popular_post_setting, PopularPostSettingRecord};
+use super::BlogPostSqlxRepository;
+use crate::domain::{
+  blog_domain::{
+    jst_date_vo::JstDate,
+    page_view_repository::{PageViewRepository, PageViewTargetVO, PageViewVO, PostViewCountVO},
+    popular_post_setting_repository::{PopularPostMode, PopularPostSettingRepository},
+  },
+  image_domain::image_repository::ImageRepository,
+};
+
+#[async_trait::async_trait]
+impl<I: ImageRepository + Send + Sync> PageViewRepository for BlogPostSqlxRepository<I> {
+  async fn find_page_view_target(&self, id: &str) -> Result<PageViewTargetVO> {
+    let post_id = Uuid::parse_str(id).context("無効なUUID形式のIDです")?;
+
+    let record = fetch_page_view_target_record_by_id(&self.pool, post_id)
+      .await
+      .context("ブログ記事の取得に失敗しました")?
+      .ok_or_else(|| anyhow::anyhow!("BlogPostNotFound:{}", id))?;
+
+    Ok(PageViewTargetVO {
+      post_id: record.id,
+      title: record.title,
+      published_date: JstDate::from_jst_naive_date(record.published_at),
+    })
+  }
+
+  async fn record_page_view(&self, page_view: &PageViewVO) -> Result<bool> {
+    let mut tx = self.pool.begin().await.context("トランザクションの開始に失敗しました")?;
+
+    let record = PageViewVisitorRecord {
+      post_id: page_view.post_id,
+      viewed_on: page_view.viewed_on.to_naive_date(),
+      visitor_hash: page_view.visitor_hash.clone(),
+    };
+    let counted = insert_page_view(&mut tx, record).await.context("閲覧の記録に失敗しました")?;
+
+    tx.commit().await.context("トランザクションのコミットに失敗しました")?;
+
+    Ok(counted)
+  }
+
+  async fn find_published_view_counts(&self, since: &JstDate, until: &JstDate) -> Result<Vec<PostViewCountVO>> {
+    let records = fetch_published_view_count_records(&self.pool, since.to_naive_date(), until.to_naive_date()).await.context("閲覧数の取得に失敗しました")?;
+
+    Ok(
+      records
+        .into_iter()
+        .map(|record| PostViewCountVO {
+          post_id: record.post_id,
+          views: record.views.max(0) as u64,
+        })
+        .collect(),
+    )
+  }
+
+  async fn delete_visitor_hashes_before(&self, date: &JstDate) -> Result<u64> {
+    delete_page_view_visitors_before(&self.pool, date.to_naive_date()).await.context("閲覧者のハッシュの削除に失敗しました")
+  }
+}
+
+#[async_trait::async_trait]
+impl<I: ImageRepository + Send + Sync> PopularPostSettingRepository for BlogPostSqlxRepository<I> {
+  async fn find_mode(&self) -> Result<PopularPostMode> {
+    let record = fetch_popular_post_setting(&self.pool).await.context("人気記事の設定の取得に失敗しました")?;
+    PopularPostMode::parse(&record.mode).with_context(|| format!("未知の人気記事の選び方です: {}", record.mode))
+  }
+
+  async fn save_mode(&self, mode: PopularPostMode) -> Result<()> {
+    let record = PopularPostSettingRecord {
+      mode: mode.as_str().to_string(),
+    };
+    upsert_popular_post_setting(&self.pool, record).await.context("人気記事の設定の保存に失敗しました")
+  }
+}
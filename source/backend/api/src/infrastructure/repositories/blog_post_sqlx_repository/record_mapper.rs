@@ -1,19 +1,28 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use uuid::Uuid;
 
 use crate::{
   domain::blog_domain::{
-    blog_post_entity::{content_entity::ContentEntity, rich_text_vo::RichTextPartVO, BlogPostEntity},
+    blog_post_entity::{
+      content_entity::ContentEntity,
+      rich_text_vo::{LinkVO, RichTextPartVO},
+      BlogPostEntity,
+    },
     pick_up_post_set_entity::PickUpPostSetEntity,
     popular_post_set_entity::PopularPostSetEntity,
+    related_post_repository::PostTermVectorVO,
   },
   infrastructure::repositories::image_sqlx_repository::ImageRecord,
 };
 
 use super::tables::{
-  pickup_posts_table::PickUpPostRecord, popular_posts_table::PopularPostRecord, AnyContentBlockRecord, BlogPostRecord, CodeBlockRecord, HeadingBlockRecord,
-  ImageBlockRecord, ImageBlockRecordWithRelations, ParagraphBlockRecord, ParagraphBlockRecordWithRelations, PostContentRecord, PostContentType,
-  RichTextLinkRecord, RichTextRecord, RichTextRecordWithRelations, TextStyleRecord,
+  paragraph_blocks_table::{HIGHLIGHT_STYLE_PREFIX, INLINE_MATH_STYLE},
+  pickup_posts_table::PickUpPostRecord,
+  popular_posts_table::PopularPostRecord,
+  AnyContentBlockRecord, BlogPostRecord, CodeBlockRecord, DetailsBlockRecord, DiagramBlockRecord, EmbedBlockRecord, FootnoteRecord,
+  FootnoteRecordWithRelations, HeadingBlockRecord, ImageBlockRecord, ImageBlockRecordWithRelations, LinkCardBlockRecord, MathBlockRecord, ParagraphBlockRecord,
+  ParagraphBlockRecordWithRelations, PostContentRecord, PostContentType, RichTextFootnoteRefRecord, RichTextLinkRecord, RichTextRecord,
+  RichTextRecordWithRelations, RichTextRubyRecord, SeoMetadataRecord, TermVectorRecord, TextStyleRecord,
 };
 
 /// BlogPostEntityからBlogPostRecordとその関連データに分解する
@@ -29,25 +38,46 @@ pub fn convert_from_blog_post_entity(entity: &BlogPostEntity) -> Result<(BlogPos
     post_date: entity.get_post_date().to_naive_date(),
     last_update_date: entity.get_last_update_date().to_naive_date(),
     published_at: entity.get_published_date().to_naive_date(),
+    excerpt: entity.get_manual_excerpt().map(str::to_string),
   };
 
   // ContentRecordを作成
   let mut content_records = Vec::new();
-  for (index, content) in entity.get_contents().iter().enumerate() {
+  append_content_records(&mut content_records, entity.get_id(), None, entity.get_contents())?;
+
+  Ok((blog_post_record, content_records))
+}
+
+/// 同じ親を持つコンテンツをレコードに変換して追加する
+///
+/// 折りたたみブロックの子コンテンツは親の直後に並べ、先頭から順に挿入すれば
+/// 親のレコードが子より先に存在するようにする。
+fn append_content_records(
+  records: &mut Vec<(PostContentRecord, AnyContentBlockRecord)>,
+  post_id: Uuid,
+  parent_id: Option<Uuid>,
+  contents: &[ContentEntity],
+) -> Result<()> {
+  for (index, content) in contents.iter().enumerate() {
     // コンテンツエンティティのIDを使用してpost_contentのIDとする
     let content_id = get_content_id_from_entity(content);
     let post_content_record = PostContentRecord {
       id: content_id,
-      post_id: entity.get_id(),
+      post_id,
       content_type: String::from(get_content_type_from_entity(content)),
+      parent_id,
       sort_order: index as i32,
     };
 
     let content_block_record = convert_content_entity_to_block_record(content, content_id)?;
-    content_records.push((post_content_record, content_block_record));
+    records.push((post_content_record, content_block_record));
+
+    if let ContentEntity::Details(details_block) = content {
+      append_content_records(records, post_id, Some(content_id), details_block.get_children())?;
+    }
   }
 
-  Ok((blog_post_record, content_records))
+  Ok(())
 }
 
 /// ContentEntityからIDを取得する
@@ -58,6 +88,11 @@ fn get_content_id_from_entity(content: &ContentEntity) -> Uuid {
     ContentEntity::Paragraph(paragraph) => paragraph.get_id(),
     ContentEntity::Image(image) => image.get_id(),
     ContentEntity::CodeBlock(code_block) => code_block.get_id(),
+    ContentEntity::Math(math_block) => math_block.get_id(),
+    ContentEntity::Embed(embed_block) => embed_block.get_id(),
+    ContentEntity::LinkCard(link_card_block) => link_card_block.get_id(),
+    ContentEntity::Details(details_block) => details_block.get_id(),
+    ContentEntity::Diagram(diagram_block) => diagram_block.get_id(),
   }
 }
 
@@ -68,6 +103,11 @@ fn get_content_type_from_entity(content: &ContentEntity) -> PostContentType {
     ContentEntity::Paragraph(_) => PostContentType::Paragraph,
     ContentEntity::Image(_) => PostContentType::Image,
     ContentEntity::CodeBlock(_) => PostContentType::CodeBlock,
+    ContentEntity::Math(_) => PostContentType::Math,
+    ContentEntity::Embed(_) => PostContentType::Embed,
+    ContentEntity::LinkCard(_) => PostContentType::LinkCard,
+    ContentEntity::Details(_) => PostContentType::Details,
+    ContentEntity::Diagram(_) => PostContentType::Diagram,
   }
 }
 
@@ -87,7 +127,7 @@ fn convert_content_entity_to_block_record(content: &ContentEntity, content_id: U
     ContentEntity::Paragraph(paragraph) => {
       let paragraph_block_record = ParagraphBlockRecord { id: content_id };
 
-      let rich_text_records = convert_rich_text_vo_to_records(paragraph.get_value(), content_id)?;
+      let rich_text_records = convert_rich_text_vo_to_records(paragraph.get_value(), RichTextOwner::ParagraphBlock(content_id))?;
 
       Ok(AnyContentBlockRecord::ParagraphBlockRecord(ParagraphBlockRecordWithRelations {
         paragraph_block: paragraph_block_record,
@@ -99,11 +139,18 @@ fn convert_content_entity_to_block_record(content: &ContentEntity, content_id: U
       let image_record = ImageRecord {
         id: image_record_id, // 画像コンテンツのIDを画像レコードのIDとして使用
         file_path: image_content.get_path().to_string(),
+        file_name: image_content.get_image().get_file_name().map(str::to_string),
+        caption: image_content.get_image().get_caption().map(str::to_string),
       };
 
+      let presentation = image_content.get_presentation();
       let image_block_record = ImageBlockRecord {
         id: content_id, // post_contentのIDを使用
         image_id: image_record_id,
+        alt: presentation.get_alt().to_string(),
+        caption: presentation.get_caption().map(str::to_string),
+        width: presentation.get_width().map(i32::try_from).transpose().context("画像の表示幅が範囲外です")?,
+        link_url: presentation.get_link_url().map(str::to_string),
       };
 
       Ok(AnyContentBlockRecord::ImageBlockRecord(ImageBlockRecordWithRelations {
@@ -111,24 +158,111 @@ fn convert_content_entity_to_block_record(content: &ContentEntity, content_id: U
         image_record,
       }))
     }
-    ContentEntity::CodeBlock(code_block) => Ok(AnyContentBlockRecord::CodeBlockRecord(CodeBlockRecord {
+    ContentEntity::CodeBlock(code_block) => {
+      let options = code_block.get_options();
+      Ok(AnyContentBlockRecord::CodeBlockRecord(CodeBlockRecord {
+        id: content_id,
+        title: code_block.get_title().to_string(),
+        code: code_block.get_code().to_string(),
+        language: code_block.get_language().to_string(),
+        highlight_lines: options.highlight_lines_to_string(),
+        start_line: i32::try_from(options.get_start_line()).context("コードブロックの開始行番号が範囲外です")?,
+        diff: options.is_diff(),
+        show_line_numbers: options.shows_line_numbers(),
+      }))
+    }
+    ContentEntity::Math(math_block) => Ok(AnyContentBlockRecord::MathBlockRecord(MathBlockRecord {
       id: content_id,
-      title: code_block.get_title().to_string(),
-      code: code_block.get_code().to_string(),
-      language: code_block.get_language().to_string(),
+      source: math_block.get_source().to_string(),
+    })),
+    ContentEntity::Embed(embed_block) => Ok(AnyContentBlockRecord::EmbedBlockRecord(EmbedBlockRecord {
+      id: content_id,
+      provider: embed_block.get_provider().as_str().to_string(),
+      resource_id: embed_block.get_resource_id().to_string(),
+    })),
+    ContentEntity::LinkCard(link_card_block) => {
+      let metadata = link_card_block.get_metadata();
+      Ok(AnyContentBlockRecord::LinkCardBlockRecord(LinkCardBlockRecord {
+        id: content_id,
+        url: link_card_block.get_url().to_string(),
+        title: metadata.title.clone(),
+        description: metadata.description.clone(),
+        site_name: metadata.site_name.clone(),
+        image_url: metadata.image_url.clone(),
+      }))
+    }
+    // 子コンテンツは append_content_records で別のレコードとして追加する
+    ContentEntity::Details(details_block) => Ok(AnyContentBlockRecord::DetailsBlockRecord(DetailsBlockRecord {
+      id: content_id,
+      summary: details_block.get_summary().to_string(),
+    })),
+    ContentEntity::Diagram(diagram_block) => Ok(AnyContentBlockRecord::DiagramBlockRecord(DiagramBlockRecord {
+      id: content_id,
+      kind: diagram_block.get_kind().as_str().to_string(),
+      source: diagram_block.get_source().to_string(),
     })),
   }
 }
 
+/// BlogPostEntityの脚注をFootnoteRecordWithRelationsのベクターに変換する
+pub fn convert_footnotes_from_blog_post_entity(entity: &BlogPostEntity) -> Result<Vec<FootnoteRecordWithRelations>> {
+  let mut records = Vec::new();
+
+  for footnote in entity.get_footnotes() {
+    let rich_text_records = convert_rich_text_vo_to_records(footnote.get_body(), RichTextOwner::Footnote(footnote.get_id()))?;
+    records.push(FootnoteRecordWithRelations {
+      footnote: FootnoteRecord {
+        id: footnote.get_id(),
+        post_id: entity.get_id(),
+        number: footnote.get_number() as i32,
+      },
+      rich_text_records_with_relations: rich_text_records,
+    });
+  }
+
+  Ok(records)
+}
+
+/// BlogPostEntityのSEOメタデータをSeoMetadataRecordに変換する（未設定の場合は行を作らないため None）
+pub fn convert_seo_metadata_from_blog_post_entity(entity: &BlogPostEntity) -> Option<SeoMetadataRecord> {
+  let seo_metadata = entity.get_seo_metadata();
+  if seo_metadata.is_empty() {
+    return None;
+  }
+
+  Some(SeoMetadataRecord {
+    post_id: entity.get_id(),
+    description: seo_metadata.get_description().map(str::to_string),
+    og_image_id: seo_metadata.get_og_image().map(|og_image| og_image.get_id()),
+    canonical_url: seo_metadata.get_canonical_url().map(str::to_string),
+    noindex: seo_metadata.is_noindex(),
+  })
+}
+
+/// PostTermVectorVOをTermVectorRecordに変換する
+pub fn convert_term_vector_to_record(term_vector: &PostTermVectorVO) -> TermVectorRecord {
+  TermVectorRecord {
+    post_id: term_vector.id,
+    terms: sqlx::types::Json(term_vector.terms.iter().map(|(term, count)| (term.clone(), *count as i32)).collect()),
+  }
+}
+
+/// リッチテキストの所属先
+#[derive(Debug, Clone, Copy)]
+enum RichTextOwner {
+  ParagraphBlock(Uuid),
+  Footnote(Uuid),
+}
+
 /// RichTextVOからRichTextRecordWithRelationsのベクターに変換する
 fn convert_rich_text_vo_to_records(
   rich_text: &crate::domain::blog_domain::blog_post_entity::rich_text_vo::RichTextVO,
-  paragraph_block_id: Uuid,
+  owner: RichTextOwner,
 ) -> Result<Vec<RichTextRecordWithRelations>> {
   let mut records = Vec::new();
 
   for (index, part) in rich_text.get_text().iter().enumerate() {
-    let record = convert_rich_text_part_to_record(part, index, paragraph_block_id)?;
+    let record = convert_rich_text_part_to_record(part, index, owner)?;
     records.push(record);
   }
 
@@ -136,31 +270,67 @@ fn convert_rich_text_vo_to_records(
 }
 
 /// RichTextPartVOからRichTextRecordWithRelationsに変換する
-fn convert_rich_text_part_to_record(part: &RichTextPartVO, sort_order: usize, paragraph_block_id: Uuid) -> Result<RichTextRecordWithRelations> {
+fn convert_rich_text_part_to_record(part: &RichTextPartVO, sort_order: usize, owner: RichTextOwner) -> Result<RichTextRecordWithRelations> {
+  let (paragraph_block_id, footnote_id) = match owner {
+    RichTextOwner::ParagraphBlock(id) => (Some(id), None),
+    RichTextOwner::Footnote(id) => (None, Some(id)),
+  };
+
   // RichTextRecordはtext_contentを直接持つ
   let rich_text_record = RichTextRecord {
     id: Uuid::new_v4(),
     paragraph_block_id,
+    footnote_id,
     text_content: part.get_text().to_string(),
     sort_order: sort_order as i32,
   };
 
-  let style_records = convert_styles_vo_to_records(part.get_styles());
+  let mut style_records = convert_styles_vo_to_records(part.get_styles());
+  if part.is_inline_math() {
+    style_records.push(TextStyleRecord {
+      id: Uuid::new_v4(),
+      style_type: INLINE_MATH_STYLE.to_string(),
+    });
+  }
 
-  let link_record = if let Some(link) = part.get_link() {
-    Some(RichTextLinkRecord {
+  // 内部リンクは読み出し時に URL を解決するため、リンク先の記事と見出しの ID のみを保存する
+  let link_record = part.get_link().map(|link| match link {
+    LinkVO::External { url } => RichTextLinkRecord {
       id: Uuid::new_v4(),
       rich_text_id: rich_text_record.id,
-      url: link.url.clone(),
-    })
-  } else {
-    None
-  };
+      url: url.clone(),
+      target_post_id: None,
+      target_heading_id: None,
+      target_heading_text: None,
+    },
+    LinkVO::Internal(target) => RichTextLinkRecord {
+      id: Uuid::new_v4(),
+      rich_text_id: rich_text_record.id,
+      url: String::new(),
+      target_post_id: Some(target.get_post_id()),
+      target_heading_id: target.get_heading_id(),
+      target_heading_text: None,
+    },
+  });
+
+  let ruby_record = part.get_ruby().map(|ruby| RichTextRubyRecord {
+    id: Uuid::new_v4(),
+    rich_text_id: rich_text_record.id,
+    ruby: ruby.get_value().to_string(),
+  });
+
+  let footnote_ref_record = part.get_footnote_ref().map(|footnote_id| RichTextFootnoteRefRecord {
+    id: Uuid::new_v4(),
+    rich_text_id: rich_text_record.id,
+    footnote_id,
+  });
 
   Ok(RichTextRecordWithRelations {
     text_record: rich_text_record,
     style_records,
     link_record,
+    ruby_record,
+    footnote_ref_record,
   })
 }
 
@@ -180,6 +350,30 @@ fn convert_styles_vo_to_records(styles: &crate::domain::blog_domain::blog_post_e
       style_type: "inline-code".to_string(),
     });
   }
+  if styles.italic {
+    records.push(TextStyleRecord {
+      id: Uuid::new_v4(),
+      style_type: "italic".to_string(),
+    });
+  }
+  if styles.strikethrough {
+    records.push(TextStyleRecord {
+      id: Uuid::new_v4(),
+      style_type: "strikethrough".to_string(),
+    });
+  }
+  if styles.underline {
+    records.push(TextStyleRecord {
+      id: Uuid::new_v4(),
+      style_type: "underline".to_string(),
+    });
+  }
+  if let Some(highlight) = styles.highlight {
+    records.push(TextStyleRecord {
+      id: Uuid::new_v4(),
+      style_type: format!("{}{}", HIGHLIGHT_STYLE_PREFIX, highlight.as_str()),
+    });
+  }
 
   records
 }
@@ -189,11 +383,19 @@ mod tests {
   use super::*;
   use crate::domain::blog_domain::{
     blog_post_entity::{
-      rich_text_vo::{LinkVO, RichTextStylesVO, RichTextVO},
+      code_block_options_vo::CodeBlockOptionsVO,
+      diagram_source_vo::DiagramKind,
+      embed_block_entity::EmbedProvider,
+      footnote_entity::FootnoteEntity,
+      image_content_entity::ImageContentEntity,
+      image_presentation_vo::ImagePresentationVO,
+      link_card_block_entity::OgpMetadataVO,
+      rich_text_vo::{HighlightColor, LinkVO, RichTextStylesVO, RichTextVO, RubyVO},
       BlogPostEntity,
     },
     jst_date_vo::JstDate,
   };
+  use crate::domain::image_domain::ImageEntity;
   use chrono::NaiveDate;
 
   #[test]
@@ -222,7 +424,7 @@ mod tests {
     styles.bold = true;
     styles.inline_code = true;
 
-    let link = LinkVO {
+    let link = LinkVO::External {
       url: "https://example.com".to_string(),
     };
 
@@ -237,7 +439,9 @@ mod tests {
       "サンプルコード".to_string(),
       "console.log('Hello');".to_string(),
       "javascript".to_string(),
-    );
+      CodeBlockOptionsVO::default(),
+    )
+    .unwrap();
     blog_post.add_content(code_block_content);
 
     // 変換を実行
@@ -316,6 +520,113 @@ mod tests {
     assert!(records.iter().any(|r| r.style_type == "inline-code"));
   }
 
+  #[test]
+  fn test_convert_inline_math_part_to_record() {
+    let part = RichTextPartVO::new("E = mc^2".to_string(), None, None).with_inline_math().unwrap();
+
+    let record = convert_rich_text_part_to_record(&part, 0, RichTextOwner::ParagraphBlock(Uuid::new_v4())).unwrap();
+
+    assert_eq!(record.style_records.len(), 1);
+    assert_eq!(record.style_records[0].style_type, INLINE_MATH_STYLE);
+  }
+
+  #[test]
+  fn test_convert_rich_text_part_with_ruby_to_record() {
+    let paragraph_block_id = Uuid::new_v4();
+    let part = RichTextPartVO::new("精進".to_string(), None, None).with_ruby(RubyVO::new("しょうじん".to_string()).unwrap()).unwrap();
+
+    let record = convert_rich_text_part_to_record(&part, 0, RichTextOwner::ParagraphBlock(paragraph_block_id)).unwrap();
+
+    let ruby_record = record.ruby_record.unwrap();
+    assert_eq!(ruby_record.rich_text_id, record.text_record.id);
+    assert_eq!(ruby_record.ruby, "しょうじん");
+  }
+
+  #[test]
+  fn test_convert_details_block_children_to_records_after_parent() {
+    let details_id = Uuid::new_v4();
+    let child_ids = [Uuid::new_v4(), Uuid::new_v4()];
+    let mut blog_post = BlogPostEntity::new(Uuid::new_v4(), "折りたたみ記事".to_string());
+    blog_post.set_thumbnail(Uuid::new_v4(), "/images/thumbnail.jpg".to_string());
+    blog_post.add_content(
+      ContentEntity::details(
+        details_id,
+        "実行ログ".to_string(),
+        child_ids.iter().map(|id| ContentEntity::math_block(*id, "x".to_string()).unwrap()).collect(),
+      )
+      .unwrap(),
+    );
+    blog_post.add_content(ContentEntity::h2(Uuid::new_v4(), "まとめ".to_string()));
+
+    let (_, content_records) = convert_from_blog_post_entity(&blog_post).unwrap();
+
+    // 親、子、次のトップレベルのコンテンツの順に並ぶ
+    let orders: Vec<(Uuid, Option<Uuid>, i32)> =
+      content_records.iter().map(|(post_content, _)| (post_content.id, post_content.parent_id, post_content.sort_order)).collect();
+    assert_eq!(orders.len(), 4);
+    assert_eq!(orders[0], (details_id, None, 0));
+    assert_eq!(orders[1], (child_ids[0], Some(details_id), 0));
+    assert_eq!(orders[2], (child_ids[1], Some(details_id), 1));
+    assert_eq!((orders[3].1, orders[3].2), (None, 1));
+    match &content_records[0].1 {
+      AnyContentBlockRecord::DetailsBlockRecord(details) => assert_eq!(details.summary, "実行ログ"),
+      _ => panic!("折りたたみブロックではありません"),
+    }
+  }
+
+  #[test]
+  fn test_convert_footnotes_to_records() {
+    let footnote_id = Uuid::new_v4();
+    let mut blog_post = BlogPostEntity::new(Uuid::new_v4(), "脚注記事".to_string());
+    blog_post.set_thumbnail(Uuid::new_v4(), "/images/thumbnail.jpg".to_string());
+    blog_post.add_content(ContentEntity::paragraph(
+      Uuid::new_v4(),
+      RichTextVO::new(vec![RichTextPartVO::new("本文".to_string(), None, None).with_footnote_ref(footnote_id)]),
+    ));
+    blog_post
+      .set_footnotes(vec![FootnoteEntity::new(
+        footnote_id,
+        RichTextVO::new(vec![RichTextPartVO::new("脚注本文".to_string(), None, None)]),
+      )
+      .unwrap()])
+      .unwrap();
+
+    // 本文側は脚注参照のレコードを持つ
+    let (_, content_records) = convert_from_blog_post_entity(&blog_post).unwrap();
+    match &content_records[0].1 {
+      AnyContentBlockRecord::ParagraphBlockRecord(paragraph) => {
+        let footnote_ref = paragraph.rich_text_records_with_relations[0].footnote_ref_record.as_ref().unwrap();
+        assert_eq!(footnote_ref.footnote_id, footnote_id);
+      }
+      _ => panic!("段落ブロックではありません"),
+    }
+
+    // 脚注の本文は脚注に属するリッチテキストとして保存される
+    let footnote_records = convert_footnotes_from_blog_post_entity(&blog_post).unwrap();
+    assert_eq!(footnote_records.len(), 1);
+    assert_eq!(footnote_records[0].footnote.number, 1);
+    let text_record = &footnote_records[0].rich_text_records_with_relations[0].text_record;
+    assert_eq!(text_record.footnote_id, Some(footnote_id));
+    assert_eq!(text_record.paragraph_block_id, None);
+    assert_eq!(text_record.text_content, "脚注本文");
+  }
+
+  #[test]
+  fn test_convert_decoration_styles_to_records() {
+    let styles = RichTextStylesVO {
+      italic: true,
+      strikethrough: true,
+      underline: true,
+      highlight: Some(HighlightColor::Pink),
+      ..Default::default()
+    };
+
+    let records = convert_styles_vo_to_records(&styles);
+    let style_types: Vec<&str> = records.iter().map(|r| r.style_type.as_str()).collect();
+
+    assert_eq!(style_types, vec!["italic", "strikethrough", "underline", "highlight-pink"]);
+  }
+
   #[test]
   fn test_get_content_type_from_entity() {
     let h2_content = ContentEntity::h2(Uuid::new_v4(), "H2".to_string());
@@ -328,8 +639,92 @@ mod tests {
     let paragraph_content = ContentEntity::paragraph(Uuid::new_v4(), rich_text);
     assert_eq!(get_content_type_from_entity(&paragraph_content), PostContentType::Paragraph);
 
-    let code_block_content = ContentEntity::code_block(Uuid::new_v4(), "タイトル".to_string(), "コード".to_string(), "rust".to_string());
+    let code_block_content = ContentEntity::code_block(
+      Uuid::new_v4(),
+      "タイトル".to_string(),
+      "コード".to_string(),
+      "rust".to_string(),
+      CodeBlockOptionsVO::default(),
+    )
+    .unwrap();
     assert_eq!(get_content_type_from_entity(&code_block_content), PostContentType::CodeBlock);
+
+    let math_block_content = ContentEntity::math_block(Uuid::new_v4(), "x^2".to_string()).unwrap();
+    assert_eq!(get_content_type_from_entity(&math_block_content), PostContentType::Math);
+
+    let embed_content = ContentEntity::embed(Uuid::new_v4(), EmbedProvider::YouTube, "dQw4w9WgXcQ".to_string()).unwrap();
+    assert_eq!(get_content_type_from_entity(&embed_content), PostContentType::Embed);
+
+    let link_card_content = ContentEntity::link_card(Uuid::new_v4(), "https://example.com".to_string(), OgpMetadataVO::default()).unwrap();
+    assert_eq!(get_content_type_from_entity(&link_card_content), PostContentType::LinkCard);
+
+    let diagram_content = ContentEntity::diagram(Uuid::new_v4(), DiagramKind::Mermaid, "pie\n  \"A\" : 1".to_string()).unwrap();
+    assert_eq!(get_content_type_from_entity(&diagram_content), PostContentType::Diagram);
+  }
+
+  #[test]
+  fn test_convert_link_card_entity_to_block_record_keeps_metadata() {
+    let content_id = Uuid::new_v4();
+    let metadata = OgpMetadataVO {
+      title: Some("タイトル".to_string()),
+      site_name: Some("Example".to_string()),
+      ..Default::default()
+    };
+    let link_card_content = ContentEntity::link_card(content_id, "https://example.com".to_string(), metadata).unwrap();
+
+    match convert_content_entity_to_block_record(&link_card_content, content_id).unwrap() {
+      AnyContentBlockRecord::LinkCardBlockRecord(record) => {
+        assert_eq!(record.id, content_id);
+        assert_eq!(record.url, "https://example.com");
+        assert_eq!(record.title.as_deref(), Some("タイトル"));
+        assert_eq!(record.description, None);
+        assert_eq!(record.site_name.as_deref(), Some("Example"));
+      }
+      _ => panic!("期待されるレコードはLinkCardBlockRecordです"),
+    }
+  }
+
+  #[test]
+  fn test_convert_code_block_entity_to_block_record_keeps_options() {
+    let content_id = Uuid::new_v4();
+    let options = CodeBlockOptionsVO::new("11", 10, true, true).unwrap();
+    let code_content = ContentEntity::code_block(content_id, "patch.diff".to_string(), "-old\n+new".to_string(), "rust".to_string(), options).unwrap();
+
+    match convert_content_entity_to_block_record(&code_content, content_id).unwrap() {
+      AnyContentBlockRecord::CodeBlockRecord(record) => {
+        assert_eq!(record.highlight_lines, "11");
+        assert_eq!(record.start_line, 10);
+        assert!(record.diff);
+        assert!(record.show_line_numbers);
+      }
+      _ => panic!("期待されるレコードはCodeBlockRecordです"),
+    }
+  }
+
+  #[test]
+  fn test_convert_image_entity_to_block_record_keeps_presentation() {
+    let content_id = Uuid::new_v4();
+    let image = ImageEntity::new(Uuid::new_v4(), "images/diagram.png".to_string());
+    let presentation = ImagePresentationVO::new(
+      "構成図".to_string(),
+      Some("全体の構成".to_string()),
+      Some(640),
+      Some("https://example.com/large.png".to_string()),
+    )
+    .unwrap();
+    let image_content = ContentEntity::image_from_entity(ImageContentEntity::new(content_id, image).with_presentation(presentation));
+
+    match convert_content_entity_to_block_record(&image_content, content_id).unwrap() {
+      AnyContentBlockRecord::ImageBlockRecord(record) => {
+        assert_eq!(record.image_block_record.id, content_id);
+        assert_eq!(record.image_block_record.alt, "構成図");
+        assert_eq!(record.image_block_record.caption.as_deref(), Some("全体の構成"));
+        assert_eq!(record.image_block_record.width, Some(640));
+        assert_eq!(record.image_block_record.link_url.as_deref(), Some("https://example.com/large.png"));
+        assert_eq!(record.image_record.file_path, "images/diagram.png");
+      }
+      _ => panic!("期待されるレコードはImageBlockRecordです"),
+    }
   }
 }
 
@@ -0,0 +1,23 @@
+use anyhow::{Context, Result};
+
+use super::tables::term_vectors_table::{fetch_published_term_vector_records, upsert_term_vector};
+use super::{convert_term_vector_to_record, convert_to_post_term_vector_vo, BlogPostSqlxRepository};
+use crate::domain::{
+  blog_domain::{
+    jst_date_vo::JstDate,
+    related_post_repository::{PostTermVectorVO, RelatedPostRepository},
+  },
+  image_domain::image_repository::ImageRepository,
+};
+
+#[async_trait::async_trait]
+impl<I: ImageRepository + Send + Sync> RelatedPostRepository for BlogPostSqlxRepository<I> {
+  async fn find_published_term_vectors(&self, today: &JstDate) -> Result<Vec<PostTermVectorVO>> {
+    let records = fetch_published_term_vector_records(&self.pool, today.to_naive_date()).await.context("関連記事用の語の出現回数の取得に失敗しました")?;
+    Ok(records.into_iter().map(convert_to_post_term_vector_vo).collect())
+  }
+
+  async fn save_term_vector(&self, term_vector: &PostTermVectorVO) -> Result<()> {
+    upsert_term_vector(&self.pool, convert_term_vector_to_record(term_vector)).await.context("関連記事用の語の出現回数の保存に失敗しました")
+  }
+}
@@ -0,0 +1,35 @@
+use anyhow::{Context, Result};
+
+use super::tables::blog_posts_table::{count_published_blog_posts, fetch_published_sitemap_entry_records};
+use super::BlogPostSqlxRepository;
+use crate::domain::{
+  blog_domain::{
+    jst_date_vo::JstDate,
+    sitemap_repository::{SitemapPostEntryVO, SitemapRepository},
+  },
+  image_domain::image_repository::ImageRepository,
+};
+
+#[async_trait::async_trait]
+impl<I: ImageRepository + Send + Sync> SitemapRepository for BlogPostSqlxRepository<I> {
+  async fn count_published_posts(&self, today: &JstDate) -> Result<u64> {
+    let count = count_published_blog_posts(&self.pool, today.to_naive_date()).await.context("公開済み記事数の取得に失敗しました")?;
+    Ok(count as u64)
+  }
+
+  async fn find_published_post_entries(&self, today: &JstDate, offset: u64, limit: u64) -> Result<Vec<SitemapPostEntryVO>> {
+    let records = fetch_published_sitemap_entry_records(&self.pool, today.to_naive_date(), offset as i64, limit as i64)
+      .await
+      .context("サイトマップ用の記事一覧の取得に失敗しました")?;
+
+    Ok(
+      records
+        .into_iter()
+        .map(|record| SitemapPostEntryVO {
+          id: record.id,
+          last_update_date: JstDate::from_jst_naive_date(record.last_update_date),
+        })
+        .collect(),
+    )
+  }
+}
@@ -1,19 +1,38 @@
 pub mod blog_posts_table;
 pub mod code_blocks_table;
+pub mod details_blocks_table;
+pub mod diagram_blocks_table;
+pub mod embed_blocks_table;
+pub mod footnotes_table;
 pub mod heading_blocks_table;
 pub mod image_blocks_table;
+pub mod link_card_blocks_table;
+pub mod math_blocks_table;
+pub mod page_views_table;
 pub mod paragraph_blocks_table;
 pub mod pickup_posts_table;
+pub mod popular_post_settings_table;
 pub mod popular_posts_table;
 pub mod post_contents_table;
+pub mod seo_metadata_table;
+pub mod term_vectors_table;
 pub mod top_tech_pick_table;
 
 // 公開する必要のある型をre-export
 pub use blog_posts_table::BlogPostRecord;
 pub use code_blocks_table::CodeBlockRecord;
+pub use details_blocks_table::DetailsBlockRecord;
+pub use diagram_blocks_table::DiagramBlockRecord;
+pub use embed_blocks_table::EmbedBlockRecord;
+pub use footnotes_table::{FootnoteRecord, FootnoteRecordWithRelations};
 pub use heading_blocks_table::HeadingBlockRecord;
 pub use image_blocks_table::{ImageBlockRecord, ImageBlockRecordWithRelations};
+pub use link_card_blocks_table::LinkCardBlockRecord;
+pub use math_blocks_table::MathBlockRecord;
 pub use paragraph_blocks_table::{
-  ParagraphBlockRecord, ParagraphBlockRecordWithRelations, RichTextLinkRecord, RichTextRecord, RichTextRecordWithRelations, TextStyleRecord,
+  ParagraphBlockRecord, ParagraphBlockRecordWithRelations, RichTextFootnoteRefRecord, RichTextLinkRecord, RichTextRecord, RichTextRecordWithRelations,
+  RichTextRubyRecord, TextStyleRecord,
 };
 pub use post_contents_table::{AnyContentBlockRecord, PostContentRecord, PostContentType};
+pub use seo_metadata_table::{SeoMetadataRecord, SeoMetadataRecordWithRelations};
+pub use term_vectors_table::TermVectorRecord;
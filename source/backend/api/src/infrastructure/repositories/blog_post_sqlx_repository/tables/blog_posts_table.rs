@@ -13,6 +13,22 @@ pub struct BlogPostRecord {
   pub post_date: chrono::NaiveDate,
   pub last_update_date: chrono::NaiveDate,
   pub published_at: chrono::NaiveDate,
+  pub excerpt: Option<String>,
+}
+
+/// サイトマップ用に記事 ID と最終更新日だけを取得したレコード
+#[derive(Debug, FromRow)]
+pub struct SitemapEntryRecord {
+  pub id: Uuid,
+  pub last_update_date: chrono::NaiveDate,
+}
+
+/// 閲覧の記録用に記事 ID・タイトル・公開日だけを取得したレコード
+#[derive(Debug, FromRow)]
+pub struct PageViewTargetRecord {
+  pub id: Uuid,
+  pub title: String,
+  pub published_at: chrono::NaiveDate,
 }
 
 /*
@@ -20,7 +36,7 @@ pub struct BlogPostRecord {
  */
 pub async fn fetch_blog_post_by_id(executor: impl Executor<'_, Database = Postgres>, id: Uuid) -> Result<BlogPostRecord> {
   let post = sqlx::query_as::<_, BlogPostRecord>(
-    "select id, title, thumbnail_image_id, post_date, last_update_date, published_at::date as published_at from blog_posts where id = $1",
+    "select id, title, thumbnail_image_id, post_date, last_update_date, published_at::date as published_at, excerpt from blog_posts where id = $1",
   )
   .bind(id)
   .fetch_one(executor)
@@ -30,7 +46,7 @@ pub async fn fetch_blog_post_by_id(executor: impl Executor<'_, Database = Postgr
 
 pub async fn fetch_latest_blog_posts_records_with_limit(executor: impl Executor<'_, Database = Postgres>, limit: Option<u32>) -> Result<Vec<BlogPostRecord>> {
   let mut query = sqlx::QueryBuilder::new(
-    "select id, title, thumbnail_image_id, post_date, last_update_date, published_at::date as published_at from blog_posts order by post_date desc",
+    "select id, title, thumbnail_image_id, post_date, last_update_date, published_at::date as published_at, excerpt from blog_posts order by post_date desc",
   );
 
   if let Some(limit_value) = limit {
@@ -44,22 +60,69 @@ pub async fn fetch_latest_blog_posts_records_with_limit(executor: impl Executor<
 
 pub async fn fetch_all_blog_posts_records(executor: impl Executor<'_, Database = Postgres>) -> Result<Vec<BlogPostRecord>> {
   let posts = sqlx::query_as::<_, BlogPostRecord>(
-    "select id, title, thumbnail_image_id, post_date, last_update_date, published_at::date as published_at from blog_posts order by post_date desc",
+    "select id, title, thumbnail_image_id, post_date, last_update_date, published_at::date as published_at, excerpt from blog_posts order by post_date desc",
   )
   .fetch_all(executor)
   .await?;
   Ok(posts)
 }
 
+/// 公開日が `today` 以前で noindex でない記事数を取得する
+pub async fn count_published_blog_posts(executor: impl Executor<'_, Database = Postgres>, today: chrono::NaiveDate) -> Result<i64> {
+  let count = sqlx::query_scalar::<_, i64>(
+    "select count(*) from blog_posts p left join blog_post_seo_metadata s on s.post_id = p.id \
+     where p.published_at::date <= $1 and not coalesce(s.noindex, false)",
+  )
+  .bind(today)
+  .fetch_one(executor)
+  .await?;
+  Ok(count)
+}
+
+/// 公開日が `today` 以前で noindex でない記事の ID と最終更新日を公開日の古い順に取得する
+pub async fn fetch_published_sitemap_entry_records(
+  executor: impl Executor<'_, Database = Postgres>,
+  today: chrono::NaiveDate,
+  offset: i64,
+  limit: i64,
+) -> Result<Vec<SitemapEntryRecord>> {
+  let records = sqlx::query_as::<_, SitemapEntryRecord>(
+    "select p.id, p.last_update_date from blog_posts p left join blog_post_seo_metadata s on s.post_id = p.id \
+     where p.published_at::date <= $1 and not coalesce(s.noindex, false) order by p.published_at, p.id offset $2 limit $3",
+  )
+  .bind(today)
+  .bind(offset)
+  .bind(limit)
+  .fetch_all(executor)
+  .await?;
+  Ok(records)
+}
+
+/// 閲覧の記録用に記事 ID・タイトル・公開日だけを取得する（記事が存在しない場合は `None`）
+pub async fn fetch_page_view_target_record_by_id(executor: impl Executor<'_, Database = Postgres>, id: Uuid) -> Result<Option<PageViewTargetRecord>> {
+  let record = sqlx::query_as::<_, PageViewTargetRecord>("select id, title, published_at::date as published_at from blog_posts where id = $1")
+    .bind(id)
+    .fetch_optional(executor)
+    .await?;
+  Ok(record)
+}
+
+/// 指定した ID のうち存在する記事の ID を取得する
+pub async fn fetch_existing_blog_post_ids(executor: impl Executor<'_, Database = Postgres>, ids: &[Uuid]) -> Result<Vec<Uuid>> {
+  let existing_ids = sqlx::query_scalar::<_, Uuid>("select id from blog_posts where id = any($1)").bind(ids).fetch_all(executor).await?;
+  Ok(existing_ids)
+}
+
 pub async fn insert_blog_post(executor: impl Executor<'_, Database = Postgres>, post: BlogPostRecord) -> Result<()> {
   let published_at_timestamp = post.published_at.and_hms_opt(0, 0, 0).unwrap().and_utc();
-  sqlx::query("INSERT INTO blog_posts (id, title, thumbnail_image_id, post_date, last_update_date, published_at) VALUES ($1, $2, $3, $4, $5, $6)")
+  sqlx::query("INSERT INTO blog_posts (id, title, thumbnail_image_id, post_date, last_update_date, published_at, excerpt) VALUES ($1, $2, $3, $4, $5, $6, $7)")
     .bind(post.id)
     .bind(post.title)
     .bind(post.thumbnail_image_id)
     .bind(post.post_date)
     .bind(post.last_update_date)
     .bind(published_at_timestamp)
+    .bind(post.excerpt)
     .execute(executor)
     .await?;
   Ok(())
@@ -67,14 +130,37 @@ pub async fn insert_blog_post(executor: impl Executor<'_, Database = Postgres>,
 
 pub async fn update_blog_post_record(executor: impl Executor<'_, Database = Postgres>, post: BlogPostRecord) -> Result<()> {
   let published_at_timestamp = post.published_at.and_hms_opt(0, 0, 0).unwrap().and_utc();
-  sqlx::query("UPDATE blog_posts SET title = $2, thumbnail_image_id = $3, post_date = $4, last_update_date = $5, published_at = $6 WHERE id = $1")
-    .bind(post.id)
-    .bind(post.title)
-    .bind(post.thumbnail_image_id)
-    .bind(post.post_date)
-    .bind(post.last_update_date)
-    .bind(published_at_timestamp)
-    .execute(executor)
-    .await?;
+  sqlx::query(
+    "UPDATE blog_posts SET title = $2, thumbnail_image_id = $3, post_date = $4, last_update_date = $5, published_at = $6, excerpt = $7 WHERE id = $1",
+  )
+  .bind(post.id)
+  .bind(post.title)
+  .bind(post.thumbnail_image_id)
+  .bind(post.post_date)
+  .bind(post.last_update_date)
+  .bind(published_at_timestamp)
+  .bind(post.excerpt)
+  .execute(executor)
+  .await?;
+  Ok(())
+}
+
+/// 同じ ID の記事が存在する場合は上書きする
+pub async fn upsert_blog_post_record(executor: impl Executor<'_, Database = Postgres>, post: BlogPostRecord) -> Result<()> {
+  let published_at_timestamp = post.published_at.and_hms_opt(0, 0, 0).unwrap().and_utc();
+  sqlx::query(
+    "INSERT INTO blog_posts (id, title, thumbnail_image_id, post_date, last_update_date, published_at, excerpt) VALUES ($1, $2, $3, $4, $5, $6, $7) \
+     ON CONFLICT (id) DO UPDATE SET title = excluded.title, thumbnail_image_id = excluded.thumbnail_image_id, post_date = excluded.post_date, \
+     last_update_date = excluded.last_update_date, published_at = excluded.published_at, excerpt = excluded.excerpt, updated_at = CURRENT_TIMESTAMP",
+  )
+  .bind(post.id)
+  .bind(post.title)
+  .bind(post.thumbnail_image_id)
+  .bind(post.post_date)
+  .bind(post.last_update_date)
+  .bind(published_at_timestamp)
+  .bind(post.excerpt)
+  .execute(executor)
+  .await?;
   Ok(())
 }
@@ -12,17 +12,25 @@ pub struct CodeBlockRecord {
   pub code: String,
   #[sqlx(rename = "lang")]
   pub language: String,
+  pub highlight_lines: String,
+  pub start_line: i32,
+  pub diff: bool,
+  pub show_line_numbers: bool,
 }
 
 /*
  * データベース操作関数
  */
 pub async fn insert_code_block(executor: impl Executor<'_, Database = Postgres>, code_block: CodeBlockRecord) -> Result<()> {
-  sqlx::query("insert into code_blocks (id, title, code, lang) values ($1, $2, $3, $4)")
+  sqlx::query("insert into code_blocks (id, title, code, lang, highlight_lines, start_line, diff, show_line_numbers) values ($1, $2, $3, $4, $5, $6, $7, $8)")
     .bind(code_block.id)
     .bind(code_block.title)
     .bind(code_block.code)
     .bind(code_block.language)
+    .bind(code_block.highlight_lines)
+    .bind(code_block.start_line)
+    .bind(code_block.diff)
+    .bind(code_block.show_line_numbers)
     .execute(executor)
     .await
     .context("コードブロックの挿入に失敗しました。")?;
@@ -30,6 +38,10 @@ pub async fn insert_code_block(executor: impl Executor<'_, Database = Postgres>,
 }
 
 pub async fn fetch_code_block_by_content_id(executor: impl Executor<'_, Database = Postgres>, content_id: Uuid) -> Result<CodeBlockRecord> {
-  let block = sqlx::query_as::<_, CodeBlockRecord>("select id, title, code, lang from code_blocks where id = $1").bind(content_id).fetch_one(executor).await?;
+  let block =
+    sqlx::query_as::<_, CodeBlockRecord>("select id, title, code, lang, highlight_lines, start_line, diff, show_line_numbers from code_blocks where id = $1")
+      .bind(content_id)
+      .fetch_one(executor)
+      .await?;
   Ok(block)
 }
@@ -0,0 +1,31 @@
+use anyhow::{Context, Result};
+use sqlx::{Executor, FromRow, Postgres};
+use uuid::Uuid;
+
+/*
+ * DB内の各テーブル構造に紐づく構造体正義
+ */
+// 子コンテンツは post_contents の parent_id で折りたたみブロックを参照する
+#[derive(Debug, FromRow)]
+pub struct DetailsBlockRecord {
+  pub id: Uuid,
+  pub summary: String,
+}
+
+/*
+ * データベース操作関数
+ */
+pub async fn insert_details_block(executor: impl Executor<'_, Database = Postgres>, details_block: DetailsBlockRecord) -> Result<()> {
+  sqlx::query("insert into details_blocks (id, summary) values ($1, $2)")
+    .bind(details_block.id)
+    .bind(details_block.summary)
+    .execute(executor)
+    .await
+    .context("折りたたみブロックの挿入に失敗しました。")?;
+  Ok(())
+}
+
+pub async fn fetch_details_block_by_content_id(executor: impl Executor<'_, Database = Postgres>, content_id: Uuid) -> Result<DetailsBlockRecord> {
+  let block = sqlx::query_as::<_, DetailsBlockRecord>("select id, summary from details_blocks where id = $1").bind(content_id).fetch_one(executor).await?;
+  Ok(block)
+}
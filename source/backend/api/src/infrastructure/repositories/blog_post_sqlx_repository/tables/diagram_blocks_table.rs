@@ -0,0 +1,32 @@
+use anyhow::{Context, Result};
+use sqlx::{Executor, FromRow, Postgres};
+use uuid::Uuid;
+
+/*
+ * DB内の各テーブル構造に紐づく構造体正義
+ */
+#[derive(Debug, FromRow)]
+pub struct DiagramBlockRecord {
+  pub id: Uuid,
+  pub kind: String,
+  pub source: String,
+}
+
+/*
+ * データベース操作関数
+ */
+pub async fn insert_diagram_block(executor: impl Executor<'_, Database = Postgres>, diagram_block: DiagramBlockRecord) -> Result<()> {
+  sqlx::query("insert into diagram_blocks (id, kind, source) values ($1, $2, $3)")
+    .bind(diagram_block.id)
+    .bind(diagram_block.kind)
+    .bind(diagram_block.source)
+    .execute(executor)
+    .await
+    .context("図ブロックの挿入に失敗しました。")?;
+  Ok(())
+}
+
+pub async fn fetch_diagram_block_by_content_id(executor: impl Executor<'_, Database = Postgres>, content_id: Uuid) -> Result<DiagramBlockRecord> {
+  let block = sqlx::query_as::<_, DiagramBlockRecord>("select id, kind, source from diagram_blocks where id = $1").bind(content_id).fetch_one(executor).await?;
+  Ok(block)
+}
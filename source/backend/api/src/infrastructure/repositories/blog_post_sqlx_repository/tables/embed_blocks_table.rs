@@ -0,0 +1,33 @@
+use anyhow::{Context, Result};
+use sqlx::{Executor, FromRow, Postgres};
+use uuid::Uuid;
+
+/*
+ * DB内の各テーブル構造に紐づく構造体正義
+ */
+#[derive(Debug, FromRow)]
+pub struct EmbedBlockRecord {
+  pub id: Uuid,
+  pub provider: String,
+  pub resource_id: String,
+}
+
+/*
+ * データベース操作関数
+ */
+pub async fn insert_embed_block(executor: impl Executor<'_, Database = Postgres>, embed_block: EmbedBlockRecord) -> Result<()> {
+  sqlx::query("insert into embed_blocks (id, provider, resource_id) values ($1, $2, $3)")
+    .bind(embed_block.id)
+    .bind(embed_block.provider)
+    .bind(embed_block.resource_id)
+    .execute(executor)
+    .await
+    .context("埋め込みブロックの挿入に失敗しました。")?;
+  Ok(())
+}
+
+pub async fn fetch_embed_block_by_content_id(executor: impl Executor<'_, Database = Postgres>, content_id: Uuid) -> Result<EmbedBlockRecord> {
+  let block =
+    sqlx::query_as::<_, EmbedBlockRecord>("select id, provider, resource_id from embed_blocks where id = $1").bind(content_id).fetch_one(executor).await?;
+  Ok(block)
+}
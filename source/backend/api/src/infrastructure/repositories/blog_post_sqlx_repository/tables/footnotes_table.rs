@@ -0,0 +1,115 @@
+use anyhow::Result;
+use sqlx::{Executor, FromRow, Postgres};
+use uuid::Uuid;
+
+use super::paragraph_blocks_table::{fetch_rich_text_relations, RichTextRecord, RichTextRecordWithRelations};
+
+/*
+ * FootnoteRecord とそれに紐づく Record の関連を含めた構造体
+ */
+#[derive(Debug)]
+pub struct FootnoteRecordWithRelations {
+  pub footnote: FootnoteRecord,
+  pub rich_text_records_with_relations: Vec<RichTextRecordWithRelations>,
+}
+
+/*
+ * DB内の各テーブル構造に紐づく構造体正義
+ */
+#[derive(Debug, FromRow)]
+pub struct FootnoteRecord {
+  pub id: Uuid,
+  pub post_id: Uuid,
+  pub number: i32,
+}
+
+/*
+ * データベース操作関数
+ */
+pub async fn fetch_footnotes_with_relations_by_post_id(
+  executor: impl Executor<'_, Database = Postgres> + Copy,
+  post_id: Uuid,
+) -> Result<Vec<FootnoteRecordWithRelations>> {
+  let footnotes = fetch_footnotes_by_post_id(executor, post_id).await?;
+  let mut footnotes_with_relations = vec![];
+  for footnote in footnotes {
+    let rich_texts = fetch_rich_texts_by_footnote(executor, footnote.id).await?;
+    let rich_text_records_with_relations = fetch_rich_text_relations(executor, rich_texts).await?;
+    footnotes_with_relations.push(FootnoteRecordWithRelations {
+      footnote,
+      rich_text_records_with_relations,
+    });
+  }
+  Ok(footnotes_with_relations)
+}
+
+pub async fn fetch_footnotes_by_post_id(executor: impl Executor<'_, Database = Postgres>, post_id: Uuid) -> Result<Vec<FootnoteRecord>> {
+  let footnotes = sqlx::query_as::<_, FootnoteRecord>("select id, post_id, number from footnotes where post_id = $1 order by number asc")
+    .bind(post_id)
+    .fetch_all(executor)
+    .await?;
+  Ok(footnotes)
+}
+
+// 脚注の本文として保存された rich_texts を取得
+pub async fn fetch_rich_texts_by_footnote(executor: impl Executor<'_, Database = Postgres>, footnote_id: Uuid) -> Result<Vec<RichTextRecord>> {
+  let texts = sqlx::query_as::<_, RichTextRecord>(
+    "select id, paragraph_block_id, footnote_id, text_content, sort_order from rich_texts where footnote_id = $1 order by sort_order asc",
+  )
+  .bind(footnote_id)
+  .fetch_all(executor)
+  .await?;
+  Ok(texts)
+}
+
+pub async fn insert_footnote(executor: impl Executor<'_, Database = Postgres>, footnote: FootnoteRecord) -> Result<()> {
+  sqlx::query("insert into footnotes (id, post_id, number) values ($1, $2, $3)")
+    .bind(footnote.id)
+    .bind(footnote.post_id)
+    .bind(footnote.number)
+    .execute(executor)
+    .await?;
+  Ok(())
+}
+
+// 本文中の脚注参照（rich_text_footnote_refs）が先に削除されている前提で、脚注とその本文を削除する
+pub async fn delete_footnotes_by_post_id(executor: impl Executor<'_, Database = Postgres>, post_id: Uuid) -> Result<()> {
+  sqlx::query(
+    "WITH link_delete AS (
+       -- Step 1: Delete rich_text_links
+       DELETE FROM rich_text_links 
+       WHERE rich_text_id IN (
+         SELECT rt.id FROM rich_texts rt 
+         JOIN footnotes f ON rt.footnote_id = f.id 
+         WHERE f.post_id = $1
+       )
+     ), ruby_delete AS (
+       -- Step 2: Delete rich_text_rubies
+       DELETE FROM rich_text_rubies 
+       WHERE rich_text_id IN (
+         SELECT rt.id FROM rich_texts rt 
+         JOIN footnotes f ON rt.footnote_id = f.id 
+         WHERE f.post_id = $1
+       )
+     ), style_delete AS (
+       -- Step 3: Delete rich_text_styles
+       DELETE FROM rich_text_styles 
+       WHERE rich_text_id IN (
+         SELECT rt.id FROM rich_texts rt 
+         JOIN footnotes f ON rt.footnote_id = f.id 
+         WHERE f.post_id = $1
+       )
+     ), rich_text_delete AS (
+       -- Step 4: Delete rich_texts
+       DELETE FROM rich_texts 
+       WHERE footnote_id IN (SELECT id FROM footnotes WHERE post_id = $1)
+     )
+     -- Step 5: Finally delete footnotes
+     DELETE FROM footnotes WHERE post_id = $1;",
+  )
+  .bind(post_id)
+  .execute(executor)
+  .await?;
+
+  Ok(())
+}
@@ -22,6 +22,10 @@ pub struct ImageBlockRecordWithRelations {
 pub struct ImageBlockRecord {
   pub id: Uuid,
   pub image_id: Uuid,
+  pub alt: String,
+  pub caption: Option<String>,
+  pub width: Option<i32>,
+  pub link_url: Option<String>,
 }
 
 /*
@@ -44,14 +48,21 @@ pub async fn fetch_image_block_record_with_relations(
 }
 
 pub async fn fetch_image_blocks_by_content_id(executor: impl Executor<'_, Database = Postgres>, content_id: Uuid) -> Result<ImageBlockRecord> {
-  let block = sqlx::query_as::<_, ImageBlockRecord>("select id, image_id from image_blocks where id = $1").bind(content_id).fetch_one(executor).await?;
+  let block = sqlx::query_as::<_, ImageBlockRecord>("select id, image_id, alt, caption, width, link_url from image_blocks where id = $1")
+    .bind(content_id)
+    .fetch_one(executor)
+    .await?;
   Ok(block)
 }
 
 pub async fn insert_image_block(executor: impl Executor<'_, Database = Postgres>, image_block: ImageBlockRecord) -> Result<()> {
-  sqlx::query("insert into image_blocks (id, image_id) values ($1, $2)")
+  sqlx::query("insert into image_blocks (id, image_id, alt, caption, width, link_url) values ($1, $2, $3, $4, $5, $6)")
     .bind(image_block.id)
     .bind(image_block.image_id)
+    .bind(image_block.alt)
+    .bind(image_block.caption)
+    .bind(image_block.width)
+    .bind(image_block.link_url)
     .execute(executor)
     .await
     .context("画像ブロックの挿入に失敗しました。")?;
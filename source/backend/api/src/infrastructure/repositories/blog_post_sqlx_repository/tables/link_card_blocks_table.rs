@@ -0,0 +1,41 @@
+use anyhow::{Context, Result};
+use sqlx::{Executor, FromRow, Postgres};
+use uuid::Uuid;
+
+/*
+ * DB内の各テーブル構造に紐づく構造体正義
+ */
+#[derive(Debug, FromRow)]
+pub struct LinkCardBlockRecord {
+  pub id: Uuid,
+  pub url: String,
+  pub title: Option<String>,
+  pub description: Option<String>,
+  pub site_name: Option<String>,
+  pub image_url: Option<String>,
+}
+
+/*
+ * データベース操作関数
+ */
+pub async fn insert_link_card_block(executor: impl Executor<'_, Database = Postgres>, link_card_block: LinkCardBlockRecord) -> Result<()> {
+  sqlx::query("insert into link_card_blocks (id, url, title, description, site_name, image_url) values ($1, $2, $3, $4, $5, $6)")
+    .bind(link_card_block.id)
+    .bind(link_card_block.url)
+    .bind(link_card_block.title)
+    .bind(link_card_block.description)
+    .bind(link_card_block.site_name)
+    .bind(link_card_block.image_url)
+    .execute(executor)
+    .await
+    .context("リンクカードブロックの挿入に失敗しました。")?;
+  Ok(())
+}
+
+pub async fn fetch_link_card_block_by_content_id(executor: impl Executor<'_, Database = Postgres>, content_id: Uuid) -> Result<LinkCardBlockRecord> {
+  let block = sqlx::query_as::<_, LinkCardBlockRecord>("select id, url, title, description, site_name, image_url from link_card_blocks where id = $1")
+    .bind(content_id)
+    .fetch_one(executor)
+    .await?;
+  Ok(block)
+}
@@ -0,0 +1,30 @@
+use anyhow::{Context, Result};
+use sqlx::{Executor, FromRow, Postgres};
+use uuid::Uuid;
+
+/*
+ * DB内の各テーブル構造に紐づく構造体正義
+ */
+#[derive(Debug, FromRow)]
+pub struct MathBlockRecord {
+  pub id: Uuid,
+  pub source: String,
+}
+
+/*
+ * データベース操作関数
+ */
+pub async fn insert_math_block(executor: impl Executor<'_, Database = Postgres>, math_block: MathBlockRecord) -> Result<()> {
+  sqlx::query("insert into math_blocks (id, source) values ($1, $2)")
+    .bind(math_block.id)
+    .bind(math_block.source)
+    .execute(executor)
+    .await
+    .context("数式ブロックの挿入に失敗しました。")?;
+  Ok(())
+}
+
+pub async fn fetch_math_block_by_content_id(executor: impl Executor<'_, Database = Postgres>, content_id: Uuid) -> Result<MathBlockRecord> {
+  let block = sqlx::query_as::<_, MathBlockRecord>("select id, source from math_blocks where id = $1").bind(content_id).fetch_one(executor).await?;
+  Ok(block)
+}
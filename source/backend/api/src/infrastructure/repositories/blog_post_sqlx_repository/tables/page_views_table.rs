@@ -0,0 +1,73 @@
+use anyhow::Result;
+use sqlx::{Executor, FromRow, Postgres, Transaction};
+use uuid::Uuid;
+
+/*
+ * DB内の各テーブル構造に紐づく構造体正義
+ */
+#[derive(Debug, FromRow)]
+pub struct PageViewVisitorRecord {
+  pub post_id: Uuid,
+  pub viewed_on: chrono::NaiveDate,
+  pub visitor_hash: String,
+}
+
+#[derive(Debug, FromRow)]
+pub struct PostViewCountRecord {
+  pub post_id: Uuid,
+  pub views: i64,
+}
+
+/*
+ * データベース操作関数
+ */
+/// 閲覧者のハッシュを挿入し、同じ日の同じ閲覧者が初めての場合だけその日の閲覧数を 1 増やす
+///
+/// 閲覧数を増やした場合は `true` を返す
+pub async fn insert_page_view(tx: &mut Transaction<'_, Postgres>, visitor: PageViewVisitorRecord) -> Result<bool> {
+  let inserted = sqlx::query(
+    "insert into page_view_visitors (post_id, viewed_on, visitor_hash) values ($1, $2, $3) on conflict (post_id, viewed_on, visitor_hash) do nothing",
+  )
+  .bind(visitor.post_id)
+  .bind(visitor.viewed_on)
+  .bind(&visitor.visitor_hash)
+  .execute(&mut **tx)
+  .await?
+  .rows_affected()
+    == 1;
+  if !inserted {
+    return Ok(false);
+  }
+
+  sqlx::query(
+    "insert into post_daily_views (post_id, viewed_on, views) values ($1, $2, 1) \
+     on conflict (post_id, viewed_on) do update set views = post_daily_views.views + 1, updated_at = current_timestamp",
+  )
+  .bind(visitor.post_id)
+  .bind(visitor.viewed_on)
+  .execute(&mut **tx)
+  .await?;
+  Ok(true)
+}
+
+/// `since` から `until` までの公開日が `until` 以前の記事の閲覧数を取得する
+pub async fn fetch_published_view_count_records(
+  executor: impl Executor<'_, Database = Postgres>,
+  since: chrono::NaiveDate,
+  until: chrono::NaiveDate,
+) -> Result<Vec<PostViewCountRecord>> {
+  let records = sqlx::query_as::<_, PostViewCountRecord>(
+    "select v.post_id, sum(v.views)::bigint as views from post_daily_views v join blog_posts p on p.id = v.post_id \
+     where v.viewed_on between $1 and $2 and p.published_at::date <= $2 group by v.post_id",
+  )
+  .bind(since)
+  .bind(until)
+  .fetch_all(executor)
+  .await?;
+  Ok(records)
+}
+
+pub async fn delete_page_view_visitors_before(executor: impl Executor<'_, Database = Postgres>, date: chrono::NaiveDate) -> Result<u64> {
+  let result = sqlx::query("delete from page_view_visitors where viewed_on < $1").bind(date).execute(executor).await?;
+  Ok(result.rows_affected())
+}
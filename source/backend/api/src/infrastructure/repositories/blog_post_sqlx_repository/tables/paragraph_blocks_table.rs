@@ -16,6 +16,8 @@ pub struct RichTextRecordWithRelations {
   pub text_record: RichTextRecord,
   pub style_records: Vec<TextStyleRecord>,
   pub link_record: Option<RichTextLinkRecord>,
+  pub ruby_record: Option<RichTextRubyRecord>,
+  pub footnote_ref_record: Option<RichTextFootnoteRefRecord>,
 }
 
 /*
@@ -26,10 +28,12 @@ pub struct ParagraphBlockRecord {
   pub id: Uuid,
 }
 
+// 段落ブロックと脚注のどちらか一方に属する
 #[derive(Debug, FromRow)]
 pub struct RichTextRecord {
   pub id: Uuid,
-  pub paragraph_block_id: Uuid,
+  pub paragraph_block_id: Option<Uuid>,
+  pub footnote_id: Option<Uuid>,
   pub text_content: String,
   pub sort_order: i32,
 }
@@ -40,6 +44,11 @@ pub struct RichTextStyleRecord {
   pub rich_text_id: Uuid,
 }
 
+/// マーカー色のスタイル種別は "highlight-yellow" のように色名を付けて保存する
+pub const HIGHLIGHT_STYLE_PREFIX: &str = "highlight-";
+/// インライン数式を表すスタイルの種類
+pub const INLINE_MATH_STYLE: &str = "inline-math";
+
 #[derive(Debug, Clone, FromRow)]
 pub struct TextStyleRecord {
   pub id: Uuid,
@@ -51,6 +60,24 @@ pub struct RichTextLinkRecord {
   pub id: Uuid,
   pub rich_text_id: Uuid,
   pub url: String,
+  pub target_post_id: Option<Uuid>,
+  pub target_heading_id: Option<Uuid>,
+  /// リンク先の見出しの現在のテキスト（読み出し時にだけ使い、保存はしない）
+  pub target_heading_text: Option<String>,
+}
+
+#[derive(Debug, FromRow)]
+pub struct RichTextRubyRecord {
+  pub id: Uuid,
+  pub rich_text_id: Uuid,
+  pub ruby: String,
+}
+
+#[derive(Debug, FromRow)]
+pub struct RichTextFootnoteRefRecord {
+  pub id: Uuid,
+  pub rich_text_id: Uuid,
+  pub footnote_id: Uuid,
 }
 
 /*
@@ -76,14 +103,26 @@ pub async fn fetch_rich_text_relations_by_paragraph(
   paragraph_block_id: Uuid,
 ) -> Result<Vec<RichTextRecordWithRelations>> {
   let rich_texts = fetch_rich_texts_by_paragraph(executor, paragraph_block_id).await?;
+  fetch_rich_text_relations(executor, rich_texts).await
+}
+
+// 取得済みの rich_texts それぞれに紐づくスタイル・リンク・ルビ・脚注参照を取得する
+pub async fn fetch_rich_text_relations(
+  executor: impl Executor<'_, Database = Postgres> + Copy,
+  rich_texts: Vec<RichTextRecord>,
+) -> Result<Vec<RichTextRecordWithRelations>> {
   let mut rich_text_with_relations = vec![];
   for rich_text in rich_texts {
     let styles = fetch_styles_by_rich_text_id(executor, rich_text.id).await?;
     let link = fetch_link_by_rich_text_id(executor, rich_text.id).await?;
+    let ruby = fetch_ruby_by_rich_text_id(executor, rich_text.id).await?;
+    let footnote_ref = fetch_footnote_ref_by_rich_text_id(executor, rich_text.id).await?;
     rich_text_with_relations.push(RichTextRecordWithRelations {
       text_record: rich_text,
       style_records: styles,
       link_record: link,
+      ruby_record: ruby,
+      footnote_ref_record: footnote_ref,
     });
   }
   Ok(rich_text_with_relations)
@@ -97,7 +136,7 @@ pub async fn fetch_paragraph_block_by_content_id(executor: impl Executor<'_, Dat
 // rich_texts を取得
 pub async fn fetch_rich_texts_by_paragraph(executor: impl Executor<'_, Database = Postgres>, paragraph_block_id: Uuid) -> Result<Vec<RichTextRecord>> {
   let texts = sqlx::query_as::<_, RichTextRecord>(
-    "select id, paragraph_block_id, text_content, sort_order from rich_texts where paragraph_block_id = $1 order by sort_order asc",
+    "select id, paragraph_block_id, footnote_id, text_content, sort_order from rich_texts where paragraph_block_id = $1 order by sort_order asc",
   )
   .bind(paragraph_block_id)
   .fetch_all(executor)
@@ -116,11 +155,34 @@ pub async fn fetch_styles_by_rich_text_id(executor: impl Executor<'_, Database =
 }
 
 pub async fn fetch_link_by_rich_text_id(executor: impl Executor<'_, Database = Postgres>, rich_text_id: Uuid) -> Result<Option<RichTextLinkRecord>> {
-  let link = sqlx::query_as::<_, RichTextLinkRecord>("select id, rich_text_id, url from rich_text_links where rich_text_id = $1")
+  let link = sqlx::query_as::<_, RichTextLinkRecord>(
+    "select l.id, l.rich_text_id, l.url, l.target_post_id, l.target_heading_id, h.text_content as target_heading_text \
+       from rich_text_links l left join heading_blocks h on h.id = l.target_heading_id where l.rich_text_id = $1",
+  )
+  .bind(rich_text_id)
+  .fetch_optional(executor)
+  .await?;
+  Ok(link)
+}
+
+pub async fn fetch_ruby_by_rich_text_id(executor: impl Executor<'_, Database = Postgres>, rich_text_id: Uuid) -> Result<Option<RichTextRubyRecord>> {
+  let ruby = sqlx::query_as::<_, RichTextRubyRecord>("select id, rich_text_id, ruby from rich_text_rubies where rich_text_id = $1")
     .bind(rich_text_id)
     .fetch_optional(executor)
     .await?;
-  Ok(link)
+  Ok(ruby)
+}
+
+pub async fn fetch_footnote_ref_by_rich_text_id(
+  executor: impl Executor<'_, Database = Postgres>,
+  rich_text_id: Uuid,
+) -> Result<Option<RichTextFootnoteRefRecord>> {
+  let footnote_ref =
+    sqlx::query_as::<_, RichTextFootnoteRefRecord>("select id, rich_text_id, footnote_id from rich_text_footnote_refs where rich_text_id = $1")
+      .bind(rich_text_id)
+      .fetch_optional(executor)
+      .await?;
+  Ok(footnote_ref)
 }
 
 pub async fn insert_paragraph_block(executor: impl Executor<'_, Database = Postgres>, paragraph_block: ParagraphBlockRecord) -> Result<()> {
@@ -129,9 +191,10 @@ pub async fn insert_paragraph_block(executor: impl Executor<'_, Database = Postg
 }
 
 pub async fn insert_rich_text(executor: impl Executor<'_, Database = Postgres>, rich_text: RichTextRecord) -> Result<()> {
-  sqlx::query("insert into rich_texts (id, paragraph_block_id, text_content, sort_order) values ($1, $2, $3, $4)")
+  sqlx::query("insert into rich_texts (id, paragraph_block_id, footnote_id, text_content, sort_order) values ($1, $2, $3, $4, $5)")
     .bind(rich_text.id)
     .bind(rich_text.paragraph_block_id)
+    .bind(rich_text.footnote_id)
     .bind(rich_text.text_content)
     .bind(rich_text.sort_order)
     .execute(executor)
@@ -154,10 +217,32 @@ pub async fn insert_text_style_if_not_exists(executor: impl Executor<'_, Databas
 }
 
 pub async fn insert_rich_text_link(executor: impl Executor<'_, Database = Postgres>, link: RichTextLinkRecord) -> Result<()> {
-  sqlx::query("insert into rich_text_links (id, rich_text_id, url) values ($1, $2, $3)")
+  sqlx::query("insert into rich_text_links (id, rich_text_id, url, target_post_id, target_heading_id) values ($1, $2, $3, $4, $5)")
     .bind(link.id)
     .bind(link.rich_text_id)
     .bind(link.url)
+    .bind(link.target_post_id)
+    .bind(link.target_heading_id)
+    .execute(executor)
+    .await?;
+  Ok(())
+}
+
+pub async fn insert_rich_text_ruby(executor: impl Executor<'_, Database = Postgres>, ruby: RichTextRubyRecord) -> Result<()> {
+  sqlx::query("insert into rich_text_rubies (id, rich_text_id, ruby) values ($1, $2, $3)")
+    .bind(ruby.id)
+    .bind(ruby.rich_text_id)
+    .bind(ruby.ruby)
+    .execute(executor)
+    .await?;
+  Ok(())
+}
+
+pub async fn insert_rich_text_footnote_ref(executor: impl Executor<'_, Database = Postgres>, footnote_ref: RichTextFootnoteRefRecord) -> Result<()> {
+  sqlx::query("insert into rich_text_footnote_refs (id, rich_text_id, footnote_id) values ($1, $2, $3)")
+    .bind(footnote_ref.id)
+    .bind(footnote_ref.rich_text_id)
+    .bind(footnote_ref.footnote_id)
     .execute(executor)
     .await?;
   Ok(())
@@ -59,11 +59,18 @@ mod tests {
         thumbnail: Image {
           id: Uuid::new_v4(),
           path: "test-coffee".to_string(),
+          file_name: None,
+          caption: None,
         },
         post_date: NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(),
         last_update_date: NaiveDate::from_ymd_opt(2025, 3, 2).unwrap(),
         published_date: NaiveDate::from_ymd_opt(1900, 1, 1).unwrap(),
         contents: vec![],
+        footnotes: vec![],
+        seo: Default::default(),
+        excerpt: Default::default(),
+        manual_excerpt: Default::default(),
+        reading_time_minutes: Default::default(),
       }
     }
   }
@@ -0,0 +1,29 @@
+use anyhow::Result;
+use sqlx::{Executor, FromRow, Postgres};
+
+/*
+ * DB内の各テーブル構造に紐づく構造体正義
+ */
+#[derive(Debug, FromRow)]
+pub struct PopularPostSettingRecord {
+  pub mode: String,
+}
+
+/*
+ * データベース操作関数
+ */
+pub async fn fetch_popular_post_setting(executor: impl Executor<'_, Database = Postgres>) -> Result<PopularPostSettingRecord> {
+  let record = sqlx::query_as::<_, PopularPostSettingRecord>("select mode from popular_post_settings where id").fetch_one(executor).await?;
+  Ok(record)
+}
+
+pub async fn upsert_popular_post_setting(executor: impl Executor<'_, Database = Postgres>, record: PopularPostSettingRecord) -> Result<()> {
+  sqlx::query(
+    "insert into popular_post_settings (id, mode) values (true, $1) \
+     on conflict (id) do update set mode = excluded.mode, updated_at = current_timestamp",
+  )
+  .bind(record.mode)
+  .execute(executor)
+  .await?;
+  Ok(())
+}
@@ -4,8 +4,13 @@ use uuid::Uuid;
 
 use super::{
   code_blocks_table::{fetch_code_block_by_content_id, CodeBlockRecord},
+  details_blocks_table::{fetch_details_block_by_content_id, DetailsBlockRecord},
+  diagram_blocks_table::{fetch_diagram_block_by_content_id, DiagramBlockRecord},
+  embed_blocks_table::{fetch_embed_block_by_content_id, EmbedBlockRecord},
   heading_blocks_table::{fetch_heading_blocks_by_content_id, HeadingBlockRecord},
   image_blocks_table::{fetch_image_block_record_with_relations, ImageBlockRecordWithRelations},
+  link_card_blocks_table::{fetch_link_card_block_by_content_id, LinkCardBlockRecord},
+  math_blocks_table::{fetch_math_block_by_content_id, MathBlockRecord},
   paragraph_blocks_table::{fetch_paragraph_block_record_with_relations, ParagraphBlockRecordWithRelations},
 };
 
@@ -19,6 +24,11 @@ pub enum AnyContentBlockRecord {
   ParagraphBlockRecord(ParagraphBlockRecordWithRelations),
   ImageBlockRecord(ImageBlockRecordWithRelations),
   CodeBlockRecord(CodeBlockRecord),
+  MathBlockRecord(MathBlockRecord),
+  EmbedBlockRecord(EmbedBlockRecord),
+  LinkCardBlockRecord(LinkCardBlockRecord),
+  DetailsBlockRecord(DetailsBlockRecord),
+  DiagramBlockRecord(DiagramBlockRecord),
 }
 
 /*
@@ -29,6 +39,9 @@ pub struct PostContentRecord {
   pub id: Uuid,
   pub post_id: Uuid,
   pub content_type: String,
+  /// 折りたたみブロックの子コンテンツの場合は親ブロックの ID
+  pub parent_id: Option<Uuid>,
+  /// 同じ親（トップレベルの場合は記事）の中での並び順
   pub sort_order: i32,
 }
 
@@ -43,6 +56,11 @@ pub enum PostContentType {
   Heading,
   Image,
   CodeBlock,
+  Math,
+  Embed,
+  LinkCard,
+  Details,
+  Diagram,
 }
 
 impl TryFrom<String> for PostContentType {
@@ -53,6 +71,11 @@ impl TryFrom<String> for PostContentType {
       "image" => Ok(PostContentType::Image),
       "paragraph" => Ok(PostContentType::Paragraph),
       "code_block" => Ok(PostContentType::CodeBlock),
+      "math_block" => Ok(PostContentType::Math),
+      "embed" => Ok(PostContentType::Embed),
+      "link_card" => Ok(PostContentType::LinkCard),
+      "details" => Ok(PostContentType::Details),
+      "diagram" => Ok(PostContentType::Diagram),
       // 何らかの理由で想定外の文字列が来る場合
       other => anyhow::bail!("想定しない content type: {}", other),
     }
@@ -66,6 +89,11 @@ impl From<PostContentType> for String {
       PostContentType::Image => "image".to_string(),
       PostContentType::Paragraph => "paragraph".to_string(),
       PostContentType::CodeBlock => "code_block".to_string(),
+      PostContentType::Math => "math_block".to_string(),
+      PostContentType::Embed => "embed".to_string(),
+      PostContentType::LinkCard => "link_card".to_string(),
+      PostContentType::Details => "details".to_string(),
+      PostContentType::Diagram => "diagram".to_string(),
     }
   }
 }
@@ -98,18 +126,38 @@ pub async fn fetch_any_content_block(
     PostContentType::CodeBlock => {
       let code_block_record: CodeBlockRecord =
         fetch_code_block_by_content_id(executor, content_record.id).await.context("コードブロックの取得に失敗しました。")?;
-      AnyContentBlockRecord::CodeBlockRecord(CodeBlockRecord {
-        id: code_block_record.id,
-        title: code_block_record.title,
-        code: code_block_record.code,
-        language: code_block_record.language,
-      })
+      AnyContentBlockRecord::CodeBlockRecord(code_block_record)
+    }
+    PostContentType::Math => {
+      let math_block_record: MathBlockRecord =
+        fetch_math_block_by_content_id(executor, content_record.id).await.context("数式ブロックの取得に失敗しました。")?;
+      AnyContentBlockRecord::MathBlockRecord(math_block_record)
+    }
+    PostContentType::Embed => {
+      let embed_block_record: EmbedBlockRecord =
+        fetch_embed_block_by_content_id(executor, content_record.id).await.context("埋め込みブロックの取得に失敗しました。")?;
+      AnyContentBlockRecord::EmbedBlockRecord(embed_block_record)
+    }
+    PostContentType::LinkCard => {
+      let link_card_block_record: LinkCardBlockRecord =
+        fetch_link_card_block_by_content_id(executor, content_record.id).await.context("リンクカードブロックの取得に失敗しました。")?;
+      AnyContentBlockRecord::LinkCardBlockRecord(link_card_block_record)
+    }
+    PostContentType::Details => {
+      let details_block_record: DetailsBlockRecord =
+        fetch_details_block_by_content_id(executor, content_record.id).await.context("折りたたみブロックの取得に失敗しました。")?;
+      AnyContentBlockRecord::DetailsBlockRecord(details_block_record)
+    }
+    PostContentType::Diagram => {
+      let diagram_block_record: DiagramBlockRecord =
+        fetch_diagram_block_by_content_id(executor, content_record.id).await.context("図ブロックの取得に失敗しました。")?;
+      AnyContentBlockRecord::DiagramBlockRecord(diagram_block_record)
     }
   };
   Ok(result)
 }
 pub async fn fetch_post_contents_by_post_id(executor: impl Executor<'_, Database = Postgres>, post_id: Uuid) -> Result<Vec<PostContentRecord>> {
-  let contents = sqlx::query_as::<_, PostContentRecord>("select id, post_id, content_type, sort_order from post_contents where post_id = $1")
+  let contents = sqlx::query_as::<_, PostContentRecord>("select id, post_id, content_type, parent_id, sort_order from post_contents where post_id = $1")
     .bind(post_id)
     .fetch_all(executor)
     .await?;
@@ -117,10 +165,11 @@ pub async fn fetch_post_contents_by_post_id(executor: impl Executor<'_, Database
 }
 
 pub async fn insert_blog_post_content(executor: impl Executor<'_, Database = Postgres>, content: PostContentRecord) -> Result<()> {
-  sqlx::query("insert into post_contents (id, post_id, content_type, sort_order) values ($1, $2, $3, $4)")
+  sqlx::query("insert into post_contents (id, post_id, content_type, parent_id, sort_order) values ($1, $2, $3, $4, $5)")
     .bind(content.id)
     .bind(content.post_id)
     .bind(content.content_type)
+    .bind(content.parent_id)
     .bind(content.sort_order)
     .execute(executor)
     .await?;
@@ -140,8 +189,26 @@ pub async fn delete_post_contents_by_post_id(executor: impl Executor<'_, Databas
          JOIN post_contents pc ON pb.id = pc.id 
          WHERE pc.post_id = $1
        )
+     ), ruby_delete AS (
+       -- Step 2: Delete rich_text_rubies
+       DELETE FROM rich_text_rubies 
+       WHERE rich_text_id IN (
+         SELECT rt.id FROM rich_texts rt 
+         JOIN paragraph_blocks pb ON rt.paragraph_block_id = pb.id 
+         JOIN post_contents pc ON pb.id = pc.id 
+         WHERE pc.post_id = $1
+       )
+     ), footnote_ref_delete AS (
+       -- Step 3: Delete rich_text_footnote_refs
+       DELETE FROM rich_text_footnote_refs 
+       WHERE rich_text_id IN (
+         SELECT rt.id FROM rich_texts rt 
+         JOIN paragraph_blocks pb ON rt.paragraph_block_id = pb.id 
+         JOIN post_contents pc ON pb.id = pc.id 
+         WHERE pc.post_id = $1
+       )
      ), style_delete AS (
-       -- Step 2: Delete rich_text_styles
+       -- Step 4: Delete rich_text_styles
        DELETE FROM rich_text_styles 
        WHERE rich_text_id IN (
          SELECT rt.id FROM rich_texts rt 
@@ -150,25 +217,40 @@ pub async fn delete_post_contents_by_post_id(executor: impl Executor<'_, Databas
          WHERE pc.post_id = $1
        )
      ), rich_text_delete AS (
-       -- Step 3: Delete rich_texts
+       -- Step 5: Delete rich_texts
        DELETE FROM rich_texts 
        WHERE paragraph_block_id IN (
          SELECT pc.id FROM post_contents pc WHERE pc.post_id = $1
        )
      ), paragraph_delete AS (
-       -- Step 4: Delete paragraph_blocks
+       -- Step 6: Delete paragraph_blocks
        DELETE FROM paragraph_blocks WHERE id IN (SELECT id FROM post_contents WHERE post_id = $1)
      ), heading_delete AS (
-       -- Step 5: Delete heading_blocks
+       -- Step 7: Delete heading_blocks
        DELETE FROM heading_blocks WHERE id IN (SELECT id FROM post_contents WHERE post_id = $1)
      ), image_delete AS (
-       -- Step 6: Delete image_blocks
+       -- Step 8: Delete image_blocks
        DELETE FROM image_blocks WHERE id IN (SELECT id FROM post_contents WHERE post_id = $1)
      ), code_delete AS (
-       -- Step 7: Delete code_blocks
+       -- Step 9: Delete code_blocks
        DELETE FROM code_blocks WHERE id IN (SELECT id FROM post_contents WHERE post_id = $1)
+     ), math_delete AS (
+       -- Step 10: Delete math_blocks
+       DELETE FROM math_blocks WHERE id IN (SELECT id FROM post_contents WHERE post_id = $1)
+     ), embed_delete AS (
+       -- Step 11: Delete embed_blocks
+       DELETE FROM embed_blocks WHERE id IN (SELECT id FROM post_contents WHERE post_id = $1)
+     ), link_card_delete AS (
+       -- Step 12: Delete link_card_blocks
+       DELETE FROM link_card_blocks WHERE id IN (SELECT id FROM post_contents WHERE post_id = $1)
+     ), details_delete AS (
+       -- Step 13: Delete details_blocks
+       DELETE FROM details_blocks WHERE id IN (SELECT id FROM post_contents WHERE post_id = $1)
+     ), diagram_delete AS (
+       -- Step 14: Delete diagram_blocks
+       DELETE FROM diagram_blocks WHERE id IN (SELECT id FROM post_contents WHERE post_id = $1)
      )
-     -- Step 8: Finally delete post_contents
+     -- Step 15: Finally delete post_contents (including children of details blocks)
      DELETE FROM post_contents WHERE post_id = $1;",
   )
   .bind(post_id)
@@ -0,0 +1,79 @@
+use anyhow::{Context, Result};
+use sqlx::{Executor, FromRow, Postgres};
+use uuid::Uuid;
+
+use crate::infrastructure::repositories::image_sqlx_repository::{table::fetch_image_by_id, ImageRecord};
+
+/*
+ * 各レコードの関連をまとめた構造体
+ */
+#[derive(Debug)]
+pub struct SeoMetadataRecordWithRelations {
+  pub seo_metadata_record: SeoMetadataRecord,
+  pub og_image_record: Option<ImageRecord>,
+}
+
+/*
+ * DB内の各テーブル構造に紐づく構造体正義
+ */
+#[derive(Debug, FromRow)]
+pub struct SeoMetadataRecord {
+  pub post_id: Uuid,
+  pub description: Option<String>,
+  pub og_image_id: Option<Uuid>,
+  pub canonical_url: Option<String>,
+  pub noindex: bool,
+}
+
+/*
+ * データベース操作関数
+ */
+// SEO メタデータが未設定の記事では None を返す
+pub async fn fetch_seo_metadata_with_relations_by_post_id(
+  executor: impl Executor<'_, Database = Postgres> + Copy,
+  post_id: Uuid,
+) -> Result<Option<SeoMetadataRecordWithRelations>> {
+  let Some(seo_metadata_record) = fetch_seo_metadata_by_post_id(executor, post_id).await? else {
+    return Ok(None);
+  };
+
+  let og_image_record = match seo_metadata_record.og_image_id {
+    Some(og_image_id) => Some(fetch_image_by_id(executor, og_image_id).await.context("OGP 画像の取得に失敗しました。")?),
+    None => None,
+  };
+
+  Ok(Some(SeoMetadataRecordWithRelations {
+    seo_metadata_record,
+    og_image_record,
+  }))
+}
+
+pub async fn fetch_seo_metadata_by_post_id(executor: impl Executor<'_, Database = Postgres>, post_id: Uuid) -> Result<Option<SeoMetadataRecord>> {
+  let seo_metadata =
+    sqlx::query_as::<_, SeoMetadataRecord>("select post_id, description, og_image_id, canonical_url, noindex from blog_post_seo_metadata where post_id = $1")
+      .bind(post_id)
+      .fetch_optional(executor)
+      .await?;
+  Ok(seo_metadata)
+}
+
+pub async fn upsert_seo_metadata(executor: impl Executor<'_, Database = Postgres>, seo_metadata: SeoMetadataRecord) -> Result<()> {
+  sqlx::query(
+    "insert into blog_post_seo_metadata (post_id, description, og_image_id, canonical_url, noindex) values ($1, $2, $3, $4, $5) \
+     on conflict (post_id) do update set description = excluded.description, og_image_id = excluded.og_image_id, \
+     canonical_url = excluded.canonical_url, noindex = excluded.noindex, updated_at = current_timestamp",
+  )
+  .bind(seo_metadata.post_id)
+  .bind(seo_metadata.description)
+  .bind(seo_metadata.og_image_id)
+  .bind(seo_metadata.canonical_url)
+  .bind(seo_metadata.noindex)
+  .execute(executor)
+  .await?;
+  Ok(())
+}
+
+pub async fn delete_seo_metadata_by_post_id(executor: impl Executor<'_, Database = Postgres>, post_id: Uuid) -> Result<()> {
+  sqlx::query("delete from blog_post_seo_metadata where post_id = $1").bind(post_id).execute(executor).await?;
+  Ok(())
+}
@@ -0,0 +1,40 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use sqlx::{types::Json, Executor, FromRow, Postgres};
+use uuid::Uuid;
+
+/*
+ * DB内の各テーブル構造に紐づく構造体正義
+ */
+#[derive(Debug, FromRow)]
+pub struct TermVectorRecord {
+  pub post_id: Uuid,
+  pub terms: Json<HashMap<String, i32>>,
+}
+
+/*
+ * データベース操作関数
+ */
+/// 公開日が `today` 以前の記事の語の出現回数を取得する
+pub async fn fetch_published_term_vector_records(executor: impl Executor<'_, Database = Postgres>, today: chrono::NaiveDate) -> Result<Vec<TermVectorRecord>> {
+  let records = sqlx::query_as::<_, TermVectorRecord>(
+    "select v.post_id, v.terms from blog_post_term_vectors v join blog_posts p on p.id = v.post_id where p.published_at::date <= $1",
+  )
+  .bind(today)
+  .fetch_all(executor)
+  .await?;
+  Ok(records)
+}
+
+pub async fn upsert_term_vector(executor: impl Executor<'_, Database = Postgres>, term_vector: TermVectorRecord) -> Result<()> {
+  sqlx::query(
+    "insert into blog_post_term_vectors (post_id, terms) values ($1, $2) \
+     on conflict (post_id) do update set terms = excluded.terms, updated_at = current_timestamp",
+  )
+  .bind(term_vector.post_id)
+  .bind(term_vector.terms)
+  .execute(executor)
+  .await?;
+  Ok(())
+}
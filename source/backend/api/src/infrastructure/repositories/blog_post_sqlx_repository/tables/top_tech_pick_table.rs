@@ -1,5 +1,5 @@
 use anyhow::Result;
-use sqlx::{Executor, FromRow, Postgres};
+use sqlx::{Executor, FromRow, Postgres, Transaction};
 use uuid::Uuid;
 
 #[derive(Debug, FromRow)]
@@ -16,3 +16,10 @@ pub async fn update_top_tech_pick_post(executor: impl Executor<'_, Database = Po
   sqlx::query("update top_tech_pick_post set post_id = $1").bind(new_post_id).execute(executor).await?;
   Ok(())
 }
+
+/// トップテック記事を置き換える（未設定の環境でも登録できるよう、更新ではなく削除してから挿入する）
+pub async fn replace_top_tech_pick_post(tx: &mut Transaction<'_, Postgres>, post_id: Uuid) -> Result<()> {
+  sqlx::query("delete from top_tech_pick_post").execute(&mut **tx).await?;
+  sqlx::query("insert into top_tech_pick_post (post_id) values ($1)").bind(post_id).execute(&mut **tx).await?;
+  Ok(())
+}
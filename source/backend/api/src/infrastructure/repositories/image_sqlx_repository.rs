@@ -77,6 +77,8 @@ impl ImageRepository for ImageSqlxRepository {
     let api_image = common::types::api::Image {
       id: image_record.id,
       path: image_record.file_path,
+      file_name: image_record.file_name,
+      caption: image_record.caption,
     };
 
     // データベースに挿入
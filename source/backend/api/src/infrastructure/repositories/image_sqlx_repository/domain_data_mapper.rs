@@ -3,7 +3,7 @@ use crate::domain::image_domain::ImageEntity;
 
 /// ImageRecordからImageEntityに変換する
 pub fn convert_to_image_entity(record: ImageRecord) -> ImageEntity {
-  ImageEntity::new(record.id, record.file_path)
+  ImageEntity::new(record.id, record.file_path).with_details(record.file_name, record.caption)
 }
 
 #[cfg(test)]
@@ -19,6 +19,8 @@ mod tests {
     let image_record = ImageRecord {
       id: image_id,
       file_path: image_path.clone(),
+      file_name: None,
+      caption: None,
     };
 
     // 変換を実行
@@ -39,6 +41,8 @@ mod tests {
       let image_record = ImageRecord {
         id: image_id,
         file_path: path.to_string(),
+        file_name: None,
+        caption: None,
       };
       let image_entity = convert_to_image_entity(image_record);
 
@@ -57,6 +61,8 @@ mod tests {
     let original_record = ImageRecord {
       id: original_id,
       file_path: original_path.clone(),
+      file_name: None,
+      caption: None,
     };
 
     let entity = convert_to_image_entity(original_record);
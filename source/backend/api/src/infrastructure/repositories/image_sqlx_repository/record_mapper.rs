@@ -6,6 +6,8 @@ pub fn convert_from_image_entity(entity: &ImageEntity) -> ImageRecord {
   ImageRecord {
     id: entity.get_id(),
     file_path: entity.get_path().to_string(),
+    file_name: entity.get_file_name().map(str::to_string),
+    caption: entity.get_caption().map(str::to_string),
   }
 }
 
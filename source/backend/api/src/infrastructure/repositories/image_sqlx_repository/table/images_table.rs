@@ -7,37 +7,71 @@ use uuid::Uuid;
 pub struct ImageRecord {
   pub id: Uuid,
   pub file_path: String,
+  pub file_name: Option<String>,
+  pub caption: Option<String>,
 }
 
 pub async fn fetch_image_by_id(executor: impl Executor<'_, Database = Postgres>, id: Uuid) -> Result<ImageRecord> {
-  let image = sqlx::query_as::<_, ImageRecord>("select id,file_path from images where id = $1").bind(id).fetch_one(executor).await?;
+  let image = sqlx::query_as::<_, ImageRecord>("select id, file_path, file_name, caption from images where id = $1").bind(id).fetch_one(executor).await?;
   Ok(image)
 }
 
 pub async fn fetch_all_images(executor: impl Executor<'_, Database = Postgres>) -> Result<Vec<ImageRecord>> {
-  let images = sqlx::query_as::<_, ImageRecord>("select id, file_path from images").fetch_all(executor).await?;
+  let images = sqlx::query_as::<_, ImageRecord>("select id, file_path, file_name, caption from images").fetch_all(executor).await?;
   Ok(images)
 }
 
 pub async fn fetch_image_by_path(executor: impl Executor<'_, Database = Postgres>, path: &str) -> Result<ImageRecord> {
-  let image = sqlx::query_as::<_, ImageRecord>("select id, file_path from images where file_path = $1").bind(path).fetch_one(executor).await?;
+  let image =
+    sqlx::query_as::<_, ImageRecord>("select id, file_path, file_name, caption from images where file_path = $1").bind(path).fetch_one(executor).await?;
   Ok(image)
 }
 
 pub async fn insert_image(executor: impl Executor<'_, Database = Postgres>, image: Image) -> Result<ImageRecord> {
-  let image = sqlx::query_as::<_, ImageRecord>("insert into images (id, file_path) values ($1, $2) returning id, file_path")
-    .bind(image.id)
-    .bind(image.path)
-    .fetch_one(executor)
-    .await?;
+  let image = sqlx::query_as::<_, ImageRecord>(
+    "insert into images (id, file_path, file_name, caption) values ($1, $2, $3, $4) returning id, file_path, file_name, caption",
+  )
+  .bind(image.id)
+  .bind(image.path)
+  .bind(image.file_name)
+  .bind(image.caption)
+  .fetch_one(executor)
+  .await?;
   Ok(image)
 }
 
+/// ID またはパスのいずれかに一致する画像を取得する
+pub async fn fetch_images_by_ids_or_paths(executor: impl Executor<'_, Database = Postgres>, ids: &[Uuid], paths: &[String]) -> Result<Vec<ImageRecord>> {
+  let images = sqlx::query_as::<_, ImageRecord>("select id, file_path, file_name, caption from images where id = any($1) or file_path = any($2)")
+    .bind(ids)
+    .bind(paths)
+    .fetch_all(executor)
+    .await?;
+  Ok(images)
+}
+
+/// 同じ ID の画像が存在する場合は上書きする
+pub async fn upsert_image(executor: impl Executor<'_, Database = Postgres>, image: ImageRecord) -> Result<()> {
+  sqlx::query(
+    "insert into images (id, file_path, file_name, caption) values ($1, $2, $3, $4) \
+     on conflict (id) do update set file_path = excluded.file_path, file_name = excluded.file_name, caption = excluded.caption, updated_at = current_timestamp",
+  )
+  .bind(image.id)
+  .bind(image.file_path)
+  .bind(image.file_name)
+  .bind(image.caption)
+  .execute(executor)
+  .await?;
+  Ok(())
+}
+
 impl From<Image> for ImageRecord {
   fn from(image: Image) -> Self {
     Self {
       id: image.id,
       file_path: image.path,
+      file_name: image.file_name,
+      caption: image.caption,
     }
   }
 }
@@ -47,6 +81,8 @@ impl From<ImageRecord> for Image {
     Self {
       id: record.id,
       path: record.file_path,
+      file_name: record.file_name,
+      caption: record.caption,
     }
   }
 }
@@ -71,9 +107,13 @@ mod tests {
     let record = ImageRecord {
       id: image_id,
       file_path: "test-image".to_string(),
+      file_name: Some("test-image.png".to_string()),
+      caption: Some("テスト画像".to_string()),
     };
     let image: Image = record.into();
     assert_eq!(image.id, image_id);
+    assert_eq!(image.file_name.as_deref(), Some("test-image.png"));
+    assert_eq!(image.caption.as_deref(), Some("テスト画像"));
   }
 
   mod helper {
@@ -83,6 +123,8 @@ mod tests {
       Ok(Image {
         id,
         path: "test-image".to_string(),
+        file_name: None,
+        caption: None,
       })
     }
   }
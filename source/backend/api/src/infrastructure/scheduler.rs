@@ -0,0 +1,27 @@
+//! 定期実行するジョブ
+//!
+//! API サーバーと同じプロセスで動かし、DIコンテナから取得したユースケースを一定間隔で実行する。
+
+use actix_web::{rt, web};
+
+use crate::application::usecase::recompute_popular_posts::RecomputePopularPostsError;
+use crate::infrastructure::di_container::DiContainer;
+
+/// 閲覧数から人気記事を選び直すジョブを開始する
+///
+/// 人気記事を管理者が選ぶ設定の場合や、閲覧された記事が足りない場合は何もしない（前日以前の閲覧者のハッシュの削除だけ行う）
+pub fn spawn_popular_posts_job(di_container: web::Data<DiContainer>) {
+  rt::spawn(async move {
+    let mut interval = rt::time::interval(di_container.popular_posts_recompute_interval());
+    loop {
+      interval.tick().await;
+
+      let usecase = di_container.recompute_popular_posts_usecase();
+      match usecase.execute().await {
+        Ok(_) => println!("閲覧数から人気記事を選び直しました"),
+        Err(e) if e.downcast_ref::<RecomputePopularPostsError>().is_some() => {}
+        Err(e) => eprintln!("人気記事の選び直しに失敗しました: {:#}", e),
+      }
+    }
+  });
+}
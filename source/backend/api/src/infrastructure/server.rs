@@ -2,6 +2,7 @@ pub mod handlers;
 pub mod openapi;
 
 use super::di_container::DiContainer;
+use super::scheduler::spawn_popular_posts_job;
 use actix_cors::Cors;
 use actix_web::{http, middleware::Condition, web, App, HttpResponse, HttpServer};
 use anyhow::{Context, Result};
@@ -18,6 +19,9 @@ pub async fn start_api_server() -> Result<()> {
   // DIコンテナを初期化
   let di_container = web::Data::new(DiContainer::new().await?);
 
+  // 閲覧数から人気記事を選び直すジョブを開始
+  spawn_popular_posts_job(di_container.clone());
+
   // 開発環境でのみ Cors を設定する
   // 本番環境では Nginx などで設定する
   HttpServer::new(move || {
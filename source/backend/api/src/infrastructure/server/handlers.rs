@@ -1,5 +1,7 @@
 mod api_mapper;
+pub mod blog_archive_tar;
 pub mod blog_post_handlers;
+pub mod client_ip;
 pub mod dto_mapper;
 pub mod image_handlers;
 pub mod response;
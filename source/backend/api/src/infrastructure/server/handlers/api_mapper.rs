@@ -1,6 +1,14 @@
+pub mod blog_archive_response_mapper;
 pub mod blog_post_response_mapper;
+pub mod dangling_internal_link_response_mapper;
 pub mod image_response_mapper;
+pub mod import_markdown_post_response_mapper;
+pub mod import_wordpress_posts_response_mapper;
+pub mod json_feed_response_mapper;
 pub mod latest_blog_posts_response_mapper;
+pub mod popular_post_mode_response_mapper;
+pub mod structured_data_response_mapper;
+pub mod toc_response_mapper;
 
 pub use blog_post_response_mapper::{view_blog_post_dto_to_response, view_blog_post_dtos_to_response};
 pub use latest_blog_posts_response_mapper::view_latest_blog_posts_dto_to_response;
@@ -0,0 +1,50 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use common::types::api::{BlogArchiveConflict, BlogArchiveConflictKind, BlogArchiveFeaturedPosts, BlogArchiveManifest, ImportBlogArchiveResponse};
+
+use super::{image_response_mapper::image_dto_list_to_response, view_blog_post_dtos_to_response};
+use crate::application::dto::{BlogArchiveConflictDTO, BlogArchiveConflictKindDTO, BlogArchiveDTO, ImportBlogArchiveResultDTO};
+use crate::infrastructure::server::handlers::blog_archive_tar::{BlogArchive, BLOG_ARCHIVE_FORMAT_VERSION};
+
+pub fn blog_archive_dto_to_archive(dto: BlogArchiveDTO, exported_at: DateTime<Utc>) -> Result<BlogArchive> {
+  let posts = view_blog_post_dtos_to_response(dto.posts)?;
+  let images = image_dto_list_to_response(dto.images);
+
+  Ok(BlogArchive {
+    manifest: BlogArchiveManifest {
+      format_version: BLOG_ARCHIVE_FORMAT_VERSION,
+      exported_at,
+      post_count: posts.len() as u32,
+      image_count: images.len() as u32,
+    },
+    images,
+    featured: BlogArchiveFeaturedPosts {
+      top_tech_pick_post_id: dto.featured.top_tech_pick,
+      pickup_post_ids: dto.featured.pick_up,
+      popular_post_ids: dto.featured.popular,
+    },
+    posts,
+  })
+}
+
+pub fn import_blog_archive_result_dto_to_response(dto: ImportBlogArchiveResultDTO) -> ImportBlogArchiveResponse {
+  ImportBlogArchiveResponse {
+    dry_run: dto.dry_run,
+    post_count: dto.post_count as u32,
+    image_count: dto.image_count as u32,
+    conflicts: dto.conflicts.into_iter().map(conflict_dto_to_response).collect(),
+  }
+}
+
+fn conflict_dto_to_response(dto: BlogArchiveConflictDTO) -> BlogArchiveConflict {
+  BlogArchiveConflict {
+    kind: match dto.kind {
+      BlogArchiveConflictKindDTO::ExistingPost => BlogArchiveConflictKind::ExistingPost,
+      BlogArchiveConflictKindDTO::ExistingImage => BlogArchiveConflictKind::ExistingImage,
+      BlogArchiveConflictKindDTO::ImagePathInUse => BlogArchiveConflictKind::ImagePathInUse,
+    },
+    id: dto.id,
+    message: dto.detail,
+    blocking: dto.blocking,
+  }
+}
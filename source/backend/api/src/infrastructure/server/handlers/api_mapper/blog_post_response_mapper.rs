@@ -1,10 +1,14 @@
 use anyhow::{anyhow, Result};
-use common::types::api::{BlogPost, BlogPostContent, CodeBlock, H2Block, H3Block, Image, ImageBlock, Link, ParagraphBlock, RichText, Style};
+use common::types::api::{
+  BlogPost, BlogPostContent, CodeBlock, DetailsBlock, DiagramBlock, DiagramKind, EmbedBlock, EmbedProvider, Footnote, H2Block, H3Block, HighlightColor,
+  HighlightToken, HighlightedCode, Image, ImageBlock, Link, LinkCardBlock, MathBlock, ParagraphBlock, RichText, SeoMetadata, Style,
+};
 use uuid::Uuid;
 
 use crate::application::dto::{
-  BlogPostCodeBlockDTO, BlogPostContentDTO, BlogPostDTO, BlogPostH2BlockDTO, BlogPostH3BlockDTO, BlogPostImageBlockDTO, BlogPostLinkDTO,
-  BlogPostParagraphBlockDTO, BlogPostRichTextDTO, BlogPostStyleDTO, ImageDTO,
+  BlogPostCodeBlockDTO, BlogPostContentDTO, BlogPostDTO, BlogPostEmbedBlockDTO, BlogPostFootnoteDTO, BlogPostH2BlockDTO, BlogPostH3BlockDTO,
+  BlogPostImageBlockDTO, BlogPostLinkCardBlockDTO, BlogPostLinkDTO, BlogPostMathBlockDTO, BlogPostParagraphBlockDTO, BlogPostRichTextDTO, BlogPostStyleDTO,
+  DiagramKindDTO, EmbedProviderDTO, HighlightColorDTO, HighlightedCodeDTO, ImageDTO, SeoMetadataDTO,
 };
 
 /// ViewBlogPostDTOをAPIレスポンス用のBlogPostに変換
@@ -19,6 +23,11 @@ pub fn view_blog_post_dto_to_response(dto: BlogPostDTO) -> Result<BlogPost> {
     last_update_date: dto.last_update_date,
     published_date: dto.published_date,
     contents: convert_contents_dto_to_api(dto.contents),
+    footnotes: dto.footnotes.into_iter().map(convert_footnote_dto_to_api).collect(),
+    seo: convert_seo_metadata_dto_to_api(dto.seo_metadata),
+    excerpt: dto.digest.excerpt,
+    manual_excerpt: dto.digest.manual_excerpt,
+    reading_time_minutes: dto.digest.reading_time_minutes,
   })
 }
 
@@ -36,7 +45,22 @@ pub fn view_blog_post_dtos_to_response(dtos: Vec<BlogPostDTO>) -> Result<Vec<Blo
 
 /// ViewBlogPostImageDTOをAPI型のImageに変換
 fn convert_image_dto_to_api(dto: ImageDTO) -> Image {
-  Image { id: dto.id, path: dto.path }
+  Image {
+    id: dto.id,
+    path: dto.path,
+    file_name: dto.file_name,
+    caption: dto.caption,
+  }
+}
+
+/// SeoMetadataDTOをAPI型のSeoMetadataに変換
+pub fn convert_seo_metadata_dto_to_api(dto: SeoMetadataDTO) -> SeoMetadata {
+  SeoMetadata {
+    description: dto.description,
+    og_image: dto.og_image.map(convert_image_dto_to_api),
+    canonical_url: dto.canonical_url,
+    noindex: dto.noindex,
+  }
 }
 
 /// ViewBlogPostContentDTOのVecをAPI型のBlogPostContentのVecに変換
@@ -52,6 +76,19 @@ fn convert_content_dto_to_api(dto: BlogPostContentDTO) -> BlogPostContent {
     BlogPostContentDTO::Paragraph(para) => BlogPostContent::Paragraph(convert_paragraph_dto_to_api(para)),
     BlogPostContentDTO::Image(img) => BlogPostContent::Image(convert_image_block_dto_to_api(img)),
     BlogPostContentDTO::Code(code) => BlogPostContent::Code(convert_code_block_dto_to_api(code)),
+    BlogPostContentDTO::Math(math) => BlogPostContent::Math(convert_math_block_dto_to_api(math)),
+    BlogPostContentDTO::Embed(embed) => BlogPostContent::Embed(convert_embed_block_dto_to_api(embed)),
+    BlogPostContentDTO::LinkCard(link_card) => BlogPostContent::LinkCard(convert_link_card_block_dto_to_api(link_card)),
+    BlogPostContentDTO::Details(details) => BlogPostContent::Details(DetailsBlock {
+      id: details.id,
+      summary: details.summary,
+      children: convert_contents_dto_to_api(details.children),
+    }),
+    BlogPostContentDTO::Diagram(diagram) => BlogPostContent::Diagram(DiagramBlock {
+      id: diagram.id,
+      kind: convert_diagram_kind_dto_to_api(diagram.kind),
+      source: diagram.source,
+    }),
   }
 }
 
@@ -79,6 +116,17 @@ fn convert_rich_text_dto_to_api(dto: BlogPostRichTextDTO) -> RichText {
     text: dto.text,
     styles: convert_style_dto_to_api(dto.styles),
     link: dto.link.map(convert_link_dto_to_api),
+    ruby: dto.ruby,
+    footnote_id: dto.footnote_id,
+  }
+}
+
+/// BlogPostFootnoteDTOをAPI型のFootnoteに変換
+fn convert_footnote_dto_to_api(dto: BlogPostFootnoteDTO) -> Footnote {
+  Footnote {
+    id: dto.id,
+    number: dto.number,
+    text: dto.text.into_iter().map(convert_rich_text_dto_to_api).collect(),
   }
 }
 
@@ -87,17 +135,44 @@ fn convert_style_dto_to_api(dto: BlogPostStyleDTO) -> Style {
   Style {
     bold: dto.bold,
     inline_code: dto.inline_code,
+    inline_math: dto.inline_math,
+    italic: dto.italic,
+    strikethrough: dto.strikethrough,
+    underline: dto.underline,
+    highlight: dto.highlight.map(convert_highlight_color_dto_to_api),
+  }
+}
+
+/// HighlightColorDTOをAPI型のHighlightColorに変換
+pub fn convert_highlight_color_dto_to_api(dto: HighlightColorDTO) -> HighlightColor {
+  match dto {
+    HighlightColorDTO::Yellow => HighlightColor::Yellow,
+    HighlightColorDTO::Green => HighlightColor::Green,
+    HighlightColorDTO::Blue => HighlightColor::Blue,
+    HighlightColorDTO::Pink => HighlightColor::Pink,
+    HighlightColorDTO::Red => HighlightColor::Red,
   }
 }
 
 /// ViewBlogPostLinkDTOをAPI型のLinkに変換
 fn convert_link_dto_to_api(dto: BlogPostLinkDTO) -> Link {
-  Link { url: dto.url }
+  Link {
+    url: dto.url,
+    post_id: dto.post_id,
+    heading_id: dto.heading_id,
+  }
 }
 
 /// ViewBlogPostImageBlockDTOをAPI型のImageBlockに変換
 fn convert_image_block_dto_to_api(dto: BlogPostImageBlockDTO) -> ImageBlock {
-  ImageBlock { id: dto.id, path: dto.path }
+  ImageBlock {
+    id: dto.id,
+    path: dto.path,
+    alt: dto.alt,
+    caption: dto.caption,
+    width: dto.width,
+    link_url: dto.link_url,
+  }
 }
 
 /// ViewBlogPostCodeBlockDTOをAPI型のCodeBlockに変換
@@ -107,6 +182,80 @@ fn convert_code_block_dto_to_api(dto: BlogPostCodeBlockDTO) -> CodeBlock {
     title: dto.title,
     code: dto.code,
     language: dto.language,
+    highlight_lines: dto.highlight_lines,
+    start_line: dto.start_line,
+    diff: dto.diff,
+    show_line_numbers: dto.show_line_numbers,
+    highlighted: dto.highlighted.map(convert_highlighted_code_dto_to_api),
+  }
+}
+
+/// HighlightedCodeDTOをAPI型のHighlightedCodeに変換
+fn convert_highlighted_code_dto_to_api(dto: HighlightedCodeDTO) -> HighlightedCode {
+  match dto {
+    HighlightedCodeDTO::Tokens(lines) => HighlightedCode::Tokens {
+      lines: lines
+        .into_iter()
+        .map(|line| {
+          line
+            .into_iter()
+            .map(|token| HighlightToken {
+              text: token.text,
+              kind: token.kind,
+            })
+            .collect()
+        })
+        .collect(),
+    },
+    HighlightedCodeDTO::Html(html) => HighlightedCode::Html { html },
+  }
+}
+
+/// BlogPostMathBlockDTOをAPI型のMathBlockに変換
+fn convert_math_block_dto_to_api(dto: BlogPostMathBlockDTO) -> MathBlock {
+  MathBlock {
+    id: dto.id,
+    source: dto.source,
+  }
+}
+
+/// BlogPostEmbedBlockDTOをAPI型のEmbedBlockに変換
+fn convert_embed_block_dto_to_api(dto: BlogPostEmbedBlockDTO) -> EmbedBlock {
+  EmbedBlock {
+    id: dto.id,
+    provider: convert_embed_provider_dto_to_api(dto.provider),
+    resource_id: dto.resource_id,
+    url: dto.url,
+  }
+}
+
+/// BlogPostLinkCardBlockDTOをAPI型のLinkCardBlockに変換
+fn convert_link_card_block_dto_to_api(dto: BlogPostLinkCardBlockDTO) -> LinkCardBlock {
+  LinkCardBlock {
+    id: dto.id,
+    url: dto.url,
+    title: dto.title,
+    description: dto.description,
+    site_name: dto.site_name,
+    image_url: dto.image_url,
+  }
+}
+
+/// EmbedProviderDTOをAPI型のEmbedProviderに変換
+pub fn convert_embed_provider_dto_to_api(dto: EmbedProviderDTO) -> EmbedProvider {
+  match dto {
+    EmbedProviderDTO::YouTube => EmbedProvider::YouTube,
+    EmbedProviderDTO::Twitter => EmbedProvider::Twitter,
+    EmbedProviderDTO::GitHubGist => EmbedProvider::GitHubGist,
+    EmbedProviderDTO::SpeakerDeck => EmbedProvider::SpeakerDeck,
+    EmbedProviderDTO::CodePen => EmbedProvider::CodePen,
+  }
+}
+
+/// DiagramKindDTOをAPI型のDiagramKindに変換
+pub fn convert_diagram_kind_dto_to_api(dto: DiagramKindDTO) -> DiagramKind {
+  match dto {
+    DiagramKindDTO::Mermaid => DiagramKind::Mermaid,
   }
 }
 
@@ -127,6 +276,8 @@ mod tests {
       thumbnail: ImageDTO {
         id: test_thumbnail_id,
         path: "/test/image.jpg".to_string(),
+        file_name: None,
+        caption: None,
       },
       post_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
       last_update_date: NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
@@ -134,8 +285,11 @@ mod tests {
         id: test_h2_id,
         text: "見出し".to_string(),
       })],
+      footnotes: vec![],
       published_date: NaiveDate::from_ymd_opt(2024, 1, 3).unwrap(),
       is_public: true,
+      seo_metadata: Default::default(),
+      digest: Default::default(),
     };
 
     let result = view_blog_post_dto_to_response(dto.clone()).unwrap();
@@ -166,12 +320,17 @@ mod tests {
       thumbnail: ImageDTO {
         id: Uuid::new_v4(),
         path: "/test/image.jpg".to_string(),
+        file_name: None,
+        caption: None,
       },
       post_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
       last_update_date: NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
       contents: vec![],
+      footnotes: vec![],
       published_date: NaiveDate::from_ymd_opt(2024, 1, 3).unwrap(),
       is_public: true,
+      seo_metadata: Default::default(),
+      digest: Default::default(),
     };
 
     let result = view_blog_post_dto_to_response(dto);
@@ -0,0 +1,22 @@
+use common::types::api::{DanglingInternalLink, DanglingLinkReason};
+
+use crate::application::dto::{DanglingInternalLinkDTO, DanglingLinkReasonDTO};
+
+/// DanglingInternalLinkDTOの一覧をAPIレスポンス用の型に変換
+pub fn dangling_internal_link_dtos_to_response(dtos: Vec<DanglingInternalLinkDTO>) -> Vec<DanglingInternalLink> {
+  dtos.into_iter().map(dangling_internal_link_dto_to_response).collect()
+}
+
+fn dangling_internal_link_dto_to_response(dto: DanglingInternalLinkDTO) -> DanglingInternalLink {
+  DanglingInternalLink {
+    source_post_id: dto.source_post_id,
+    source_post_title: dto.source_post_title,
+    target_post_id: dto.target_post_id,
+    target_heading_id: dto.target_heading_id,
+    reason: match dto.reason {
+      DanglingLinkReasonDTO::PostNotFound => DanglingLinkReason::PostNotFound,
+      DanglingLinkReasonDTO::Unpublished => DanglingLinkReason::Unpublished,
+      DanglingLinkReasonDTO::HeadingNotFound => DanglingLinkReason::HeadingNotFound,
+    },
+  }
+}
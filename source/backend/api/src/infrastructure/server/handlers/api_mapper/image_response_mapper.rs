@@ -3,7 +3,12 @@ use common::types::api::Image;
 
 /// ImageDTOをAPIレスポンス用のImageに変換
 pub fn image_dto_to_response(dto: ImageDTO) -> Image {
-  Image { id: dto.id, path: dto.path }
+  Image {
+    id: dto.id,
+    path: dto.path,
+    file_name: dto.file_name,
+    caption: dto.caption,
+  }
 }
 
 /// ImageDTOのVecをAPIレスポンス用のImageのVecに変換
@@ -25,6 +30,8 @@ mod tests {
     let dto = ImageDTO {
       id: test_id,
       path: test_path.clone(),
+      file_name: None,
+      caption: None,
     };
 
     // 変換実行
@@ -47,10 +54,14 @@ mod tests {
       ImageDTO {
         id: test_id1,
         path: test_path1.clone(),
+        file_name: None,
+        caption: None,
       },
       ImageDTO {
         id: test_id2,
         path: test_path2.clone(),
+        file_name: None,
+        caption: None,
       },
     ];
 
@@ -86,6 +97,8 @@ mod tests {
     let dto = ImageDTO {
       id: test_id,
       path: test_path.clone(),
+      file_name: None,
+      caption: None,
     };
 
     // 変換実行
@@ -0,0 +1,20 @@
+use anyhow::Result;
+use common::types::api::{ImportMarkdownPostResponse, MarkdownImportWarning};
+
+use super::view_blog_post_dto_to_response;
+use crate::application::dto::ImportMarkdownPostResultDTO;
+
+/// ImportMarkdownPostResultDTOをAPIレスポンス用の型に変換
+pub fn import_markdown_post_result_dto_to_response(dto: ImportMarkdownPostResultDTO) -> Result<ImportMarkdownPostResponse> {
+  Ok(ImportMarkdownPostResponse {
+    post: view_blog_post_dto_to_response(dto.blog_post)?,
+    warnings: dto
+      .warnings
+      .into_iter()
+      .map(|warning| MarkdownImportWarning {
+        line: warning.line as u32,
+        message: warning.message,
+      })
+      .collect(),
+  })
+}
@@ -0,0 +1,25 @@
+use common::types::api::{ImportWordPressPostsResponse, WordPressImportItemReport, WordPressImportStatus};
+
+use crate::application::dto::{WordPressImportItemReportDTO, WordPressImportStatusDTO};
+
+/// WordPressImportItemReportDTOの一覧をAPIレスポンス用の型に変換
+pub fn wordpress_import_item_report_dtos_to_response(dtos: Vec<WordPressImportItemReportDTO>) -> ImportWordPressPostsResponse {
+  ImportWordPressPostsResponse {
+    items: dtos
+      .into_iter()
+      .map(|dto| WordPressImportItemReport {
+        wordpress_post_id: dto.wordpress_post_id,
+        title: dto.title,
+        status: match dto.status {
+          WordPressImportStatusDTO::Imported => WordPressImportStatus::Imported,
+          WordPressImportStatusDTO::Skipped => WordPressImportStatus::Skipped,
+          WordPressImportStatusDTO::Failed => WordPressImportStatus::Failed,
+        },
+        post_id: dto.post_id,
+        reason: dto.reason,
+        lossy_notes: dto.lossy_notes,
+        registered_image_paths: dto.registered_image_paths,
+      })
+      .collect(),
+  }
+}
@@ -0,0 +1,73 @@
+use common::types::api::{JsonFeed, JsonFeedItem};
+
+use crate::application::dto::{JsonFeedDTO, JsonFeedItemDTO};
+
+const JSON_FEED_VERSION: &str = "https://jsonfeed.org/version/1.1";
+
+/// JsonFeedDTOをJSON Feed 1.1の型に変換
+pub fn json_feed_dto_to_response(dto: JsonFeedDTO) -> JsonFeed {
+  JsonFeed {
+    version: JSON_FEED_VERSION.to_string(),
+    title: dto.title,
+    home_page_url: dto.home_page_url,
+    feed_url: dto.feed_url,
+    description: Some(dto.description).filter(|description| !description.is_empty()),
+    language: "ja".to_string(),
+    items: dto.items.into_iter().map(json_feed_item_dto_to_response).collect(),
+  }
+}
+
+fn json_feed_item_dto_to_response(dto: JsonFeedItemDTO) -> JsonFeedItem {
+  JsonFeedItem {
+    id: dto.id.to_string(),
+    url: dto.url,
+    title: dto.title,
+    content_html: dto.content_html,
+    summary: Some(dto.summary).filter(|summary| !summary.is_empty()),
+    image: dto.image,
+    date_published: dto.date_published,
+    date_modified: dto.date_modified,
+    // 記事にはまだタグがないため出力しない
+    tags: None,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use chrono::DateTime;
+  use uuid::Uuid;
+
+  #[test]
+  fn serializes_as_json_feed_1_1() {
+    let date = DateTime::parse_from_rfc3339("2024-03-02T00:00:00+09:00").unwrap();
+    let dto = JsonFeedDTO {
+      title: "鉄火ブログ".to_string(),
+      description: String::new(),
+      home_page_url: "https://blog.example.com/".to_string(),
+      feed_url: "https://blog.example.com/api/blog/feed.json".to_string(),
+      items: vec![JsonFeedItemDTO {
+        id: Uuid::nil(),
+        url: "https://blog.example.com/posts/1".to_string(),
+        title: "記事".to_string(),
+        content_html: "<p>本文</p>\n".to_string(),
+        summary: "本文".to_string(),
+        image: None,
+        date_published: date,
+        date_modified: date,
+      }],
+      last_modified: None,
+    };
+
+    let json = serde_json::to_value(json_feed_dto_to_response(dto)).unwrap();
+
+    assert_eq!(json["version"], "https://jsonfeed.org/version/1.1");
+    assert!(json.get("description").is_none());
+    let item = &json["items"][0];
+    assert_eq!(item["id"], Uuid::nil().to_string());
+    assert_eq!(item["content_html"], "<p>本文</p>\n");
+    assert_eq!(item["date_published"], "2024-03-02T00:00:00+09:00");
+    assert!(item.get("image").is_none());
+    assert!(item.get("tags").is_none());
+  }
+}
@@ -1,11 +1,19 @@
 use anyhow::{anyhow, Result};
-use common::types::api::{BlogPost, BlogPostContent, CodeBlock, H2Block, H3Block, Image, ImageBlock, Link, ParagraphBlock, RichText, Style};
+use common::types::api::{
+  BlogPost, BlogPostContent, CodeBlock, DetailsBlock, DiagramBlock, EmbedBlock, Footnote, H2Block, H3Block, Image, ImageBlock, Link, LinkCardBlock, MathBlock,
+  ParagraphBlock, RichText, Style,
+};
 use uuid::Uuid;
 
+use super::blog_post_response_mapper::{
+  convert_diagram_kind_dto_to_api, convert_embed_provider_dto_to_api, convert_highlight_color_dto_to_api, convert_seo_metadata_dto_to_api,
+};
+
 use crate::application::usecase::view_latest_blog_posts::dto::{
-  ViewLatestBlogPostCodeBlockDTO, ViewLatestBlogPostContentDTO, ViewLatestBlogPostH2BlockDTO, ViewLatestBlogPostH3BlockDTO, ViewLatestBlogPostImageBlockDTO,
-  ViewLatestBlogPostImageDTO, ViewLatestBlogPostItemDTO, ViewLatestBlogPostLinkDTO, ViewLatestBlogPostParagraphBlockDTO, ViewLatestBlogPostRichTextDTO,
-  ViewLatestBlogPostStyleDTO, ViewLatestBlogPostsDTO,
+  ViewLatestBlogPostCodeBlockDTO, ViewLatestBlogPostContentDTO, ViewLatestBlogPostEmbedBlockDTO, ViewLatestBlogPostFootnoteDTO, ViewLatestBlogPostH2BlockDTO,
+  ViewLatestBlogPostH3BlockDTO, ViewLatestBlogPostImageBlockDTO, ViewLatestBlogPostImageDTO, ViewLatestBlogPostItemDTO, ViewLatestBlogPostLinkCardBlockDTO,
+  ViewLatestBlogPostLinkDTO, ViewLatestBlogPostMathBlockDTO, ViewLatestBlogPostParagraphBlockDTO, ViewLatestBlogPostRichTextDTO, ViewLatestBlogPostStyleDTO,
+  ViewLatestBlogPostsDTO,
 };
 
 /// ViewLatestBlogPostsDTOをAPIレスポンスのVec<BlogPost>に変換する
@@ -33,12 +41,22 @@ fn convert_view_latest_blog_post_item_dto_to_blog_post(dto: ViewLatestBlogPostIt
     last_update_date: dto.last_update_date,
     published_date: dto.published_date,
     contents: convert_view_latest_contents_dto_to_api(dto.contents),
+    footnotes: dto.footnotes.into_iter().map(convert_view_latest_footnote_dto_to_api).collect(),
+    seo: convert_seo_metadata_dto_to_api(dto.seo_metadata),
+    excerpt: dto.digest.excerpt,
+    manual_excerpt: dto.digest.manual_excerpt,
+    reading_time_minutes: dto.digest.reading_time_minutes,
   })
 }
 
 /// ViewLatestBlogPostImageDTOをAPI型のImageに変換
 fn convert_view_latest_image_dto_to_api(dto: ViewLatestBlogPostImageDTO) -> Image {
-  Image { id: dto.id, path: dto.path }
+  Image {
+    id: dto.id,
+    path: dto.path,
+    file_name: dto.file_name,
+    caption: dto.caption,
+  }
 }
 
 /// ViewLatestBlogPostContentDTOのVecをAPI型のBlogPostContentのVecに変換
@@ -54,6 +72,19 @@ fn convert_view_latest_content_dto_to_api(dto: ViewLatestBlogPostContentDTO) ->
     ViewLatestBlogPostContentDTO::Paragraph(para) => BlogPostContent::Paragraph(convert_view_latest_paragraph_dto_to_api(para)),
     ViewLatestBlogPostContentDTO::Image(img) => BlogPostContent::Image(convert_view_latest_image_block_dto_to_api(img)),
     ViewLatestBlogPostContentDTO::Code(code) => BlogPostContent::Code(convert_view_latest_code_block_dto_to_api(code)),
+    ViewLatestBlogPostContentDTO::Math(math) => BlogPostContent::Math(convert_view_latest_math_block_dto_to_api(math)),
+    ViewLatestBlogPostContentDTO::Embed(embed) => BlogPostContent::Embed(convert_view_latest_embed_block_dto_to_api(embed)),
+    ViewLatestBlogPostContentDTO::LinkCard(link_card) => BlogPostContent::LinkCard(convert_view_latest_link_card_block_dto_to_api(link_card)),
+    ViewLatestBlogPostContentDTO::Details(details) => BlogPostContent::Details(DetailsBlock {
+      id: details.id,
+      summary: details.summary,
+      children: convert_view_latest_contents_dto_to_api(details.children),
+    }),
+    ViewLatestBlogPostContentDTO::Diagram(diagram) => BlogPostContent::Diagram(DiagramBlock {
+      id: diagram.id,
+      kind: convert_diagram_kind_dto_to_api(diagram.kind),
+      source: diagram.source,
+    }),
   }
 }
 
@@ -81,6 +112,17 @@ fn convert_view_latest_rich_text_dto_to_api(dto: ViewLatestBlogPostRichTextDTO)
     text: dto.text,
     styles: convert_view_latest_style_dto_to_api(dto.styles),
     link: dto.link.map(convert_view_latest_link_dto_to_api),
+    ruby: dto.ruby,
+    footnote_id: dto.footnote_id,
+  }
+}
+
+/// ViewLatestBlogPostFootnoteDTOをAPI型のFootnoteに変換
+fn convert_view_latest_footnote_dto_to_api(dto: ViewLatestBlogPostFootnoteDTO) -> Footnote {
+  Footnote {
+    id: dto.id,
+    number: dto.number,
+    text: dto.text.into_iter().map(convert_view_latest_rich_text_dto_to_api).collect(),
   }
 }
 
@@ -89,17 +131,33 @@ fn convert_view_latest_style_dto_to_api(dto: ViewLatestBlogPostStyleDTO) -> Styl
   Style {
     bold: dto.bold,
     inline_code: dto.inline_code,
+    inline_math: dto.inline_math,
+    italic: dto.italic,
+    strikethrough: dto.strikethrough,
+    underline: dto.underline,
+    highlight: dto.highlight.map(convert_highlight_color_dto_to_api),
   }
 }
 
 /// ViewLatestBlogPostLinkDTOをAPI型のLinkに変換
 fn convert_view_latest_link_dto_to_api(dto: ViewLatestBlogPostLinkDTO) -> Link {
-  Link { url: dto.url }
+  Link {
+    url: dto.url,
+    post_id: dto.post_id,
+    heading_id: dto.heading_id,
+  }
 }
 
 /// ViewLatestBlogPostImageBlockDTOをAPI型のImageBlockに変換
 fn convert_view_latest_image_block_dto_to_api(dto: ViewLatestBlogPostImageBlockDTO) -> ImageBlock {
-  ImageBlock { id: dto.id, path: dto.path }
+  ImageBlock {
+    id: dto.id,
+    path: dto.path,
+    alt: dto.alt,
+    caption: dto.caption,
+    width: dto.width,
+    link_url: dto.link_url,
+  }
 }
 
 /// ViewLatestBlogPostCodeBlockDTOをAPI型のCodeBlockに変換
@@ -109,6 +167,41 @@ fn convert_view_latest_code_block_dto_to_api(dto: ViewLatestBlogPostCodeBlockDTO
     title: dto.title,
     code: dto.code,
     language: dto.language,
+    highlight_lines: dto.highlight_lines,
+    start_line: dto.start_line,
+    diff: dto.diff,
+    show_line_numbers: dto.show_line_numbers,
+    highlighted: None,
+  }
+}
+
+/// ViewLatestBlogPostMathBlockDTOをAPI型のMathBlockに変換
+fn convert_view_latest_math_block_dto_to_api(dto: ViewLatestBlogPostMathBlockDTO) -> MathBlock {
+  MathBlock {
+    id: dto.id,
+    source: dto.source,
+  }
+}
+
+/// ViewLatestBlogPostEmbedBlockDTOをAPI型のEmbedBlockに変換
+fn convert_view_latest_embed_block_dto_to_api(dto: ViewLatestBlogPostEmbedBlockDTO) -> EmbedBlock {
+  EmbedBlock {
+    id: dto.id,
+    provider: convert_embed_provider_dto_to_api(dto.provider),
+    resource_id: dto.resource_id,
+    url: dto.url,
+  }
+}
+
+/// ViewLatestBlogPostLinkCardBlockDTOをAPI型のLinkCardBlockに変換
+fn convert_view_latest_link_card_block_dto_to_api(dto: ViewLatestBlogPostLinkCardBlockDTO) -> LinkCardBlock {
+  LinkCardBlock {
+    id: dto.id,
+    url: dto.url,
+    title: dto.title,
+    description: dto.description,
+    site_name: dto.site_name,
+    image_url: dto.image_url,
   }
 }
 
@@ -144,12 +237,17 @@ mod tests {
       thumbnail: ViewLatestBlogPostImageDTO {
         id: thumbnail_id,
         path: "test-thumbnail.jpg".to_string(),
+        file_name: None,
+        caption: None,
       },
       post_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
       last_update_date: NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
       contents: vec![],
+      footnotes: vec![],
       published_date: NaiveDate::from_ymd_opt(2024, 1, 3).unwrap(),
       is_public: true,
+      seo_metadata: Default::default(),
+      digest: Default::default(),
     };
 
     let dto = ViewLatestBlogPostsDTO { blog_posts: vec![post_dto] };
@@ -186,12 +284,17 @@ mod tests {
         thumbnail: ViewLatestBlogPostImageDTO {
           id: thumbnail_id,
           path: format!("thumbnail{}.jpg", i),
+          file_name: None,
+          caption: None,
         },
         post_date: NaiveDate::from_ymd_opt(2024, 1, i as u32).unwrap(),
         last_update_date: NaiveDate::from_ymd_opt(2024, 1, i as u32 + 10).unwrap(),
         contents: vec![],
+        footnotes: vec![],
         published_date: NaiveDate::from_ymd_opt(2024, 1, 3).unwrap(),
         is_public: true,
+        seo_metadata: Default::default(),
+        digest: Default::default(),
       };
 
       blog_posts_dto.push(post_dto);
@@ -242,38 +345,69 @@ mod tests {
             styles: ViewLatestBlogPostStyleDTO {
               bold: false,
               inline_code: false,
+              inline_math: false,
+              italic: false,
+              strikethrough: false,
+              underline: false,
+              highlight: None,
             },
             link: None,
+            ruby: None,
+            footnote_id: None,
           },
           ViewLatestBlogPostRichTextDTO {
             text: "太字のテキスト".to_string(),
             styles: ViewLatestBlogPostStyleDTO {
               bold: true,
               inline_code: false,
+              inline_math: false,
+              italic: false,
+              strikethrough: false,
+              underline: false,
+              highlight: None,
             },
             link: None,
+            ruby: None,
+            footnote_id: None,
           },
           ViewLatestBlogPostRichTextDTO {
             text: "リンクテキスト".to_string(),
             styles: ViewLatestBlogPostStyleDTO {
               bold: false,
               inline_code: false,
+              inline_math: false,
+              italic: false,
+              strikethrough: false,
+              underline: false,
+              highlight: None,
             },
             link: Some(ViewLatestBlogPostLinkDTO {
               url: "https://example.com".to_string(),
+              post_id: None,
+              heading_id: None,
             }),
+            ruby: None,
+            footnote_id: None,
           },
         ],
       }),
       ViewLatestBlogPostContentDTO::Image(ViewLatestBlogPostImageBlockDTO {
         id: image_id,
         path: "test-image.jpg".to_string(),
+        alt: String::new(),
+        caption: None,
+        width: None,
+        link_url: None,
       }),
       ViewLatestBlogPostContentDTO::Code(ViewLatestBlogPostCodeBlockDTO {
         id: code_id,
         title: "サンプルコード".to_string(),
         code: "console.log('Hello, World!');".to_string(),
         language: "javascript".to_string(),
+        highlight_lines: None,
+        start_line: 1,
+        diff: false,
+        show_line_numbers: false,
       }),
     ];
 
@@ -283,12 +417,17 @@ mod tests {
       thumbnail: ViewLatestBlogPostImageDTO {
         id: thumbnail_id,
         path: "test-thumbnail.jpg".to_string(),
+        file_name: None,
+        caption: None,
       },
       post_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
       last_update_date: NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
       contents,
       published_date: NaiveDate::from_ymd_opt(2024, 1, 3).unwrap(),
       is_public: true,
+      footnotes: vec![],
+      seo_metadata: Default::default(),
+      digest: Default::default(),
     };
 
     let dto = ViewLatestBlogPostsDTO { blog_posts: vec![post_dto] };
@@ -372,12 +511,17 @@ mod tests {
       thumbnail: ViewLatestBlogPostImageDTO {
         id: Uuid::new_v4(),
         path: "test-thumbnail.jpg".to_string(),
+        file_name: None,
+        caption: None,
       },
       post_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
       last_update_date: NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
       contents: vec![],
+      footnotes: vec![],
       published_date: NaiveDate::from_ymd_opt(2024, 1, 3).unwrap(),
       is_public: true,
+      seo_metadata: Default::default(),
+      digest: Default::default(),
     };
 
     let dto = ViewLatestBlogPostsDTO {
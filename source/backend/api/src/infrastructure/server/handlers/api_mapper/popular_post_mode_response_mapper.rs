@@ -0,0 +1,12 @@
+use common::types::api::{PopularPostMode, PopularPostModeSetting};
+
+use crate::application::dto::PopularPostModeDTO;
+
+/// PopularPostModeDTOをAPIレスポンス用のPopularPostModeSettingに変換
+pub fn popular_post_mode_dto_to_response(dto: PopularPostModeDTO) -> PopularPostModeSetting {
+  let mode = match dto {
+    PopularPostModeDTO::Manual => PopularPostMode::Manual,
+    PopularPostModeDTO::Automatic => PopularPostMode::Automatic,
+  };
+  PopularPostModeSetting { mode }
+}
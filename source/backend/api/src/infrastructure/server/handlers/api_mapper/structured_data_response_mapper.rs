@@ -0,0 +1,62 @@
+use common::types::api::{BlogPostingStructuredData, StructuredDataOrganization};
+
+use crate::application::dto::BlogPostingDTO;
+
+const SCHEMA_ORG_CONTEXT: &str = "https://schema.org";
+
+/// BlogPostingDTOをJSON-LDのBlogPostingに変換
+pub fn blog_posting_dto_to_response(dto: BlogPostingDTO) -> BlogPostingStructuredData {
+  // 個人ブログのため、著者・発行者ともにサイトとして出力する
+  let organization = StructuredDataOrganization {
+    schema_type: "Organization".to_string(),
+    name: dto.site_name,
+    url: dto.site_url,
+  };
+
+  BlogPostingStructuredData {
+    context: SCHEMA_ORG_CONTEXT.to_string(),
+    schema_type: "BlogPosting".to_string(),
+    headline: dto.headline,
+    description: dto.description,
+    image: dto.image,
+    main_entity_of_page: dto.url.clone(),
+    url: dto.url,
+    date_published: dto.date_published,
+    date_modified: dto.date_modified,
+    in_language: "ja".to_string(),
+    author: organization.clone(),
+    publisher: organization,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use chrono::DateTime;
+
+  #[test]
+  fn serializes_as_json_ld_blog_posting() {
+    let date = DateTime::parse_from_rfc3339("2024-03-02T00:00:00+09:00").unwrap();
+    let dto = BlogPostingDTO {
+      headline: "記事".to_string(),
+      description: "説明".to_string(),
+      image: None,
+      url: "https://blog.example.com/posts/1".to_string(),
+      date_published: date,
+      date_modified: date,
+      site_name: "鉄火ブログ".to_string(),
+      site_url: "https://blog.example.com/".to_string(),
+    };
+
+    let json = serde_json::to_value(blog_posting_dto_to_response(dto)).unwrap();
+
+    assert_eq!(json["@context"], "https://schema.org");
+    assert_eq!(json["@type"], "BlogPosting");
+    assert_eq!(json["mainEntityOfPage"], "https://blog.example.com/posts/1");
+    assert_eq!(json["datePublished"], "2024-03-02T00:00:00+09:00");
+    assert_eq!(json["inLanguage"], "ja");
+    assert_eq!(json["publisher"]["@type"], "Organization");
+    assert_eq!(json["author"]["name"], "鉄火ブログ");
+    assert!(json.get("image").is_none());
+  }
+}
@@ -0,0 +1,18 @@
+use common::types::api::TocItem;
+
+use crate::application::dto::TocItemDTO;
+
+/// Vec<TocItemDTO>をAPIレスポンス用のVec<TocItem>に変換
+pub fn toc_item_dtos_to_response(dtos: Vec<TocItemDTO>) -> Vec<TocItem> {
+  dtos.into_iter().map(toc_item_dto_to_response).collect()
+}
+
+fn toc_item_dto_to_response(dto: TocItemDTO) -> TocItem {
+  TocItem {
+    id: dto.id,
+    text: dto.text,
+    anchor: dto.anchor,
+    level: dto.level,
+    children: toc_item_dtos_to_response(dto.children),
+  }
+}
@@ -0,0 +1,231 @@
+use std::io::Read;
+
+use actix_web::web::Bytes;
+use anyhow::{bail, Context, Result};
+use common::types::api::{BlogArchiveFeaturedPosts, BlogArchiveManifest, BlogPost, Image};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// 現在書き出しているアーカイブの形式のバージョン
+pub const BLOG_ARCHIVE_FORMAT_VERSION: u32 = 1;
+
+const MANIFEST_PATH: &str = "manifest.json";
+const IMAGES_PATH: &str = "images.json";
+const FEATURED_PATH: &str = "featured.json";
+const POSTS_DIR: &str = "posts/";
+const BLOCK_SIZE: usize = 512;
+
+/// tar 形式のアーカイブの中身
+///
+/// - `manifest.json`: 形式のバージョンと件数
+/// - `images.json`: `images` テーブルの全行
+/// - `featured.json`: トップテックピック・ピックアップ記事・人気記事の記事 ID
+/// - `posts/{uuid}.json`: 記事ごとに `BlogPost` 形式の JSON
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlogArchive {
+  pub manifest: BlogArchiveManifest,
+  pub images: Vec<Image>,
+  pub featured: BlogArchiveFeaturedPosts,
+  pub posts: Vec<BlogPost>,
+}
+
+/// アーカイブを tar のエントリごとのチャンクに変換する
+///
+/// チャンクを順に送ればそのまま tar ファイルになるため、記事ごとにストリーミングでレスポンスできる
+pub fn encode_blog_archive(archive: &BlogArchive) -> Result<Vec<Bytes>> {
+  let mtime = archive.manifest.exported_at.timestamp().max(0) as u64;
+
+  let mut chunks = vec![
+    encode_entry(MANIFEST_PATH, &archive.manifest, mtime)?,
+    encode_entry(IMAGES_PATH, &archive.images, mtime)?,
+    encode_entry(FEATURED_PATH, &archive.featured, mtime)?,
+  ];
+  for post in &archive.posts {
+    chunks.push(encode_entry(&format!("{}{}.json", POSTS_DIR, post.id), post, mtime)?);
+  }
+
+  // アーカイブの終端は 0 埋めの 2 ブロック
+  chunks.push(Bytes::from(vec![0u8; BLOCK_SIZE * 2]));
+
+  Ok(chunks)
+}
+
+fn encode_entry<T: Serialize>(path: &str, value: &T, mtime: u64) -> Result<Bytes> {
+  let data = serde_json::to_vec_pretty(value).with_context(|| format!("{} の書き出しに失敗しました", path))?;
+
+  let mut header = tar::Header::new_ustar();
+  header.set_path(path).with_context(|| format!("{} はアーカイブのパスとして使えません", path))?;
+  header.set_size(data.len() as u64);
+  header.set_mode(0o644);
+  header.set_mtime(mtime);
+  header.set_entry_type(tar::EntryType::Regular);
+  header.set_cksum();
+
+  let padding = (BLOCK_SIZE - data.len() % BLOCK_SIZE) % BLOCK_SIZE;
+  let mut chunk = Vec::with_capacity(BLOCK_SIZE + data.len() + padding);
+  chunk.extend_from_slice(header.as_bytes());
+  chunk.extend_from_slice(&data);
+  chunk.resize(chunk.len() + padding, 0);
+
+  Ok(Bytes::from(chunk))
+}
+
+/// tar 形式のアーカイブを読み込む
+///
+/// 目録がない・形式のバージョンが異なる・件数が目録と一致しない・不明なファイルを含む場合はエラーとする
+pub fn decode_blog_archive(bytes: &[u8]) -> Result<BlogArchive> {
+  let mut manifest: Option<BlogArchiveManifest> = None;
+  let mut images: Option<Vec<Image>> = None;
+  let mut featured: Option<BlogArchiveFeaturedPosts> = None;
+  let mut posts = Vec::new();
+
+  let mut tar_archive = tar::Archive::new(bytes);
+  for entry in tar_archive.entries().context("tar ファイルとして読み込めません")? {
+    let mut entry = entry.context("tar ファイルとして読み込めません")?;
+    if !entry.header().entry_type().is_file() {
+      continue;
+    }
+    let path = entry.path().context("ファイル名を読み込めません")?.to_string_lossy().into_owned();
+    let mut data = Vec::new();
+    entry.read_to_end(&mut data).with_context(|| format!("{} を読み込めません", path))?;
+
+    match path.as_str() {
+      MANIFEST_PATH => manifest = Some(decode_entry(&path, &data)?),
+      IMAGES_PATH => images = Some(decode_entry(&path, &data)?),
+      FEATURED_PATH => featured = Some(decode_entry(&path, &data)?),
+      _ if path.starts_with(POSTS_DIR) && path.ends_with(".json") => {
+        let post: BlogPost = decode_entry(&path, &data)?;
+        if path != format!("{}{}.json", POSTS_DIR, post.id) {
+          bail!("{} のファイル名と記事 ID {} が一致しません", path, post.id);
+        }
+        posts.push(post);
+      }
+      _ => bail!("アーカイブに不明なファイル {} が含まれています", path),
+    }
+  }
+
+  let manifest = manifest.with_context(|| format!("{} が含まれていません", MANIFEST_PATH))?;
+  if manifest.format_version != BLOG_ARCHIVE_FORMAT_VERSION {
+    bail!("対応していない形式のバージョンです: {}", manifest.format_version);
+  }
+  let images = images.with_context(|| format!("{} が含まれていません", IMAGES_PATH))?;
+  if manifest.post_count as usize != posts.len() || manifest.image_count as usize != images.len() {
+    bail!(
+      "目録の件数（記事 {} 件・画像 {} 件）と中身（記事 {} 件・画像 {} 件）が一致しません",
+      manifest.post_count,
+      manifest.image_count,
+      posts.len(),
+      images.len()
+    );
+  }
+
+  Ok(BlogArchive {
+    manifest,
+    images,
+    featured: featured.unwrap_or_default(),
+    posts,
+  })
+}
+
+fn decode_entry<T: DeserializeOwned>(path: &str, data: &[u8]) -> Result<T> {
+  serde_json::from_slice(data).with_context(|| format!("{} の形式が不正です", path))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use chrono::{NaiveDate, TimeZone, Utc};
+  use uuid::Uuid;
+
+  fn sample_archive() -> BlogArchive {
+    let image = Image {
+      id: Uuid::from_u128(2),
+      path: "thumbnails/a.png".to_string(),
+      file_name: Some("a.png".to_string()),
+      caption: None,
+    };
+    let post = BlogPost {
+      id: Uuid::from_u128(1),
+      title: "アーカイブ記事".to_string(),
+      thumbnail: image.clone(),
+      post_date: NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
+      last_update_date: NaiveDate::from_ymd_opt(2024, 2, 3).unwrap(),
+      published_date: NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
+      contents: vec![],
+      footnotes: vec![],
+      seo: Default::default(),
+      excerpt: Default::default(),
+      manual_excerpt: Default::default(),
+      reading_time_minutes: Default::default(),
+    };
+
+    BlogArchive {
+      manifest: BlogArchiveManifest {
+        format_version: BLOG_ARCHIVE_FORMAT_VERSION,
+        exported_at: Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap(),
+        post_count: 1,
+        image_count: 1,
+      },
+      images: vec![image],
+      featured: BlogArchiveFeaturedPosts {
+        top_tech_pick_post_id: Some(post.id),
+        ..Default::default()
+      },
+      posts: vec![post],
+    }
+  }
+
+  fn to_tar(archive: &BlogArchive) -> Vec<u8> {
+    encode_blog_archive(archive).unwrap().concat()
+  }
+
+  #[test]
+  fn round_trips_through_tar() {
+    let archive = sample_archive();
+
+    let bytes = to_tar(&archive);
+
+    assert_eq!(bytes.len() % BLOCK_SIZE, 0);
+    assert_eq!(decode_blog_archive(&bytes).unwrap(), archive);
+  }
+
+  #[test]
+  fn writes_one_entry_per_post() {
+    let bytes = to_tar(&sample_archive());
+
+    let mut tar_archive = tar::Archive::new(bytes.as_slice());
+    let paths: Vec<String> = tar_archive.entries().unwrap().map(|entry| entry.unwrap().path().unwrap().to_string_lossy().into_owned()).collect();
+
+    assert_eq!(
+      paths,
+      vec![
+        "manifest.json",
+        "images.json",
+        "featured.json",
+        "posts/00000000-0000-0000-0000-000000000001.json"
+      ]
+    );
+  }
+
+  #[test]
+  fn rejects_archive_whose_counts_do_not_match_manifest() {
+    let mut archive = sample_archive();
+    archive.manifest.post_count = 2;
+
+    let result = decode_blog_archive(&to_tar(&archive));
+
+    assert!(result.unwrap_err().to_string().contains("一致しません"));
+  }
+
+  #[test]
+  fn rejects_unsupported_format_version() {
+    let mut archive = sample_archive();
+    archive.manifest.format_version = BLOG_ARCHIVE_FORMAT_VERSION + 1;
+
+    assert!(decode_blog_archive(&to_tar(&archive)).is_err());
+  }
+
+  #[test]
+  fn rejects_non_tar_input() {
+    assert!(decode_blog_archive(b"not a tar archive").is_err());
+  }
+}
@@ -2,9 +2,32 @@ use actix_web::{web, Scope};
 
 use super::image_handlers::{admin_image_scope, image_scope};
 
+/// アーカイブの取り込みで受け付けるリクエストボディの上限（画像は行のみで、ファイル本体は含まない）
+const BLOG_ARCHIVE_PAYLOAD_LIMIT: usize = 64 * 1024 * 1024;
+
+/// WordPress のエクスポートファイル（WXR）の取り込みで受け付けるリクエストボディの上限
+const WORDPRESS_IMPORT_PAYLOAD_LIMIT: usize = 32 * 1024 * 1024;
+
+/// RSS・Atom フィードをキャッシュしてよい秒数
+const FEED_MAX_AGE_SECONDS: u32 = 600;
+
+/// サイトマップをキャッシュしてよい秒数
+const SITEMAP_MAX_AGE_SECONDS: u32 = 3600;
+
+/// 関連記事の件数の既定値と上限
+const DEFAULT_RELATED_POSTS_LIMIT: usize = 3;
+const MAX_RELATED_POSTS_LIMIT: usize = 10;
+
 // TODO image_scope が同階層の別モジュールとなっており構造的に気持ち悪いので、上手く階層化する
 pub fn blog_scope() -> Scope {
-  web::scope("/blog").service(posts_scope()).service(image_scope())
+  web::scope("/blog")
+    .route("/feed.xml", web::get().to(handle_funcs::get_rss_feed))
+    .route("/atom.xml", web::get().to(handle_funcs::get_atom_feed))
+    .route("/feed.json", web::get().to(handle_funcs::get_json_feed))
+    .route("/sitemap.xml", web::get().to(handle_funcs::get_sitemap))
+    .route("/sitemap-{page}.xml", web::get().to(handle_funcs::get_sitemap_page))
+    .service(posts_scope())
+    .service(image_scope())
 }
 
 fn posts_scope() -> Scope {
@@ -13,6 +36,10 @@ fn posts_scope() -> Scope {
     .route("/top-tech-pick", web::get().to(handle_funcs::get_top_tech_pick_blog_post))
     .route("/pickup", web::get().to(handle_funcs::get_pickup_blog_posts))
     .route("/popular", web::get().to(handle_funcs::get_popular_blog_posts))
+    .route("/{uuid}/structured-data", web::get().to(handle_funcs::get_blog_post_structured_data))
+    .route("/{uuid}/toc", web::get().to(handle_funcs::get_blog_post_toc))
+    .route("/{uuid}/related", web::get().to(handle_funcs::get_related_blog_posts))
+    .route("/{uuid}/views", web::post().to(handle_funcs::record_blog_post_view))
     .route("/{uuid}", web::get().to(handle_funcs::get_blog_post))
 }
 
@@ -26,24 +53,72 @@ pub fn admin_blog_posts_scope() -> Scope {
     .route("/top-tech-pick", web::put().to(handle_funcs::put_top_tech_pick_blog_post))
     .route("/pickup", web::put().to(handle_funcs::put_pickup_blog_posts))
     .route("/popular", web::put().to(handle_funcs::put_popular_blog_posts))
+    .route("/popular/mode", web::get().to(handle_funcs::get_popular_post_mode))
+    .route("/popular/mode", web::put().to(handle_funcs::put_popular_post_mode))
+    .route("/popular/recompute", web::post().to(handle_funcs::recompute_popular_blog_posts))
+    .route("/internal-links/dangling", web::get().to(handle_funcs::get_dangling_internal_links))
+    .route("/related-index/rebuild", web::post().to(handle_funcs::rebuild_related_post_index))
+    .route("/import/markdown", web::post().to(handle_funcs::import_markdown_post))
+    .service(
+      web::resource("/import/wordpress")
+        .app_data(web::PayloadConfig::new(WORDPRESS_IMPORT_PAYLOAD_LIMIT))
+        .route(web::post().to(handle_funcs::import_wordpress_posts)),
+    )
+    .service(
+      web::resource("/archive")
+        .app_data(web::PayloadConfig::new(BLOG_ARCHIVE_PAYLOAD_LIMIT))
+        .route(web::get().to(handle_funcs::export_blog_archive))
+        .route(web::post().to(handle_funcs::import_blog_archive)),
+    )
+    .route("/{uuid}/export/markdown", web::get().to(handle_funcs::export_blog_post_markdown))
     .route("/{uuid}", web::get().to(handle_funcs::get_admin_blog_post))
     .route("/{uuid}", web::put().to(handle_funcs::update_blog_post))
+    .route("/{uuid}/link-cards/{content_id}/refresh", web::post().to(handle_funcs::refresh_link_card))
     .route("", web::get().to(handle_funcs::get_admin_blog_posts))
     .route("", web::post().to(handle_funcs::create_blog_post))
 }
 
 pub mod handle_funcs {
+  use super::{DEFAULT_RELATED_POSTS_LIMIT, FEED_MAX_AGE_SECONDS, MAX_RELATED_POSTS_LIMIT, SITEMAP_MAX_AGE_SECONDS};
+  use crate::application::dto::{BlogFeedFormatDTO, BlogPostDTO, CodeHighlightFormatDTO, LinkCardFetchFailureDTO, PageViewClientDTO};
+  use crate::application::usecase::import_blog_archive::ImportBlogArchiveError;
+  use crate::application::usecase::import_markdown_post::markdown_post_parser::MarkdownPostParserError;
+  use crate::application::usecase::import_wordpress_posts::WordPressImportError;
+  use crate::application::usecase::recompute_popular_posts::RecomputePopularPostsError;
+  use crate::application::usecase::view_sitemap::ViewSitemapError;
+  use crate::domain::blog_domain::{blog_post_factory::BlogPostFactoryError, errors::blog_domain_error::BlogDomainError, ogp_fetcher::OgpFetchError};
   use crate::infrastructure::{
     di_container::DiContainer,
     server::handlers::{
-      api_mapper::{blog_post_response_mapper, view_blog_post_dto_to_response, view_blog_post_dtos_to_response, view_latest_blog_posts_dto_to_response},
-      dto_mapper::{create_blog_post_mapper::api_create_blog_post_request_to_create_dto, update_blog_post_mapper::api_update_blog_post_request_to_update_dto},
-      response::err::ApiCustomError,
+      api_mapper::{
+        blog_archive_response_mapper::{blog_archive_dto_to_archive, import_blog_archive_result_dto_to_response},
+        blog_post_response_mapper,
+        dangling_internal_link_response_mapper::dangling_internal_link_dtos_to_response,
+        import_markdown_post_response_mapper::import_markdown_post_result_dto_to_response,
+        import_wordpress_posts_response_mapper::wordpress_import_item_report_dtos_to_response,
+        json_feed_response_mapper::json_feed_dto_to_response,
+        popular_post_mode_response_mapper::popular_post_mode_dto_to_response,
+        structured_data_response_mapper::blog_posting_dto_to_response,
+        toc_response_mapper::toc_item_dtos_to_response,
+        view_blog_post_dto_to_response, view_blog_post_dtos_to_response, view_latest_blog_posts_dto_to_response,
+      },
+      blog_archive_tar::{decode_blog_archive, encode_blog_archive},
+      client_ip::resolve_client_ip,
+      dto_mapper::{
+        create_blog_post_mapper::api_create_blog_post_request_to_create_dto, import_blog_archive_mapper::blog_archive_to_import_dto,
+        popular_post_mode_mapper::api_popular_post_mode_setting_to_dto, update_blog_post_mapper::api_update_blog_post_request_to_update_dto,
+      },
+      response::{cache::cacheable_response, err::ApiCustomError},
     },
   };
-  use actix_web::{web, HttpResponse, Responder};
+  use actix_web::{web, HttpRequest, HttpResponse, Responder};
   use anyhow::Result;
-  use common::types::api::{BlogPost, CreateBlogPostRequest, UpdateBlogPostRequest};
+  use chrono::Utc;
+  use common::types::api::{
+    BlogPost, BlogPostingStructuredData, CreateBlogPostRequest, DanglingInternalLink, ImportBlogArchiveResponse, ImportMarkdownPostResponse,
+    ImportWordPressPostsResponse, JsonFeed, PopularPostModeSetting, RebuildRelatedPostIndexResponse, TocItem, UpdateBlogPostRequest,
+  };
+  use futures::stream;
   use serde::Deserialize;
 
   #[derive(Deserialize)]
@@ -51,25 +126,103 @@ pub mod handle_funcs {
     pub include_unpublished: Option<bool>,
   }
 
+  #[derive(Deserialize)]
+  pub struct ImportBlogArchiveQuery {
+    pub dry_run: Option<bool>,
+  }
+
+  #[derive(Deserialize)]
+  pub struct RelatedBlogPostsQuery {
+    pub limit: Option<usize>,
+  }
+
+  #[derive(Deserialize)]
+  pub struct BlogPostQuery {
+    pub highlight: Option<String>,
+    pub format: Option<String>,
+  }
+
+  /// highlight クエリパラメータで指定された形式でコードブロックをハイライトする
+  ///
+  /// 指定がない場合は何もしない（クライアント側でハイライトする）
+  fn highlight_code_blocks(dto: &mut BlogPostDTO, highlight: Option<&str>, di_container: &DiContainer) -> Result<(), ApiCustomError> {
+    let format = match highlight {
+      None => return Ok(()),
+      Some("tokens") => CodeHighlightFormatDTO::Tokens,
+      Some("html") => CodeHighlightFormatDTO::Html,
+      Some(other) => {
+        return Err(ApiCustomError::ValidationError(format!(
+          "highlight には tokens または html を指定してください: {}",
+          other
+        )));
+      }
+    };
+
+    let service = di_container.code_highlight_service();
+    service.highlight_blog_post(dto, format).map_err(ApiCustomError::Other)
+  }
+
+  /// OGP メタデータを取得できなかったリンクカードを記録する（記事は空のメタデータで保存済み）
+  fn log_link_card_fetch_failures(failures: &[LinkCardFetchFailureDTO]) {
+    for failure in failures {
+      eprintln!(
+        "リンクカード {} の OGP メタデータを取得できませんでした: {}",
+        failure.content_id, failure.message
+      );
+    }
+  }
+
+  /// 公開記事を HTML としてレスポンスする
+  async fn get_blog_post_html(post_id: &str, di_container: &DiContainer) -> Result<HttpResponse, ApiCustomError> {
+    let usecase = di_container.render_blog_post_html_usecase();
+    let html = usecase.execute(post_id).await.map_err(|e| {
+      if e.to_string().starts_with("BlogPostNotFound:") {
+        ApiCustomError::BlogPostNotFound(post_id.to_string())
+      } else {
+        ApiCustomError::Other(e)
+      }
+    })?;
+
+    Ok(HttpResponse::Ok().content_type("text/html; charset=utf-8").body(html))
+  }
+
   #[utoipa::path(
     get,
     path = "/api/blog/posts/{uuid}",
     responses(
-      (status = 200, description = "Blog post found", body = BlogPost),
+      (status = 200, description = "Blog post found (rendered as text/html when format=html)", body = BlogPost),
+      (status = 400, description = "Invalid highlight format or output format"),
       (status = 404, description = "Blog post not found")
     ),
     params(
-      ("uuid" = String, Path, description = "Blog post UUID")
+      ("uuid" = String, Path, description = "Blog post UUID"),
+      ("highlight" = Option<String>, Query, description = "Return server-side highlighted code blocks as `tokens` or `html`"),
+      ("format" = Option<String>, Query, description = "Output format: `json` (default) or `html`")
     )
   )]
-  pub async fn get_blog_post(path: web::Path<String>, di_container: web::Data<DiContainer>) -> Result<impl Responder, ApiCustomError> {
+  pub async fn get_blog_post(
+    path: web::Path<String>,
+    query: web::Query<BlogPostQuery>,
+    di_container: web::Data<DiContainer>,
+  ) -> Result<impl Responder, ApiCustomError> {
     println!("get_blog_post");
     println!("path: {:?}", path);
     let post_id = path.into_inner();
 
+    match query.format.as_deref() {
+      None | Some("json") => {}
+      Some("html") => return get_blog_post_html(&post_id, &di_container).await,
+      Some(other) => {
+        return Err(ApiCustomError::ValidationError(format!(
+          "format には json または html を指定してください: {}",
+          other
+        )));
+      }
+    }
+
     // DIコンテナからユースケースを取得
     let usecase = di_container.view_blog_post_usecase();
-    let dto = usecase.execute(&post_id).await.map_err(|e| {
+    let mut dto = usecase.execute(&post_id).await.map_err(|e| {
       // BlogPostNotFound エラーを特別扱い
       let error_message = e.to_string();
       if error_message.starts_with("BlogPostNotFound:") {
@@ -79,12 +232,148 @@ pub mod handle_funcs {
       }
     })?;
 
+    // 要求された場合はコードブロックをサーバー側でハイライトする
+    highlight_code_blocks(&mut dto, query.highlight.as_deref(), &di_container)?;
+
     // DTOをAPIレスポンスに変換
     let blog_post = view_blog_post_dto_to_response(dto).map_err(|e| ApiCustomError::Other(e))?;
 
     Ok(HttpResponse::Ok().json(blog_post))
   }
 
+  #[utoipa::path(
+    get,
+    path = "/api/blog/posts/{uuid}/structured-data",
+    responses(
+      (status = 200, description = "JSON-LD BlogPosting structured data of the published blog post", body = BlogPostingStructuredData, content_type = "application/ld+json"),
+      (status = 404, description = "Blog post not found")
+    ),
+    params(
+      ("uuid" = String, Path, description = "Blog post UUID")
+    )
+  )]
+  pub async fn get_blog_post_structured_data(path: web::Path<String>, di_container: web::Data<DiContainer>) -> Result<impl Responder, ApiCustomError> {
+    let post_id = path.into_inner();
+
+    let usecase = di_container.view_blog_post_structured_data_usecase();
+    let dto = usecase.execute(&post_id).await.map_err(|e| {
+      if e.to_string().starts_with("BlogPostNotFound:") {
+        ApiCustomError::BlogPostNotFound(post_id.clone())
+      } else {
+        ApiCustomError::Other(e)
+      }
+    })?;
+
+    let json = serde_json::to_string(&blog_posting_dto_to_response(dto)).map_err(|e| ApiCustomError::Other(e.into()))?;
+
+    Ok(HttpResponse::Ok().content_type("application/ld+json; charset=utf-8").body(json))
+  }
+
+  #[utoipa::path(
+    get,
+    path = "/api/blog/posts/{uuid}/toc",
+    responses(
+      (status = 200, description = "Table of contents (H2 -> H3 tree) of the published blog post", body = Vec<TocItem>),
+      (status = 404, description = "Blog post not found")
+    ),
+    params(
+      ("uuid" = String, Path, description = "Blog post UUID")
+    )
+  )]
+  pub async fn get_blog_post_toc(path: web::Path<String>, di_container: web::Data<DiContainer>) -> Result<impl Responder, ApiCustomError> {
+    let post_id = path.into_inner();
+
+    let usecase = di_container.view_blog_post_toc_usecase();
+    let dtos = usecase.execute(&post_id).await.map_err(|e| {
+      if e.to_string().starts_with("BlogPostNotFound:") {
+        ApiCustomError::BlogPostNotFound(post_id.clone())
+      } else {
+        ApiCustomError::Other(e)
+      }
+    })?;
+
+    Ok(HttpResponse::Ok().json(toc_item_dtos_to_response(dtos)))
+  }
+
+  #[utoipa::path(
+    get,
+    path = "/api/blog/posts/{uuid}/related",
+    responses(
+      (status = 200, description = "Published blog posts related to the blog post, most similar first", body = Vec<BlogPost>),
+      (status = 400, description = "Invalid limit"),
+      (status = 404, description = "Blog post not found")
+    ),
+    params(
+      ("uuid" = String, Path, description = "Blog post UUID"),
+      ("limit" = Option<usize>, Query, description = "Maximum number of related posts (1-10, default 3)")
+    )
+  )]
+  pub async fn get_related_blog_posts(
+    path: web::Path<String>,
+    query: web::Query<RelatedBlogPostsQuery>,
+    di_container: web::Data<DiContainer>,
+  ) -> Result<impl Responder, ApiCustomError> {
+    let post_id = path.into_inner();
+
+    let limit = query.limit.unwrap_or(DEFAULT_RELATED_POSTS_LIMIT);
+    if !(1..=MAX_RELATED_POSTS_LIMIT).contains(&limit) {
+      return Err(ApiCustomError::ValidationError(format!(
+        "limit には 1 から {} までの値を指定してください: {}",
+        MAX_RELATED_POSTS_LIMIT, limit
+      )));
+    }
+
+    let usecase = di_container.view_related_posts_usecase();
+    let dtos = usecase.execute(&post_id, limit).await.map_err(|e| {
+      if e.to_string().starts_with("BlogPostNotFound:") {
+        ApiCustomError::BlogPostNotFound(post_id.clone())
+      } else {
+        ApiCustomError::Other(e)
+      }
+    })?;
+
+    let blog_posts = view_blog_post_dtos_to_response(dtos).map_err(ApiCustomError::Other)?;
+
+    Ok(HttpResponse::Ok().json(blog_posts))
+  }
+
+  #[utoipa::path(
+    post,
+    path = "/api/blog/posts/{uuid}/views",
+    responses(
+      (status = 204, description = "Page view accepted (views by bots and repeated views by the same visitor on the same day are not counted)"),
+      (status = 404, description = "Blog post not found")
+    ),
+    params(
+      ("uuid" = String, Path, description = "Blog post UUID")
+    )
+  )]
+  pub async fn record_blog_post_view(
+    req: HttpRequest,
+    path: web::Path<String>,
+    di_container: web::Data<DiContainer>,
+  ) -> Result<impl Responder, ApiCustomError> {
+    let post_id = path.into_inner();
+
+    // 信頼するプロキシを経由した場合のみ X-Forwarded-For から閲覧者の IP アドレスを取得する
+    let client = PageViewClientDTO {
+      ip: resolve_client_ip(&req, di_container.trusted_proxies()).map(|ip| ip.to_string()),
+      user_agent: req.headers().get(actix_web::http::header::USER_AGENT).and_then(|value| value.to_str().ok()).map(str::to_string),
+    };
+
+    let usecase = di_container.record_page_view_usecase();
+    usecase.execute(&post_id, client).await.map_err(|e| {
+      if e.to_string().starts_with("BlogPostNotFound:") {
+        ApiCustomError::BlogPostNotFound(post_id.clone())
+      } else {
+        ApiCustomError::Other(e)
+      }
+    })?;
+
+    // 数えたかどうかは返さない
+    Ok(HttpResponse::NoContent().finish())
+  }
+
   #[utoipa::path(
     get,
     path = "/api/blog/posts/latest",
@@ -105,6 +394,99 @@ pub mod handle_funcs {
     Ok(HttpResponse::Ok().json(blog_posts))
   }
 
+  /// フィードを出力し、ETag・Last-Modified 付きでレスポンスする
+  async fn get_blog_feed(req: &HttpRequest, di_container: &DiContainer, format: BlogFeedFormatDTO, content_type: &str) -> Result<HttpResponse, ApiCustomError> {
+    let usecase = di_container.view_blog_feed_usecase();
+    let feed = usecase.execute(format).await.map_err(ApiCustomError::Other)?;
+
+    Ok(cacheable_response(req, content_type, feed.xml, feed.last_modified, FEED_MAX_AGE_SECONDS))
+  }
+
+  #[utoipa::path(
+    get,
+    path = "/api/blog/feed.xml",
+    responses(
+      (status = 200, description = "RSS 2.0 feed of the latest published blog posts", content_type = "application/rss+xml"),
+      (status = 304, description = "Feed not modified since the ETag / Last-Modified sent by the client")
+    )
+  )]
+  pub async fn get_rss_feed(req: HttpRequest, di_container: web::Data<DiContainer>) -> Result<impl Responder, ApiCustomError> {
+    get_blog_feed(&req, &di_container, BlogFeedFormatDTO::Rss, "application/rss+xml; charset=utf-8").await
+  }
+
+  #[utoipa::path(
+    get,
+    path = "/api/blog/atom.xml",
+    responses(
+      (status = 200, description = "Atom feed of the latest published blog posts", content_type = "application/atom+xml"),
+      (status = 304, description = "Feed not modified since the ETag / Last-Modified sent by the client")
+    )
+  )]
+  pub async fn get_atom_feed(req: HttpRequest, di_container: web::Data<DiContainer>) -> Result<impl Responder, ApiCustomError> {
+    get_blog_feed(&req, &di_container, BlogFeedFormatDTO::Atom, "application/atom+xml; charset=utf-8").await
+  }
+
+  #[utoipa::path(
+    get,
+    path = "/api/blog/feed.json",
+    responses(
+      (status = 200, description = "JSON Feed 1.1 of the latest published blog posts", body = JsonFeed, content_type = "application/feed+json"),
+      (status = 304, description = "Feed not modified since the ETag / Last-Modified sent by the client")
+    )
+  )]
+  pub async fn get_json_feed(req: HttpRequest, di_container: web::Data<DiContainer>) -> Result<impl Responder, ApiCustomError> {
+    let usecase = di_container.view_blog_json_feed_usecase();
+    let dto = usecase.execute().await.map_err(ApiCustomError::Other)?;
+    let last_modified = dto.last_modified;
+
+    let json = serde_json::to_string(&json_feed_dto_to_response(dto)).map_err(|e| ApiCustomError::Other(e.into()))?;
+
+    Ok(cacheable_response(
+      &req,
+      "application/feed+json; charset=utf-8",
+      json,
+      last_modified,
+      FEED_MAX_AGE_SECONDS,
+    ))
+  }
+
+  #[utoipa::path(
+    get,
+    path = "/api/blog/sitemap.xml",
+    responses(
+      (status = 200, description = "Sitemap of published blog posts, or a sitemap index when they exceed 50,000 URLs", content_type = "application/xml")
+    )
+  )]
+  pub async fn get_sitemap(req: HttpRequest, di_container: web::Data<DiContainer>) -> Result<impl Responder, ApiCustomError> {
+    let usecase = di_container.view_sitemap_usecase();
+    let xml = usecase.execute().await.map_err(ApiCustomError::Other)?;
+
+    Ok(cacheable_response(&req, "application/xml; charset=utf-8", xml, None, SITEMAP_MAX_AGE_SECONDS))
+  }
+
+  #[utoipa::path(
+    get,
+    path = "/api/blog/sitemap-{page}.xml",
+    responses(
+      (status = 200, description = "One of the sitemaps listed in the sitemap index", content_type = "application/xml"),
+      (status = 404, description = "Sitemap page out of range")
+    ),
+    params(
+      ("page" = u64, Path, description = "1-based sitemap number")
+    )
+  )]
+  pub async fn get_sitemap_page(req: HttpRequest, path: web::Path<u64>, di_container: web::Data<DiContainer>) -> Result<impl Responder, ApiCustomError> {
+    let page = path.into_inner();
+
+    let usecase = di_container.view_sitemap_usecase();
+    let xml = usecase.execute_page(page).await.map_err(|e| match e.downcast_ref::<ViewSitemapError>() {
+      Some(ViewSitemapError::PageNotFound(_)) => ApiCustomError::ContentNotFound(e.to_string()),
+      None => ApiCustomError::Other(e),
+    })?;
+
+    Ok(cacheable_response(&req, "application/xml; charset=utf-8", xml, None, SITEMAP_MAX_AGE_SECONDS))
+  }
+
   #[utoipa::path(
     get,
     path = "/api/blog/posts/top-tech-pick",
@@ -248,6 +630,60 @@ pub mod handle_funcs {
     Ok(HttpResponse::Ok().json(blog_posts))
   }
 
+  #[utoipa::path(
+    get,
+    path = "/api/admin/blog/posts/popular/mode",
+    responses(
+      (status = 200, description = "How popular posts are selected", body = PopularPostModeSetting)
+    )
+  )]
+  pub async fn get_popular_post_mode(di_container: web::Data<DiContainer>) -> Result<impl Responder, ApiCustomError> {
+    let usecase = di_container.view_popular_post_mode_usecase();
+    let dto = usecase.execute().await.map_err(ApiCustomError::Other)?;
+
+    Ok(HttpResponse::Ok().json(popular_post_mode_dto_to_response(dto)))
+  }
+
+  #[utoipa::path(
+    put,
+    path = "/api/admin/blog/posts/popular/mode",
+    request_body = PopularPostModeSetting,
+    responses(
+      (status = 200, description = "How popular posts are selected was updated", body = PopularPostModeSetting)
+    )
+  )]
+  pub async fn put_popular_post_mode(
+    setting_req: web::Json<PopularPostModeSetting>,
+    di_container: web::Data<DiContainer>,
+  ) -> Result<impl Responder, ApiCustomError> {
+    let mode = api_popular_post_mode_setting_to_dto(setting_req.into_inner());
+
+    let usecase = di_container.update_popular_post_mode_usecase();
+    let dto = usecase.execute(mode).await.map_err(ApiCustomError::Other)?;
+
+    Ok(HttpResponse::Ok().json(popular_post_mode_dto_to_response(dto)))
+  }
+
+  #[utoipa::path(
+    post,
+    path = "/api/admin/blog/posts/popular/recompute",
+    responses(
+      (status = 200, description = "Popular blog posts reselected from recent page views", body = Vec<BlogPost>),
+      (status = 409, description = "Popular posts are selected manually, or not enough posts have been viewed")
+    )
+  )]
+  pub async fn recompute_popular_blog_posts(di_container: web::Data<DiContainer>) -> Result<impl Responder, ApiCustomError> {
+    let usecase = di_container.recompute_popular_posts_usecase();
+    let dtos = usecase.execute().await.map_err(|e| match e.downcast_ref::<RecomputePopularPostsError>() {
+      Some(recompute_error) => ApiCustomError::Conflict(recompute_error.to_string()),
+      None => ApiCustomError::Other(e),
+    })?;
+
+    let blog_posts = view_blog_post_dtos_to_response(dtos).map_err(ApiCustomError::Other)?;
+
+    Ok(HttpResponse::Ok().json(blog_posts))
+  }
+
   #[utoipa::path(
     get,
     path = "/api/admin/blog/posts",
@@ -279,7 +715,8 @@ pub mod handle_funcs {
     path = "/api/admin/blog/posts",
     request_body = CreateBlogPostRequest,
     responses(
-      (status = 200, description = "Blog post created", body = BlogPost)
+      (status = 200, description = "Blog post created", body = BlogPost),
+      (status = 400, description = "Invalid blog post content")
     )
   )]
   pub async fn create_blog_post(
@@ -293,33 +730,166 @@ pub mod handle_funcs {
 
     // DIコンテナからユースケースを取得
     let usecase = di_container.create_blog_post_usecase();
-    let blog_post_dto = usecase.execute(create_dto).await.map_err(|e| ApiCustomError::Other(e))?;
+    let result_dto = usecase.execute(create_dto).await.map_err(|e| {
+      // コンテンツの検証エラーはバリデーションエラーとして扱う
+      if let Some(BlogPostFactoryError::InvalidContent(domain_error)) = e.downcast_ref::<BlogPostFactoryError>() {
+        ApiCustomError::ValidationError(domain_error.to_string())
+      } else {
+        ApiCustomError::Other(e)
+      }
+    })?;
+    log_link_card_fetch_failures(&result_dto.link_card_fetch_failures);
 
     // DTOをAPIレスポンスに変換
-    let blog_post = view_blog_post_dto_to_response(blog_post_dto).map_err(|e| ApiCustomError::Other(e))?;
+    let blog_post = view_blog_post_dto_to_response(result_dto.blog_post).map_err(|e| ApiCustomError::Other(e))?;
 
     Ok(HttpResponse::Ok().json(blog_post))
   }
 
+  #[utoipa::path(
+    post,
+    path = "/api/admin/blog/posts/import/markdown",
+    request_body(content = String, content_type = "text/markdown", description = "Markdown file with optional YAML front matter"),
+    responses(
+      (status = 200, description = "Blog post created from Markdown, with warnings for constructs that could not be imported as-is", body = ImportMarkdownPostResponse),
+      (status = 400, description = "Invalid front matter, missing title or invalid content")
+    )
+  )]
+  pub async fn import_markdown_post(markdown: String, di_container: web::Data<DiContainer>) -> Result<impl Responder, ApiCustomError> {
+    // DIコンテナからユースケースを取得
+    let usecase = di_container.import_markdown_post_usecase();
+    let result_dto = usecase.execute(&markdown).await.map_err(|e| {
+      // フロントマターやコンテンツの検証エラーはバリデーションエラーとして扱う
+      if let Some(MarkdownPostParserError::InvalidMarkdown(domain_error)) = e.downcast_ref::<MarkdownPostParserError>() {
+        ApiCustomError::ValidationError(domain_error.to_string())
+      } else if let Some(BlogPostFactoryError::InvalidContent(domain_error)) = e.downcast_ref::<BlogPostFactoryError>() {
+        ApiCustomError::ValidationError(domain_error.to_string())
+      } else {
+        ApiCustomError::Other(e)
+      }
+    })?;
+    log_link_card_fetch_failures(&result_dto.link_card_fetch_failures);
+
+    // DTOをAPIレスポンスに変換
+    let response = import_markdown_post_result_dto_to_response(result_dto).map_err(ApiCustomError::Other)?;
+
+    Ok(HttpResponse::Ok().json(response))
+  }
+
+  #[utoipa::path(
+    post,
+    path = "/api/admin/blog/posts/import/wordpress",
+    request_body(content = String, content_type = "application/xml", description = "WordPress export file (WXR)"),
+    responses(
+      (status = 200, description = "Per-item report of imported, skipped and failed WordPress posts, with what could not be imported as-is", body = ImportWordPressPostsResponse),
+      (status = 400, description = "Not a WordPress export file")
+    )
+  )]
+  pub async fn import_wordpress_posts(wxr: String, di_container: web::Data<DiContainer>) -> Result<impl Responder, ApiCustomError> {
+    // DIコンテナからユースケースを取得
+    let usecase = di_container.import_wordpress_posts_usecase();
+    let report_dtos = usecase.execute(&wxr).await.map_err(|e| {
+      // WXR として読み込めない場合はバリデーションエラーとして扱う（記事ごとの失敗はレポートに含まれる）
+      if let Some(import_error) = e.downcast_ref::<WordPressImportError>() {
+        ApiCustomError::ValidationError(import_error.to_string())
+      } else {
+        ApiCustomError::Other(e)
+      }
+    })?;
+
+    Ok(HttpResponse::Ok().json(wordpress_import_item_report_dtos_to_response(report_dtos)))
+  }
+
+  #[utoipa::path(
+    get,
+    path = "/api/admin/blog/posts/archive",
+    responses(
+      (status = 200, description = "Tar archive of every post (as BlogPost JSON), all images rows and the featured post settings", content_type = "application/x-tar")
+    )
+  )]
+  pub async fn export_blog_archive(di_container: web::Data<DiContainer>) -> Result<impl Responder, ApiCustomError> {
+    // DIコンテナからユースケースを取得
+    let usecase = di_container.export_blog_archive_usecase();
+    let dto = usecase.execute().await.map_err(ApiCustomError::Other)?;
+
+    // DTOをアーカイブに変換し、ファイルごとに分けて送る
+    let exported_at = Utc::now();
+    let archive = blog_archive_dto_to_archive(dto, exported_at).map_err(ApiCustomError::Other)?;
+    let chunks = encode_blog_archive(&archive).map_err(ApiCustomError::Other)?;
+
+    Ok(
+      HttpResponse::Ok()
+        .content_type("application/x-tar")
+        .insert_header((
+          "Content-Disposition",
+          format!("attachment; filename=\"blog-archive-{}.tar\"", exported_at.format("%Y%m%d%H%M%S")),
+        ))
+        .streaming(stream::iter(chunks.into_iter().map(Ok::<_, actix_web::Error>))),
+    )
+  }
+
+  #[utoipa::path(
+    post,
+    path = "/api/admin/blog/posts/archive",
+    request_body(content = Vec<u8>, description = "Tar archive produced by the export endpoint", content_type = "application/x-tar"),
+    responses(
+      (status = 200, description = "Archive restored (or only checked when dry_run=true)", body = ImportBlogArchiveResponse),
+      (status = 400, description = "Invalid archive"),
+      (status = 409, description = "Archive conflicts with existing images")
+    ),
+    params(
+      ("dry_run" = Option<bool>, Query, description = "Only validate the archive and report conflicts by UUID without writing anything")
+    )
+  )]
+  pub async fn import_blog_archive(
+    body: web::Bytes,
+    query: web::Query<ImportBlogArchiveQuery>,
+    di_container: web::Data<DiContainer>,
+  ) -> Result<impl Responder, ApiCustomError> {
+    // tar ファイルを読み込んでDTOに変換
+    let archive = decode_blog_archive(&body).map_err(|e| ApiCustomError::ValidationError(format!("{:#}", e)))?;
+    let import_dto = blog_archive_to_import_dto(archive);
+
+    // DIコンテナからユースケースを取得
+    let usecase = di_container.import_blog_archive_usecase();
+    let result_dto = usecase.execute(import_dto, query.dry_run.unwrap_or(false)).await.map_err(|e| {
+      if let Some(conflict_error) = e.downcast_ref::<ImportBlogArchiveError>() {
+        ApiCustomError::Conflict(conflict_error.to_string())
+      } else if let Some(domain_error) = e.downcast_ref::<BlogDomainError>() {
+        ApiCustomError::ValidationError(domain_error.to_string())
+      } else {
+        ApiCustomError::Other(e)
+      }
+    })?;
+
+    Ok(HttpResponse::Ok().json(import_blog_archive_result_dto_to_response(result_dto)))
+  }
+
   #[utoipa::path(
     get,
     path = "/api/admin/blog/posts/{uuid}",
     responses(
       (status = 200, description = "Blog post found (including unpublished)", body = BlogPost),
+      (status = 400, description = "Invalid highlight format"),
       (status = 404, description = "Blog post not found")
     ),
     params(
-      ("uuid" = String, Path, description = "Blog post UUID")
+      ("uuid" = String, Path, description = "Blog post UUID"),
+      ("highlight" = Option<String>, Query, description = "Return server-side highlighted code blocks as `tokens` or `html`")
     )
   )]
-  pub async fn get_admin_blog_post(path: web::Path<String>, di_container: web::Data<DiContainer>) -> Result<impl Responder, ApiCustomError> {
+  pub async fn get_admin_blog_post(
+    path: web::Path<String>,
+    query: web::Query<BlogPostQuery>,
+    di_container: web::Data<DiContainer>,
+  ) -> Result<impl Responder, ApiCustomError> {
     println!("get_admin_blog_post");
     println!("path: {:?}", path);
     let post_id = path.into_inner();
 
     // DIコンテナからユースケースを取得
     let usecase = di_container.view_admin_blog_post_usecase();
-    let dto = usecase.execute(&post_id).await.map_err(|e| {
+    let mut dto = usecase.execute(&post_id).await.map_err(|e| {
       // BlogPostNotFound エラーを特別扱い
       let error_message = e.to_string();
       if error_message.starts_with("BlogPostNotFound:") {
@@ -329,18 +899,53 @@ pub mod handle_funcs {
       }
     })?;
 
+    // 要求された場合はコードブロックをサーバー側でハイライトする
+    highlight_code_blocks(&mut dto, query.highlight.as_deref(), &di_container)?;
+
     // DTOをAPIレスポンスに変換
     let blog_post = view_blog_post_dto_to_response(dto).map_err(|e| ApiCustomError::Other(e))?;
 
     Ok(HttpResponse::Ok().json(blog_post))
   }
 
+  #[utoipa::path(
+    get,
+    path = "/api/admin/blog/posts/{uuid}/export/markdown",
+    responses(
+      (status = 200, description = "Blog post exported as Markdown with YAML front matter (including unpublished)", content_type = "text/markdown"),
+      (status = 404, description = "Blog post not found")
+    ),
+    params(
+      ("uuid" = String, Path, description = "Blog post UUID")
+    )
+  )]
+  pub async fn export_blog_post_markdown(path: web::Path<String>, di_container: web::Data<DiContainer>) -> Result<impl Responder, ApiCustomError> {
+    let post_id = path.into_inner();
+
+    let usecase = di_container.export_blog_post_markdown_usecase();
+    let markdown = usecase.execute(&post_id).await.map_err(|e| {
+      if e.to_string().starts_with("BlogPostNotFound:") {
+        ApiCustomError::BlogPostNotFound(post_id.clone())
+      } else {
+        ApiCustomError::Other(e)
+      }
+    })?;
+
+    Ok(
+      HttpResponse::Ok()
+        .content_type("text/markdown; charset=utf-8")
+        .insert_header(("Content-Disposition", format!("attachment; filename=\"{}.md\"", post_id)))
+        .body(markdown),
+    )
+  }
+
   #[utoipa::path(
     put,
     path = "/api/admin/blog/posts/{uuid}",
     request_body = UpdateBlogPostRequest,
     responses(
       (status = 200, description = "Blog post updated", body = BlogPost),
+      (status = 400, description = "Invalid blog post content"),
       (status = 404, description = "Blog post not found")
     ),
     params(
@@ -361,21 +966,92 @@ pub mod handle_funcs {
 
     // DIコンテナからユースケースを取得
     let usecase = di_container.update_blog_post_usecase();
-    let blog_post_dto = usecase.execute(&post_id, update_dto).await.map_err(|e| {
+    let result_dto = usecase.execute(&post_id, update_dto).await.map_err(|e| {
       // エラーメッセージによる分類
       let error_message = e.to_string();
       if error_message.contains("非公開にできません") {
         ApiCustomError::ValidationError(error_message)
+      } else if let Some(domain_error) = e.downcast_ref::<BlogDomainError>() {
+        ApiCustomError::ValidationError(domain_error.to_string())
       } else if error_message.starts_with("BlogPostNotFound:") {
         ApiCustomError::BlogPostNotFound(post_id.clone())
       } else {
         ApiCustomError::Other(e)
       }
     })?;
+    log_link_card_fetch_failures(&result_dto.link_card_fetch_failures);
+
+    // DTOをAPIレスポンスに変換
+    let blog_post = view_blog_post_dto_to_response(result_dto.blog_post).map_err(|e| ApiCustomError::Other(e))?;
+
+    Ok(HttpResponse::Ok().json(blog_post))
+  }
+
+  #[utoipa::path(
+    post,
+    path = "/api/admin/blog/posts/{uuid}/link-cards/{content_id}/refresh",
+    responses(
+      (status = 200, description = "Link card metadata refreshed", body = BlogPost),
+      (status = 404, description = "Blog post or link card not found"),
+      (status = 502, description = "Failed to fetch OGP metadata")
+    ),
+    params(
+      ("uuid" = String, Path, description = "Blog post UUID"),
+      ("content_id" = String, Path, description = "Link card content UUID")
+    )
+  )]
+  pub async fn refresh_link_card(path: web::Path<(String, String)>, di_container: web::Data<DiContainer>) -> Result<impl Responder, ApiCustomError> {
+    let (post_id, content_id) = path.into_inner();
+
+    // DIコンテナからユースケースを取得
+    let usecase = di_container.refresh_link_card_usecase();
+    let blog_post_dto = usecase.execute(&post_id, &content_id).await.map_err(|e| {
+      if let Some(BlogDomainError::LinkCardNotFound { content_id }) = e.downcast_ref::<BlogDomainError>() {
+        ApiCustomError::ContentNotFound(content_id.clone())
+      } else if e.downcast_ref::<OgpFetchError>().is_some() {
+        ApiCustomError::ExternalServiceError(format!("{:#}", e))
+      } else if e.to_string().starts_with("BlogPostNotFound:") {
+        ApiCustomError::BlogPostNotFound(post_id.clone())
+      } else {
+        ApiCustomError::Other(e)
+      }
+    })?;
 
     // DTOをAPIレスポンスに変換
     let blog_post = view_blog_post_dto_to_response(blog_post_dto).map_err(|e| ApiCustomError::Other(e))?;
 
     Ok(HttpResponse::Ok().json(blog_post))
   }
+
+  #[utoipa::path(
+    get,
+    path = "/api/admin/blog/posts/internal-links/dangling",
+    responses(
+      (status = 200, description = "Dangling internal links", body = Vec<DanglingInternalLink>)
+    )
+  )]
+  pub async fn get_dangling_internal_links(di_container: web::Data<DiContainer>) -> Result<impl Responder, ApiCustomError> {
+    // DIコンテナからユースケースを取得
+    let usecase = di_container.find_dangling_internal_links_usecase();
+    let dtos = usecase.execute().await.map_err(ApiCustomError::Other)?;
+
+    // DTOをAPIレスポンスに変換
+    let dangling_links = dangling_internal_link_dtos_to_response(dtos);
+
+    Ok(HttpResponse::Ok().json(dangling_links))
+  }
+
+  #[utoipa::path(
+    post,
+    path = "/api/admin/blog/posts/related-index/rebuild",
+    responses(
+      (status = 200, description = "Term vectors of all blog posts used for related posts were rebuilt", body = RebuildRelatedPostIndexResponse)
+    )
+  )]
+  pub async fn rebuild_related_post_index(di_container: web::Data<DiContainer>) -> Result<impl Responder, ApiCustomError> {
+    let usecase = di_container.rebuild_related_post_index_usecase();
+    let post_count = usecase.execute().await.map_err(ApiCustomError::Other)?;
+
+    Ok(HttpResponse::Ok().json(RebuildRelatedPostIndexResponse { post_count: post_count as u32 }))
+  }
 }
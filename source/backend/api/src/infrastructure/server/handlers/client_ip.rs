@@ -0,0 +1,82 @@
+use std::net::IpAddr;
+
+use actix_web::HttpRequest;
+
+const X_FORWARDED_FOR: &str = "x-forwarded-for";
+
+/// リクエストを送ってきた閲覧者の IP アドレスを求める
+///
+/// 接続元が信頼するプロキシの場合に限り `X-Forwarded-For` を読む。
+/// 値の末尾（接続元に近い側）からたどり、信頼するプロキシ以外で最初に現れたアドレスを閲覧者とする。
+/// 閲覧者が先頭に任意の値を書き足しても、信頼するプロキシが付け加えた部分より前は読まない
+pub fn resolve_client_ip(req: &HttpRequest, trusted_proxies: &[IpAddr]) -> Option<IpAddr> {
+  let forwarded_for = req.headers().get(X_FORWARDED_FOR).and_then(|value| value.to_str().ok());
+  client_ip_from(req.peer_addr().map(|addr| addr.ip()), forwarded_for, trusted_proxies)
+}
+
+fn client_ip_from(peer: Option<IpAddr>, forwarded_for: Option<&str>, trusted_proxies: &[IpAddr]) -> Option<IpAddr> {
+  let mut client = peer?;
+  if !trusted_proxies.contains(&client) {
+    return Some(client);
+  }
+
+  for value in forwarded_for.unwrap_or_default().rsplit(',') {
+    // 読み取れない値がある場合は、それより前の値も信頼できないため直前のプロキシを閲覧者とする
+    let Ok(address) = value.trim().parse::<IpAddr>() else {
+      break;
+    };
+    client = address;
+    if !trusted_proxies.contains(&client) {
+      break;
+    }
+  }
+  Some(client)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn ip(value: &str) -> IpAddr {
+    value.parse().unwrap()
+  }
+
+  #[test]
+  fn ignores_forwarded_header_from_untrusted_peer() {
+    let client = client_ip_from(Some(ip("203.0.113.5")), Some("198.51.100.1"), &[ip("10.0.0.1")]);
+
+    assert_eq!(client, Some(ip("203.0.113.5")));
+  }
+
+  #[test]
+  fn reads_forwarded_header_from_trusted_proxy() {
+    let client = client_ip_from(Some(ip("10.0.0.1")), Some("198.51.100.1"), &[ip("10.0.0.1")]);
+
+    assert_eq!(client, Some(ip("198.51.100.1")));
+  }
+
+  #[test]
+  fn ignores_addresses_prepended_by_client() {
+    // 閲覧者が書き足した 192.0.2.1 は、信頼するプロキシが付け加えた 198.51.100.1 より前にあるため読まない
+    let client = client_ip_from(Some(ip("10.0.0.1")), Some("192.0.2.1, 198.51.100.1"), &[ip("10.0.0.1")]);
+
+    assert_eq!(client, Some(ip("198.51.100.1")));
+  }
+
+  #[test]
+  fn skips_chained_trusted_proxies() {
+    let trusted_proxies = [ip("10.0.0.1"), ip("10.0.0.2")];
+
+    let client = client_ip_from(Some(ip("10.0.0.1")), Some("198.51.100.1, 10.0.0.2"), &trusted_proxies);
+
+    assert_eq!(client, Some(ip("198.51.100.1")));
+  }
+
+  #[test]
+  fn falls_back_to_proxy_when_header_is_missing_or_invalid() {
+    let trusted_proxies = [ip("10.0.0.1")];
+
+    assert_eq!(client_ip_from(Some(ip("10.0.0.1")), None, &trusted_proxies), Some(ip("10.0.0.1")));
+    assert_eq!(client_ip_from(Some(ip("10.0.0.1")), Some("unknown"), &trusted_proxies), Some(ip("10.0.0.1")));
+  }
+}
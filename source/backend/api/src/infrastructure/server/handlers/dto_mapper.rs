@@ -3,6 +3,8 @@
 
 pub mod api_image_to_register_dto_mapper;
 pub mod create_blog_post_mapper;
+pub mod import_blog_archive_mapper;
+pub mod popular_post_mode_mapper;
 pub mod update_blog_post_mapper;
 
 pub use api_image_to_register_dto_mapper::api_create_image_request_to_register_dto;
@@ -1,5 +1,7 @@
+use crate::application::dto::{DiagramKindDTO, HighlightColorDTO};
 use crate::application::usecase::create_blog_post::dto::{
-  CreateBlogPostDTO, CreateContentDTO, CreateImageDTO, CreateLinkDTO, CreateRichTextDTO, CreateStyleDTO,
+  CreateBlogPostDTO, CreateCodeBlockOptionsDTO, CreateContentDTO, CreateFootnoteDTO, CreateImageDTO, CreateImagePresentationDTO, CreateLinkDTO,
+  CreateRichTextDTO, CreateSeoMetadataDTO, CreateStyleDTO,
 };
 use common::types::api;
 use uuid::Uuid;
@@ -13,6 +15,28 @@ pub fn api_create_blog_post_request_to_create_dto(request: api::CreateBlogPostRe
     last_update_date: Some(request.last_update_date),
     published_date: Some(request.published_date),
     contents: request.contents.into_iter().map(api_create_content_request_to_create_dto).collect(),
+    footnotes: request.footnotes.into_iter().map(api_create_footnote_request_to_create_dto).collect(),
+    seo_metadata: api_seo_metadata_to_create_dto(request.seo),
+    manual_excerpt: request.manual_excerpt,
+  }
+}
+
+pub fn api_seo_metadata_to_create_dto(seo: api::SeoMetadata) -> CreateSeoMetadataDTO {
+  CreateSeoMetadataDTO {
+    description: seo.description,
+    og_image: seo.og_image.map(|og_image| CreateImageDTO {
+      id: og_image.id,
+      path: og_image.path,
+    }),
+    canonical_url: seo.canonical_url,
+    noindex: seo.noindex,
+  }
+}
+
+fn api_create_footnote_request_to_create_dto(request: api::CreateFootnoteRequest) -> CreateFootnoteDTO {
+  CreateFootnoteDTO {
+    id: request.id,
+    text: request.text.into_iter().map(api_create_rich_text_request_to_create_dto).collect(),
   }
 }
 
@@ -40,12 +64,46 @@ pub fn api_create_content_request_to_create_dto(request: api::CreateBlogPostCont
     api::CreateBlogPostContentRequest::Image(image) => CreateContentDTO::Image {
       id: Uuid::new_v4(), // 新しいIDを生成
       path: image.path,
+      presentation: CreateImagePresentationDTO {
+        alt: image.alt,
+        caption: image.caption,
+        width: image.width,
+        link_url: image.link_url,
+      },
     },
     api::CreateBlogPostContentRequest::Code(code) => CreateContentDTO::CodeBlock {
       id: Uuid::new_v4(), // 新しいIDを生成
       title: code.title,
       code: code.code,
       language: code.language,
+      options: CreateCodeBlockOptionsDTO {
+        highlight_lines: code.highlight_lines,
+        start_line: code.start_line,
+        diff: code.diff,
+        show_line_numbers: code.show_line_numbers,
+      },
+    },
+    api::CreateBlogPostContentRequest::Math(math) => CreateContentDTO::Math {
+      id: Uuid::new_v4(), // 新しいIDを生成
+      source: math.source,
+    },
+    api::CreateBlogPostContentRequest::Embed(embed) => CreateContentDTO::Embed {
+      id: Uuid::new_v4(), // 新しいIDを生成
+      url: embed.url,
+    },
+    api::CreateBlogPostContentRequest::LinkCard(link_card) => CreateContentDTO::LinkCard {
+      id: Uuid::new_v4(), // 新しいIDを生成
+      url: link_card.url,
+    },
+    api::CreateBlogPostContentRequest::Details(details) => CreateContentDTO::Details {
+      id: Uuid::new_v4(), // 新しいIDを生成
+      summary: details.summary,
+      children: details.children.into_iter().map(api_create_content_request_to_create_dto).collect(),
+    },
+    api::CreateBlogPostContentRequest::Diagram(diagram) => CreateContentDTO::Diagram {
+      id: Uuid::new_v4(), // 新しいIDを生成
+      kind: api_diagram_kind_to_dto(diagram.kind),
+      source: diagram.source,
     },
   }
 }
@@ -55,6 +113,8 @@ fn api_create_rich_text_request_to_create_dto(api_rich_text: api::RichText) -> C
     text: api_rich_text.text,
     styles: api_create_style_request_to_create_dto(api_rich_text.styles),
     link: api_rich_text.link.map(api_create_link_request_to_create_dto),
+    ruby: api_rich_text.ruby,
+    footnote_id: api_rich_text.footnote_id,
   }
 }
 
@@ -62,17 +122,53 @@ fn api_create_style_request_to_create_dto(api_style: api::Style) -> CreateStyleD
   CreateStyleDTO {
     bold: api_style.bold,
     inline_code: api_style.inline_code,
+    inline_math: api_style.inline_math,
+    italic: api_style.italic,
+    strikethrough: api_style.strikethrough,
+    underline: api_style.underline,
+    highlight: api_style.highlight.map(api_highlight_color_to_dto),
+  }
+}
+
+fn api_highlight_color_to_dto(api_color: api::HighlightColor) -> HighlightColorDTO {
+  match api_color {
+    api::HighlightColor::Yellow => HighlightColorDTO::Yellow,
+    api::HighlightColor::Green => HighlightColorDTO::Green,
+    api::HighlightColor::Blue => HighlightColorDTO::Blue,
+    api::HighlightColor::Pink => HighlightColorDTO::Pink,
+    api::HighlightColor::Red => HighlightColorDTO::Red,
+  }
+}
+
+fn api_diagram_kind_to_dto(api_kind: api::DiagramKind) -> DiagramKindDTO {
+  match api_kind {
+    api::DiagramKind::Mermaid => DiagramKindDTO::Mermaid,
   }
 }
 
 fn api_create_link_request_to_create_dto(api_link: api::Link) -> CreateLinkDTO {
-  CreateLinkDTO { url: api_link.url }
+  CreateLinkDTO {
+    url: api_link.url,
+    post_id: api_link.post_id,
+    heading_id: api_link.heading_id,
+  }
 }
 
 pub fn api_create_blog_post_contents_to_create_dto(contents: Vec<api::BlogPostContent>) -> Vec<CreateContentDTO> {
   contents.into_iter().map(api_blog_post_content_to_create_dto).collect()
 }
 
+/// 更新リクエストの脚注を変換する（脚注番号はサーバー側で振り直すため受け取った値は使わない）
+pub fn api_footnotes_to_create_dto(footnotes: Vec<api::Footnote>) -> Vec<CreateFootnoteDTO> {
+  footnotes
+    .into_iter()
+    .map(|footnote| CreateFootnoteDTO {
+      id: footnote.id,
+      text: footnote.text.into_iter().map(api_create_rich_text_request_to_create_dto).collect(),
+    })
+    .collect()
+}
+
 fn api_blog_post_content_to_create_dto(content: api::BlogPostContent) -> CreateContentDTO {
   match content {
     api::BlogPostContent::H2(h2) => CreateContentDTO::H2 { id: h2.id, text: h2.text },
@@ -84,12 +180,45 @@ fn api_blog_post_content_to_create_dto(content: api::BlogPostContent) -> CreateC
     api::BlogPostContent::Image(image) => CreateContentDTO::Image {
       id: image.id,
       path: image.path,
+      presentation: CreateImagePresentationDTO {
+        alt: image.alt,
+        caption: image.caption,
+        width: image.width,
+        link_url: image.link_url,
+      },
     },
     api::BlogPostContent::Code(code) => CreateContentDTO::CodeBlock {
       id: code.id,
       title: code.title,
       code: code.code,
       language: code.language,
+      options: CreateCodeBlockOptionsDTO {
+        highlight_lines: code.highlight_lines,
+        start_line: Some(code.start_line),
+        diff: code.diff,
+        show_line_numbers: code.show_line_numbers,
+      },
+    },
+    api::BlogPostContent::Math(math) => CreateContentDTO::Math {
+      id: math.id,
+      source: math.source,
+    },
+    // provider と resourceId から組み立てられた正規の URL を再検証する
+    api::BlogPostContent::Embed(embed) => CreateContentDTO::Embed { id: embed.id, url: embed.url },
+    // OGP メタデータはリクエストの値を使わず、保存済みの値の引き継ぎかサーバー側での取得で決まる
+    api::BlogPostContent::LinkCard(link_card) => CreateContentDTO::LinkCard {
+      id: link_card.id,
+      url: link_card.url,
+    },
+    api::BlogPostContent::Details(details) => CreateContentDTO::Details {
+      id: details.id,
+      summary: details.summary,
+      children: api_create_blog_post_contents_to_create_dto(details.children),
+    },
+    api::BlogPostContent::Diagram(diagram) => CreateContentDTO::Diagram {
+      id: diagram.id,
+      kind: api_diagram_kind_to_dto(diagram.kind),
+      source: diagram.source,
     },
   }
 }
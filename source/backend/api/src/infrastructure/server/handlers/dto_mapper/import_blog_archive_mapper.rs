@@ -0,0 +1,74 @@
+use crate::application::dto::{FeaturedPostIdsDTO, ImageDTO};
+use crate::application::usecase::create_blog_post::dto::CreateImageDTO;
+use crate::application::usecase::import_blog_archive::dto::{ArchivedBlogPostDTO, ArchivedOgpMetadataDTO, ImportBlogArchiveDTO};
+use crate::infrastructure::server::handlers::blog_archive_tar::BlogArchive;
+use crate::infrastructure::server::handlers::dto_mapper::create_blog_post_mapper::{
+  api_create_blog_post_contents_to_create_dto, api_footnotes_to_create_dto, api_seo_metadata_to_create_dto,
+};
+use common::types::api::{BlogPost, BlogPostContent, Image};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+pub fn blog_archive_to_import_dto(archive: BlogArchive) -> ImportBlogArchiveDTO {
+  ImportBlogArchiveDTO {
+    posts: archive.posts.into_iter().map(api_blog_post_to_archived_dto).collect(),
+    images: archive.images.into_iter().map(api_image_to_dto).collect(),
+    featured: FeaturedPostIdsDTO {
+      top_tech_pick: archive.featured.top_tech_pick_post_id,
+      pick_up: archive.featured.pickup_post_ids,
+      popular: archive.featured.popular_post_ids,
+    },
+  }
+}
+
+fn api_blog_post_to_archived_dto(post: BlogPost) -> ArchivedBlogPostDTO {
+  let link_card_metadata = api_link_card_metadata_to_dto(&post.contents);
+  ArchivedBlogPostDTO {
+    id: post.id,
+    title: post.title,
+    thumbnail: CreateImageDTO {
+      id: post.thumbnail.id,
+      path: post.thumbnail.path,
+    },
+    post_date: post.post_date,
+    last_update_date: post.last_update_date,
+    published_date: post.published_date,
+    contents: api_create_blog_post_contents_to_create_dto(post.contents),
+    footnotes: api_footnotes_to_create_dto(post.footnotes),
+    seo_metadata: api_seo_metadata_to_create_dto(post.seo),
+    manual_excerpt: post.manual_excerpt,
+    link_card_metadata,
+  }
+}
+
+/// 折りたたみブロックの子コンテンツも含めて、リンクカードの OGP メタデータを集める
+fn api_link_card_metadata_to_dto(contents: &[BlogPostContent]) -> HashMap<Uuid, ArchivedOgpMetadataDTO> {
+  contents
+    .iter()
+    .flat_map(|content| match content {
+      BlogPostContent::Details(details) => details.children.iter().collect::<Vec<_>>(),
+      other => vec![other],
+    })
+    .filter_map(|content| match content {
+      BlogPostContent::LinkCard(link_card) => Some((
+        link_card.id,
+        ArchivedOgpMetadataDTO {
+          title: link_card.title.clone(),
+          description: link_card.description.clone(),
+          site_name: link_card.site_name.clone(),
+          image_url: link_card.image_url.clone(),
+        },
+      )),
+      _ => None,
+    })
+    .collect()
+}
+
+fn api_image_to_dto(image: Image) -> ImageDTO {
+  ImageDTO {
+    id: image.id,
+    path: image.path,
+    file_name: image.file_name,
+    caption: image.caption,
+  }
+}
@@ -0,0 +1,11 @@
+use common::types::api::{PopularPostMode, PopularPostModeSetting};
+
+use crate::application::dto::PopularPostModeDTO;
+
+/// APIリクエストのPopularPostModeSettingをPopularPostModeDTOに変換
+pub fn api_popular_post_mode_setting_to_dto(setting: PopularPostModeSetting) -> PopularPostModeDTO {
+  match setting.mode {
+    PopularPostMode::Manual => PopularPostModeDTO::Manual,
+    PopularPostMode::Automatic => PopularPostModeDTO::Automatic,
+  }
+}
@@ -1,6 +1,8 @@
 use crate::application::usecase::create_blog_post::dto::CreateImageDTO;
 use crate::application::usecase::update_blog_post::dto::UpdateBlogPostDTO;
-use crate::infrastructure::server::handlers::dto_mapper::create_blog_post_mapper::api_create_blog_post_contents_to_create_dto;
+use crate::infrastructure::server::handlers::dto_mapper::create_blog_post_mapper::{
+  api_create_blog_post_contents_to_create_dto, api_footnotes_to_create_dto, api_seo_metadata_to_create_dto,
+};
 use common::types::api::UpdateBlogPostRequest;
 
 pub fn api_update_blog_post_request_to_update_dto(request: UpdateBlogPostRequest) -> UpdateBlogPostDTO {
@@ -12,5 +14,8 @@ pub fn api_update_blog_post_request_to_update_dto(request: UpdateBlogPostRequest
     },
     published_date: request.published_date,
     contents: api_create_blog_post_contents_to_create_dto(request.contents),
+    footnotes: api_footnotes_to_create_dto(request.footnotes),
+    seo_metadata: api_seo_metadata_to_create_dto(request.seo),
+    manual_excerpt: request.manual_excerpt,
   }
 }
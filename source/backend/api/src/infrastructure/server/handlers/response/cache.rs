@@ -0,0 +1,116 @@
+use actix_web::{
+  http::header::{self, CacheControl, CacheDirective, EntityTag, Header, HttpDate, IfModifiedSince, IfNoneMatch},
+  HttpRequest, HttpResponse,
+};
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+use std::time::{Duration, SystemTime};
+
+/// キャッシュ可能なレスポンスを返す
+///
+/// 本文のハッシュを ETag、`last_modified` を Last-Modified として付け、
+/// リクエストの If-None-Match（なければ If-Modified-Since）と一致する場合は本文なしの 304 を返す。
+///
+/// # Arguments
+/// * `req` - 条件付きリクエストのヘッダーを読むためのリクエスト
+/// * `content_type` - 本文の Content-Type
+/// * `body` - 本文
+/// * `last_modified` - 本文の元になったデータの最終更新日時
+/// * `max_age` - Cache-Control の max-age（秒）
+pub fn cacheable_response(req: &HttpRequest, content_type: &str, body: String, last_modified: Option<DateTime<Utc>>, max_age: u32) -> HttpResponse {
+  let etag = EntityTag::new_strong(format!("{:x}", Sha256::digest(body.as_bytes())));
+  let last_modified = last_modified.map(to_http_date);
+
+  let not_modified = is_not_modified(req, &etag, last_modified);
+
+  let mut response = if not_modified { HttpResponse::NotModified() } else { HttpResponse::Ok() };
+  response.insert_header(CacheControl(vec![CacheDirective::Public, CacheDirective::MaxAge(max_age)])).insert_header(header::ETag(etag));
+  if let Some(last_modified) = last_modified {
+    response.insert_header(header::LastModified(last_modified));
+  }
+
+  if not_modified {
+    return response.finish();
+  }
+  response.content_type(content_type).body(body)
+}
+
+fn is_not_modified(req: &HttpRequest, etag: &EntityTag, last_modified: Option<HttpDate>) -> bool {
+  // If-None-Match がある場合は If-Modified-Since を無視する（RFC 9110 13.2.2）
+  if req.headers().contains_key(header::IF_NONE_MATCH) {
+    return match IfNoneMatch::parse(req) {
+      Ok(IfNoneMatch::Any) => true,
+      Ok(IfNoneMatch::Items(tags)) => tags.iter().any(|tag| tag.weak_eq(etag)),
+      Err(_) => false,
+    };
+  }
+
+  match (IfModifiedSince::parse(req), last_modified) {
+    (Ok(IfModifiedSince(since)), Some(last_modified)) => SystemTime::from(last_modified) <= SystemTime::from(since),
+    _ => false,
+  }
+}
+
+fn to_http_date(datetime: DateTime<Utc>) -> HttpDate {
+  let seconds = datetime.timestamp().max(0) as u64;
+  HttpDate::from(SystemTime::UNIX_EPOCH + Duration::from_secs(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use actix_web::{body::to_bytes, http::StatusCode, test::TestRequest};
+  use chrono::TimeZone;
+
+  fn last_modified() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 4, 4, 15, 0, 0).unwrap()
+  }
+
+  #[actix_web::test]
+  async fn returns_body_with_cache_headers() {
+    let req = TestRequest::default().to_http_request();
+
+    let response = cacheable_response(&req, "application/rss+xml; charset=utf-8", "<rss/>".to_string(), Some(last_modified()), 600);
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let headers = response.headers();
+    assert_eq!(headers.get(header::CONTENT_TYPE).unwrap(), "application/rss+xml; charset=utf-8");
+    assert_eq!(headers.get(header::CACHE_CONTROL).unwrap(), "public, max-age=600");
+    assert_eq!(headers.get(header::LAST_MODIFIED).unwrap(), "Thu, 04 Apr 2024 15:00:00 GMT");
+    assert!(headers.get(header::ETAG).unwrap().to_str().unwrap().starts_with('"'));
+    assert_eq!(to_bytes(response.into_body()).await.unwrap(), "<rss/>");
+  }
+
+  #[actix_web::test]
+  async fn returns_not_modified_when_etag_matches() {
+    let first = cacheable_response(&TestRequest::default().to_http_request(), "text/xml", "<rss/>".to_string(), None, 600);
+    let etag = first.headers().get(header::ETAG).unwrap().clone();
+    let req = TestRequest::default().insert_header((header::IF_NONE_MATCH, etag)).to_http_request();
+
+    let response = cacheable_response(&req, "text/xml", "<rss/>".to_string(), None, 600);
+
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert!(to_bytes(response.into_body()).await.unwrap().is_empty());
+  }
+
+  #[actix_web::test]
+  async fn ignores_if_modified_since_when_etag_does_not_match() {
+    let req = TestRequest::default()
+      .insert_header((header::IF_NONE_MATCH, "\"stale\""))
+      .insert_header((header::IF_MODIFIED_SINCE, "Fri, 05 Apr 2024 00:00:00 GMT"))
+      .to_http_request();
+
+    let response = cacheable_response(&req, "text/xml", "<rss/>".to_string(), Some(last_modified()), 600);
+
+    assert_eq!(response.status(), StatusCode::OK);
+  }
+
+  #[actix_web::test]
+  async fn returns_not_modified_when_not_modified_since() {
+    let req = TestRequest::default().insert_header((header::IF_MODIFIED_SINCE, "Thu, 04 Apr 2024 15:00:00 GMT")).to_http_request();
+
+    let response = cacheable_response(&req, "text/xml", "<rss/>".to_string(), Some(last_modified()), 600);
+
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+  }
+}
@@ -12,6 +12,15 @@ pub enum ApiCustomError {
   #[error("バリデーションエラー: {0}")]
   ValidationError(String),
 
+  #[error("コンテンツが見つかりませんでした。")]
+  ContentNotFound(String),
+
+  #[error("{0}")]
+  Conflict(String),
+
+  #[error("外部サービスからの取得に失敗しました。[{0}]")]
+  ExternalServiceError(String),
+
   #[error(transparent)]
   ActixWebError(#[from] actix_web::Error),
 
@@ -26,6 +35,9 @@ impl ResponseError for ApiCustomError {
       ApiCustomError::NotFoundURL => StatusCode::NOT_FOUND,
       ApiCustomError::BlogPostNotFound(_) => StatusCode::NOT_FOUND,
       ApiCustomError::ValidationError(_) => StatusCode::BAD_REQUEST,
+      ApiCustomError::ContentNotFound(_) => StatusCode::NOT_FOUND,
+      ApiCustomError::Conflict(_) => StatusCode::CONFLICT,
+      ApiCustomError::ExternalServiceError(_) => StatusCode::BAD_GATEWAY,
       ApiCustomError::ActixWebError(err) => err.as_response_error().status_code(),
       ApiCustomError::Other(_) => StatusCode::INTERNAL_SERVER_ERROR,
     }
@@ -37,6 +49,9 @@ impl ResponseError for ApiCustomError {
       ApiCustomError::NotFoundURL => HttpResponse::build(self.status_code()).json(ErrResponse { message: format!("{}", self) }),
       ApiCustomError::BlogPostNotFound(_) => HttpResponse::build(self.status_code()).json(ErrResponse { message: format!("{}", self) }),
       ApiCustomError::ValidationError(message) => HttpResponse::build(self.status_code()).json(ErrResponse { message: message.clone() }),
+      ApiCustomError::ContentNotFound(_) => HttpResponse::build(self.status_code()).json(ErrResponse { message: format!("{}", self) }),
+      ApiCustomError::Conflict(message) => HttpResponse::build(self.status_code()).json(ErrResponse { message: message.clone() }),
+      ApiCustomError::ExternalServiceError(_) => HttpResponse::build(self.status_code()).json(ErrResponse { message: format!("{}", self) }),
       ApiCustomError::ActixWebError(err) => {
         let message = match self.status_code() {
           StatusCode::NOT_FOUND => "Not Found.",
@@ -1,6 +1,10 @@
 use actix_web::{HttpResponse, Responder};
 use common::types::api::{
-  BlogPost, BlogPostContent, CodeBlock, H2Block, H3Block, Image, ImageBlock, Link, ParagraphBlock, RichText, Style, UpdateBlogPostRequest,
+  BlogArchiveConflict, BlogArchiveConflictKind, BlogPost, BlogPostContent, BlogPostingStructuredData, CodeBlock, DanglingInternalLink, DanglingLinkReason,
+  DetailsBlock, DiagramBlock, DiagramKind, EmbedBlock, EmbedProvider, Footnote, H2Block, H3Block, HighlightColor, HighlightToken, HighlightedCode, Image,
+  ImageBlock, ImportBlogArchiveResponse, ImportMarkdownPostResponse, ImportWordPressPostsResponse, JsonFeed, JsonFeedItem, Link, LinkCardBlock,
+  MarkdownImportWarning, MathBlock, ParagraphBlock, PopularPostMode, PopularPostModeSetting, RebuildRelatedPostIndexResponse, RichText, SeoMetadata,
+  StructuredDataOrganization, Style, TocItem, UpdateBlogPostRequest, WordPressImportItemReport, WordPressImportStatus,
 };
 use utoipa::OpenApi;
 
@@ -8,22 +12,42 @@ use utoipa::OpenApi;
 #[openapi(
   paths(
     crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::get_blog_post,
+    crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::get_blog_post_structured_data,
+    crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::get_blog_post_toc,
+    crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::get_related_blog_posts,
+    crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::record_blog_post_view,
     crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::get_latest_blog_posts,
+    crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::get_rss_feed,
+    crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::get_atom_feed,
+    crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::get_json_feed,
+    crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::get_sitemap,
+    crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::get_sitemap_page,
     crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::get_top_tech_pick_blog_post,
     crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::put_top_tech_pick_blog_post,
     crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::get_pickup_blog_posts,
     crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::put_pickup_blog_posts,
     crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::get_popular_blog_posts,
     crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::put_popular_blog_posts,
+    crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::get_popular_post_mode,
+    crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::put_popular_post_mode,
+    crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::recompute_popular_blog_posts,
     crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::create_blog_post,
+    crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::import_markdown_post,
+    crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::import_wordpress_posts,
+    crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::export_blog_archive,
+    crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::import_blog_archive,
     crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::get_admin_blog_posts,
     crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::get_admin_blog_post,
+    crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::export_blog_post_markdown,
     crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::update_blog_post,
+    crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::refresh_link_card,
+    crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::get_dangling_internal_links,
+    crate::infrastructure::server::handlers::blog_post_handlers::handle_funcs::rebuild_related_post_index,
     crate::infrastructure::server::handlers::image_handlers::handle_funcs::get_images,
     crate::infrastructure::server::handlers::image_handlers::handle_funcs::create_image,
   ),
   components(
-    schemas(BlogPost, Image, BlogPostContent, H2Block, H3Block, ParagraphBlock, RichText, ImageBlock, CodeBlock, Style, Link, UpdateBlogPostRequest)
+    schemas(BlogPost, Footnote, SeoMetadata, Image, BlogPostContent, H2Block, H3Block, ParagraphBlock, RichText, ImageBlock, CodeBlock, HighlightedCode, HighlightToken, MathBlock, EmbedBlock, EmbedProvider, LinkCardBlock, DetailsBlock, DiagramBlock, DiagramKind, Style, HighlightColor, Link, UpdateBlogPostRequest, DanglingInternalLink, DanglingLinkReason, ImportMarkdownPostResponse, MarkdownImportWarning, ImportBlogArchiveResponse, BlogArchiveConflict, BlogArchiveConflictKind, ImportWordPressPostsResponse, WordPressImportItemReport, WordPressImportStatus, JsonFeed, JsonFeedItem, BlogPostingStructuredData, StructuredDataOrganization, TocItem, RebuildRelatedPostIndexResponse, PopularPostMode, PopularPostModeSetting)
   ),
   tags(
     (name = "blog", description = "Blog API"),
@@ -1,7 +1,10 @@
 #[cfg(test)]
 mod tests {
   use chrono::NaiveDate;
-  use common::types::api::{BlogPost, BlogPostContent, CodeBlock, H2Block, H3Block, Image, ImageBlock, Link, ParagraphBlock, RichText, Style};
+  use common::types::api::{
+    BlogPost, BlogPostContent, CodeBlock, DetailsBlock, DiagramBlock, DiagramKind, EmbedBlock, EmbedProvider, H2Block, H3Block, HighlightColor, Image,
+    ImageBlock, Link, LinkCardBlock, MathBlock, ParagraphBlock, RichText, Style,
+  };
   use serde_json;
   use uuid::Uuid;
 
@@ -15,11 +18,18 @@ mod tests {
       thumbnail: Image {
         id: Uuid::new_v4(),
         path: "/images/test.jpg".to_string(),
+        file_name: None,
+        caption: None,
       },
       post_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
       last_update_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
       published_date: NaiveDate::from_ymd_opt(1900, 1, 1).unwrap(),
       contents: vec![],
+      footnotes: vec![],
+      seo: Default::default(),
+      excerpt: Default::default(),
+      manual_excerpt: Default::default(),
+      reading_time_minutes: Default::default(),
     };
 
     // 型が正しくインスタンス化できることを確認
@@ -42,13 +52,24 @@ mod tests {
           styles: Style {
             bold: true,
             inline_code: false,
+            inline_math: false,
+            italic: false,
+            strikethrough: false,
+            underline: false,
+            highlight: None,
           },
           link: None,
+          ruby: None,
+          footnote_id: None,
         }],
       }),
       BlogPostContent::Image(ImageBlock {
         id: Uuid::new_v4(),
         path: "/images/content.jpg".to_string(),
+        alt: String::new(),
+        caption: None,
+        width: None,
+        link_url: None,
       }),
     ];
 
@@ -58,11 +79,18 @@ mod tests {
       thumbnail: Image {
         id: Uuid::new_v4(),
         path: "/images/thumbnail.jpg".to_string(),
+        file_name: None,
+        caption: None,
       },
       post_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
       last_update_date: NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
       published_date: NaiveDate::from_ymd_opt(1900, 1, 1).unwrap(),
       contents,
+      footnotes: vec![],
+      seo: Default::default(),
+      excerpt: Default::default(),
+      manual_excerpt: Default::default(),
+      reading_time_minutes: Default::default(),
     };
 
     // JSONシリアライズが正常に動作することを確認
@@ -84,15 +112,38 @@ mod tests {
       Style {
         bold: true,
         inline_code: false,
+        inline_math: false,
+        italic: false,
+        strikethrough: false,
+        underline: false,
+        highlight: None,
       },
       Style {
         bold: false,
         inline_code: true,
+        inline_math: false,
+        italic: false,
+        strikethrough: false,
+        underline: false,
+        highlight: None,
+      },
+      Style {
+        bold: true,
+        inline_code: true,
+        inline_math: false,
+        italic: false,
+        strikethrough: false,
+        underline: false,
+        highlight: None,
       },
-      Style { bold: true, inline_code: true },
       Style {
         bold: false,
         inline_code: false,
+        inline_math: false,
+        italic: false,
+        strikethrough: false,
+        underline: false,
+        highlight: None,
       },
     ];
 
@@ -101,6 +152,8 @@ mod tests {
         text: "テストテキスト".to_string(),
         styles: style.clone(),
         link: None,
+        ruby: None,
+        footnote_id: None,
       };
 
       // JSONシリアライズ・デシリアライズ
@@ -129,11 +182,18 @@ mod tests {
       thumbnail: Image {
         id: db_thumbnail_id,
         path: "/images/db-image.jpg".to_string(),
+        file_name: None,
+        caption: None,
       },
       post_date: db_post_date,
       last_update_date: db_update_date,
       published_date: NaiveDate::from_ymd_opt(1900, 1, 1).unwrap(),
       contents: vec![],
+      footnotes: vec![],
+      seo: Default::default(),
+      excerpt: Default::default(),
+      manual_excerpt: Default::default(),
+      reading_time_minutes: Default::default(),
     };
 
     // 変換が正常に行われることを確認
@@ -166,6 +226,8 @@ mod tests {
   fn test_link_serialization() {
     let link = Link {
       url: "https://example.com".to_string(),
+      post_id: None,
+      heading_id: None,
     };
 
     let json = serde_json::to_string(&link).unwrap();
@@ -174,6 +236,20 @@ mod tests {
     assert_eq!(link.url, deserialized.url);
   }
 
+  /// 内部リンクはURLを省略して記事IDと見出しIDで指定できることのテスト
+  #[test]
+  fn test_internal_link_deserialization_without_url() {
+    let post_id = Uuid::new_v4();
+    let heading_id = Uuid::new_v4();
+    let json = format!(r#"{{"postId":"{}","headingId":"{}"}}"#, post_id, heading_id);
+
+    let link: Link = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(link.url, "");
+    assert_eq!(link.post_id, Some(post_id));
+    assert_eq!(link.heading_id, Some(heading_id));
+  }
+
   /// camelCase変換のテスト
   #[test]
   fn test_camel_case_serialization() {
@@ -183,11 +259,18 @@ mod tests {
       thumbnail: Image {
         id: Uuid::new_v4(),
         path: "/images/camel.jpg".to_string(),
+        file_name: None,
+        caption: None,
       },
       post_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
       last_update_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
       published_date: NaiveDate::from_ymd_opt(1900, 1, 1).unwrap(),
       contents: vec![],
+      footnotes: vec![],
+      seo: Default::default(),
+      excerpt: Default::default(),
+      manual_excerpt: Default::default(),
+      reading_time_minutes: Default::default(),
     };
 
     let json = serde_json::to_string(&blog_post).unwrap();
@@ -219,12 +302,52 @@ mod tests {
       BlogPostContent::Image(ImageBlock {
         id: Uuid::new_v4(),
         path: "/image.jpg".to_string(),
+        alt: String::new(),
+        caption: None,
+        width: None,
+        link_url: None,
       }),
       BlogPostContent::Code(CodeBlock {
         id: Uuid::new_v4(),
         title: "コード".to_string(),
         code: "code".to_string(),
         language: "rust".to_string(),
+        highlight_lines: None,
+        start_line: 1,
+        diff: false,
+        show_line_numbers: false,
+        highlighted: None,
+      }),
+      BlogPostContent::Math(MathBlock {
+        id: Uuid::new_v4(),
+        source: "x^2".to_string(),
+      }),
+      BlogPostContent::Embed(EmbedBlock {
+        id: Uuid::new_v4(),
+        provider: EmbedProvider::YouTube,
+        resource_id: "dQw4w9WgXcQ".to_string(),
+        url: "https://www.youtube.com/watch?v=dQw4w9WgXcQ".to_string(),
+      }),
+      BlogPostContent::LinkCard(LinkCardBlock {
+        id: Uuid::new_v4(),
+        url: "https://example.com/article".to_string(),
+        title: Some("記事タイトル".to_string()),
+        description: None,
+        site_name: Some("Example".to_string()),
+        image_url: None,
+      }),
+      BlogPostContent::Details(DetailsBlock {
+        id: Uuid::new_v4(),
+        summary: "実行ログ".to_string(),
+        children: vec![BlogPostContent::Math(MathBlock {
+          id: Uuid::new_v4(),
+          source: "y".to_string(),
+        })],
+      }),
+      BlogPostContent::Diagram(DiagramBlock {
+        id: Uuid::new_v4(),
+        kind: DiagramKind::Mermaid,
+        source: "flowchart LR\n  A --> B".to_string(),
       }),
     ];
 
@@ -242,7 +365,63 @@ mod tests {
         BlogPostContent::Paragraph(_) => assert_eq!(json_value.get("type").unwrap(), "paragraph"),
         BlogPostContent::Image(_) => assert_eq!(json_value.get("type").unwrap(), "image"),
         BlogPostContent::Code(_) => assert_eq!(json_value.get("type").unwrap(), "codeBlock"),
+        BlogPostContent::Math(_) => assert_eq!(json_value.get("type").unwrap(), "math"),
+        BlogPostContent::Embed(_) => {
+          assert_eq!(json_value.get("type").unwrap(), "embed");
+          assert_eq!(json_value.get("provider").unwrap(), "youtube");
+          assert_eq!(json_value.get("resourceId").unwrap(), "dQw4w9WgXcQ");
+        }
+        BlogPostContent::LinkCard(_) => {
+          assert_eq!(json_value.get("type").unwrap(), "linkCard");
+          assert_eq!(json_value.get("siteName").unwrap(), "Example");
+          assert!(json_value.get("description").unwrap().is_null());
+        }
+        BlogPostContent::Details(_) => {
+          assert_eq!(json_value.get("type").unwrap(), "details");
+          assert_eq!(json_value.get("summary").unwrap(), "実行ログ");
+          // 子コンテンツも type タグ付きでシリアライズされる
+          assert_eq!(json_value["children"][0]["type"], "math");
+        }
+        BlogPostContent::Diagram(_) => {
+          assert_eq!(json_value.get("type").unwrap(), "diagram");
+          assert_eq!(json_value.get("kind").unwrap(), "mermaid");
+        }
       }
     }
   }
+
+  /// inlineMath を含まない既存の Style JSON を読み込めることの確認
+  #[test]
+  fn test_style_without_inline_math_deserialization() {
+    let style: Style = serde_json::from_str(r#"{"bold":true,"inlineCode":false}"#).unwrap();
+
+    assert!(style.bold);
+    assert!(!style.inline_math);
+    assert!(!style.italic);
+    assert!(!style.strikethrough);
+    assert!(!style.underline);
+    assert_eq!(style.highlight, None);
+  }
+
+  /// ruby を含まない既存の RichText JSON を読み込めることの確認
+  #[test]
+  fn test_rich_text_without_ruby_deserialization() {
+    let rich_text: RichText = serde_json::from_str(r#"{"text":"漢字","styles":{"bold":false,"inlineCode":false},"link":null}"#).unwrap();
+    assert_eq!(rich_text.ruby, None);
+
+    let with_ruby: RichText = serde_json::from_str(r#"{"text":"漢字","styles":{"bold":false,"inlineCode":false},"link":null,"ruby":"かんじ"}"#).unwrap();
+    assert_eq!(with_ruby.ruby.as_deref(), Some("かんじ"));
+  }
+
+  /// マーカー色が小文字の色名でシリアライズされることの確認
+  #[test]
+  fn test_style_highlight_serialization() {
+    let style: Style = serde_json::from_str(r#"{"bold":false,"inlineCode":false,"italic":true,"highlight":"yellow"}"#).unwrap();
+    assert!(style.italic);
+    assert_eq!(style.highlight, Some(HighlightColor::Yellow));
+
+    let json_value = serde_json::to_value(&style).unwrap();
+    assert_eq!(json_value.get("highlight").unwrap(), "yellow");
+    assert!(serde_json::from_str::<Style>(r#"{"bold":false,"inlineCode":false,"highlight":"purple"}"#).is_err());
+  }
 }
@@ -3,7 +3,9 @@ mod tests {
   use crate::tests::helper::http::request::Request;
   use crate::tests::{handlers::blog_posts::test_helper, helper::http::methods::Methods};
   use anyhow::{Context, Result};
-  use common::types::api::{BlogPost, BlogPostContent, H2Block, Image, ImageBlock, ParagraphBlock, RichText, Style};
+  use common::types::api::{
+    BlogPost, BlogPostContent, BlogPostingStructuredData, H2Block, HighlightedCode, Image, ImageBlock, JsonFeed, ParagraphBlock, RichText, Style, TocItem,
+  };
   use uuid::Uuid;
 
   #[tokio::test(flavor = "current_thread")]
@@ -90,6 +92,227 @@ mod tests {
     Ok(())
   }
 
+  // highlight=html を指定するとコードブロックにハイライト済みの HTML が付与される
+  #[tokio::test(flavor = "current_thread")]
+  async fn get_single_blog_post_with_highlighted_code() -> Result<()> {
+    let url = format!(
+      "http://localhost:8001/blog/posts/{uuid}?highlight=html",
+      uuid = helper::regular_post_id().unwrap()
+    );
+    let resp = Request::new(Methods::GET, &url).send().await.unwrap().text().await.unwrap();
+
+    let blog_post: BlogPost = serde_json::from_str(&resp).context("JSON データをパースできませんでした").unwrap();
+
+    let code_block = blog_post
+      .contents
+      .iter()
+      .find_map(|content| match content {
+        BlogPostContent::Code(code) => Some(code),
+        _ => None,
+      })
+      .expect("コードブロックが含まれていません");
+    match &code_block.highlighted {
+      Some(HighlightedCode::Html { html }) => assert!(html.contains("<span class=\"hl-")),
+      other => panic!("ハイライト済みの HTML が返っていません: {:?}", other),
+    }
+    Ok(())
+  }
+
+  // 未対応のハイライト形式を指定すると 400 エラーが返る
+  #[tokio::test(flavor = "current_thread")]
+  async fn get_single_blog_post_with_invalid_highlight_format() -> Result<()> {
+    let url = format!(
+      "http://localhost:8001/blog/posts/{uuid}?highlight=svg",
+      uuid = helper::regular_post_id().unwrap()
+    );
+    let resp = Request::new(Methods::GET, &url).send().await.unwrap();
+
+    assert_eq!(resp.status(), 400);
+    Ok(())
+  }
+
+  // format=html を指定すると記事を HTML で取得できる
+  #[tokio::test(flavor = "current_thread")]
+  async fn get_single_blog_post_as_html() -> Result<()> {
+    let url = format!("http://localhost:8001/blog/posts/{uuid}?format=html", uuid = helper::regular_post_id().unwrap());
+    let resp = Request::new(Methods::GET, &url).send().await.unwrap();
+
+    assert_eq!(resp.status(), 200);
+    let content_type = resp.header("content-type").unwrap_or_default();
+    assert!(
+      content_type.starts_with("text/html"),
+      "Content-Type が text/html ではありません: {}",
+      content_type
+    );
+
+    let html = resp.text().await.unwrap();
+    assert!(html.starts_with("<article>\n<h1>初めての技術スタックへの挑戦</h1>\n"));
+    assert!(html.contains("<code class=\"language-javascript\">"));
+    Ok(())
+  }
+
+  // RSS 2.0 のフィードには公開済みの記事だけが含まれる
+  #[tokio::test(flavor = "current_thread")]
+  async fn get_rss_feed() -> Result<()> {
+    let resp = Request::new(Methods::GET, "http://localhost:8001/blog/feed.xml").send().await.unwrap();
+
+    assert_eq!(resp.status(), 200);
+    assert!(resp.header("content-type").unwrap_or_default().starts_with("application/rss+xml"));
+    assert_eq!(resp.header("cache-control").as_deref(), Some("public, max-age=600"));
+    assert!(resp.header("etag").is_some());
+    assert!(resp.header("last-modified").is_some());
+
+    let xml = resp.text().await.unwrap();
+    assert!(xml.contains("<rss version=\"2.0\""));
+    assert!(!xml.contains("50年後記事1"));
+    Ok(())
+  }
+
+  // ETag が一致する場合は 304 を返す
+  #[tokio::test(flavor = "current_thread")]
+  async fn get_atom_feed_not_modified() -> Result<()> {
+    let url = "http://localhost:8001/blog/atom.xml";
+    let resp = Request::new(Methods::GET, url).send().await.unwrap();
+
+    assert_eq!(resp.status(), 200);
+    assert!(resp.header("content-type").unwrap_or_default().starts_with("application/atom+xml"));
+    let etag = resp.header("etag").unwrap();
+    assert!(resp.text().await.unwrap().contains("<feed xmlns=\"http://www.w3.org/2005/Atom\""));
+
+    let resp = Request::new(Methods::GET, url).header("if-none-match", &etag).send().await.unwrap();
+    assert_eq!(resp.status(), 304);
+    Ok(())
+  }
+
+  // JSON Feed は common の型としてパースできる
+  #[tokio::test(flavor = "current_thread")]
+  async fn get_json_feed() -> Result<()> {
+    let resp = Request::new(Methods::GET, "http://localhost:8001/blog/feed.json").send().await.unwrap();
+
+    assert_eq!(resp.status(), 200);
+    assert!(resp.header("content-type").unwrap_or_default().starts_with("application/feed+json"));
+    assert!(resp.header("etag").is_some());
+
+    let feed: JsonFeed = serde_json::from_str(&resp.text().await.unwrap()).context("JSON Feed をパースできませんでした").unwrap();
+    assert_eq!(feed.version, "https://jsonfeed.org/version/1.1");
+    assert!(!feed.items.is_empty());
+    assert!(feed.items.iter().all(|item| item.title != "50年後記事1"));
+    assert!(feed.items.iter().all(|item| !item.content_html.is_empty()));
+    Ok(())
+  }
+
+  // SEO メタデータが未設定の記事では、タイトル・サムネイル・記事ページの URL から構造化データを作る
+  #[tokio::test(flavor = "current_thread")]
+  async fn get_blog_post_structured_data() -> Result<()> {
+    let post_id = helper::regular_post_id().unwrap();
+    let url = format!("http://localhost:8001/blog/posts/{}/structured-data", post_id);
+    let resp = Request::new(Methods::GET, &url).send().await.unwrap();
+
+    assert_eq!(resp.status(), 200);
+    assert!(resp.header("content-type").unwrap_or_default().starts_with("application/ld+json"));
+
+    let posting: BlogPostingStructuredData = serde_json::from_str(&resp.text().await.unwrap()).context("JSON-LD をパースできませんでした").unwrap();
+    assert_eq!(posting.context, "https://schema.org");
+    assert_eq!(posting.schema_type, "BlogPosting");
+    assert_eq!(posting.headline, helper::expected_regular_blog_post().unwrap().title);
+    assert!(posting.url.ends_with(&format!("/posts/{}", post_id)));
+    assert_eq!(posting.main_entity_of_page, posting.url);
+    assert!(posting.image.is_some());
+    Ok(())
+  }
+
+  // 目次は見出しのテキストと ID から作った記事内で一意なアンカーを持つ
+  #[tokio::test(flavor = "current_thread")]
+  async fn get_blog_post_toc() -> Result<()> {
+    let post_id = helper::regular_post_id().unwrap();
+    let url = format!("http://localhost:8001/blog/posts/{}/toc", post_id);
+    let resp = Request::new(Methods::GET, &url).send().await.unwrap();
+
+    assert_eq!(resp.status(), 200);
+
+    let toc: Vec<TocItem> = serde_json::from_str(&resp.text().await.unwrap()).context("目次をパースできませんでした").unwrap();
+    assert!(!toc.is_empty());
+
+    let items: Vec<&TocItem> = toc.iter().flat_map(|item| std::iter::once(item).chain(item.children.iter())).collect();
+    let anchors: std::collections::HashSet<&str> = items.iter().map(|item| item.anchor.as_str()).collect();
+    assert_eq!(anchors.len(), items.len());
+    for item in items {
+      assert!(item.anchor.ends_with(&item.id.simple().to_string()[..8]));
+    }
+    Ok(())
+  }
+
+  // 関連記事には記事自身と未公開記事は含まれない
+  #[tokio::test(flavor = "current_thread")]
+  async fn get_related_blog_posts() -> Result<()> {
+    // テストデータは SQL で投入しているため、関連記事用の語の出現回数を先に求めておく
+    let rebuild_url = "http://localhost:8001/admin/blog/posts/related-index/rebuild";
+    let resp = Request::new(Methods::POST { body: String::new() }, rebuild_url).send().await.unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let post_id = helper::regular_post_id().unwrap();
+    let url = format!("http://localhost:8001/blog/posts/{}/related?limit=10", post_id);
+    let resp = Request::new(Methods::GET, &url).send().await.unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let related_posts: Vec<BlogPost> = serde_json::from_str(&resp.text().await.unwrap()).context("関連記事をパースできませんでした").unwrap();
+    assert!(related_posts.len() <= 10);
+    assert!(related_posts.iter().all(|post| post.id != post_id));
+    // 50年後記事1（未公開記事）
+    assert!(related_posts.iter().all(|post| post.id.to_string() != "12345678-90ab-cdef-1234-567890abcdef"));
+    Ok(())
+  }
+
+  // 関連記事の件数は 1 から 10 までしか指定できない
+  #[tokio::test(flavor = "current_thread")]
+  async fn get_related_blog_posts_invalid_limit() -> Result<()> {
+    let post_id = helper::regular_post_id().unwrap();
+    for limit in [0, 11] {
+      let url = format!("http://localhost:8001/blog/posts/{}/related?limit={}", post_id, limit);
+      let resp = Request::new(Methods::GET, &url).send().await.unwrap();
+      assert_eq!(resp.status(), 400);
+    }
+    Ok(())
+  }
+
+  // 存在しない記事の構造化データは取得できない
+  #[tokio::test(flavor = "current_thread")]
+  async fn get_blog_post_structured_data_not_found() -> Result<()> {
+    let url = format!("http://localhost:8001/blog/posts/{}/structured-data", Uuid::new_v4());
+    let resp = Request::new(Methods::GET, &url).send().await.unwrap();
+
+    assert_eq!(resp.status(), 404);
+    Ok(())
+  }
+
+  // サイトマップには公開済みの記事だけが含まれる
+  #[tokio::test(flavor = "current_thread")]
+  async fn get_sitemap() -> Result<()> {
+    let resp = Request::new(Methods::GET, "http://localhost:8001/blog/sitemap.xml").send().await.unwrap();
+
+    assert_eq!(resp.status(), 200);
+    assert!(resp.header("content-type").unwrap_or_default().starts_with("application/xml"));
+
+    let xml = resp.text().await.unwrap();
+    assert!(xml.contains("<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">"));
+    assert!(xml.contains(&format!("/posts/{}</loc>", helper::regular_post_id().unwrap())));
+    assert!(xml.contains("<lastmod>2021-01-02</lastmod>"));
+    // 50年後記事1（未公開記事）
+    assert!(!xml.contains("12345678-90ab-cdef-1234-567890abcdef"));
+    Ok(())
+  }
+
+  // 記事数を超えるページのサイトマップは存在しない
+  #[tokio::test(flavor = "current_thread")]
+  async fn get_sitemap_page_out_of_range() -> Result<()> {
+    let resp = Request::new(Methods::GET, "http://localhost:8001/blog/sitemap-1.xml").send().await.unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let resp = Request::new(Methods::GET, "http://localhost:8001/blog/sitemap-2.xml").send().await.unwrap();
+    assert_eq!(resp.status(), 404);
+    Ok(())
+  }
+
   #[tokio::test(flavor = "current_thread")]
   async fn get_admin_blog_posts_include_unpublished() -> Result<()> {
     let url = "http://localhost:8001/admin/blog/posts?include_unpublished=true";
@@ -180,6 +403,44 @@ mod tests {
     Ok(())
   }
 
+  // 管理者用: 未公開記事も Markdown として書き出せる
+  #[tokio::test(flavor = "current_thread")]
+  async fn export_admin_blog_post_as_markdown() -> Result<()> {
+    let url = format!(
+      "http://localhost:8001/admin/blog/posts/{uuid}/export/markdown",
+      uuid = test_helper::future_post_id().unwrap()
+    );
+    let resp = Request::new(Methods::GET, &url).send().await.unwrap();
+
+    assert_eq!(resp.status(), 200);
+    let content_type = resp.header("content-type").unwrap_or_default();
+    assert!(
+      content_type.starts_with("text/markdown"),
+      "Content-Type が text/markdown ではありません: {}",
+      content_type
+    );
+
+    let markdown = resp.text().await.unwrap();
+    assert!(markdown.starts_with("---\ntitle: \"50年後記事1\"\n"));
+    assert!(markdown.contains("published: false\n---\n"));
+    Ok(())
+  }
+
+  // 管理者用: 未公開記事も含めて全記事を tar アーカイブとして書き出せる
+  #[tokio::test(flavor = "current_thread")]
+  async fn export_admin_blog_archive() -> Result<()> {
+    let url = "http://localhost:8001/admin/blog/posts/archive";
+    let resp = Request::new(Methods::GET, url).send().await.unwrap();
+
+    assert_eq!(resp.status(), 200);
+    assert_eq!(resp.header("content-type").unwrap_or_default(), "application/x-tar");
+
+    let archive = resp.text().await.unwrap();
+    assert!(archive.contains("manifest.json"));
+    assert!(archive.contains(&format!("posts/{}.json", test_helper::future_post_id().unwrap())));
+    Ok(())
+  }
+
   mod helper {
     use common::types::api::{CodeBlock, H3Block, Link};
 
@@ -192,7 +453,9 @@ mod tests {
         title: "初めての技術スタックへの挑戦".to_string(),
         thumbnail: Image {
           id: Uuid::new_v4(),
-          path: "test-coffee".to_string()
+          path: "test-coffee".to_string(),
+          file_name: None,
+          caption: None,
         },
         post_date: "2021-01-01".parse()?,
         last_update_date: "2021-01-02".parse()?,
@@ -202,8 +465,10 @@ mod tests {
             id: Uuid::new_v4(),
             text: vec![RichText {
               text: "新しい技術スタックに挑戦することは、いつも冒険と学びの場です。未経験の技術に取り組むたびに、新たな可能性が広がり、成長を実感します。未知のフレームワークやツールを使いこなしていく過程で、技術と一体になる感覚を得ることができます。".to_string(),
-              styles: Style { bold: false, inline_code: false },
+              styles: Style { bold: false, inline_code: false , inline_math: false, italic: false, strikethrough: false, underline: false, highlight: None},
               link: Option::None,
+              ruby: None,
+              footnote_id: None,
             }],
           }),
           BlogPostContent::H2(H2Block {
@@ -218,8 +483,10 @@ mod tests {
             id: Uuid::new_v4(),
             text: vec![RichText {
               text: "すべては一歩から始まります。既存の快適な環境を離れ、新しい技術への挑戦を始めます。最初はドキュメントを読み、サンプルコードを試しながら理解を深めていきます。コードを読むことは、詳細なロジックを理解するための重要な歩準です。一緒にコードを書き試していくと、その過程で新しい発見や意外な気づきが広がり、それらが学習の楽しさにつながります。学ぶつれ、最初は難しく思えたことも後になると理解出来るようになり、それによって自信も上がってきます。最初の図書の読み方やツールの使い方を学んでいく中で、学ぶことの楽しさが潤しみ、目標に向かう過程がより愉快なものに変わっていきます。".to_string(),
-              styles: Style { bold: false, inline_code: false },
+              styles: Style { bold: false, inline_code: false , inline_math: false, italic: false, strikethrough: false, underline: false, highlight: None},
               link: Option::None,
+              ruby: None,
+              footnote_id: None,
             }],
           }),
           BlogPostContent::H2(H2Block {
@@ -235,20 +502,27 @@ mod tests {
             text: vec![
               RichText {
                 text: "試行錯誤は技術習得において欠かせないプロセスです。一度でうまくいかないことがほとんどですが、その失敗から学ぶことで次のステップへのヒントを得ることができます。新しい技術を学ぶ過程では、複数のアプローチを試してみて、何が効果的で何がそうでないかを確認することが非常に重要です。このプロセスによって、自分に合った最適な方法を見つけ出し、技術に対する理解が深まります。".to_string(),
-                styles: Style { bold: false, inline_code: false },
+                styles: Style { bold: false, inline_code: false , inline_math: false, italic: false, strikethrough: false, underline: false, highlight: None},
                 link: Option::Some(Link {
                   url: "https://example.com".to_string(),
+                  post_id: None, heading_id: None,
                 }),
+                ruby: None,
+                footnote_id: None,
               },
               RichText {
                 text: "繰り返しの実践が技術力を向上させる鍵です。".to_string(),
-                styles: Style { bold: true, inline_code: false },
+                styles: Style { bold: true, inline_code: false , inline_math: false, italic: false, strikethrough: false, underline: false, highlight: None},
                 link: Option::None,
+                ruby: None,
+                footnote_id: None,
               },
               RichText {
                 text: "新しいコードを試し、デバッグしながら学ぶことで、単なる理論以上の実践的なスキルを身に付けることができます。".to_string(),
-                styles: Style { bold: false, inline_code: true },
+                styles: Style { bold: false, inline_code: true , inline_math: false, italic: false, strikethrough: false, underline: false, highlight: None},
                 link: Option::None,
+                ruby: None,
+                footnote_id: None,
               },
             ],
           }),
@@ -259,13 +533,19 @@ mod tests {
           BlogPostContent::Image(ImageBlock {
             id: Uuid::new_v4(),
             path: "test-book".to_string(),
+            alt: String::new(),
+            caption: None,
+            width: None,
+            link_url: None,
           }),
           BlogPostContent::Paragraph(ParagraphBlock {
             id: Uuid::new_v4(),
             text: vec![RichText {
               text: "技術の習得には多くの時間と試行錯誤が必要です。途中でエラーに遭遇したり、思った通りに動作しないこともありますが、それが学びの一部です。失敗を繰り返しながら改善を続けることで、徐々に技術が身についていきます。問題に直面した際に、その原因を調査し、解決する過程で多くの新しい知識を得ることができます。このプロセスは、ただ単に技術を学ぶだけでなく、課題解決能力や論理的な思考力を鍛える機会にもなります。".to_string(),
-              styles: Style { bold: false, inline_code: false },
+              styles: Style { bold: false, inline_code: false , inline_math: false, italic: false, strikethrough: false, underline: false, highlight: None},
               link: Option::None,
+              ruby: None,
+              footnote_id: None,
             }],
           }),
           BlogPostContent::Code(CodeBlock {
@@ -273,8 +553,18 @@ mod tests {
             title: "サンプルコード".to_string(),
             code: "console.log(\"Hello, World!\");".to_string(),
             language: "javascript".to_string(),
+            highlight_lines: None,
+            start_line: 1,
+            diff: false,
+            show_line_numbers: false,
+            highlighted: None,
           })
         ],
+        footnotes: vec![],
+        seo: Default::default(),
+        excerpt: Default::default(),
+        manual_excerpt: Default::default(),
+        reading_time_minutes: Default::default(),
       };
 
       Ok(blog_post)
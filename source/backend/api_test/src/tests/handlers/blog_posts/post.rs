@@ -1,10 +1,17 @@
 #[cfg(test)]
 mod tests {
   use crate::tests::handlers::blog_posts::post::helper;
+  use crate::tests::handlers::blog_posts::test_helper;
   use crate::tests::helper::http::methods::Methods;
   use crate::tests::helper::http::request::Request;
   use anyhow::{Context, Result};
-  use common::types::api::{BlogPost, CreateBlogPostRequest};
+  use common::types::api::{
+    BlogArchiveConflictKind, BlogPost, BlogPostContent, CreateBlogPostContentRequest, CreateBlogPostRequest, CreateCodeBlockRequest, CreateDetailsBlockRequest,
+    CreateDiagramBlockRequest, CreateEmbedBlockRequest, CreateFootnoteRequest, CreateImageBlockRequest, CreateLinkCardBlockRequest, CreateMathBlockRequest,
+    CreateParagraphBlockRequest, DanglingInternalLink, DanglingLinkReason, DiagramKind, EmbedProvider, HighlightColor, ImportBlogArchiveResponse,
+    ImportMarkdownPostResponse, ImportWordPressPostsResponse, Link, RichText, Style, WordPressImportStatus,
+  };
+  use uuid::Uuid;
 
   #[tokio::test(flavor = "current_thread")]
   async fn post_single_blog_post() -> Result<()> {
@@ -27,6 +34,41 @@ mod tests {
     Ok(())
   }
 
+  #[tokio::test(flavor = "current_thread")]
+  async fn post_blog_post_excerpt_and_reading_time() -> Result<()> {
+    let url = "http://localhost:8001/admin/blog/posts";
+
+    // 抜粋を指定しない場合は本文の段落から作られる
+    let mut blog_post_for_req: CreateBlogPostRequest = helper::create_blog_post_request_for_req("抜粋テスト記事").await.unwrap();
+    let post_request = Request::new(
+      Methods::POST {
+        body: serde_json::to_string(&blog_post_for_req).context("JSON データに変換できませんでした").unwrap(),
+      },
+      &url,
+    );
+    let resp = post_request.send().await.unwrap().text().await.unwrap();
+    let blog_post_by_resp: BlogPost = serde_json::from_str(&resp).context("JSON データをパースできませんでした").unwrap();
+
+    assert!(!blog_post_by_resp.excerpt.is_empty());
+    assert_eq!(blog_post_by_resp.manual_excerpt, None);
+    assert!(blog_post_by_resp.reading_time_minutes >= 1);
+
+    // 抜粋を指定した場合はそれが使われる
+    blog_post_for_req.manual_excerpt = Some("カードに表示する抜粋".to_string());
+    let post_request = Request::new(
+      Methods::POST {
+        body: serde_json::to_string(&blog_post_for_req).context("JSON データに変換できませんでした").unwrap(),
+      },
+      &url,
+    );
+    let resp = post_request.send().await.unwrap().text().await.unwrap();
+    let blog_post_by_resp: BlogPost = serde_json::from_str(&resp).context("JSON データをパースできませんでした").unwrap();
+
+    assert_eq!(blog_post_by_resp.excerpt, "カードに表示する抜粋");
+    assert_eq!(blog_post_by_resp.manual_excerpt.as_deref(), Some("カードに表示する抜粋"));
+    Ok(())
+  }
+
   #[tokio::test(flavor = "current_thread")]
   async fn post_blog_post_with_future_published_date() -> Result<()> {
     let url = "http://localhost:8001/admin/blog/posts";
@@ -52,14 +94,788 @@ mod tests {
     assert_eq!(blog_post_by_resp.published_date, future_date);
     Ok(())
   }
+
+  #[tokio::test(flavor = "current_thread")]
+  async fn post_blog_post_with_invalid_latex_returns_bad_request() -> Result<()> {
+    let url = "http://localhost:8001/admin/blog/posts";
+
+    // 禁止マクロを含む数式ブロックを持つブログ記事 json を作成
+    let mut blog_post_for_req: CreateBlogPostRequest = helper::create_blog_post_request_for_req("不正な数式記事").await.unwrap();
+    blog_post_for_req.contents = vec![CreateBlogPostContentRequest::Math(CreateMathBlockRequest {
+      source: r"\input{/etc/passwd}".to_string(),
+    })];
+    let blog_post_json_for_req: String = serde_json::to_string(&blog_post_for_req).context("JSON データに変換できませんでした").unwrap();
+
+    // POST リクエストを送信 -> バリデーションエラーになることを確認
+    let post_request = Request::new(Methods::POST { body: blog_post_json_for_req }, &url);
+    let response = post_request.send().await.unwrap();
+    assert_eq!(response.status(), 400);
+    Ok(())
+  }
+
+  #[tokio::test(flavor = "current_thread")]
+  async fn import_markdown_post() -> Result<()> {
+    let url = "http://localhost:8001/admin/blog/posts/import/markdown";
+
+    // 登録済みの画像と、記事で表現できないリストを含む Markdown を作成
+    let any_image = test_helper::fetch_any_image().await?;
+    let markdown = format!(
+      "---\ntitle: Markdown 取り込み記事\npost_date: 2024-03-04\npublished_date: 2024-03-04\n---\n\n## 見出し\n\n**太字**の段落\n\n![画像]({})\n*キャプション*\n\n- リスト\n",
+      any_image.path
+    );
+
+    // POST リクエストを送信 -> レスポンスを取得 -> JSON データを構造体にパース
+    let resp = Request::new(Methods::POST { body: markdown }, url).send().await.unwrap().text().await.unwrap();
+    let import_resp: ImportMarkdownPostResponse = serde_json::from_str(&resp).context("JSON データをパースできませんでした").unwrap();
+
+    assert_eq!(import_resp.post.title, "Markdown 取り込み記事");
+    assert_eq!(import_resp.post.post_date.to_string(), "2024-03-04");
+    assert_eq!(import_resp.post.contents.len(), 4);
+    match &import_resp.post.contents[2] {
+      BlogPostContent::Image(image_block) => {
+        assert_eq!(image_block.path, any_image.path);
+        assert_eq!(image_block.caption.as_deref(), Some("キャプション"));
+      }
+      _ => panic!("Image ブロックではありません"),
+    }
+    // リストは段落に変換され、その旨が警告として返る
+    assert_eq!(import_resp.warnings.len(), 1);
+    assert_eq!(import_resp.warnings[0].line, 14);
+    Ok(())
+  }
+
+  #[tokio::test(flavor = "current_thread")]
+  async fn import_markdown_post_without_title_returns_bad_request() -> Result<()> {
+    let url = "http://localhost:8001/admin/blog/posts/import/markdown";
+
+    let response = Request::new(
+      Methods::POST {
+        body: "タイトルのない本文\n".to_string(),
+      },
+      url,
+    )
+    .send()
+    .await
+    .unwrap();
+
+    assert_eq!(response.status(), 400);
+    Ok(())
+  }
+
+  #[tokio::test(flavor = "current_thread")]
+  async fn post_blog_post_with_embed_block() -> Result<()> {
+    let url = "http://localhost:8001/admin/blog/posts";
+
+    // 埋め込みブロックを持つブログ記事 json を作成
+    let mut blog_post_for_req: CreateBlogPostRequest = helper::create_blog_post_request_for_req("埋め込み記事").await.unwrap();
+    blog_post_for_req.contents = vec![CreateBlogPostContentRequest::Embed(CreateEmbedBlockRequest {
+      url: "https://x.com/someone/status/1234567890123456789".to_string(),
+    })];
+    let blog_post_json_for_req: String = serde_json::to_string(&blog_post_for_req).context("JSON データに変換できませんでした").unwrap();
+
+    // POST リクエストを送信 -> レスポンスを取得 -> JSON データを構造体にパース
+    let post_request = Request::new(Methods::POST { body: blog_post_json_for_req }, &url);
+    let resp = post_request.send().await.unwrap().text().await.unwrap();
+    let blog_post_by_resp: BlogPost = serde_json::from_str(&resp).context("JSON データをパースできませんでした").unwrap();
+
+    // URL からプロバイダとリソース ID が抽出されていることを確認
+    match &blog_post_by_resp.contents[0] {
+      BlogPostContent::Embed(embed) => {
+        assert_eq!(embed.provider, EmbedProvider::Twitter);
+        assert_eq!(embed.resource_id, "1234567890123456789");
+      }
+      _ => panic!("Embed ブロックではありません"),
+    }
+    Ok(())
+  }
+
+  #[tokio::test(flavor = "current_thread")]
+  async fn post_blog_post_with_raw_iframe_embed_returns_bad_request() -> Result<()> {
+    let url = "http://localhost:8001/admin/blog/posts";
+
+    // iframe をそのまま埋め込もうとするブログ記事 json を作成
+    let mut blog_post_for_req: CreateBlogPostRequest = helper::create_blog_post_request_for_req("iframe 記事").await.unwrap();
+    blog_post_for_req.contents = vec![CreateBlogPostContentRequest::Embed(CreateEmbedBlockRequest {
+      url: r#"<iframe src="https://www.youtube.com/embed/dQw4w9WgXcQ"></iframe>"#.to_string(),
+    })];
+    let blog_post_json_for_req: String = serde_json::to_string(&blog_post_for_req).context("JSON データに変換できませんでした").unwrap();
+
+    // POST リクエストを送信 -> バリデーションエラーになることを確認
+    let post_request = Request::new(Methods::POST { body: blog_post_json_for_req }, &url);
+    let response = post_request.send().await.unwrap();
+    assert_eq!(response.status(), 400);
+    Ok(())
+  }
+
+  #[tokio::test(flavor = "current_thread")]
+  async fn post_blog_post_with_image_presentation() -> Result<()> {
+    let url = "http://localhost:8001/admin/blog/posts";
+
+    // 代替テキストやキャプションを指定した画像ブロックを持つブログ記事 json を作成
+    let any_image = test_helper::fetch_any_image().await?;
+    let mut blog_post_for_req: CreateBlogPostRequest = helper::create_blog_post_request_for_req("画像記事").await.unwrap();
+    blog_post_for_req.contents = vec![CreateBlogPostContentRequest::Image(CreateImageBlockRequest {
+      path: any_image.path.clone(),
+      alt: "サンプル画像".to_string(),
+      caption: Some("記事用のキャプション".to_string()),
+      width: Some(640),
+      link_url: Some("https://example.com/original.png".to_string()),
+    })];
+    let blog_post_json_for_req: String = serde_json::to_string(&blog_post_for_req).context("JSON データに変換できませんでした").unwrap();
+
+    // POST リクエストを送信 -> レスポンスを取得 -> JSON データを構造体にパース
+    let post_request = Request::new(Methods::POST { body: blog_post_json_for_req }, &url);
+    let resp = post_request.send().await.unwrap().text().await.unwrap();
+    let blog_post_by_resp: BlogPost = serde_json::from_str(&resp).context("JSON データをパースできませんでした").unwrap();
+
+    // 指定した表示設定が保存されていることを確認
+    match &blog_post_by_resp.contents[0] {
+      BlogPostContent::Image(image_block) => {
+        assert_eq!(image_block.path, any_image.path);
+        assert_eq!(image_block.alt, "サンプル画像");
+        assert_eq!(image_block.caption.as_deref(), Some("記事用のキャプション"));
+        assert_eq!(image_block.width, Some(640));
+        assert_eq!(image_block.link_url.as_deref(), Some("https://example.com/original.png"));
+      }
+      _ => panic!("Image ブロックではありません"),
+    }
+    Ok(())
+  }
+
+  #[tokio::test(flavor = "current_thread")]
+  async fn post_blog_post_with_invalid_image_width_returns_bad_request() -> Result<()> {
+    let url = "http://localhost:8001/admin/blog/posts";
+
+    // 表示幅が範囲外の画像ブロックを持つブログ記事 json を作成
+    let any_image = test_helper::fetch_any_image().await?;
+    let mut blog_post_for_req: CreateBlogPostRequest = helper::create_blog_post_request_for_req("不正な画像記事").await.unwrap();
+    blog_post_for_req.contents = vec![CreateBlogPostContentRequest::Image(CreateImageBlockRequest {
+      path: any_image.path,
+      alt: "サンプル画像".to_string(),
+      caption: None,
+      width: Some(100_000),
+      link_url: None,
+    })];
+    let blog_post_json_for_req: String = serde_json::to_string(&blog_post_for_req).context("JSON データに変換できませんでした").unwrap();
+
+    // POST リクエストを送信 -> バリデーションエラーになることを確認
+    let post_request = Request::new(Methods::POST { body: blog_post_json_for_req }, &url);
+    let response = post_request.send().await.unwrap();
+    assert_eq!(response.status(), 400);
+    Ok(())
+  }
+
+  #[tokio::test(flavor = "current_thread")]
+  async fn post_blog_post_with_decorated_rich_text() -> Result<()> {
+    let url = "http://localhost:8001/admin/blog/posts";
+
+    // 斜体・打ち消し線・下線・マーカーを指定した段落を持つブログ記事 json を作成
+    let mut blog_post_for_req: CreateBlogPostRequest = helper::create_blog_post_request_for_req("装飾テキスト記事").await.unwrap();
+    let decorated_style = Style {
+      bold: false,
+      inline_code: false,
+      inline_math: false,
+      italic: true,
+      strikethrough: true,
+      underline: true,
+      highlight: Some(HighlightColor::Blue),
+    };
+    blog_post_for_req.contents = vec![CreateBlogPostContentRequest::Paragraph(CreateParagraphBlockRequest {
+      text: vec![RichText {
+        text: "装飾されたテキスト".to_string(),
+        styles: decorated_style.clone(),
+        link: None,
+        ruby: None,
+        footnote_id: None,
+      }],
+    })];
+    let blog_post_json_for_req: String = serde_json::to_string(&blog_post_for_req).context("JSON データに変換できませんでした").unwrap();
+
+    // POST リクエストを送信 -> レスポンスを取得 -> JSON データを構造体にパース
+    let post_request = Request::new(Methods::POST { body: blog_post_json_for_req }, &url);
+    let resp = post_request.send().await.unwrap().text().await.unwrap();
+    let blog_post_by_resp: BlogPost = serde_json::from_str(&resp).context("JSON データをパースできませんでした").unwrap();
+
+    // スタイルがそのまま保存されていることを確認
+    match &blog_post_by_resp.contents[0] {
+      BlogPostContent::Paragraph(paragraph) => assert_eq!(paragraph.text[0].styles, decorated_style),
+      _ => panic!("ParagraphBlock ではありません"),
+    }
+    Ok(())
+  }
+
+  #[tokio::test(flavor = "current_thread")]
+  async fn post_blog_post_with_ruby() -> Result<()> {
+    let url = "http://localhost:8001/admin/blog/posts";
+
+    // ルビを振ったテキストを持つブログ記事 json を作成
+    let mut blog_post_for_req: CreateBlogPostRequest = helper::create_blog_post_request_for_req("ルビ記事").await.unwrap();
+    blog_post_for_req.contents = vec![CreateBlogPostContentRequest::Paragraph(CreateParagraphBlockRequest {
+      text: vec![RichText {
+        text: "冪等性".to_string(),
+        styles: Style {
+          bold: false,
+          inline_code: false,
+          inline_math: false,
+          italic: false,
+          strikethrough: false,
+          underline: false,
+          highlight: None,
+        },
+        link: None,
+        ruby: Some("べきとうせい".to_string()),
+        footnote_id: None,
+      }],
+    })];
+    let blog_post_json_for_req: String = serde_json::to_string(&blog_post_for_req).context("JSON データに変換できませんでした").unwrap();
+
+    // POST リクエストを送信 -> レスポンスを取得 -> JSON データを構造体にパース
+    let post_request = Request::new(Methods::POST { body: blog_post_json_for_req }, &url);
+    let resp = post_request.send().await.unwrap().text().await.unwrap();
+    let blog_post_by_resp: BlogPost = serde_json::from_str(&resp).context("JSON データをパースできませんでした").unwrap();
+
+    // ルビが保存されていることを確認
+    match &blog_post_by_resp.contents[0] {
+      BlogPostContent::Paragraph(paragraph) => assert_eq!(paragraph.text[0].ruby.as_deref(), Some("べきとうせい")),
+      _ => panic!("ParagraphBlock ではありません"),
+    }
+    Ok(())
+  }
+
+  #[tokio::test(flavor = "current_thread")]
+  async fn post_blog_post_with_ruby_on_empty_text_returns_bad_request() -> Result<()> {
+    let url = "http://localhost:8001/admin/blog/posts";
+
+    // 空文字列にルビを振ったブログ記事 json を作成
+    let mut blog_post_for_req: CreateBlogPostRequest = helper::create_blog_post_request_for_req("不正なルビ記事").await.unwrap();
+    blog_post_for_req.contents = vec![CreateBlogPostContentRequest::Paragraph(CreateParagraphBlockRequest {
+      text: vec![RichText {
+        text: " ".to_string(),
+        styles: Style {
+          bold: false,
+          inline_code: false,
+          inline_math: false,
+          italic: false,
+          strikethrough: false,
+          underline: false,
+          highlight: None,
+        },
+        link: None,
+        ruby: Some("よみ".to_string()),
+        footnote_id: None,
+      }],
+    })];
+    let blog_post_json_for_req: String = serde_json::to_string(&blog_post_for_req).context("JSON データに変換できませんでした").unwrap();
+
+    // POST リクエストを送信 -> バリデーションエラーになることを確認
+    let post_request = Request::new(Methods::POST { body: blog_post_json_for_req }, &url);
+    let response = post_request.send().await.unwrap();
+    assert_eq!(response.status(), 400);
+    Ok(())
+  }
+
+  #[tokio::test(flavor = "current_thread")]
+  async fn post_blog_post_with_footnotes() -> Result<()> {
+    let url = "http://localhost:8001/admin/blog/posts";
+
+    // 定義順と参照順が異なる脚注を持つブログ記事 json を作成
+    let first_ref_id = uuid::Uuid::new_v4();
+    let second_ref_id = uuid::Uuid::new_v4();
+    let mut blog_post_for_req: CreateBlogPostRequest = helper::create_blog_post_request_for_req("脚注記事").await.unwrap();
+    blog_post_for_req.contents = vec![CreateBlogPostContentRequest::Paragraph(CreateParagraphBlockRequest {
+      text: vec![plain_rich_text("本文1", Some(first_ref_id)), plain_rich_text("本文2", Some(second_ref_id))],
+    })];
+    blog_post_for_req.footnotes = vec![
+      CreateFootnoteRequest {
+        id: second_ref_id,
+        text: vec![plain_rich_text("後に参照される脚注", None)],
+      },
+      CreateFootnoteRequest {
+        id: first_ref_id,
+        text: vec![plain_rich_text("先に参照される脚注", None)],
+      },
+    ];
+    let blog_post_json_for_req: String = serde_json::to_string(&blog_post_for_req).context("JSON データに変換できませんでした").unwrap();
+
+    // POST リクエストを送信 -> レスポンスを取得 -> JSON データを構造体にパース
+    let post_request = Request::new(Methods::POST { body: blog_post_json_for_req }, &url);
+    let resp = post_request.send().await.unwrap().text().await.unwrap();
+    let blog_post_by_resp: BlogPost = serde_json::from_str(&resp).context("JSON データをパースできませんでした").unwrap();
+
+    // 脚注が本文中の初出順に採番されていることを確認
+    assert_eq!(blog_post_by_resp.footnotes.len(), 2);
+    assert_eq!(blog_post_by_resp.footnotes[0].id, first_ref_id);
+    assert_eq!(blog_post_by_resp.footnotes[0].number, 1);
+    assert_eq!(blog_post_by_resp.footnotes[1].id, second_ref_id);
+    assert_eq!(blog_post_by_resp.footnotes[1].number, 2);
+    Ok(())
+  }
+
+  #[tokio::test(flavor = "current_thread")]
+  async fn post_blog_post_with_undefined_footnote_ref_returns_bad_request() -> Result<()> {
+    let url = "http://localhost:8001/admin/blog/posts";
+
+    // 定義されていない脚注を参照するブログ記事 json を作成
+    let mut blog_post_for_req: CreateBlogPostRequest = helper::create_blog_post_request_for_req("不正な脚注記事").await.unwrap();
+    blog_post_for_req.contents = vec![CreateBlogPostContentRequest::Paragraph(CreateParagraphBlockRequest {
+      text: vec![plain_rich_text("本文", Some(uuid::Uuid::new_v4()))],
+    })];
+    let blog_post_json_for_req: String = serde_json::to_string(&blog_post_for_req).context("JSON データに変換できませんでした").unwrap();
+
+    // POST リクエストを送信 -> バリデーションエラーになることを確認
+    let post_request = Request::new(Methods::POST { body: blog_post_json_for_req }, &url);
+    let response = post_request.send().await.unwrap();
+    assert_eq!(response.status(), 400);
+    Ok(())
+  }
+
+  #[tokio::test(flavor = "current_thread")]
+  async fn post_blog_post_with_details_block() -> Result<()> {
+    let url = "http://localhost:8001/admin/blog/posts";
+
+    // 段落とコードブロックを子に持つ折りたたみブロックの記事 json を作成
+    let mut blog_post_for_req: CreateBlogPostRequest = helper::create_blog_post_request_for_req("折りたたみ記事").await.unwrap();
+    blog_post_for_req.contents = vec![
+      CreateBlogPostContentRequest::Details(CreateDetailsBlockRequest {
+        summary: "実行ログ".to_string(),
+        children: vec![
+          CreateBlogPostContentRequest::Paragraph(CreateParagraphBlockRequest {
+            text: vec![plain_rich_text("ログの説明", None)],
+          }),
+          CreateBlogPostContentRequest::Code(CreateCodeBlockRequest {
+            title: "stdout".to_string(),
+            code: "done.".to_string(),
+            language: "text".to_string(),
+            highlight_lines: None,
+            start_line: None,
+            diff: false,
+            show_line_numbers: false,
+          }),
+        ],
+      }),
+      CreateBlogPostContentRequest::Paragraph(CreateParagraphBlockRequest {
+        text: vec![plain_rich_text("折りたたみの後の段落", None)],
+      }),
+    ];
+    let blog_post_json_for_req: String = serde_json::to_string(&blog_post_for_req).context("JSON データに変換できませんでした").unwrap();
+
+    // POST リクエストを送信 -> レスポンスを取得 -> JSON データを構造体にパース
+    let post_request = Request::new(Methods::POST { body: blog_post_json_for_req }, &url);
+    let resp = post_request.send().await.unwrap().text().await.unwrap();
+    let blog_post_by_resp: BlogPost = serde_json::from_str(&resp).context("JSON データをパースできませんでした").unwrap();
+
+    // 子コンテンツが並び順どおりに折りたたみブロックの中に復元されることを確認
+    assert_eq!(blog_post_by_resp.contents.len(), 2);
+    match &blog_post_by_resp.contents[0] {
+      BlogPostContent::Details(details) => {
+        assert_eq!(details.summary, "実行ログ");
+        assert_eq!(details.children.len(), 2);
+        assert!(matches!(details.children[0], BlogPostContent::Paragraph(_)));
+        assert!(matches!(details.children[1], BlogPostContent::Code(_)));
+      }
+      _ => panic!("DetailsBlock ではありません"),
+    }
+    assert!(matches!(blog_post_by_resp.contents[1], BlogPostContent::Paragraph(_)));
+    Ok(())
+  }
+
+  #[tokio::test(flavor = "current_thread")]
+  async fn post_blog_post_with_nested_details_block_returns_bad_request() -> Result<()> {
+    let url = "http://localhost:8001/admin/blog/posts";
+
+    // 折りたたみブロックを入れ子にした記事 json を作成
+    let mut blog_post_for_req: CreateBlogPostRequest = helper::create_blog_post_request_for_req("入れ子の折りたたみ記事").await.unwrap();
+    blog_post_for_req.contents = vec![CreateBlogPostContentRequest::Details(CreateDetailsBlockRequest {
+      summary: "外側".to_string(),
+      children: vec![CreateBlogPostContentRequest::Details(CreateDetailsBlockRequest {
+        summary: "内側".to_string(),
+        children: vec![CreateBlogPostContentRequest::Paragraph(CreateParagraphBlockRequest {
+          text: vec![plain_rich_text("本文", None)],
+        })],
+      })],
+    })];
+    let blog_post_json_for_req: String = serde_json::to_string(&blog_post_for_req).context("JSON データに変換できませんでした").unwrap();
+
+    // POST リクエストを送信 -> バリデーションエラーになることを確認
+    let post_request = Request::new(Methods::POST { body: blog_post_json_for_req }, &url);
+    let response = post_request.send().await.unwrap();
+    assert_eq!(response.status(), 400);
+    Ok(())
+  }
+
+  #[tokio::test(flavor = "current_thread")]
+  async fn post_blog_post_with_diagram_block() -> Result<()> {
+    let url = "http://localhost:8001/admin/blog/posts";
+
+    // Mermaid の図を含むブログ記事 json を作成
+    let source = "sequenceDiagram\n  participant C as Client\n  C->>API: POST /posts\n  API-->>C: 201";
+    let mut blog_post_for_req: CreateBlogPostRequest = helper::create_blog_post_request_for_req("図を含む記事").await.unwrap();
+    blog_post_for_req.contents = vec![CreateBlogPostContentRequest::Diagram(CreateDiagramBlockRequest {
+      kind: DiagramKind::Mermaid,
+      source: source.to_string(),
+    })];
+    let blog_post_json_for_req: String = serde_json::to_string(&blog_post_for_req).context("JSON データに変換できませんでした").unwrap();
+
+    // POST リクエストを送信 -> レスポンスを取得 -> JSON データを構造体にパース
+    let post_request = Request::new(Methods::POST { body: blog_post_json_for_req }, &url);
+    let resp = post_request.send().await.unwrap().text().await.unwrap();
+    let blog_post_by_resp: BlogPost = serde_json::from_str(&resp).context("JSON データをパースできませんでした").unwrap();
+
+    // 図のソースがそのまま保存されていることを確認
+    match &blog_post_by_resp.contents[0] {
+      BlogPostContent::Diagram(diagram) => {
+        assert_eq!(diagram.kind, DiagramKind::Mermaid);
+        assert_eq!(diagram.source, source);
+      }
+      _ => panic!("DiagramBlock ではありません"),
+    }
+    Ok(())
+  }
+
+  #[tokio::test(flavor = "current_thread")]
+  async fn post_blog_post_with_invalid_diagram_returns_bad_request() -> Result<()> {
+    let url = "http://localhost:8001/admin/blog/posts";
+
+    // 図の種類の宣言がないソースを持つ記事 json を作成
+    let mut blog_post_for_req: CreateBlogPostRequest = helper::create_blog_post_request_for_req("不正な図を含む記事").await.unwrap();
+    blog_post_for_req.contents = vec![CreateBlogPostContentRequest::Diagram(CreateDiagramBlockRequest {
+      kind: DiagramKind::Mermaid,
+      source: "A --> B".to_string(),
+    })];
+    let blog_post_json_for_req: String = serde_json::to_string(&blog_post_for_req).context("JSON データに変換できませんでした").unwrap();
+
+    // POST リクエストを送信 -> バリデーションエラーになることを確認
+    let post_request = Request::new(Methods::POST { body: blog_post_json_for_req }, &url);
+    let response = post_request.send().await.unwrap();
+    assert_eq!(response.status(), 400);
+    Ok(())
+  }
+
+  fn plain_rich_text(text: &str, footnote_id: Option<uuid::Uuid>) -> RichText {
+    RichText {
+      text: text.to_string(),
+      styles: Style {
+        bold: false,
+        inline_code: false,
+        inline_math: false,
+        italic: false,
+        strikethrough: false,
+        underline: false,
+        highlight: None,
+      },
+      link: None,
+      ruby: None,
+      footnote_id,
+    }
+  }
+
+  #[tokio::test(flavor = "current_thread")]
+  async fn post_blog_post_with_internal_link_to_missing_post_is_reported_as_dangling() -> Result<()> {
+    let url = "http://localhost:8001/admin/blog/posts";
+    let missing_post_id = uuid::Uuid::new_v4();
+
+    // 存在しない記事への内部リンクを持つブログ記事 json を作成
+    let mut blog_post_for_req: CreateBlogPostRequest = helper::create_blog_post_request_for_req("内部リンク記事").await.unwrap();
+    blog_post_for_req.contents = vec![CreateBlogPostContentRequest::Paragraph(CreateParagraphBlockRequest {
+      text: vec![RichText {
+        text: "関連記事".to_string(),
+        styles: Style {
+          bold: false,
+          inline_code: false,
+          inline_math: false,
+          italic: false,
+          strikethrough: false,
+          underline: false,
+          highlight: None,
+        },
+        link: Some(Link {
+          url: String::new(),
+          post_id: Some(missing_post_id),
+          heading_id: None,
+        }),
+        ruby: None,
+        footnote_id: None,
+      }],
+    })];
+    let blog_post_json_for_req: String = serde_json::to_string(&blog_post_for_req).context("JSON データに変換できませんでした").unwrap();
+
+    // POST リクエストを送信 -> レスポンスを取得 -> JSON データを構造体にパース
+    let post_request = Request::new(Methods::POST { body: blog_post_json_for_req }, &url);
+    let resp = post_request.send().await.unwrap().text().await.unwrap();
+    let blog_post_by_resp: BlogPost = serde_json::from_str(&resp).context("JSON データをパースできませんでした").unwrap();
+
+    // 内部リンクが記事パスに解決されていることを確認
+    match &blog_post_by_resp.contents[0] {
+      BlogPostContent::Paragraph(paragraph) => {
+        let link = paragraph.text[0].link.as_ref().expect("リンクがありません");
+        assert_eq!(link.url, format!("/posts/{}", missing_post_id));
+        assert_eq!(link.post_id, Some(missing_post_id));
+      }
+      _ => panic!("ParagraphBlock ではありません"),
+    }
+
+    // リンク切れ一覧に含まれていることを確認
+    let dangling_url = "http://localhost:8001/admin/blog/posts/internal-links/dangling";
+    let resp = Request::new(Methods::GET, &dangling_url).send().await.unwrap().text().await.unwrap();
+    let dangling_links: Vec<DanglingInternalLink> = serde_json::from_str(&resp).context("JSON データをパースできませんでした").unwrap();
+    let reported = dangling_links.iter().find(|link| link.source_post_id == blog_post_by_resp.id).expect("リンク切れとして報告されていません");
+    assert_eq!(reported.target_post_id, missing_post_id);
+    assert_eq!(reported.reason, DanglingLinkReason::PostNotFound);
+    Ok(())
+  }
+
+  #[tokio::test(flavor = "current_thread")]
+  async fn post_blog_post_with_code_block_options() -> Result<()> {
+    let url = "http://localhost:8001/admin/blog/posts";
+
+    // 強調表示行や開始行番号を指定したコードブロックを持つブログ記事 json を作成
+    let mut blog_post_for_req: CreateBlogPostRequest = helper::create_blog_post_request_for_req("差分コード記事").await.unwrap();
+    blog_post_for_req.contents = vec![CreateBlogPostContentRequest::Code(CreateCodeBlockRequest {
+      title: "main.rs".to_string(),
+      code: " fn main() {\n-    old();\n+    new();\n }".to_string(),
+      language: "rust".to_string(),
+      highlight_lines: Some("12, 11".to_string()),
+      start_line: Some(10),
+      diff: true,
+      show_line_numbers: true,
+    })];
+    let blog_post_json_for_req: String = serde_json::to_string(&blog_post_for_req).context("JSON データに変換できませんでした").unwrap();
+
+    // POST リクエストを送信 -> レスポンスを取得 -> JSON データを構造体にパース
+    let post_request = Request::new(Methods::POST { body: blog_post_json_for_req }, &url);
+    let resp = post_request.send().await.unwrap().text().await.unwrap();
+    let blog_post_by_resp: BlogPost = serde_json::from_str(&resp).context("JSON データをパースできませんでした").unwrap();
+
+    // 表示オプションが正規化されて保存されていることを確認
+    match &blog_post_by_resp.contents[0] {
+      BlogPostContent::Code(code_block) => {
+        assert_eq!(code_block.highlight_lines.as_deref(), Some("11-12"));
+        assert_eq!(code_block.start_line, 10);
+        assert!(code_block.diff);
+        assert!(code_block.show_line_numbers);
+      }
+      _ => panic!("CodeBlock ではありません"),
+    }
+    Ok(())
+  }
+
+  #[tokio::test(flavor = "current_thread")]
+  async fn post_blog_post_with_highlight_beyond_code_returns_bad_request() -> Result<()> {
+    let url = "http://localhost:8001/admin/blog/posts";
+
+    // コードの行数を超える強調表示行を指定したブログ記事 json を作成
+    let mut blog_post_for_req: CreateBlogPostRequest = helper::create_blog_post_request_for_req("不正な強調表示記事").await.unwrap();
+    blog_post_for_req.contents = vec![CreateBlogPostContentRequest::Code(CreateCodeBlockRequest {
+      title: "main.rs".to_string(),
+      code: "fn main() {}".to_string(),
+      language: "rust".to_string(),
+      highlight_lines: Some("1-3".to_string()),
+      start_line: None,
+      diff: false,
+      show_line_numbers: false,
+    })];
+    let blog_post_json_for_req: String = serde_json::to_string(&blog_post_for_req).context("JSON データに変換できませんでした").unwrap();
+
+    // POST リクエストを送信 -> バリデーションエラーになることを確認
+    let post_request = Request::new(Methods::POST { body: blog_post_json_for_req }, &url);
+    let response = post_request.send().await.unwrap();
+    assert_eq!(response.status(), 400);
+    Ok(())
+  }
+
+  #[tokio::test(flavor = "current_thread")]
+  async fn post_blog_post_with_unreachable_link_card_keeps_url() -> Result<()> {
+    let url = "http://localhost:8001/admin/blog/posts";
+
+    // OGP を取得できない URL のリンクカードを持つブログ記事 json を作成
+    let mut blog_post_for_req: CreateBlogPostRequest = helper::create_blog_post_request_for_req("リンクカード記事").await.unwrap();
+    blog_post_for_req.contents = vec![CreateBlogPostContentRequest::LinkCard(CreateLinkCardBlockRequest {
+      url: "http://127.0.0.1:9/unreachable".to_string(),
+    })];
+    let blog_post_json_for_req: String = serde_json::to_string(&blog_post_for_req).context("JSON データに変換できませんでした").unwrap();
+
+    // POST リクエストを送信 -> レスポンスを取得 -> JSON データを構造体にパース
+    let post_request = Request::new(Methods::POST { body: blog_post_json_for_req }, &url);
+    let resp = post_request.send().await.unwrap().text().await.unwrap();
+    let blog_post_by_resp: BlogPost = serde_json::from_str(&resp).context("JSON データをパースできませんでした").unwrap();
+
+    // OGP の取得に失敗しても記事は作成され、メタデータは空になることを確認
+    match &blog_post_by_resp.contents[0] {
+      BlogPostContent::LinkCard(link_card) => {
+        assert_eq!(link_card.url, "http://127.0.0.1:9/unreachable");
+        assert_eq!(link_card.title, None);
+      }
+      _ => panic!("LinkCard ブロックではありません"),
+    }
+    Ok(())
+  }
+
+  #[tokio::test(flavor = "current_thread")]
+  async fn post_blog_post_with_non_http_link_card_returns_bad_request() -> Result<()> {
+    let url = "http://localhost:8001/admin/blog/posts";
+
+    // http(s) 以外の URL のリンクカードを持つブログ記事 json を作成
+    let mut blog_post_for_req: CreateBlogPostRequest = helper::create_blog_post_request_for_req("不正なリンクカード記事").await.unwrap();
+    blog_post_for_req.contents = vec![CreateBlogPostContentRequest::LinkCard(CreateLinkCardBlockRequest {
+      url: "javascript:alert(1)".to_string(),
+    })];
+    let blog_post_json_for_req: String = serde_json::to_string(&blog_post_for_req).context("JSON データに変換できませんでした").unwrap();
+
+    // POST リクエストを送信 -> バリデーションエラーになることを確認
+    let post_request = Request::new(Methods::POST { body: blog_post_json_for_req }, &url);
+    let response = post_request.send().await.unwrap();
+    assert_eq!(response.status(), 400);
+    Ok(())
+  }
+
+  #[tokio::test(flavor = "current_thread")]
+  async fn refresh_missing_link_card_returns_not_found() -> Result<()> {
+    // リンクカードを含まない記事を作成
+    let blog_post_for_req: CreateBlogPostRequest = helper::create_blog_post_request_for_req("リンクカードなし記事").await.unwrap();
+    let blog_post_json_for_req: String = serde_json::to_string(&blog_post_for_req).context("JSON データに変換できませんでした").unwrap();
+    let post_request = Request::new(Methods::POST { body: blog_post_json_for_req }, "http://localhost:8001/admin/blog/posts");
+    let resp = post_request.send().await.unwrap().text().await.unwrap();
+    let blog_post_by_resp: BlogPost = serde_json::from_str(&resp).context("JSON データをパースできませんでした").unwrap();
+
+    // 存在しないリンクカードの再取得は 404 になることを確認
+    let refresh_url = format!(
+      "http://localhost:8001/admin/blog/posts/{}/link-cards/{}/refresh",
+      blog_post_by_resp.id,
+      uuid::Uuid::new_v4()
+    );
+    let response = Request::new(Methods::POST { body: String::new() }, &refresh_url).send().await.unwrap();
+    assert_eq!(response.status(), 404);
+    Ok(())
+  }
+
+  #[tokio::test(flavor = "current_thread")]
+  async fn import_wordpress_posts() -> Result<()> {
+    let url = "http://localhost:8001/admin/blog/posts/import/wordpress";
+    let wxr = r#"<?xml version="1.0" encoding="UTF-8" ?>
+<rss version="2.0" xmlns:content="http://purl.org/rss/1.0/modules/content/" xmlns:wp="http://wordpress.org/export/1.2/">
+<channel>
+  <item>
+    <title>WordPress から移行した記事</title>
+    <content:encoded><![CDATA[<h2>見出し</h2><p>本文の<strong>強調</strong></p><img src="https://old.example.com/wp-content/uploads/2019/05/api-test.png"><ul><li>項目</li></ul>]]></content:encoded>
+    <wp:post_id>1</wp:post_id>
+    <wp:post_date>2019-05-01 10:00:00</wp:post_date>
+    <wp:status>publish</wp:status>
+    <wp:post_type>post</wp:post_type>
+  </item>
+  <item>
+    <title>下書き</title>
+    <wp:post_id>2</wp:post_id>
+    <wp:status>draft</wp:status>
+    <wp:post_type>post</wp:post_type>
+  </item>
+</channel>
+</rss>"#;
+
+    let resp = Request::new(Methods::POST { body: wxr.to_string() }, url).send().await.unwrap().text().await.unwrap();
+    let import_resp: ImportWordPressPostsResponse = serde_json::from_str(&resp).context("JSON データをパースできませんでした").unwrap();
+
+    assert_eq!(import_resp.items.len(), 2);
+    let imported = &import_resp.items[0];
+    assert_eq!(imported.status, WordPressImportStatus::Imported);
+    assert!(imported.lossy_notes.contains(&"リストを段落に変換しました".to_string()));
+    assert_eq!(import_resp.items[1].status, WordPressImportStatus::Skipped);
+
+    // 作成された記事の投稿日が WordPress の投稿日になっていることを確認
+    let post_url = format!("http://localhost:8001/admin/blog/posts/{}", imported.post_id.unwrap());
+    let resp = Request::new(Methods::GET, &post_url).send().await.unwrap().text().await.unwrap();
+    let blog_post: BlogPost = serde_json::from_str(&resp).context("JSON データをパースできませんでした").unwrap();
+    assert_eq!(blog_post.post_date.to_string(), "2019-05-01");
+    assert_eq!(blog_post.thumbnail.path, "wordpress/2019/05/api-test.png");
+    Ok(())
+  }
+
+  #[tokio::test(flavor = "current_thread")]
+  async fn import_wordpress_posts_with_invalid_file_returns_bad_request() -> Result<()> {
+    let post_request = Request::new(
+      Methods::POST {
+        body: "<html></html>".to_string(),
+      },
+      "http://localhost:8001/admin/blog/posts/import/wordpress",
+    );
+    let response = post_request.send().await.unwrap();
+    assert_eq!(response.status(), 400);
+    Ok(())
+  }
+
+  #[tokio::test(flavor = "current_thread")]
+  async fn import_exported_blog_archive_as_dry_run() -> Result<()> {
+    let url = "http://localhost:8001/admin/blog/posts/archive";
+
+    // 書き出したアーカイブをそのままドライランで取り込む
+    let archive = Request::new(Methods::GET, url).send().await.unwrap().text().await.unwrap();
+    let import_url = format!("{}?dry_run=true", url);
+    let resp = Request::new(Methods::POST { body: archive }, &import_url).send().await.unwrap().text().await.unwrap();
+    let import_resp: ImportBlogArchiveResponse = serde_json::from_str(&resp).context("JSON データをパースできませんでした").unwrap();
+
+    // 既存の記事は上書き対象として報告され、取り込みを妨げる衝突はない
+    let seeded_post_id = test_helper::minimal_blog_post1()?.id;
+    assert!(import_resp.dry_run);
+    assert!(import_resp.conflicts.iter().any(|conflict| conflict.kind == BlogArchiveConflictKind::ExistingPost && conflict.id == seeded_post_id));
+    assert!(import_resp.conflicts.iter().all(|conflict| !conflict.blocking));
+    Ok(())
+  }
+
+  // 閲覧の記録は数えたかどうかに関わらず 204 を返す
+  #[tokio::test(flavor = "current_thread")]
+  async fn record_blog_post_view() -> Result<()> {
+    let url = "http://localhost:8001/blog/posts/672f2772-72b5-404a-8895-b1fbbf310801/views";
+    let browser = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/130.0.0.0 Safari/537.36";
+
+    for user_agent in [browser, browser, "Mozilla/5.0 (compatible; Googlebot/2.1; +http://www.google.com/bot.html)"] {
+      let resp = Request::new(Methods::POST { body: String::new() }, url).header("User-Agent", user_agent).send().await.unwrap();
+      assert_eq!(resp.status(), 204);
+    }
+    Ok(())
+  }
+
+  // 存在しない記事と未公開記事の閲覧は記録できない
+  #[tokio::test(flavor = "current_thread")]
+  async fn record_blog_post_view_not_found() -> Result<()> {
+    let browser = "Mozilla/5.0 (Macintosh; Intel Mac OS X 14_0) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.0 Safari/605.1.15";
+    // 50年後記事1（未公開記事）
+    for post_id in [Uuid::new_v4().to_string(), "12345678-90ab-cdef-1234-567890abcdef".to_string()] {
+      let url = format!("http://localhost:8001/blog/posts/{}/views", post_id);
+      let resp = Request::new(Methods::POST { body: String::new() }, &url).header("User-Agent", browser).send().await.unwrap();
+      assert_eq!(resp.status(), 404);
+    }
+    Ok(())
+  }
+
+  // 人気記事を管理者が選ぶ設定の場合は閲覧数から選び直せない
+  #[tokio::test(flavor = "current_thread")]
+  async fn recompute_popular_posts_in_manual_mode_returns_conflict() -> Result<()> {
+    let url = "http://localhost:8001/admin/blog/posts/popular/recompute";
+    let resp = Request::new(Methods::POST { body: String::new() }, url).send().await.unwrap();
+
+    assert_eq!(resp.status(), 409);
+    Ok(())
+  }
+
+  #[tokio::test(flavor = "current_thread")]
+  async fn import_invalid_blog_archive_returns_bad_request() -> Result<()> {
+    let post_request = Request::new(
+      Methods::POST {
+        body: "tar ではないデータ".to_string(),
+      },
+      "http://localhost:8001/admin/blog/posts/archive",
+    );
+    let response = post_request.send().await.unwrap();
+    assert_eq!(response.status(), 400);
+    Ok(())
+  }
 }
 
 mod helper {
   use crate::tests::handlers::blog_posts::test_helper;
   use anyhow::Result;
   use common::types::api::{
-    CreateBlogPostContentRequest, CreateBlogPostRequest, CreateCodeBlockRequest, CreateH2BlockRequest, CreateH3BlockRequest, CreateImageBlockRequest,
-    CreateImageContentRequest, CreateParagraphBlockRequest, Link, RichText, Style,
+    CreateBlogPostContentRequest, CreateBlogPostRequest, CreateCodeBlockRequest, CreateEmbedBlockRequest, CreateH2BlockRequest, CreateH3BlockRequest,
+    CreateImageBlockRequest, CreateImageContentRequest, CreateMathBlockRequest, CreateParagraphBlockRequest, Link, RichText, Style,
   };
 
   pub async fn create_blog_post_request_for_req(title: &str) -> Result<CreateBlogPostRequest> {
@@ -79,18 +895,37 @@ mod helper {
           text: vec![
             RichText {
               text: "これはテスト用の文字列です。".to_string(),
-              styles: Style { bold: true, inline_code: true },
+              styles: Style {
+                bold: true,
+                inline_code: true,
+                inline_math: false,
+                italic: false,
+                strikethrough: false,
+                underline: false,
+                highlight: None,
+              },
               link: Option::None,
+              ruby: None,
+              footnote_id: None,
             },
             RichText {
               text: "これはテスト用の文字列その2です。".to_string(),
               styles: Style {
                 bold: false,
                 inline_code: false,
+                inline_math: false,
+                italic: false,
+                strikethrough: false,
+                underline: false,
+                highlight: None,
               },
               link: Option::Some(Link {
                 url: "https://example.com".to_string(),
+                post_id: None,
+                heading_id: None,
               }),
+              ruby: None,
+              footnote_id: None,
             },
           ],
         }),
@@ -100,13 +935,32 @@ mod helper {
         CreateBlogPostContentRequest::H3(CreateH3BlockRequest {
           text: "見出しレベル3".to_string(),
         }),
-        CreateBlogPostContentRequest::Image(CreateImageBlockRequest { path: any_image.path.clone() }),
+        CreateBlogPostContentRequest::Image(CreateImageBlockRequest {
+          path: any_image.path.clone(),
+          alt: String::new(),
+          caption: None,
+          width: None,
+          link_url: None,
+        }),
         CreateBlogPostContentRequest::Code(CreateCodeBlockRequest {
           title: "サンプルコード".to_string(),
           code: "console.log('Hello, World!')".to_string(),
           language: "javascript".to_string(),
+          highlight_lines: None,
+          start_line: None,
+          diff: false,
+          show_line_numbers: false,
+        }),
+        CreateBlogPostContentRequest::Math(CreateMathBlockRequest {
+          source: r"\sum_{i=1}^{n} i = \frac{n(n+1)}{2}".to_string(),
+        }),
+        CreateBlogPostContentRequest::Embed(CreateEmbedBlockRequest {
+          url: "https://www.youtube.com/watch?v=dQw4w9WgXcQ".to_string(),
         }),
       ],
+      footnotes: vec![],
+      seo: Default::default(),
+      manual_excerpt: Default::default(),
     };
 
     Ok(blog_post_request)
@@ -130,10 +984,20 @@ mod helper {
           styles: Style {
             bold: false,
             inline_code: false,
+            inline_math: false,
+            italic: false,
+            strikethrough: false,
+            underline: false,
+            highlight: None,
           },
           link: Option::None,
+          ruby: None,
+          footnote_id: None,
         }],
       })],
+      footnotes: vec![],
+      seo: Default::default(),
+      manual_excerpt: Default::default(),
     };
 
     Ok(blog_post_request)
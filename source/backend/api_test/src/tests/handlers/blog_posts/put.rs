@@ -3,7 +3,7 @@ mod tests {
   use crate::tests::helper::http::request::Request;
   use crate::tests::{handlers::blog_posts::test_helper, helper::http::methods::Methods};
   use anyhow::{Context, Result};
-  use common::types::api::{BlogPost, ErrResponse, UpdateBlogPostRequest};
+  use common::types::api::{BlogPost, ErrResponse, PopularPostMode, PopularPostModeSetting, SeoMetadata, UpdateBlogPostRequest};
 
   #[tokio::test(flavor = "current_thread")]
   async fn put_top_tech_pick_post() -> Result<()> {
@@ -90,6 +90,9 @@ mod tests {
       contents: vec![common::types::api::CreateBlogPostContentRequest::H2(common::types::api::CreateH2BlockRequest {
         text: "元の見出し".to_string(),
       })],
+      footnotes: vec![],
+      seo: Default::default(),
+      manual_excerpt: Default::default(),
     };
 
     let create_url = "http://localhost:8001/admin/blog/posts";
@@ -114,8 +117,15 @@ mod tests {
         common::types::api::BlogPostContent::Image(common::types::api::ImageBlock {
           id: uuid::Uuid::new_v4(),
           path: image_for_block.path,
+          alt: "編集後の画像".to_string(),
+          caption: Some("編集後のキャプション".to_string()),
+          width: Some(480),
+          link_url: None,
         }),
       ],
+      footnotes: vec![],
+      seo: Default::default(),
+      manual_excerpt: Default::default(),
     };
 
     let url = format!("http://localhost:8001/admin/blog/posts/{}", created_post.id);
@@ -151,6 +161,76 @@ mod tests {
     // 公開日が正しく設定されていることを確認
     assert_eq!(edited_post.published_date, update_request.published_date);
 
+    // 画像ブロックの表示設定が保存されていることを確認
+    match &edited_post.contents[1] {
+      common::types::api::BlogPostContent::Image(image_block) => {
+        assert_eq!(image_block.alt, "編集後の画像");
+        assert_eq!(image_block.caption.as_deref(), Some("編集後のキャプション"));
+        assert_eq!(image_block.width, Some(480));
+      }
+      _ => panic!("2番目のコンテンツは画像ブロックである必要があります"),
+    }
+
+    Ok(())
+  }
+
+  #[tokio::test(flavor = "current_thread")]
+  async fn put_blog_post_seo_metadata() -> Result<()> {
+    let thumbnail = test_helper::fetch_any_image().await?;
+    let og_image = test_helper::fetch_any_image().await?;
+
+    // SEO メタデータ付きで記事を作成
+    let create_request = common::types::api::CreateBlogPostRequest {
+      title: "SEO メタデータ付きの記事".to_string(),
+      thumbnail: common::types::api::CreateImageContentRequest {
+        id: Some(thumbnail.id),
+        path: thumbnail.path.clone(),
+      },
+      post_date: "2025-01-01".parse()?,
+      last_update_date: "2025-01-01".parse()?,
+      published_date: "2025-01-01".parse()?,
+      contents: vec![common::types::api::CreateBlogPostContentRequest::H2(common::types::api::CreateH2BlockRequest {
+        text: "見出し".to_string(),
+      })],
+      footnotes: vec![],
+      seo: SeoMetadata {
+        description: Some("検索結果に表示される説明".to_string()),
+        og_image: Some(og_image.clone()),
+        canonical_url: Some("https://example.com/original".to_string()),
+        noindex: true,
+      },
+      manual_excerpt: Default::default(),
+    };
+
+    let create_body = serde_json::to_string(&create_request).context("作成リクエストのJSON変換に失敗")?;
+    let create_resp = Request::new(Methods::POST { body: create_body }, "http://localhost:8001/admin/blog/posts").send().await.unwrap().text().await.unwrap();
+    let created_post: BlogPost = serde_json::from_str(&create_resp).context("作成レスポンスのパースに失敗")?;
+
+    assert_eq!(created_post.seo, create_request.seo);
+
+    // SEO メタデータを空にして編集すると、すべての項目が未設定に戻る
+    let url = format!("http://localhost:8001/admin/blog/posts/{}", created_post.id);
+    let mut update_request = UpdateBlogPostRequest {
+      title: created_post.title.clone(),
+      thumbnail: created_post.thumbnail.clone(),
+      published_date: created_post.published_date,
+      contents: created_post.contents.clone(),
+      footnotes: vec![],
+      seo: Default::default(),
+      manual_excerpt: Default::default(),
+    };
+    let request_body = serde_json::to_string(&update_request).context("編集リクエストのJSON変換に失敗")?;
+    let response = Request::new(Methods::PUT { body: request_body }, &url).send().await.unwrap();
+    assert_eq!(response.status(), 200);
+    let edited_post: BlogPost = serde_json::from_str(&response.text().await.unwrap()).context("編集レスポンスのパースに失敗")?;
+    assert_eq!(edited_post.seo, SeoMetadata::default());
+
+    // 160 文字を超えるメタディスクリプションは受け付けない
+    update_request.seo.description = Some("あ".repeat(161));
+    let request_body = serde_json::to_string(&update_request).context("編集リクエストのJSON変換に失敗")?;
+    let response = Request::new(Methods::PUT { body: request_body }, &url).send().await.unwrap();
+    assert_eq!(response.status(), 400);
+
     Ok(())
   }
 
@@ -166,6 +246,9 @@ mod tests {
       thumbnail: dummy_post.thumbnail,
       published_date: dummy_post.published_date,
       contents: dummy_post.contents,
+      footnotes: vec![],
+      seo: Default::default(),
+      manual_excerpt: Default::default(),
     };
 
     let url = format!("http://localhost:8001/admin/blog/posts/{}", non_existent_id);
@@ -206,6 +289,9 @@ mod tests {
       thumbnail: popular_post.thumbnail.clone(),
       published_date: tomorrow,
       contents: popular_post.contents.clone(),
+      footnotes: vec![],
+      seo: Default::default(),
+      manual_excerpt: Default::default(),
     };
 
     let url = format!("http://localhost:8001/admin/blog/posts/{}", popular_post.id);
@@ -246,6 +332,9 @@ mod tests {
       thumbnail: pickup_post.thumbnail.clone(),
       published_date: tomorrow,
       contents: pickup_post.contents.clone(),
+      footnotes: vec![],
+      seo: Default::default(),
+      manual_excerpt: Default::default(),
     };
 
     let url = format!("http://localhost:8001/admin/blog/posts/{}", pickup_post.id);
@@ -286,6 +375,9 @@ mod tests {
       thumbnail: top_tech_pick_post.thumbnail.clone(),
       published_date: tomorrow,
       contents: top_tech_pick_post.contents.clone(),
+      footnotes: vec![],
+      seo: Default::default(),
+      manual_excerpt: Default::default(),
     };
 
     let url = format!("http://localhost:8001/admin/blog/posts/{}", top_tech_pick_post.id);
@@ -307,6 +399,23 @@ mod tests {
     Ok(())
   }
 
+  // 人気記事の選び方を保存して取得できる（他のテストで人気記事が変わらないよう、管理者が選ぶ設定のまま確かめる）
+  #[tokio::test(flavor = "current_thread")]
+  async fn put_popular_post_mode() -> Result<()> {
+    let url = "http://localhost:8001/admin/blog/posts/popular/mode";
+    let setting = PopularPostModeSetting { mode: PopularPostMode::Manual };
+    let body = serde_json::to_string(&setting).context("JSON データに変換できませんでした").unwrap();
+
+    let resp = Request::new(Methods::PUT { body }, url).send().await.unwrap().text().await.unwrap();
+    let updated: PopularPostModeSetting = serde_json::from_str(&resp).context("JSON データをパースできませんでした").unwrap();
+    assert_eq!(updated, setting);
+
+    let resp = Request::new(Methods::GET, url).send().await.unwrap().text().await.unwrap();
+    let saved: PopularPostModeSetting = serde_json::from_str(&resp).context("JSON データをパースできませんでした").unwrap();
+    assert_eq!(saved, setting);
+    Ok(())
+  }
+
   mod helper {
     use super::*;
 
@@ -52,6 +52,10 @@ pub fn assert_blog_post_without_uuid(actual: &BlogPost, expected: &BlogPost) {
           _ => panic!("Image 以外の要素が入っています"),
         };
         assert_eq!(a.path, e.path, "Imageのpath不一致: contents[{}]", i);
+        assert_eq!(a.alt, e.alt, "Imageのalt不一致: contents[{}]", i);
+        assert_eq!(a.caption, e.caption, "Imageのcaption不一致: contents[{}]", i);
+        assert_eq!(a.width, e.width, "Imageのwidth不一致: contents[{}]", i);
+        assert_eq!(a.link_url, e.link_url, "Imageのlink_url不一致: contents[{}]", i);
       }
       BlogPostContent::Code(actual_code_block) => {
         let expected_code_block = match expected_block {
@@ -65,6 +69,100 @@ pub fn assert_blog_post_without_uuid(actual: &BlogPost, expected: &BlogPost) {
           "CodeBlock の language 不一致: contents[{}]",
           i
         );
+        assert_eq!(
+          actual_code_block.highlight_lines, expected_code_block.highlight_lines,
+          "CodeBlock の highlight_lines 不一致: contents[{}]",
+          i
+        );
+        assert_eq!(
+          actual_code_block.start_line, expected_code_block.start_line,
+          "CodeBlock の start_line 不一致: contents[{}]",
+          i
+        );
+        assert_eq!(actual_code_block.diff, expected_code_block.diff, "CodeBlock の diff 不一致: contents[{}]", i);
+        assert_eq!(
+          actual_code_block.show_line_numbers, expected_code_block.show_line_numbers,
+          "CodeBlock の show_line_numbers 不一致: contents[{}]",
+          i
+        );
+      }
+      BlogPostContent::Math(actual_math_block) => {
+        let expected_math_block = match expected_block {
+          BlogPostContent::Math(e) => e,
+          _ => panic!("MathBlock 以外の要素が入っています"),
+        };
+        assert_eq!(
+          actual_math_block.source, expected_math_block.source,
+          "MathBlock の source 不一致: contents[{}]",
+          i
+        );
+      }
+      BlogPostContent::Embed(actual_embed_block) => {
+        let expected_embed_block = match expected_block {
+          BlogPostContent::Embed(e) => e,
+          _ => panic!("EmbedBlock 以外の要素が入っています"),
+        };
+        assert_eq!(
+          actual_embed_block.provider, expected_embed_block.provider,
+          "EmbedBlock の provider 不一致: contents[{}]",
+          i
+        );
+        assert_eq!(
+          actual_embed_block.resource_id, expected_embed_block.resource_id,
+          "EmbedBlock の resourceId 不一致: contents[{}]",
+          i
+        );
+      }
+      BlogPostContent::LinkCard(actual_link_card_block) => {
+        let expected_link_card_block = match expected_block {
+          BlogPostContent::LinkCard(e) => e,
+          _ => panic!("LinkCardBlock 以外の要素が入っています"),
+        };
+        assert_eq!(
+          actual_link_card_block.url, expected_link_card_block.url,
+          "LinkCardBlock の url 不一致: contents[{}]",
+          i
+        );
+        assert_eq!(
+          actual_link_card_block.title, expected_link_card_block.title,
+          "LinkCardBlock の title 不一致: contents[{}]",
+          i
+        );
+      }
+      BlogPostContent::Details(actual_details_block) => {
+        let expected_details_block = match expected_block {
+          BlogPostContent::Details(e) => e,
+          _ => panic!("DetailsBlock 以外の要素が入っています"),
+        };
+        assert_eq!(
+          actual_details_block.summary, expected_details_block.summary,
+          "DetailsBlock の summary 不一致: contents[{}]",
+          i
+        );
+        // 子コンテンツは種類と並び順が一致していることを確認する
+        let child_kinds = |children: &[BlogPostContent]| children.iter().map(std::mem::discriminant).collect::<Vec<_>>();
+        assert_eq!(
+          child_kinds(&actual_details_block.children),
+          child_kinds(&expected_details_block.children),
+          "DetailsBlock の children 不一致: contents[{}]",
+          i
+        );
+      }
+      BlogPostContent::Diagram(actual_diagram_block) => {
+        let expected_diagram_block = match expected_block {
+          BlogPostContent::Diagram(e) => e,
+          _ => panic!("DiagramBlock 以外の要素が入っています"),
+        };
+        assert_eq!(
+          actual_diagram_block.kind, expected_diagram_block.kind,
+          "DiagramBlock の kind 不一致: contents[{}]",
+          i
+        );
+        assert_eq!(
+          actual_diagram_block.source, expected_diagram_block.source,
+          "DiagramBlock の source 不一致: contents[{}]",
+          i
+        );
       }
     }
   }
@@ -90,6 +188,8 @@ pub fn minimal_blog_post1() -> Result<BlogPost> {
     thumbnail: Image {
       id: Uuid::new_v4(),
       path: "test-book".to_string(),
+      file_name: None,
+      caption: None,
     },
     post_date: "2025-01-01".parse()?,
     last_update_date: "2025-01-01".parse()?,
@@ -106,11 +206,23 @@ pub fn minimal_blog_post1() -> Result<BlogPost> {
           styles: Style {
             bold: false,
             inline_code: false,
+            inline_math: false,
+            italic: false,
+            strikethrough: false,
+            underline: false,
+            highlight: None,
           },
           link: Option::None,
+          ruby: None,
+          footnote_id: None,
         }],
       }),
     ],
+    footnotes: vec![],
+    seo: Default::default(),
+    excerpt: Default::default(),
+    manual_excerpt: Default::default(),
+    reading_time_minutes: Default::default(),
   };
   Ok(blog_post)
 }
@@ -122,6 +234,8 @@ pub fn minimal_blog_post2() -> Result<BlogPost> {
     thumbnail: Image {
       id: Uuid::new_v4(),
       path: "test-mechanical".to_string(),
+      file_name: None,
+      caption: None,
     },
     post_date: "2025-02-01".parse()?,
     last_update_date: "2025-02-01".parse()?,
@@ -138,11 +252,23 @@ pub fn minimal_blog_post2() -> Result<BlogPost> {
           styles: Style {
             bold: false,
             inline_code: false,
+            inline_math: false,
+            italic: false,
+            strikethrough: false,
+            underline: false,
+            highlight: None,
           },
           link: Option::None,
+          ruby: None,
+          footnote_id: None,
         }],
       }),
     ],
+    footnotes: vec![],
+    seo: Default::default(),
+    excerpt: Default::default(),
+    manual_excerpt: Default::default(),
+    reading_time_minutes: Default::default(),
   };
   Ok(blog_post)
 }
@@ -154,6 +280,8 @@ pub fn minimal_blog_post3() -> Result<BlogPost> {
     thumbnail: Image {
       id: Uuid::new_v4(),
       path: "test-coffee".to_string(),
+      file_name: None,
+      caption: None,
     },
     post_date: "2025-03-01".parse()?,
     last_update_date: "2025-03-01".parse()?,
@@ -170,11 +298,23 @@ pub fn minimal_blog_post3() -> Result<BlogPost> {
           styles: Style {
             bold: false,
             inline_code: false,
+            inline_math: false,
+            italic: false,
+            strikethrough: false,
+            underline: false,
+            highlight: None,
           },
           link: Option::None,
+          ruby: None,
+          footnote_id: None,
         }],
       }),
     ],
+    footnotes: vec![],
+    seo: Default::default(),
+    excerpt: Default::default(),
+    manual_excerpt: Default::default(),
+    reading_time_minutes: Default::default(),
   };
   Ok(blog_post)
 }
@@ -196,6 +336,8 @@ pub fn expected_future_blog_post() -> Result<BlogPost> {
     thumbnail: Image {
       id: Uuid::new_v4(),
       path: "test-book".to_string(),
+      file_name: None,
+      caption: None,
     },
     post_date: today_str.parse()?,
     last_update_date: today_str.parse()?,
@@ -212,11 +354,23 @@ pub fn expected_future_blog_post() -> Result<BlogPost> {
           styles: Style {
             bold: false,
             inline_code: false,
+            inline_math: false,
+            italic: false,
+            strikethrough: false,
+            underline: false,
+            highlight: None,
           },
           link: Option::None,
+          ruby: None,
+          footnote_id: None,
         }],
       }),
     ],
+    footnotes: vec![],
+    seo: Default::default(),
+    excerpt: Default::default(),
+    manual_excerpt: Default::default(),
+    reading_time_minutes: Default::default(),
   };
 
   Ok(blog_post)
@@ -17,10 +17,10 @@ mod tests {
     assert!(actual_images_resp.len() >= expected_images.len(), "取得した画像の数が期待値を下回っています");
     // expected_images　の各画像が含まれていることを、path を確認して検証
     for expected_image in expected_images {
-      assert!(
-        actual_images_resp.iter().any(|img| img.path == expected_image.path),
-        "取得した画像に期待される画像が含まれていません"
-      );
+      let actual_image = actual_images_resp.iter().find(|img| img.path == expected_image.path).expect("取得した画像に期待される画像が含まれていません");
+      // 画像自体に保存されたファイル名とキャプションも返されることを確認
+      assert_eq!(actual_image.file_name, expected_image.file_name);
+      assert_eq!(actual_image.caption, expected_image.caption);
     }
 
     Ok(())
@@ -34,14 +34,20 @@ mod tests {
       let image1 = Image {
         id: Uuid::new_v4(),
         path: "test-book".to_string(),
+        file_name: Some("book".to_string()),
+        caption: Some("本の画像".to_string()),
       };
       let image2 = Image {
         id: Uuid::new_v4(),
         path: "test-mechanical".to_string(),
+        file_name: Some("mechanical".to_string()),
+        caption: Some("機械の画像".to_string()),
       };
       let image3 = Image {
         id: Uuid::new_v4(),
         path: "test-coffee".to_string(),
+        file_name: Some("coffee".to_string()),
+        caption: Some("コーヒーの画像".to_string()),
       };
       vec![image1, image2, image3]
     }
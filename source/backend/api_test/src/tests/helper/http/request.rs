@@ -27,6 +27,11 @@ impl Request {
     }
   }
 
+  pub fn header(mut self, name: &str, value: &str) -> Self {
+    self.request_builder = self.request_builder.header(name, value);
+    self
+  }
+
   pub async fn send(self) -> Result<Response> {
     let resp = self.request_builder.send().await.context("HTTP リクエストを送りましたが、エラーが発生しました")?;
     Ok(Response::new(resp))
@@ -16,4 +16,8 @@ impl Response {
   pub fn status(&self) -> u16 {
     self.resp.status().as_u16()
   }
+
+  pub fn header(&self, name: &str) -> Option<String> {
+    self.resp.headers().get(name).and_then(|value| value.to_str().ok()).map(|value| value.to_string())
+  }
 }
@@ -93,8 +93,15 @@ mod tests {
         styles: Style {
           bold: true,
           inline_code: false,
+          inline_math: false,
+          italic: false,
+          strikethrough: false,
+          underline: false,
+          highlight: None,
         },
         link: None,
+        ruby: None,
+        footnote_id: None,
       }],
     });
 
@@ -109,6 +116,10 @@ mod tests {
     let image_content = BlogPostContent::Image(ImageBlock {
       id: Uuid::new_v4(),
       path: "/images/content.jpg".to_string(),
+      alt: String::new(),
+      caption: None,
+      width: None,
+      link_url: None,
     });
 
     let image_json = serde_json::to_string(&image_content)?;
@@ -124,6 +135,11 @@ mod tests {
       title: "サンプルコード".to_string(),
       code: "console.log('Hello, World!');".to_string(),
       language: "javascript".to_string(),
+      highlight_lines: None,
+      start_line: 1,
+      diff: false,
+      show_line_numbers: false,
+      highlighted: None,
     });
 
     let code_json = serde_json::to_string(&code_content)?;
@@ -168,16 +184,30 @@ mod tests {
           styles: Style {
             bold: false,
             inline_code: false,
+            inline_math: false,
+            italic: false,
+            strikethrough: false,
+            underline: false,
+            highlight: None,
           },
           link: None,
+          ruby: None,
+          footnote_id: None,
         },
         RichText {
           text: "太字テキスト".to_string(),
           styles: Style {
             bold: true,
             inline_code: false,
+            inline_math: false,
+            italic: false,
+            strikethrough: false,
+            underline: false,
+            highlight: None,
           },
           link: None,
+          ruby: None,
+          footnote_id: None,
         },
       ],
     };
@@ -190,6 +220,10 @@ mod tests {
     let image_block = ImageBlock {
       id: Uuid::new_v4(),
       path: "/images/test.jpg".to_string(),
+      alt: String::new(),
+      caption: None,
+      width: None,
+      link_url: None,
     };
 
     let image_json = serde_json::to_string(&image_block)?;
@@ -208,11 +242,18 @@ mod tests {
       thumbnail: Image {
         id: Uuid::new_v4(),
         path: "/images/empty.jpg".to_string(),
+        file_name: None,
+        caption: None,
       },
       post_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
       last_update_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
       published_date: NaiveDate::from_ymd_opt(1900, 1, 1).unwrap(),
       contents: vec![],
+      footnotes: vec![],
+      seo: Default::default(),
+      excerpt: Default::default(),
+      manual_excerpt: Default::default(),
+      reading_time_minutes: Default::default(),
     };
 
     let json = serde_json::to_string(&blog_post)?;
@@ -231,10 +272,19 @@ mod tests {
       styles: Style {
         bold: false,
         inline_code: false,
+        inline_math: false,
+        italic: false,
+        strikethrough: false,
+        underline: false,
+        highlight: None,
       },
       link: Some(Link {
         url: "https://example.com".to_string(),
+        post_id: None,
+        heading_id: None,
       }),
+      ruby: None,
+      footnote_id: None,
     };
 
     let json = serde_json::to_string(&rich_text)?;
@@ -251,8 +301,18 @@ mod tests {
   fn test_rich_text_with_multiple_styles() -> Result<()> {
     let rich_text = RichText {
       text: "太字かつコード".to_string(),
-      styles: Style { bold: true, inline_code: true },
+      styles: Style {
+        bold: true,
+        inline_code: true,
+        inline_math: false,
+        italic: false,
+        strikethrough: false,
+        underline: false,
+        highlight: None,
+      },
       link: None,
+      ruby: None,
+      footnote_id: None,
     };
 
     let json = serde_json::to_string(&rich_text)?;
@@ -286,6 +346,8 @@ mod tests {
       thumbnail: Image {
         id: Uuid::new_v4(),
         path: "/images/code.jpg".to_string(),
+        file_name: None,
+        caption: None,
       },
       post_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
       last_update_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
@@ -295,7 +357,17 @@ mod tests {
         title: "Rustのサンプル".to_string(),
         code: "fn main() {\n    println!(\"Hello, World!\");\n}".to_string(),
         language: "rust".to_string(),
+        highlight_lines: None,
+        start_line: 1,
+        diff: false,
+        show_line_numbers: false,
+        highlighted: None,
       })],
+      footnotes: vec![],
+      seo: Default::default(),
+      excerpt: Default::default(),
+      manual_excerpt: Default::default(),
+      reading_time_minutes: Default::default(),
     };
 
     let json = serde_json::to_string(&blog_post)?;
@@ -320,6 +392,8 @@ mod tests {
       thumbnail: Image {
         id: Uuid::new_v4(),
         path: "/images/test.jpg".to_string(),
+        file_name: None,
+        caption: None,
       },
       post_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
       last_update_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
@@ -336,11 +410,23 @@ mod tests {
             styles: Style {
               bold: true,
               inline_code: false,
+              inline_math: false,
+              italic: false,
+              strikethrough: false,
+              underline: false,
+              highlight: None,
             },
             link: None,
+            ruby: None,
+            footnote_id: None,
           }],
         }),
       ],
+      footnotes: vec![],
+      seo: Default::default(),
+      excerpt: Default::default(),
+      manual_excerpt: Default::default(),
+      reading_time_minutes: Default::default(),
     }
   }
 }
@@ -1,4 +1,4 @@
-use chrono::NaiveDate;
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
@@ -14,6 +14,49 @@ pub struct BlogPost {
   pub last_update_date: NaiveDate,
   pub published_date: NaiveDate,
   pub contents: Vec<BlogPostContent>,
+  // 脚注番号順に並んだ脚注。既存データとの互換性のため、未指定の場合は脚注なしとして扱う
+  #[serde(default)]
+  pub footnotes: Vec<Footnote>,
+  // 既存データとの互換性のため、未指定の場合は SEO メタデータなしとして扱う
+  #[serde(default)]
+  pub seo: SeoMetadata,
+  // 記事一覧のカードに表示する抜粋。手動で設定した抜粋、なければ本文の段落から作った抜粋
+  #[serde(default)]
+  pub excerpt: String,
+  // 手動で設定した抜粋。未設定の場合は本文から自動で作る
+  #[serde(default)]
+  pub manual_excerpt: Option<String>,
+  // 読了時間（分）
+  #[serde(default)]
+  pub reading_time_minutes: u32,
+}
+
+// 記事の SEO メタデータ。未設定の項目はフロントエンドで本文やサムネイルから補う
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SeoMetadata {
+  // メタディスクリプション（160 文字以内）
+  #[serde(default)]
+  pub description: Option<String>,
+  // OGP 画像。未指定の場合はサムネイルを使う
+  #[serde(default)]
+  pub og_image: Option<Image>,
+  // 正規 URL（http / https の絶対 URL）。未指定の場合は記事自身の URL
+  #[serde(default)]
+  pub canonical_url: Option<String>,
+  // 検索エンジンにインデックスさせない場合は true
+  #[serde(default)]
+  pub noindex: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Footnote {
+  pub id: Uuid,
+  // 本文中で最初に参照された順に 1 から振られる番号。更新リクエストでは無視され、サーバー側で振り直す
+  #[serde(default)]
+  pub number: u32,
+  pub text: Vec<RichText>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
@@ -21,6 +64,8 @@ pub struct BlogPost {
 pub struct Image {
   pub id: Uuid,
   pub path: String,
+  pub file_name: Option<String>,
+  pub caption: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
@@ -28,12 +73,41 @@ pub struct Image {
 pub struct Style {
   pub bold: bool,
   pub inline_code: bool,
+  // 既存データとの互換性のため、未指定の場合は false として扱う
+  #[serde(default)]
+  pub inline_math: bool,
+  #[serde(default)]
+  pub italic: bool,
+  #[serde(default)]
+  pub strikethrough: bool,
+  #[serde(default)]
+  pub underline: bool,
+  // マーカー（背景色）。未指定の場合はマーカーなし
+  #[serde(default)]
+  pub highlight: Option<HighlightColor>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Copy, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum HighlightColor {
+  Yellow,
+  Green,
+  Blue,
+  Pink,
+  Red,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Link {
+  // 内部リンクの場合はレスポンス時に解決した URL が入る（リクエスト時は省略可能）
+  #[serde(default)]
   pub url: String,
+  // 内部リンクのリンク先。指定した場合は url ではなく記事の現在の URL へリンクする
+  #[serde(default)]
+  pub post_id: Option<Uuid>,
+  #[serde(default)]
+  pub heading_id: Option<Uuid>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
@@ -49,6 +123,16 @@ pub enum BlogPostContent {
   Image(ImageBlock),
   #[serde(rename = "codeBlock")]
   Code(CodeBlock),
+  #[serde(rename = "math")]
+  Math(MathBlock),
+  #[serde(rename = "embed")]
+  Embed(EmbedBlock),
+  #[serde(rename = "linkCard")]
+  LinkCard(LinkCardBlock),
+  #[serde(rename = "details")]
+  Details(DetailsBlock),
+  #[serde(rename = "diagram")]
+  Diagram(DiagramBlock),
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
@@ -78,6 +162,12 @@ pub struct RichText {
   pub text: String,
   pub styles: Style,
   pub link: Option<Link>,
+  // ルビ（読み仮名）。既存データとの互換性のため、未指定の場合はルビなしとして扱う
+  #[serde(default)]
+  pub ruby: Option<String>,
+  // 参照する脚注の ID。脚注番号の表示位置になる
+  #[serde(default)]
+  pub footnote_id: Option<Uuid>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
@@ -85,6 +175,12 @@ pub struct RichText {
 pub struct ImageBlock {
   pub id: Uuid,
   pub path: String,
+  // 既存データとの互換性のため、未指定の場合は装飾画像 (空の代替テキスト) として扱う
+  #[serde(default)]
+  pub alt: String,
+  pub caption: Option<String>,
+  pub width: Option<u32>,
+  pub link_url: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
@@ -94,6 +190,108 @@ pub struct CodeBlock {
   pub title: String,
   pub code: String,
   pub language: String,
+  // 強調表示する行を "3-5,9" 形式で指定する（行番号は start_line を起点とした表示上の番号）
+  pub highlight_lines: Option<String>,
+  #[serde(default = "default_code_start_line")]
+  pub start_line: u32,
+  #[serde(default)]
+  pub diff: bool,
+  #[serde(default)]
+  pub show_line_numbers: bool,
+  // サーバー側でハイライトした結果。?highlight=tokens|html を指定した場合のみ含まれる
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub highlighted: Option<HighlightedCode>,
+}
+
+fn default_code_start_line() -> u32 {
+  1
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
+#[serde(tag = "format", rename_all = "camelCase")]
+pub enum HighlightedCode {
+  // 行ごとのトークン列
+  #[serde(rename = "tokens")]
+  Tokens { lines: Vec<Vec<HighlightToken>> },
+  // トークンを <span class="hl-{kind}"> で囲んだ HTML（改行で行を連結）
+  #[serde(rename = "html")]
+  Html { html: String },
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct HighlightToken {
+  pub text: String,
+  // keyword, string, comment などのトークンの種類。装飾しないトークンは null
+  pub kind: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MathBlock {
+  pub id: Uuid,
+  pub source: String,
+}
+
+// 埋め込みブロックは iframe を保持せず、フロントエンドがプロバイダとリソース ID から描画する
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EmbedBlock {
+  pub id: Uuid,
+  pub provider: EmbedProvider,
+  pub resource_id: String,
+  pub url: String,
+}
+
+// リンクカードはサーバー側で取得・保存した OGP メタデータを返す（取得できなかった項目は null）
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkCardBlock {
+  pub id: Uuid,
+  pub url: String,
+  pub title: Option<String>,
+  pub description: Option<String>,
+  pub site_name: Option<String>,
+  pub image_url: Option<String>,
+}
+
+// 折りたたみブロック。子コンテンツに折りたたみブロックや見出しは含められない
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DetailsBlock {
+  pub id: Uuid,
+  pub summary: String,
+  #[schema(no_recursion)]
+  pub children: Vec<BlogPostContent>,
+}
+
+// 図はソースのまま返し、フロントエンドが kind に応じたライブラリで描画する
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DiagramBlock {
+  pub id: Uuid,
+  pub kind: DiagramKind,
+  pub source: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Copy, ToSchema)]
+pub enum DiagramKind {
+  #[serde(rename = "mermaid")]
+  Mermaid,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Copy, ToSchema)]
+pub enum EmbedProvider {
+  #[serde(rename = "youtube")]
+  YouTube,
+  #[serde(rename = "twitter")]
+  Twitter,
+  #[serde(rename = "gist")]
+  GitHubGist,
+  #[serde(rename = "speakerdeck")]
+  SpeakerDeck,
+  #[serde(rename = "codepen")]
+  CodePen,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
@@ -111,6 +309,21 @@ pub struct CreateBlogPostRequest {
   pub last_update_date: NaiveDate,
   pub published_date: NaiveDate,
   pub contents: Vec<CreateBlogPostContentRequest>,
+  #[serde(default)]
+  pub footnotes: Vec<CreateFootnoteRequest>,
+  #[serde(default)]
+  pub seo: SeoMetadata,
+  // 記事一覧に表示する抜粋。未指定の場合は本文から自動で作る
+  #[serde(default)]
+  pub manual_excerpt: Option<String>,
+}
+
+// 脚注の ID は本文中の footnoteId から参照するため、クライアント側で採番する
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateFootnoteRequest {
+  pub id: Uuid,
+  pub text: Vec<RichText>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
@@ -139,6 +352,16 @@ pub enum CreateBlogPostContentRequest {
   Image(CreateImageBlockRequest),
   #[serde(rename = "codeBlock")]
   Code(CreateCodeBlockRequest),
+  #[serde(rename = "math")]
+  Math(CreateMathBlockRequest),
+  #[serde(rename = "embed")]
+  Embed(CreateEmbedBlockRequest),
+  #[serde(rename = "linkCard")]
+  LinkCard(CreateLinkCardBlockRequest),
+  #[serde(rename = "details")]
+  Details(CreateDetailsBlockRequest),
+  #[serde(rename = "diagram")]
+  Diagram(CreateDiagramBlockRequest),
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
@@ -163,6 +386,11 @@ pub struct CreateParagraphBlockRequest {
 #[serde(rename_all = "camelCase")]
 pub struct CreateImageBlockRequest {
   pub path: String,
+  #[serde(default)]
+  pub alt: String,
+  pub caption: Option<String>,
+  pub width: Option<u32>,
+  pub link_url: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
@@ -171,6 +399,47 @@ pub struct CreateCodeBlockRequest {
   pub title: String,
   pub code: String,
   pub language: String,
+  pub highlight_lines: Option<String>,
+  pub start_line: Option<u32>,
+  #[serde(default)]
+  pub diff: bool,
+  #[serde(default)]
+  pub show_line_numbers: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateMathBlockRequest {
+  pub source: String,
+}
+
+// 埋め込み元の URL を受け取り、サーバー側でプロバイダとリソース ID を抽出する
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateEmbedBlockRequest {
+  pub url: String,
+}
+
+// リンク先の URL のみを受け取り、OGP メタデータはサーバー側で取得する
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateLinkCardBlockRequest {
+  pub url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateDetailsBlockRequest {
+  pub summary: String,
+  #[schema(no_recursion)]
+  pub children: Vec<CreateBlogPostContentRequest>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateDiagramBlockRequest {
+  pub kind: DiagramKind,
+  pub source: String,
 }
 
 // 更新用のリクエスト型（post_dateとlast_update_dateは含まない）
@@ -181,4 +450,239 @@ pub struct UpdateBlogPostRequest {
   pub thumbnail: Image,
   pub published_date: NaiveDate,
   pub contents: Vec<BlogPostContent>,
+  #[serde(default)]
+  pub footnotes: Vec<Footnote>,
+  #[serde(default)]
+  pub seo: SeoMetadata,
+  // 記事一覧に表示する抜粋。未指定の場合は本文から自動で作る
+  #[serde(default)]
+  pub manual_excerpt: Option<String>,
+}
+
+// リンク切れになっている内部リンク（管理画面向け）
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DanglingInternalLink {
+  pub source_post_id: Uuid,
+  pub source_post_title: String,
+  pub target_post_id: Uuid,
+  pub target_heading_id: Option<Uuid>,
+  pub reason: DanglingLinkReason,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Copy, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum DanglingLinkReason {
+  // リンク先の記事が削除されている
+  PostNotFound,
+  // リンク先の記事が未公開
+  Unpublished,
+  // リンク先の記事に指定した見出しが存在しない
+  HeadingNotFound,
+}
+
+// Markdown からの記事の取り込み結果（管理画面向け）
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportMarkdownPostResponse {
+  pub post: BlogPost,
+  pub warnings: Vec<MarkdownImportWarning>,
+}
+
+// 取り込めなかった、または形を変えて取り込んだ記法
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkdownImportWarning {
+  // Markdown ファイル上の行番号（1 始まり）
+  pub line: u32,
+  pub message: String,
+}
+
+// 記事の一括書き出し・取り込みに使うアーカイブの目録（manifest.json）
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BlogArchiveManifest {
+  pub format_version: u32,
+  pub exported_at: DateTime<Utc>,
+  pub post_count: u32,
+  pub image_count: u32,
+}
+
+// アーカイブに含まれる注目記事の設定（featured.json）。未設定のものは空になる
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BlogArchiveFeaturedPosts {
+  pub top_tech_pick_post_id: Option<Uuid>,
+  pub pickup_post_ids: Vec<Uuid>,
+  pub popular_post_ids: Vec<Uuid>,
+}
+
+// アーカイブの取り込み結果（管理画面向け）
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportBlogArchiveResponse {
+  // true の場合は何も書き込まれていない
+  pub dry_run: bool,
+  pub post_count: u32,
+  pub image_count: u32,
+  pub conflicts: Vec<BlogArchiveConflict>,
+}
+
+// 既存のデータと衝突するアーカイブ内のレコード
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BlogArchiveConflict {
+  pub kind: BlogArchiveConflictKind,
+  pub id: Uuid,
+  pub message: String,
+  // true の場合は取り込みが中止される
+  pub blocking: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum BlogArchiveConflictKind {
+  // 同じ ID の記事が存在し、上書きされる
+  ExistingPost,
+  // 同じ ID の画像が存在し、上書きされる
+  ExistingImage,
+  // 同じパスの画像が別の ID で登録されている
+  ImagePathInUse,
+}
+
+// WordPress のエクスポートファイル（WXR）からの記事の取り込み結果（管理画面向け）
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportWordPressPostsResponse {
+  pub items: Vec<WordPressImportItemReport>,
+}
+
+// WordPress の記事 1 件ごとの取り込み結果
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WordPressImportItemReport {
+  pub wordpress_post_id: u64,
+  pub title: String,
+  pub status: WordPressImportStatus,
+  // 作成した記事の ID（取り込んだ場合のみ）
+  pub post_id: Option<Uuid>,
+  // 取り込まなかった・失敗した理由
+  pub reason: Option<String>,
+  // 取り込めなかった、または形を変えて取り込んだ内容
+  pub lossy_notes: Vec<String>,
+  // 新たに登録した画像のパス。画像ファイルは別途アップロードが必要
+  pub registered_image_paths: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum WordPressImportStatus {
+  Imported,
+  Skipped,
+  Failed,
+}
+
+// JSON Feed 1.1（https://www.jsonfeed.org/version/1.1/）。キーは仕様に合わせて snake_case にする
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
+pub struct JsonFeed {
+  // 常に "https://jsonfeed.org/version/1.1"
+  pub version: String,
+  pub title: String,
+  pub home_page_url: String,
+  pub feed_url: String,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub description: Option<String>,
+  pub language: String,
+  pub items: Vec<JsonFeedItem>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
+pub struct JsonFeedItem {
+  pub id: String,
+  pub url: String,
+  pub title: String,
+  // 本文のブロックを HTML に変換したもの
+  pub content_html: String,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub summary: Option<String>,
+  // サムネイル画像の URL
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub image: Option<String>,
+  pub date_published: DateTime<FixedOffset>,
+  pub date_modified: DateTime<FixedOffset>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub tags: Option<Vec<String>>,
+}
+
+// schema.org の BlogPosting を表す JSON-LD（https://schema.org/BlogPosting）。キーは schema.org の語彙に合わせる
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BlogPostingStructuredData {
+  // 常に "https://schema.org"
+  #[serde(rename = "@context")]
+  pub context: String,
+  // 常に "BlogPosting"
+  #[serde(rename = "@type")]
+  pub schema_type: String,
+  pub headline: String,
+  pub description: String,
+  // OGP 画像（未指定の場合はサムネイル）の URL
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub image: Option<String>,
+  // 正規 URL（未指定の場合は記事の URL）
+  pub url: String,
+  pub main_entity_of_page: String,
+  pub date_published: DateTime<FixedOffset>,
+  pub date_modified: DateTime<FixedOffset>,
+  pub in_language: String,
+  pub author: StructuredDataOrganization,
+  pub publisher: StructuredDataOrganization,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StructuredDataOrganization {
+  // 常に "Organization"
+  #[serde(rename = "@type")]
+  pub schema_type: String,
+  pub name: String,
+  pub url: String,
+}
+
+// 記事の目次の項目。H2 の項目は続く H3 を children に持つ
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TocItem {
+  // 見出しブロックの ID
+  pub id: Uuid,
+  pub text: String,
+  // 見出しへのリンクに使うアンカー。見出しのテキストと ID から作り、記事内で一意。HTML の見出しの id 属性や内部リンクのフラグメントと同じ値
+  pub anchor: String,
+  // 見出しのレベル（2 または 3）
+  pub level: u8,
+  #[schema(no_recursion)]
+  pub children: Vec<TocItem>,
+}
+
+// 関連記事の索引（記事ごとの語の出現回数）の再構築結果（管理画面向け）
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RebuildRelatedPostIndexResponse {
+  pub post_count: u32,
+}
+
+// 人気記事の選び方（管理画面向け）
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Copy, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum PopularPostMode {
+  // 管理者が選んだ記事を表示する
+  Manual,
+  // 直近の閲覧数から定期的に選び直す
+  Automatic,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PopularPostModeSetting {
+  pub mode: PopularPostMode,
 }